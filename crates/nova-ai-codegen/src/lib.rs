@@ -187,6 +187,7 @@ impl ErrorReport {
                 NovaSeverity::Error => "error",
                 NovaSeverity::Warning => "warning",
                 NovaSeverity::Info => "info",
+                NovaSeverity::Hint => "hint",
             };
             let bucket = match diagnostic_bucket(&diag.diagnostic) {
                 ValidationBucket::Syntax => "syntax",
@@ -1358,6 +1359,7 @@ fn severity_fingerprint(severity: NovaSeverity) -> u8 {
         NovaSeverity::Error => 0,
         NovaSeverity::Warning => 1,
         NovaSeverity::Info => 2,
+        NovaSeverity::Hint => 3,
     }
 }
 
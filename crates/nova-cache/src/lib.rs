@@ -45,6 +45,7 @@ mod pack;
 mod path;
 mod prune;
 mod query_disk_cache;
+mod router_shard_index;
 mod shard_index;
 mod store;
 mod util;
@@ -74,6 +75,10 @@ pub use pack::{
 pub use path::{normalize_inputs_map, normalize_rel_path};
 pub use prune::{prune_cache, PruneError, PrunePolicy, PruneReport};
 pub use query_disk_cache::{QueryDiskCache, QueryDiskCachePolicy, QUERY_DISK_CACHE_SCHEMA_VERSION};
+pub use router_shard_index::{
+    load_router_shard_index, router_shard_cache_path, save_router_shard_index, RouterShardIndex,
+    ShardRootFingerprint,
+};
 pub use shard_index::{load_shard_index, save_shard_index, shard_cache_path};
 pub use store::{store_for_url, CacheStore, HttpStore, LocalStore};
 pub use util::{atomic_write, now_millis, BINCODE_PAYLOAD_LIMIT_BYTES};
@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use nova_remote_proto::{ShardId, ShardIndex, PROTOCOL_VERSION};
+use serde::{Deserialize, Serialize};
+
+use crate::error::CacheError;
+use crate::util::{atomic_write, bincode_deserialize, bincode_serialize, read_file_limited};
+
+const ROUTER_SHARD_INDEX_CACHE_MAGIC: [u8; 8] = *b"NOVARSHD";
+const ROUTER_SHARD_INDEX_CACHE_FORMAT_VERSION: u32 = 1;
+
+/// A cheap summary of the Java files under a shard's source root, used to decide whether a
+/// router-side shard index cache still describes the root.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardRootFingerprint {
+    pub file_count: u64,
+    /// Latest modification time of any of the files, in nanoseconds since the Unix epoch.
+    pub max_mtime_nanos: u64,
+}
+
+/// A shard index persisted by the router, with the source root it was built from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouterShardIndex {
+    pub root: PathBuf,
+    pub root_fingerprint: ShardRootFingerprint,
+    pub index: ShardIndex,
+}
+
+#[derive(Debug, Serialize)]
+struct RouterShardIndexCacheFile<'a> {
+    magic: [u8; 8],
+    cache_format_version: u32,
+    protocol_version: u32,
+    root: &'a Path,
+    root_fingerprint: ShardRootFingerprint,
+    payload: &'a ShardIndex,
+}
+
+#[derive(Debug, Deserialize)]
+struct RouterShardIndexCacheFileOwned {
+    magic: [u8; 8],
+    cache_format_version: u32,
+    protocol_version: u32,
+    root: PathBuf,
+    root_fingerprint: ShardRootFingerprint,
+    payload: ShardIndex,
+}
+
+/// Where the router persists the index of `shard_id`. Kept apart from the workers' own caches
+/// (see [`crate::shard_cache_path`]), which live directly under `cache_dir`.
+pub fn router_shard_cache_path(cache_dir: &Path, shard_id: ShardId) -> PathBuf {
+    cache_dir
+        .join("router")
+        .join(format!("shard-{shard_id}.bin"))
+}
+
+pub fn save_router_shard_index(
+    cache_dir: &Path,
+    root: &Path,
+    root_fingerprint: ShardRootFingerprint,
+    index: &ShardIndex,
+) -> Result<(), CacheError> {
+    let path = router_shard_cache_path(cache_dir, index.shard_id);
+    let file = RouterShardIndexCacheFile {
+        magic: ROUTER_SHARD_INDEX_CACHE_MAGIC,
+        cache_format_version: ROUTER_SHARD_INDEX_CACHE_FORMAT_VERSION,
+        protocol_version: PROTOCOL_VERSION,
+        root,
+        root_fingerprint,
+        payload: index,
+    };
+    let bytes = bincode_serialize(&file)?;
+    atomic_write(&path, &bytes)
+}
+
+/// Load the router's cached index of `shard_id`.
+///
+/// A cache that can't be read back (corrupt, from another format or protocol version, or for
+/// another shard) is deleted and reported as a miss.
+pub fn load_router_shard_index(cache_dir: &Path, shard_id: ShardId) -> Option<RouterShardIndex> {
+    let path = router_shard_cache_path(cache_dir, shard_id);
+    let bytes = read_file_limited(&path)?;
+
+    let file: RouterShardIndexCacheFileOwned = match bincode_deserialize(&bytes) {
+        Ok(file) => file,
+        Err(err) => {
+            discard(
+                &path,
+                shard_id,
+                format_args!("failed to decode cache: {err}"),
+            );
+            return None;
+        }
+    };
+    if file.magic != ROUTER_SHARD_INDEX_CACHE_MAGIC
+        || file.cache_format_version != ROUTER_SHARD_INDEX_CACHE_FORMAT_VERSION
+        || file.protocol_version != PROTOCOL_VERSION
+    {
+        discard(
+            &path,
+            shard_id,
+            format_args!(
+                "unsupported cache version (format {}, protocol {})",
+                file.cache_format_version, file.protocol_version
+            ),
+        );
+        return None;
+    }
+    if file.payload.shard_id != shard_id {
+        discard(
+            &path,
+            shard_id,
+            format_args!("cache is for shard {}", file.payload.shard_id),
+        );
+        return None;
+    }
+
+    Some(RouterShardIndex {
+        root: file.root,
+        root_fingerprint: file.root_fingerprint,
+        index: file.payload,
+    })
+}
+
+fn discard(path: &Path, shard_id: ShardId, message: std::fmt::Arguments<'_>) {
+    let _ = std::fs::remove_file(path);
+    tracing::warn!(
+        target: "nova.cache",
+        shard_id,
+        path = %path.display(),
+        "discarding router shard index cache: {message}"
+    );
+}
@@ -11,4 +11,5 @@ mod metadata_roundtrip;
 mod pack_large_file;
 mod prune;
 mod query_disk_cache;
+mod router_shard_index;
 mod shard_index;
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use nova_cache::{
+    load_router_shard_index, router_shard_cache_path, save_router_shard_index, ShardRootFingerprint,
+};
+use nova_remote_proto::{ShardId, ShardIndex, Symbol};
+
+fn sample_index(shard_id: ShardId) -> ShardIndex {
+    ShardIndex {
+        shard_id,
+        revision: 9,
+        index_generation: 3,
+        symbols: vec![Symbol {
+            name: "Foo".to_string(),
+            path: "src/Foo.java".to_string(),
+            line: 2,
+            column: 13,
+        }],
+    }
+}
+
+#[test]
+fn router_shard_index_roundtrip() {
+    let tmp = tempfile::tempdir().unwrap();
+    let index = sample_index(4);
+    let fingerprint = ShardRootFingerprint {
+        file_count: 12,
+        max_mtime_nanos: 1_700_000_000_000_000_000,
+    };
+
+    save_router_shard_index(tmp.path(), Path::new("/ws/src"), fingerprint, &index).unwrap();
+    assert_eq!(
+        router_shard_cache_path(tmp.path(), 4),
+        tmp.path().join("router").join("shard-4.bin")
+    );
+
+    let loaded = load_router_shard_index(tmp.path(), 4).expect("cache hit");
+    assert_eq!(loaded.root, Path::new("/ws/src"));
+    assert_eq!(loaded.root_fingerprint, fingerprint);
+    assert_eq!(loaded.index, index);
+
+    assert!(load_router_shard_index(tmp.path(), 5).is_none());
+}
+
+#[test]
+fn corrupt_router_shard_index_is_deleted() {
+    let tmp = tempfile::tempdir().unwrap();
+    let index = sample_index(0);
+    save_router_shard_index(
+        tmp.path(),
+        Path::new("/ws/src"),
+        ShardRootFingerprint::default(),
+        &index,
+    )
+    .unwrap();
+
+    let path = router_shard_cache_path(tmp.path(), 0);
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes.truncate(bytes.len() / 2);
+    std::fs::write(&path, bytes).unwrap();
+
+    assert!(load_router_shard_index(tmp.path(), 0).is_none());
+    assert!(!path.exists(), "corrupt cache should be removed");
+
+    // A cache written for another shard is a miss too.
+    save_router_shard_index(
+        tmp.path(),
+        Path::new("/ws/src"),
+        ShardRootFingerprint::default(),
+        &index,
+    )
+    .unwrap();
+    std::fs::rename(&path, router_shard_cache_path(tmp.path(), 1)).unwrap();
+    assert!(load_router_shard_index(tmp.path(), 1).is_none());
+    assert!(!router_shard_cache_path(tmp.path(), 1).exists());
+}
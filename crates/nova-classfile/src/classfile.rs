@@ -1,4 +1,4 @@
-use crate::annotation::Annotation;
+use crate::annotation::{Annotation, ConstValue, ElementValue};
 use crate::constant_pool::ConstantPool;
 use crate::error::{Error, Result};
 use crate::reader::Reader;
@@ -17,6 +17,8 @@ pub struct ClassFile {
     pub runtime_visible_annotations: Vec<Annotation>,
     pub runtime_invisible_annotations: Vec<Annotation>,
     pub inner_classes: Vec<InnerClassInfo>,
+    /// The class has the `Deprecated` attribute.
+    pub is_deprecated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +29,8 @@ pub struct ClassMember {
     pub signature: Option<String>,
     pub runtime_visible_annotations: Vec<Annotation>,
     pub runtime_invisible_annotations: Vec<Annotation>,
+    /// The member has the `Deprecated` attribute.
+    pub is_deprecated: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -93,8 +97,71 @@ impl ClassFile {
             runtime_visible_annotations: class_attrs.runtime_visible_annotations,
             runtime_invisible_annotations: class_attrs.runtime_invisible_annotations,
             inner_classes: class_attrs.inner_classes,
+            is_deprecated: class_attrs.is_deprecated,
         })
     }
+
+    /// Whether the class is deprecated, with the `since`/`forRemoval` elements of its
+    /// `@Deprecated` annotation when it has one.
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        deprecation(
+            self.is_deprecated,
+            self.runtime_visible_annotations
+                .iter()
+                .chain(&self.runtime_invisible_annotations),
+        )
+    }
+}
+
+impl ClassMember {
+    /// Whether the member is deprecated, with the `since`/`forRemoval` elements of its
+    /// `@Deprecated` annotation when it has one.
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        deprecation(
+            self.is_deprecated,
+            self.runtime_visible_annotations
+                .iter()
+                .chain(&self.runtime_invisible_annotations),
+        )
+    }
+}
+
+/// A deprecated class or member (see [`ClassFile::deprecation`]).
+///
+/// javac emits both the `Deprecated` attribute and the `@Deprecated` annotation; only the
+/// annotation carries `since` and `forRemoval`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deprecation {
+    pub since: Option<String>,
+    pub for_removal: bool,
+}
+
+const DEPRECATED_DESCRIPTOR: &str = "Ljava/lang/Deprecated;";
+
+fn deprecation<'a>(
+    has_attribute: bool,
+    annotations: impl IntoIterator<Item = &'a Annotation>,
+) -> Option<Deprecation> {
+    let Some(annotation) = annotations
+        .into_iter()
+        .find(|a| a.type_descriptor == DEPRECATED_DESCRIPTOR)
+    else {
+        return has_attribute.then(Deprecation::default);
+    };
+
+    let mut out = Deprecation::default();
+    for (name, value) in &annotation.elements {
+        match (name.as_str(), value) {
+            ("since", ElementValue::Const(ConstValue::String(since))) => {
+                out.since = Some(since.clone());
+            }
+            ("forRemoval", ElementValue::Const(ConstValue::Boolean(for_removal))) => {
+                out.for_removal = *for_removal;
+            }
+            _ => {}
+        }
+    }
+    Some(out)
 }
 
 fn parse_member(reader: &mut Reader<'_>, cp: &ConstantPool) -> Result<ClassMember> {
@@ -110,6 +177,7 @@ fn parse_member(reader: &mut Reader<'_>, cp: &ConstantPool) -> Result<ClassMembe
         signature: attrs.signature,
         runtime_visible_annotations: attrs.runtime_visible_annotations,
         runtime_invisible_annotations: attrs.runtime_invisible_annotations,
+        is_deprecated: attrs.is_deprecated,
     })
 }
 
@@ -119,6 +187,7 @@ struct ParsedAttributes {
     runtime_visible_annotations: Vec<Annotation>,
     runtime_invisible_annotations: Vec<Annotation>,
     inner_classes: Vec<InnerClassInfo>,
+    is_deprecated: bool,
 }
 
 enum AttributeTarget {
@@ -146,6 +215,10 @@ fn parse_attributes(
                 parsed.signature = Some(cp.get_utf8(sig_index)?.to_string());
                 sub.ensure_empty()?;
             }
+            "Deprecated" => {
+                parsed.is_deprecated = true;
+                sub.ensure_empty()?;
+            }
             "RuntimeVisibleAnnotations" => {
                 let num = sub.read_u2()? as usize;
                 let mut anns = Vec::with_capacity(num);
@@ -11,7 +11,7 @@ mod signature;
 mod stub;
 
 pub use crate::annotation::{Annotation, ConstValue, ElementValue};
-pub use crate::classfile::{ClassFile, ClassMember, InnerClassInfo};
+pub use crate::classfile::{ClassFile, ClassMember, Deprecation, InnerClassInfo};
 pub use crate::descriptor::{parse_field_descriptor, parse_method_descriptor};
 pub use crate::descriptor::{BaseType, FieldType, MethodDescriptor, ReturnType};
 pub use crate::error::{Error, Result};
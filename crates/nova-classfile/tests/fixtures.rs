@@ -1,5 +1,5 @@
 use nova_classfile::{
-    BaseType, ClassFile, ClassMember, ConstValue, ElementValue, FieldType, ReturnType,
+    BaseType, ClassFile, ClassMember, ConstValue, Deprecation, ElementValue, FieldType, ReturnType,
     TypeSignature,
 };
 
@@ -168,6 +168,33 @@ fn parse_annotation_numeric_constants() {
     );
 }
 
+#[test]
+fn parse_deprecations() {
+    let bytes = include_bytes!("../testdata/Deprecations.class");
+    let class = ClassFile::parse(bytes).unwrap();
+    assert_eq!(class.deprecation(), None);
+
+    let field = class.fields.iter().find(|f| f.name == "gone").unwrap();
+    assert!(field.is_deprecated);
+    assert_eq!(
+        field.deprecation(),
+        Some(Deprecation {
+            since: Some("9".into()),
+            for_removal: true,
+        })
+    );
+
+    let method = |name: &str| class.methods.iter().find(|m| m.name == name).unwrap();
+    assert_eq!(method("old").deprecation(), Some(Deprecation::default()));
+    // A `@deprecated` javadoc tag only produces the `Deprecated` attribute.
+    assert!(method("javadocOnly").runtime_visible_annotations.is_empty());
+    assert_eq!(
+        method("javadocOnly").deprecation(),
+        Some(Deprecation::default())
+    );
+    assert_eq!(method("fresh").deprecation(), None);
+}
+
 #[test]
 fn stub_is_best_effort_for_unparseable_signature_attribute() {
     let class = ClassFile {
@@ -184,12 +211,14 @@ fn stub_is_best_effort_for_unparseable_signature_attribute() {
             signature: Some("not a signature".into()),
             runtime_visible_annotations: Vec::new(),
             runtime_invisible_annotations: Vec::new(),
+            is_deprecated: false,
         }],
         methods: Vec::new(),
         signature: Some("not a signature".into()),
         runtime_visible_annotations: Vec::new(),
         runtime_invisible_annotations: Vec::new(),
         inner_classes: Vec::new(),
+        is_deprecated: false,
     };
 
     let stub = class.stub().unwrap();
@@ -13,13 +13,13 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use nova_classfile::{parse_module_info_class, ClassFile};
+use nova_classfile::{parse_module_info_class, Annotation, ClassFile};
 use nova_core::{Name, PackageName, QualifiedName, StaticMemberId, TypeIndex, TypeName};
 use nova_deps_cache::{
     DependencyIndexBundle, DependencyIndexStore, DepsClassStub, DepsFieldStub, DepsMethodStub,
 };
 use nova_modules::{ModuleInfo, ModuleName};
-use nova_types::{FieldStub, MethodStub, TypeDefStub, TypeProvider};
+use nova_types::{Deprecation, FieldStub, MethodStub, TypeDefStub, TypeProvider};
 
 const MODULE_INFO_CLASS_CANDIDATES: [&str; 4] = [
     "module-info.class",
@@ -482,6 +482,7 @@ impl From<&ClasspathFieldStub> for FieldStub {
             descriptor: value.descriptor.clone(),
             signature: value.signature.clone(),
             access_flags: value.access_flags,
+            deprecation: deprecation(&value.annotations),
         }
     }
 }
@@ -493,6 +494,7 @@ impl From<&ClasspathMethodStub> for MethodStub {
             descriptor: value.descriptor.clone(),
             signature: value.signature.clone(),
             access_flags: value.access_flags,
+            deprecation: deprecation(&value.annotations),
         }
     }
 }
@@ -507,6 +509,9 @@ impl From<&ClasspathClassStub> for TypeDefStub {
             signature: value.signature.clone(),
             fields: value.fields.iter().map(FieldStub::from).collect(),
             methods: value.methods.iter().map(MethodStub::from).collect(),
+            deprecation: deprecation(&value.annotations),
+            // The persisted classpath stubs do not keep the `InnerClasses` attribute.
+            inner_class: None,
         }
     }
 }
@@ -2013,6 +2018,35 @@ fn manifest_is_multi_release(manifest: &str) -> bool {
         .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
 }
 
+const DEPRECATED_DESCRIPTOR: &str = "Ljava/lang/Deprecated;";
+
+/// The annotation type descriptors recorded in a stub. The classfile `Deprecated` attribute is
+/// recorded as `@Deprecated`, so that [`deprecation`] sees both.
+fn annotation_descriptors(
+    visible: Vec<Annotation>,
+    invisible: Vec<Annotation>,
+    has_deprecated_attribute: bool,
+) -> Vec<String> {
+    let mut out: Vec<String> = visible
+        .into_iter()
+        .chain(invisible)
+        .map(|a| a.type_descriptor)
+        .collect();
+    if has_deprecated_attribute && !out.iter().any(|a| a == DEPRECATED_DESCRIPTOR) {
+        out.push(DEPRECATED_DESCRIPTOR.to_string());
+    }
+    out
+}
+
+/// Stubs only record annotation types, so the `since`/`forRemoval` elements of `@Deprecated`
+/// are not available here.
+fn deprecation(annotations: &[String]) -> Option<Deprecation> {
+    annotations
+        .iter()
+        .any(|a| a == DEPRECATED_DESCRIPTOR)
+        .then(Deprecation::default)
+}
+
 fn stub_from_classfile(cf: ClassFile) -> ClasspathClassStub {
     let internal_name = cf.this_class;
     let binary_name = internal_name_to_binary(&internal_name);
@@ -2028,12 +2062,11 @@ fn stub_from_classfile(cf: ClassFile) -> ClasspathClassStub {
             .map(|i| internal_name_to_binary(&i))
             .collect(),
         signature: cf.signature,
-        annotations: cf
-            .runtime_visible_annotations
-            .into_iter()
-            .chain(cf.runtime_invisible_annotations)
-            .map(|a| a.type_descriptor)
-            .collect(),
+        annotations: annotation_descriptors(
+            cf.runtime_visible_annotations,
+            cf.runtime_invisible_annotations,
+            cf.is_deprecated,
+        ),
         fields: cf
             .fields
             .into_iter()
@@ -2042,12 +2075,11 @@ fn stub_from_classfile(cf: ClassFile) -> ClasspathClassStub {
                 descriptor: f.descriptor,
                 signature: f.signature,
                 access_flags: f.access_flags,
-                annotations: f
-                    .runtime_visible_annotations
-                    .into_iter()
-                    .chain(f.runtime_invisible_annotations)
-                    .map(|a| a.type_descriptor)
-                    .collect(),
+                annotations: annotation_descriptors(
+                    f.runtime_visible_annotations,
+                    f.runtime_invisible_annotations,
+                    f.is_deprecated,
+                ),
             })
             .collect(),
         methods: cf
@@ -2058,12 +2090,11 @@ fn stub_from_classfile(cf: ClassFile) -> ClasspathClassStub {
                 descriptor: m.descriptor,
                 signature: m.signature,
                 access_flags: m.access_flags,
-                annotations: m
-                    .runtime_visible_annotations
-                    .into_iter()
-                    .chain(m.runtime_invisible_annotations)
-                    .map(|a| a.type_descriptor)
-                    .collect(),
+                annotations: annotation_descriptors(
+                    m.runtime_visible_annotations,
+                    m.runtime_invisible_annotations,
+                    m.is_deprecated,
+                ),
             })
             .collect(),
     }
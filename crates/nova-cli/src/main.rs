@@ -2411,7 +2411,7 @@ fn workspace_symbols_distributed(args: &SymbolsArgs) -> Result<Vec<WorkspaceSymb
                 listen_addr,
                 worker_command,
                 cache_dir,
-                auth_token: None,
+                auth: None,
                 allow_insecure_tcp: false,
                 max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
                 max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -43,12 +43,11 @@ fn diagnostics(db: &dyn NovaDiagnostics, file: FileId) -> Arc<Vec<Diagnostic>> {
         cancel::checkpoint_cancelled_every(db, steps, 128);
         steps = steps.wrapping_add(1);
 
-        out.push(Diagnostic {
-            severity: Severity::Error,
-            code: "syntax-error".into(),
-            message: err.message.clone(),
-            span: Some(Span::new(err.range.start as usize, err.range.end as usize)),
-        });
+        out.push(Diagnostic::error(
+            "syntax-error",
+            err.message.clone(),
+            Some(Span::new(err.range.start as usize, err.range.end as usize)),
+        ));
     }
 
     // Syntax feature gate diagnostics.
@@ -123,8 +122,9 @@ fn diagnostics_cmp(a: &Diagnostic, b: &Diagnostic) -> Ordering {
 
 fn severity_rank(sev: Severity) -> u8 {
     match sev {
-        Severity::Error => 2,
-        Severity::Warning => 1,
-        Severity::Info => 0,
+        Severity::Error => 3,
+        Severity::Warning => 2,
+        Severity::Info => 1,
+        Severity::Hint => 0,
     }
 }
@@ -16,11 +16,12 @@ use nova_resolve::{NameResolution, Resolution, ScopeKind, StaticMemberResolution
 use nova_syntax::{lex, unescape_char_literal, JavaLanguageLevel, SyntaxKind, Token};
 use nova_types::{
     assignment_conversion, assignment_conversion_with_const, binary_numeric_promotion,
-    cast_conversion, format_resolved_method, format_type, infer_diamond_type_args, is_subtype, lub,
-    CallKind, ClassDef, ClassId, ClassKind, ConstValue, ConstructorDef, Diagnostic, FieldDef,
-    MethodCall, MethodCandidateFailureReason, MethodDef, MethodNotFound, MethodResolution,
-    PrimitiveType, ResolvedMethod, Span, TyContext, Type, TypeEnv, TypeParamDef, TypeProvider,
-    TypeStore, TypeVarId, TypeWarning, UncheckedReason, WildcardBound,
+    cast_conversion, deprecation_warning, format_resolved_method, format_type,
+    infer_diamond_type_args, is_subtype, lint_category, lub, warning_severity, CallKind, ClassDef,
+    ClassId, ClassKind, ConstValue, ConstructorDef, Deprecation, Diagnostic, DiagnosticTag,
+    FieldDef, MethodCall, MethodCandidateFailureReason, MethodDef, MethodNotFound,
+    MethodResolution, PrimitiveType, ResolvedMethod, Span, TyContext, Type, TypeEnv, TypeParamDef,
+    TypeProvider, TypeStore, TypeVarId, TypeWarning, UncheckedReason, WildcardBound,
 };
 use nova_types_bridge::ExternalTypeLoader;
 
@@ -1386,6 +1387,7 @@ fn type_of_def(db: &dyn NovaTypeck, def: DefWithBodyId) -> Type {
                 .unwrap_or(scopes.file_scope);
 
             let tree = db.hir_item_tree(file);
+            let text = db.file_content(file);
 
             // Signature-only type resolution: build a minimal type environment and resolve the
             // declared return type without touching the body HIR/typeck.
@@ -1428,7 +1430,7 @@ fn type_of_def(db: &dyn NovaTypeck, def: DefWithBodyId) -> Type {
             // Define source types in this file so `Type::Class` ids are stable.
             let SourceTypes {
                 source_type_vars, ..
-            } = define_source_types(&resolver, &scopes, &tree, &mut loader);
+            } = define_source_types(&resolver, &scopes, &tree, &text, &mut loader);
 
             let type_vars = type_vars_for_owner(
                 &resolver,
@@ -3052,6 +3054,35 @@ fn annotation_name_span(file_text: &str, ann: &nova_hir::item_tree::AnnotationUs
     Some(Span::new(name_start, name_end))
 }
 
+/// Best-effort: the [`Deprecation`] declared by a `@Deprecated` annotation, with its `since` and
+/// `forRemoval` elements read from the annotation's source text.
+fn source_deprecation(
+    file_text: &str,
+    annotations: &[nova_hir::item_tree::AnnotationUse],
+) -> Option<Deprecation> {
+    let ann = annotations
+        .iter()
+        .find(|ann| matches!(ann.name.as_str(), "Deprecated" | "java.lang.Deprecated"))?;
+    let mut deprecation = Deprecation::default();
+    let text = file_text
+        .get(ann.range.start..ann.range.end)
+        .unwrap_or_default();
+    let Some((_, args)) = text.split_once('(') else {
+        return Some(deprecation);
+    };
+    for element in args.trim_end().trim_end_matches(')').split(',') {
+        let Some((name, value)) = element.split_once('=') else {
+            continue;
+        };
+        match name.trim() {
+            "since" => deprecation.since = Some(value.trim().trim_matches('"').to_string()),
+            "forRemoval" => deprecation.for_removal = value.trim() == "true",
+            _ => {}
+        }
+    }
+    Some(deprecation)
+}
+
 fn alloc_type_param_ids(
     loader: &mut ExternalTypeLoader<'_>,
     default_bound: &Type,
@@ -3498,6 +3529,7 @@ fn project_base_type_store_data(
 
             let scopes = db.scope_graph(*file);
             let tree = db.hir_item_tree(*file);
+            let text = db.file_content(*file);
 
             let file_rel = db.file_rel_path(*file);
             let from = module_for_file(&cfg, file_rel.as_str());
@@ -3523,7 +3555,7 @@ fn project_base_type_store_data(
 
             // In JPMS mode, source type accessibility depends on the "from" module, so per-body
             // queries still build workspace member/type-param maps on demand.
-            let _ = define_source_types(&resolver, &scopes, &tree, &mut loader);
+            let _ = define_source_types(&resolver, &scopes, &tree, &text, &mut loader);
         }
     } else {
         let shadowing_provider =
@@ -3535,6 +3567,7 @@ fn project_base_type_store_data(
 
             let scopes = db.scope_graph(*file);
             let tree = db.hir_item_tree(*file);
+            let text = db.file_content(*file);
 
             let workspace_index = WorkspaceFirstIndex {
                 workspace: &workspace,
@@ -3545,7 +3578,7 @@ fn project_base_type_store_data(
                 .with_classpath(&workspace_index)
                 .with_workspace(&workspace);
 
-            source_types.extend(define_source_types(&resolver, &scopes, &tree, &mut loader));
+            source_types.extend(define_source_types(&resolver, &scopes, &tree, &text, &mut loader));
         }
 
         drop(loader);
@@ -4384,6 +4417,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
         let item_file = item.file();
         let tree = self.db.hir_item_tree(item_file);
         let scopes = self.db.scope_graph(item_file);
+        let text = self.db.file_content(item_file);
         let class_scope = scopes
             .class_scopes
             .get(&item)
@@ -4399,6 +4433,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
             loader,
             &object_ty,
             item_type_params(&tree, item),
+            Some(class_id),
             &mut class_vars,
         );
         let class_type_param_ids: Vec<TypeVarId> =
@@ -4489,6 +4524,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             ty,
                             is_static,
                             is_final,
+                            deprecation: source_deprecation(&text, &field.annotations),
                         });
                     }
                     nova_hir::item_tree::Member::Method(mid) => {
@@ -4509,6 +4545,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             loader,
                             &object_ty,
                             &method.type_params,
+                            None,
                             &mut vars,
                         );
                         let method_type_param_ids: Vec<TypeVarId> =
@@ -4576,10 +4613,13 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             name: method.name.clone(),
                             type_params: method_type_param_ids,
                             params,
+                            param_names: method.params.iter().map(|p| p.name.clone()).collect(),
                             return_type,
                             is_static,
                             is_varargs,
                             is_abstract: method.body.is_none(),
+                            is_private: method.modifiers.raw & Modifiers::PRIVATE != 0,
+                            deprecation: source_deprecation(&text, &method.annotations),
                         });
                     }
                     nova_hir::item_tree::Member::Constructor(cid) => {
@@ -4636,6 +4676,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             params,
                             is_varargs,
                             is_accessible,
+                            deprecation: source_deprecation(&text, &ctor.annotations),
                         });
                     }
                     _ => {}
@@ -4654,6 +4695,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                     params: Vec::new(),
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 });
             }
             nova_hir::ids::ItemId::Record(id) => {
@@ -4700,12 +4742,15 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                         params: canonical_params,
                         is_varargs: canonical_is_varargs,
                         is_accessible,
+                        deprecation: None,
                     });
                 }
             }
             _ => {}
         }
 
+        let (enclosing, is_static_nested) =
+            source_nesting(&scopes.scopes, &tree, item, class_scope, loader);
         loader.store.define_class(
             class_id,
             ClassDef {
@@ -4717,6 +4762,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                 fields,
                 constructors,
                 methods,
+                enclosing,
+                is_static_nested,
+                is_abstract: item_modifiers(&tree, item).raw & Modifiers::ABSTRACT != 0,
+                deprecation: source_deprecation(&text, item_annotations(&tree, item)),
             },
         );
 
@@ -6114,6 +6163,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                         args: sig.params.clone(),
                         expected_return: Some(sig.return_type.clone()),
                         explicit_type_args: vec![],
+                        allow_partial_type_args: false,
+                        start_above: None,
+                        caller_context: None,
+                        arg_shapes: vec![],
                     };
                     let mut ctx = TyContext::new(env_ro);
                     let static_res = nova_types::resolve_method_call(&mut ctx, &static_call);
@@ -6132,6 +6185,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                 args: rest.to_vec(),
                                 expected_return: Some(sig.return_type.clone()),
                                 explicit_type_args: vec![],
+                                allow_partial_type_args: false,
+                                start_above: None,
+                                caller_context: None,
+                                arg_shapes: vec![],
                             };
                             let mut ctx = TyContext::new(env_ro);
                             let instance_res =
@@ -6149,6 +6206,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                         args: sig.params.clone(),
                         expected_return: Some(sig.return_type.clone()),
                         explicit_type_args: vec![],
+                        allow_partial_type_args: false,
+                        start_above: None,
+                        caller_context: None,
+                        arg_shapes: vec![],
                     };
                     let mut ctx = TyContext::new(env_ro);
                     let res = nova_types::resolve_method_call(&mut ctx, &call);
@@ -7402,6 +7463,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                     args: inner_arg_tys,
                                     expected_return: None,
                                     explicit_type_args: Vec::new(),
+                                    allow_partial_type_args: false,
+                                    start_above: None,
+                                    caller_context: None,
+                                    arg_shapes: vec![],
                                 };
 
                                 let env_ro: &dyn TypeEnv = &*loader.store;
@@ -7441,6 +7506,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                         args: inner_arg_tys.clone(),
                                         expected_return: None,
                                         explicit_type_args: Vec::new(),
+                                        allow_partial_type_args: false,
+                                        start_above: None,
+                                        caller_context: None,
+                                        arg_shapes: vec![],
                                     };
 
                                     match {
@@ -7487,6 +7556,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -7522,6 +7595,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -7557,6 +7634,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -8571,16 +8652,17 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
     }
 
     fn emit_unchecked_warning(&mut self, reason: UncheckedReason, span: Span) {
-        let reason = match reason {
-            UncheckedReason::RawConversion => "raw conversion",
-            UncheckedReason::UncheckedCast => "cast",
-            UncheckedReason::UncheckedVarargs => "varargs",
+        let message = match &reason {
+            UncheckedReason::RawConversion => "unchecked raw conversion",
+            UncheckedReason::RawTypeUsage => "raw type usage",
+            UncheckedReason::UncheckedAssignment => "unchecked assignment",
+            UncheckedReason::UncheckedInvocation => "unchecked method invocation",
+            UncheckedReason::UncheckedCast => "unchecked cast",
+            UncheckedReason::UncheckedVarargs => "unchecked varargs",
         };
-        self.diagnostics.push(Diagnostic::warning(
-            "unchecked",
-            format!("unchecked {reason}"),
-            Some(span),
-        ));
+        let category = lint_category(&TypeWarning::Unchecked(reason));
+        self.diagnostics
+            .push(Diagnostic::warning(category.as_str(), message, Some(span)));
     }
 
     fn emit_method_warnings(
@@ -8605,6 +8687,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                 }
             }
         }
+        unique.extend(deprecation_warning(method));
 
         for warning in unique {
             match warning {
@@ -8624,6 +8707,23 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                 TypeWarning::Unchecked(reason) => {
                     self.emit_unchecked_warning(reason, call_span);
                 }
+                TypeWarning::DeprecatedMember { for_removal } => {
+                    let name = method.name.as_str();
+                    let message = if for_removal {
+                        format!("`{name}` is deprecated and marked for removal")
+                    } else {
+                        format!("`{name}` is deprecated")
+                    };
+                    self.diagnostics.push(
+                        Diagnostic::new(
+                            warning_severity(&warning),
+                            lint_category(&warning).as_str(),
+                            message,
+                            Some(call_span),
+                        )
+                        .with_tag(DiagnosticTag::Deprecated),
+                    );
+                }
             }
         }
     }
@@ -8746,6 +8846,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                     args: inner_arg_tys,
                                     expected_return: None,
                                     explicit_type_args: Vec::new(),
+                                    allow_partial_type_args: false,
+                                    start_above: None,
+                                    caller_context: None,
+                                    arg_shapes: vec![],
                                 };
 
                                 let env_ro: &dyn TypeEnv = &*loader.store;
@@ -8785,6 +8889,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                         args: inner_arg_tys.clone(),
                                         expected_return: None,
                                         explicit_type_args: Vec::new(),
+                                        allow_partial_type_args: false,
+                                        start_above: None,
+                                        caller_context: None,
+                                        arg_shapes: vec![],
                                     };
 
                                     match {
@@ -8831,6 +8939,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -8866,6 +8978,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -8901,6 +9017,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                             args: inner_arg_tys.clone(),
                                             expected_return: None,
                                             explicit_type_args: Vec::new(),
+                                            allow_partial_type_args: false,
+                                            start_above: None,
+                                            caller_context: None,
+                                            arg_shapes: vec![],
                                         };
                                         match {
                                             let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9035,6 +9155,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                 self.ensure_type_loaded(loader, &recv_ty);
 
                 let arg_types = arg_types(self, loader);
+                let caller_context = self.caller_class(loader);
                 let call = MethodCall {
                     receiver: recv_ty,
                     call_kind,
@@ -9042,6 +9163,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                     args: arg_types,
                     expected_return: expected.cloned(),
                     explicit_type_args: resolved_explicit_type_args.clone(),
+                    allow_partial_type_args: false,
+                    start_above: None,
+                    caller_context,
+                    arg_shapes: vec![],
                 };
 
                 let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9095,6 +9220,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                                 args: call.args.clone(),
                                 expected_return: call.expected_return.clone(),
                                 explicit_type_args: call.explicit_type_args.clone(),
+                                allow_partial_type_args: false,
+                                start_above: None,
+                                caller_context: None,
+                                arg_shapes: vec![],
                             };
                             let mut ctx = TyContext::new(env_ro);
                             match nova_types::resolve_method_call(&mut ctx, &instance_call) {
@@ -9139,6 +9268,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                 // static-imported methods.
                 let mut implicit_not_found: Option<MethodNotFound> = None;
                 let is_static_context = self.is_static_context();
+                let caller_context = self.caller_class(loader);
                 for item in self.enclosing_class_items() {
                     let Some(owner_name) = self.scopes.type_name(item).map(|t| t.as_str()) else {
                         continue;
@@ -9165,6 +9295,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                         args: arg_types.clone(),
                         expected_return: expected.cloned(),
                         explicit_type_args: resolved_explicit_type_args.clone(),
+                        allow_partial_type_args: false,
+                        start_above: None,
+                        caller_context,
+                        arg_shapes: vec![],
                     };
 
                     let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9226,6 +9360,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             args: arg_types.clone(),
                             expected_return: None,
                             explicit_type_args: resolved_explicit_type_args.clone(),
+                            allow_partial_type_args: false,
+                            start_above: None,
+                            caller_context: None,
+                            arg_shapes: vec![],
                         };
                         let mut ctx = TyContext::new(env_ro);
                         match nova_types::resolve_method_call(&mut ctx, &call) {
@@ -9280,6 +9418,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             args: arg_types,
                             expected_return: expected.cloned(),
                             explicit_type_args: resolved_explicit_type_args.clone(),
+                            allow_partial_type_args: false,
+                            start_above: None,
+                            caller_context: None,
+                            arg_shapes: vec![],
                         };
 
                         let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9369,6 +9511,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             args: arg_types,
                             expected_return: expected.cloned(),
                             explicit_type_args: resolved_explicit_type_args.clone(),
+                            allow_partial_type_args: false,
+                            start_above: None,
+                            caller_context: None,
+                            arg_shapes: vec![],
                         };
 
                         let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9454,6 +9600,10 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
                             args: arg_types,
                             expected_return: expected.cloned(),
                             explicit_type_args: resolved_explicit_type_args.clone(),
+                            allow_partial_type_args: false,
+                            start_above: None,
+                            caller_context: None,
+                            arg_shapes: vec![],
                         };
 
                         let env_ro: &dyn TypeEnv = &*loader.store;
@@ -9745,6 +9895,28 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
             return Diagnostic::error("unresolved-method", message, Some(span));
         }
 
+        // An overload that matches but is `private` elsewhere reads like javac's "has private
+        // access" error rather than a missing method.
+        if let Some(inaccessible) = not_found.candidates.iter().find(|cand| {
+            cand.failures
+                .iter()
+                .any(|failure| failure.reason == MethodCandidateFailureReason::Inaccessible)
+        }) {
+            let owner = format_type(env, &Type::class(inaccessible.candidate.owner, vec![]));
+            let params = inaccessible
+                .candidate
+                .params
+                .iter()
+                .map(|t| format_type(env, t))
+                .collect::<Vec<_>>();
+            let message = format!(
+                "`{}({})` has private access in `{owner}`",
+                not_found.name,
+                params.join(", ")
+            );
+            return Diagnostic::error("unresolved-method", message, Some(span));
+        }
+
         message.push_str("\n\ncandidates:");
         for cand in not_found.candidates.iter().take(5) {
             message.push_str("\n  - ");
@@ -9961,23 +10133,7 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
         item: nova_hir::ids::ItemId,
         enclosing: nova_hir::ids::ItemId,
     ) -> bool {
-        use nova_hir::ids::ItemId::*;
-
-        // Interfaces, annotations, enums, and records are implicitly static member types.
-        if matches!(item, Interface(_) | Annotation(_) | Enum(_) | Record(_)) {
-            return true;
-        }
-
-        // Any member type declared in an interface/annotation is implicitly static.
-        if matches!(enclosing, Interface(_) | Annotation(_)) {
-            return true;
-        }
-
-        match item {
-            Class(id) => self.tree.class(id).modifiers.raw & Modifiers::STATIC != 0,
-            // Covered above, but keep a catch-all for future variants.
-            _ => false,
-        }
+        member_type_is_static(self.tree, item, enclosing)
     }
 
     fn var_inference_enabled(&self) -> bool {
@@ -10008,6 +10164,14 @@ impl<'a, 'idx> BodyChecker<'a, 'idx> {
         None
     }
 
+    /// The class containing the code being checked, used to decide member accessibility.
+    fn caller_class(&self, loader: &mut ExternalTypeLoader<'_>) -> Option<ClassId> {
+        match self.enclosing_class_type(loader)? {
+            Type::Class(nova_types::ClassType { def, .. }) => Some(def),
+            _ => None,
+        }
+    }
+
     fn resolve_qualified_this_super_qualifier_type(
         &mut self,
         loader: &mut ExternalTypeLoader<'_>,
@@ -10924,8 +11088,9 @@ fn define_workspace_source_types<'idx>(
             }
         }
         let tree = db.hir_item_tree(file);
+        let text = db.file_content(file);
         let scopes = db.scope_graph(file);
-        out.extend(define_source_types(resolver, &scopes, &tree, loader));
+        out.extend(define_source_types(resolver, &scopes, &tree, &text, loader));
     }
 
     out
@@ -11003,6 +11168,7 @@ fn type_vars_for_owner<'idx>(
                 loader,
                 &object_ty,
                 &tree.constructor(c).type_params,
+                None,
                 &mut vars,
             );
         }
@@ -11012,6 +11178,100 @@ fn type_vars_for_owner<'idx>(
     vars
 }
 
+fn item_modifiers(tree: &nova_hir::item_tree::ItemTree, item: nova_hir::ids::ItemId) -> Modifiers {
+    match item {
+        nova_hir::ids::ItemId::Class(id) => tree.class(id).modifiers,
+        nova_hir::ids::ItemId::Interface(id) => tree.interface(id).modifiers,
+        nova_hir::ids::ItemId::Enum(id) => tree.enum_(id).modifiers,
+        nova_hir::ids::ItemId::Record(id) => tree.record(id).modifiers,
+        nova_hir::ids::ItemId::Annotation(id) => tree.annotation(id).modifiers,
+    }
+}
+
+/// Returns `true` if `item` is a member type that is `static` (or implicitly static) with respect
+/// to its enclosing type `enclosing`.
+fn member_type_is_static(
+    tree: &nova_hir::item_tree::ItemTree,
+    item: nova_hir::ids::ItemId,
+    enclosing: nova_hir::ids::ItemId,
+) -> bool {
+    use nova_hir::ids::ItemId::*;
+
+    // Interfaces, annotations, enums, and records are implicitly static member types.
+    if matches!(item, Interface(_) | Annotation(_) | Enum(_) | Record(_)) {
+        return true;
+    }
+
+    // Any member type declared in an interface/annotation is implicitly static.
+    if matches!(enclosing, Interface(_) | Annotation(_)) {
+        return true;
+    }
+
+    match item {
+        Class(id) => tree.class(id).modifiers.raw & Modifiers::STATIC != 0,
+        // Covered above, but keep a catch-all for future variants.
+        _ => false,
+    }
+}
+
+/// The lexically enclosing class of the source type `item` (whose body scope is `class_scope`),
+/// and whether `item` is static with respect to it.
+///
+/// A local class declared in a static method or initializer has no enclosing instance either.
+fn source_nesting(
+    scopes: &nova_resolve::ScopeGraph,
+    tree: &nova_hir::item_tree::ItemTree,
+    item: nova_hir::ids::ItemId,
+    class_scope: nova_resolve::ScopeId,
+    loader: &mut ExternalTypeLoader<'_>,
+) -> (Option<ClassId>, bool) {
+    let mut in_static_body = false;
+    let mut scope = scopes.scope_opt(class_scope).and_then(|data| data.parent());
+    let mut steps = 0u32;
+    while let Some(id) = scope {
+        // Avoid panics and infinite loops if the scope graph is malformed.
+        let Some(data) = scopes.scope_opt(id) else {
+            break;
+        };
+        match data.kind() {
+            ScopeKind::Class { item: outer } => {
+                let enclosing = scopes
+                    .type_name(*outer)
+                    .map(|name| loader.store.intern_class_id(name.as_str()));
+                let is_static = in_static_body || member_type_is_static(tree, item, *outer);
+                return (enclosing, is_static);
+            }
+            ScopeKind::Method { method } => {
+                in_static_body |= tree.method(*method).modifiers.raw & Modifiers::STATIC != 0;
+            }
+            ScopeKind::Initializer { initializer } => {
+                in_static_body |= tree.initializer(*initializer).is_static;
+            }
+            _ => {}
+        }
+
+        scope = data.parent();
+        steps = steps.wrapping_add(1);
+        if steps > 256 {
+            break;
+        }
+    }
+    (None, false)
+}
+
+fn item_annotations(
+    tree: &nova_hir::item_tree::ItemTree,
+    item: nova_hir::ids::ItemId,
+) -> &[nova_hir::item_tree::AnnotationUse] {
+    match item {
+        nova_hir::ids::ItemId::Class(id) => tree.class(id).annotations.as_slice(),
+        nova_hir::ids::ItemId::Interface(id) => tree.interface(id).annotations.as_slice(),
+        nova_hir::ids::ItemId::Enum(id) => tree.enum_(id).annotations.as_slice(),
+        nova_hir::ids::ItemId::Record(id) => tree.record(id).annotations.as_slice(),
+        nova_hir::ids::ItemId::Annotation(id) => tree.annotation(id).annotations.as_slice(),
+    }
+}
+
 fn item_type_params<'a>(
     tree: &'a nova_hir::item_tree::ItemTree,
     item: nova_hir::ids::ItemId,
@@ -11031,6 +11291,7 @@ fn allocate_type_params<'idx>(
     loader: &mut ExternalTypeLoader<'_>,
     default_bound: &Type,
     type_params: &[nova_hir::item_tree::TypeParam],
+    declared_by: Option<ClassId>,
     vars: &mut HashMap<String, TypeVarId>,
 ) -> Vec<(String, TypeVarId)> {
     let mut allocated = Vec::new();
@@ -11082,6 +11343,7 @@ fn allocate_type_params<'idx>(
                 name: tp.name.clone(),
                 upper_bounds,
                 lower_bound: None,
+                declared_by,
             },
         );
     }
@@ -11254,6 +11516,7 @@ fn define_source_types<'idx>(
     resolver: &nova_resolve::Resolver<'idx>,
     scopes: &nova_resolve::ItemTreeScopeBuildResult,
     tree: &nova_hir::item_tree::ItemTree,
+    text: &str,
     loader: &mut ExternalTypeLoader<'_>,
 ) -> SourceTypes {
     let mut items = Vec::new();
@@ -11312,6 +11575,7 @@ fn define_source_types<'idx>(
             loader,
             &object_ty,
             class_type_params,
+            Some(class_id),
             &mut class_vars,
         );
         source_type_vars
@@ -11360,6 +11624,7 @@ fn define_source_types<'idx>(
                         ty,
                         is_static,
                         is_final,
+                        deprecation: source_deprecation(text, &field.annotations),
                     });
                 }
                 nova_hir::item_tree::Member::Method(mid) => {
@@ -11377,6 +11642,7 @@ fn define_source_types<'idx>(
                         loader,
                         &object_ty,
                         &method.type_params,
+                        None,
                         &mut vars,
                     );
                     source_type_vars.methods.insert(*mid, type_params.clone());
@@ -11435,10 +11701,14 @@ fn define_source_types<'idx>(
                         name: method.name.clone(),
                         type_params: method_type_param_ids,
                         params,
+                        param_names: method.params.iter().map(|p| p.name.clone()).collect(),
                         return_type,
                         is_static,
                         is_varargs,
                         is_abstract: method.body.is_none(),
+                        is_private: method.modifiers.raw & nova_hir::item_tree::Modifiers::PRIVATE
+                            != 0,
+                        deprecation: source_deprecation(text, &method.annotations),
                     });
                 }
                 nova_hir::item_tree::Member::Constructor(cid) => {
@@ -11489,6 +11759,7 @@ fn define_source_types<'idx>(
                         params,
                         is_varargs,
                         is_accessible,
+                        deprecation: source_deprecation(text, &ctor.annotations),
                     });
                 }
                 _ => {}
@@ -11506,6 +11777,7 @@ fn define_source_types<'idx>(
                     params: Vec::new(),
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 });
             }
             nova_hir::ids::ItemId::Record(id) => {
@@ -11552,12 +11824,15 @@ fn define_source_types<'idx>(
                         params: canonical_params,
                         is_varargs: canonical_is_varargs,
                         is_accessible,
+                        deprecation: None,
                     });
                 }
             }
             _ => {}
         }
 
+        let (enclosing, is_static_nested) =
+            source_nesting(&scopes.scopes, tree, item, class_scope, loader);
         loader.store.define_class(
             class_id,
             ClassDef {
@@ -11569,6 +11844,10 @@ fn define_source_types<'idx>(
                 fields,
                 constructors,
                 methods,
+                enclosing,
+                is_static_nested,
+                is_abstract: item_modifiers(tree, item).raw & Modifiers::ABSTRACT != 0,
+                deprecation: source_deprecation(text, item_annotations(tree, item)),
             },
         );
     }
@@ -13106,6 +13385,7 @@ fn format_method_candidate_failure_reason(
                 arg_index + 1
             )
         }
+        MethodCandidateFailureReason::Inaccessible => "method has private access".to_string(),
     }
 }
 
@@ -686,6 +686,65 @@ class C {
     );
 }
 
+#[test]
+fn widening_to_raw_type_emits_rawtypes_warning() {
+    let src = r#"
+class C {
+    void m(java.util.List<String> xs) {
+        java.util.List raw = xs;
+    }
+}
+"#;
+
+    let (db, file) = setup_db(src);
+    let diags = db.type_diagnostics(file);
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.code.as_ref() == "rawtypes"),
+        "expected a rawtypes warning diagnostic, got {diags:?}"
+    );
+    assert!(
+        diags.iter().all(|d| d.code.as_ref() != "unchecked"),
+        "expected no unchecked warning diagnostic, got {diags:?}"
+    );
+}
+
+#[test]
+fn calling_deprecated_source_method_emits_deprecation_diagnostic() {
+    let src = r#"
+class Legacy {
+    @Deprecated
+    void old() {}
+
+    @java.lang.Deprecated(since = "9", forRemoval = true)
+    void gone() {}
+}
+
+class C {
+    void m(Legacy legacy) {
+        legacy.old();
+        legacy.gone();
+    }
+}
+"#;
+
+    let (db, file) = setup_db(src);
+    let diags = db.type_diagnostics(file);
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.code.as_ref() == "deprecation" && d.message.contains("`old`")),
+        "expected a deprecation diagnostic, got {diags:?}"
+    );
+    assert!(
+        diags.iter().any(|d| d.severity == Severity::Warning
+            && d.code.as_ref() == "removal"
+            && d.message.contains("`gone`")),
+        "expected a removal warning diagnostic, got {diags:?}"
+    );
+}
+
 #[test]
 fn synchronized_on_primitive_is_error() {
     let src = r#"
@@ -6048,6 +6107,103 @@ class Foo {
     );
 }
 
+#[test]
+fn source_abstract_class_is_not_instantiable() {
+    let src = r#"
+abstract class Shape {
+    Shape() {}
+}
+
+class Square extends Shape {
+    Square() {}
+}
+"#;
+
+    let (db, file) = setup_db(src);
+
+    let tree = db.hir_item_tree(file);
+    let (&ctor_ast_id, _) = tree
+        .constructors
+        .iter()
+        .find(|(_, ctor)| ctor.name == "Square")
+        .expect("expected Square constructor in item tree");
+    let ctor_id = nova_hir::ids::ConstructorId::new(file, ctor_ast_id);
+
+    let result = db.typeck_body(DefWithBodyId::Constructor(ctor_id));
+    let env = &*result.env;
+    let shape = env
+        .lookup_class("Shape")
+        .expect("expected Shape to be in env");
+    let square = env
+        .lookup_class("Square")
+        .expect("expected Square to be in env");
+    assert!(
+        env.class(shape)
+            .expect("expected Shape class def")
+            .is_abstract
+    );
+    assert!(!nova_types::is_instantiable(
+        env,
+        &Type::class(shape, vec![])
+    ));
+    assert!(nova_types::is_instantiable(
+        env,
+        &Type::class(square, vec![])
+    ));
+}
+
+#[test]
+fn source_member_types_record_their_nesting() {
+    let src = r#"
+class Outer<T> {
+    Outer() {}
+    static class Nested {}
+    class Inner {}
+    interface Callback {}
+}
+"#;
+
+    let (db, file) = setup_db(src);
+
+    let tree = db.hir_item_tree(file);
+    let (&ctor_ast_id, _) = tree
+        .constructors
+        .iter()
+        .find(|(_, ctor)| ctor.name == "Outer")
+        .expect("expected Outer constructor in item tree");
+    let ctor_id = nova_hir::ids::ConstructorId::new(file, ctor_ast_id);
+
+    let result = db.typeck_body(DefWithBodyId::Constructor(ctor_id));
+    let env = &*result.env;
+    let outer = env
+        .lookup_class("Outer")
+        .expect("expected Outer to be in env");
+    let outer_def = env.class(outer).expect("expected Outer class def");
+    assert_eq!(outer_def.enclosing, None);
+    assert!(!outer_def.is_static_nested);
+
+    for (name, is_static) in [
+        ("Outer$Nested", true),
+        ("Outer$Inner", false),
+        ("Outer$Callback", true),
+    ] {
+        let id = env
+            .lookup_class(name)
+            .unwrap_or_else(|| panic!("expected {name} to be in env"));
+        let def = env.class(id).expect("expected member class def");
+        assert_eq!(def.enclosing, Some(outer), "{name}");
+        assert_eq!(def.is_static_nested, is_static, "{name}");
+    }
+
+    let inner = env.lookup_class("Outer$Inner").unwrap();
+    let nested = env.lookup_class("Outer$Nested").unwrap();
+    assert_eq!(
+        env.class(inner).unwrap().all_type_params(env),
+        outer_def.type_params
+    );
+    assert!(env.class(nested).unwrap().all_type_params(env).is_empty());
+}
+
 #[test]
 fn ensure_workspace_class_preserves_constructor_defs() {
     let src = r#"
@@ -12190,3 +12346,78 @@ class C {
         "expected invalid-yield diagnostic; got {diags:?}"
     );
 }
+
+#[test]
+fn private_method_called_from_another_class_reports_private_access() {
+    let src = r#"
+class A {
+  private void m(String s) {}
+  void ok() { m("x"); new A().m("y"); }
+  static class Nested { void n(A a) { a.m("z"); } }
+}
+class B {
+  void f(A a) { a.m("x"); }
+}
+"#;
+
+    let (db, file) = setup_db(src);
+    let diags = db.type_diagnostics(file);
+    let unresolved = diags
+        .iter()
+        .filter(|d| d.code.as_ref() == "unresolved-method")
+        .collect::<Vec<_>>();
+    assert_eq!(unresolved.len(), 1, "{diags:?}");
+    assert_eq!(
+        unresolved[0].message,
+        "`m(String)` has private access in `A`"
+    );
+
+    let call = src.rfind("a.m(\"x\")").unwrap();
+    let span = unresolved[0].span.expect("span");
+    assert_eq!((span.start, span.end), (call, call + "a.m(\"x\")".len()));
+}
+
+#[test]
+fn source_type_params_record_their_declaring_class() {
+    let src = r#"
+class Outer<T> {
+    class Inner<U> {
+        <M> void m() {}
+    }
+    void run() {}
+}
+"#;
+
+    let (db, file) = setup_db(src);
+    let body = db.typeck_body(first_method_with_body(&db, file));
+    let env = &*body.env;
+
+    let outer = env
+        .lookup_class("Outer")
+        .expect("expected Outer to be defined");
+    let inner = env
+        .lookup_class("Outer$Inner")
+        .expect("expected Outer$Inner to be defined");
+    let inner_def = env
+        .class(inner)
+        .expect("expected a definition for Outer$Inner");
+
+    let [t] = env.class(outer).unwrap().type_params[..] else {
+        panic!("expected one type parameter on Outer");
+    };
+    assert_eq!(env.type_param(t).unwrap().declared_by, Some(outer));
+
+    let u = *inner_def.type_params.last().expect("expected U on Inner");
+    assert_eq!(env.type_param(u).unwrap().name, "U");
+    assert_eq!(env.type_param(u).unwrap().declared_by, Some(inner));
+
+    let m = inner_def
+        .methods
+        .iter()
+        .find(|method| method.name == "m")
+        .expect("expected method m on Inner");
+    let [tv] = m.type_params[..] else {
+        panic!("expected one type parameter on m");
+    };
+    assert_eq!(env.type_param(tv).unwrap().declared_by, None);
+}
@@ -740,6 +740,7 @@ where
                 label: item.label,
                 detail: item.detail,
                 replace_span: None,
+                ..Default::default()
             })
             .collect())
     }
@@ -575,6 +575,7 @@ fn ptr_len_roundtrip_and_basic_call_works() {
             label: "from-wasm".to_string(),
             detail: None,
             replace_span: None,
+            ..Default::default()
         }]
     );
 }
@@ -85,15 +85,18 @@ impl FrameworkAnalyzer for DaggerAnalyzer {
             .diagnostics
             .iter()
             .filter(|d| d.file == file_path)
-            .map(|d| Diagnostic {
-                severity: match d.severity {
+            .map(|d| {
+                let severity = match d.severity {
                     DiagnosticSeverity::Error => Severity::Error,
                     DiagnosticSeverity::Warning => Severity::Warning,
                     DiagnosticSeverity::Information | DiagnosticSeverity::Hint => Severity::Info,
-                },
-                code: dagger_code(d.source.as_deref()),
-                message: d.message.clone(),
-                span: core_range_to_span_with_index(file_text, &index, d.range),
+                };
+                Diagnostic::new(
+                    severity,
+                    dagger_code(d.source.as_deref()),
+                    d.message.clone(),
+                    core_range_to_span_with_index(file_text, &index, d.range),
+                )
             })
             .collect()
     }
@@ -587,8 +590,14 @@ mod tests {
         let mid_start = mid.saturating_sub(SAMPLE / 2);
         let mid_end = (mid_start + SAMPLE).min(len);
         let mutation_idx = 100;
-        assert!(mutation_idx >= SAMPLE, "expected index outside prefix sample");
-        assert!(mutation_idx < len - SAMPLE, "expected index outside suffix sample");
+        assert!(
+            mutation_idx >= SAMPLE,
+            "expected index outside prefix sample"
+        );
+        assert!(
+            mutation_idx < len - SAMPLE,
+            "expected index outside suffix sample"
+        );
         assert!(
             mutation_idx < mid_start || mutation_idx >= mid_end,
             "expected index outside middle sample"
@@ -788,6 +788,7 @@ fn mapping_property_completions(
             label: name.clone(),
             detail: None,
             replace_span: Some(replace_span),
+            ..Default::default()
         })
         .collect();
     items.sort_by(|a, b| a.label.cmp(&b.label));
@@ -1643,6 +1644,7 @@ fn mapping_property_completions_best_effort(
             label: name.clone(),
             detail: None,
             replace_span: Some(replace_span),
+            ..Default::default()
         })
         .collect();
     items.sort_by(|a, b| a.label.cmp(&b.label));
@@ -1855,6 +1857,7 @@ fn mapping_property_completions_fs(
             label: name.clone(),
             detail: None,
             replace_span: Some(replace_span),
+            ..Default::default()
         })
         .collect();
     items.sort_by(|a, b| a.label.cmp(&b.label));
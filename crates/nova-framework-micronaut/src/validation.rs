@@ -5,7 +5,7 @@ use crate::parse::{
 use crate::FileDiagnostic;
 use crate::JavaSource;
 use nova_syntax::{SyntaxKind, SyntaxNode};
-use nova_types::{Diagnostic, Severity};
+use nova_types::Diagnostic;
 
 pub const MICRONAUT_VALIDATION_PRIMITIVE_NONNULL: &str = "MICRONAUT_VALIDATION_PRIMITIVE_NONNULL";
 pub const MICRONAUT_VALIDATION_CONSTRAINT_MISMATCH: &str =
@@ -100,23 +100,21 @@ fn validate_constraints(
 
     for ann in annotations {
         match ann.simple_name.as_str() {
-            "NotNull" if is_primitive => out.push(Diagnostic {
-                severity: Severity::Warning,
-                code: MICRONAUT_VALIDATION_PRIMITIVE_NONNULL.into(),
-                message: format!(
+            "NotNull" if is_primitive => out.push(Diagnostic::warning(
+                MICRONAUT_VALIDATION_PRIMITIVE_NONNULL,
+                format!(
                     "Bean Validation annotation @NotNull has no effect on primitive type `{ty}`"
                 ),
-                span: Some(ann.span),
-            }),
-            "NotBlank" | "Email" if !is_string => out.push(Diagnostic {
-                severity: Severity::Warning,
-                code: MICRONAUT_VALIDATION_CONSTRAINT_MISMATCH.into(),
-                message: format!(
+                Some(ann.span),
+            )),
+            "NotBlank" | "Email" if !is_string => out.push(Diagnostic::warning(
+                MICRONAUT_VALIDATION_CONSTRAINT_MISMATCH,
+                format!(
                     "Bean Validation annotation @{} is typically only valid on String/CharSequence types (found `{ty}`)",
                     ann.simple_name
                 ),
-                span: Some(ann.span),
-            }),
+                Some(ann.span),
+            )),
             "Min" | "Max"
             | "Positive"
             | "PositiveOrZero"
@@ -126,15 +124,14 @@ fn validate_constraints(
             | "DecimalMax"
                 if !is_numeric =>
             {
-                out.push(Diagnostic {
-                    severity: Severity::Warning,
-                    code: MICRONAUT_VALIDATION_CONSTRAINT_MISMATCH.into(),
-                    message: format!(
+                out.push(Diagnostic::warning(
+                    MICRONAUT_VALIDATION_CONSTRAINT_MISMATCH,
+                    format!(
                         "Bean Validation annotation @{} is typically only valid on numeric types (found `{ty}`)",
                         ann.simple_name
                     ),
-                    span: Some(ann.span),
-                })
+                    Some(ann.span),
+                ))
             }
             _ => {}
         }
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 
-use nova_types::{Diagnostic, Severity, Span};
+use nova_types::{Diagnostic, Span};
 use tree_sitter::{Node, Parser, Tree};
 
 pub const CDI_UNSATISFIED_CODE: &str = "QUARKUS_CDI_UNSATISFIED_DEPENDENCY";
@@ -540,12 +540,11 @@ fn detect_circular_dependencies(index: &CdiIndex) -> Vec<SourceDiagnostic> {
             let bean = class_beans[idx];
             diagnostics.push(SourceDiagnostic {
                 source: bean.location.source,
-                diagnostic: Diagnostic {
-                    severity: Severity::Warning,
-                    code: CDI_CIRCULAR_CODE.into(),
-                    message: msg.clone(),
-                    span: Some(bean.location.span),
-                },
+                diagnostic: Diagnostic::warning(
+                    CDI_CIRCULAR_CODE,
+                    msg.clone(),
+                    Some(bean.location.span),
+                ),
             });
         }
     }
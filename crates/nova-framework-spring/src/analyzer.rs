@@ -584,6 +584,7 @@ impl FrameworkAnalyzer for SpringAnalyzer {
                             label: profile.clone(),
                             detail: None,
                             replace_span: None,
+                            ..Default::default()
                         }));
 
                         // Profiles discovered from `@Profile` annotations on beans.
@@ -599,6 +600,7 @@ impl FrameworkAnalyzer for SpringAnalyzer {
                                         label: profile.clone(),
                                         detail: None,
                                         replace_span: None,
+                                        ..Default::default()
                                     }),
                             );
                         }
@@ -616,6 +618,7 @@ impl FrameworkAnalyzer for SpringAnalyzer {
                                     label: profile.clone(),
                                     detail: None,
                                     replace_span: None,
+                                    ..Default::default()
                                 }),
                         );
                     }
@@ -14,11 +14,13 @@ pub fn qualifier_completions(model: &BeanModel) -> Vec<CompletionItem> {
                 label: b.name.clone(),
                 detail: Some(b.ty.clone()),
                 replace_span: None,
+                ..Default::default()
             })
             .chain(b.qualifiers.iter().map(|q| CompletionItem {
                 label: q.clone(),
                 detail: Some(b.ty.clone()),
                 replace_span: None,
+                ..Default::default()
             }))
         })
         .collect();
@@ -37,6 +39,7 @@ pub fn profile_completions() -> Vec<CompletionItem> {
             label: p.to_string(),
             detail: None,
             replace_span: None,
+            ..Default::default()
         })
         .collect()
 }
@@ -89,6 +92,7 @@ pub fn value_completions(keys: &BTreeSet<String>) -> Vec<CompletionItem> {
             label: k.clone(),
             detail: None,
             replace_span: None,
+            ..Default::default()
         })
         .collect()
 }
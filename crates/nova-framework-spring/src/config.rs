@@ -542,6 +542,7 @@ pub fn completions_for_value_placeholder(
                 label: meta.name.clone(),
                 detail: property_completion_detail(meta),
                 replace_span: None,
+                ..Default::default()
             });
         }
     }
@@ -559,6 +560,7 @@ pub fn completions_for_value_placeholder(
                 label: key,
                 detail: None,
                 replace_span: None,
+                ..Default::default()
             });
         }
     }
@@ -638,6 +640,7 @@ pub fn completions_for_properties_file(
                         label: value,
                         detail: None,
                         replace_span: None,
+                        ..Default::default()
                     });
                 }
             }
@@ -653,6 +656,7 @@ pub fn completions_for_properties_file(
                 label: meta.name.clone(),
                 detail: property_completion_detail(meta),
                 replace_span: None,
+                ..Default::default()
             });
         }
     }
@@ -670,6 +674,7 @@ pub fn completions_for_properties_file(
                 label: key,
                 detail: None,
                 replace_span: None,
+                ..Default::default()
             });
         }
     }
@@ -706,6 +711,7 @@ pub fn completions_for_yaml_file(
                     label: value,
                     detail: None,
                     replace_span: None,
+                    ..Default::default()
                 })
                 .collect();
         }
@@ -736,6 +742,7 @@ pub fn completions_for_yaml_file(
                     label: segment,
                     detail,
                     replace_span: None,
+                    ..Default::default()
                 });
             }
         }
@@ -755,6 +762,7 @@ pub fn completions_for_yaml_file(
                     label: segment,
                     detail: None,
                     replace_span: None,
+                    ..Default::default()
                 });
             }
         }
@@ -1,5 +1,7 @@
 use nova_db::InMemoryFileStore;
-use nova_types::{CompletionItem, Diagnostic};
+use nova_types::{CompletionItem, CompletionItemKind, Diagnostic, InsertTextFormat, Span};
+
+use crate::text::TextIndex;
 
 /// Thin compatibility wrapper used by `nova-workspace`.
 ///
@@ -23,14 +25,87 @@ pub fn completions(java_source: &str, offset: usize) -> Vec<CompletionItem> {
     let file_id = db.file_id_for_path("/virtual/Main.java");
     db.set_file_text(file_id, java_source.to_string());
 
-    let text_index = crate::text::TextIndex::new(java_source);
+    let text_index = TextIndex::new(java_source);
     let position = text_index.offset_to_position(offset);
-    crate::code_intelligence::completions(&db, file_id, position)
+    completion_items_from_lsp(
+        java_source,
+        crate::code_intelligence::completions(&db, file_id, position),
+    )
+}
+
+/// Convert LSP completion items for the file `text` into [`CompletionItem`]s, carrying over
+/// their kind, insert text, ranking texts, and additional edits (e.g. auto-imports).
+pub fn completion_items_from_lsp(
+    text: &str,
+    items: impl IntoIterator<Item = lsp_types::CompletionItem>,
+) -> Vec<CompletionItem> {
+    let text_index = TextIndex::new(text);
+    let span_of = |range: lsp_types::Range| {
+        let start = text_index.position_to_offset(range.start)?;
+        let end = text_index.position_to_offset(range.end)?;
+        Some(Span::new(start, end))
+    };
+
+    items
         .into_iter()
-        .map(|item| CompletionItem {
-            label: item.label,
-            detail: item.detail,
-            replace_span: None,
+        .map(|item| {
+            let (replace_span, edit_text) = match item.text_edit {
+                Some(lsp_types::CompletionTextEdit::Edit(edit)) => {
+                    (span_of(edit.range), Some(edit.new_text))
+                }
+                Some(lsp_types::CompletionTextEdit::InsertAndReplace(edit)) => {
+                    (span_of(edit.replace), Some(edit.new_text))
+                }
+                None => (None, None),
+            };
+            let insert_text = edit_text
+                .or(item.insert_text)
+                .filter(|text| *text != item.label);
+            let insert_text_format = match item.insert_text_format {
+                Some(lsp_types::InsertTextFormat::SNIPPET) => InsertTextFormat::Snippet,
+                _ => InsertTextFormat::PlainText,
+            };
+            let deprecated = item.deprecated == Some(true)
+                || item
+                    .tags
+                    .is_some_and(|tags| tags.contains(&lsp_types::CompletionItemTag::DEPRECATED));
+
+            CompletionItem {
+                kind: item.kind.and_then(completion_kind_from_lsp),
+                detail: item.detail,
+                replace_span,
+                insert_text,
+                insert_text_format,
+                sort_text: item.sort_text,
+                filter_text: item.filter_text,
+                additional_edits: item
+                    .additional_text_edits
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|edit| Some((span_of(edit.range)?, edit.new_text)))
+                    .collect(),
+                deprecated,
+                label: item.label,
+            }
         })
         .collect()
 }
+
+fn completion_kind_from_lsp(kind: lsp_types::CompletionItemKind) -> Option<CompletionItemKind> {
+    use lsp_types::CompletionItemKind as Lsp;
+
+    Some(match kind {
+        Lsp::METHOD | Lsp::FUNCTION => CompletionItemKind::Method,
+        Lsp::FIELD | Lsp::PROPERTY | Lsp::CONSTANT => CompletionItemKind::Field,
+        Lsp::CLASS | Lsp::STRUCT => CompletionItemKind::Class,
+        Lsp::INTERFACE => CompletionItemKind::Interface,
+        Lsp::ENUM => CompletionItemKind::Enum,
+        Lsp::ENUM_MEMBER => CompletionItemKind::EnumMember,
+        Lsp::KEYWORD => CompletionItemKind::Keyword,
+        Lsp::VARIABLE => CompletionItemKind::Variable,
+        Lsp::CONSTRUCTOR => CompletionItemKind::Constructor,
+        Lsp::MODULE => CompletionItemKind::Package,
+        Lsp::SNIPPET => CompletionItemKind::Snippet,
+        _ => return None,
+    })
+}
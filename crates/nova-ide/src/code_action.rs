@@ -12,7 +12,6 @@ use nova_types::Span;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -172,12 +171,11 @@ pub fn diagnostic_quick_fixes(
             .filter_map(|diagnostic| {
                 let code = diagnostic_code(diagnostic)?;
                 let span = lsp_range_to_span(source, &diagnostic.range)?;
-                Some(nova_types::Diagnostic {
-                    severity: nova_types::Severity::Error,
-                    code: Cow::Owned(code.to_string()),
-                    message: diagnostic.message.clone(),
-                    span: Some(span),
-                })
+                Some(nova_types::Diagnostic::error(
+                    code.to_string(),
+                    diagnostic.message.clone(),
+                    Some(span),
+                ))
             })
             .collect();
 
@@ -1357,6 +1357,7 @@ fn severity_rank(severity: Severity) -> u8 {
         Severity::Error => 0,
         Severity::Warning => 1,
         Severity::Info => 2,
+        Severity::Hint => 3,
     }
 }
 
@@ -2304,6 +2305,7 @@ pub fn file_diagnostics_lsp(db: &dyn Database, file: FileId) -> Vec<lsp_types::D
                 Severity::Error => DiagnosticSeverity::ERROR,
                 Severity::Warning => DiagnosticSeverity::WARNING,
                 Severity::Info => DiagnosticSeverity::INFORMATION,
+                Severity::Hint => DiagnosticSeverity::HINT,
             }),
             code: Some(NumberOrString::String(d.code.into_owned())),
             source: Some("nova".into()),
@@ -9783,17 +9785,10 @@ fn parse_source_type_in_context(
     }
 
     let mut ty = match s {
-        "void" => Type::Void,
         "null" => Type::Null,
-        "boolean" => Type::Primitive(PrimitiveType::Boolean),
-        "byte" => Type::Primitive(PrimitiveType::Byte),
-        "short" => Type::Primitive(PrimitiveType::Short),
-        "char" => Type::Primitive(PrimitiveType::Char),
-        "int" => Type::Primitive(PrimitiveType::Int),
-        "long" => Type::Primitive(PrimitiveType::Long),
-        "float" => Type::Primitive(PrimitiveType::Float),
-        "double" => Type::Primitive(PrimitiveType::Double),
-        other => ctx.resolve_reference_type(types, other),
+        other => {
+            Type::from_keyword(other).unwrap_or_else(|| ctx.resolve_reference_type(types, other))
+        }
     };
 
     for _ in 0..array_dims {
@@ -9841,19 +9836,25 @@ fn ensure_minimal_completion_jdk(types: &mut TypeStore) {
                 name: "filter".to_string(),
                 type_params: vec![],
                 params: vec![predicate_ty],
+                param_names: vec![],
                 return_type: stream_ty.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "map".to_string(),
                 type_params: vec![],
                 params: vec![function_ty],
+                param_names: vec![],
                 return_type: stream_ty.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "collect".to_string(),
@@ -9861,10 +9862,13 @@ fn ensure_minimal_completion_jdk(types: &mut TypeStore) {
                 // Keep the parameter type loose: the full `Collector` model isn't present in
                 // Nova's minimal JDK.
                 params: vec![Type::Named("Collector".to_string())],
+                param_names: vec![],
                 return_type: object_ty,
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             },
         ];
 
@@ -9891,64 +9895,85 @@ fn ensure_minimal_completion_jdk(types: &mut TypeStore) {
                 name: "getName".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: string_ty.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "getSimpleName".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: string_ty.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "getPackageName".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: string_ty,
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "getSuperclass".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: class_ty,
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "isInterface".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Primitive(PrimitiveType::Boolean),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "isEnum".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Primitive(PrimitiveType::Boolean),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "isPrimitive".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Primitive(PrimitiveType::Boolean),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ];
 
@@ -12274,6 +12299,10 @@ fn infer_call_return_type(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&types);
@@ -12838,6 +12867,10 @@ fn infer_call_return_type_in_store(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&*types);
@@ -13887,6 +13920,10 @@ fn populate_type_store_with_workspace_decls(types: &mut TypeStore, db: &dyn Data
             fields: Vec::new(),
             constructors: Vec::new(),
             methods: Vec::new(),
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
     }
 
@@ -13915,6 +13952,10 @@ fn populate_type_store_with_workspace_decls(types: &mut TypeStore, db: &dyn Data
             fields: Vec::new(),
             constructors: Vec::new(),
             methods: Vec::new(),
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
     }
 }
@@ -15875,6 +15916,10 @@ fn expected_type_for_call_argument(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&*types);
@@ -16252,6 +16297,10 @@ fn type_store_for_completion(analysis: &Analysis, file_ctx: &CompletionResolveCt
             fields: Vec::new(),
             constructors: Vec::new(),
             methods: Vec::new(),
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
     }
 
@@ -19488,6 +19537,10 @@ fn semantic_call_signatures(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&*types);
@@ -19533,6 +19586,10 @@ fn semantic_call_for_inlay(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&*types);
@@ -20095,6 +20152,10 @@ fn expected_argument_type_for_completion(
         args,
         expected_return: None,
         explicit_type_args: Vec::new(),
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&*types);
@@ -20222,6 +20283,10 @@ fn ensure_local_class_id(types: &mut TypeStore, analysis: &Analysis, class: &Cla
             fields: Vec::new(),
             constructors: Vec::new(),
             methods: Vec::new(),
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         })
     });
 
@@ -20255,10 +20320,13 @@ fn ensure_local_class_id(types: &mut TypeStore, analysis: &Analysis, class: &Cla
                 .iter()
                 .map(|p| parse_source_type_in_context(types, &file_ctx, &p.ty))
                 .collect(),
+            param_names: m.params.iter().map(|p| p.name.clone()).collect(),
             return_type: parse_source_type_in_context(types, &file_ctx, &m.ret_ty),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         })
         .collect::<Vec<_>>();
 
@@ -20313,10 +20381,13 @@ fn ensure_type_methods_loaded(types: &mut TypeStore, receiver: &Type) {
                     name: m.name.clone(),
                     type_params: Vec::new(),
                     params,
+                    param_names: Vec::new(),
                     return_type,
                     is_static: m.access_flags & ACC_STATIC != 0,
                     is_varargs: m.access_flags & ACC_VARARGS != 0,
                     is_abstract: m.access_flags & ACC_ABSTRACT != 0,
+                    is_private: false,
+                    deprecation: None,
                 });
             }
 
@@ -20367,6 +20438,7 @@ fn ensure_type_fields_loaded(types: &mut TypeStore, receiver: &Type) {
                     ty,
                     is_static: f.access_flags & ACC_STATIC != 0,
                     is_final: f.access_flags & ACC_FINAL != 0,
+                    deprecation: None,
                 });
             }
 
@@ -20439,6 +20511,10 @@ fn ensure_class_id(types: &mut TypeStore, name: &str) -> Option<ClassId> {
                     fields: Vec::new(),
                     constructors: Vec::new(),
                     methods: Vec::new(),
+                    enclosing: None,
+                    is_static_nested: false,
+                    is_abstract: false,
+                    deprecation: None,
                 });
 
                 if let Some(class_def) = types.class_mut(id) {
@@ -20450,10 +20526,13 @@ fn ensure_class_id(types: &mut TypeStore, name: &str) -> Option<ClassId> {
                             params: vec![Type::Named(
                                 "java.util.function.Predicate".to_string(),
                             )],
+                            param_names: Vec::new(),
                             return_type: stream_ty.clone(),
                             is_static: false,
                             is_varargs: false,
                             is_abstract: false,
+                            is_private: false,
+                            deprecation: None,
                         },
                         MethodDef {
                             name: "map".to_string(),
@@ -20461,10 +20540,13 @@ fn ensure_class_id(types: &mut TypeStore, name: &str) -> Option<ClassId> {
                             params: vec![Type::Named(
                                 "java.util.function.Function".to_string(),
                             )],
+                            param_names: Vec::new(),
                             return_type: stream_ty.clone(),
                             is_static: false,
                             is_varargs: false,
                             is_abstract: false,
+                            is_private: false,
+                            deprecation: None,
                         },
                         MethodDef {
                             name: "collect".to_string(),
@@ -20472,10 +20554,13 @@ fn ensure_class_id(types: &mut TypeStore, name: &str) -> Option<ClassId> {
                             params: vec![Type::Named(
                                 "java.util.stream.Collector".to_string(),
                             )],
+                            param_names: Vec::new(),
                             return_type: Type::Unknown,
                             is_static: false,
                             is_varargs: false,
                             is_abstract: false,
+                            is_private: false,
+                            deprecation: None,
                         },
                     ]);
                 }
@@ -20516,6 +20601,10 @@ fn ensure_class_id(types: &mut TypeStore, name: &str) -> Option<ClassId> {
         fields: Vec::new(),
         constructors: Vec::new(),
         methods: Vec::new(),
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     Some(id)
@@ -20542,55 +20631,73 @@ fn add_builtin_string_methods(types: &mut TypeStore, string: ClassId) {
             name: "length".to_string(),
             type_params: Vec::new(),
             params: Vec::new(),
+            param_names: Vec::new(),
             return_type: int.clone(),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
         MethodDef {
             name: "substring".to_string(),
             type_params: Vec::new(),
             params: vec![int.clone()],
+            param_names: Vec::new(),
             return_type: string_ty.clone(),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
         MethodDef {
             name: "substring".to_string(),
             type_params: Vec::new(),
             params: vec![int.clone(), int.clone()],
+            param_names: Vec::new(),
             return_type: string_ty.clone(),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
         MethodDef {
             name: "charAt".to_string(),
             type_params: Vec::new(),
             params: vec![int.clone()],
+            param_names: Vec::new(),
             return_type: Type::Primitive(PrimitiveType::Char),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
         MethodDef {
             name: "trim".to_string(),
             type_params: Vec::new(),
             params: Vec::new(),
+            param_names: Vec::new(),
             return_type: string_ty.clone(),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
         MethodDef {
             name: "isEmpty".to_string(),
             type_params: Vec::new(),
             params: Vec::new(),
+            param_names: Vec::new(),
             return_type: Type::Primitive(PrimitiveType::Boolean),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         },
     ]);
 }
@@ -20649,27 +20756,19 @@ fn parse_source_type_with_imports(
         s = stripped.trim_end();
     }
 
-    let mut ty = match s {
-        "void" => Type::Void,
-        "boolean" => Type::Primitive(PrimitiveType::Boolean),
-        "byte" => Type::Primitive(PrimitiveType::Byte),
-        "short" => Type::Primitive(PrimitiveType::Short),
-        "char" => Type::Primitive(PrimitiveType::Char),
-        "int" => Type::Primitive(PrimitiveType::Int),
-        "long" => Type::Primitive(PrimitiveType::Long),
-        "float" => Type::Primitive(PrimitiveType::Float),
-        "double" => Type::Primitive(PrimitiveType::Double),
-        other => {
-            if let Some(id) = ensure_class_id(types, other) {
+    let mut ty = match Type::from_keyword(s) {
+        Some(ty) => ty,
+        None => {
+            if let Some(id) = ensure_class_id(types, s) {
                 Type::class(id, vec![])
-            } else if let Some(resolved) = resolve_imported_type_name(types, import_ctx, other) {
+            } else if let Some(resolved) = resolve_imported_type_name(types, import_ctx, s) {
                 if let Some(id) = ensure_class_id(types, &resolved) {
                     Type::class(id, vec![])
                 } else {
                     Type::Named(resolved)
                 }
             } else {
-                Type::Named(other.to_string())
+                Type::Named(s.to_string())
             }
         }
     };
@@ -20699,21 +20798,13 @@ fn parse_source_type(types: &mut TypeStore, source: &str) -> Type {
         s = stripped.trim_end();
     }
 
-    let mut ty = match s {
-        "void" => Type::Void,
-        "boolean" => Type::Primitive(PrimitiveType::Boolean),
-        "byte" => Type::Primitive(PrimitiveType::Byte),
-        "short" => Type::Primitive(PrimitiveType::Short),
-        "char" => Type::Primitive(PrimitiveType::Char),
-        "int" => Type::Primitive(PrimitiveType::Int),
-        "long" => Type::Primitive(PrimitiveType::Long),
-        "float" => Type::Primitive(PrimitiveType::Float),
-        "double" => Type::Primitive(PrimitiveType::Double),
-        other => {
-            if let Some(id) = ensure_class_id(types, other) {
+    let mut ty = match Type::from_keyword(s) {
+        Some(ty) => ty,
+        None => {
+            if let Some(id) = ensure_class_id(types, s) {
                 Type::class(id, vec![])
             } else {
-                Type::Named(other.to_string())
+                Type::Named(s.to_string())
             }
         }
     };
@@ -22351,6 +22442,10 @@ fn define_local_interfaces(types: &mut TypeStore, tokens: &[Token]) {
                     fields: Vec::new(),
                     constructors: Vec::new(),
                     methods,
+                    enclosing: None,
+                    is_static_nested: false,
+                    is_abstract: false,
+                    deprecation: None,
                 },
             );
 
@@ -22437,10 +22532,13 @@ fn parse_interface_methods(tokens: &[Token]) -> Vec<MethodDef> {
             name: name_tok.text.clone(),
             type_params: Vec::new(),
             params: vec![Type::Unknown; params.len()],
+            param_names: params.into_iter().map(|p| p.name).collect(),
             return_type: Type::Unknown,
             is_static,
             is_varargs: false,
             is_abstract,
+            is_private: false,
+            deprecation: None,
         });
 
         i = end_idx + 1;
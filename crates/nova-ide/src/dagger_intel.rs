@@ -82,16 +82,19 @@ pub(crate) fn diagnostics_for_file_with_cancel<DB: ?Sized + Database>(
         .diagnostics
         .iter()
         .filter(|d| d.file == file_path)
-        .map(|d| Diagnostic {
-            severity: match d.severity {
+        .map(|d| {
+            let severity = match d.severity {
                 nova_core::BuildDiagnosticSeverity::Error => Severity::Error,
                 nova_core::BuildDiagnosticSeverity::Warning => Severity::Warning,
                 nova_core::BuildDiagnosticSeverity::Information
                 | nova_core::BuildDiagnosticSeverity::Hint => Severity::Info,
-            },
-            code: dagger_code(d.source.as_deref()).into(),
-            message: d.message.clone(),
-            span: core_range_to_span(text, d.range),
+            };
+            Diagnostic::new(
+                severity,
+                dagger_code(d.source.as_deref()),
+                d.message.clone(),
+                core_range_to_span(text, d.range),
+            )
         })
         .collect()
 }
@@ -394,13 +397,17 @@ mod tests {
         text.push_str(&"a".repeat(1024));
         text.push_str(suffix);
 
-        let mut db = MutableDb { file_id, path, text };
+        let mut db = MutableDb {
+            file_id,
+            path,
+            text,
+        };
         let cancel = CancellationToken::new();
 
-        let proj1 =
-            project_analysis_with_cancel(&db, db.path.as_path(), &cancel).expect("expected project");
-        let proj2 =
-            project_analysis_with_cancel(&db, db.path.as_path(), &cancel).expect("expected cache hit");
+        let proj1 = project_analysis_with_cancel(&db, db.path.as_path(), &cancel)
+            .expect("expected project");
+        let proj2 = project_analysis_with_cancel(&db, db.path.as_path(), &cancel)
+            .expect("expected cache hit");
         assert!(
             Arc::ptr_eq(&proj1, &proj2),
             "expected dagger project to be reused from cache"
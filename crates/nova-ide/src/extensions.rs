@@ -14,7 +14,6 @@ use nova_refactor::{
     TextDatabase,
 };
 use nova_scheduler::CancellationToken;
-use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 
@@ -936,6 +935,7 @@ impl<DB: ?Sized + Send + Sync + 'static> IdeExtensions<DB> {
                 nova_ext::Severity::Error => 0,
                 nova_ext::Severity::Warning => 1,
                 nova_ext::Severity::Info => 2,
+                nova_ext::Severity::Hint => 3,
             }
         }
 
@@ -1052,6 +1052,7 @@ where
                 nova_ext::Severity::Error => 0,
                 nova_ext::Severity::Warning => 1,
                 nova_ext::Severity::Info => 2,
+                nova_ext::Severity::Hint => 3,
             }
         }
 
@@ -1114,24 +1115,7 @@ where
         let extension_items = self
             .completions(cancel, file, offset)
             .into_iter()
-            .map(|item| {
-                let label = item.label;
-                let mut out = lsp_types::CompletionItem {
-                    label: label.clone(),
-                    detail: item.detail,
-                    ..lsp_types::CompletionItem::default()
-                };
-
-                if let Some(span) = item.replace_span {
-                    out.text_edit =
-                        Some(lsp_types::CompletionTextEdit::Edit(lsp_types::TextEdit {
-                            range: text_index.span_to_lsp_range(span),
-                            new_text: label,
-                        }));
-                }
-
-                out
-            });
+            .map(|item| completion_item_to_lsp(&text_index, item));
 
         completions.extend(extension_items);
         completions
@@ -1348,12 +1332,12 @@ where
                     // Be forward-compatible with unknown severities.
                     Some(_) => nova_ext::Severity::Info,
                 };
-                diagnostics.push(Diagnostic {
+                diagnostics.push(Diagnostic::new(
                     severity,
-                    code: Cow::Owned(code.clone()),
-                    message: diagnostic.message.clone(),
-                    span: Some(Span::new(start, end)),
-                });
+                    code.clone(),
+                    diagnostic.message.clone(),
+                    Some(Span::new(start, end)),
+                ));
             }
             actions.extend(crate::quick_fixes::create_symbol_quick_fixes(
                 self.db.as_ref().as_dyn_nova_db(),
@@ -1476,6 +1460,66 @@ where
     }
 }
 
+/// Convert an extension-provided completion item into its LSP form.
+fn completion_item_to_lsp(
+    text_index: &TextIndex<'_>,
+    item: nova_types::CompletionItem,
+) -> lsp_types::CompletionItem {
+    use lsp_types::CompletionItemKind as Lsp;
+    use nova_types::CompletionItemKind as Kind;
+
+    let kind = item.kind.map(|kind| match kind {
+        Kind::Method => Lsp::METHOD,
+        Kind::Field => Lsp::FIELD,
+        Kind::Class => Lsp::CLASS,
+        Kind::Interface => Lsp::INTERFACE,
+        Kind::Enum => Lsp::ENUM,
+        Kind::EnumMember => Lsp::ENUM_MEMBER,
+        Kind::Keyword => Lsp::KEYWORD,
+        Kind::Variable => Lsp::VARIABLE,
+        Kind::Constructor => Lsp::CONSTRUCTOR,
+        Kind::Package => Lsp::MODULE,
+        Kind::Snippet => Lsp::SNIPPET,
+    });
+    let insert_text_format = match item.insert_text_format {
+        nova_types::InsertTextFormat::PlainText => None,
+        nova_types::InsertTextFormat::Snippet => Some(lsp_types::InsertTextFormat::SNIPPET),
+    };
+    let additional_text_edits = (!item.additional_edits.is_empty()).then(|| {
+        item.additional_edits
+            .into_iter()
+            .map(|(span, new_text)| lsp_types::TextEdit {
+                range: text_index.span_to_lsp_range(span),
+                new_text,
+            })
+            .collect()
+    });
+
+    let mut out = lsp_types::CompletionItem {
+        label: item.label,
+        kind,
+        detail: item.detail,
+        insert_text_format,
+        sort_text: item.sort_text,
+        filter_text: item.filter_text,
+        additional_text_edits,
+        tags: item
+            .deprecated
+            .then(|| vec![lsp_types::CompletionItemTag::DEPRECATED]),
+        ..lsp_types::CompletionItem::default()
+    };
+    match item.replace_span {
+        Some(span) => {
+            out.text_edit = Some(lsp_types::CompletionTextEdit::Edit(lsp_types::TextEdit {
+                range: text_index.span_to_lsp_range(span),
+                new_text: item.insert_text.unwrap_or_else(|| out.label.clone()),
+            }));
+        }
+        None => out.insert_text = item.insert_text,
+    }
+    out
+}
+
 fn dedupe_code_actions_by_kind_and_title(actions: &mut Vec<lsp_types::CodeActionOrCommand>) {
     // Some quick-fix sources overlap (e.g. multiple passes offering the same "Create field"
     // action). Dedupe by (kind, title) to avoid noisy duplicate entries in the UI.
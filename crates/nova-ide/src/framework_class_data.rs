@@ -7,7 +7,7 @@
 use nova_hir::framework::{Annotation, ClassData, ConstructorData, FieldData, MethodData};
 use nova_syntax::ast::{self as syntax_ast, AstNode};
 use nova_syntax::SyntaxKind;
-use nova_types::{Parameter, PrimitiveType, Span, Type};
+use nova_types::{Parameter, Span, Type};
 
 /// Extract all `ClassData` instances found in `source`.
 ///
@@ -365,18 +365,7 @@ fn parse_type(raw: &str) -> Type {
 
     let base = strip_generic_args(&raw);
 
-    let mut ty = match base.as_str() {
-        "void" => Type::Void,
-        "boolean" => Type::Primitive(PrimitiveType::Boolean),
-        "byte" => Type::Primitive(PrimitiveType::Byte),
-        "short" => Type::Primitive(PrimitiveType::Short),
-        "char" => Type::Primitive(PrimitiveType::Char),
-        "int" => Type::Primitive(PrimitiveType::Int),
-        "long" => Type::Primitive(PrimitiveType::Long),
-        "float" => Type::Primitive(PrimitiveType::Float),
-        "double" => Type::Primitive(PrimitiveType::Double),
-        other => Type::Named(other.to_string()),
-    };
+    let mut ty = Type::from_keyword(&base).unwrap_or_else(|| Type::Named(base.clone()));
 
     for _ in 0..dims {
         ty = Type::Array(Box::new(ty));
@@ -6,8 +6,7 @@ use nova_hir::ast_id::AstIdMap;
 use nova_hir::item_tree::{FieldKind, Item, ItemTree, Member, Modifiers};
 use nova_hir::lowering::lower_item_tree;
 use nova_types::{
-    ClassDef, ClassKind, ConstructorDef, FieldDef, MethodDef, PrimitiveType, Type, TypeEnv,
-    TypeStore,
+    ClassDef, ClassKind, ConstructorDef, FieldDef, MethodDef, Type, TypeEnv, TypeStore,
 };
 
 /// Incrementally extracts type signatures from Java source files and registers
@@ -281,6 +280,10 @@ fn collect_class_defs(
     };
 
     let binary_name = binary_name(ctx.package.as_deref(), outer, name);
+    let is_abstract = matches!(
+        *item,
+        Item::Class(id) if tree.class(id).modifiers.raw & Modifiers::ABSTRACT != 0
+    );
 
     let mut fields = Vec::new();
     let mut constructors = Vec::new();
@@ -314,6 +317,7 @@ fn collect_class_defs(
                     ty: parse_type_ref(ctx, store, &data.ty),
                     is_static,
                     is_final,
+                    deprecation: None,
                 });
             }
             Member::Method(id) => {
@@ -324,10 +328,12 @@ fn collect_class_defs(
                 let is_abstract = data.modifiers.raw & Modifiers::ABSTRACT != 0
                     || (data.body.is_none() && data.modifiers.raw & Modifiers::NATIVE == 0);
                 let mut params = Vec::with_capacity(data.params.len());
+                let mut param_names = Vec::with_capacity(data.params.len());
                 let mut is_varargs = false;
                 for param in &data.params {
                     let (ty, varargs) = parse_param_type_ref(ctx, store, &param.ty);
                     params.push(ty);
+                    param_names.push(param.name.clone());
                     is_varargs |= varargs;
                 }
 
@@ -335,10 +341,13 @@ fn collect_class_defs(
                     name: data.name.clone(),
                     type_params: vec![],
                     params,
+                    param_names,
                     return_type: parse_type_ref(ctx, store, &data.return_ty),
                     is_static,
                     is_varargs,
                     is_abstract,
+                    is_private: data.modifiers.raw & Modifiers::PRIVATE != 0,
+                    deprecation: None,
                 });
             }
             Member::Constructor(id) => {
@@ -356,6 +365,7 @@ fn collect_class_defs(
                     params,
                     is_varargs,
                     is_accessible,
+                    deprecation: None,
                 });
             }
             Member::Initializer(_) => {}
@@ -391,6 +401,10 @@ fn collect_class_defs(
         fields,
         constructors,
         methods,
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract,
+        deprecation: None,
     });
 }
 
@@ -946,17 +960,8 @@ fn parse_param_type_ref(ctx: &ResolveCtx, store: &TypeStore, text: &str) -> (Typ
         .map_or(base, |(head, _)| head.trim_end());
 
     let mut ty = match base {
-        "void" => Type::Void,
-        "boolean" => Type::Primitive(PrimitiveType::Boolean),
-        "byte" => Type::Primitive(PrimitiveType::Byte),
-        "short" => Type::Primitive(PrimitiveType::Short),
-        "char" => Type::Primitive(PrimitiveType::Char),
-        "int" => Type::Primitive(PrimitiveType::Int),
-        "long" => Type::Primitive(PrimitiveType::Long),
-        "float" => Type::Primitive(PrimitiveType::Float),
-        "double" => Type::Primitive(PrimitiveType::Double),
         "" => Type::Unknown,
-        other => ctx.resolve_type_name(store, other),
+        other => Type::from_keyword(other).unwrap_or_else(|| ctx.resolve_type_name(store, other)),
     };
 
     for _ in 0..dims {
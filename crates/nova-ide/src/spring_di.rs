@@ -259,6 +259,7 @@ pub(crate) fn profile_completion_items_with_cancel<DB: ?Sized + Database>(
                 label: profile.clone(),
                 detail: None,
                 replace_span: None,
+                ..Default::default()
             }),
     );
     items.sort_by(|a, b| a.label.cmp(&b.label));
@@ -793,6 +794,7 @@ fn discovered_profile_completions<DB: ?Sized + Database>(
             label: profile,
             detail: None,
             replace_span: None,
+            ..Default::default()
         })
         .collect()
 }
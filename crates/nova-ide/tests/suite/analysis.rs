@@ -1,4 +1,5 @@
 use nova_ide::analysis;
+use nova_types::{CompletionItemKind, InsertTextFormat, Span};
 
 #[test]
 fn diagnostics_are_provided_by_code_intelligence_layer() {
@@ -31,3 +32,49 @@ fn completions_delegate_to_code_intelligence_layer() {
         "expected completion list to contain String.length; got {labels:?}"
     );
 }
+
+#[test]
+fn method_completions_carry_kind_and_call_snippets() {
+    let src = r#"class A { void m() { "x".subs } }"#;
+    let offset = src.find("subs").expect("expected member prefix") + "subs".len();
+
+    let items = analysis::completions(src, offset);
+    let substring = items
+        .iter()
+        .find(|item| {
+            item.label == "substring" && item.insert_text_format == InsertTextFormat::Snippet
+        })
+        .unwrap_or_else(|| panic!("expected substring(int, int) completion; got {items:#?}"));
+    assert_eq!(substring.kind, Some(CompletionItemKind::Method));
+    assert_eq!(
+        substring.insert_text.as_deref(),
+        Some("substring(${1:arg0}, ${2:arg1})$0")
+    );
+
+    let src = r#"class A { void m() { "x".len } }"#;
+    let offset = src.find("len").expect("expected member prefix") + "len".len();
+    let items = analysis::completions(src, offset);
+    let length = items
+        .iter()
+        .find(|item| item.label == "length")
+        .unwrap_or_else(|| panic!("expected length() completion; got {items:#?}"));
+    assert_eq!(length.insert_text.as_deref(), Some("length()"));
+    assert_eq!(length.insert_text_format, InsertTextFormat::PlainText);
+}
+
+#[test]
+fn auto_import_completions_carry_the_import_edit() {
+    let src = "class A { void m() { ArrayLi } }";
+    let offset = src.find("ArrayLi").expect("expected type prefix") + "ArrayLi".len();
+
+    let items = analysis::completions(src, offset);
+    let array_list = items
+        .iter()
+        .find(|item| item.label == "ArrayList")
+        .unwrap_or_else(|| panic!("expected ArrayList completion; got {items:#?}"));
+    assert_eq!(array_list.kind, Some(CompletionItemKind::Class));
+    assert_eq!(
+        array_list.additional_edits,
+        vec![(Span::new(0, 0), "import java.util.ArrayList;\n".to_string())]
+    );
+}
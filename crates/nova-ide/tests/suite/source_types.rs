@@ -40,6 +40,10 @@ public class B {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&store);
@@ -77,6 +81,10 @@ class A {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&store);
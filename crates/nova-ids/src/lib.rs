@@ -1,7 +1,9 @@
 //! Canonical strongly-typed IDs used across Nova.
 //!
 //! This crate is intentionally dependency-free so it can sit at the bottom of the
-//! dependency graph (shared by Salsa, semantic layers, and framework analyzers).
+//! dependency graph (shared by Salsa, semantic layers, and framework analyzers). The optional
+//! `serde` feature serializes every id as its raw `u32`; the optional `schemars` feature describes
+//! it as such in JSON schemas.
 
 macro_rules! define_id {
     ($(#[$meta:meta])* $vis:vis struct $name:ident; $($rest:tt)*) => {
@@ -33,6 +35,35 @@ macro_rules! define_id {
             }
         }
 
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u32(self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <u32 as serde::Deserialize>::deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $name {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                <u32 as schemars::JsonSchema>::json_schema(gen)
+            }
+        }
+
         define_id!($($rest)*);
     };
     () => {};
@@ -43,6 +43,8 @@ pub const BUILTIN_JDK_BINARY_NAMES: &[&str] = &[
     // on-disk JDK index.
     "java.util.Map",
     "java.util.Map$Entry",
+    "java.util.Objects",
+    "java.util.Optional",
     // java.util.function
     "java.util.function.Consumer",
     "java.util.function.Function",
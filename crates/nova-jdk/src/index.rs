@@ -9,6 +9,7 @@ use std::time::Duration;
 use nova_classfile::{parse_module_info_class, ClassFile};
 use nova_modules::{ModuleGraph, ModuleInfo, ModuleName, JAVA_BASE};
 use nova_process::{run_command, RunOptions};
+use nova_types::InnerClassStub;
 use once_cell::sync::OnceCell;
 use thiserror::Error;
 
@@ -1266,7 +1267,20 @@ pub enum JdkIndexError {
 }
 
 pub(crate) fn classfile_to_stub(class_file: ClassFile) -> JdkClassStub {
+    // Local and anonymous classes have no `outer_class`; only member classes record their
+    // declaring class.
+    let inner_class = class_file
+        .inner_classes
+        .iter()
+        .find(|inner| inner.inner_class == class_file.this_class)
+        .and_then(|inner| {
+            Some(InnerClassStub {
+                outer_binary_name: internal_to_binary(inner.outer_class.as_deref()?),
+                access_flags: inner.access_flags,
+            })
+        });
     JdkClassStub {
+        deprecation: class_file.deprecation().map(crate::stub::deprecation),
         binary_name: internal_to_binary(&class_file.this_class),
         internal_name: class_file.this_class,
         access_flags: class_file.access_flags,
@@ -1277,6 +1291,7 @@ pub(crate) fn classfile_to_stub(class_file: ClassFile) -> JdkClassStub {
             .fields
             .into_iter()
             .map(|f| JdkFieldStub {
+                deprecation: f.deprecation().map(crate::stub::deprecation),
                 access_flags: f.access_flags,
                 name: f.name,
                 descriptor: f.descriptor,
@@ -1287,12 +1302,14 @@ pub(crate) fn classfile_to_stub(class_file: ClassFile) -> JdkClassStub {
             .methods
             .into_iter()
             .map(|m| JdkMethodStub {
+                deprecation: m.deprecation().map(crate::stub::deprecation),
                 access_flags: m.access_flags,
                 name: m.name,
                 descriptor: m.descriptor,
                 signature: m.signature,
             })
             .collect(),
+        inner_class,
     }
 }
 
@@ -1380,3 +1397,65 @@ fn dir_class_path(dir: &Path, internal_name: &str) -> PathBuf {
     rel.set_extension("class");
     dir.join(rel)
 }
+
+#[cfg(test)]
+mod tests {
+    use nova_classfile::{ClassFile, InnerClassInfo};
+    use nova_types::InnerClassStub;
+
+    use super::classfile_to_stub;
+
+    fn class_file(this_class: &str, inner_classes: Vec<InnerClassInfo>) -> ClassFile {
+        ClassFile {
+            minor_version: 0,
+            major_version: 61,
+            access_flags: 0x0001,
+            this_class: this_class.to_string(),
+            super_class: Some("java/lang/Object".to_string()),
+            interfaces: Vec::new(),
+            fields: Vec::new(),
+            methods: Vec::new(),
+            signature: None,
+            runtime_visible_annotations: Vec::new(),
+            runtime_invisible_annotations: Vec::new(),
+            inner_classes,
+            is_deprecated: false,
+        }
+    }
+
+    #[test]
+    fn stub_keeps_own_inner_classes_entry() {
+        let entry = InnerClassInfo {
+            inner_class: "java/util/Map$Entry".to_string(),
+            outer_class: Some("java/util/Map".to_string()),
+            inner_name: Some("Entry".to_string()),
+            access_flags: 0x0609, // ACC_PUBLIC | ACC_STATIC | ACC_INTERFACE | ACC_ABSTRACT
+        };
+        let anonymous = InnerClassInfo {
+            inner_class: "java/util/Map$1".to_string(),
+            outer_class: None,
+            inner_name: None,
+            access_flags: 0,
+        };
+
+        let stub = classfile_to_stub(class_file(
+            "java/util/Map$Entry",
+            vec![anonymous.clone(), entry.clone()],
+        ));
+        assert_eq!(
+            stub.inner_class,
+            Some(InnerClassStub {
+                outer_binary_name: "java.util.Map".to_string(),
+                access_flags: 0x0609,
+            })
+        );
+
+        // The outer class lists its members, but is not one itself.
+        let stub = classfile_to_stub(class_file("java/util/Map", vec![entry]));
+        assert_eq!(stub.inner_class, None);
+
+        // Anonymous classes have no declaring class.
+        let stub = classfile_to_stub(class_file("java/util/Map$1", vec![anonymous]));
+        assert_eq!(stub.inner_class, None);
+    }
+}
@@ -57,12 +57,14 @@ static BUILTIN_MATH_STUB: Lazy<Arc<JdkClassStub>> = Lazy::new(|| {
                 name: "PI".to_string(),
                 descriptor: "D".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkFieldStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC | ACC_FINAL,
                 name: "E".to_string(),
                 descriptor: "D".to_string(),
                 signature: None,
+                deprecation: None,
             },
         ],
         // Note: `Math.max`/`min` are overloaded in the real JDK.
@@ -76,50 +78,60 @@ static BUILTIN_MATH_STUB: Lazy<Arc<JdkClassStub>> = Lazy::new(|| {
                 name: "max".to_string(),
                 descriptor: "(II)I".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "max".to_string(),
                 descriptor: "(JJ)J".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "max".to_string(),
                 descriptor: "(FF)F".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "max".to_string(),
                 descriptor: "(DD)D".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "min".to_string(),
                 descriptor: "(II)I".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "min".to_string(),
                 descriptor: "(JJ)J".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "min".to_string(),
                 descriptor: "(FF)F".to_string(),
                 signature: None,
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
                 name: "min".to_string(),
                 descriptor: "(DD)D".to_string(),
                 signature: None,
+                deprecation: None,
             },
         ],
+        deprecation: None,
+        inner_class: None,
     })
 });
 
@@ -148,6 +160,7 @@ static BUILTIN_COLLECTIONS_STUB: Lazy<Arc<JdkClassStub>> = Lazy::new(|| {
                 // built-in JDK provider, clobbering `TypeStore::with_minimal_jdk`'s generic method
                 // model and causing `emptyList()` to resolve to raw `List`.
                 signature: Some("<T:Ljava/lang/Object;>()Ljava/util/List<TT;>;".to_string()),
+                deprecation: None,
             },
             JdkMethodStub {
                 access_flags: ACC_PUBLIC | ACC_STATIC,
@@ -155,8 +168,11 @@ static BUILTIN_COLLECTIONS_STUB: Lazy<Arc<JdkClassStub>> = Lazy::new(|| {
                 descriptor: "(Ljava/lang/Object;)Ljava/util/List;".to_string(),
                 // <T>(TT;)Ljava/util/List<TT;>;
                 signature: Some("<T:Ljava/lang/Object;>(TT;)Ljava/util/List<TT;>;".to_string()),
+                deprecation: None,
             },
         ],
+        deprecation: None,
+        inner_class: None,
     })
 });
 
@@ -229,6 +245,7 @@ impl From<&JdkFieldStub> for FieldStub {
             descriptor: value.descriptor.clone(),
             signature: value.signature.clone(),
             access_flags: value.access_flags,
+            deprecation: value.deprecation.clone(),
         }
     }
 }
@@ -240,6 +257,7 @@ impl From<&JdkMethodStub> for MethodStub {
             descriptor: value.descriptor.clone(),
             signature: value.signature.clone(),
             access_flags: value.access_flags,
+            deprecation: value.deprecation.clone(),
         }
     }
 }
@@ -261,6 +279,8 @@ impl From<&JdkClassStub> for TypeDefStub {
             signature: value.signature.clone(),
             fields: value.fields.iter().map(FieldStub::from).collect(),
             methods: value.methods.iter().map(MethodStub::from).collect(),
+            deprecation: value.deprecation.clone(),
+            inner_class: value.inner_class.clone(),
         }
     }
 }
@@ -1,3 +1,5 @@
+use nova_types::{Deprecation, InnerClassStub};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JdkFieldStub {
     pub access_flags: u16,
@@ -6,6 +8,7 @@ pub struct JdkFieldStub {
     pub descriptor: String,
     /// Optional generic signature string from the `Signature` attribute.
     pub signature: Option<String>,
+    pub deprecation: Option<Deprecation>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,6 +19,7 @@ pub struct JdkMethodStub {
     pub descriptor: String,
     /// Optional generic signature string from the `Signature` attribute.
     pub signature: Option<String>,
+    pub deprecation: Option<Deprecation>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,6 +35,8 @@ pub struct JdkClassStub {
     pub signature: Option<String>,
     pub fields: Vec<JdkFieldStub>,
     pub methods: Vec<JdkMethodStub>,
+    pub deprecation: Option<Deprecation>,
+    pub inner_class: Option<InnerClassStub>,
 }
 
 impl JdkClassStub {
@@ -50,6 +56,13 @@ pub(crate) fn internal_to_binary(internal: &str) -> String {
     internal.replace('/', ".")
 }
 
+pub(crate) fn deprecation(deprecation: nova_classfile::Deprecation) -> Deprecation {
+    Deprecation {
+        since: deprecation.since,
+        for_removal: deprecation.for_removal,
+    }
+}
+
 pub(crate) fn binary_to_internal(binary: &str) -> String {
     binary.replace('.', "/")
 }
@@ -12,16 +12,16 @@ pub struct NovaLspFrontend {
 }
 
 impl NovaLspFrontend {
-    pub fn new_in_process(source_roots: Vec<PathBuf>) -> Self {
+    pub fn new_in_process(source_roots: Vec<PathBuf>) -> Result<Self> {
         let layout = WorkspaceLayout {
             source_roots: source_roots
                 .into_iter()
                 .map(|path| SourceRoot { path })
                 .collect(),
         };
-        Self {
-            router: QueryRouter::new_in_process(layout),
-        }
+        Ok(Self {
+            router: QueryRouter::new_in_process(layout)?,
+        })
     }
 
     pub async fn new_distributed(
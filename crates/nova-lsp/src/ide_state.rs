@@ -146,6 +146,7 @@ fn diagnostic_to_lsp(text: &str, diag: Diagnostic) -> lsp_types::Diagnostic {
             Severity::Error => DiagnosticSeverity::ERROR,
             Severity::Warning => DiagnosticSeverity::WARNING,
             Severity::Info => DiagnosticSeverity::INFORMATION,
+            Severity::Hint => DiagnosticSeverity::HINT,
         }),
         code: Some(NumberOrString::String(diag.code.to_string())),
         source: Some("nova".into()),
@@ -775,6 +775,7 @@ pub fn diagnostics_with_extensions(
                 nova_ext::Severity::Error => lsp_types::DiagnosticSeverity::ERROR,
                 nova_ext::Severity::Warning => lsp_types::DiagnosticSeverity::WARNING,
                 nova_ext::Severity::Info => lsp_types::DiagnosticSeverity::INFORMATION,
+                nova_ext::Severity::Hint => lsp_types::DiagnosticSeverity::HINT,
             }),
             code: Some(lsp_types::NumberOrString::String(d.code.to_string())),
             source: Some("nova".into()),
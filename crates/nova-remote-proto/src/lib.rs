@@ -22,6 +22,15 @@ pub const MAX_SEARCH_RESULTS_PER_MESSAGE: usize = 10_000;
 /// Maximum number of diagnostics allowed in a single v3 `Diagnostics` response.
 pub const MAX_DIAGNOSTICS_PER_MESSAGE: usize = 100_000;
 
+/// Maximum number of related locations allowed on a single v3 `RemoteDiagnostic`.
+pub const MAX_RELATED_PER_DIAGNOSTIC: usize = 256;
+
+/// Maximum number of tags or fix-hint arguments allowed on a single v3 `RemoteDiagnostic`.
+pub const MAX_DIAGNOSTIC_ATTRS: usize = 64;
+
+/// Maximum number of references allowed in a single v3 `References` response.
+pub const MAX_REFERENCES_PER_MESSAGE: usize = 100_000;
+
 /// Maximum number of symbols allowed in a single `ShardIndex` message (v3 protocol).
 pub const MAX_SYMBOLS_PER_SHARD_INDEX: usize = 1_000_000;
 
@@ -42,7 +51,8 @@ mod bounded_de {
     use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
 
     use crate::{
-        MAX_DIAGNOSTICS_PER_MESSAGE, MAX_FILES_PER_MESSAGE, MAX_FILE_TEXT_BYTES,
+        MAX_DIAGNOSTICS_PER_MESSAGE, MAX_DIAGNOSTIC_ATTRS, MAX_FILES_PER_MESSAGE,
+        MAX_FILE_TEXT_BYTES, MAX_REFERENCES_PER_MESSAGE, MAX_RELATED_PER_DIAGNOSTIC,
         MAX_SEARCH_RESULTS_PER_MESSAGE, MAX_SMALL_STRING_BYTES, MAX_SYMBOLS_PER_SHARD_INDEX,
     };
 
@@ -196,6 +206,54 @@ mod bounded_de {
         vec_with_limit(deserializer, MAX_DIAGNOSTICS_PER_MESSAGE, "diagnostics")
     }
 
+    pub fn related_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        vec_with_limit(
+            deserializer,
+            MAX_RELATED_PER_DIAGNOSTIC,
+            "related locations",
+        )
+    }
+
+    pub fn diagnostic_attrs_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        vec_with_limit(deserializer, MAX_DIAGNOSTIC_ATTRS, "diagnostic attributes")
+    }
+
+    pub fn small_string_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SmallString(String);
+
+        impl<'de> Deserialize<'de> for SmallString {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                small_string(deserializer).map(SmallString)
+            }
+        }
+
+        let items: Vec<SmallString> =
+            vec_with_limit(deserializer, MAX_DIAGNOSTIC_ATTRS, "diagnostic attributes")?;
+        Ok(items.into_iter().map(|SmallString(s)| s).collect())
+    }
+
+    pub fn references_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        vec_with_limit(deserializer, MAX_REFERENCES_PER_MESSAGE, "references")
+    }
+
     pub fn symbols_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
     where
         D: Deserializer<'de>,
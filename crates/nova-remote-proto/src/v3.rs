@@ -126,6 +126,8 @@ pub struct RouterWelcome {
 pub enum RejectCode {
     InvalidRequest,
     Unauthorized,
+    /// The auth token was valid once but has since been rotated out.
+    ExpiredToken,
     UnsupportedVersion,
     Internal,
     #[serde(other)]
@@ -187,6 +189,14 @@ pub enum Request {
         revision: Revision,
         file: FileText,
     },
+    /// Apply a batch of file changes and re-index the shard once.
+    ///
+    /// Workers that predate this request answer it with [`RpcErrorCode::InvalidRequest`]; routers
+    /// fall back to `UpdateFile`/`IndexShard` in that case.
+    UpdateFiles {
+        revision: Revision,
+        changes: Vec<FileChange>,
+    },
     /// Best-effort diagnostics for a single file.
     ///
     /// This is intentionally minimal: it exists to enable an end-to-end distributed analysis
@@ -195,12 +205,53 @@ pub enum Request {
         #[serde(deserialize_with = "crate::bounded_de::small_string")]
         path: String,
     },
+    /// Every reference to `symbol` in the shard's files.
+    ///
+    /// Workers that predate this request answer it with [`RpcErrorCode::InvalidRequest`].
+    FindReferences {
+        symbol: SymbolKey,
+    },
     GetWorkerStats,
     Shutdown,
     #[serde(other)]
     Unknown,
 }
 
+/// The symbol a [`Request::FindReferences`] query is about.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SymbolKey {
+    #[serde(deserialize_with = "crate::bounded_de::small_string")]
+    pub name: String,
+}
+
+/// An occurrence of a symbol's name in a file.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SymbolReference {
+    #[serde(deserialize_with = "crate::bounded_de::small_string")]
+    pub path: String,
+    /// UTF-8 byte offset of the occurrence in the file.
+    pub offset: u32,
+    /// 0-based UTF-16 (LSP-compatible) position of the occurrence.
+    pub line: u32,
+    /// 0-based UTF-16 (LSP-compatible) position of the occurrence.
+    pub column: u32,
+}
+
+/// A single change in an [`Request::UpdateFiles`] batch.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FileChange {
+    /// The file was created or its contents changed.
+    Upsert { file: FileText },
+    /// The file was deleted; its symbols must be dropped from the shard index.
+    Delete {
+        #[serde(deserialize_with = "crate::bounded_de::small_string")]
+        path: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum DiagnosticSeverity {
@@ -212,6 +263,51 @@ pub enum DiagnosticSeverity {
     Unknown,
 }
 
+/// Editor rendering hint for a [`RemoteDiagnostic`] (mirrors LSP `DiagnosticTag`).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoteDiagnosticTag {
+    Unnecessary,
+    Deprecated,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A secondary location attached to a [`RemoteDiagnostic`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteRelatedInfo {
+    /// File containing the location; `None` means the diagnostic's own file.
+    #[serde(
+        default,
+        deserialize_with = "crate::bounded_de::opt_small_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub path: Option<String>,
+    /// 0-based UTF-16 (LSP-compatible) position of the location.
+    pub line: u32,
+    /// 0-based UTF-16 (LSP-compatible) position of the location.
+    pub column: u32,
+    #[serde(deserialize_with = "crate::bounded_de::small_string")]
+    pub message: String,
+}
+
+/// Identifies a quick fix for a [`RemoteDiagnostic`]; the router resolves it into edits.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteFixHint {
+    #[serde(deserialize_with = "crate::bounded_de::small_string")]
+    pub id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::bounded_de::small_string_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub args: Vec<String>,
+}
+
+/// A diagnostic reported by a worker.
+///
+/// Every field after `message` was added after the initial v3 release and defaults to empty, so
+/// older workers and routers interoperate with newer ones.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RemoteDiagnostic {
     pub severity: DiagnosticSeverity,
@@ -219,6 +315,26 @@ pub struct RemoteDiagnostic {
     pub column: u32,
     #[serde(deserialize_with = "crate::bounded_de::small_string")]
     pub message: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::bounded_de::opt_small_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::bounded_de::related_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub related: Vec<RemoteRelatedInfo>,
+    #[serde(
+        default,
+        deserialize_with = "crate::bounded_de::diagnostic_attrs_vec",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub tags: Vec<RemoteDiagnosticTag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_hint: Option<RemoteFixHint>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -230,6 +346,10 @@ pub enum Response {
         #[serde(deserialize_with = "crate::bounded_de::diagnostics_vec")]
         diagnostics: Vec<RemoteDiagnostic>,
     },
+    References {
+        #[serde(deserialize_with = "crate::bounded_de::references_vec")]
+        references: Vec<SymbolReference>,
+    },
     WorkerStats(WorkerStats),
     Shutdown,
     #[serde(other)]
@@ -240,6 +360,13 @@ pub enum Response {
 #[serde(tag = "type", content = "body", rename_all = "snake_case")]
 pub enum Notification {
     CachedIndex(ShardIndex),
+    /// Sent by a worker while it handles `IndexShard`: `files_done` of the request's
+    /// `file_count` files have been indexed.
+    IndexProgress {
+        revision: Revision,
+        files_done: u32,
+        file_count: u32,
+    },
     #[serde(other)]
     Unknown,
 }
@@ -1,7 +1,8 @@
 use anyhow::{bail, ensure, Context};
 
 use crate::{
-    MAX_DIAGNOSTICS_PER_MESSAGE, MAX_FILES_PER_MESSAGE, MAX_FILE_TEXT_BYTES, MAX_MESSAGE_BYTES,
+    MAX_DIAGNOSTICS_PER_MESSAGE, MAX_DIAGNOSTIC_ATTRS, MAX_FILES_PER_MESSAGE, MAX_FILE_TEXT_BYTES,
+    MAX_MESSAGE_BYTES, MAX_REFERENCES_PER_MESSAGE, MAX_RELATED_PER_DIAGNOSTIC,
     MAX_SEARCH_RESULTS_PER_MESSAGE, MAX_SMALL_STRING_BYTES, MAX_SYMBOLS_PER_SHARD_INDEX,
 };
 
@@ -147,7 +148,7 @@ impl<'a> Reader<'a> {
 fn limits_for_map_value(key: &str, base: Limits) -> Limits {
     match key {
         // File payloads.
-        "files" => Limits {
+        "files" | "changes" => Limits {
             max_array_len: MAX_FILES_PER_MESSAGE,
             min_array_item_bytes: MIN_BYTES_PER_COMPLEX_ARRAY_ITEM,
             ..base
@@ -166,6 +167,30 @@ fn limits_for_map_value(key: &str, base: Limits) -> Limits {
             min_array_item_bytes: MIN_BYTES_PER_COMPLEX_ARRAY_ITEM,
             ..base
         },
+        "related" => Limits {
+            max_array_len: MAX_RELATED_PER_DIAGNOSTIC,
+            min_array_item_bytes: MIN_BYTES_PER_COMPLEX_ARRAY_ITEM,
+            ..base
+        },
+        "tags" => Limits {
+            max_array_len: MAX_DIAGNOSTIC_ATTRS,
+            max_text_len: 64,
+            min_array_item_bytes: 1,
+            ..base
+        },
+        "args" => Limits {
+            max_array_len: MAX_DIAGNOSTIC_ATTRS,
+            max_text_len: MAX_SMALL_STRING_BYTES,
+            min_array_item_bytes: 1,
+            ..base
+        },
+
+        // Reference payloads.
+        "references" => Limits {
+            max_array_len: MAX_REFERENCES_PER_MESSAGE,
+            min_array_item_bytes: MIN_BYTES_PER_COMPLEX_ARRAY_ITEM,
+            ..base
+        },
 
         // Symbol payloads.
         "symbols" => Limits {
@@ -191,12 +216,11 @@ fn limits_for_map_value(key: &str, base: Limits) -> Limits {
         },
 
         // Small strings.
-        "path" | "name" | "auth_token" | "message" | "details" | "worker_build" | "query" => {
-            Limits {
-                max_text_len: MAX_SMALL_STRING_BYTES,
-                ..base
-            }
-        }
+        "path" | "name" | "auth_token" | "message" | "details" | "worker_build" | "query"
+        | "source" | "id" => Limits {
+            max_text_len: MAX_SMALL_STRING_BYTES,
+            ..base
+        },
 
         // Enum discriminator strings should always be short.
         "type" | "compression" => Limits {
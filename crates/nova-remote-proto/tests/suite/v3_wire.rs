@@ -1,7 +1,8 @@
 use nova_remote_proto::v3::{
     decode_rpc_payload, decode_wire_frame, encode_rpc_payload, encode_wire_frame, CachedIndexInfo,
-    Capabilities, CompressionAlgo, ProtocolVersion, Request, RpcPayload, SupportedVersions,
-    WireFrame,
+    Capabilities, CompressionAlgo, DiagnosticSeverity, FileChange, Notification, ProtocolVersion,
+    RemoteDiagnostic, RemoteDiagnosticTag, RemoteFixHint, RemoteRelatedInfo, Request, Response,
+    RpcPayload, RpcResult, SupportedVersions, SymbolKey, SymbolReference, WireFrame,
 };
 use nova_remote_proto::{FileText, ShardIndex, Symbol, WorkerStats};
 use std::collections::BTreeMap;
@@ -78,6 +79,124 @@ fn packet_frame_roundtrip() {
     assert_eq!(decoded_payload, payload);
 }
 
+#[test]
+fn update_files_roundtrip() {
+    let payload = RpcPayload::Request(Request::UpdateFiles {
+        revision: 6,
+        changes: vec![
+            FileChange::Upsert {
+                file: FileText {
+                    path: "src/Main.java".into(),
+                    text: "class Main {}".into(),
+                },
+            },
+            FileChange::Delete {
+                path: "src/Old.java".into(),
+            },
+        ],
+    });
+    let bytes = encode_rpc_payload(&payload).unwrap();
+    assert_eq!(decode_rpc_payload(&bytes).unwrap(), payload);
+}
+
+#[test]
+fn index_progress_notification_roundtrip() {
+    let payload = RpcPayload::Notification(Notification::IndexProgress {
+        revision: 3,
+        files_done: 256,
+        file_count: 1000,
+    });
+    let bytes = encode_rpc_payload(&payload).unwrap();
+    assert_eq!(decode_rpc_payload(&bytes).unwrap(), payload);
+}
+
+#[test]
+fn find_references_roundtrip() {
+    let request = RpcPayload::Request(Request::FindReferences {
+        symbol: SymbolKey { name: "Foo".into() },
+    });
+    let bytes = encode_rpc_payload(&request).unwrap();
+    assert_eq!(decode_rpc_payload(&bytes).unwrap(), request);
+
+    let response = RpcPayload::Response(RpcResult::Ok {
+        value: Response::References {
+            references: vec![SymbolReference {
+                path: "src/Main.java".into(),
+                offset: 42,
+                line: 3,
+                column: 8,
+            }],
+        },
+    });
+    let bytes = encode_rpc_payload(&response).unwrap();
+    assert_eq!(decode_rpc_payload(&bytes).unwrap(), response);
+}
+
+#[test]
+fn diagnostics_roundtrip_with_related_locations() {
+    let response = RpcPayload::Response(RpcResult::Ok {
+        value: Response::Diagnostics {
+            diagnostics: vec![RemoteDiagnostic {
+                severity: DiagnosticSeverity::Warning,
+                line: 2,
+                column: 4,
+                message: "duplicate bean `foo`".into(),
+                source: Some("spring".into()),
+                related: vec![RemoteRelatedInfo {
+                    path: Some("src/Other.java".into()),
+                    line: 7,
+                    column: 1,
+                    message: "also declared here".into(),
+                }],
+                tags: vec![RemoteDiagnosticTag::Unnecessary],
+                fix_hint: Some(RemoteFixHint {
+                    id: "rename-bean".into(),
+                    args: vec!["foo".into()],
+                }),
+            }],
+        },
+    });
+    let bytes = encode_rpc_payload(&response).unwrap();
+    assert_eq!(decode_rpc_payload(&bytes).unwrap(), response);
+}
+
+#[test]
+fn diagnostics_without_related_fields_still_decode() {
+    // Workers that predate related locations only send these four fields.
+    let mut diagnostic = BTreeMap::new();
+    diagnostic.insert(
+        serde_cbor::Value::Text("severity".into()),
+        serde_cbor::Value::Text("error".into()),
+    );
+    diagnostic.insert(
+        serde_cbor::Value::Text("line".into()),
+        serde_cbor::Value::Integer(1),
+    );
+    diagnostic.insert(
+        serde_cbor::Value::Text("column".into()),
+        serde_cbor::Value::Integer(0),
+    );
+    diagnostic.insert(
+        serde_cbor::Value::Text("message".into()),
+        serde_cbor::Value::Text("bad".into()),
+    );
+    let bytes = serde_cbor::to_vec(&serde_cbor::Value::Map(diagnostic)).unwrap();
+    let decoded: RemoteDiagnostic = serde_cbor::from_slice(&bytes).unwrap();
+    assert_eq!(
+        decoded,
+        RemoteDiagnostic {
+            severity: DiagnosticSeverity::Error,
+            line: 1,
+            column: 0,
+            message: "bad".into(),
+            source: None,
+            related: Vec::new(),
+            tags: Vec::new(),
+            fix_hint: None,
+        }
+    );
+}
+
 #[test]
 fn decoding_ignores_unknown_fields_in_structs() {
     let index = ShardIndex {
@@ -366,6 +366,10 @@ fn resolves_parameterized_qualifying_nested_type() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let type_vars = HashMap::new();
@@ -451,6 +455,10 @@ fn nested_binary_guess_resolves_from_env_when_owner_resolves() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let type_vars = HashMap::new();
@@ -488,6 +496,10 @@ fn does_not_fallback_to_env_for_unresolved_qualified_name() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let type_vars = HashMap::new();
@@ -684,6 +696,10 @@ fn resolves_catch_union_types_via_lub() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let _a_id = env.add_class(ClassDef {
         name: "com.example.A".to_string(),
@@ -694,6 +710,10 @@ fn resolves_catch_union_types_via_lub() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let _b_id = env.add_class(ClassDef {
         name: "com.example.B".to_string(),
@@ -704,6 +724,10 @@ fn resolves_catch_union_types_via_lub() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let ty = resolve_type_ref_text(&resolver, &scopes, scope, &env, &type_vars, "A|B", None);
@@ -1875,6 +1899,10 @@ fn type_use_annotations_on_parameterized_qualifying_nested_type_are_ignored() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let type_vars = HashMap::new();
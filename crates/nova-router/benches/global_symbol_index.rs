@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use nova_remote_proto::Symbol;
+use nova_router::GlobalSymbolIndex;
+
+const SYMBOL_COUNT: usize = 100_000;
+const LIMIT: usize = 100;
+
+fn configure_rayon() {
+    // Criterion uses Rayon internally for statistics. On constrained CI hosts we can fail to spawn
+    // the default-sized thread pool (EAGAIN / WouldBlock), which panics during analysis.
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| {
+        if std::env::var_os("RAYON_NUM_THREADS").is_none() {
+            std::env::set_var("RAYON_NUM_THREADS", "1");
+        }
+    });
+}
+
+/// Deterministic CamelCase corpus (`HashMapService000042`, ...), sorted like the router's merged
+/// shard symbols.
+fn synthetic_symbols(count: usize) -> Vec<Symbol> {
+    const HEADS: &[&str] = &[
+        "Array",
+        "Hash",
+        "Linked",
+        "Tree",
+        "Concurrent",
+        "Sorted",
+        "Weak",
+        "Identity",
+    ];
+    const MIDDLES: &[&str] = &["Map", "Set", "List", "Queue", "Deque", "Table", "Cache"];
+    const KINDS: &[&str] = &[
+        "Service", "Manager", "Handler", "Provider", "Factory", "Builder", "Client",
+    ];
+
+    let mut out: Vec<Symbol> = (0..count)
+        .map(|i| {
+            let head = HEADS[i % HEADS.len()];
+            let middle = MIDDLES[(i / HEADS.len()) % MIDDLES.len()];
+            let kind = KINDS[(i / (HEADS.len() * MIDDLES.len())) % KINDS.len()];
+            Symbol {
+                name: format!("{head}{middle}{kind}{i:06}"),
+                path: format!("src/pkg{:03}/{head}{middle}{kind}{i:06}.java", i % 256),
+                line: 0,
+                column: 0,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    out
+}
+
+fn bench_global_symbol_index(c: &mut Criterion) {
+    configure_rayon();
+
+    let symbols = synthetic_symbols(SYMBOL_COUNT);
+    let index = GlobalSymbolIndex::new(symbols.clone(), 0);
+
+    // Sanity-check the scenario: if this changes, the numbers stop being meaningful.
+    let results = index.search("HMS", LIMIT);
+    assert_eq!(results.len(), LIMIT, "expected \"HMS\" to fill the limit");
+    assert!(
+        results[0].name.starts_with("HashMapService"),
+        "expected \"HMS\" to match camel humps first, got {}",
+        results[0].name
+    );
+
+    let mut group = c.benchmark_group("global_symbol_index");
+    group.warm_up_time(Duration::from_secs(1));
+    group.measurement_time(Duration::from_secs(5));
+    group.sample_size(10);
+
+    group.bench_function("new_100k", |b| {
+        b.iter_batched(
+            || symbols.clone(),
+            |symbols| black_box(GlobalSymbolIndex::new(symbols, 0)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("search_camel_hump_hms", |b| {
+        b.iter(|| black_box(index.search(black_box("HMS"), black_box(LIMIT))))
+    });
+
+    group.bench_function("search_prefix_hash", |b| {
+        b.iter(|| black_box(index.search(black_box("hash"), black_box(LIMIT))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_global_symbol_index);
+criterion_main!(benches);
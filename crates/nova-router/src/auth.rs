@@ -0,0 +1,198 @@
+//! Application-layer authentication of worker handshakes.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use nova_remote_proto::v3::{HandshakeReject, RejectCode};
+use nova_remote_proto::ShardId;
+
+/// How many revoked tokens are remembered, so that a worker still using one is told its token
+/// expired rather than that it is unknown.
+const MAX_REVOKED_TOKENS: usize = 16;
+
+/// The tokens workers must present in their handshake.
+///
+/// Clones share their tokens, so keeping a handle (or calling
+/// [`crate::QueryRouter::set_auth_tokens`]) updates a running router. New handshakes are checked
+/// against the current tokens. Workers that are already connected keep their connection.
+///
+/// A token set has a *primary* token, which is handed to locally spawned workers, plus tokens
+/// that are still accepted, e.g. the previous token during a rotation window. A shard can be
+/// given its own token set: only those tokens then authenticate that shard, and they
+/// authenticate no other shard.
+#[derive(Clone)]
+pub struct AuthValidator {
+    state: Arc<RwLock<AuthState>>,
+}
+
+struct AuthState {
+    global: TokenSet,
+    shards: HashMap<ShardId, TokenSet>,
+    revoked: VecDeque<String>,
+}
+
+struct TokenSet {
+    primary: String,
+    also_accept: Vec<String>,
+}
+
+impl TokenSet {
+    fn tokens(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.primary.as_str()).chain(self.also_accept.iter().map(String::as_str))
+    }
+
+    fn accepts(&self, token: &str) -> bool {
+        matches_any(self.tokens(), token)
+    }
+}
+
+impl AuthValidator {
+    /// Accept `token` from every shard.
+    pub fn from_token(token: impl Into<String>) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(AuthState {
+                global: TokenSet {
+                    primary: token.into(),
+                    also_accept: Vec::new(),
+                },
+                shards: HashMap::new(),
+                revoked: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Replace the tokens accepted from shards without a token set of their own.
+    ///
+    /// Previously accepted tokens that are not in the new set are revoked.
+    pub fn set_tokens(&self, primary: String, also_accept: Vec<String>) {
+        self.update(|state| {
+            Some(std::mem::replace(
+                &mut state.global,
+                TokenSet {
+                    primary,
+                    also_accept,
+                },
+            ))
+        });
+    }
+
+    /// Give `shard_id` its own token set, replacing the tokens it accepted before.
+    pub fn set_shard_tokens(&self, shard_id: ShardId, primary: String, also_accept: Vec<String>) {
+        self.update(|state| {
+            state.shards.insert(
+                shard_id,
+                TokenSet {
+                    primary,
+                    also_accept,
+                },
+            )
+        });
+    }
+
+    /// Make `shard_id` accept the shared tokens again.
+    pub fn clear_shard_tokens(&self, shard_id: ShardId) {
+        self.update(|state| state.shards.remove(&shard_id));
+    }
+
+    /// The token a worker for `shard_id` should present.
+    pub fn primary_token(&self, shard_id: ShardId) -> String {
+        let state = self.read();
+        state
+            .shards
+            .get(&shard_id)
+            .unwrap_or(&state.global)
+            .primary
+            .clone()
+    }
+
+    /// Check the token presented in a handshake claiming `shard_id`.
+    pub(crate) fn validate(
+        &self,
+        shard_id: ShardId,
+        token: Option<&str>,
+    ) -> Result<(), HandshakeReject> {
+        let state = self.read();
+        let accepted = state.shards.get(&shard_id).unwrap_or(&state.global);
+        match token {
+            Some(token) if accepted.accepts(token) => Ok(()),
+            Some(token) if matches_any(state.revoked.iter().map(String::as_str), token) => {
+                Err(HandshakeReject {
+                    code: RejectCode::ExpiredToken,
+                    message: "authentication failed: the auth token has expired; \
+restart the worker with the current token"
+                        .into(),
+                })
+            }
+            _ => Err(HandshakeReject {
+                code: RejectCode::Unauthorized,
+                message: "authentication failed".into(),
+            }),
+        }
+    }
+
+    /// Apply `change`, which returns the token set it replaced, and revoke the replaced tokens
+    /// that are no longer accepted anywhere.
+    fn update(&self, change: impl FnOnce(&mut AuthState) -> Option<TokenSet>) {
+        let mut state = self
+            .state
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let replaced = change(&mut state);
+
+        let AuthState {
+            global,
+            shards,
+            revoked,
+        } = &mut *state;
+        let is_accepted = |token: &str| {
+            std::iter::once(&*global)
+                .chain(shards.values())
+                .any(|set| set.tokens().any(|accepted| accepted == token))
+        };
+        revoked.retain(|token| !is_accepted(token));
+        for token in replaced.iter().flat_map(TokenSet::tokens) {
+            if !is_accepted(token) && !revoked.iter().any(|known| known == token) {
+                revoked.push_back(token.to_string());
+            }
+        }
+        while revoked.len() > MAX_REVOKED_TOKENS {
+            revoked.pop_front();
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, AuthState> {
+        self.state
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl std::fmt::Debug for AuthValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.read();
+        f.debug_struct("AuthValidator")
+            .field("shards_with_own_tokens", &state.shards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether `token` is one of `candidates`, comparing against every candidate so the time taken
+/// doesn't reveal which one matched.
+fn matches_any<'a>(candidates: impl IntoIterator<Item = &'a str>, token: &str) -> bool {
+    candidates.into_iter().fold(false, |found, candidate| {
+        found | constant_time_eq(candidate, token)
+    })
+}
+
+/// Compare two tokens in time that depends only on their lengths (which are not secret).
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |acc, (x, y)| std::hint::black_box(acc | (x ^ y)));
+    diff == 0
+}
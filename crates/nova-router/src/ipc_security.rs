@@ -190,7 +190,7 @@ pub(crate) fn create_secure_named_pipe_server(
     // This keeps the implementation small while still ensuring other local users cannot connect.
     //
     // Note: this is about local multi-tenant safety, not full authentication. If workers are
-    // started externally, use `DistributedRouterConfig::auth_token` as an application-layer guard.
+    // started externally, use `DistributedRouterConfig::auth` as an application-layer guard.
     let sddl = OsStr::new("D:P(A;;GA;;;SY)(A;;GA;;;OW)");
     let mut sddl_w: Vec<u16> = sddl.encode_wide().collect();
     sddl_w.push(0);
@@ -1,19 +1,21 @@
 use std::cell::RefCell;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use anyhow::{anyhow, Context};
 use nova_bugreport::{install_panic_hook, PanicHookConfig};
+use nova_cache::ShardRootFingerprint;
 use nova_config::{init_tracing_with_config, NovaConfig};
 use nova_fuzzy::{
-    FuzzyMatcher, MatchScore, TrigramCandidateScratch, TrigramIndex, TrigramIndexBuilder,
+    FuzzyMatcher, MatchKind, MatchScore, TrigramCandidateScratch, TrigramIndex, TrigramIndexBuilder,
 };
 use nova_remote_proto::v3::{
-    HandshakeReject, Notification, RejectCode, RemoteDiagnostic, Request, Response,
+    FileChange as RemoteFileChange, HandshakeReject, Notification, RejectCode, RemoteDiagnostic,
+    Request, Response, RpcErrorCode, SymbolKey, SymbolReference,
 };
 use nova_remote_proto::{FileText, ShardId, ShardIndex, Symbol, WorkerId, WorkerStats};
 use nova_remote_rpc::{
@@ -23,7 +25,7 @@ use nova_scheduler::{CancellationToken, Cancelled, Scheduler, SchedulerConfig, T
 use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, BufReader};
 use tokio::net::TcpListener;
 use tokio::process::Command;
-use tokio::sync::{watch, Mutex, Notify, OwnedSemaphorePermit, RwLock, Semaphore};
+use tokio::sync::{mpsc, oneshot, watch, Mutex, Notify, OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::task::{JoinHandle, JoinSet};
 use tokio::time::{timeout, Duration, Instant};
 use tracing::{error, info, warn};
@@ -31,12 +33,14 @@ use tracing::{error, info, warn};
 #[cfg(unix)]
 use tokio::net::UnixListener;
 
+mod auth;
 mod ipc_security;
 
 mod supervisor;
 #[cfg(feature = "tls")]
 pub mod tls;
 
+pub use auth::AuthValidator;
 use supervisor::RestartBackoff;
 
 pub type Result<T> = anyhow::Result<T>;
@@ -45,6 +49,11 @@ fn rpc_cancelled_error() -> anyhow::Error {
     anyhow!(nova_remote_rpc::RpcError::Canceled)
 }
 
+fn is_cancelled_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<nova_remote_rpc::RpcError>()
+        .is_some_and(|err| matches!(err, nova_remote_rpc::RpcError::Canceled))
+}
+
 /// Initialize structured logging and install the global panic hook used by Nova.
 ///
 /// `nova-router` is typically embedded within `nova-lsp`, which is responsible
@@ -78,6 +87,23 @@ const WORKER_SHUTDOWN_RPC_TIMEOUT: Duration = Duration::from_secs(2);
 // snapshot.
 const MAX_CONCURRENT_SHARD_FILE_SNAPSHOTS: usize = 2;
 
+// Maximum number of shard workers a fanout query (e.g. find references) has requests in flight to.
+const MAX_CONCURRENT_FANOUT_QUERIES: usize = 8;
+
+// A diagnostics request for a document version that already has a call in flight shares that
+// call's response if the call started at most this long ago.
+const DIAGNOSTICS_COALESCE_WINDOW: Duration = Duration::from_millis(250);
+
+// Maximum number of diagnostics RPCs in flight to one worker, so that a burst of them can't
+// starve indexing requests on the same connection.
+const MAX_CONCURRENT_DIAGNOSTICS_PER_WORKER: usize = 2;
+
+// In-process indexing reports `IndexProgressEvent::ShardProgress` every this many files.
+const INDEX_PROGRESS_INTERVAL_FILES: usize = 256;
+
+// A `JavaFilePaths` walk buffers at most this many discovered-but-unconsumed paths.
+const JAVA_FILE_PATHS_BUFFER: usize = 1024;
+
 const WORKER_RESTART_BACKOFF_INITIAL: Duration = Duration::from_millis(50);
 const WORKER_RESTART_BACKOFF_MAX: Duration = Duration::from_secs(5);
 const WORKER_SESSION_RESET_BACKOFF_AFTER: Duration = Duration::from_secs(10);
@@ -110,6 +136,247 @@ pub struct WorkspaceLayout {
     pub source_roots: Vec<SourceRoot>,
 }
 
+impl WorkspaceLayout {
+    /// Checks that every file can be assigned to exactly one shard.
+    ///
+    /// Listing the same source root twice is an error. Nested roots (one root inside another) are
+    /// allowed but logged: files under the inner root belong only to the inner root's shard, since
+    /// files are assigned to the longest matching root.
+    pub fn validate(&self) -> Result<()> {
+        for (idx, root) in self.source_roots.iter().enumerate() {
+            for other in &self.source_roots[..idx] {
+                if root.path == other.path {
+                    return Err(anyhow!("duplicate source root {}", root.path.display()));
+                }
+                let (outer, inner) = if root.path.starts_with(&other.path) {
+                    (&other.path, &root.path)
+                } else if other.path.starts_with(&root.path) {
+                    (&root.path, &other.path)
+                } else {
+                    continue;
+                };
+                warn!(
+                    outer = %outer.display(),
+                    inner = %inner.display(),
+                    "nested source roots; files under the inner root are only indexed in its shard"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A change to a single file, applied with [`QueryRouter::update_files`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileChange {
+    /// The file was created or its contents changed.
+    Upsert { path: PathBuf, text: String },
+    /// The file was deleted (it may already be gone from disk).
+    Delete { path: PathBuf },
+}
+
+impl FileChange {
+    pub fn path(&self) -> &Path {
+        match self {
+            FileChange::Upsert { path, .. } | FileChange::Delete { path } => path,
+        }
+    }
+}
+
+/// Progress of an `index_workspace` run, delivered to [`QueryRouter::subscribe_progress`]
+/// subscribers.
+///
+/// Every run ends with exactly one of `WorkspaceIndexCompleted`, `Cancelled` (the caller cancelled
+/// it or a newer run superseded it), or `Failed`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexProgressEvent {
+    /// The shard's directory walk finished with `file_count` files. Indexing starts as soon as
+    /// the walk finds its first file, so it is already underway when this is reported.
+    ShardStarted {
+        shard_id: ShardId,
+        file_count: usize,
+    },
+    /// `files_done` of the shard's files have been indexed. Reported at a coarse granularity.
+    ShardProgress {
+        shard_id: ShardId,
+        files_done: usize,
+    },
+    /// The shard's index was built (`duration` is measured from the start of the directory walk).
+    ShardCompleted {
+        shard_id: ShardId,
+        symbol_count: usize,
+        duration: Duration,
+    },
+    WorkspaceIndexCompleted {
+        revision: u64,
+    },
+    Cancelled {
+        revision: u64,
+    },
+    Failed {
+        revision: u64,
+        error: String,
+    },
+}
+
+impl IndexProgressEvent {
+    /// The terminal event of the run for `revision` that stopped with `err`.
+    fn index_error(revision: u64, err: &anyhow::Error) -> Self {
+        if is_cancelled_error(err) {
+            IndexProgressEvent::Cancelled { revision }
+        } else {
+            IndexProgressEvent::Failed {
+                revision,
+                error: format!("{err:#}"),
+            }
+        }
+    }
+}
+
+/// Health of a shard as seen by the router, returned by [`QueryRouter::shard_health`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShardHealth {
+    pub shard_id: ShardId,
+    pub root: PathBuf,
+    pub status: ShardStatus,
+    /// The served index was loaded from the router's cache under `cache_dir` when the router
+    /// started and has not been rebuilt since, so it may be stale. It is refreshed as soon as the
+    /// shard's worker connects.
+    pub index_from_cache: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShardStatus {
+    /// A worker is connected. `last_index_revision` is the revision of the shard index the router
+    /// serves symbols from (`None` until the shard is first indexed).
+    Healthy { last_index_revision: Option<u64> },
+    /// A worker is connected but a request to it failed, so the shard's symbols may be stale
+    /// since `stale_since`.
+    Degraded {
+        stale_since: std::time::Instant,
+        last_error: String,
+    },
+    /// No worker has been connected since `since` (it has not connected yet, or it crashed and is
+    /// being restarted). The shard's last index, if any, is still served.
+    NoWorker { since: std::time::Instant },
+}
+
+/// A request needed a shard whose worker is known to be down (its supervisor is waiting to
+/// restart it), so it failed without waiting for a replacement worker.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("worker for shard {shard_id} is unavailable")]
+pub struct ShardUnavailable {
+    pub shard_id: ShardId,
+}
+
+/// Worker supervision state of a shard, returned by [`QueryRouter::worker_supervision_info`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkerSupervisionInfo {
+    pub shard_id: ShardId,
+    /// The connected worker, if any.
+    pub worker_id: Option<WorkerId>,
+    /// How many times the supervisor has started a replacement worker, after a crash or a
+    /// [`QueryRouter::restart_shard`]. Always zero when the router does not spawn workers.
+    pub restart_count: u64,
+    /// Delay (before jitter) of the supervisor's next automatic restart. Doubles with every
+    /// short-lived worker up to a cap and is reset by [`QueryRouter::restart_shard`]. Always zero
+    /// when the router does not spawn workers.
+    pub backoff: Duration,
+    /// How the shard's last worker went away, if one has.
+    pub last_exit: Option<WorkerExit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkerExit {
+    /// The worker that went away, or `None` if it never completed its handshake.
+    pub worker_id: Option<WorkerId>,
+    pub at: std::time::Instant,
+    pub reason: WorkerExitReason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkerExitReason {
+    /// The worker process exited by itself. `code` is `None` if it was killed by a signal.
+    Exited { code: Option<i32> },
+    /// The worker process could not be spawned.
+    SpawnFailed { error: String },
+    /// The worker did not complete its handshake in time and was killed.
+    HandshakeTimeout,
+    /// The router severed the connection because the worker violated the protocol (e.g. it
+    /// reported data for another shard).
+    ProtocolViolation,
+    /// The connection to the worker closed.
+    Disconnected,
+    /// The worker was stopped by [`QueryRouter::restart_shard`].
+    Restarted,
+}
+
+/// Options of [`QueryRouter::restart_shard_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RestartShardOptions {
+    /// Delete the shard's cached index under `cache_dir` before the new worker starts, so it
+    /// can't load a corrupt cache again.
+    pub clear_cache: bool,
+}
+
+/// Result of [`QueryRouter::index_workspace_partial`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexSummary {
+    /// Shards whose index was rebuilt, in shard id order.
+    pub indexed: Vec<ShardId>,
+    /// Shards that could not be indexed and why, in shard id order. Their previous index is kept.
+    pub skipped: Vec<(ShardId, String)>,
+}
+
+/// Result of [`QueryRouter::update_layout`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LayoutDiff {
+    /// Shards created for new source roots, in layout order.
+    pub added: Vec<(ShardId, PathBuf)>,
+    /// Shards whose source root is no longer part of the layout, in their previous layout order.
+    pub removed: Vec<(ShardId, PathBuf)>,
+}
+
+/// Result of [`QueryRouter::find_references`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FindReferencesResult {
+    /// References from every shard that answered, sorted by path and then offset.
+    pub references: Vec<SymbolReference>,
+    /// Shards that could not be queried, in shard id order. Their references are missing.
+    pub partial: Vec<ShardId>,
+}
+
+/// The per-shard results of [`DistributedRouter::fanout_query`].
+struct FanoutResults<R> {
+    /// Results of the shards that answered, in shard id order.
+    results: Vec<(ShardId, R)>,
+    /// Shards whose worker was unavailable or failed the request, in shard id order.
+    failed: Vec<ShardId>,
+}
+
+/// The senders handed out by [`QueryRouter::subscribe_progress`].
+#[derive(Default)]
+struct ProgressSubscribers {
+    senders: std::sync::Mutex<Vec<mpsc::UnboundedSender<IndexProgressEvent>>>,
+}
+
+impl ProgressSubscribers {
+    fn subscribe(&self) -> mpsc::UnboundedReceiver<IndexProgressEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.lock().push(tx);
+        rx
+    }
+
+    /// Send `event` to every subscriber, dropping the ones whose receiver is gone.
+    fn emit(&self, event: IndexProgressEvent) {
+        self.lock().retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<mpsc::UnboundedSender<IndexProgressEvent>>> {
+        self.senders.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ListenAddr {
     #[cfg(unix)]
@@ -150,7 +417,11 @@ pub struct DistributedRouterConfig {
     pub listen_addr: ListenAddr,
     pub worker_command: PathBuf,
     pub cache_dir: PathBuf,
-    pub auth_token: Option<String>,
+    /// Tokens workers must present in their handshake; `None` disables token authentication.
+    ///
+    /// When the router spawns workers and no validator is configured, it generates a random
+    /// token for them.
+    pub auth: Option<AuthValidator>,
     /// Allow binding plaintext TCP sockets / connecting over plaintext TCP.
     ///
     /// Plaintext TCP is insecure because it exposes source code and (when enabled) auth tokens to
@@ -179,7 +450,7 @@ impl std::fmt::Debug for DistributedRouterConfig {
         s.field("listen_addr", &self.listen_addr)
             .field("worker_command", &self.worker_command)
             .field("cache_dir", &self.cache_dir)
-            .field("auth_present", &self.auth_token.is_some())
+            .field("auth_present", &self.auth.is_some())
             .field("allow_insecure_tcp", &self.allow_insecure_tcp)
             .field("max_rpc_bytes", &self.max_rpc_bytes)
             .field("max_inflight_handshakes", &self.max_inflight_handshakes)
@@ -326,7 +597,7 @@ impl DistributedRouterConfig {
     /// centralizes the conservative defaults used by Nova:
     ///
     /// - `spawn_workers = true`
-    /// - `auth_token = None`
+    /// - `auth = None`
     /// - `allow_insecure_tcp = false`
     /// - `max_rpc_bytes = DEFAULT_MAX_RPC_BYTES`
     /// - `max_inflight_handshakes = DEFAULT_MAX_INFLIGHT_HANDSHAKES`
@@ -336,7 +607,7 @@ impl DistributedRouterConfig {
             listen_addr,
             worker_command,
             cache_dir,
-            auth_token: None,
+            auth: None,
             allow_insecure_tcp: false,
             max_rpc_bytes: DEFAULT_MAX_RPC_BYTES,
             max_inflight_handshakes: DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -416,7 +687,7 @@ Configure the router TLS server with a client CA certificate (TlsServerConfig::w
 
         let non_loopback = !addr.ip().is_loopback();
 
-        if self.auth_token.is_some() {
+        if self.auth.is_some() {
             return Err(anyhow!(
                 "refusing to start distributed router with plaintext TCP (`tcp:`) while an auth token is configured. \
 Plaintext TCP would expose the auth token and shard source code in cleartext. \
@@ -459,16 +730,18 @@ enum RouterMode {
 }
 
 impl QueryRouter {
-    pub fn new_in_process(layout: WorkspaceLayout) -> Self {
-        Self {
+    pub fn new_in_process(layout: WorkspaceLayout) -> Result<Self> {
+        layout.validate()?;
+        Ok(Self {
             inner: RouterMode::InProcess(InProcessRouter::new(layout)),
-        }
+        })
     }
 
     pub async fn new_distributed(
         config: DistributedRouterConfig,
         layout: WorkspaceLayout,
     ) -> Result<Self> {
+        layout.validate()?;
         Ok(Self {
             inner: RouterMode::Distributed(DistributedRouter::new(config, layout).await?),
         })
@@ -483,8 +756,13 @@ impl QueryRouter {
 
     pub async fn index_workspace_cancelable(&self, cancel: CancellationToken) -> Result<()> {
         match &self.inner {
-            RouterMode::InProcess(router) => router.index_workspace_cancelable(cancel).await,
-            RouterMode::Distributed(router) => router.index_workspace_cancelable(cancel).await,
+            RouterMode::InProcess(router) => {
+                router.index_workspace_cancelable(cancel).await.map(|_| ())
+            }
+            RouterMode::Distributed(router) => router
+                .index_workspace_cancelable(cancel, false)
+                .await
+                .map(|_| ()),
         }
     }
 
@@ -493,6 +771,65 @@ impl QueryRouter {
             .await
     }
 
+    /// Like [`QueryRouter::index_workspace_cancelable`], but a shard whose worker is down or
+    /// fails to index is skipped instead of failing the run. Skipped shards keep serving their
+    /// previous index. Cancellation still fails the whole run.
+    pub async fn index_workspace_partial_cancelable(
+        &self,
+        cancel: CancellationToken,
+    ) -> Result<IndexSummary> {
+        match &self.inner {
+            RouterMode::InProcess(router) => {
+                let mut indexed = router.index_workspace_cancelable(cancel).await?;
+                indexed.sort_unstable();
+                Ok(IndexSummary {
+                    indexed,
+                    skipped: Vec::new(),
+                })
+            }
+            RouterMode::Distributed(router) => {
+                router.index_workspace_cancelable(cancel, true).await
+            }
+        }
+    }
+
+    pub async fn index_workspace_partial(&self) -> Result<IndexSummary> {
+        self.index_workspace_partial_cancelable(CancellationToken::new())
+            .await
+    }
+
+    /// The health of every shard, in shard id order.
+    ///
+    /// In-process shards have no worker and are always healthy.
+    pub async fn shard_health(&self) -> Vec<ShardHealth> {
+        match &self.inner {
+            RouterMode::InProcess(router) => router.shard_health().await,
+            RouterMode::Distributed(router) => router.state.shard_health().await,
+        }
+    }
+
+    /// Subscribe to the progress of `index_workspace` runs started after this call.
+    ///
+    /// In distributed mode `ShardProgress` is forwarded from worker `IndexProgress`
+    /// notifications; workers that don't send them only report `ShardStarted` and
+    /// `ShardCompleted`. Dropping the receiver unsubscribes.
+    pub fn subscribe_progress(&self) -> mpsc::UnboundedReceiver<IndexProgressEvent> {
+        match &self.inner {
+            RouterMode::InProcess(router) => router.progress.subscribe(),
+            RouterMode::Distributed(router) => router.state.progress.subscribe(),
+        }
+    }
+
+    /// The shard owning `path`: the longest source root containing it, or `None` if no root
+    /// does. Answered from the router's layout, without an RPC.
+    pub fn shard_for_path(&self, path: &Path) -> Option<ShardId> {
+        let layout = match &self.inner {
+            RouterMode::InProcess(router) => &router.layout,
+            RouterMode::Distributed(router) => &router.state.layout,
+        };
+        read_layout(layout).shard_for_path(path)
+    }
+
     pub async fn update_file_cancelable(
         &self,
         cancel: CancellationToken,
@@ -514,6 +851,66 @@ impl QueryRouter {
             .await
     }
 
+    /// Apply a batch of file changes, re-indexing each affected shard once.
+    ///
+    /// Changes are grouped by shard and applied in order, so a later change to the same path
+    /// wins. Deleted files are dropped from their shard's index even if they are already gone
+    /// from disk. Like [`QueryRouter::update_file_cancelable`], this fails without applying
+    /// anything if a path is not in any source root.
+    pub async fn update_files_cancelable(
+        &self,
+        cancel: CancellationToken,
+        changes: Vec<FileChange>,
+    ) -> Result<()> {
+        match &self.inner {
+            RouterMode::InProcess(router) => router.update_files_cancelable(cancel, changes).await,
+            RouterMode::Distributed(router) => {
+                router.update_files_cancelable(cancel, changes).await
+            }
+        }
+    }
+
+    pub async fn update_files(&self, changes: Vec<FileChange>) -> Result<()> {
+        self.update_files_cancelable(CancellationToken::new(), changes)
+            .await
+    }
+
+    /// The worker supervision state of every shard, in shard id order.
+    ///
+    /// In-process routers have no workers and return an empty list.
+    pub async fn worker_supervision_info(&self) -> Vec<WorkerSupervisionInfo> {
+        match &self.inner {
+            RouterMode::InProcess(_) => Vec::new(),
+            RouterMode::Distributed(router) => router.state.worker_supervision_info().await,
+        }
+    }
+
+    pub async fn restart_shard(&self, shard_id: ShardId) -> Result<()> {
+        self.restart_shard_with_options(shard_id, RestartShardOptions::default())
+            .await
+    }
+
+    /// Replace the worker of `shard_id`, e.g. one stuck in a crash loop.
+    ///
+    /// When the router spawns workers, the current worker is asked to shut down (and killed if it
+    /// doesn't exit in time), the restart backoff is reset and a fresh worker is started right
+    /// away; this returns once the old worker is gone. Otherwise the worker's connection is
+    /// severed and an external worker is expected to reconnect. With
+    /// [`RestartShardOptions::clear_cache`] the shard's cached index is deleted before the new
+    /// worker starts.
+    pub async fn restart_shard_with_options(
+        &self,
+        shard_id: ShardId,
+        options: RestartShardOptions,
+    ) -> Result<()> {
+        match &self.inner {
+            RouterMode::InProcess(_) => Err(anyhow!(
+                "cannot restart shard {shard_id}: in-process routers have no workers"
+            )),
+            RouterMode::Distributed(router) => router.restart_shard(shard_id, options).await,
+        }
+    }
+
     pub async fn worker_stats(&self) -> Result<HashMap<ShardId, WorkerStats>> {
         match &self.inner {
             RouterMode::InProcess(router) => Ok(router.worker_stats()),
@@ -528,6 +925,42 @@ impl QueryRouter {
         }
     }
 
+    /// Rotate the worker auth tokens without restarting the router.
+    ///
+    /// New handshakes must present `primary` or one of `also_accept` (e.g. the previous token,
+    /// until every worker has switched over); tokens accepted before and not listed here are
+    /// rejected as expired. Connected workers keep their connection, and locally spawned
+    /// workers receive `primary` from their next respawn on. Shards with their own tokens (see
+    /// [`QueryRouter::set_shard_auth_tokens`]) are unaffected.
+    pub fn set_auth_tokens(&self, primary: String, also_accept: Vec<String>) -> Result<()> {
+        self.auth_validator()?.set_tokens(primary, also_accept);
+        Ok(())
+    }
+
+    /// Like [`QueryRouter::set_auth_tokens`], but for `shard_id` alone: from now on only these
+    /// tokens authenticate a worker for `shard_id`, and they authenticate no other shard.
+    pub fn set_shard_auth_tokens(
+        &self,
+        shard_id: ShardId,
+        primary: String,
+        also_accept: Vec<String>,
+    ) -> Result<()> {
+        self.auth_validator()?
+            .set_shard_tokens(shard_id, primary, also_accept);
+        Ok(())
+    }
+
+    fn auth_validator(&self) -> Result<&AuthValidator> {
+        match &self.inner {
+            RouterMode::InProcess(_) => Err(anyhow!(
+                "in-process routers have no workers to authenticate"
+            )),
+            RouterMode::Distributed(router) => router.state.config.auth.as_ref().ok_or_else(|| {
+                anyhow!("the router was started without worker authentication (`auth = None`)")
+            }),
+        }
+    }
+
     pub async fn workspace_symbols(&self, query: &str) -> Vec<Symbol> {
         match &self.inner {
             RouterMode::InProcess(router) => router.workspace_symbols(query).await,
@@ -539,6 +972,11 @@ impl QueryRouter {
     ///
     /// This is intentionally minimal: it exists to enable an end-to-end distributed analysis
     /// prototype. Callers should treat failures as non-fatal.
+    ///
+    /// A request supersedes the file's in-flight diagnostics request, if any: the older request's
+    /// RPC is cancelled and its callers get no diagnostics. Use
+    /// [`QueryRouter::diagnostics_versioned_cancelable`] to share responses between requests for
+    /// the same document version instead.
     pub async fn diagnostics_cancelable(
         &self,
         cancel: CancellationToken,
@@ -546,7 +984,9 @@ impl QueryRouter {
     ) -> Vec<RemoteDiagnostic> {
         match &self.inner {
             RouterMode::InProcess(_) => Vec::new(),
-            RouterMode::Distributed(router) => router.diagnostics_cancelable(cancel, path).await,
+            RouterMode::Distributed(router) => {
+                router.diagnostics_cancelable(cancel, path, None).await
+            }
         }
     }
 
@@ -554,110 +994,466 @@ impl QueryRouter {
         self.diagnostics_cancelable(CancellationToken::new(), path)
             .await
     }
-}
-
-struct InProcessRouter {
-    layout: WorkspaceLayout,
-    global_revision: AtomicU64,
-    shard_indexes: Mutex<HashMap<ShardId, ShardIndex>>,
-    global_symbols: RwLock<GlobalSymbolIndex>,
-    scheduler: Scheduler,
-    index_token: Mutex<CancellationToken>,
-}
 
-impl InProcessRouter {
-    fn new(layout: WorkspaceLayout) -> Self {
-        let scheduler = tokio::runtime::Handle::try_current()
-            .map(|handle| Scheduler::new_with_io_handle(SchedulerConfig::default(), handle))
-            .unwrap_or_else(|_| Scheduler::default());
-        Self {
-            layout,
-            global_revision: AtomicU64::new(0),
-            shard_indexes: Mutex::new(HashMap::new()),
-            global_symbols: RwLock::new(GlobalSymbolIndex::default()),
-            scheduler,
-            index_token: Mutex::new(CancellationToken::new()),
+    /// Like [`QueryRouter::diagnostics_cancelable`], for version `doc_version` of the document
+    /// (e.g. the LSP document version).
+    ///
+    /// A request for the version of the file's in-flight request shares that request's response
+    /// if it started recently. A request for a later version supersedes it, and a request for an
+    /// earlier version gets no diagnostics, as do callers of a request that was superseded before
+    /// its response arrived.
+    pub async fn diagnostics_versioned_cancelable(
+        &self,
+        cancel: CancellationToken,
+        path: PathBuf,
+        doc_version: u64,
+    ) -> Vec<RemoteDiagnostic> {
+        match &self.inner {
+            RouterMode::InProcess(_) => Vec::new(),
+            RouterMode::Distributed(router) => {
+                router
+                    .diagnostics_cancelable(cancel, path, Some(doc_version))
+                    .await
+            }
         }
     }
 
-    async fn next_index_token(&self) -> CancellationToken {
-        let mut guard = self.index_token.lock().await;
-        guard.cancel();
-        let token = CancellationToken::new();
-        *guard = token.clone();
-        token
+    pub async fn diagnostics_versioned(
+        &self,
+        path: PathBuf,
+        doc_version: u64,
+    ) -> Vec<RemoteDiagnostic> {
+        self.diagnostics_versioned_cancelable(CancellationToken::new(), path, doc_version)
+            .await
     }
 
-    async fn index_workspace_cancelable(&self, cancel: CancellationToken) -> Result<()> {
-        if cancel.is_cancelled() {
-            return Err(rpc_cancelled_error());
+    /// Every reference to `symbol` across all shards.
+    ///
+    /// References are textual: each occurrence of the symbol's name as a whole identifier,
+    /// including its declaration. In distributed mode every shard's worker is queried
+    /// concurrently; shards that cannot be queried are reported in
+    /// [`FindReferencesResult::partial`] instead of failing the query. In-process shards are
+    /// searched on disk.
+    pub async fn find_references_cancelable(
+        &self,
+        cancel: CancellationToken,
+        symbol: SymbolKey,
+    ) -> Result<FindReferencesResult> {
+        match &self.inner {
+            RouterMode::InProcess(router) => {
+                router.find_references_cancelable(cancel, symbol).await
+            }
+            RouterMode::Distributed(router) => {
+                router.find_references_cancelable(cancel, symbol).await
+            }
         }
+    }
 
-        let token = self.next_index_token().await;
-        let revision = self.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
-        let mut collect_set = JoinSet::new();
-
-        // Collect shard files concurrently so multi-shard indexing can start promptly.
-        for (shard_id, root) in self.layout.source_roots.iter().enumerate() {
-            let root = root.path.clone();
-            let shard_id = shard_id as ShardId;
-            collect_set.spawn(async move {
-                let files = collect_java_file_paths(&root).await.with_context(|| {
-                    format!("collect files for shard {shard_id} ({})", root.display())
-                })?;
-                Ok::<_, anyhow::Error>((shard_id, files))
-            });
+    pub async fn find_references(&self, symbol: SymbolKey) -> Result<FindReferencesResult> {
+        self.find_references_cancelable(CancellationToken::new(), symbol)
+            .await
+    }
+
+    /// Replace the workspace layout without restarting the router.
+    ///
+    /// Source roots present in both the old and the new layout keep their shard id, worker, and
+    /// shard index. Roots that disappeared are torn down (their worker is shut down and their
+    /// symbols are dropped from the global symbol index), while new roots are assigned fresh
+    /// shard ids (in distributed mode a worker is spawned for them when `spawn_workers` is set).
+    /// A root whose path changed is a removal plus an addition.
+    ///
+    /// New shards are not indexed eagerly. The next `index_workspace` only indexes them, plus
+    /// existing shards that gained or lost a nested root; the other shards keep their index.
+    ///
+    /// In-process, an in-flight `index_workspace` is superseded (as by `update_file`). In
+    /// distributed mode it keeps running, but results for removed shards are discarded.
+    pub async fn update_layout(&self, new_layout: WorkspaceLayout) -> Result<LayoutDiff> {
+        new_layout.validate()?;
+        match &self.inner {
+            RouterMode::InProcess(router) => router.update_layout(new_layout).await,
+            RouterMode::Distributed(router) => router.update_layout(new_layout).await,
         }
+    }
 
-        let mut indexes = HashMap::new();
-        let mut join_set = JoinSet::new();
+    /// Replace the workspace layout without restarting the router, discarding the
+    /// [`LayoutDiff`]. See [`QueryRouter::update_layout`].
+    pub async fn reload_layout(&self, new_layout: WorkspaceLayout) -> Result<()> {
+        self.update_layout(new_layout).await.map(|_| ())
+    }
+}
 
-        // Pipeline file collection -> indexing so that early shards can start indexing work while
-        // later shards are still walking the filesystem.
-        while !collect_set.is_empty() || !join_set.is_empty() {
-            tokio::select! {
-                biased;
-                _ = cancel.cancelled() => {
-                    token.cancel();
-                    return Err(rpc_cancelled_error());
-                }
-                _ = token.cancelled() => {
-                    return Ok(());
-                }
-                res = collect_set.join_next(), if !collect_set.is_empty() => {
-                    let Some(res) = res else {
-                        continue;
-                    };
+/// Router-local assignment of shard ids to source roots.
+///
+/// Shard ids are handed out in layout order and never reused: reloading the layout keeps the id
+/// of every root that is still present so workers, cached indexes, and in-flight requests for
+/// unchanged roots stay valid.
+///
+/// When roots are nested, a file belongs to the longest root containing it; collecting the files
+/// of the outer root skips the inner roots (see [`ShardLayout::nested_roots`]).
+#[derive(Clone, Debug, Default)]
+struct ShardLayout {
+    shards: Vec<(ShardId, PathBuf)>,
+    next_shard_id: ShardId,
+    /// Shards whose files changed with a reload and that have not been indexed since.
+    unindexed: BTreeSet<ShardId>,
+    /// Bumped by every reload, so an indexing run can tell whether the files it indexed are
+    /// still the shard's files.
+    generation: u64,
+}
 
-                    let (shard_id, files) = match res {
-                        Ok(Ok(res)) => res,
-                        Ok(Err(err)) => {
-                            token.cancel();
-                            return Err(err);
-                        }
-                        Err(err) => {
-                            token.cancel();
-                            return Err(anyhow!("file collection task panicked: {err}"));
-                        }
-                    };
+/// The changes of an `update_files` batch that belong to one shard.
+#[derive(Debug)]
+struct ShardFileChanges {
+    shard_id: ShardId,
+    root: PathBuf,
+    nested_roots: Vec<PathBuf>,
+    changes: Vec<FileChange>,
+}
 
-                    // Spawn indexing for this shard immediately.
-                    let task = self.scheduler.spawn_background_with_token(token.clone(), move |token| {
-                        Cancelled::check(&token)?;
-                        let symbols = index_for_files(shard_id, files, None, &token)?;
-                        Cancelled::check(&token)?;
-                        Ok(symbols)
-                    });
+impl ShardFileChanges {
+    /// Apply the changes to `files` (the paths collected from disk).
+    ///
+    /// Returns the texts of upserted files; deleted files are removed from `files` and upserted
+    /// files missing from disk are added.
+    fn apply_to_paths(&self, files: &mut Vec<String>) -> HashMap<String, String> {
+        let mut latest: HashMap<String, Option<&str>> = HashMap::new();
+        for change in &self.changes {
+            let path = change.path().to_string_lossy().to_string();
+            let text = match change {
+                FileChange::Upsert { text, .. } => Some(text.as_str()),
+                FileChange::Delete { .. } => None,
+            };
+            latest.insert(path, text);
+        }
 
-                    join_set.spawn(async move { (shard_id, task.join().await) });
-                }
-                res = join_set.join_next(), if !join_set.is_empty() => {
-                    let Some(res) = res else {
-                        continue;
-                    };
+        files.retain(|file| !matches!(latest.get(file), Some(None)));
+        let existing: HashSet<String> = files.iter().cloned().collect();
+        let mut overrides = HashMap::new();
+        for (path, text) in latest {
+            let Some(text) = text else {
+                continue;
+            };
+            if !existing.contains(&path) {
+                files.push(path.clone());
+            }
+            overrides.insert(path, text.to_string());
+        }
+        overrides
+    }
+
+    fn remote_changes(&self) -> Vec<RemoteFileChange> {
+        self.changes
+            .iter()
+            .map(|change| match change {
+                FileChange::Upsert { path, text } => RemoteFileChange::Upsert {
+                    file: FileText {
+                        path: path.to_string_lossy().to_string(),
+                        text: text.clone(),
+                    },
+                },
+                FileChange::Delete { path } => RemoteFileChange::Delete {
+                    path: path.to_string_lossy().to_string(),
+                },
+            })
+            .collect()
+    }
+}
+
+impl ShardLayout {
+    fn new(layout: &WorkspaceLayout) -> Self {
+        let mut out = Self::default();
+        out.reload(layout);
+        out.unindexed.clear();
+        out
+    }
+
+    fn contains(&self, shard_id: ShardId) -> bool {
+        self.shards.iter().any(|(id, _)| *id == shard_id)
+    }
+
+    fn shard_ids(&self) -> Vec<ShardId> {
+        self.shards.iter().map(|(id, _)| *id).collect()
+    }
+
+    fn roots(&self) -> Vec<(ShardId, PathBuf)> {
+        self.shards.clone()
+    }
+
+    fn root(&self, shard_id: ShardId) -> Option<&Path> {
+        self.shards
+            .iter()
+            .find_map(|(id, root)| (*id == shard_id).then_some(root.as_path()))
+    }
+
+    fn shard_for_path(&self, path: &Path) -> Option<ShardId> {
+        self.shards
+            .iter()
+            .filter(|(_, root)| path.starts_with(root))
+            .max_by_key(|(_, root)| root.components().count())
+            .map(|(id, _)| *id)
+    }
+
+    /// Group `changes` by owning shard, in shard id order.
+    fn group_file_changes(&self, changes: Vec<FileChange>) -> Result<Vec<ShardFileChanges>> {
+        let mut groups: std::collections::BTreeMap<ShardId, ShardFileChanges> = Default::default();
+        for change in changes {
+            let shard_id = self
+                .shard_for_path(change.path())
+                .ok_or_else(|| anyhow!("file {:?} not in any source root", change.path()))?;
+            let group = groups.entry(shard_id).or_insert_with(|| {
+                let root = self
+                    .root(shard_id)
+                    .map(Path::to_path_buf)
+                    .unwrap_or_default();
+                ShardFileChanges {
+                    shard_id,
+                    nested_roots: self.nested_roots(&root),
+                    root,
+                    changes: Vec::new(),
+                }
+            });
+            group.changes.push(change);
+        }
+        Ok(groups.into_values().collect())
+    }
+
+    /// The other roots located inside `root`, whose files belong to their own shards.
+    fn nested_roots(&self, root: &Path) -> Vec<PathBuf> {
+        self.shards
+            .iter()
+            .filter(|(_, other)| other != root && other.starts_with(root))
+            .map(|(_, other)| other.clone())
+            .collect()
+    }
+
+    /// The shards `index_workspace` should index, and the layout generation they belong to.
+    ///
+    /// Right after a reload these are only the shards it left unindexed; otherwise every shard.
+    fn index_targets(&self) -> (u64, Vec<(ShardId, PathBuf)>) {
+        let targets = if self.unindexed.is_empty() {
+            self.roots()
+        } else {
+            self.shards
+                .iter()
+                .filter(|(id, _)| self.unindexed.contains(id))
+                .cloned()
+                .collect()
+        };
+        (self.generation, targets)
+    }
+
+    /// Record that `shard_id` was indexed with its files as of `generation`.
+    fn mark_indexed(&mut self, shard_id: ShardId, generation: u64) {
+        if generation == self.generation {
+            self.unindexed.remove(&shard_id);
+        }
+    }
+
+    fn reload(&mut self, layout: &WorkspaceLayout) -> LayoutDiff {
+        let before = self.clone();
+        let mut previous = std::mem::take(&mut self.shards);
+        let mut diff = LayoutDiff::default();
+
+        for root in &layout.source_roots {
+            let existing = previous
+                .iter()
+                .position(|(_, path)| path == &root.path)
+                .map(|idx| previous.remove(idx));
+            let entry = match existing {
+                Some(entry) => entry,
+                None => {
+                    let shard_id = self.next_shard_id;
+                    self.next_shard_id += 1;
+                    diff.added.push((shard_id, root.path.clone()));
+                    (shard_id, root.path.clone())
+                }
+            };
+            self.shards.push(entry);
+        }
+        diff.removed = previous;
+
+        self.generation += 1;
+        for (shard_id, _) in &diff.removed {
+            self.unindexed.remove(shard_id);
+        }
+        self.unindexed
+            .extend(diff.added.iter().map(|(shard_id, _)| *shard_id));
+        // Gaining or losing a nested root changes which files an existing shard owns.
+        for (shard_id, root) in &self.shards {
+            if before.root(*shard_id).is_some()
+                && before.nested_roots(root) != self.nested_roots(root)
+            {
+                self.unindexed.insert(*shard_id);
+            }
+        }
+        diff
+    }
+}
+
+fn read_layout(
+    layout: &std::sync::RwLock<ShardLayout>,
+) -> std::sync::RwLockReadGuard<'_, ShardLayout> {
+    layout.read().unwrap_or_else(|err| err.into_inner())
+}
+
+fn write_layout(
+    layout: &std::sync::RwLock<ShardLayout>,
+) -> std::sync::RwLockWriteGuard<'_, ShardLayout> {
+    layout.write().unwrap_or_else(|err| err.into_inner())
+}
+
+struct InProcessRouter {
+    layout: std::sync::RwLock<ShardLayout>,
+    global_revision: AtomicU64,
+    shard_indexes: Mutex<HashMap<ShardId, ShardIndex>>,
+    global_symbols: RwLock<GlobalSymbolIndex>,
+    scheduler: Scheduler,
+    index_token: Mutex<CancellationToken>,
+    progress: Arc<ProgressSubscribers>,
+}
+
+impl InProcessRouter {
+    fn new(layout: WorkspaceLayout) -> Self {
+        let scheduler = tokio::runtime::Handle::try_current()
+            .map(|handle| Scheduler::new_with_io_handle(SchedulerConfig::default(), handle))
+            .unwrap_or_else(|_| Scheduler::default());
+        Self {
+            layout: std::sync::RwLock::new(ShardLayout::new(&layout)),
+            global_revision: AtomicU64::new(0),
+            shard_indexes: Mutex::new(HashMap::new()),
+            global_symbols: RwLock::new(GlobalSymbolIndex::default()),
+            scheduler,
+            index_token: Mutex::new(CancellationToken::new()),
+            progress: Arc::new(ProgressSubscribers::default()),
+        }
+    }
+
+    async fn next_index_token(&self) -> CancellationToken {
+        let mut guard = self.index_token.lock().await;
+        guard.cancel();
+        let token = CancellationToken::new();
+        *guard = token.clone();
+        token
+    }
+
+    /// Returns the shards the run indexed (see [`ShardLayout::index_targets`]).
+    async fn index_workspace_cancelable(&self, cancel: CancellationToken) -> Result<Vec<ShardId>> {
+        if cancel.is_cancelled() {
+            return Err(rpc_cancelled_error());
+        }
+
+        let token = self.next_index_token().await;
+        let revision = self.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
+        let (generation, roots) = {
+            let layout = read_layout(&self.layout);
+            let (generation, targets) = layout.index_targets();
+            let roots = targets
+                .into_iter()
+                .map(|(shard_id, root)| {
+                    let nested = layout.nested_roots(&root);
+                    (shard_id, root, nested)
+                })
+                .collect::<Vec<_>>();
+            (generation, roots)
+        };
+        let shard_ids = roots.iter().map(|(shard_id, _, _)| *shard_id).collect();
+
+        let result = self
+            .index_workspace_run(&cancel, &token, revision, generation, roots)
+            .await;
+        self.progress.emit(match &result {
+            Ok(true) => IndexProgressEvent::WorkspaceIndexCompleted { revision },
+            // A newer run superseded this one.
+            Ok(false) => IndexProgressEvent::Cancelled { revision },
+            Err(err) => IndexProgressEvent::index_error(revision, err),
+        });
+        result.map(|_| shard_ids)
+    }
+
+    /// Index `roots` for `revision`; returns `Ok(false)` if `token` was cancelled by a newer run
+    /// before the results were installed.
+    async fn index_workspace_run(
+        &self,
+        cancel: &CancellationToken,
+        token: &CancellationToken,
+        revision: u64,
+        generation: u64,
+        roots: Vec<(ShardId, PathBuf, Vec<PathBuf>)>,
+    ) -> Result<bool> {
+        let mut indexes = HashMap::new();
+        let mut join_set = JoinSet::new();
+
+        // Index each shard while its directory walk is still discovering files. `ShardStarted` is
+        // reported once the walk has finished (so it carries the final file count), and progress
+        // is held back until then.
+        for (shard_id, root, nested) in roots {
+            let paths = JavaFilePaths::walk(root, nested);
+            let file_count = paths.file_count();
+            let started = Instant::now();
+
+            let progress = Arc::clone(&self.progress);
+            let task = self
+                .scheduler
+                .spawn_background_with_token(token.clone(), move |token| {
+                    Cancelled::check(&token)?;
+                    let reported_start = std::cell::Cell::new(false);
+                    let report_start = || -> bool {
+                        if reported_start.get() {
+                            return true;
+                        }
+                        let Some(&file_count) = file_count.get() else {
+                            return false;
+                        };
+                        reported_start.set(true);
+                        progress.emit(IndexProgressEvent::ShardStarted {
+                            shard_id,
+                            file_count,
+                        });
+                        true
+                    };
+                    let report = |files_done| {
+                        // Don't report progress after the run's terminal event.
+                        if !token.is_cancelled() && report_start() {
+                            progress.emit(IndexProgressEvent::ShardProgress {
+                                shard_id,
+                                files_done,
+                            });
+                        }
+                    };
+                    let symbols = index_for_files(
+                        shard_id,
+                        paths.blocking_iter(),
+                        HashMap::new(),
+                        &token,
+                        &report,
+                    )?;
+                    Cancelled::check(&token)?;
+                    if symbols.is_ok() {
+                        report_start();
+                    }
+                    Ok(symbols)
+                });
+
+            join_set.spawn(async move { (shard_id, started, task.join().await) });
+        }
+
+        while !join_set.is_empty() {
+            tokio::select! {
+                biased;
+                _ = cancel.cancelled() => {
+                    token.cancel();
+                    return Err(rpc_cancelled_error());
+                }
+                _ = token.cancelled() => {
+                    return Ok(false);
+                }
+                res = join_set.join_next() => {
+                    let Some(res) = res else {
+                        continue;
+                    };
 
-                    let (shard_id, symbols) = match res {
-                        Ok((shard_id, res)) => (shard_id, res),
+                    let (shard_id, started, symbols) = match res {
+                        Ok((shard_id, started, res)) => (shard_id, started, res),
                         Err(err) => {
                             // The join task itself should never panic, but surface it as an indexing error.
                             token.cancel();
@@ -667,7 +1463,7 @@ impl InProcessRouter {
 
                     let symbols = match symbols {
                         Ok(symbols) => symbols,
-                        Err(TaskError::Cancelled) => return Ok(()),
+                        Err(TaskError::Cancelled) => return Ok(false),
                         Err(TaskError::Panicked) => {
                             token.cancel();
                             return Err(anyhow!("indexing task panicked"));
@@ -685,6 +1481,11 @@ impl InProcessRouter {
                         }
                     };
 
+                    self.progress.emit(IndexProgressEvent::ShardCompleted {
+                        shard_id,
+                        symbol_count: symbols.len(),
+                        duration: started.elapsed(),
+                    });
                     indexes.insert(
                         shard_id,
                         ShardIndex {
@@ -699,30 +1500,38 @@ impl InProcessRouter {
         }
 
         if token.is_cancelled() {
-            return Ok(());
+            return Ok(false);
         }
         if cancel.is_cancelled() {
             return Err(rpc_cancelled_error());
         }
 
-        let symbols = build_global_symbols(indexes.values());
+        let mut all_indexes = self.shard_indexes.lock().await;
+        let mut merged = all_indexes.clone();
+        let indexed: Vec<ShardId> = indexes.keys().copied().collect();
+        merged.extend(indexes);
+        let symbols = build_global_symbols(merged.values());
 
         // Check cancellation as close to committing as possible so a new indexing run can prevent
         // stale results from being installed (including during `build_global_symbols`).
         if token.is_cancelled() {
-            return Ok(());
+            return Ok(false);
         }
         if cancel.is_cancelled() {
             return Err(rpc_cancelled_error());
         }
 
+        *all_indexes = merged;
+        drop(all_indexes);
         {
-            let mut guard = self.shard_indexes.lock().await;
-            *guard = indexes;
+            let mut layout = write_layout(&self.layout);
+            for shard_id in indexed {
+                layout.mark_indexed(shard_id, generation);
+            }
         }
 
         write_global_symbols(&self.global_symbols, symbols, revision).await;
-        Ok(())
+        Ok(true)
     }
 
     async fn update_file_cancelable(
@@ -730,61 +1539,78 @@ impl InProcessRouter {
         cancel: CancellationToken,
         path: PathBuf,
         text: String,
+    ) -> Result<()> {
+        self.update_files_cancelable(cancel, vec![FileChange::Upsert { path, text }])
+            .await
+    }
+
+    async fn update_files_cancelable(
+        &self,
+        cancel: CancellationToken,
+        changes: Vec<FileChange>,
     ) -> Result<()> {
         if cancel.is_cancelled() {
             return Err(rpc_cancelled_error());
         }
 
+        let groups = read_layout(&self.layout).group_file_changes(changes)?;
         let token = self.next_index_token().await;
-        let shard_id = self
-            .layout
-            .source_roots
-            .iter()
-            .enumerate()
-            .find_map(|(id, root)| path.starts_with(&root.path).then_some(id as ShardId))
-            .ok_or_else(|| anyhow!("file {path:?} not in any source root"))?;
-
         let revision = self.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
 
-        let mut shard_files =
-            collect_java_file_paths(&self.layout.source_roots[shard_id as usize].path).await?;
-        if cancel.is_cancelled() {
-            token.cancel();
-            return Err(rpc_cancelled_error());
-        }
-        let path_str = path.to_string_lossy().to_string();
-        if !shard_files.iter().any(|file| file == &path_str) {
-            shard_files.push(path_str.clone());
-        }
-
-        let task = self
-            .scheduler
-            .spawn_background_with_token(token.clone(), move |token| {
-                Cancelled::check(&token)?;
-                let symbols =
-                    index_for_files(shard_id, shard_files, Some((path_str, text)), &token)?;
-                Cancelled::check(&token)?;
-                Ok(symbols)
-            });
-
-        let join_result = tokio::select! {
-            biased;
-            _ = cancel.cancelled() => {
+        // Re-index every affected shard once, with the whole batch applied.
+        let mut tasks = Vec::with_capacity(groups.len());
+        for group in groups {
+            let mut shard_files = collect_java_file_paths(&group.root, &group.nested_roots).await?;
+            if cancel.is_cancelled() {
                 token.cancel();
                 return Err(rpc_cancelled_error());
             }
-            res = task.join() => res,
-        };
+            let overrides = group.apply_to_paths(&mut shard_files);
+
+            let shard_id = group.shard_id;
+            let task = self
+                .scheduler
+                .spawn_background_with_token(token.clone(), move |token| {
+                    Cancelled::check(&token)?;
+                    let symbols = index_for_files(
+                        shard_id,
+                        shard_files.into_iter().map(Ok),
+                        overrides,
+                        &token,
+                        &|_| {},
+                    )?;
+                    Cancelled::check(&token)?;
+                    Ok(symbols)
+                });
+            tasks.push((shard_id, task));
+        }
 
-        let symbols = match join_result {
-            Ok(symbols) => symbols,
-            Err(TaskError::Cancelled) => return Ok(()),
-            Err(TaskError::Panicked) => return Err(anyhow!("indexing task panicked")),
-            Err(TaskError::DeadlineExceeded(_)) => {
-                return Err(anyhow!("indexing task exceeded deadline"))
-            }
-        };
-        let symbols = symbols?;
+        let mut new_indexes = Vec::with_capacity(tasks.len());
+        for (shard_id, task) in tasks {
+            let join_result = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => {
+                    token.cancel();
+                    return Err(rpc_cancelled_error());
+                }
+                res = task.join() => res,
+            };
+
+            let symbols = match join_result {
+                Ok(symbols) => symbols,
+                Err(TaskError::Cancelled) => return Ok(()),
+                Err(TaskError::Panicked) => return Err(anyhow!("indexing task panicked")),
+                Err(TaskError::DeadlineExceeded(_)) => {
+                    return Err(anyhow!("indexing task exceeded deadline"))
+                }
+            };
+            new_indexes.push(ShardIndex {
+                shard_id,
+                revision,
+                index_generation: revision,
+                symbols: symbols?,
+            });
+        }
 
         if token.is_cancelled() {
             return Ok(());
@@ -792,16 +1618,12 @@ impl InProcessRouter {
         if cancel.is_cancelled() {
             return Err(rpc_cancelled_error());
         }
-        let new_index = ShardIndex {
-            shard_id,
-            revision,
-            index_generation: revision,
-            symbols,
-        };
 
         let indexes_snapshot = {
             let mut guard = self.shard_indexes.lock().await;
-            guard.insert(shard_id, new_index);
+            for index in new_indexes {
+                guard.insert(index.shard_id, index);
+            }
             guard.clone()
         };
 
@@ -810,6 +1632,86 @@ impl InProcessRouter {
         Ok(())
     }
 
+    async fn find_references_cancelable(
+        &self,
+        cancel: CancellationToken,
+        symbol: SymbolKey,
+    ) -> Result<FindReferencesResult> {
+        if cancel.is_cancelled() {
+            return Err(rpc_cancelled_error());
+        }
+
+        let roots = {
+            let layout = read_layout(&self.layout);
+            layout
+                .roots()
+                .into_iter()
+                .map(|(shard_id, root)| {
+                    let nested = layout.nested_roots(&root);
+                    (shard_id, root, nested)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut partial = Vec::new();
+        let mut tasks = Vec::with_capacity(roots.len());
+        for (shard_id, root, nested) in roots {
+            let files = match collect_java_file_paths(&root, &nested).await {
+                Ok(files) => files,
+                Err(err) => {
+                    warn!(shard_id, error = ?err, "find references: failed to collect shard files");
+                    partial.push(shard_id);
+                    continue;
+                }
+            };
+            let name = symbol.name.clone();
+            let task = self
+                .scheduler
+                .spawn_background_with_token(cancel.clone(), move |token| {
+                    find_references_in_files(files, &name, &token)
+                });
+            tasks.push((shard_id, task));
+        }
+
+        let mut shard_references = Vec::with_capacity(tasks.len());
+        for (shard_id, task) in tasks {
+            match task.join().await {
+                Ok(Ok(references)) => shard_references.push(references),
+                Ok(Err(err)) => {
+                    warn!(shard_id, error = ?err, "find references: failed to search shard");
+                    partial.push(shard_id);
+                }
+                Err(TaskError::Cancelled) => return Err(rpc_cancelled_error()),
+                Err(TaskError::Panicked) => return Err(anyhow!("find references task panicked")),
+                Err(TaskError::DeadlineExceeded(_)) => {
+                    return Err(anyhow!("find references task exceeded deadline"))
+                }
+            }
+        }
+
+        partial.sort_unstable();
+        Ok(FindReferencesResult {
+            references: merge_references(shard_references),
+            partial,
+        })
+    }
+
+    async fn shard_health(&self) -> Vec<ShardHealth> {
+        let roots = read_layout(&self.layout).roots();
+        let indexes = self.shard_indexes.lock().await;
+        roots
+            .into_iter()
+            .map(|(shard_id, root)| ShardHealth {
+                shard_id,
+                root,
+                status: ShardStatus::Healthy {
+                    last_index_revision: indexes.get(&shard_id).map(|index| index.revision),
+                },
+                index_from_cache: false,
+            })
+            .collect()
+    }
+
     fn worker_stats(&self) -> HashMap<ShardId, WorkerStats> {
         HashMap::new()
     }
@@ -818,19 +1720,38 @@ impl InProcessRouter {
         let guard = self.global_symbols.read().await;
         guard.search(query, WORKSPACE_SYMBOL_LIMIT)
     }
+
+    async fn update_layout(&self, layout: WorkspaceLayout) -> Result<LayoutDiff> {
+        // Cancel in-flight indexing so it cannot install results computed for the old layout.
+        let _token = self.next_index_token().await;
+        let diff = write_layout(&self.layout).reload(&layout);
+        let revision = self.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let indexes_snapshot = {
+            let mut guard = self.shard_indexes.lock().await;
+            for (shard_id, _) in &diff.removed {
+                guard.remove(shard_id);
+            }
+            guard.clone()
+        };
+
+        let symbols = build_global_symbols(indexes_snapshot.values());
+        write_global_symbols(&self.global_symbols, symbols, revision).await;
+        Ok(diff)
+    }
 }
 
 struct DistributedRouter {
     state: Arc<RouterState>,
     accept_task: Mutex<Option<JoinHandle<()>>>,
-    worker_supervisors: Mutex<Vec<JoinHandle<()>>>,
+    worker_supervisors: Mutex<HashMap<ShardId, WorkerSupervisor>>,
     shutdown_tx: watch::Sender<bool>,
     bound_listen_addr_rx: watch::Receiver<Option<ListenAddr>>,
 }
 
 struct RouterState {
     config: DistributedRouterConfig,
-    layout: WorkspaceLayout,
+    layout: std::sync::RwLock<ShardLayout>,
     next_worker_id: AtomicU32,
     global_revision: AtomicU64,
     shards: Mutex<HashMap<ShardId, ShardState>>,
@@ -841,13 +1762,312 @@ struct RouterState {
     handshake_semaphore: Arc<Semaphore>,
     connection_semaphore: Arc<Semaphore>,
     shard_snapshot_semaphore: Arc<Semaphore>,
+    query_fanout_semaphore: Arc<Semaphore>,
     bound_listen_addr_tx: watch::Sender<Option<ListenAddr>>,
+    progress: ProgressSubscribers,
+    /// Revision of the in-flight `IndexShard` request of each shard; worker `IndexProgress`
+    /// notifications for any other revision are dropped.
+    indexing_shards: std::sync::Mutex<HashMap<ShardId, u64>>,
+    /// The diagnostics call in flight for each file.
+    diagnostics_calls: std::sync::Mutex<HashMap<(ShardId, PathBuf), DiagnosticsCall>>,
+    next_diagnostics_call_id: AtomicU64,
+    /// Serializes writes to the router's shard index cache, so the last write of a shard is of
+    /// its latest index.
+    index_cache_lock: Mutex<()>,
+}
+
+impl RouterState {
+    async fn shard_index_applied(&self, shard_id: ShardId, revision: u64) {
+        if let Some(shard) = self.shards.lock().await.get_mut(&shard_id) {
+            shard.index_applied(revision);
+        }
+    }
+
+    /// Write the current index of `shard_id` to the router's cache, with the fingerprint of its
+    /// source root, so the next session can serve it before its first index run completes.
+    /// Failures are logged and otherwise ignored.
+    async fn persist_shard_index(&self, shard_id: ShardId) {
+        let _guard = self.index_cache_lock.lock().await;
+        let Some((root, nested)) = ({
+            let layout = read_layout(&self.layout);
+            layout
+                .root(shard_id)
+                .map(|root| (root.to_path_buf(), layout.nested_roots(root)))
+        }) else {
+            return;
+        };
+        let Some(index) = self.shard_indexes.lock().await.get(&shard_id).cloned() else {
+            return;
+        };
+
+        let result = async {
+            let fingerprint = shard_root_fingerprint(&root, &nested).await?;
+            let cache_dir = self.config.cache_dir.clone();
+            tokio::task::spawn_blocking(move || {
+                nova_cache::save_router_shard_index(&cache_dir, &root, fingerprint, &index)
+            })
+            .await??;
+            anyhow::Ok(())
+        }
+        .await;
+        if let Err(err) = result {
+            warn!(shard_id, error = ?err, "failed to persist shard index");
+        }
+    }
+
+    /// Record a failed request to the shard's worker in its health (cancellation is not a
+    /// failure).
+    async fn shard_request_failed(&self, shard_id: ShardId, err: &anyhow::Error) {
+        if is_cancelled_error(err) || err.downcast_ref::<ShardUnavailable>().is_some() {
+            return;
+        }
+        if let Some(shard) = self.shards.lock().await.get_mut(&shard_id) {
+            shard.request_failed(err);
+        }
+    }
+
+    async fn set_shard_restarting(&self, shard_id: ShardId, restarting: bool) {
+        if let Some(shard) = self.shards.lock().await.get_mut(&shard_id) {
+            shard.restarting = restarting;
+        }
+        if restarting {
+            // Fail the requests waiting for this shard's worker.
+            self.notify.notify_waiters();
+        }
+    }
+
+    async fn worker_exited(
+        &self,
+        shard_id: ShardId,
+        worker_id: Option<WorkerId>,
+        reason: WorkerExitReason,
+    ) {
+        if let Some(shard) = self.shards.lock().await.get_mut(&shard_id) {
+            shard.worker_exited(worker_id, reason);
+        }
+    }
+
+    /// Record that the process of worker `worker_id` exited, dropping it if it is still the
+    /// shard's worker (returns whether it was). A worker the router severed for a protocol
+    /// violation keeps that reason.
+    async fn worker_process_exited(
+        &self,
+        shard_id: ShardId,
+        worker_id: WorkerId,
+        code: Option<i32>,
+    ) -> bool {
+        let mut guard = self.shards.lock().await;
+        let Some(shard) = guard.get_mut(&shard_id) else {
+            return false;
+        };
+        let reason = WorkerExitReason::Exited { code };
+        if shard.current_worker_lost(worker_id, reason.clone()) {
+            return true;
+        }
+        let severed = shard.last_exit.as_ref().is_some_and(|exit| {
+            exit.worker_id == Some(worker_id) && exit.reason == WorkerExitReason::ProtocolViolation
+        });
+        if !severed {
+            shard.worker_exited(Some(worker_id), reason);
+        }
+        false
+    }
+
+    async fn set_worker_restart_state(
+        &self,
+        shard_id: ShardId,
+        restart_count: u64,
+        backoff: Duration,
+    ) {
+        if let Some(shard) = self.shards.lock().await.get_mut(&shard_id) {
+            shard.restart_count = restart_count;
+            shard.restart_backoff = backoff;
+        }
+    }
+
+    async fn worker_supervision_info(&self) -> Vec<WorkerSupervisionInfo> {
+        let guard = self.shards.lock().await;
+        let mut info: Vec<WorkerSupervisionInfo> = guard
+            .iter()
+            .map(|(shard_id, shard)| WorkerSupervisionInfo {
+                shard_id: *shard_id,
+                worker_id: shard.worker.as_ref().map(|w| w.worker_id),
+                restart_count: shard.restart_count,
+                backoff: shard.restart_backoff,
+                last_exit: shard.last_exit.clone(),
+            })
+            .collect();
+        info.sort_by_key(|shard| shard.shard_id);
+        info
+    }
+
+    async fn shard_health(&self) -> Vec<ShardHealth> {
+        let guard = self.shards.lock().await;
+        let mut health: Vec<ShardHealth> = guard
+            .iter()
+            .map(|(shard_id, shard)| ShardHealth {
+                shard_id: *shard_id,
+                root: shard.root.clone(),
+                status: shard.status.clone(),
+                index_from_cache: shard.index_from_cache,
+            })
+            .collect();
+        health.sort_by_key(|shard| shard.shard_id);
+        health
+    }
+
+    fn lock_indexing_shards(&self) -> std::sync::MutexGuard<'_, HashMap<ShardId, u64>> {
+        self.indexing_shards
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn lock_diagnostics_calls(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<(ShardId, PathBuf), DiagnosticsCall>> {
+        self.diagnostics_calls
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn shard_indexing_started(&self, shard_id: ShardId, revision: u64, file_count: usize) {
+        self.lock_indexing_shards().insert(shard_id, revision);
+        self.progress.emit(IndexProgressEvent::ShardStarted {
+            shard_id,
+            file_count,
+        });
+    }
+
+    /// Forward a worker `IndexProgress` notification if it belongs to the shard's in-flight
+    /// `IndexShard` request.
+    fn shard_indexing_progress(&self, shard_id: ShardId, revision: u64, files_done: u32) {
+        // Emit while holding the lock so progress can't be reported after `ShardCompleted`.
+        let guard = self.lock_indexing_shards();
+        if guard.get(&shard_id) == Some(&revision) {
+            self.progress.emit(IndexProgressEvent::ShardProgress {
+                shard_id,
+                files_done: files_done as usize,
+            });
+        }
+    }
+
+    fn shard_indexing_completed(&self, shard_id: ShardId, symbol_count: usize, duration: Duration) {
+        self.lock_indexing_shards().remove(&shard_id);
+        self.progress.emit(IndexProgressEvent::ShardCompleted {
+            shard_id,
+            symbol_count,
+            duration,
+        });
+    }
+
+    /// Emit the terminal event of the run for `revision`, after which none of its shards report
+    /// progress.
+    fn workspace_indexing_finished(&self, revision: u64, event: IndexProgressEvent) {
+        self.lock_indexing_shards()
+            .retain(|_, shard_revision| *shard_revision != revision);
+        self.progress.emit(event);
+    }
 }
 
 struct ShardState {
     root: PathBuf,
     worker: Option<WorkerHandle>,
     pending_worker: Option<WorkerId>,
+    status: ShardStatus,
+    /// Set by the worker supervisor while it waits to restart a failed worker; requests for the
+    /// shard fail with [`ShardUnavailable`] instead of waiting for a worker.
+    restarting: bool,
+    last_index_revision: Option<u64>,
+    /// See [`ShardHealth::index_from_cache`].
+    index_from_cache: bool,
+    restart_count: u64,
+    restart_backoff: Duration,
+    last_exit: Option<WorkerExit>,
+}
+
+impl ShardState {
+    fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            worker: None,
+            pending_worker: None,
+            status: ShardStatus::NoWorker {
+                since: std::time::Instant::now(),
+            },
+            restarting: false,
+            last_index_revision: None,
+            index_from_cache: false,
+            restart_count: 0,
+            restart_backoff: Duration::ZERO,
+            last_exit: None,
+        }
+    }
+
+    fn worker_connected(&mut self, worker: WorkerHandle) {
+        self.worker = Some(worker);
+        self.restarting = false;
+        self.status = ShardStatus::Healthy {
+            last_index_revision: self.last_index_revision,
+        };
+    }
+
+    fn worker_lost(&mut self) {
+        self.worker = None;
+        if !matches!(self.status, ShardStatus::NoWorker { .. }) {
+            self.status = ShardStatus::NoWorker {
+                since: std::time::Instant::now(),
+            };
+        }
+    }
+
+    fn worker_exited(&mut self, worker_id: Option<WorkerId>, reason: WorkerExitReason) {
+        self.last_exit = Some(WorkerExit {
+            worker_id,
+            at: std::time::Instant::now(),
+            reason,
+        });
+    }
+
+    /// Drop the connected worker `worker_id` (if it is still the shard's worker), recording why.
+    /// Returns whether it was.
+    fn current_worker_lost(&mut self, worker_id: WorkerId, reason: WorkerExitReason) -> bool {
+        if self.pending_worker == Some(worker_id) {
+            self.pending_worker = None;
+        }
+        if self
+            .worker
+            .as_ref()
+            .is_none_or(|w| w.worker_id != worker_id)
+        {
+            return false;
+        }
+        self.worker_lost();
+        self.worker_exited(Some(worker_id), reason);
+        true
+    }
+
+    fn index_applied(&mut self, revision: u64) {
+        self.last_index_revision = Some(revision);
+        self.index_from_cache = false;
+        if self.worker.is_some() {
+            self.status = ShardStatus::Healthy {
+                last_index_revision: Some(revision),
+            };
+        }
+    }
+
+    fn request_failed(&mut self, err: &anyhow::Error) {
+        let stale_since = match self.status {
+            // Without a worker the shard is already reported as down.
+            ShardStatus::NoWorker { .. } => return,
+            ShardStatus::Degraded { stale_since, .. } => stale_since,
+            ShardStatus::Healthy { .. } => std::time::Instant::now(),
+        };
+        self.status = ShardStatus::Degraded {
+            stale_since,
+            last_error: format!("{err:#}"),
+        };
+    }
 }
 
 #[derive(Clone)]
@@ -855,13 +2075,16 @@ struct WorkerHandle {
     shard_id: ShardId,
     worker_id: WorkerId,
     conn: RpcConnection,
+    diagnostics_semaphore: Arc<Semaphore>,
 }
 
 impl DistributedRouter {
     async fn new(config: DistributedRouterConfig, layout: WorkspaceLayout) -> Result<Self> {
         let mut config = config;
-        if config.spawn_workers && config.auth_token.is_none() {
-            config.auth_token = Some(ipc_security::generate_auth_token()?);
+        if config.spawn_workers && config.auth.is_none() {
+            config.auth = Some(AuthValidator::from_token(
+                ipc_security::generate_auth_token()?,
+            ));
         }
 
         config.validate()?;
@@ -886,21 +2109,15 @@ impl DistributedRouter {
             worker_command = %config.worker_command.display(),
             "starting distributed router"
         );
+        let layout = ShardLayout::new(&layout);
         let mut shards = HashMap::new();
-        for (idx, root) in layout.source_roots.iter().enumerate() {
-            shards.insert(
-                idx as ShardId,
-                ShardState {
-                    root: root.path.clone(),
-                    worker: None,
-                    pending_worker: None,
-                },
-            );
+        for (shard_id, root) in layout.roots() {
+            shards.insert(shard_id, ShardState::new(root));
         }
 
         let state = Arc::new(RouterState {
             config: config.clone(),
-            layout,
+            layout: std::sync::RwLock::new(layout),
             next_worker_id: AtomicU32::new(1),
             global_revision: AtomicU64::new(0),
             shards: Mutex::new(shards),
@@ -911,8 +2128,15 @@ impl DistributedRouter {
             handshake_semaphore,
             connection_semaphore,
             shard_snapshot_semaphore,
+            query_fanout_semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_FANOUT_QUERIES)),
             bound_listen_addr_tx,
+            progress: ProgressSubscribers::default(),
+            indexing_shards: std::sync::Mutex::new(HashMap::new()),
+            diagnostics_calls: std::sync::Mutex::new(HashMap::new()),
+            next_diagnostics_call_id: AtomicU64::new(0),
+            index_cache_lock: Mutex::new(()),
         });
+        load_cached_shard_indexes(&state).await;
 
         let accept_state = state.clone();
         let accept_shutdown_rx = shutdown_rx.clone();
@@ -927,15 +2151,14 @@ impl DistributedRouter {
             }
         });
 
-        let mut worker_supervisors = Vec::new();
+        let mut worker_supervisors = HashMap::new();
         if config.spawn_workers {
-            for shard_id in 0..(state.layout.source_roots.len() as ShardId) {
-                let supervisor_state = state.clone();
-                let mut supervisor_shutdown_rx = shutdown_rx.clone();
-                worker_supervisors.push(tokio::spawn(async move {
-                    worker_supervisor_loop(supervisor_state, shard_id, &mut supervisor_shutdown_rx)
-                        .await;
-                }));
+            let shard_ids = read_layout(&state.layout).shard_ids();
+            for shard_id in shard_ids {
+                worker_supervisors.insert(
+                    shard_id,
+                    spawn_worker_supervisor(state.clone(), shard_id, shutdown_rx.clone()),
+                );
             }
         }
 
@@ -961,13 +2184,35 @@ impl DistributedRouter {
         }
     }
 
-    async fn index_workspace_cancelable(&self, cancel: CancellationToken) -> Result<()> {
+    async fn index_workspace_cancelable(
+        &self,
+        cancel: CancellationToken,
+        partial: bool,
+    ) -> Result<IndexSummary> {
         if cancel.is_cancelled() {
             return Err(rpc_cancelled_error());
         }
 
         let revision = self.state.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
-        if self.state.layout.source_roots.is_empty() {
+        let result = self.index_workspace_run(cancel, revision, partial).await;
+        let event = match &result {
+            Ok(_) => IndexProgressEvent::WorkspaceIndexCompleted { revision },
+            Err(err) => IndexProgressEvent::index_error(revision, err),
+        };
+        self.state.workspace_indexing_finished(revision, event);
+        result
+    }
+
+    /// Index every shard for `revision`. In `partial` mode shards that fail are recorded in the
+    /// summary (keeping their previous index) instead of failing the whole run.
+    async fn index_workspace_run(
+        &self,
+        cancel: CancellationToken,
+        revision: u64,
+        partial: bool,
+    ) -> Result<IndexSummary> {
+        let (generation, roots) = read_layout(&self.state.layout).index_targets();
+        if roots.is_empty() {
             {
                 let mut guard = self.state.shard_indexes.lock().await;
                 guard.clear();
@@ -978,116 +2223,123 @@ impl DistributedRouter {
                 .fetch_add(1, Ordering::SeqCst)
                 + 1;
             write_global_symbols(&self.state.global_symbols, Vec::new(), update_id).await;
-            return Ok(());
+            return Ok(IndexSummary::default());
         }
 
         let mut join_set = JoinSet::new();
         let snapshot_semaphore = Arc::clone(&self.state.shard_snapshot_semaphore);
-        for shard_id in 0..(self.state.layout.source_roots.len() as ShardId) {
+        for (shard_id, root) in roots {
             let state = self.state.clone();
-            let root = self.state.layout.source_roots[shard_id as usize]
-                .path
-                .clone();
             let cancel = cancel.clone();
             let snapshot_semaphore = Arc::clone(&snapshot_semaphore);
 
             join_set.spawn(async move {
-                if cancel.is_cancelled() {
-                    return Err(rpc_cancelled_error());
-                }
-
-                let worker = wait_for_worker_cancelable(state.clone(), shard_id, &cancel)
-                    .await
-                    .with_context(|| format!("wait for worker for shard {shard_id}"))?;
+                let result = async move {
+                    if cancel.is_cancelled() {
+                        return Err(rpc_cancelled_error());
+                    }
 
-                if cancel.is_cancelled() {
-                    return Err(rpc_cancelled_error());
-                }
+                    let worker = wait_for_worker_inner(state.clone(), shard_id, &cancel, partial)
+                        .await
+                        .with_context(|| format!("wait for worker for shard {shard_id}"))?;
 
-                // Limit how many shards can build + send full file snapshots concurrently.
-                let snapshot_permit: OwnedSemaphorePermit = snapshot_semaphore
-                    .acquire_owned()
-                    .await
-                    .map_err(|_| anyhow!("file snapshot semaphore closed"))?;
+                    if cancel.is_cancelled() {
+                        return Err(rpc_cancelled_error());
+                    }
 
-                let files = collect_java_files(&root).await.with_context(|| {
-                    format!("collect files for shard {shard_id} ({})", root.display())
-                })?;
+                    // Limit how many shards can build + send full file snapshots concurrently.
+                    let snapshot_permit: OwnedSemaphorePermit = snapshot_semaphore
+                        .acquire_owned()
+                        .await
+                        .map_err(|_| anyhow!("file snapshot semaphore closed"))?;
 
-                if cancel.is_cancelled() {
-                    return Err(rpc_cancelled_error());
-                }
+                    let nested = read_layout(&state.layout).nested_roots(&root);
+                    let files = collect_java_files(&root, &nested).await.with_context(|| {
+                        format!("collect files for shard {shard_id} ({})", root.display())
+                    })?;
 
-                // Start the RPC call (which serializes/writes the full snapshot) while holding the
-                // snapshot permit, then drop it before waiting for the worker to finish indexing so
-                // the next shard can begin snapshotting.
-                let pending: PendingCall = match timeout(
-                    WORKER_RPC_WRITE_TIMEOUT,
-                    worker.conn.start_call(Request::IndexShard { revision, files }),
-                )
-                .await
-                {
-                    Ok(Ok(pending)) => pending,
-                    Ok(Err(err)) => {
-                        return Err(anyhow!(err)).with_context(|| {
-                            format!(
-                                "send request to worker {} (shard {})",
-                                worker.worker_id, worker.shard_id
-                            )
-                        });
-                    }
-                    Err(_) => {
-                        let _ = worker.conn.shutdown().await;
-                        return Err(anyhow!(
-                            "timed out writing request to worker {} (shard {})",
-                            worker.worker_id,
-                            worker.shard_id
-                        ));
+                    if cancel.is_cancelled() {
+                        return Err(rpc_cancelled_error());
                     }
-                };
 
-                drop(snapshot_permit);
+                    state.shard_indexing_started(shard_id, revision, files.len());
+                    let started = Instant::now();
 
-                let request_id = pending.request_id();
+                    // Start the RPC call (which serializes/writes the full snapshot) while holding the
+                    // snapshot permit, then drop it before waiting for the worker to finish indexing so
+                    // the next shard can begin snapshotting.
+                    let pending: PendingCall = match timeout(
+                        WORKER_RPC_WRITE_TIMEOUT,
+                        worker.conn.start_call(Request::IndexShard { revision, files }),
+                    )
+                    .await
+                    {
+                        Ok(Ok(pending)) => pending,
+                        Ok(Err(err)) => {
+                            return Err(anyhow!(err)).with_context(|| {
+                                format!(
+                                    "send request to worker {} (shard {})",
+                                    worker.worker_id, worker.shard_id
+                                )
+                            });
+                        }
+                        Err(_) => {
+                            let _ = worker.conn.shutdown().await;
+                            return Err(anyhow!(
+                                "timed out writing request to worker {} (shard {})",
+                                worker.worker_id,
+                                worker.shard_id
+                            ));
+                        }
+                    };
 
-                let resp = tokio::select! {
-                    biased;
-                    _ = cancel.cancelled() => {
-                        // Best-effort: cancellation is advisory and the caller may already have moved on.
-                        let _ = timeout(WORKER_RPC_CANCEL_TIMEOUT, worker.conn.cancel(request_id)).await;
-                        return Err(rpc_cancelled_error());
-                    }
-                    res = timeout(WORKER_RPC_READ_TIMEOUT, pending.wait()) => {
-                        match res {
-                            Ok(Ok(resp)) => resp,
-                            Ok(Err(err)) => match err {
-                                nova_remote_rpc::RpcError::Canceled => return Err(rpc_cancelled_error()),
-                                err => {
-                                    return Err(anyhow!(err)).with_context(|| {
-                                        format!(
-                                            "receive response from worker {} (shard {})",
-                                            worker.worker_id, worker.shard_id
-                                        )
-                                    })
+                    drop(snapshot_permit);
+
+                    let request_id = pending.request_id();
+
+                    let resp = tokio::select! {
+                        biased;
+                        _ = cancel.cancelled() => {
+                            // Best-effort: cancellation is advisory and the caller may already have moved on.
+                            let _ = timeout(WORKER_RPC_CANCEL_TIMEOUT, worker.conn.cancel(request_id)).await;
+                            return Err(rpc_cancelled_error());
+                        }
+                        res = timeout(WORKER_RPC_READ_TIMEOUT, pending.wait()) => {
+                            match res {
+                                Ok(Ok(resp)) => resp,
+                                Ok(Err(err)) => match err {
+                                    nova_remote_rpc::RpcError::Canceled => return Err(rpc_cancelled_error()),
+                                    err => {
+                                        return Err(anyhow!(err)).with_context(|| {
+                                            format!(
+                                                "receive response from worker {} (shard {})",
+                                                worker.worker_id, worker.shard_id
+                                            )
+                                        })
+                                    }
+                                },
+                                Err(_) => {
+                                    let _ = worker.conn.shutdown().await;
+                                    return Err(anyhow!(
+                                        "timed out waiting for response from worker {} (shard {})",
+                                        worker.worker_id,
+                                        worker.shard_id
+                                    ));
                                 }
-                            },
-                            Err(_) => {
-                                let _ = worker.conn.shutdown().await;
-                                return Err(anyhow!(
-                                    "timed out waiting for response from worker {} (shard {})",
-                                    worker.worker_id,
-                                    worker.shard_id
-                                ));
                             }
                         }
-                    }
-                };
+                    };
 
-                Ok::<_, anyhow::Error>((shard_id, worker, resp))
+                    Ok::<_, anyhow::Error>((worker, started, resp))
+                }
+                .await;
+                (shard_id, result)
             });
         }
 
+        let mut summary = IndexSummary::default();
         let mut updated_any = false;
+        let mut applied_shards = Vec::new();
         let mut error: Option<anyhow::Error> = None;
         let mut cancelled = false;
 
@@ -1105,14 +2357,22 @@ impl DistributedRouter {
                 break;
             };
 
-            let (shard_id, worker, resp) = match res {
-                Ok(Ok(res)) => res,
-                Ok(Err(err)) => {
-                    error = Some(err);
+            let (shard_id, result) = match res {
+                Ok(res) => res,
+                Err(err) => {
+                    error = Some(anyhow!("indexing task panicked: {err}"));
                     break;
                 }
+            };
+            let (worker, started, resp) = match result {
+                Ok(res) => res,
                 Err(err) => {
-                    error = Some(anyhow!("indexing task panicked: {err}"));
+                    self.state.shard_request_failed(shard_id, &err).await;
+                    if partial && !is_cancelled_error(&err) {
+                        summary.skipped.push((shard_id, format!("{err:#}")));
+                        continue;
+                    }
+                    error = Some(err);
                     break;
                 }
             };
@@ -1127,13 +2387,22 @@ impl DistributedRouter {
                         ));
                         break;
                     }
+                    self.state.shard_indexing_completed(
+                        shard_id,
+                        index.symbols.len(),
+                        started.elapsed(),
+                    );
 
                     // Apply the shard index immediately, but defer rebuilding the global symbol
                     // index until the end to avoid quadratic rebuild work.
+                    let index_revision = index.revision;
                     let applied = {
                         let incoming_key = (index.revision, index.index_generation);
                         let mut guard = self.state.shard_indexes.lock().await;
-                        if let Some(current) = guard.get(&shard_id) {
+                        if !read_layout(&self.state.layout).contains(shard_id) {
+                            // The shard was removed by a layout reload while it was indexing.
+                            false
+                        } else if let Some(current) = guard.get(&shard_id) {
                             let current_key = (current.revision, current.index_generation);
                             if incoming_key < current_key {
                                 false
@@ -1152,7 +2421,15 @@ impl DistributedRouter {
                             true
                         }
                     };
+                    if applied {
+                        applied_shards.push(shard_id);
+                        write_layout(&self.state.layout).mark_indexed(shard_id, generation);
+                        self.state
+                            .shard_index_applied(shard_id, index_revision)
+                            .await;
+                    }
                     updated_any |= applied;
+                    summary.indexed.push(shard_id);
                 }
                 other => {
                     error = Some(anyhow!("unexpected worker response: {other:?}"));
@@ -1188,12 +2465,17 @@ impl DistributedRouter {
             symbols.dedup();
             write_global_symbols(&self.state.global_symbols, symbols, update_id).await;
         }
+        for shard_id in applied_shards {
+            self.state.persist_shard_index(shard_id).await;
+        }
 
         if let Some(err) = error {
             return Err(err);
         }
 
-        Ok(())
+        summary.indexed.sort_unstable();
+        summary.skipped.sort_by_key(|(shard_id, _)| *shard_id);
+        Ok(summary)
     }
 
     async fn update_file_cancelable(
@@ -1206,28 +2488,129 @@ impl DistributedRouter {
             return Err(rpc_cancelled_error());
         }
 
-        let shard_id = self
-            .state
-            .layout
-            .source_roots
-            .iter()
-            .enumerate()
-            .find_map(|(id, root)| path.starts_with(&root.path).then_some(id as ShardId))
+        let shard_id = read_layout(&self.state.layout)
+            .shard_for_path(&path)
             .ok_or_else(|| anyhow!("file {path:?} not in any source root"))?;
 
         let revision = self.state.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
-        let worker = wait_for_worker_cancelable(self.state.clone(), shard_id, &cancel).await?;
+        let worker =
+            wait_for_available_worker_cancelable(self.state.clone(), shard_id, &cancel).await?;
         let file = FileText {
             path: path.to_string_lossy().to_string(),
             text,
         };
 
-        let resp = worker_call_cancelable(&worker, &cancel, Request::UpdateFile { revision, file })
-            .await?;
+        let resp =
+            worker_call_cancelable(&worker, &cancel, Request::UpdateFile { revision, file }).await;
+        let resp = match resp {
+            Ok(resp) => resp,
+            Err(err) => {
+                self.state.shard_request_failed(shard_id, &err).await;
+                return Err(err);
+            }
+        };
+        self.apply_update_response(&worker, resp).await
+    }
+
+    async fn update_files_cancelable(
+        &self,
+        cancel: CancellationToken,
+        changes: Vec<FileChange>,
+    ) -> Result<()> {
+        if cancel.is_cancelled() {
+            return Err(rpc_cancelled_error());
+        }
+
+        let groups = read_layout(&self.state.layout).group_file_changes(changes)?;
+        let revision = self.state.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
+        for group in groups {
+            let worker =
+                wait_for_available_worker_cancelable(self.state.clone(), group.shard_id, &cancel)
+                    .await?;
+            let request = Request::UpdateFiles {
+                revision,
+                changes: group.remote_changes(),
+            };
+            let resp = match worker_call_cancelable(&worker, &cancel, request).await {
+                Err(err) if is_unknown_request_error(&err) => {
+                    self.update_files_legacy(&cancel, &worker, revision, group)
+                        .await?;
+                    continue;
+                }
+                Err(err) => {
+                    self.state.shard_request_failed(group.shard_id, &err).await;
+                    return Err(err);
+                }
+                Ok(resp) => resp,
+            };
+            self.apply_update_response(&worker, resp).await?;
+        }
+        Ok(())
+    }
+
+    /// Apply `group` on a worker that predates `Request::UpdateFiles`.
+    ///
+    /// Upserts are sent one `UpdateFile` at a time; a batch with deletions is sent as a full
+    /// `IndexShard` snapshot since older workers cannot drop individual files.
+    async fn update_files_legacy(
+        &self,
+        cancel: &CancellationToken,
+        worker: &WorkerHandle,
+        revision: u64,
+        group: ShardFileChanges,
+    ) -> Result<()> {
+        let has_deletions = group
+            .changes
+            .iter()
+            .any(|change| matches!(change, FileChange::Delete { .. }));
+        if !has_deletions {
+            for change in group.changes {
+                let FileChange::Upsert { path, text } = change else {
+                    continue;
+                };
+                let file = FileText {
+                    path: path.to_string_lossy().to_string(),
+                    text,
+                };
+                let resp =
+                    worker_call_cancelable(worker, cancel, Request::UpdateFile { revision, file })
+                        .await?;
+                self.apply_update_response(worker, resp).await?;
+            }
+            return Ok(());
+        }
+
+        let mut files = collect_java_files(&group.root, &group.nested_roots).await?;
+        if cancel.is_cancelled() {
+            return Err(rpc_cancelled_error());
+        }
+        let mut paths: Vec<String> = files.iter().map(|file| file.path.clone()).collect();
+        let mut overrides = group.apply_to_paths(&mut paths);
+        let kept: HashSet<&str> = paths.iter().map(String::as_str).collect();
+        files.retain(|file| kept.contains(file.path.as_str()));
+        for file in &mut files {
+            if let Some(text) = overrides.remove(&file.path) {
+                file.text = text;
+            }
+        }
+        files.extend(
+            overrides
+                .into_iter()
+                .map(|(path, text)| FileText { path, text }),
+        );
+
+        let resp =
+            worker_call_cancelable(worker, cancel, Request::IndexShard { revision, files }).await?;
+        self.apply_update_response(worker, resp).await
+    }
+
+    /// Install the shard index a worker returned for an update request.
+    async fn apply_update_response(&self, worker: &WorkerHandle, resp: Response) -> Result<()> {
+        let shard_id = worker.shard_id;
         match resp {
             Response::ShardIndex(index) => {
                 if index.shard_id != shard_id {
-                    self.disconnect_worker(&worker).await;
+                    self.disconnect_worker(worker).await;
                     return Err(anyhow!(
                         "worker returned index for wrong shard {} (expected {shard_id})",
                         index.shard_id
@@ -1241,8 +2624,7 @@ impl DistributedRouter {
     }
 
     async fn worker_stats(&self) -> Result<HashMap<ShardId, WorkerStats>> {
-        let shard_ids: Vec<ShardId> =
-            (0..self.state.layout.source_roots.len() as ShardId).collect();
+        let shard_ids = read_layout(&self.state.layout).shard_ids();
         let mut stats = HashMap::new();
         for shard_id in shard_ids {
             let worker = wait_for_worker(self.state.clone(), shard_id).await?;
@@ -1274,72 +2656,228 @@ impl DistributedRouter {
         &self,
         cancel: CancellationToken,
         path: PathBuf,
+        doc_version: Option<u64>,
     ) -> Vec<RemoteDiagnostic> {
         if cancel.is_cancelled() {
             return Vec::new();
         }
 
-        let shard_id = self
-            .state
-            .layout
-            .source_roots
-            .iter()
-            .enumerate()
-            .find_map(|(id, root)| path.starts_with(&root.path).then_some(id as ShardId));
+        let shard_id = read_layout(&self.state.layout).shard_for_path(&path);
+
+        let Some(shard_id) = shard_id else {
+            return Vec::new();
+        };
+
+        let key = (shard_id, path);
+        let waiter = {
+            let mut calls = self.state.lock_diagnostics_calls();
+            match calls.get_mut(&key) {
+                Some(call) if call.is_newer_than(doc_version) => return Vec::new(),
+                Some(call) if call.can_share(doc_version) => {
+                    call.waiters += 1;
+                    DiagnosticsWaiter {
+                        state: self.state.clone(),
+                        key,
+                        id: call.id,
+                        result: call.result.clone(),
+                    }
+                }
+                _ => {
+                    let call = start_diagnostics_call(self.state.clone(), key.clone(), doc_version);
+                    let waiter = DiagnosticsWaiter {
+                        state: self.state.clone(),
+                        key: key.clone(),
+                        id: call.id,
+                        result: call.result.clone(),
+                    };
+                    if let Some(superseded) = calls.insert(key, call) {
+                        superseded.cancel.cancel();
+                    }
+                    waiter
+                }
+            }
+        };
+        waiter.wait(&cancel).await
+    }
+
+    async fn find_references_cancelable(
+        &self,
+        cancel: CancellationToken,
+        symbol: SymbolKey,
+    ) -> Result<FindReferencesResult> {
+        let fanout = self
+            .fanout_query(
+                &cancel,
+                Request::FindReferences { symbol },
+                |resp| match resp {
+                    Response::References { references } => Ok(references),
+                    other => Err(anyhow!("unexpected worker response: {other:?}")),
+                },
+            )
+            .await?;
+        Ok(FindReferencesResult {
+            references: merge_references(fanout.results.into_iter().map(|(_, refs)| refs)),
+            partial: fanout.failed,
+        })
+    }
+
+    /// Send `request` to every shard's worker concurrently and map each response with `extract`.
+    ///
+    /// At most [`MAX_CONCURRENT_FANOUT_QUERIES`] requests are in flight at once. A shard whose
+    /// worker is unavailable or fails the request (or whose response `extract` rejects) is
+    /// reported in [`FanoutResults::failed`] rather than failing the query. Cancelling `cancel`
+    /// fails the query and sends `Cancel` to the workers that have the request in flight.
+    async fn fanout_query<R, F>(
+        &self,
+        cancel: &CancellationToken,
+        request: Request,
+        extract: F,
+    ) -> Result<FanoutResults<R>>
+    where
+        R: Send + 'static,
+        F: Fn(Response) -> Result<R> + Send + Sync + 'static,
+    {
+        if cancel.is_cancelled() {
+            return Err(rpc_cancelled_error());
+        }
+
+        let extract = Arc::new(extract);
+        let mut join_set = JoinSet::new();
+        for shard_id in read_layout(&self.state.layout).shard_ids() {
+            let state = self.state.clone();
+            let cancel = cancel.clone();
+            let request = request.clone();
+            let extract = Arc::clone(&extract);
+            join_set.spawn(async move {
+                let result = async {
+                    let worker =
+                        wait_for_available_worker_cancelable(state.clone(), shard_id, &cancel)
+                            .await?;
+                    let _permit = Arc::clone(&state.query_fanout_semaphore)
+                        .acquire_owned()
+                        .await
+                        .map_err(|_| anyhow!("query fanout semaphore closed"))?;
+                    let resp = worker_call_cancelable(&worker, &cancel, request).await?;
+                    extract(resp)
+                }
+                .await;
+                (shard_id, result)
+            });
+        }
+
+        let mut fanout = FanoutResults {
+            results: Vec::new(),
+            failed: Vec::new(),
+        };
+        loop {
+            let res = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => {
+                    // Detach the in-flight tasks so they can observe the cancellation token and
+                    // best-effort send v3 Cancel packets to workers.
+                    tokio::spawn(async move { while join_set.join_next().await.is_some() {} });
+                    return Err(rpc_cancelled_error());
+                }
+                res = join_set.join_next() => res,
+            };
+            let Some(res) = res else {
+                break;
+            };
+
+            let (shard_id, result) = res.map_err(|err| anyhow!("query task panicked: {err}"))?;
+            match result {
+                Ok(value) => fanout.results.push((shard_id, value)),
+                Err(err) => {
+                    warn!(shard_id, error = ?err, "shard query failed");
+                    self.state.shard_request_failed(shard_id, &err).await;
+                    fanout.failed.push(shard_id);
+                }
+            }
+        }
+
+        fanout.results.sort_by_key(|(shard_id, _)| *shard_id);
+        fanout.failed.sort_unstable();
+        Ok(fanout)
+    }
 
-        let Some(shard_id) = shard_id else {
-            return Vec::new();
+    async fn update_layout(&self, layout: WorkspaceLayout) -> Result<LayoutDiff> {
+        let diff = write_layout(&self.state.layout).reload(&layout);
+
+        let removed_conns: Vec<RpcConnection> = {
+            let mut guard = self.state.shards.lock().await;
+            for (shard_id, root) in &diff.added {
+                guard.insert(*shard_id, ShardState::new(root.clone()));
+            }
+            diff.removed
+                .iter()
+                .filter_map(|(shard_id, _)| guard.remove(shard_id))
+                .filter_map(|shard| shard.worker.map(|w| w.conn))
+                .collect()
         };
+        // Wake up supervisors and waiters so they observe the removed shards.
+        self.state.notify.notify_waiters();
 
-        let worker = match wait_for_worker_cancelable(self.state.clone(), shard_id, &cancel).await {
-            Ok(worker) => worker,
-            Err(err) => {
-                if err
-                    .downcast_ref::<nova_remote_rpc::RpcError>()
-                    .is_some_and(|err| matches!(err, nova_remote_rpc::RpcError::Canceled))
+        for conn in removed_conns {
+            tokio::spawn(async move {
+                // Best-effort, mirroring `shutdown`: let the worker exit cleanly before closing
+                // the transport.
+                if let Ok(Ok(pending)) =
+                    timeout(WORKER_RPC_WRITE_TIMEOUT, conn.start_call(Request::Shutdown)).await
                 {
-                    return Vec::new();
+                    let _ = timeout(WORKER_SHUTDOWN_RPC_TIMEOUT, pending.wait()).await;
                 }
-                warn!(
-                    shard_id,
-                    error = ?err,
-                    "diagnostics request dropped: shard worker unavailable"
-                );
-                return Vec::new();
-            }
-        };
+                let _ = conn.shutdown().await;
+            });
+        }
 
-        let worker_id = worker.worker_id;
-        let path_str = path.to_string_lossy().to_string();
-        match worker_call_cancelable(&worker, &cancel, Request::Diagnostics { path: path_str })
-            .await
-        {
-            Ok(Response::Diagnostics { diagnostics }) => diagnostics,
-            Ok(other) => {
-                warn!(
-                    shard_id,
-                    worker_id,
-                    response = ?other,
-                    "unexpected worker response for diagnostics request"
-                );
-                Vec::new()
+        if self.state.config.spawn_workers {
+            let mut supervisors = self.worker_supervisors.lock().await;
+            for (shard_id, _) in &diff.removed {
+                // The supervisor notices the shard removal, reaps its worker process and exits.
+                if let Some(supervisor) = supervisors.remove(shard_id) {
+                    let mut task = supervisor.task;
+                    tokio::spawn(async move {
+                        if timeout(WORKER_KILL_TIMEOUT * 2, &mut task).await.is_err() {
+                            task.abort();
+                            let _ = task.await;
+                        }
+                    });
+                }
             }
-            Err(err) => {
-                if err
-                    .downcast_ref::<nova_remote_rpc::RpcError>()
-                    .is_some_and(|err| matches!(err, nova_remote_rpc::RpcError::Canceled))
-                {
-                    return Vec::new();
+            if !*self.shutdown_tx.borrow() {
+                for (shard_id, _) in &diff.added {
+                    supervisors.insert(
+                        *shard_id,
+                        spawn_worker_supervisor(
+                            self.state.clone(),
+                            *shard_id,
+                            self.shutdown_tx.subscribe(),
+                        ),
+                    );
                 }
-                warn!(
-                    shard_id,
-                    worker_id,
-                    error = ?err,
-                    "diagnostics request failed"
-                );
-                Vec::new()
             }
         }
+
+        let (symbols, update_id) = {
+            let mut guard = self.state.shard_indexes.lock().await;
+            for (shard_id, _) in &diff.removed {
+                guard.remove(shard_id);
+            }
+            let update_id = self
+                .state
+                .shard_indexes_update_id
+                .fetch_add(1, Ordering::SeqCst)
+                + 1;
+            (build_global_symbols(guard.values()), update_id)
+        };
+        write_global_symbols(&self.state.global_symbols, symbols, update_id).await;
+
+        info!(
+            added = ?diff.added,
+            removed = ?diff.removed,
+            "updated workspace layout"
+        );
+        Ok(diff)
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -1387,8 +2925,8 @@ impl DistributedRouter {
             }
         }
 
-        let tasks = std::mem::take(&mut *self.worker_supervisors.lock().await);
-        for mut task in tasks {
+        let supervisors = std::mem::take(&mut *self.worker_supervisors.lock().await);
+        for mut task in supervisors.into_values().map(|supervisor| supervisor.task) {
             if timeout(Duration::from_secs(1), &mut task).await.is_err() {
                 task.abort();
                 let _ = task.await;
@@ -1403,25 +2941,61 @@ impl DistributedRouter {
         Ok(())
     }
 
+    async fn restart_shard(&self, shard_id: ShardId, options: RestartShardOptions) -> Result<()> {
+        if !self.state.shards.lock().await.contains_key(&shard_id) {
+            return Err(anyhow!("unknown shard {shard_id}"));
+        }
+
+        if self.state.config.spawn_workers {
+            let commands = self
+                .worker_supervisors
+                .lock()
+                .await
+                .get(&shard_id)
+                .map(|supervisor| supervisor.commands.clone());
+            let stopped = || anyhow!("worker supervisor for shard {shard_id} has stopped");
+            let commands = commands.ok_or_else(stopped)?;
+            let (done, done_rx) = oneshot::channel();
+            commands
+                .send(SupervisorCommand::Restart(RestartRequest {
+                    clear_cache: options.clear_cache,
+                    done,
+                }))
+                .map_err(|_| stopped())?;
+            return done_rx.await.map_err(|_| stopped())?;
+        }
+
+        // Workers are started externally: sever the connection and let the worker (or its own
+        // supervisor) reconnect.
+        let conn = {
+            let mut guard = self.state.shards.lock().await;
+            guard.get_mut(&shard_id).and_then(|shard| {
+                let worker = shard.worker.clone()?;
+                shard.current_worker_lost(worker.worker_id, WorkerExitReason::Restarted);
+                Some(worker.conn)
+            })
+        };
+        self.state.notify.notify_waiters();
+        if let Some(conn) = conn {
+            let _ = conn.shutdown().await;
+        }
+        if options.clear_cache {
+            clear_shard_cache(&self.state.config.cache_dir, shard_id)?;
+        }
+        Ok(())
+    }
+
     async fn disconnect_worker(&self, worker: &WorkerHandle) {
         // Treat shard mismatches as a protocol violation and sever the connection so it cannot
-        // keep returning poisoned cross-shard responses.
-        let _ = worker.conn.shutdown().await;
-
-        let mut guard = self.state.shards.lock().await;
-        if let Some(shard) = guard.get_mut(&worker.shard_id) {
-            if shard
-                .worker
-                .as_ref()
-                .is_some_and(|w| w.worker_id == worker.worker_id)
-            {
-                shard.worker = None;
-            }
-            if shard.pending_worker == Some(worker.worker_id) {
-                shard.pending_worker = None;
+        // keep returning poisoned cross-shard responses. Drop the worker handle first so the
+        // connection cleanup task doesn't record the disconnect as an ordinary one.
+        {
+            let mut guard = self.state.shards.lock().await;
+            if let Some(shard) = guard.get_mut(&worker.shard_id) {
+                shard.current_worker_lost(worker.worker_id, WorkerExitReason::ProtocolViolation);
             }
         }
-        drop(guard);
+        let _ = worker.conn.shutdown().await;
         self.state.notify.notify_waiters();
     }
 }
@@ -1435,6 +3009,25 @@ async fn wait_for_worker_cancelable(
     state: Arc<RouterState>,
     shard_id: ShardId,
     cancel: &CancellationToken,
+) -> Result<WorkerHandle> {
+    wait_for_worker_inner(state, shard_id, cancel, false).await
+}
+
+/// Like [`wait_for_worker_cancelable`], but fails with [`ShardUnavailable`] instead of waiting
+/// while the shard's supervisor is backing off before restarting its worker.
+async fn wait_for_available_worker_cancelable(
+    state: Arc<RouterState>,
+    shard_id: ShardId,
+    cancel: &CancellationToken,
+) -> Result<WorkerHandle> {
+    wait_for_worker_inner(state, shard_id, cancel, true).await
+}
+
+async fn wait_for_worker_inner(
+    state: Arc<RouterState>,
+    shard_id: ShardId,
+    cancel: &CancellationToken,
+    fail_fast: bool,
 ) -> Result<WorkerHandle> {
     if cancel.is_cancelled() {
         return Err(rpc_cancelled_error());
@@ -1446,10 +3039,17 @@ async fn wait_for_worker_cancelable(
                 return Err(rpc_cancelled_error());
             }
 
-            if let Some(worker) = {
+            let (worker, restarting) = {
                 let guard = state.shards.lock().await;
-                guard.get(&shard_id).and_then(|s| s.worker.clone())
-            } {
+                guard
+                    .get(&shard_id)
+                    .map(|s| (s.worker.clone(), s.restarting))
+                    .unwrap_or((None, false))
+            };
+            if fail_fast && restarting && worker.is_none() {
+                return Err(anyhow!(ShardUnavailable { shard_id }));
+            }
+            if let Some(worker) = worker {
                 if worker.shard_id != shard_id {
                     return Err(anyhow!(
                         "internal error: shard {shard_id} mapped to worker for shard {}",
@@ -1470,9 +3070,176 @@ async fn wait_for_worker_cancelable(
     .context("timed out waiting for worker")?
 }
 
+/// A diagnostics RPC for one file, shared by the requests waiting on it.
+struct DiagnosticsCall {
+    id: u64,
+    doc_version: Option<u64>,
+    started: Instant,
+    /// Cancels the RPC when the call is superseded or its last waiter goes away.
+    cancel: CancellationToken,
+    /// `None` until the call finishes; a superseded call finishes with no diagnostics.
+    result: watch::Receiver<Option<Vec<RemoteDiagnostic>>>,
+    waiters: usize,
+}
+
+impl DiagnosticsCall {
+    /// Whether this call is for a later document version than `doc_version`, making a request
+    /// for `doc_version` stale.
+    fn is_newer_than(&self, doc_version: Option<u64>) -> bool {
+        matches!((self.doc_version, doc_version), (Some(current), Some(requested)) if requested < current)
+    }
+
+    /// Whether a request for `doc_version` can share this call's response. Requests without a
+    /// version never share, as the document may have changed since this call started.
+    fn can_share(&self, doc_version: Option<u64>) -> bool {
+        doc_version.is_some()
+            && doc_version == self.doc_version
+            && self.started.elapsed() < DIAGNOSTICS_COALESCE_WINDOW
+    }
+}
+
+/// A request waiting on a [`DiagnosticsCall`]; dropping the last waiter cancels the call.
+struct DiagnosticsWaiter {
+    state: Arc<RouterState>,
+    key: (ShardId, PathBuf),
+    id: u64,
+    result: watch::Receiver<Option<Vec<RemoteDiagnostic>>>,
+}
+
+impl DiagnosticsWaiter {
+    async fn wait(mut self, cancel: &CancellationToken) -> Vec<RemoteDiagnostic> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Vec::new(),
+            result = self.result.wait_for(Option::is_some) => result
+                .map(|result| result.clone().unwrap_or_default())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Drop for DiagnosticsWaiter {
+    fn drop(&mut self) {
+        let mut calls = self.state.lock_diagnostics_calls();
+        let Some(call) = calls.get_mut(&self.key).filter(|call| call.id == self.id) else {
+            return;
+        };
+        call.waiters -= 1;
+        if call.waiters == 0 {
+            if let Some(call) = calls.remove(&self.key) {
+                call.cancel.cancel();
+            }
+        }
+    }
+}
+
+/// Spawn the diagnostics RPC for `key`. The returned call has one waiter, which the caller
+/// must create.
+fn start_diagnostics_call(
+    state: Arc<RouterState>,
+    key: (ShardId, PathBuf),
+    doc_version: Option<u64>,
+) -> DiagnosticsCall {
+    let id = state
+        .next_diagnostics_call_id
+        .fetch_add(1, Ordering::Relaxed);
+    let cancel = CancellationToken::new();
+    let (result_tx, result) = watch::channel(None);
+
+    let call_cancel = cancel.clone();
+    tokio::spawn(async move {
+        let (shard_id, path) = &key;
+        let diagnostics = diagnostics_rpc(&state, *shard_id, path, &call_cancel).await;
+        let current = {
+            let mut calls = state.lock_diagnostics_calls();
+            let current = calls.get(&key).is_some_and(|call| call.id == id);
+            if current {
+                calls.remove(&key);
+            }
+            current
+        };
+        // A superseded call's response describes an outdated document; drop it.
+        let _ = result_tx.send(Some(if current { diagnostics } else { Vec::new() }));
+    });
+
+    DiagnosticsCall {
+        id,
+        doc_version,
+        started: Instant::now(),
+        cancel,
+        result,
+        waiters: 1,
+    }
+}
+
+async fn diagnostics_rpc(
+    state: &Arc<RouterState>,
+    shard_id: ShardId,
+    path: &Path,
+    cancel: &CancellationToken,
+) -> Vec<RemoteDiagnostic> {
+    let worker = wait_for_available_worker_cancelable(state.clone(), shard_id, cancel).await;
+    let worker = match worker {
+        Ok(worker) => worker,
+        Err(err) => {
+            if is_cancelled_error(&err) {
+                return Vec::new();
+            }
+            warn!(
+                shard_id,
+                error = ?err,
+                "diagnostics request dropped: shard worker unavailable"
+            );
+            return Vec::new();
+        }
+    };
+
+    let _permit = tokio::select! {
+        biased;
+        _ = cancel.cancelled() => return Vec::new(),
+        permit = worker.diagnostics_semaphore.acquire() => match permit {
+            Ok(permit) => permit,
+            Err(_) => return Vec::new(),
+        },
+    };
+
+    let worker_id = worker.worker_id;
+    let path_str = path.to_string_lossy().to_string();
+    match worker_call_cancelable(&worker, cancel, Request::Diagnostics { path: path_str }).await {
+        Ok(Response::Diagnostics { diagnostics }) => diagnostics,
+        Ok(other) => {
+            warn!(
+                shard_id,
+                worker_id,
+                response = ?other,
+                "unexpected worker response for diagnostics request"
+            );
+            Vec::new()
+        }
+        Err(err) => {
+            if is_cancelled_error(&err) {
+                return Vec::new();
+            }
+            state.shard_request_failed(shard_id, &err).await;
+            warn!(
+                shard_id,
+                worker_id,
+                error = ?err,
+                "diagnostics request failed"
+            );
+            Vec::new()
+        }
+    }
+}
+
 async fn apply_shard_index(state: Arc<RouterState>, index: ShardIndex) {
+    let (shard_id, revision) = (index.shard_id, index.revision);
     let (indexes_snapshot, update_id) = {
         let mut guard = state.shard_indexes.lock().await;
+        if !read_layout(&state.layout).contains(index.shard_id) {
+            // Late index for a shard that was removed by a layout reload.
+            return;
+        }
         if let Some(current) = guard.get(&index.shard_id) {
             let current_key = (current.revision, current.index_generation);
             let incoming_key = (index.revision, index.index_generation);
@@ -1485,8 +3252,138 @@ async fn apply_shard_index(state: Arc<RouterState>, index: ShardIndex) {
         (guard.clone(), update_id)
     };
 
+    state.shard_index_applied(shard_id, revision).await;
+
     let symbols = build_global_symbols(indexes_snapshot.values());
     write_global_symbols(&state.global_symbols, symbols, update_id).await;
+
+    tokio::spawn(async move { state.persist_shard_index(shard_id).await });
+}
+
+/// Serve the shard indexes the router persisted in a previous session, for the shards whose
+/// source root is unchanged since (see [`ShardRootFingerprint`]). They are marked as
+/// [`ShardHealth::index_from_cache`] until rebuilt. Caches are loaded one shard at a time.
+async fn load_cached_shard_indexes(state: &RouterState) {
+    let layout = read_layout(&state.layout).clone();
+    let mut max_revision = None;
+    for (shard_id, root) in layout.roots() {
+        let cache_dir = state.config.cache_dir.clone();
+        let Ok(Some(cached)) = tokio::task::spawn_blocking(move || {
+            nova_cache::load_router_shard_index(&cache_dir, shard_id)
+        })
+        .await
+        else {
+            continue;
+        };
+        if cached.root != root {
+            continue;
+        }
+        match shard_root_fingerprint(&root, &layout.nested_roots(&root)).await {
+            Ok(fingerprint) if fingerprint == cached.root_fingerprint => {}
+            Ok(_) => {
+                info!(shard_id, root = %root.display(), "shard root changed; ignoring cached index");
+                continue;
+            }
+            Err(err) => {
+                warn!(shard_id, error = ?err, "failed to fingerprint shard root");
+                continue;
+            }
+        }
+
+        let revision = cached.index.revision;
+        max_revision = max_revision.max(Some(revision));
+        state
+            .shard_indexes
+            .lock()
+            .await
+            .insert(shard_id, cached.index);
+        if let Some(shard) = state.shards.lock().await.get_mut(&shard_id) {
+            shard.last_index_revision = Some(revision);
+            shard.index_from_cache = true;
+        }
+    }
+
+    let Some(max_revision) = max_revision else {
+        return;
+    };
+    // Revisions of this session must order after the cached ones, or their indexes would be
+    // rejected as older.
+    state
+        .global_revision
+        .fetch_max(max_revision, Ordering::SeqCst);
+    let (symbols, update_id) = {
+        let guard = state.shard_indexes.lock().await;
+        let update_id = state.shard_indexes_update_id.fetch_add(1, Ordering::SeqCst) + 1;
+        (build_global_symbols(guard.values()), update_id)
+    };
+    write_global_symbols(&state.global_symbols, symbols, update_id).await;
+}
+
+/// Rebuild the index of a shard that is served from the router's cache, replacing symbols of
+/// files that changed while the router was down.
+async fn refresh_cached_shard_index(state: Arc<RouterState>, worker: WorkerHandle) {
+    let shard_id = worker.shard_id;
+    let Some((root, nested)) = ({
+        let layout = read_layout(&state.layout);
+        layout
+            .root(shard_id)
+            .map(|root| (root.to_path_buf(), layout.nested_roots(root)))
+    }) else {
+        return;
+    };
+
+    let result = async {
+        let files = {
+            let _snapshot_permit = state
+                .shard_snapshot_semaphore
+                .acquire()
+                .await
+                .map_err(|_| anyhow!("file snapshot semaphore closed"))?;
+            collect_java_files(&root, &nested).await?
+        };
+        let revision = state.global_revision.fetch_add(1, Ordering::SeqCst) + 1;
+        worker_call_cancelable(
+            &worker,
+            &CancellationToken::new(),
+            Request::IndexShard { revision, files },
+        )
+        .await
+    }
+    .await;
+
+    match result {
+        Ok(Response::ShardIndex(index)) if index.shard_id == shard_id => {
+            apply_shard_index(state, index).await;
+        }
+        Ok(other) => {
+            warn!(
+                shard_id,
+                worker_id = worker.worker_id,
+                response = ?other,
+                "unexpected response to cached index refresh"
+            );
+        }
+        Err(err) => {
+            state.shard_request_failed(shard_id, &err).await;
+            warn!(
+                shard_id,
+                worker_id = worker.worker_id,
+                error = ?err,
+                "failed to refresh cached shard index"
+            );
+        }
+    }
+}
+
+/// Whether a worker rejected a request it does not know (i.e. it predates the request).
+fn is_unknown_request_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<nova_remote_rpc::RpcError>()
+        .is_some_and(|err| {
+            matches!(
+                err,
+                nova_remote_rpc::RpcError::Remote(remote) if remote.code == RpcErrorCode::InvalidRequest
+            )
+        })
 }
 
 async fn worker_call(worker: &WorkerHandle, request: Request) -> Result<Response> {
@@ -1597,7 +3494,7 @@ async fn accept_loop_unix(
             }
             res = listener.accept() => {
                 let (stream, _) = res.with_context(|| format!("accept unix socket {path:?}"))?;
-                if state.config.auth_token.is_none() {
+                if state.config.auth.is_none() {
                     match ipc_security::unix_peer_uid_matches_current_user(&stream) {
                         Ok(true) => {}
                         Ok(false) => {
@@ -1853,8 +3750,9 @@ async fn handle_new_connection(
         .max(1);
     let max_rpc_len: u32 = max_rpc_bytes.try_into().unwrap_or(u32::MAX);
 
+    // Tokens are checked during admission instead of via `expected_auth_token`, so they can
+    // change while the router runs and depend on the claimed shard.
     let mut cfg = RpcRouterConfig::default();
-    cfg.expected_auth_token = state.config.auth_token.clone();
     cfg.pre_handshake_max_frame_len = MAX_HELLO_BYTES
         .try_into()
         .unwrap_or(nova_remote_rpc::DEFAULT_PRE_HANDSHAKE_MAX_FRAME_LEN);
@@ -1874,12 +3772,19 @@ async fn handle_new_connection(
         WORKER_HANDSHAKE_TIMEOUT,
         RpcConnection::handshake_as_router_with_config_and_admission(stream, cfg, move |hello| {
             let shard_id = hello.shard_id;
+            let auth_token = hello.auth_token.clone();
             let reservation_hook = reservation_hook.clone();
             let admission_state = admission_state.clone();
             let admission_identity = admission_identity.clone();
             #[cfg(not(feature = "tls"))]
             let _ = &admission_identity;
             async move {
+                if let Some(auth) = admission_state.config.auth.as_ref() {
+                    if let Err(reject) = auth.validate(shard_id, auth_token.as_deref()) {
+                        return RouterAdmission::Reject(reject);
+                    }
+                }
+
                 #[cfg(feature = "tls")]
                 {
                     let allowlist = &admission_state.config.tls_client_cert_fingerprint_allowlist;
@@ -1982,10 +3887,11 @@ async fn handle_new_connection(
         shard_id,
         worker_id,
         conn: conn.clone(),
+        diagnostics_semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_DIAGNOSTICS_PER_WORKER)),
     };
 
     // Finalize the reservation now that the welcome frame is on the wire.
-    {
+    let index_from_cache = {
         let mut guard = state.shards.lock().await;
         let Some(shard) = guard.get_mut(&shard_id) else {
             return Err(anyhow!(
@@ -1999,8 +3905,9 @@ async fn handle_new_connection(
             ));
         }
         shard.pending_worker = None;
-        shard.worker = Some(handle.clone());
-    }
+        shard.worker_connected(handle.clone());
+        shard.index_from_cache
+    };
 
     info!(shard_id, worker_id, has_cached_index, "worker connected");
 
@@ -2034,10 +3941,10 @@ async fn handle_new_connection(
                                 }
 
                                 let conn = worker.conn.clone();
-                                shard.worker = None;
-                                if shard.pending_worker == Some(worker_id) {
-                                    shard.pending_worker = None;
-                                }
+                                shard.current_worker_lost(
+                                    worker_id,
+                                    WorkerExitReason::ProtocolViolation,
+                                );
                                 conn
                             };
                             notif_state.notify.notify_waiters();
@@ -2050,6 +3957,11 @@ async fn handle_new_connection(
 
                         apply_shard_index(notif_state, index).await;
                     }
+                    Notification::IndexProgress {
+                        revision,
+                        files_done,
+                        ..
+                    } => notif_state.shard_indexing_progress(shard_id, revision, files_done),
                     Notification::Unknown => {}
                 }
             }
@@ -2064,21 +3976,15 @@ async fn handle_new_connection(
         info!(shard_id, worker_id, "worker connection closed");
         let mut guard = cleanup_state.shards.lock().await;
         if let Some(shard) = guard.get_mut(&shard_id) {
-            if shard
-                .worker
-                .as_ref()
-                .is_some_and(|w| w.worker_id == worker_id)
-            {
-                shard.worker = None;
-            }
-            if shard.pending_worker == Some(worker_id) {
-                shard.pending_worker = None;
-            }
+            shard.current_worker_lost(worker_id, WorkerExitReason::Disconnected);
         }
         cleanup_state.notify.notify_waiters();
     });
 
-    if has_cached_index {
+    if index_from_cache {
+        // Indexing the shard also loads its files into the worker.
+        tokio::spawn(refresh_cached_shard_index(state.clone(), handle.clone()));
+    } else if has_cached_index {
         let refresh_state = state.clone();
         let refresh_handle = handle.clone();
         tokio::spawn(async move {
@@ -2104,7 +4010,8 @@ async fn handle_new_connection(
                 Err(_) => return,
             };
 
-            let files = match collect_java_files(&root).await {
+            let nested = read_layout(&refresh_state.layout).nested_roots(&root);
+            let files = match collect_java_files(&root, &nested).await {
                 Ok(files) => files,
                 Err(err) => {
                     warn!(
@@ -2245,29 +4152,118 @@ async fn kill_and_reap_worker(
     }
 }
 
+/// A command for the worker supervisor of a shard.
+enum SupervisorCommand {
+    Restart(RestartRequest),
+}
+
+/// A [`QueryRouter::restart_shard`] call: stop the current worker, reset the restart backoff and
+/// start a fresh worker right away. `done` receives the outcome once the old worker is gone.
+struct RestartRequest {
+    clear_cache: bool,
+    done: oneshot::Sender<Result<()>>,
+}
+
+struct WorkerSupervisor {
+    task: JoinHandle<()>,
+    commands: mpsc::UnboundedSender<SupervisorCommand>,
+}
+
+fn spawn_worker_supervisor(
+    state: Arc<RouterState>,
+    shard_id: ShardId,
+    mut shutdown_rx: watch::Receiver<bool>,
+) -> WorkerSupervisor {
+    let (commands, mut commands_rx) = mpsc::unbounded_channel();
+    let task = tokio::spawn(async move {
+        worker_supervisor_loop(state, shard_id, &mut shutdown_rx, &mut commands_rx).await;
+    });
+    WorkerSupervisor { task, commands }
+}
+
+/// Delete the cached index of `shard_id` under `cache_dir`, if there is one.
+fn clear_shard_cache(cache_dir: &Path, shard_id: ShardId) -> Result<()> {
+    let path = nova_cache::shard_cache_path(cache_dir, shard_id);
+    match std::fs::remove_file(&path) {
+        Ok(()) => {
+            info!(shard_id, path = %path.display(), "cleared shard cache");
+            Ok(())
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err).with_context(|| format!("remove shard cache {}", path.display())),
+    }
+}
+
+/// Stop a connected worker for a restart: ask it to shut down, then kill it if it hasn't exited
+/// within [`WORKER_KILL_TIMEOUT`].
+async fn stop_worker_gracefully(
+    shard_id: ShardId,
+    attempt: u64,
+    conn: Option<RpcConnection>,
+    mut child: tokio::process::Child,
+) -> Option<std::process::ExitStatus> {
+    if let Some(conn) = conn {
+        if let Ok(Ok(pending)) =
+            timeout(WORKER_RPC_WRITE_TIMEOUT, conn.start_call(Request::Shutdown)).await
+        {
+            let _ = timeout(WORKER_SHUTDOWN_RPC_TIMEOUT, pending.wait()).await;
+        }
+        let _ = conn.shutdown().await;
+    }
+
+    match timeout(WORKER_KILL_TIMEOUT, child.wait()).await {
+        Ok(Ok(status)) => Some(status),
+        _ => kill_and_reap_worker(shard_id, attempt, child, "restart").await,
+    }
+}
+
 async fn worker_supervisor_loop(
     state: Arc<RouterState>,
     shard_id: ShardId,
     shutdown_rx: &mut watch::Receiver<bool>,
+    commands: &mut mpsc::UnboundedReceiver<SupervisorCommand>,
 ) {
     let connect_arg = state.config.listen_addr.as_worker_connect_arg();
     let mut backoff =
         RestartBackoff::new(WORKER_RESTART_BACKOFF_INITIAL, WORKER_RESTART_BACKOFF_MAX);
     let mut attempt: u64 = 0;
+    // Restarts requested since the last worker was stopped, answered before the next spawn.
+    let mut restarts: Vec<RestartRequest> = Vec::new();
 
     loop {
         if *shutdown_rx.borrow() {
             return;
         }
+        if !restarts.is_empty() {
+            backoff.reset();
+            let clear_cache = restarts.iter().any(|restart| restart.clear_cache);
+            let result = if clear_cache {
+                clear_shard_cache(&state.config.cache_dir, shard_id)
+            } else {
+                Ok(())
+            };
+            for restart in restarts.drain(..) {
+                let _ = restart.done.send(match &result {
+                    Ok(()) => Ok(()),
+                    Err(err) => Err(anyhow!("{err:#}")),
+                });
+            }
+        }
+        state.set_shard_restarting(shard_id, false).await;
 
         let previous_worker_id = {
             let guard = state.shards.lock().await;
-            guard
-                .get(&shard_id)
-                .and_then(|shard| shard.worker.as_ref().map(|w| w.worker_id))
+            let Some(shard) = guard.get(&shard_id) else {
+                // The shard was removed by a layout reload.
+                return;
+            };
+            shard.worker.as_ref().map(|w| w.worker_id)
         };
 
         attempt += 1;
+        state
+            .set_worker_restart_state(shard_id, attempt - 1, backoff.peek_delay())
+            .await;
 
         let mut cmd = Command::new(&state.config.worker_command);
         cmd.kill_on_drop(true);
@@ -2282,10 +4278,11 @@ async fn worker_supervisor_loop(
         cmd.arg("--max-rpc-bytes")
             .arg(state.config.max_rpc_bytes.to_string());
 
-        if let Some(token) = state.config.auth_token.as_ref() {
+        if let Some(auth) = state.config.auth.as_ref() {
             // Avoid passing secrets via argv. Instead, set the token in the child environment and
-            // instruct the worker to read it.
-            cmd.env("NOVA_WORKER_AUTH_TOKEN", token);
+            // instruct the worker to read it. The token is read at every (re)spawn, so rotated
+            // tokens reach the next worker; a running worker keeps its connection.
+            cmd.env("NOVA_WORKER_AUTH_TOKEN", auth.primary_token(shard_id));
             cmd.arg("--auth-token-env").arg("NOVA_WORKER_AUTH_TOKEN");
         }
 
@@ -2312,9 +4309,23 @@ async fn worker_supervisor_loop(
                     error = ?err,
                     "failed to spawn worker; retrying"
                 );
+                state
+                    .worker_exited(
+                        shard_id,
+                        None,
+                        WorkerExitReason::SpawnFailed {
+                            error: err.to_string(),
+                        },
+                    )
+                    .await;
+                state
+                    .set_worker_restart_state(shard_id, attempt - 1, backoff.peek_delay())
+                    .await;
+                state.set_shard_restarting(shard_id, true).await;
                 tokio::select! {
                     _ = shutdown_rx.changed() => {},
                     _ = tokio::time::sleep(delay) => {},
+                    Some(SupervisorCommand::Restart(restart)) = commands.recv() => restarts.push(restart),
                 }
                 continue;
             }
@@ -2330,12 +4341,14 @@ async fn worker_supervisor_loop(
 
         enum SpawnEvent {
             Shutdown,
+            Removed,
             Exited(std::process::ExitStatus),
             Connected {
                 worker_id: WorkerId,
                 connected_at: Instant,
             },
             HandshakeTimeout,
+            Restart(RestartRequest),
         }
 
         let handshake_deadline = Instant::now() + WORKER_HANDSHAKE_TIMEOUT;
@@ -2344,12 +4357,16 @@ async fn worker_supervisor_loop(
                 break SpawnEvent::Shutdown;
             }
 
-            if let Some(worker_id) = {
+            let current_worker_id = {
                 let guard = state.shards.lock().await;
                 guard
                     .get(&shard_id)
-                    .and_then(|shard| shard.worker.as_ref().map(|w| w.worker_id))
-            } {
+                    .map(|shard| shard.worker.as_ref().map(|w| w.worker_id))
+            };
+            let Some(current_worker_id) = current_worker_id else {
+                break SpawnEvent::Removed;
+            };
+            if let Some(worker_id) = current_worker_id {
                 if Some(worker_id) != previous_worker_id {
                     break SpawnEvent::Connected {
                         worker_id,
@@ -2371,6 +4388,7 @@ async fn worker_supervisor_loop(
                 }
                 _ = state.notify.notified() => {}
                 _ = tokio::time::sleep_until(handshake_deadline) => break SpawnEvent::HandshakeTimeout,
+                Some(SupervisorCommand::Restart(restart)) = commands.recv() => break SpawnEvent::Restart(restart),
             }
         };
 
@@ -2379,6 +4397,10 @@ async fn worker_supervisor_loop(
                 let _ = kill_and_reap_worker(shard_id, attempt, child, "shutdown").await;
                 return;
             }
+            SpawnEvent::Removed => {
+                let _ = kill_and_reap_worker(shard_id, attempt, child, "shard-removed").await;
+                return;
+            }
             SpawnEvent::HandshakeTimeout => {
                 warn!(
                     shard_id,
@@ -2388,6 +4410,9 @@ async fn worker_supervisor_loop(
                 );
                 let status =
                     kill_and_reap_worker(shard_id, attempt, child, "handshake-timeout").await;
+                state
+                    .worker_exited(shard_id, None, WorkerExitReason::HandshakeTimeout)
+                    .await;
                 (false, status)
             }
             SpawnEvent::Exited(status) => {
@@ -2397,8 +4422,26 @@ async fn worker_supervisor_loop(
                     status = ?status,
                     "worker exited before handshake"
                 );
+                state
+                    .worker_exited(
+                        shard_id,
+                        None,
+                        WorkerExitReason::Exited {
+                            code: status.code(),
+                        },
+                    )
+                    .await;
                 (false, Some(status))
             }
+            SpawnEvent::Restart(restart) => {
+                info!(shard_id, attempt, "restart requested; stopping worker");
+                let _ = kill_and_reap_worker(shard_id, attempt, child, "restart").await;
+                state
+                    .worker_exited(shard_id, None, WorkerExitReason::Restarted)
+                    .await;
+                restarts.push(restart);
+                continue;
+            }
             SpawnEvent::Connected {
                 worker_id,
                 connected_at,
@@ -2407,8 +4450,10 @@ async fn worker_supervisor_loop(
 
                 enum SessionEvent {
                     Shutdown,
+                    Removed,
                     Exited(std::process::ExitStatus),
                     Disconnected,
+                    Restart(RestartRequest),
                 }
 
                 let session_event = loop {
@@ -2420,7 +4465,10 @@ async fn worker_supervisor_loop(
                         let guard = state.shards.lock().await;
                         guard
                             .get(&shard_id)
-                            .and_then(|shard| shard.worker.as_ref().map(|w| w.worker_id))
+                            .map(|shard| shard.worker.as_ref().map(|w| w.worker_id))
+                    };
+                    let Some(current_worker_id) = current_worker_id else {
+                        break SessionEvent::Removed;
                     };
                     if current_worker_id != Some(worker_id) {
                         break SessionEvent::Disconnected;
@@ -2438,6 +4486,7 @@ async fn worker_supervisor_loop(
                             }
                         }
                         _ = state.notify.notified() => {}
+                        Some(SupervisorCommand::Restart(restart)) = commands.recv() => break SessionEvent::Restart(restart),
                     }
                 };
 
@@ -2449,6 +4498,12 @@ async fn worker_supervisor_loop(
                         let _ = kill_and_reap_worker(shard_id, attempt, child, "shutdown").await;
                         return;
                     }
+                    SessionEvent::Removed => {
+                        info!(shard_id, worker_id, "shard removed; stopping worker");
+                        let _ =
+                            kill_and_reap_worker(shard_id, attempt, child, "shard-removed").await;
+                        return;
+                    }
                     SessionEvent::Disconnected => {
                         warn!(
                             shard_id,
@@ -2458,6 +4513,13 @@ async fn worker_supervisor_loop(
                         );
                         let status =
                             kill_and_reap_worker(shard_id, attempt, child, "disconnected").await;
+                        // A worker that exited by itself closed the connection first; report its
+                        // exit code rather than the disconnect.
+                        if let Some(code) = status.and_then(|status| status.code()) {
+                            state
+                                .worker_process_exited(shard_id, worker_id, Some(code))
+                                .await;
+                        }
                         (stable, status)
                     }
                     SessionEvent::Exited(status) => {
@@ -2468,23 +4530,39 @@ async fn worker_supervisor_loop(
                             status = ?status,
                             "worker exited; restarting"
                         );
-                        let should_clear_worker = {
+                        if state
+                            .worker_process_exited(shard_id, worker_id, status.code())
+                            .await
+                        {
+                            state.notify.notify_waiters();
+                        }
+                        (stable, Some(status))
+                    }
+                    SessionEvent::Restart(restart) => {
+                        info!(shard_id, worker_id, "restart requested; stopping worker");
+                        let conn = {
                             let mut guard = state.shards.lock().await;
-                            guard.get_mut(&shard_id).is_some_and(|shard| {
-                                let is_current = shard
+                            guard.get_mut(&shard_id).and_then(|shard| {
+                                let conn = shard
                                     .worker
                                     .as_ref()
-                                    .is_some_and(|w| w.worker_id == worker_id);
-                                if is_current {
-                                    shard.worker = None;
+                                    .filter(|w| w.worker_id == worker_id)
+                                    .map(|w| w.conn.clone());
+                                if !shard
+                                    .current_worker_lost(worker_id, WorkerExitReason::Restarted)
+                                {
+                                    shard.worker_exited(
+                                        Some(worker_id),
+                                        WorkerExitReason::Restarted,
+                                    );
                                 }
-                                is_current
+                                conn
                             })
                         };
-                        if should_clear_worker {
-                            state.notify.notify_waiters();
-                        }
-                        (stable, Some(status))
+                        state.notify.notify_waiters();
+                        let _ = stop_worker_gracefully(shard_id, attempt, conn, child).await;
+                        restarts.push(restart);
+                        continue;
                     }
                 }
             }
@@ -2501,9 +4579,14 @@ async fn worker_supervisor_loop(
         let backoff_delay = backoff.next_delay();
         let delay = add_worker_restart_jitter(backoff_delay);
         info!(shard_id, backoff_delay = ?backoff_delay, delay = ?delay, "restarting worker");
+        state
+            .set_worker_restart_state(shard_id, attempt - 1, backoff.peek_delay())
+            .await;
+        state.set_shard_restarting(shard_id, true).await;
         tokio::select! {
             _ = shutdown_rx.changed() => {},
             _ = tokio::time::sleep(delay) => {},
+            Some(SupervisorCommand::Restart(restart)) = commands.recv() => restarts.push(restart),
         }
     }
 }
@@ -2652,18 +4735,23 @@ where
     }
 }
 
+/// Index `files` into a sorted symbol list, reading them from disk unless their text is given in
+/// `overrides`. `files` is consumed lazily, so it can still be producing paths (see
+/// [`JavaFilePaths`]); an `Err` item aborts indexing with that error.
+///
+/// `progress` is called with the number of indexed files every [`INDEX_PROGRESS_INTERVAL_FILES`]
+/// files (and once all files are done).
 fn index_for_files(
     shard_id: ShardId,
-    mut files: Vec<String>,
-    override_file: Option<(String, String)>,
+    files: impl IntoIterator<Item = Result<String>>,
+    mut overrides: HashMap<String, String>,
     cancel: &CancellationToken,
+    progress: &dyn Fn(usize),
 ) -> std::result::Result<anyhow::Result<Vec<Symbol>>, Cancelled> {
     use nova_db::{FileId, NovaHir, SalsaDatabase, SourceRootId};
 
     Cancelled::check(cancel)?;
 
-    files.sort();
-
     let db = SalsaDatabase::new();
     let root = SourceRootId::from_raw(shard_id);
     // Use a single `FileId` and overwrite inputs as we iterate to keep peak Salsa memo usage
@@ -2672,15 +4760,16 @@ fn index_for_files(
     let file_id = FileId::from_raw(0);
     db.set_source_root(file_id, root);
 
-    let override_file = override_file.map(|(path, text)| (path, Arc::new(text)));
-
     let mut symbols = Vec::new();
+    let mut files_done = 0;
     for file in files {
         Cancelled::check(cancel)?;
-        let text = if let Some((_, override_text)) =
-            override_file.as_ref().filter(|(path, _)| path == &file)
-        {
-            Arc::clone(override_text)
+        let file = match file {
+            Ok(file) => file,
+            Err(err) => return Ok(Err(err)),
+        };
+        let text = if let Some(text) = overrides.remove(&file) {
+            Arc::new(text)
         } else {
             match std::fs::read_to_string(&file)
                 .with_context(|| format!("read {file:?}"))
@@ -2875,6 +4964,14 @@ fn index_for_files(
         for item in tree.items.iter() {
             collect_item_symbols(tree.as_ref(), *item, &line_index, &text, &file, &mut symbols);
         }
+
+        files_done += 1;
+        if files_done % INDEX_PROGRESS_INTERVAL_FILES == 0 {
+            progress(files_done);
+        }
+    }
+    if files_done % INDEX_PROGRESS_INTERVAL_FILES != 0 {
+        progress(files_done);
     }
 
     symbols.sort_by(|a, b| {
@@ -2888,6 +4985,61 @@ fn index_for_files(
     Ok(Ok(symbols))
 }
 
+/// Search `files` (read from disk) for references to `name`; see [`find_name_references`].
+fn find_references_in_files(
+    files: Vec<String>,
+    name: &str,
+    cancel: &CancellationToken,
+) -> std::result::Result<anyhow::Result<Vec<SymbolReference>>, Cancelled> {
+    let mut references = Vec::new();
+    for file in files {
+        Cancelled::check(cancel)?;
+        let text = match std::fs::read_to_string(&file) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Ok(Err(anyhow!(err).context(format!("read {file:?}")))),
+        };
+        references.extend(find_name_references(&file, &text, name));
+    }
+    Ok(Ok(references))
+}
+
+/// Every occurrence of `name` in `text` that is a whole Java identifier (not part of a longer
+/// one).
+fn find_name_references(path: &str, text: &str, name: &str) -> Vec<SymbolReference> {
+    if name.is_empty() {
+        return Vec::new();
+    }
+    let is_ident = |ch: char| ch.is_alphanumeric() || ch == '_' || ch == '$';
+    let line_index = nova_core::LineIndex::new(text);
+    text.match_indices(name)
+        .filter(|(start, _)| {
+            let before = text[..*start].chars().next_back();
+            let after = text[start + name.len()..].chars().next();
+            !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+        })
+        .map(|(start, _)| {
+            let offset = u32::try_from(start).unwrap_or(u32::MAX);
+            let pos = line_index.position(text, nova_core::TextSize::from(offset));
+            SymbolReference {
+                path: path.to_string(),
+                offset,
+                line: pos.line,
+                column: pos.character,
+            }
+        })
+        .collect()
+}
+
+/// Concatenate per-shard references, sorted by path and then offset.
+fn merge_references(
+    shards: impl IntoIterator<Item = Vec<SymbolReference>>,
+) -> Vec<SymbolReference> {
+    let mut references: Vec<SymbolReference> = shards.into_iter().flatten().collect();
+    references.sort_by(|a, b| a.path.cmp(&b.path).then(a.offset.cmp(&b.offset)));
+    references
+}
+
 fn build_global_symbols<'a>(
     shard_indexes: impl IntoIterator<Item = &'a ShardIndex>,
 ) -> Vec<Symbol> {
@@ -2911,10 +5063,18 @@ thread_local! {
         RefCell::new(TrigramCandidateScratch::default());
 }
 
+/// The router's merged workspace symbol index.
+///
+/// `pub` only so `benches/global_symbol_index.rs` can time it; not part of the API.
 #[derive(Debug, Clone)]
-struct GlobalSymbolIndex {
+#[doc(hidden)]
+pub struct GlobalSymbolIndex {
     update_id: u64,
     symbols: Vec<Symbol>,
+    /// Camel-hump initials of each symbol name (see [`camel_humps`]), parallel to `symbols`.
+    humps: Vec<String>,
+    /// Trigrams of each symbol's name and camel humps, so acronym queries (`AIOOBE`) find
+    /// candidates too.
     trigram: TrigramIndex,
     prefix1: Vec<Vec<u32>>,
 }
@@ -2924,6 +5084,7 @@ impl Default for GlobalSymbolIndex {
         Self {
             update_id: 0,
             symbols: Vec::new(),
+            humps: Vec::new(),
             trigram: TrigramIndexBuilder::new().build(),
             prefix1: vec![Vec::new(); 256],
         }
@@ -2931,16 +5092,19 @@ impl Default for GlobalSymbolIndex {
 }
 
 impl GlobalSymbolIndex {
-    fn new(symbols: Vec<Symbol>, update_id: u64) -> Self {
+    pub fn new(symbols: Vec<Symbol>, update_id: u64) -> Self {
         let mut prefix1: Vec<Vec<u32>> = vec![Vec::new(); 256];
         let mut builder = TrigramIndexBuilder::new();
+        let mut humps = Vec::with_capacity(symbols.len());
 
         for (id, sym) in symbols.iter().enumerate() {
             let id_u32: u32 = id
                 .try_into()
                 .unwrap_or_else(|_| panic!("symbol index too large: {id}"));
 
-            builder.insert(id_u32, &sym.name);
+            let sym_humps = camel_humps(&sym.name);
+            builder.insert2(id_u32, &sym.name, &sym_humps);
+            humps.push(sym_humps);
 
             if let Some(&b0) = sym.name.as_bytes().first() {
                 prefix1[b0.to_ascii_lowercase() as usize].push(id_u32);
@@ -2950,12 +5114,17 @@ impl GlobalSymbolIndex {
         Self {
             update_id,
             symbols,
+            humps,
             trigram: builder.build(),
             prefix1,
         }
     }
 
-    fn search(&self, query: &str, limit: usize) -> Vec<Symbol> {
+    /// Returns up to `limit` symbols matching `query`, best match first.
+    ///
+    /// See [`SymbolMatcher`] for the supported query forms. Results are ordered by
+    /// [`MatchScore`], then by name; `limit` applies after scoring.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<Symbol> {
         if limit == 0 || self.symbols.is_empty() {
             return Vec::new();
         }
@@ -2964,7 +5133,9 @@ impl GlobalSymbolIndex {
             return self.symbols.iter().take(limit).cloned().collect();
         }
 
-        let mut matcher = FuzzyMatcher::new(query);
+        let mut matcher = SymbolMatcher::new(query);
+        let name_query = matcher.name_query().to_owned();
+        let query = name_query.as_str();
 
         let mut scored = BinaryHeap::with_capacity(limit);
 
@@ -3054,6 +5225,12 @@ impl GlobalSymbolIndex {
                     limit,
                     &mut scored,
                 );
+                self.score_candidates_top_k(
+                    self.hump_candidates(&matcher, query_first, candidates),
+                    &mut matcher,
+                    limit,
+                    &mut scored,
+                );
                 true
             }
         });
@@ -3085,10 +5262,36 @@ impl GlobalSymbolIndex {
         self.finish_top_k(scored, limit)
     }
 
+    /// Candidates for a camel-hump match that the trigram filter missed.
+    ///
+    /// Trigrams only find humps matched consecutively (`NPE` in `NullPointerException`), so hump
+    /// queries also check the camel humps of every symbol sharing the query's first letter
+    /// (`NewParserForEvents`).
+    fn hump_candidates(
+        &self,
+        matcher: &SymbolMatcher,
+        query_first: Option<u8>,
+        trigram_candidates: &[u32],
+    ) -> Vec<u32> {
+        let bucket = match query_first {
+            Some(b0) if matcher.matches_humps() => self.prefix1[b0 as usize].as_slice(),
+            _ => &[],
+        };
+        bucket
+            .iter()
+            .copied()
+            .filter(|&id| {
+                trigram_candidates.binary_search(&id).is_err()
+                    && camel_hump_score(&matcher.hump_query, self.humps[id as usize].as_bytes())
+                        .is_some()
+            })
+            .collect()
+    }
+
     fn score_candidates_top_k<'a>(
         &'a self,
         ids: impl IntoIterator<Item = u32>,
-        matcher: &mut FuzzyMatcher,
+        matcher: &mut SymbolMatcher,
         limit: usize,
         out: &mut BinaryHeap<ScoredSymbolHeapItem<'a>>,
     ) {
@@ -3100,7 +5303,7 @@ impl GlobalSymbolIndex {
             let Some(sym) = self.symbols.get(id as usize) else {
                 continue;
             };
-            if let Some(score) = matcher.score(&sym.name) {
+            if let Some(score) = matcher.score(sym, &self.humps[id as usize]) {
                 let scored = LocalScoredSymbol { id, score };
                 if out.len() < limit {
                     out.push(ScoredSymbolHeapItem {
@@ -3170,14 +5373,14 @@ impl GlobalSymbolIndex {
     fn score_candidates_all(
         &self,
         ids: impl IntoIterator<Item = u32>,
-        matcher: &mut FuzzyMatcher,
+        matcher: &mut SymbolMatcher,
         out: &mut Vec<LocalScoredSymbol>,
     ) {
         for id in ids {
             let Some(sym) = self.symbols.get(id as usize) else {
                 continue;
             };
-            if let Some(score) = matcher.score(&sym.name) {
+            if let Some(score) = matcher.score(sym, &self.humps[id as usize]) {
                 out.push(LocalScoredSymbol { id, score });
             }
         }
@@ -3195,7 +5398,9 @@ impl GlobalSymbolIndex {
             return self.symbols.iter().take(limit).cloned().collect();
         }
 
-        let mut matcher = FuzzyMatcher::new(query);
+        let mut matcher = SymbolMatcher::new(query);
+        let name_query = matcher.name_query().to_owned();
+        let query = name_query.as_str();
 
         let mut scored = Vec::new();
 
@@ -3248,6 +5453,8 @@ impl GlobalSymbolIndex {
 
         if !candidates.is_empty() {
             self.score_candidates_all(candidates.iter().copied(), &mut matcher, &mut scored);
+            let humps = self.hump_candidates(&matcher, query_first, candidates);
+            self.score_candidates_all(humps, &mut matcher, &mut scored);
             return self.finish(scored, limit);
         }
 
@@ -3269,6 +5476,168 @@ impl GlobalSymbolIndex {
     }
 }
 
+/// Scores symbols against a workspace symbol query.
+///
+/// - The query is matched fuzzily against the symbol name ([`FuzzyMatcher`]).
+/// - An ASCII query is also matched against the name's camel humps, so `AIOOBE` finds
+///   `ArrayIndexOutOfBoundsException` (see [`camel_hump_score`]).
+/// - In a qualified query (`o.e.MyService`) only the trailing segment is matched against the
+///   name; the earlier segments must be prefixes of directories in the symbol's path, in order
+///   (`org/example/MyService.java`).
+struct SymbolMatcher {
+    name: FuzzyMatcher,
+    /// ASCII-lowercased query, or empty when it can't match camel humps.
+    hump_query: Vec<u8>,
+    /// ASCII-lowercased package segments of a qualified query.
+    qualifier: Vec<String>,
+}
+
+impl SymbolMatcher {
+    fn new(query: &str) -> Self {
+        let (qualifier, name) = match query.rsplit_once('.') {
+            Some((qualifier, name)) => (
+                qualifier
+                    .split('.')
+                    .filter(|segment| !segment.is_empty())
+                    .map(|segment| segment.to_ascii_lowercase())
+                    .collect(),
+                name,
+            ),
+            None => (Vec::new(), query),
+        };
+        let hump_query = if name.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            name.bytes().map(|b| b.to_ascii_lowercase()).collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            name: FuzzyMatcher::new(name),
+            hump_query,
+            qualifier,
+        }
+    }
+
+    fn matches_humps(&self) -> bool {
+        !self.hump_query.is_empty()
+    }
+
+    /// The part of the query matched against symbol names.
+    fn name_query(&self) -> &str {
+        self.name.query()
+    }
+
+    fn score(&mut self, sym: &Symbol, humps: &str) -> Option<MatchScore> {
+        if !self.qualifier_matches(&sym.path) {
+            return None;
+        }
+        let fuzzy = self.name.score(&sym.name);
+        let hump = camel_hump_score(&self.hump_query, humps.as_bytes());
+        match (fuzzy, hump) {
+            (Some(score), _) if score.kind == MatchKind::Prefix => Some(score),
+            (Some(score), Some(hump)) => Some(MatchScore {
+                kind: MatchKind::Fuzzy,
+                score: score.score.max(hump),
+            }),
+            (Some(score), None) => Some(score),
+            (None, Some(hump)) => Some(MatchScore {
+                kind: MatchKind::Fuzzy,
+                score: hump,
+            }),
+            (None, None) => None,
+        }
+    }
+
+    fn qualifier_matches(&self, path: &str) -> bool {
+        if self.qualifier.is_empty() {
+            return true;
+        }
+        let mut dirs = path.split(['/', '\\']);
+        // The last component is the file name.
+        dirs.next_back();
+        let mut segments = self.qualifier.iter().peekable();
+        for dir in dirs {
+            let Some(segment) = segments.peek() else {
+                break;
+            };
+            if dir.len() >= segment.len()
+                && dir.as_bytes()[..segment.len()].eq_ignore_ascii_case(segment.as_bytes())
+            {
+                segments.next();
+            }
+        }
+        segments.peek().is_none()
+    }
+}
+
+/// The lowercased first letter of each camel hump in `name`: `ArrayIndexOutOfBoundsException`
+/// becomes `aioobe` and `parse_XML2` becomes `pxml2`.
+///
+/// A hump starts at the first alphanumeric character, at every uppercase letter, after a
+/// non-alphanumeric separator, and at the first digit of a number.
+fn camel_humps(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut out = String::new();
+    for (i, &b) in bytes.iter().enumerate() {
+        if !b.is_ascii_alphanumeric() {
+            continue;
+        }
+        let starts_hump = match i.checked_sub(1).map(|prev| bytes[prev]) {
+            None => true,
+            Some(prev) => {
+                !prev.is_ascii_alphanumeric()
+                    || b.is_ascii_uppercase()
+                    || (b.is_ascii_digit() && !prev.is_ascii_digit())
+            }
+        };
+        if starts_hump {
+            out.push(b.to_ascii_lowercase() as char);
+        }
+    }
+    out
+}
+
+/// Scores `query` as a subsequence of `humps` (both ASCII-lowercased), or `None` if it isn't one.
+///
+/// Matching consecutive humps, and starting at the first one, scores higher than skipping humps.
+/// The scale is comparable to [`FuzzyMatcher`]'s fuzzy scores, so a good hump match outranks a
+/// scattered subsequence match of the name.
+fn camel_hump_score(query: &[u8], humps: &[u8]) -> Option<i32> {
+    const HUMP_MATCH: i32 = 30;
+    const BONUS_CONSECUTIVE: i32 = 15;
+    const BONUS_FIRST: i32 = 20;
+    const SKIP_PENALTY: i32 = 5;
+    const TRAILING_PENALTY: i32 = 1;
+
+    if query.is_empty() || query.len() > humps.len() {
+        return None;
+    }
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut last: Option<usize> = None;
+    for &q in query {
+        let pos = next + humps[next..].iter().position(|&h| h == q)?;
+        score += HUMP_MATCH;
+        let skipped = match last {
+            None if pos == 0 => {
+                score += BONUS_FIRST;
+                0
+            }
+            None => pos,
+            Some(last) if pos == last + 1 => {
+                score += BONUS_CONSECUTIVE;
+                0
+            }
+            Some(last) => pos - last - 1,
+        };
+        score -= SKIP_PENALTY * skipped as i32;
+        last = Some(pos);
+        next = pos + 1;
+    }
+    score -= TRAILING_PENALTY * (humps.len() - next) as i32;
+    Some(score)
+}
+
 #[derive(Debug, Clone)]
 struct LocalScoredSymbol {
     id: u32,
@@ -3314,8 +5683,61 @@ async fn write_global_symbols(
     *guard = GlobalSymbolIndex::new(symbols, update_id);
 }
 
-async fn collect_java_file_paths(root: &Path) -> Result<Vec<String>> {
-    let mut out = Vec::new();
+/// The `.java` files under a shard root, yielded as the directory walk discovers them.
+///
+/// The walk runs on its own task so consumers (e.g. [`index_for_files`]) can start on the first
+/// files while later directories are still being read; at most [`JAVA_FILE_PATHS_BUFFER`] paths
+/// are held in memory at a time.
+struct JavaFilePaths {
+    rx: mpsc::Receiver<Result<String>>,
+    file_count: Arc<OnceLock<usize>>,
+}
+
+impl JavaFilePaths {
+    /// Start walking `root`, skipping the directories in `nested_roots`.
+    fn walk(root: PathBuf, nested_roots: Vec<PathBuf>) -> Self {
+        let (tx, rx) = mpsc::channel(JAVA_FILE_PATHS_BUFFER);
+        let file_count = Arc::new(OnceLock::new());
+        let walk_file_count = Arc::clone(&file_count);
+        tokio::spawn(async move {
+            match walk_java_file_paths(&root, &nested_roots, &tx).await {
+                Ok(Some(count)) => {
+                    let _ = walk_file_count.set(count);
+                }
+                // The consumer went away.
+                Ok(None) => {}
+                Err(err) => {
+                    let err = err.context(format!("collect files under {}", root.display()));
+                    let _ = tx.send(Err(err)).await;
+                }
+            }
+        });
+        Self { rx, file_count }
+    }
+
+    /// Set to the total number of files once the walk has found them all.
+    fn file_count(&self) -> Arc<OnceLock<usize>> {
+        Arc::clone(&self.file_count)
+    }
+
+    async fn next(&mut self) -> Option<Result<String>> {
+        self.rx.recv().await
+    }
+
+    /// Blocking iterator over the remaining paths, for consumers on a blocking thread pool.
+    fn blocking_iter(mut self) -> impl Iterator<Item = Result<String>> {
+        std::iter::from_fn(move || self.rx.blocking_recv())
+    }
+}
+
+/// Walk `root` depth-first, sending every `.java` file to `tx`; returns the number of files sent,
+/// or `None` if the receiver was dropped before the walk finished.
+async fn walk_java_file_paths(
+    root: &Path,
+    nested_roots: &[PathBuf],
+    tx: &mpsc::Sender<Result<String>>,
+) -> Result<Option<usize>> {
+    let mut count = 0;
     let mut stack = vec![root.to_path_buf()];
 
     while let Some(dir) = stack.pop() {
@@ -3338,58 +5760,76 @@ async fn collect_java_file_paths(root: &Path) -> Result<Vec<String>> {
                 Err(err) => return Err(err).with_context(|| format!("metadata {path:?}")),
             };
             if meta.is_dir() {
-                stack.push(path);
+                if !nested_roots.contains(&path) {
+                    stack.push(path);
+                }
             } else if meta.is_file() && path.extension().and_then(|s| s.to_str()) == Some("java") {
-                out.push(path.to_string_lossy().to_string());
+                if tx
+                    .send(Ok(path.to_string_lossy().to_string()))
+                    .await
+                    .is_err()
+                {
+                    return Ok(None);
+                }
+                count += 1;
             }
         }
     }
 
-    Ok(out)
+    Ok(Some(count))
 }
 
-async fn collect_java_files(root: &Path) -> Result<Vec<FileText>> {
+/// Collect the `.java` files under `root`, skipping the directories in `nested_roots`.
+async fn collect_java_file_paths(root: &Path, nested_roots: &[PathBuf]) -> Result<Vec<String>> {
+    let mut paths = JavaFilePaths::walk(root.to_path_buf(), nested_roots.to_vec());
     let mut out = Vec::new();
-    let mut stack = vec![root.to_path_buf()];
+    while let Some(path) = paths.next().await {
+        out.push(path?);
+    }
+    Ok(out)
+}
 
-    while let Some(dir) = stack.pop() {
-        let mut read_dir = match tokio::fs::read_dir(&dir).await {
-            Ok(read_dir) => read_dir,
+/// Like [`collect_java_file_paths`], but also reads each file.
+async fn collect_java_files(root: &Path, nested_roots: &[PathBuf]) -> Result<Vec<FileText>> {
+    let mut paths = JavaFilePaths::walk(root.to_path_buf(), nested_roots.to_vec());
+    let mut out = Vec::new();
+    while let Some(path) = paths.next().await {
+        let path = path?;
+        let text = match tokio::fs::read_to_string(&path).await {
+            Ok(text) => text,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
-            Err(err) => {
-                return Err(err).with_context(|| format!("read_dir {dir:?}"));
-            }
+            Err(err) => return Err(err).with_context(|| format!("read {path:?}")),
         };
-        while let Some(entry) = read_dir
-            .next_entry()
-            .await
-            .with_context(|| format!("next_entry {dir:?}"))?
-        {
-            let path = entry.path();
-            let meta = match entry.metadata().await {
-                Ok(meta) => meta,
-                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
-                Err(err) => return Err(err).with_context(|| format!("metadata {path:?}")),
-            };
-            if meta.is_dir() {
-                stack.push(path);
-            } else if meta.is_file() && path.extension().and_then(|s| s.to_str()) == Some("java") {
-                let text = match tokio::fs::read_to_string(&path).await {
-                    Ok(text) => text,
-                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
-                    Err(err) => return Err(err).with_context(|| format!("read {path:?}")),
-                };
-                out.push(FileText {
-                    path: path.to_string_lossy().to_string(),
-                    text,
-                });
-            }
-        }
+        out.push(FileText { path, text });
     }
-
     Ok(out)
 }
 
+/// The number and latest modification time of the `.java` files under `root`.
+async fn shard_root_fingerprint(
+    root: &Path,
+    nested_roots: &[PathBuf],
+) -> Result<ShardRootFingerprint> {
+    let mut paths = JavaFilePaths::walk(root.to_path_buf(), nested_roots.to_vec());
+    let mut fingerprint = ShardRootFingerprint::default();
+    while let Some(path) = paths.next().await {
+        let path = path?;
+        let meta = match tokio::fs::metadata(&path).await {
+            Ok(meta) => meta,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err).with_context(|| format!("metadata {path:?}")),
+        };
+        let mtime_nanos = meta
+            .modified()
+            .ok()
+            .and_then(|mtime| mtime.duration_since(std::time::UNIX_EPOCH).ok())
+            .map_or(0, |mtime| mtime.as_nanos() as u64);
+        fingerprint.file_count += 1;
+        fingerprint.max_mtime_nanos = fingerprint.max_mtime_nanos.max(mtime_nanos);
+    }
+    Ok(fingerprint)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3408,7 +5848,7 @@ mod tests {
             listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain("127.0.0.1:0".parse().unwrap())),
             worker_command: PathBuf::from("nova-worker"),
             cache_dir: std::env::temp_dir(),
-            auth_token: Some(token.to_string()),
+            auth: Some(AuthValidator::from_token(token)),
             allow_insecure_tcp: false,
             max_rpc_bytes: DEFAULT_MAX_RPC_BYTES,
             max_inflight_handshakes: DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -3429,6 +5869,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shard_layout_reload_keeps_ids_of_unchanged_roots() {
+        let layout = |roots: &[&str]| WorkspaceLayout {
+            source_roots: roots
+                .iter()
+                .map(|root| SourceRoot {
+                    path: PathBuf::from(root),
+                })
+                .collect(),
+        };
+
+        let mut shards = ShardLayout::new(&layout(&["/ws/a", "/ws/b", "/ws/c"]));
+        assert_eq!(shards.shard_ids(), vec![0, 1, 2]);
+
+        let diff = shards.reload(&layout(&["/ws/c", "/ws/d", "/ws/a"]));
+        assert_eq!(diff.added, vec![(3, PathBuf::from("/ws/d"))]);
+        assert_eq!(diff.removed, vec![(1, PathBuf::from("/ws/b"))]);
+        assert_eq!(shards.shard_ids(), vec![2, 3, 0]);
+        assert_eq!(shards.root(0), Some(Path::new("/ws/a")));
+        assert_eq!(shards.shard_for_path(Path::new("/ws/c/X.java")), Some(2));
+        assert_eq!(shards.shard_for_path(Path::new("/ws/b/X.java")), None);
+
+        // Removed ids are never handed out again, even if the root comes back.
+        let diff = shards.reload(&layout(&["/ws/b"]));
+        assert_eq!(diff.added, vec![(4, PathBuf::from("/ws/b"))]);
+        assert_eq!(
+            diff.removed.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![2, 3, 0]
+        );
+    }
+
+    #[test]
+    fn shard_layout_tracks_shards_left_unindexed_by_a_reload() {
+        let layout = |roots: &[&str]| WorkspaceLayout {
+            source_roots: roots
+                .iter()
+                .map(|root| SourceRoot {
+                    path: PathBuf::from(root),
+                })
+                .collect(),
+        };
+        let target_ids = |shards: &ShardLayout| {
+            let (_, targets) = shards.index_targets();
+            targets.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+
+        let mut shards = ShardLayout::new(&layout(&["/ws/a", "/ws/b"]));
+        assert_eq!(target_ids(&shards), vec![0, 1]);
+
+        // A new root inside `/ws/a` takes files away from shard 0, so both need indexing.
+        shards.reload(&layout(&["/ws/a", "/ws/b", "/ws/a/gen"]));
+        assert_eq!(target_ids(&shards), vec![0, 2]);
+
+        // Indexing results from before the latest reload don't count.
+        let (generation, _) = shards.index_targets();
+        shards.reload(&layout(&["/ws/a", "/ws/b", "/ws/a/gen", "/ws/c"]));
+        shards.mark_indexed(0, generation);
+        assert_eq!(target_ids(&shards), vec![0, 2, 3]);
+
+        let (generation, _) = shards.index_targets();
+        for id in [0, 2, 3] {
+            shards.mark_indexed(id, generation);
+        }
+        assert_eq!(target_ids(&shards), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn shard_layout_assigns_nested_files_to_the_longest_root() {
+        let layout = |roots: &[&str]| WorkspaceLayout {
+            source_roots: roots
+                .iter()
+                .map(|root| SourceRoot {
+                    path: PathBuf::from(root),
+                })
+                .collect(),
+        };
+
+        // The assignment does not depend on layout order.
+        for roots in [["/ws/src", "/ws/src/gen"], ["/ws/src/gen", "/ws/src"]] {
+            let layout = layout(&roots);
+            layout.validate().unwrap();
+            let shards = ShardLayout::new(&layout);
+            let outer = shards.shard_for_path(Path::new("/ws/src/A.java")).unwrap();
+            let inner = shards
+                .shard_for_path(Path::new("/ws/src/gen/B.java"))
+                .unwrap();
+            assert_ne!(outer, inner);
+            assert_eq!(shards.root(outer), Some(Path::new("/ws/src")));
+            assert_eq!(shards.root(inner), Some(Path::new("/ws/src/gen")));
+            assert_eq!(
+                shards.nested_roots(Path::new("/ws/src")),
+                vec![PathBuf::from("/ws/src/gen")]
+            );
+            assert!(shards.nested_roots(Path::new("/ws/src/gen")).is_empty());
+        }
+
+        assert!(layout(&["/ws/a", "/ws/b", "/ws/a"]).validate().is_err());
+    }
+
     #[test]
     fn global_symbol_search_prefers_prefix_matches() {
         let symbols = vec![
@@ -3451,6 +5990,57 @@ mod tests {
         assert_eq!(results[0].name, "foobar");
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn java_file_paths_yields_every_java_file_and_nothing_else() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path().join("src");
+        let nested = root.join("nested");
+        for dir in [
+            root.join("com/example/deep"),
+            root.join("resources"),
+            nested.clone(),
+        ] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        for file in [
+            "A.java",
+            "com/example/B.java",
+            "com/example/deep/C.java",
+            "README.md",
+            "com/example/B.class",
+            "resources/app.properties",
+            "nested/Skipped.java",
+        ] {
+            std::fs::write(root.join(file), "class X {}").unwrap();
+        }
+        // A directory with a `.java` name is not a file.
+        std::fs::create_dir_all(root.join("Dir.java")).unwrap();
+
+        let mut paths = JavaFilePaths::walk(root.clone(), vec![nested]);
+        let file_count = paths.file_count();
+        let mut found = Vec::new();
+        while let Some(path) = paths.next().await {
+            found.push(path.unwrap());
+        }
+        found.sort();
+
+        let mut expected = ["A.java", "com/example/B.java", "com/example/deep/C.java"]
+            .map(|file| root.join(file).to_string_lossy().to_string())
+            .to_vec();
+        expected.sort();
+        assert_eq!(found, expected);
+        assert_eq!(file_count.get(), Some(&3));
+
+        // The blocking iterator sees the same files.
+        let paths = JavaFilePaths::walk(root, vec![tmp.path().join("src/nested")]);
+        let blocking = tokio::task::spawn_blocking(move || {
+            paths.blocking_iter().collect::<Result<Vec<_>>>().unwrap()
+        })
+        .await
+        .unwrap();
+        assert_eq!(blocking.len(), 3);
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn drain_worker_output_truncates_overlong_lines() {
         const MAX_WORKER_OUTPUT_LINE_BYTES: usize = 64 * 1024;
@@ -3583,6 +6173,106 @@ mod tests {
         assert_eq!(results[0].name, "FooBar");
     }
 
+    fn symbol(name: &str, path: &str) -> Symbol {
+        Symbol {
+            name: name.into(),
+            path: path.into(),
+            line: 0,
+            column: 0,
+        }
+    }
+
+    fn names(results: &[Symbol]) -> Vec<&str> {
+        results.iter().map(|sym| sym.name.as_str()).collect()
+    }
+
+    #[test]
+    fn global_symbol_search_matches_camel_humps() {
+        let index = GlobalSymbolIndex::new(
+            vec![
+                symbol("ArrayIndexOutOfBoundsException", "a.java"),
+                symbol("ArrayList", "b.java"),
+                symbol("Kaioken", "c.java"),
+                symbol("NewParserForEvents", "d.java"),
+                symbol("NullPointerException", "e.java"),
+            ],
+            0,
+        );
+
+        // None of the query's trigrams occur in the name itself.
+        assert_eq!(
+            names(&index.search("AIOOBE", 10)),
+            vec!["ArrayIndexOutOfBoundsException"]
+        );
+        assert_eq!(
+            names(&index.search("aioobe", 10)),
+            vec!["ArrayIndexOutOfBoundsException"]
+        );
+
+        // Consecutive humps from the first one beat a match that skips a hump.
+        assert_eq!(
+            names(&index.search("NPE", 10)),
+            vec!["NullPointerException", "NewParserForEvents"]
+        );
+    }
+
+    #[test]
+    fn global_symbol_search_matches_qualified_queries() {
+        let index = GlobalSymbolIndex::new(
+            vec![
+                symbol("MyService", "/ws/src/org/example/MyService.java"),
+                symbol("MyService", "/ws/src/com/other/MyService.java"),
+                symbol(
+                    "MyServiceImpl",
+                    "/ws/src/org/example/impl/MyServiceImpl.java",
+                ),
+                symbol("Unrelated", "/ws/src/org/example/Unrelated.java"),
+            ],
+            0,
+        );
+
+        let results = index.search("o.e.MyService", 10);
+        assert_eq!(names(&results), vec!["MyService", "MyServiceImpl"]);
+        assert_eq!(results[0].path, "/ws/src/org/example/MyService.java");
+
+        let results = index.search("com.MySe", 10);
+        assert_eq!(names(&results), vec!["MyService"]);
+        assert_eq!(results[0].path, "/ws/src/com/other/MyService.java");
+
+        // Segments must match directories in order, and never the file name.
+        assert!(index.search("e.o.MyService", 10).is_empty());
+        assert!(index.search("MyService.MyService", 10).is_empty());
+
+        // A trailing `.` lists everything in the matching packages.
+        assert_eq!(
+            names(&index.search("org.example.", 10)),
+            vec!["MyService", "Unrelated", "MyServiceImpl"]
+        );
+    }
+
+    #[test]
+    fn global_symbol_search_orders_by_score_then_name_and_limits_after_scoring() {
+        let index = GlobalSymbolIndex::new(
+            vec![
+                symbol("aXXXXb", "a.java"),
+                symbol("ab", "b.java"),
+                symbol("ab", "a.java"),
+                symbol("abc", "c.java"),
+            ],
+            0,
+        );
+
+        // `aXXXXb` sorts first by name but is only a fuzzy match, so it must not take the only
+        // slot before scoring.
+        let results = index.search("ab", 1);
+        assert_eq!(names(&results), vec!["ab"]);
+        assert_eq!(results[0].path, "a.java");
+
+        let results = index.search("ab", 10);
+        assert_eq!(names(&results), vec!["ab", "ab", "abc", "aXXXXb"]);
+        assert_eq!(results[1].path, "b.java");
+    }
+
     #[test]
     fn global_symbol_search_filters_by_trigrams_for_long_queries() {
         let symbols = vec![
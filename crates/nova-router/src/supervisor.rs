@@ -20,6 +20,11 @@ impl RestartBackoff {
         self.next = self.initial;
     }
 
+    /// The delay the next call to [`RestartBackoff::next_delay`] returns.
+    pub fn peek_delay(&self) -> Duration {
+        self.next
+    }
+
     pub fn next_delay(&mut self) -> Duration {
         let delay = self.next;
         let doubled = self.next.checked_mul(2).unwrap_or(self.max);
@@ -52,6 +57,8 @@ mod tests {
         let _ = backoff.next_delay();
         let _ = backoff.next_delay();
         backoff.reset();
+        assert_eq!(backoff.peek_delay(), Duration::from_millis(50));
         assert_eq!(backoff.next_delay(), Duration::from_millis(50));
+        assert_eq!(backoff.peek_delay(), Duration::from_millis(100));
     }
 }
@@ -5,9 +5,10 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
 use nova_remote_proto::v3::{
-    Capabilities, ProtocolVersion, Request, Response, RpcError as ProtoRpcError, RpcErrorCode,
-    SupportedVersions, WorkerHello,
+    Capabilities, Notification, ProtocolVersion, Request, Response, RpcError as ProtoRpcError,
+    RpcErrorCode, SupportedVersions, SymbolReference, WorkerHello,
 };
+use nova_remote_proto::FileText;
 use nova_remote_proto::{RpcMessage, ShardId, ShardIndex, WorkerStats};
 use nova_remote_rpc::{RpcConnection, RpcTransportError};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
@@ -24,8 +25,15 @@ async fn main() -> Result<()> {
     let args = Args::parse()?;
     std::fs::create_dir_all(&args.cache_dir).context("create cache dir")?;
 
-    let cfg = TestWorkerConfig::load(&args.cache_dir);
+    let cfg = TestWorkerConfig::load(&args.cache_dir, args.shard_id);
     let attempt = record_attempt(&args.cache_dir, args.shard_id)?;
+    // Lets tests kill the current worker of a shard.
+    std::fs::write(
+        args.cache_dir
+            .join(format!("worker-shard{}.pid", args.shard_id)),
+        std::process::id().to_string(),
+    )
+    .context("write pid file")?;
 
     println!("test worker shard {} attempt {}", args.shard_id, attempt);
     eprintln!("test worker shard {} attempt {}", args.shard_id, attempt);
@@ -38,6 +46,16 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
+    if cfg.crash_on_cached_index
+        && nova_cache::shard_cache_path(&args.cache_dir, args.shard_id).exists()
+    {
+        eprintln!(
+            "test worker shard {} crashing on cached index",
+            args.shard_id
+        );
+        std::process::exit(2);
+    }
+
     if attempt <= cfg.connect_delay_attempts && cfg.connect_delay_ms > 0 {
         tokio::time::sleep(Duration::from_millis(cfg.connect_delay_ms)).await;
     }
@@ -81,6 +99,8 @@ struct WorkerState {
     revision: u64,
     index_generation: u64,
     file_count: u32,
+    /// The files of the last `IndexShard` request, searched by `FindReferences`.
+    files: Vec<FileText>,
 }
 
 impl WorkerState {
@@ -90,7 +110,24 @@ impl WorkerState {
             revision: 0,
             index_generation: 0,
             file_count: 0,
+            files: Vec::new(),
+        }
+    }
+
+    /// Every occurrence of `name` (even inside longer identifiers), in file order.
+    fn references(&self, name: &str) -> Vec<SymbolReference> {
+        let mut references = Vec::new();
+        for file in &self.files {
+            for (offset, _) in file.text.match_indices(name) {
+                references.push(SymbolReference {
+                    path: file.path.clone(),
+                    offset: offset.try_into().unwrap_or(u32::MAX),
+                    line: 0,
+                    column: 0,
+                });
+            }
         }
+        references
     }
 
     fn stats(&self) -> WorkerStats {
@@ -344,15 +381,20 @@ async fn run_v3(
     let state = std::sync::Arc::new(tokio::sync::Mutex::new(WorkerState::new(shard_id)));
     let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
     let block_index_until_cancel = cfg.block_index_until_cancel;
+    let reject_update_files = cfg.reject_update_files;
+    let report_index_progress = cfg.report_index_progress;
+    let fail_find_references = cfg.fail_find_references;
 
     conn.set_request_handler({
         let state = state.clone();
         let shutdown_tx = shutdown_tx.clone();
         let cache_dir = cache_dir.clone();
+        let conn = conn.clone();
         move |ctx, req| {
             let state = state.clone();
             let shutdown_tx = shutdown_tx.clone();
             let cache_dir = cache_dir.clone();
+            let conn = conn.clone();
             async move {
                 match req {
                     Request::LoadFiles { revision, files } => {
@@ -370,9 +412,24 @@ async fn run_v3(
                             return Err(cancelled_error());
                         }
 
+                        if report_index_progress {
+                            let file_count = files.len().try_into().unwrap_or(u32::MAX);
+                            let _ = conn
+                                .notify(Notification::IndexProgress {
+                                    revision,
+                                    files_done: file_count,
+                                    file_count,
+                                })
+                                .await;
+                            // Notifications are handled asynchronously by the router; give it a
+                            // chance to see the progress before the response.
+                            tokio::time::sleep(Duration::from_millis(100)).await;
+                        }
+
                         let mut guard = state.lock().await;
                         guard.revision = revision;
                         guard.file_count = files.len().try_into().unwrap_or(u32::MAX);
+                        guard.files = files;
                         guard.index_generation = guard.index_generation.saturating_add(1);
                         Ok(Response::ShardIndex(guard.shard_index()))
                     }
@@ -383,9 +440,34 @@ async fn run_v3(
                         guard.index_generation = guard.index_generation.saturating_add(1);
                         Ok(Response::ShardIndex(guard.shard_index()))
                     }
+                    Request::UpdateFiles { .. } if reject_update_files => Err(ProtoRpcError {
+                        code: RpcErrorCode::InvalidRequest,
+                        message: "unknown request".into(),
+                        retryable: false,
+                        details: None,
+                    }),
+                    Request::UpdateFiles { revision, .. } => {
+                        let mut guard = state.lock().await;
+                        guard.revision = revision;
+                        guard.file_count = guard.file_count.max(1);
+                        guard.index_generation = guard.index_generation.saturating_add(1);
+                        Ok(Response::ShardIndex(guard.shard_index()))
+                    }
                     Request::Diagnostics { .. } => Ok(Response::Diagnostics {
                         diagnostics: Vec::new(),
                     }),
+                    Request::FindReferences { .. } if fail_find_references => Err(ProtoRpcError {
+                        code: RpcErrorCode::Internal,
+                        message: "find references failed".into(),
+                        retryable: false,
+                        details: None,
+                    }),
+                    Request::FindReferences { symbol } => {
+                        let guard = state.lock().await;
+                        Ok(Response::References {
+                            references: guard.references(&symbol.name),
+                        })
+                    }
                     Request::GetWorkerStats => {
                         let guard = state.lock().await;
                         Ok(Response::WorkerStats(guard.stats()))
@@ -543,17 +625,35 @@ struct TestWorkerConfig {
     exit_after_handshake_attempts: u32,
     exit_after_handshake_delay_ms: u64,
     block_index_until_cancel: bool,
+    /// Answer `UpdateFiles` like a worker that predates it.
+    reject_update_files: bool,
+    /// Send one `IndexProgress` notification before answering `IndexShard`.
+    report_index_progress: bool,
+    /// Answer `FindReferences` with an error.
+    fail_find_references: bool,
+    /// Exit with status 2 on startup while the shard has a cached index, like a worker that
+    /// crashes on a corrupt cache.
+    crash_on_cached_index: bool,
 }
 
 impl TestWorkerConfig {
-    fn load(cache_dir: &Path) -> Self {
-        let path = cache_dir.join("nova-router-test-worker.conf");
-        let contents = match std::fs::read_to_string(&path) {
-            Ok(contents) => contents,
-            Err(_) => return Self::default(),
-        };
-
+    /// Load `nova-router-test-worker.conf`, then apply the keys of the shard's
+    /// `nova-router-test-worker-shard{N}.conf` on top.
+    fn load(cache_dir: &Path, shard_id: ShardId) -> Self {
         let mut cfg = Self::default();
+        for name in [
+            "nova-router-test-worker.conf".to_string(),
+            format!("nova-router-test-worker-shard{shard_id}.conf"),
+        ] {
+            if let Ok(contents) = std::fs::read_to_string(cache_dir.join(name)) {
+                cfg.apply(&contents);
+            }
+        }
+        cfg
+    }
+
+    fn apply(&mut self, contents: &str) {
+        let cfg = self;
         for line in contents.lines() {
             let line = line.trim();
             if line.is_empty() || line.starts_with('#') {
@@ -590,11 +690,21 @@ impl TestWorkerConfig {
                 "block_index_until_cancel" => {
                     cfg.block_index_until_cancel = parse_bool(value);
                 }
+                "reject_update_files" => {
+                    cfg.reject_update_files = parse_bool(value);
+                }
+                "report_index_progress" => {
+                    cfg.report_index_progress = parse_bool(value);
+                }
+                "fail_find_references" => {
+                    cfg.fail_find_references = parse_bool(value);
+                }
+                "crash_on_cached_index" => {
+                    cfg.crash_on_cached_index = parse_bool(value);
+                }
                 _ => {}
             }
         }
-
-        cfg
     }
 }
 
@@ -0,0 +1,131 @@
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use nova_remote_proto::ShardId;
+use nova_router::{
+    AuthValidator, DistributedRouterConfig, ListenAddr, QueryRouter, SourceRoot, WorkspaceLayout,
+};
+use tokio::net::UnixStream;
+
+use crate::remote_rpc_util;
+
+async fn start_router(dir: &Path, shards: usize, auth: AuthValidator) -> Result<QueryRouter> {
+    let socket_path = dir.join("router.sock");
+    let cache_dir = dir.join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+
+    let mut source_roots = Vec::new();
+    for idx in 0..shards {
+        let root = dir.join(format!("root{idx}"));
+        tokio::fs::create_dir_all(&root).await?;
+        source_roots.push(SourceRoot { path: root });
+    }
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(socket_path.clone()),
+        worker_command: PathBuf::from("unused-worker-bin"),
+        cache_dir,
+        auth: Some(auth),
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: false,
+    };
+    let router = QueryRouter::new_distributed(config, WorkspaceLayout { source_roots }).await?;
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+    while std::fs::metadata(&socket_path).is_err() {
+        if tokio::time::Instant::now() >= deadline {
+            bail!("timed out waiting for router socket {socket_path:?} to be created");
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    Ok(router)
+}
+
+/// Handshake as a worker for `shard_id`; returns the rejection message if the router refused.
+async fn connect(dir: &Path, shard_id: ShardId, token: &str) -> Result<Option<String>> {
+    let socket_path = dir.join("router.sock");
+    let res = remote_rpc_util::connect_and_handshake_worker(
+        || async {
+            UnixStream::connect(&socket_path)
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        shard_id,
+        Some(token.to_string()),
+    )
+    .await;
+    match res {
+        Ok(conn) => {
+            conn.shutdown().await;
+            Ok(None)
+        }
+        Err(err) => Ok(Some(err.to_string())),
+    }
+}
+
+fn assert_rejected(res: Option<String>, code: &str) {
+    let Some(msg) = res else {
+        panic!("expected the handshake to be rejected with {code}");
+    };
+    assert!(
+        msg.contains(&format!("code={code}")),
+        "unexpected rejection: {msg:?}"
+    );
+}
+
+#[tokio::test]
+async fn rotated_out_tokens_are_accepted_until_revoked() -> Result<()> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path();
+    let router = start_router(dir, 3, AuthValidator::from_token("old-token")).await?;
+
+    assert_eq!(connect(dir, 0, "old-token").await?, None);
+
+    // Rotation window: both tokens work.
+    router.set_auth_tokens("new-token".into(), vec!["old-token".into()])?;
+    assert_eq!(connect(dir, 1, "old-token").await?, None);
+    assert_eq!(connect(dir, 1, "new-token").await?, None);
+
+    // Revoked: the old token is reported as expired, unknown tokens as unauthorized.
+    router.set_auth_tokens("new-token".into(), Vec::new())?;
+    assert_rejected(connect(dir, 2, "old-token").await?, "ExpiredToken");
+    assert_rejected(connect(dir, 2, "bogus-token").await?, "Unauthorized");
+    assert_eq!(connect(dir, 2, "new-token").await?, None);
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn shard_tokens_only_authenticate_their_shard() -> Result<()> {
+    let tmp = tempfile::TempDir::new()?;
+    let dir = tmp.path();
+    let auth = AuthValidator::from_token("shared-token");
+    let router = start_router(dir, 3, auth.clone()).await?;
+
+    router.set_shard_auth_tokens(0, "shard0-token".into(), Vec::new())?;
+    auth.set_shard_tokens(1, "shard1-token".into(), Vec::new());
+    assert_eq!(auth.primary_token(0), "shard0-token");
+    assert_eq!(auth.primary_token(2), "shared-token");
+
+    // A shard's token can't be used to attach as another shard...
+    assert_rejected(connect(dir, 1, "shard0-token").await?, "Unauthorized");
+    assert_rejected(connect(dir, 2, "shard0-token").await?, "Unauthorized");
+    // ...and shards with their own tokens no longer accept the shared one.
+    assert_rejected(connect(dir, 0, "shared-token").await?, "Unauthorized");
+
+    assert_eq!(connect(dir, 0, "shard0-token").await?, None);
+    assert_eq!(connect(dir, 1, "shard1-token").await?, None);
+    assert_eq!(connect(dir, 2, "shared-token").await?, None);
+
+    router.shutdown().await?;
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use nova_router::{
+    DistributedRouterConfig, FileChange, ListenAddr, QueryRouter, SourceRoot, WorkspaceLayout,
+};
+use tempfile::TempDir;
+
+fn layout(roots: &[&Path]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        source_roots: roots
+            .iter()
+            .map(|root| SourceRoot {
+                path: root.to_path_buf(),
+            })
+            .collect(),
+    }
+}
+
+fn upsert(path: &Path, text: &str) -> FileChange {
+    FileChange::Upsert {
+        path: path.to_path_buf(),
+        text: text.to_string(),
+    }
+}
+
+async fn has_symbol(router: &QueryRouter, name: &str) -> bool {
+    router
+        .workspace_symbols(name)
+        .await
+        .iter()
+        .any(|s| s.name == name)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn batch_update_applies_upserts_and_deletions_across_shards() -> Result<()> {
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    tokio::fs::create_dir_all(&root_a).await?;
+    tokio::fs::create_dir_all(&root_b).await?;
+    tokio::fs::write(root_a.join("Alpha.java"), "class Alpha {}").await?;
+    tokio::fs::write(root_a.join("Gone.java"), "class Gone {}").await?;
+    tokio::fs::write(root_b.join("Beta.java"), "class Beta {}").await?;
+    tokio::fs::write(root_b.join("Stale.java"), "class Stale {}").await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root_a, &root_b]))?;
+    router.index_workspace().await?;
+    assert!(has_symbol(&router, "Gone").await);
+    assert!(has_symbol(&router, "Stale").await);
+
+    // `Gone.java` is already removed from disk, `Stale.java` is still there when the deletion is
+    // reported, and `Gamma.java` only exists in the editor.
+    tokio::fs::remove_file(root_a.join("Gone.java")).await?;
+    router
+        .update_files(vec![
+            upsert(&root_a.join("Alpha.java"), "class Alpha {} class Alpha2 {}"),
+            FileChange::Delete {
+                path: root_a.join("Gone.java"),
+            },
+            upsert(&root_b.join("Gamma.java"), "class Gamma {}"),
+            FileChange::Delete {
+                path: root_b.join("Stale.java"),
+            },
+        ])
+        .await?;
+
+    for name in ["Alpha", "Alpha2", "Beta", "Gamma"] {
+        assert!(has_symbol(&router, name).await, "missing {name}");
+    }
+    assert!(!has_symbol(&router, "Gone").await);
+    assert!(!has_symbol(&router, "Stale").await);
+
+    // A path outside every source root fails the whole batch.
+    let err = router
+        .update_files(vec![
+            upsert(&root_a.join("Alpha.java"), "class Changed {}"),
+            upsert(&tmp.path().join("elsewhere/X.java"), "class X {}"),
+        ])
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("not in any source root"),
+        "{err:#}"
+    );
+    assert!(!has_symbol(&router, "Changed").await);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn start_distributed(
+    tmp: &TempDir,
+    roots: &[&Path],
+    worker_conf: &str,
+) -> Result<QueryRouter> {
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+    tokio::fs::write(cache_dir.join("nova-router-test-worker.conf"), worker_conf).await?;
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir,
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: true,
+    };
+    QueryRouter::new_distributed(config, layout(roots)).await
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn distributed_batch_update_sends_one_request_per_shard() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    tokio::fs::create_dir_all(&root_a).await?;
+    tokio::fs::create_dir_all(&root_b).await?;
+
+    let router = start_distributed(&tmp, &[&root_a, &root_b], "").await?;
+    let before = router.worker_stats().await?;
+
+    router
+        .update_files(vec![
+            upsert(&root_a.join("A1.java"), "class A1 {}"),
+            upsert(&root_b.join("B1.java"), "class B1 {}"),
+            upsert(&root_a.join("A2.java"), "class A2 {}"),
+            FileChange::Delete {
+                path: root_b.join("B2.java"),
+            },
+        ])
+        .await?;
+
+    let after = router.worker_stats().await?;
+    for shard in [0, 1] {
+        assert_eq!(
+            after[&shard].index_generation,
+            before[&shard].index_generation + 1,
+            "expected shard {shard} to reindex exactly once"
+        );
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn distributed_batch_update_falls_back_for_old_workers() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let root = tmp.path().join("src");
+    tokio::fs::create_dir_all(&root).await?;
+    tokio::fs::write(root.join("A.java"), "class A {}").await?;
+    tokio::fs::write(root.join("B.java"), "class B {}").await?;
+
+    let router = start_distributed(&tmp, &[&root], "reject_update_files=true\n").await?;
+    let start = router.worker_stats().await?[&0].index_generation;
+
+    // Upserts only: one `UpdateFile` per file.
+    router
+        .update_files(vec![
+            upsert(&root.join("A.java"), "class A {}"),
+            upsert(&root.join("C.java"), "class C {}"),
+        ])
+        .await?;
+    let stats = router.worker_stats().await?;
+    assert_eq!(stats[&0].index_generation, start + 2);
+
+    // With a deletion: a single `IndexShard` snapshot without the deleted file.
+    router
+        .update_files(vec![
+            FileChange::Delete {
+                path: root.join("B.java"),
+            },
+            upsert(&root.join("C.java"), "class C {}"),
+        ])
+        .await?;
+    let stats = router.worker_stats().await?;
+    assert_eq!(stats[&0].index_generation, start + 3);
+    assert_eq!(stats[&0].file_count, 2);
+
+    router.shutdown().await?;
+    Ok(())
+}
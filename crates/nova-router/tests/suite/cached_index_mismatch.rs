@@ -29,7 +29,7 @@ async fn cached_index_notification_for_wrong_shard_disconnects_worker() -> Resul
         listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain("127.0.0.1:0".parse()?)),
         worker_command: PathBuf::from("unused"),
         cache_dir,
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
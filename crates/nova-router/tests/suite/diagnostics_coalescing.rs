@@ -0,0 +1,261 @@
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::remote_rpc_util;
+use anyhow::{anyhow, Context, Result};
+use nova_remote_proto::v3::{
+    DiagnosticSeverity, RemoteDiagnostic, Request, Response, RpcError as ProtoRpcError,
+    RpcErrorCode,
+};
+use nova_remote_rpc::RpcConnection;
+use nova_router::{DistributedRouterConfig, ListenAddr, QueryRouter, SourceRoot, WorkspaceLayout};
+use tempfile::TempDir;
+use tokio::net::UnixStream;
+use tokio::sync::mpsc;
+
+const DIAGNOSTICS_DELAY: Duration = Duration::from_millis(300);
+
+#[derive(Debug, PartialEq, Eq)]
+enum WorkerEvent {
+    Started(String),
+    Cancelled(String),
+}
+
+/// A router with one shard whose worker answers `Diagnostics` requests after
+/// [`DIAGNOSTICS_DELAY`], with a single diagnostic whose message is the requested path.
+struct Harness {
+    _tmp: TempDir,
+    src: PathBuf,
+    router: QueryRouter,
+    _conn: RpcConnection,
+    events: mpsc::UnboundedReceiver<WorkerEvent>,
+    max_concurrent: Arc<AtomicUsize>,
+}
+
+impl Harness {
+    async fn start() -> Result<Self> {
+        let tmp = TempDir::new()?;
+        let src = tmp.path().join("shard0").join("src");
+        tokio::fs::create_dir_all(&src).await?;
+        let listen_path = tmp.path().join("router.sock");
+
+        let config = DistributedRouterConfig {
+            listen_addr: ListenAddr::Unix(listen_path.clone()),
+            worker_command: PathBuf::from("unused-worker"),
+            cache_dir: tmp.path().join("cache"),
+            auth: None,
+            allow_insecure_tcp: false,
+            max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+            max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+            max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+            #[cfg(feature = "tls")]
+            tls_client_cert_fingerprint_allowlist: Default::default(),
+            spawn_workers: false,
+        };
+        let layout = WorkspaceLayout {
+            source_roots: vec![SourceRoot { path: src.clone() }],
+        };
+        let router = QueryRouter::new_distributed(config, layout).await?;
+
+        let conn = match remote_rpc_util::connect_and_handshake_worker(
+            || connect_unix_with_retry(&listen_path),
+            0,
+            None,
+        )
+        .await?
+        {
+            remote_rpc_util::ConnectedWorker::V3(conn) => conn,
+            remote_rpc_util::ConnectedWorker::LegacyV2(_) => {
+                return Err(anyhow!("expected a v3 handshake"))
+            }
+        };
+
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_concurrent = Arc::new(AtomicUsize::new(0));
+        let max = max_concurrent.clone();
+        conn.set_request_handler(move |ctx, req| {
+            let events_tx = events_tx.clone();
+            let in_flight = in_flight.clone();
+            let max = max.clone();
+            async move {
+                match req {
+                    Request::Diagnostics { path } => {
+                        let _ = events_tx.send(WorkerEvent::Started(path.clone()));
+                        let running = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max.fetch_max(running, Ordering::SeqCst);
+
+                        let mut cancel = ctx.cancellation();
+                        let cancelled = tokio::select! {
+                            _ = cancel.cancelled() => true,
+                            _ = tokio::time::sleep(DIAGNOSTICS_DELAY) => false,
+                        };
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        if cancelled {
+                            let _ = events_tx.send(WorkerEvent::Cancelled(path));
+                            return Err(ProtoRpcError {
+                                code: RpcErrorCode::Cancelled,
+                                message: "request cancelled".into(),
+                                retryable: true,
+                                details: None,
+                            });
+                        }
+                        Ok(Response::Diagnostics {
+                            diagnostics: vec![diagnostic(&path)],
+                        })
+                    }
+                    Request::Shutdown => Ok(Response::Shutdown),
+                    _ => Ok(Response::Ack),
+                }
+            }
+        });
+
+        Ok(Self {
+            _tmp: tmp,
+            src,
+            router,
+            _conn: conn,
+            events,
+            max_concurrent,
+        })
+    }
+}
+
+async fn next_event(events: &mut mpsc::UnboundedReceiver<WorkerEvent>) -> Result<WorkerEvent> {
+    tokio::time::timeout(Duration::from_secs(5), events.recv())
+        .await
+        .context("timed out waiting for the worker")?
+        .context("worker handler dropped")
+}
+
+fn drain_events(events: &mut mpsc::UnboundedReceiver<WorkerEvent>) -> Vec<WorkerEvent> {
+    std::iter::from_fn(|| events.try_recv().ok()).collect()
+}
+
+fn diagnostic(path: &str) -> RemoteDiagnostic {
+    RemoteDiagnostic {
+        severity: DiagnosticSeverity::Error,
+        line: 1,
+        column: 1,
+        message: path.to_string(),
+        source: None,
+        related: Vec::new(),
+        tags: Vec::new(),
+        fix_hint: None,
+    }
+}
+
+async fn connect_unix_with_retry(socket_path: &Path) -> Result<UnixStream> {
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+    loop {
+        match UnixStream::connect(socket_path).await {
+            Ok(stream) => return Ok(stream),
+            Err(err) => {
+                if tokio::time::Instant::now() >= deadline {
+                    return Err(anyhow!(
+                        "timed out connecting to router at {socket_path:?}: {err}"
+                    ));
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn newer_document_version_cancels_stale_diagnostics_call() -> Result<()> {
+    let mut harness = Harness::start().await?;
+    let Harness {
+        ref router,
+        ref mut events,
+        ref src,
+        ..
+    } = harness;
+    let file = src.join("A.java");
+    let file_str = file.to_string_lossy().to_string();
+
+    let (stale, fresh) = tokio::join!(router.diagnostics_versioned(file.clone(), 1), async {
+        let started = next_event(events).await?;
+        let fresh = router.diagnostics_versioned(file.clone(), 2).await;
+        anyhow::Ok((started, fresh))
+    });
+    let (started, fresh) = fresh?;
+    let later_events = drain_events(events);
+
+    assert!(stale.is_empty(), "superseded call returned {stale:?}");
+    assert_eq!(fresh, vec![diagnostic(&file_str)]);
+    assert_eq!(started, WorkerEvent::Started(file_str.clone()));
+    assert!(
+        later_events.contains(&WorkerEvent::Cancelled(file_str.clone())),
+        "stale call was not cancelled: {later_events:?}"
+    );
+
+    // A request for an older version than the latest is stale and issues no RPC.
+    let (older, newest) = tokio::join!(
+        async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            router.diagnostics_versioned(file.clone(), 2).await
+        },
+        router.diagnostics_versioned(file.clone(), 3),
+    );
+    assert!(older.is_empty());
+    assert_eq!(newest, vec![diagnostic(&file_str)]);
+    assert_eq!(drain_events(events), vec![WorkerEvent::Started(file_str)]);
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn same_version_diagnostics_requests_share_one_call() -> Result<()> {
+    let mut harness = Harness::start().await?;
+    let file = harness.src.join("A.java");
+    let file_str = file.to_string_lossy().to_string();
+
+    let (first, second) = tokio::join!(
+        harness.router.diagnostics_versioned(file.clone(), 7),
+        async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            harness.router.diagnostics_versioned(file.clone(), 7).await
+        },
+    );
+
+    assert_eq!(first, vec![diagnostic(&file_str)]);
+    assert_eq!(second, first);
+    assert_eq!(
+        drain_events(&mut harness.events),
+        vec![WorkerEvent::Started(file_str)]
+    );
+
+    harness.router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_diagnostics_rpcs_per_worker_are_capped() -> Result<()> {
+    let harness = Harness::start().await?;
+    let files = ["A.java", "B.java", "C.java"].map(|name| harness.src.join(name));
+
+    let [a, b, c] = files.clone();
+    let (a, b, c) = tokio::join!(
+        harness.router.diagnostics(a),
+        harness.router.diagnostics(b),
+        harness.router.diagnostics(c),
+    );
+
+    for (file, diagnostics) in files.iter().zip([a, b, c]) {
+        assert_eq!(
+            diagnostics,
+            vec![diagnostic(&file.to_string_lossy())],
+            "{file:?}"
+        );
+    }
+    assert_eq!(harness.max_concurrent.load(Ordering::SeqCst), 2);
+
+    harness.router.shutdown().await?;
+    Ok(())
+}
@@ -47,7 +47,7 @@ async fn distributed_rpc_cancellation_propagates_to_worker() -> anyhow::Result<(
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: worker_bin,
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -174,7 +174,7 @@ async fn distributed_indexing_starts_all_shards_concurrently() -> anyhow::Result
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: worker_bin,
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
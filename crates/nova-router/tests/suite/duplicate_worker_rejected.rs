@@ -21,7 +21,7 @@ async fn concurrent_worker_connections_for_same_shard_are_rejected() -> Result<(
         listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain(addr)),
         worker_command: PathBuf::from("unused"),
         cache_dir: tmp.path().join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
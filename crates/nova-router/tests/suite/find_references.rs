@@ -0,0 +1,143 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use nova_remote_proto::v3::{SymbolKey, SymbolReference};
+use nova_router::{QueryRouter, SourceRoot, WorkspaceLayout};
+use nova_scheduler::CancellationToken;
+use tempfile::TempDir;
+
+fn layout(roots: &[&Path]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        source_roots: roots
+            .iter()
+            .map(|root| SourceRoot {
+                path: root.to_path_buf(),
+            })
+            .collect(),
+    }
+}
+
+async fn write_file(root: &Path, name: &str, text: &str) -> Result<String> {
+    tokio::fs::create_dir_all(root).await?;
+    let path = root.join(name);
+    tokio::fs::write(&path, text)
+        .await
+        .with_context(|| format!("write {name}"))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+fn foo() -> SymbolKey {
+    SymbolKey { name: "Foo".into() }
+}
+
+/// `(path, offset)` of each reference.
+fn locations(references: &[SymbolReference]) -> Vec<(String, u32)> {
+    references
+        .iter()
+        .map(|reference| (reference.path.clone(), reference.offset))
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn in_process_find_references_merges_shards() -> Result<()> {
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    let b = write_file(&root_b, "B.java", "class B {\n  Foo foo;\n}").await?;
+    let a = write_file(&root_a, "A.java", "class Foo { FooBar x; Foo y; }").await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root_a, &root_b]))?;
+    let result = router.find_references(foo()).await?;
+
+    assert!(result.partial.is_empty());
+    assert_eq!(
+        result.references,
+        vec![
+            SymbolReference {
+                path: a.clone(),
+                offset: 6,
+                line: 0,
+                column: 6,
+            },
+            SymbolReference {
+                path: a,
+                offset: 22,
+                line: 0,
+                column: 22,
+            },
+            SymbolReference {
+                path: b,
+                offset: 12,
+                line: 1,
+                column: 2,
+            },
+        ]
+    );
+
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+    assert!(router
+        .find_references_cancelable(cancel, foo())
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn distributed_find_references_reports_failed_shards() -> Result<()> {
+    use std::path::PathBuf;
+
+    use nova_router::{DistributedRouterConfig, ListenAddr, ShardStatus};
+
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    let root_c = tmp.path().join("c");
+    let c = write_file(&root_c, "C.java", "Foo c;").await?;
+    write_file(&root_b, "B.java", "Foo b;").await?;
+    let a2 = write_file(&root_a, "A2.java", "Foo").await?;
+    let a1 = write_file(&root_a, "A1.java", "class A { Foo x; Foo y; }").await?;
+
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+    tokio::fs::write(
+        cache_dir.join("nova-router-test-worker-shard1.conf"),
+        "fail_find_references=true\n",
+    )
+    .await?;
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir,
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: true,
+    };
+    let router = QueryRouter::new_distributed(config, layout(&[&root_a, &root_b, &root_c])).await?;
+    // The test worker answers `FindReferences` from the files it was indexed with.
+    router.index_workspace().await?;
+
+    let result = router.find_references(foo()).await?;
+    assert_eq!(
+        locations(&result.references),
+        vec![(a1.clone(), 10), (a1, 17), (a2, 0), (c, 0)]
+    );
+    assert_eq!(result.partial, vec![1]);
+
+    let health = router.shard_health().await;
+    assert!(
+        matches!(health[1].status, ShardStatus::Degraded { .. }),
+        "{health:?}"
+    );
+
+    router.shutdown().await?;
+    Ok(())
+}
@@ -39,7 +39,7 @@ async fn start_router(
         listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain("127.0.0.1:0".parse()?)),
         worker_command: PathBuf::from("nova-worker"),
         cache_dir: tmp.path().join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes,
@@ -0,0 +1,217 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use nova_router::{IndexProgressEvent, QueryRouter, SourceRoot, WorkspaceLayout};
+use nova_scheduler::CancellationToken;
+use tempfile::TempDir;
+use tokio::sync::mpsc;
+
+fn layout(roots: &[&Path]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        source_roots: roots
+            .iter()
+            .map(|root| SourceRoot {
+                path: root.to_path_buf(),
+            })
+            .collect(),
+    }
+}
+
+fn drain(rx: &mut mpsc::UnboundedReceiver<IndexProgressEvent>) -> Vec<IndexProgressEvent> {
+    let mut events = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+        events.push(event);
+    }
+    events
+}
+
+/// The events of `shard_id`, with `ShardCompleted` durations zeroed.
+fn shard_events(events: &[IndexProgressEvent], shard_id: u32) -> Vec<IndexProgressEvent> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            IndexProgressEvent::ShardStarted { shard_id: id, .. }
+            | IndexProgressEvent::ShardProgress { shard_id: id, .. }
+                if *id == shard_id =>
+            {
+                Some(event.clone())
+            }
+            IndexProgressEvent::ShardCompleted {
+                shard_id: id,
+                symbol_count,
+                ..
+            } if *id == shard_id => Some(IndexProgressEvent::ShardCompleted {
+                shard_id,
+                symbol_count: *symbol_count,
+                duration: Default::default(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+async fn write_files(root: &Path, names: &[&str]) -> Result<()> {
+    tokio::fs::create_dir_all(root).await?;
+    for name in names {
+        tokio::fs::write(
+            root.join(format!("{name}.java")),
+            format!("class {name} {{}}"),
+        )
+        .await
+        .with_context(|| format!("write {name}.java"))?;
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn in_process_index_workspace_reports_shard_progress() -> Result<()> {
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    write_files(&root_a, &["A1", "A2"]).await?;
+    write_files(&root_b, &["B1", "B2", "B3"]).await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root_a, &root_b]))?;
+    let mut rx = router.subscribe_progress();
+    router.index_workspace().await?;
+    let events = drain(&mut rx);
+
+    assert_eq!(
+        shard_events(&events, 0),
+        vec![
+            IndexProgressEvent::ShardStarted {
+                shard_id: 0,
+                file_count: 2,
+            },
+            IndexProgressEvent::ShardProgress {
+                shard_id: 0,
+                files_done: 2,
+            },
+            IndexProgressEvent::ShardCompleted {
+                shard_id: 0,
+                symbol_count: 2,
+                duration: Default::default(),
+            },
+        ]
+    );
+    assert_eq!(
+        shard_events(&events, 1),
+        vec![
+            IndexProgressEvent::ShardStarted {
+                shard_id: 1,
+                file_count: 3,
+            },
+            IndexProgressEvent::ShardProgress {
+                shard_id: 1,
+                files_done: 3,
+            },
+            IndexProgressEvent::ShardCompleted {
+                shard_id: 1,
+                symbol_count: 3,
+                duration: Default::default(),
+            },
+        ]
+    );
+    assert_eq!(events.len(), 7, "{events:?}");
+    assert_eq!(
+        events.last(),
+        Some(&IndexProgressEvent::WorkspaceIndexCompleted { revision: 1 })
+    );
+
+    // Incremental updates are not workspace indexing runs.
+    router
+        .update_file(root_a.join("A1.java"), "class Changed {}".into())
+        .await?;
+    assert_eq!(drain(&mut rx), Vec::new());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn in_process_cancelled_index_workspace_reports_cancelled() -> Result<()> {
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root = tmp.path().join("a");
+    write_files(&root, &["A1"]).await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root]))?;
+    let mut rx = router.subscribe_progress();
+
+    let cancel = CancellationToken::new();
+    let index = router.index_workspace_cancelable(cancel.clone());
+    tokio::pin!(index);
+    // Start the run (file collection is asynchronous, so it can't finish on the first poll).
+    tokio::select! {
+        biased;
+        _ = &mut index => panic!("indexing finished before it was cancelled"),
+        _ = std::future::ready(()) => {}
+    }
+    cancel.cancel();
+    assert!(index.await.is_err());
+
+    assert_eq!(
+        drain(&mut rx).last(),
+        Some(&IndexProgressEvent::Cancelled { revision: 1 })
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn distributed_index_workspace_forwards_worker_progress() -> Result<()> {
+    use std::path::PathBuf;
+
+    use nova_router::{DistributedRouterConfig, ListenAddr};
+
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root = tmp.path().join("a");
+    write_files(&root, &["A1", "A2"]).await?;
+
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+    tokio::fs::write(
+        cache_dir.join("nova-router-test-worker.conf"),
+        "report_index_progress=true\n",
+    )
+    .await?;
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir,
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: true,
+    };
+    let router = QueryRouter::new_distributed(config, layout(&[&root])).await?;
+    let mut rx = router.subscribe_progress();
+    router.index_workspace().await?;
+
+    // The test worker reports an empty index.
+    assert_eq!(
+        shard_events(&drain(&mut rx), 0),
+        vec![
+            IndexProgressEvent::ShardStarted {
+                shard_id: 0,
+                file_count: 2,
+            },
+            IndexProgressEvent::ShardProgress {
+                shard_id: 0,
+                files_done: 2,
+            },
+            IndexProgressEvent::ShardCompleted {
+                shard_id: 0,
+                symbol_count: 0,
+                duration: Default::default(),
+            },
+        ]
+    );
+
+    router.shutdown().await?;
+    Ok(())
+}
@@ -14,7 +14,7 @@ async fn refuses_plain_tcp_on_non_loopback_by_default() {
         ))),
         worker_command: PathBuf::from("nova-worker"),
         cache_dir: std::env::temp_dir(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -48,7 +48,7 @@ async fn refuses_plain_tcp_with_auth_token_by_default() {
         ))),
         worker_command: PathBuf::from("nova-worker"),
         cache_dir: std::env::temp_dir(),
-        auth_token: Some("secret-token".into()),
+        auth: Some(nova_router::AuthValidator::from_token("secret-token")),
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -17,7 +17,7 @@ mod unix {
             // Not used because `spawn_workers` is false.
             worker_command: PathBuf::from("nova-worker"),
             cache_dir: tmp.path().join("cache"),
-            auth_token: None,
+            auth: None,
             allow_insecure_tcp: false,
             max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
             max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -79,7 +79,7 @@ mod unix {
             // Not used because `spawn_workers` is false.
             worker_command: PathBuf::from("nova-worker"),
             cache_dir: tmp.path().join("cache"),
-            auth_token: None,
+            auth: None,
             allow_insecure_tcp: false,
             max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
             max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
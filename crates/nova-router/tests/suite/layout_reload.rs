@@ -0,0 +1,273 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use nova_router::{LayoutDiff, QueryRouter, ShardStatus, SourceRoot, WorkspaceLayout};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn reload_layout_keeps_existing_symbols_and_indexes_new_roots() -> Result<()> {
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    tokio::fs::create_dir_all(&root_a)
+        .await
+        .context("create source root a")?;
+    tokio::fs::create_dir_all(&root_b)
+        .await
+        .context("create source root b")?;
+    tokio::fs::write(
+        root_a.join("Alpha.java"),
+        "package a; public class Alpha {}",
+    )
+    .await
+    .context("write Alpha.java")?;
+    tokio::fs::write(root_b.join("Beta.java"), "package b; public class Beta {}")
+        .await
+        .context("write Beta.java")?;
+
+    let router = QueryRouter::new_in_process(WorkspaceLayout {
+        source_roots: vec![SourceRoot {
+            path: root_a.clone(),
+        }],
+    })?;
+    router.index_workspace().await?;
+    assert!(router
+        .workspace_symbols("Alpha")
+        .await
+        .iter()
+        .any(|s| s.name == "Alpha"));
+
+    // Adding a root must not drop the symbols of the unchanged shard.
+    router
+        .reload_layout(WorkspaceLayout {
+            source_roots: vec![
+                SourceRoot {
+                    path: root_a.clone(),
+                },
+                SourceRoot {
+                    path: root_b.clone(),
+                },
+            ],
+        })
+        .await?;
+    assert!(router
+        .workspace_symbols("Alpha")
+        .await
+        .iter()
+        .any(|s| s.name == "Alpha"));
+
+    // The new root is picked up by the next indexing run.
+    router.index_workspace().await?;
+    assert!(router
+        .workspace_symbols("Beta")
+        .await
+        .iter()
+        .any(|s| s.name == "Beta"));
+
+    // Removing a root drops its symbols immediately, while the remaining shard keeps serving.
+    router
+        .reload_layout(WorkspaceLayout {
+            source_roots: vec![SourceRoot { path: root_b }],
+        })
+        .await?;
+    assert!(router.workspace_symbols("Alpha").await.is_empty());
+    assert!(router
+        .workspace_symbols("Beta")
+        .await
+        .iter()
+        .any(|s| s.name == "Beta"));
+
+    // Files from the removed root are no longer routed anywhere.
+    assert!(router
+        .update_file(
+            root_a.join("Alpha.java"),
+            "package a; public class Alpha {}".into()
+        )
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+fn layout(roots: &[&Path]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        source_roots: roots
+            .iter()
+            .map(|root| SourceRoot {
+                path: root.to_path_buf(),
+            })
+            .collect(),
+    }
+}
+
+async fn write_class(root: &Path, name: &str) -> Result<PathBuf> {
+    tokio::fs::create_dir_all(root).await?;
+    let path = root.join(format!("{name}.java"));
+    tokio::fs::write(&path, format!("public class {name} {{}}")).await?;
+    Ok(path)
+}
+
+/// `last_index_revision` of every shard, in layout order.
+async fn index_revisions(router: &QueryRouter) -> Vec<(u32, Option<u64>)> {
+    router
+        .shard_health()
+        .await
+        .into_iter()
+        .map(|shard| match shard.status {
+            ShardStatus::Healthy {
+                last_index_revision,
+            } => (shard.shard_id, last_index_revision),
+            other => panic!("unexpected shard status: {other:?}"),
+        })
+        .collect()
+}
+
+async fn has_symbol(router: &QueryRouter, name: &str) -> bool {
+    router
+        .workspace_symbols(name)
+        .await
+        .iter()
+        .any(|s| s.name == name)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_layout_indexes_only_new_shards_and_drops_removed_ones() -> Result<()> {
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    let root_c = tmp.path().join("c");
+    write_class(&root_a, "Alpha").await?;
+    write_class(&root_b, "Beta").await?;
+    write_class(&root_c, "Gamma").await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root_a, &root_b]))?;
+    router.index_workspace().await?;
+    let initial = index_revisions(&router).await;
+    assert!(initial.iter().all(|(_, rev)| rev.is_some()), "{initial:?}");
+
+    let diff = router
+        .update_layout(layout(&[&root_a, &root_b, &root_c]))
+        .await?;
+    assert_eq!(
+        diff,
+        LayoutDiff {
+            added: vec![(2, root_c.clone())],
+            removed: vec![],
+        }
+    );
+
+    // Only the new shard is indexed; the others keep their index from the first run.
+    let summary = router.index_workspace_partial().await?;
+    assert_eq!(summary.indexed, vec![2]);
+    let revisions = index_revisions(&router).await;
+    assert_eq!(revisions[..2], initial[..]);
+    assert!(revisions[2].1 > initial[0].1, "{revisions:?}");
+    assert!(has_symbol(&router, "Gamma").await);
+    assert!(has_symbol(&router, "Alpha").await);
+
+    // Removing a root drops its symbols without re-indexing the remaining shards.
+    let diff = router.update_layout(layout(&[&root_a, &root_c])).await?;
+    assert_eq!(diff.added, vec![]);
+    assert_eq!(diff.removed, vec![(1, root_b.clone())]);
+    assert!(!has_symbol(&router, "Beta").await);
+    assert!(has_symbol(&router, "Alpha").await);
+    assert!(has_symbol(&router, "Gamma").await);
+    assert_eq!(
+        index_revisions(&router).await,
+        vec![revisions[0], revisions[2]]
+    );
+
+    // With nothing left over from layout updates, indexing covers every shard again.
+    let summary = router.index_workspace_partial().await?;
+    assert_eq!(summary.indexed, vec![0, 2]);
+    let latest = index_revisions(&router).await;
+    assert!(latest[0].1 > revisions[0].1 && latest[1].1 > revisions[2].1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn update_layout_treats_a_moved_root_as_remove_and_add() -> Result<()> {
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+    let old_root = tmp.path().join("old");
+    let new_root = tmp.path().join("new");
+    write_class(&old_root, "Moved").await?;
+    write_class(&new_root, "Moved").await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&old_root]))?;
+    router.index_workspace().await?;
+
+    let diff = router.update_layout(layout(&[&new_root])).await?;
+    assert_eq!(
+        diff,
+        LayoutDiff {
+            added: vec![(1, new_root.clone())],
+            removed: vec![(0, old_root)],
+        }
+    );
+    assert!(!has_symbol(&router, "Moved").await);
+
+    router.index_workspace().await?;
+    let symbols = router.workspace_symbols("Moved").await;
+    assert_eq!(symbols.len(), 1);
+    assert!(Path::new(&symbols[0].path).starts_with(&new_root));
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn distributed_update_layout_spawns_and_stops_shard_workers() -> Result<()> {
+    use nova_router::{DistributedRouterConfig, ListenAddr};
+
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    let root_c = tmp.path().join("c");
+    write_class(&root_a, "Alpha").await?;
+    write_class(&root_b, "Beta").await?;
+    write_class(&root_c, "Gamma").await?;
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir,
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: true,
+    };
+    let router = QueryRouter::new_distributed(config, layout(&[&root_a, &root_b])).await?;
+    assert_eq!(router.index_workspace_partial().await?.indexed, vec![0, 1]);
+    let initial = index_revisions(&router).await;
+
+    let diff = router
+        .update_layout(layout(&[&root_a, &root_b, &root_c]))
+        .await?;
+    assert_eq!(diff.added, vec![(2, root_c.clone())]);
+
+    // A worker is spawned for the new shard, and only that shard is indexed.
+    let summary = router.index_workspace_partial().await?;
+    assert_eq!(summary.indexed, vec![2], "{summary:?}");
+    let revisions = index_revisions(&router).await;
+    assert_eq!(revisions[..2], initial[..]);
+    assert!(revisions[2].1.is_some(), "{revisions:?}");
+
+    let diff = router.update_layout(layout(&[&root_a, &root_c])).await?;
+    assert_eq!(diff.removed, vec![(1, root_b)]);
+    let supervised: Vec<u32> = router
+        .worker_supervision_info()
+        .await
+        .iter()
+        .map(|info| info.shard_id)
+        .collect();
+    assert_eq!(supervised, vec![0, 2]);
+
+    router.shutdown().await?;
+    Ok(())
+}
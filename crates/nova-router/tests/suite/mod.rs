@@ -1,12 +1,21 @@
+mod auth_token_rotation;
+mod batch_updates;
 mod cached_index_mismatch;
+mod diagnostics_coalescing;
 mod distributed_cancellation_propagation;
 mod duplicate_worker_rejected;
+mod find_references;
 mod handshake_admission;
+mod index_progress;
 mod insecure_tcp;
 mod ipc_permissions_unix;
+mod layout_reload;
 mod mtls_allowlist_validation;
 mod mtls_shard_allowlist;
+mod nested_source_roots;
+mod router_index_cache;
 mod rpc_hardening;
+mod shard_health;
 mod shard_integrity;
 mod spawn_workers_tls;
 mod stale_cached_index;
@@ -20,7 +20,7 @@ async fn mtls_allowlist_requires_client_ca_verification() {
         }),
         worker_command: PathBuf::from("unused-worker"),
         cache_dir: tmp.path().join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -58,7 +58,7 @@ async fn mtls_allowlist_requires_tcp_tls_transport() {
         listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
         worker_command: PathBuf::from("unused-worker"),
         cache_dir: tmp.path().join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use nova_router::{QueryRouter, SourceRoot, WorkspaceLayout};
+
+#[test]
+fn duplicate_source_roots_are_rejected() {
+    let layout = WorkspaceLayout {
+        source_roots: vec![
+            SourceRoot {
+                path: "/ws/src".into(),
+            },
+            SourceRoot {
+                path: "/ws/src/".into(),
+            },
+        ],
+    };
+    let err = QueryRouter::new_in_process(layout).unwrap_err();
+    assert!(
+        err.to_string().contains("duplicate source root"),
+        "unexpected error: {err:#}"
+    );
+}
+
+#[test]
+fn shard_for_path_picks_the_longest_root() {
+    let router = QueryRouter::new_in_process(WorkspaceLayout {
+        source_roots: vec![
+            SourceRoot {
+                path: "/ws/app/src".into(),
+            },
+            SourceRoot {
+                path: "/ws/lib/src".into(),
+            },
+            SourceRoot {
+                path: "/ws/app/src/generated".into(),
+            },
+        ],
+    })
+    .unwrap();
+
+    let shard = |path: &str| router.shard_for_path(Path::new(path));
+    assert_eq!(shard("/ws/app/src/com/example/App.java"), Some(0));
+    assert_eq!(shard("/ws/lib/src/Lib.java"), Some(1));
+    assert_eq!(shard("/ws/app/src/generated/Gen.java"), Some(2));
+    assert_eq!(shard("/ws/app/src-other/App.java"), None);
+    assert_eq!(shard("/elsewhere/Other.java"), None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn nested_source_roots_index_each_file_once() -> Result<()> {
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+
+    let outer = tmp.path().join("src");
+    let inner = outer.join("generated");
+    tokio::fs::create_dir_all(&inner)
+        .await
+        .context("create nested source root")?;
+    tokio::fs::write(outer.join("Outer.java"), "public class Outer {}")
+        .await
+        .context("write Outer.java")?;
+    tokio::fs::write(inner.join("Inner.java"), "public class Inner {}")
+        .await
+        .context("write Inner.java")?;
+
+    let router = QueryRouter::new_in_process(WorkspaceLayout {
+        source_roots: vec![
+            SourceRoot {
+                path: outer.clone(),
+            },
+            SourceRoot {
+                path: inner.clone(),
+            },
+        ],
+    })?;
+    router.index_workspace().await?;
+
+    let outer_symbols = router.workspace_symbols("Outer").await;
+    assert_eq!(
+        outer_symbols.iter().filter(|s| s.name == "Outer").count(),
+        1
+    );
+    let inner_symbols = router.workspace_symbols("Inner").await;
+    let inner_symbols: Vec<_> = inner_symbols.iter().filter(|s| s.name == "Inner").collect();
+    assert_eq!(inner_symbols.len(), 1, "{inner_symbols:?}");
+
+    // Updates to files under the inner root are routed to the inner root's shard only.
+    router
+        .update_file(inner.join("Inner.java"), "public class Inner {}".into())
+        .await?;
+    let inner_symbols = router.workspace_symbols("Inner").await;
+    assert_eq!(
+        inner_symbols.iter().filter(|s| s.name == "Inner").count(),
+        1
+    );
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use nova_remote_proto::v3::{
+    Capabilities, ProtocolVersion, Request, Response, SupportedVersions, WorkerHello,
+};
+use nova_remote_proto::{ShardIndex, Symbol};
+use nova_remote_rpc::RpcConnection;
+use nova_router::{
+    DistributedRouterConfig, ListenAddr, QueryRouter, SourceRoot, TcpListenAddr, WorkspaceLayout,
+};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+async fn start_router(root: &Path, cache_dir: &Path) -> Result<QueryRouter> {
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain("127.0.0.1:0".parse()?)),
+        worker_command: PathBuf::from("unused"),
+        cache_dir: cache_dir.to_path_buf(),
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: false,
+    };
+    let layout = WorkspaceLayout {
+        source_roots: vec![SourceRoot {
+            path: root.to_path_buf(),
+        }],
+    };
+    QueryRouter::new_distributed(config, layout)
+        .await
+        .context("start distributed router")
+}
+
+/// Connect a worker for shard 0 that answers every `IndexShard` with `symbols`.
+async fn connect_worker(router: &QueryRouter, symbols: Vec<Symbol>) -> Result<RpcConnection> {
+    let listen = timeout(Duration::from_secs(2), router.bound_listen_addr())
+        .await
+        .map_err(|_| anyhow!("timed out waiting for router to bind"))?
+        .ok_or_else(|| anyhow!("router did not report a bound listen address"))?;
+    let addr: SocketAddr = match listen {
+        ListenAddr::Tcp(TcpListenAddr::Plain(addr)) => addr,
+        other => return Err(anyhow!("unexpected listen address: {other:?}")),
+    };
+
+    let hello = WorkerHello {
+        shard_id: 0,
+        auth_token: None,
+        supported_versions: SupportedVersions {
+            min: ProtocolVersion::CURRENT,
+            max: ProtocolVersion::CURRENT,
+        },
+        capabilities: Capabilities::default(),
+        cached_index_info: None,
+        worker_build: None,
+    };
+    let (conn, _welcome) =
+        RpcConnection::handshake_as_worker(TcpStream::connect(addr).await?, hello)
+            .await
+            .context("handshake as v3 worker")?;
+    conn.set_request_handler(move |_ctx, req| {
+        let symbols = symbols.clone();
+        async move {
+            match req {
+                Request::IndexShard { revision, .. } => Ok(Response::ShardIndex(ShardIndex {
+                    shard_id: 0,
+                    revision,
+                    index_generation: 1,
+                    symbols,
+                })),
+                Request::Shutdown => Ok(Response::Shutdown),
+                _ => Ok(Response::Ack),
+            }
+        }
+    });
+    Ok(conn)
+}
+
+fn symbol(name: &str) -> Symbol {
+    Symbol {
+        name: name.into(),
+        path: format!("{name}.java"),
+        line: 0,
+        column: 0,
+    }
+}
+
+/// Run a session that indexes the workspace (persisting its shard index) and shuts down.
+async fn index_and_shut_down(root: &Path, cache_dir: &Path, symbols: Vec<Symbol>) -> Result<()> {
+    let router = start_router(root, cache_dir).await?;
+    let _worker = connect_worker(&router, symbols).await?;
+    router.index_workspace().await.context("index workspace")?;
+    router.shutdown().await
+}
+
+async fn fixture() -> Result<(tempfile::TempDir, PathBuf, PathBuf)> {
+    let tmp = tempfile::tempdir().context("create temp dir")?;
+    let root = tmp.path().join("root");
+    tokio::fs::create_dir_all(&root).await?;
+    tokio::fs::write(root.join("Alpha.java"), "class Alpha {}").await?;
+    let cache_dir = tmp.path().join("cache");
+    Ok((tmp, root, cache_dir))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cached_shard_index_is_served_before_first_index_and_then_refreshed() -> Result<()> {
+    let (_tmp, root, cache_dir) = fixture().await?;
+    index_and_shut_down(&root, &cache_dir, vec![symbol("Alpha")]).await?;
+
+    let cached = nova_cache::load_router_shard_index(&cache_dir, 0).context("router cache")?;
+    assert_eq!(cached.root, root);
+    assert_eq!(cached.index.symbols, vec![symbol("Alpha")]);
+
+    // No worker has connected yet, so nothing has been indexed in this session.
+    let router = start_router(&root, &cache_dir).await?;
+    assert_eq!(router.workspace_symbols("").await, vec![symbol("Alpha")]);
+    let health = router.shard_health().await;
+    assert!(health[0].index_from_cache, "{health:?}");
+
+    // The worker's first connection replaces the cached index.
+    let _worker = connect_worker(&router, vec![symbol("Beta")]).await?;
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while router.workspace_symbols("").await != vec![symbol("Beta")] {
+        anyhow::ensure!(
+            tokio::time::Instant::now() < deadline,
+            "cached index was not refreshed"
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(!router.shard_health().await[0].index_from_cache);
+
+    router.shutdown().await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cached_shard_index_of_changed_root_is_ignored() -> Result<()> {
+    let (_tmp, root, cache_dir) = fixture().await?;
+    index_and_shut_down(&root, &cache_dir, vec![symbol("Alpha")]).await?;
+
+    tokio::fs::write(root.join("Gamma.java"), "class Gamma {}").await?;
+
+    let router = start_router(&root, &cache_dir).await?;
+    assert!(router.workspace_symbols("").await.is_empty());
+    assert!(!router.shard_health().await[0].index_from_cache);
+
+    router.shutdown().await
+}
@@ -97,7 +97,7 @@ async fn start_router(tmp: &TempDir, listen_path: PathBuf) -> Result<(EnvVarGuar
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: PathBuf::from("nova-worker"),
         cache_dir: tmp.path().join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -0,0 +1,195 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use nova_router::{QueryRouter, ShardStatus, SourceRoot, WorkspaceLayout};
+use tempfile::TempDir;
+
+fn layout(roots: &[&Path]) -> WorkspaceLayout {
+    WorkspaceLayout {
+        source_roots: roots
+            .iter()
+            .map(|root| SourceRoot {
+                path: root.to_path_buf(),
+            })
+            .collect(),
+    }
+}
+
+async fn write_file(root: &Path, name: &str) -> Result<()> {
+    tokio::fs::create_dir_all(root).await?;
+    tokio::fs::write(
+        root.join(format!("{name}.java")),
+        format!("class {name} {{}}"),
+    )
+    .await
+    .with_context(|| format!("write {name}.java"))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn in_process_shards_are_always_healthy() -> Result<()> {
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    write_file(&root_a, "A").await?;
+    write_file(&root_b, "B").await?;
+
+    let router = QueryRouter::new_in_process(layout(&[&root_a, &root_b]))?;
+    let health = router.shard_health().await;
+    assert_eq!(
+        health
+            .iter()
+            .map(|shard| (shard.shard_id, shard.root.clone(), shard.status.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                0,
+                root_a.clone(),
+                ShardStatus::Healthy {
+                    last_index_revision: None
+                }
+            ),
+            (
+                1,
+                root_b.clone(),
+                ShardStatus::Healthy {
+                    last_index_revision: None
+                }
+            ),
+        ]
+    );
+
+    let summary = router.index_workspace_partial().await?;
+    assert_eq!(summary.indexed, vec![0, 1]);
+    assert!(summary.skipped.is_empty());
+    for shard in router.shard_health().await {
+        assert_eq!(
+            shard.status,
+            ShardStatus::Healthy {
+                last_index_revision: Some(1)
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn dead_shard_worker_is_reported_and_skipped() -> Result<()> {
+    use std::path::PathBuf;
+
+    use nova_router::{DistributedRouterConfig, ListenAddr, ShardUnavailable};
+    use tokio::process::Command;
+    use tokio::time::{sleep, timeout, Duration, Instant};
+
+    let tmp = TempDir::new().context("create temp dir")?;
+    let root_a = tmp.path().join("a");
+    let root_b = tmp.path().join("b");
+    write_file(&root_a, "A").await?;
+    write_file(&root_b, "B").await?;
+
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+
+    let config = DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(tmp.path().join("router.sock")),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir: cache_dir.clone(),
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers: true,
+    };
+    let router = QueryRouter::new_distributed(config, layout(&[&root_a, &root_b])).await?;
+
+    let summary = router.index_workspace_partial().await?;
+    assert_eq!(summary.indexed, vec![0, 1]);
+    assert!(summary.skipped.is_empty(), "{summary:?}");
+    for shard in router.shard_health().await {
+        assert!(
+            matches!(
+                shard.status,
+                ShardStatus::Healthy {
+                    last_index_revision: Some(_)
+                }
+            ),
+            "{shard:?}"
+        );
+    }
+
+    // Kill shard 1's worker and keep its replacements from starting.
+    tokio::fs::write(
+        cache_dir.join("nova-router-test-worker-shard1.conf"),
+        "fail_attempts=1000000\n",
+    )
+    .await?;
+    let pid = tokio::fs::read_to_string(cache_dir.join("worker-shard1.pid")).await?;
+    let status = Command::new("kill")
+        .args(["-9", pid.trim()])
+        .status()
+        .await
+        .context("run kill")?;
+    assert!(status.success());
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let health = router.shard_health().await;
+        if matches!(health[1].status, ShardStatus::NoWorker { .. }) {
+            break;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "shard 1 still has a worker: {health:?}"
+        );
+        sleep(Duration::from_millis(20)).await;
+    }
+
+    // Requests for the dead shard fail fast instead of waiting for a replacement worker.
+    let err = timeout(
+        Duration::from_secs(5),
+        router.update_file(root_b.join("B.java"), "class B2 {}".into()),
+    )
+    .await
+    .context("update_file waited for the dead worker")?
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ShardUnavailable>(),
+        Some(&ShardUnavailable { shard_id: 1 }),
+        "{err:?}"
+    );
+
+    let summary = timeout(Duration::from_secs(5), router.index_workspace_partial())
+        .await
+        .context("partial indexing waited for the dead worker")??;
+    assert_eq!(summary.indexed, vec![0]);
+    assert_eq!(
+        summary
+            .skipped
+            .iter()
+            .map(|(shard_id, _)| *shard_id)
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+
+    let health = router.shard_health().await;
+    assert!(
+        matches!(
+            health[0].status,
+            ShardStatus::Healthy {
+                last_index_revision: Some(_)
+            }
+        ),
+        "{health:?}"
+    );
+    assert!(
+        matches!(health[1].status, ShardStatus::NoWorker { .. }),
+        "{health:?}"
+    );
+
+    router.shutdown().await?;
+    Ok(())
+}
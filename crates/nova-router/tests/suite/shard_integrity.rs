@@ -126,7 +126,7 @@ async fn update_file_rejects_cross_shard_index_poisoning() -> Result<()> {
         listen_addr: ListenAddr::Unix(listen_path.clone()),
         worker_command: PathBuf::from("unused-worker"),
         cache_dir,
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -247,7 +247,7 @@ async fn worker_stats_rejects_mismatched_shard_id() -> Result<()> {
         listen_addr: ListenAddr::Unix(listen_path.clone()),
         worker_command: PathBuf::from("unused-worker"),
         cache_dir,
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -38,7 +38,7 @@ async fn spawn_workers_with_tcp_tls_is_rejected() {
         // The worker command is never reached because validation rejects the configuration.
         worker_command: PathBuf::from("nova-worker"),
         cache_dir: dir.join("cache"),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -24,7 +24,7 @@ async fn unix_socket_enforces_auth_token_when_configured() -> anyhow::Result<()>
         listen_addr: ListenAddr::Unix(socket_path.clone()),
         worker_command: PathBuf::from("unused-worker-bin"),
         cache_dir,
-        auth_token: Some("secret-token".into()),
+        auth: Some(nova_router::AuthValidator::from_token("secret-token")),
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -28,7 +28,7 @@ async fn router_handles_v3_hello_or_rejects_with_clear_error() -> Result<()> {
         listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain(addr)),
         worker_command: PathBuf::from("unused"),
         cache_dir,
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -106,7 +106,7 @@ async fn v3_router_rejects_v2_hello_with_clear_error() -> Result<()> {
         listen_addr: ListenAddr::Tcp(TcpListenAddr::Plain(addr)),
         worker_command: PathBuf::from("unused"),
         cache_dir,
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -3,7 +3,10 @@ use std::process::Stdio;
 use std::sync::{Mutex, OnceLock};
 
 use anyhow::Context;
-use nova_router::{DistributedRouterConfig, ListenAddr, QueryRouter, SourceRoot, WorkspaceLayout};
+use nova_router::{
+    DistributedRouterConfig, ListenAddr, QueryRouter, RestartShardOptions, SourceRoot,
+    WorkerExitReason, WorkerSupervisionInfo, WorkspaceLayout,
+};
 use tempfile::TempDir;
 use tokio::process::Command;
 use tokio::time::{timeout, Duration, Instant};
@@ -47,7 +50,7 @@ async fn worker_supervisor_backs_off_on_crash_loop_and_recovers() -> anyhow::Res
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: worker_bin,
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -118,7 +121,7 @@ async fn worker_supervisor_enforces_handshake_deadline() -> anyhow::Result<()> {
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: worker_bin,
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -187,7 +190,7 @@ async fn worker_supervisor_recovers_when_worker_exits_while_idle() -> anyhow::Re
         listen_addr: ListenAddr::Unix(listen_path),
         worker_command: worker_bin,
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -272,7 +275,7 @@ async fn router_accepts_replacement_worker_after_remote_disconnect() -> anyhow::
         // Not used because spawn_workers is false (we spawn the fixture workers ourselves).
         worker_command: PathBuf::from("unused-worker-bin"),
         cache_dir: cache_dir.clone(),
-        auth_token: None,
+        auth: None,
         allow_insecure_tcp: false,
         max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
         max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
@@ -342,3 +345,217 @@ async fn router_accepts_replacement_worker_after_remote_disconnect() -> anyhow::
     let _ = timeout(Duration::from_secs(5), second.wait()).await?;
     Ok(())
 }
+
+#[cfg(unix)]
+fn restart_test_config(
+    listen_path: PathBuf,
+    cache_dir: PathBuf,
+    spawn_workers: bool,
+) -> DistributedRouterConfig {
+    DistributedRouterConfig {
+        listen_addr: ListenAddr::Unix(listen_path),
+        worker_command: PathBuf::from(env!("CARGO_BIN_EXE_nova-router-test-worker")),
+        cache_dir,
+        auth: None,
+        allow_insecure_tcp: false,
+        max_rpc_bytes: nova_router::DEFAULT_MAX_RPC_BYTES,
+        max_inflight_handshakes: nova_router::DEFAULT_MAX_INFLIGHT_HANDSHAKES,
+        max_worker_connections: nova_router::DEFAULT_MAX_WORKER_CONNECTIONS,
+        #[cfg(feature = "tls")]
+        tls_client_cert_fingerprint_allowlist: Default::default(),
+        spawn_workers,
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_supervision(
+    router: &QueryRouter,
+    what: &str,
+    mut done: impl FnMut(&WorkerSupervisionInfo) -> bool,
+) -> anyhow::Result<WorkerSupervisionInfo> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let info = router.worker_supervision_info().await;
+        if done(&info[0]) {
+            return Ok(info[0].clone());
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!("timed out waiting for {what}: {info:?}");
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "current_thread")]
+async fn restart_shard_clears_corrupt_cache_and_resets_backoff() -> anyhow::Result<()> {
+    let _guard = WORKER_SUPERVISION_TEST_LOCK
+        .get_or_init(|| Mutex::new(()))
+        .lock()
+        .unwrap();
+    let tmp = TempDir::new()?;
+    let source_root = tmp.path().join("module_a").join("src");
+    tokio::fs::create_dir_all(&source_root).await?;
+
+    // The worker crashes on startup for as long as the shard has a cached index.
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+    let cache_file = nova_cache::shard_cache_path(&cache_dir, 0);
+    tokio::fs::write(&cache_file, b"corrupt").await?;
+    tokio::fs::write(
+        cache_dir.join("nova-router-test-worker.conf"),
+        "crash_on_cached_index=true\n",
+    )
+    .await?;
+
+    let config = restart_test_config(tmp.path().join("router.sock"), cache_dir.clone(), true);
+    let layout = WorkspaceLayout {
+        source_roots: vec![SourceRoot { path: source_root }],
+    };
+    let router = QueryRouter::new_distributed(config, layout).await?;
+
+    // Three crashes (50ms, 100ms and 200ms apart) put the next restart 400ms out.
+    let crashing = wait_for_supervision(&router, "crash loop", |info| {
+        info.restart_count >= 3 && info.backoff >= Duration::from_millis(400)
+    })
+    .await?;
+    assert_eq!(crashing.worker_id, None);
+    assert_eq!(
+        crashing.last_exit.map(|exit| exit.reason),
+        Some(WorkerExitReason::Exited { code: Some(2) })
+    );
+
+    timeout(
+        Duration::from_secs(10),
+        router.restart_shard_with_options(0, RestartShardOptions { clear_cache: true }),
+    )
+    .await
+    .context("timed out restarting shard")??;
+    assert!(!cache_file.exists(), "shard cache was not cleared");
+
+    let recovered = wait_for_supervision(&router, "replacement worker", |info| {
+        info.worker_id.is_some()
+    })
+    .await?;
+    assert_eq!(recovered.backoff, Duration::from_millis(50));
+    assert!(recovered.restart_count > crashing.restart_count);
+    assert!(router.worker_stats().await?.contains_key(&0));
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "current_thread")]
+async fn restart_shard_replaces_connected_worker() -> anyhow::Result<()> {
+    let _guard = WORKER_SUPERVISION_TEST_LOCK
+        .get_or_init(|| Mutex::new(()))
+        .lock()
+        .unwrap();
+    let tmp = TempDir::new()?;
+    let source_root = tmp.path().join("module_a").join("src");
+    tokio::fs::create_dir_all(&source_root).await?;
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+
+    let config = restart_test_config(tmp.path().join("router.sock"), cache_dir, true);
+    let layout = WorkspaceLayout {
+        source_roots: vec![SourceRoot { path: source_root }],
+    };
+    let router = QueryRouter::new_distributed(config, layout).await?;
+
+    let first =
+        wait_for_supervision(&router, "first worker", |info| info.worker_id.is_some()).await?;
+    assert_eq!(first.restart_count, 0);
+    assert_eq!(first.last_exit, None);
+
+    timeout(Duration::from_secs(10), router.restart_shard(0))
+        .await
+        .context("timed out restarting shard")??;
+
+    let second = wait_for_supervision(&router, "replacement worker", |info| {
+        info.worker_id.is_some() && info.worker_id != first.worker_id
+    })
+    .await?;
+    assert_eq!(second.restart_count, 1);
+    let last_exit = second.last_exit.expect("restart should be recorded");
+    assert_eq!(last_exit.worker_id, first.worker_id);
+    assert_eq!(last_exit.reason, WorkerExitReason::Restarted);
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test(flavor = "current_thread")]
+async fn restart_shard_severs_external_worker() -> anyhow::Result<()> {
+    let _guard = WORKER_SUPERVISION_TEST_LOCK
+        .get_or_init(|| Mutex::new(()))
+        .lock()
+        .unwrap();
+    let tmp = TempDir::new()?;
+    let source_root = tmp.path().join("module_a").join("src");
+    tokio::fs::create_dir_all(&source_root).await?;
+    let cache_dir = tmp.path().join("cache");
+    tokio::fs::create_dir_all(&cache_dir).await?;
+    let cache_file = nova_cache::shard_cache_path(&cache_dir, 0);
+    tokio::fs::write(&cache_file, b"corrupt").await?;
+
+    let listen_path = tmp.path().join("router.sock");
+    let config = restart_test_config(listen_path.clone(), cache_dir.clone(), false);
+    let layout = WorkspaceLayout {
+        source_roots: vec![SourceRoot { path: source_root }],
+    };
+    let router = QueryRouter::new_distributed(config, layout).await?;
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while std::fs::metadata(&listen_path).is_err() {
+        if Instant::now() >= deadline {
+            anyhow::bail!("timed out waiting for router socket {listen_path:?} to be created");
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let spawn_worker = || {
+        Command::new(env!("CARGO_BIN_EXE_nova-router-test-worker"))
+            .kill_on_drop(true)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .arg("--connect")
+            .arg(format!("unix:{}", listen_path.display()))
+            .arg("--shard-id")
+            .arg("0")
+            .arg("--cache-dir")
+            .arg(&cache_dir)
+            .spawn()
+    };
+
+    let _first = spawn_worker()?;
+    let first =
+        wait_for_supervision(&router, "first worker", |info| info.worker_id.is_some()).await?;
+
+    router
+        .restart_shard_with_options(0, RestartShardOptions { clear_cache: true })
+        .await?;
+    let info = router.worker_supervision_info().await;
+    assert_eq!(info[0].worker_id, None);
+    assert_eq!(info[0].restart_count, 0);
+    assert_eq!(
+        info[0]
+            .last_exit
+            .as_ref()
+            .map(|exit| (exit.worker_id, exit.reason.clone())),
+        Some((first.worker_id, WorkerExitReason::Restarted))
+    );
+    assert!(!cache_file.exists(), "shard cache was not cleared");
+
+    // The router accepts the worker that reconnects.
+    let _second = spawn_worker()?;
+    let stats = timeout(Duration::from_secs(10), router.worker_stats())
+        .await
+        .context("timed out waiting for replacement worker")??;
+    assert!(stats.contains_key(&0));
+
+    router.shutdown().await?;
+    Ok(())
+}
@@ -167,6 +167,7 @@ impl<'a> ExternalTypeLoader<'a> {
                     ty,
                     is_static: field.access_flags & ACC_STATIC != 0,
                     is_final: field.access_flags & ACC_FINAL != 0,
+                    deprecation: field.deprecation.clone(),
                 }
             })
             .collect::<Vec<_>>();
@@ -191,6 +192,15 @@ impl<'a> ExternalTypeLoader<'a> {
             }
         }
 
+        // Only a member class's own `InnerClasses` entry records whether it is `static`.
+        let (enclosing, is_static_nested) = match &stub.inner_class {
+            Some(inner) => (
+                self.ensure_class(&inner.outer_binary_name),
+                inner.access_flags & ACC_STATIC != 0,
+            ),
+            None => (None, false),
+        };
+
         ClassDef {
             name: binary_name.to_string(),
             kind,
@@ -200,6 +210,10 @@ impl<'a> ExternalTypeLoader<'a> {
             fields,
             constructors,
             methods,
+            enclosing,
+            is_static_nested,
+            is_abstract: stub.access_flags & ACC_ABSTRACT != 0,
+            deprecation: stub.deprecation.clone(),
         }
     }
 
@@ -314,6 +328,7 @@ fn constructor_def(
         params,
         is_varargs,
         is_accessible,
+        deprecation: stub.deprecation.clone(),
     }
 }
 
@@ -326,16 +341,20 @@ fn method_def(
     let is_static = access_flags & ACC_STATIC != 0;
     let is_varargs = access_flags & ACC_VARARGS != 0;
     let is_abstract = access_flags & ACC_ABSTRACT != 0;
+    let is_private = access_flags & ACC_PRIVATE != 0;
 
     let Ok(desc) = parse_method_descriptor(&stub.descriptor) else {
         return MethodDef {
             name: stub.name.clone(),
             type_params: Vec::new(),
             params: Vec::new(),
+            param_names: Vec::new(),
             return_type: Type::Unknown,
             is_static,
             is_varargs,
             is_abstract,
+            is_private,
+            deprecation: stub.deprecation.clone(),
         };
     };
 
@@ -350,10 +369,13 @@ fn method_def(
             name: stub.name.clone(),
             type_params,
             params,
+            param_names: vec![],
             return_type,
             is_static,
             is_varargs,
             is_abstract,
+            is_private,
+            deprecation: stub.deprecation.clone(),
         };
     }
 
@@ -371,10 +393,13 @@ fn method_def(
         name: stub.name.clone(),
         type_params: Vec::new(),
         params,
+        param_names: Vec::new(),
         return_type,
         is_static,
         is_varargs,
         is_abstract,
+        is_private,
+        deprecation: stub.deprecation.clone(),
     }
 }
 
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use nova_types::{
-    ClassDef, ClassKind, ConstructorDef, FieldStub, MethodDef, MethodStub, PrimitiveType, Type,
-    TypeDefStub, TypeEnv, TypeProvider, TypeStore, WildcardBound,
+    ClassDef, ClassKind, ConstructorDef, FieldStub, InnerClassStub, MethodDef, MethodStub,
+    PrimitiveType, Type, TypeDefStub, TypeEnv, TypeProvider, TypeStore, WildcardBound,
 };
 use nova_types_bridge::ExternalTypeLoader;
 
@@ -39,8 +39,11 @@ fn does_not_overwrite_non_placeholder_minimal_jdk_types() {
             name: "max".to_string(),
             descriptor: "(II)I".to_string(),
             signature: None,
-            access_flags: 0x0001 | 0x0008, // ACC_PUBLIC | ACC_STATIC
+            access_flags: 0x0001 | 0x0008, // ACC_PUBLIC | ACC_STATIC,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     };
 
     let collections_stub = TypeDefStub {
@@ -54,8 +57,11 @@ fn does_not_overwrite_non_placeholder_minimal_jdk_types() {
             name: "emptyList".to_string(),
             descriptor: "()Ljava/util/List;".to_string(),
             signature: None,
-            access_flags: 0x0001 | 0x0008, // ACC_PUBLIC | ACC_STATIC
+            access_flags: 0x0001 | 0x0008, // ACC_PUBLIC | ACC_STATIC,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -147,15 +153,19 @@ fn loads_generic_class_without_panicking() {
                 name: "get".to_string(),
                 descriptor: "(I)Ljava/lang/Object;".to_string(),
                 signature: Some("(I)TE;".to_string()),
-                access_flags: 0x0400, // ACC_ABSTRACT
+                access_flags: 0x0400, // ACC_ABSTRACT,
+                deprecation: None,
             },
             MethodStub {
                 name: "add".to_string(),
                 descriptor: "(Ljava/lang/Object;)Z".to_string(),
                 signature: Some("(TE;)Z".to_string()),
-                access_flags: 0x0400, // ACC_ABSTRACT
+                access_flags: 0x0400, // ACC_ABSTRACT,
+                deprecation: None,
             },
         ],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -190,6 +200,8 @@ fn resolves_self_referential_type_param_bounds() {
         signature: Some("<E:Ljava/lang/Enum<TE;>;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -226,6 +238,8 @@ fn cycle_safe_loading() {
         signature: None,
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
     let b_stub = TypeDefStub {
         binary_name: "com.example.B".to_string(),
@@ -235,6 +249,8 @@ fn cycle_safe_loading() {
         signature: None,
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -268,6 +284,8 @@ fn parses_wildcard_type_arguments_in_field_signatures() {
         signature: Some("<E:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let outer_stub = TypeDefStub {
@@ -281,8 +299,11 @@ fn parses_wildcard_type_arguments_in_field_signatures() {
             descriptor: "Ljava/util/List;".to_string(),
             signature: Some("Ljava/util/List<+TT;>;".to_string()),
             access_flags: 0x0000,
+            deprecation: None,
         }],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -326,6 +347,8 @@ fn resolves_self_referential_method_type_param_bounds() {
         signature: Some("<T:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let util_stub = TypeDefStub {
@@ -340,7 +363,10 @@ fn resolves_self_referential_method_type_param_bounds() {
             descriptor: "(Ljava/lang/Object;)Ljava/lang/Object;".to_string(),
             signature: Some("<T:Ljava/lang/Comparable<TT;>;>(TT;)TT;".to_string()),
             access_flags: 0x0000,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -392,7 +418,10 @@ fn ensure_class_does_not_overwrite_existing_non_placeholder_definition() {
             descriptor: "()V".to_string(),
             signature: None,
             access_flags: 0x0000,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -413,16 +442,24 @@ fn ensure_class_does_not_overwrite_existing_non_placeholder_definition() {
             params: vec![],
             is_varargs: false,
             is_accessible: true,
+            deprecation: None,
         }],
         methods: vec![MethodDef {
             name: "workspaceMethod".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let mut loader = ExternalTypeLoader::new(&mut store, &provider);
@@ -456,7 +493,10 @@ fn ensure_class_does_not_overwrite_existing_supertype_during_recursive_load() {
             descriptor: "()V".to_string(),
             signature: None,
             access_flags: 0x0000,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     };
 
     let bar_stub = TypeDefStub {
@@ -467,6 +507,8 @@ fn ensure_class_does_not_overwrite_existing_supertype_during_recursive_load() {
         signature: None,
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     };
 
     let mut provider = MapProvider::default();
@@ -490,16 +532,24 @@ fn ensure_class_does_not_overwrite_existing_supertype_during_recursive_load() {
             params: vec![],
             is_varargs: false,
             is_accessible: true,
+            deprecation: None,
         }],
         methods: vec![MethodDef {
             name: "workspaceMethod".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let mut loader = ExternalTypeLoader::new(&mut store, &provider);
@@ -524,3 +574,63 @@ fn ensure_class_does_not_overwrite_existing_supertype_during_recursive_load() {
         "expected recursive ensure_class(Foo) to avoid overwriting existing defs"
     );
 }
+
+#[test]
+fn member_classes_take_nesting_from_inner_classes_entry() {
+    let stub = |binary_name: &str, signature: Option<&str>, inner_class| TypeDefStub {
+        binary_name: binary_name.to_string(),
+        access_flags: 0x0001, // ACC_PUBLIC
+        super_binary_name: Some("java.lang.Object".to_string()),
+        interfaces: vec![],
+        signature: signature.map(str::to_string),
+        fields: vec![],
+        methods: vec![],
+        deprecation: None,
+        inner_class,
+    };
+    let member_of_outer = |access_flags| {
+        Some(InnerClassStub {
+            outer_binary_name: "com.example.Outer".to_string(),
+            access_flags,
+        })
+    };
+
+    let mut provider = MapProvider::default();
+    for stub in [
+        stub(
+            "com.example.Outer",
+            Some("<T:Ljava/lang/Object;>Ljava/lang/Object;"),
+            None,
+        ),
+        stub("com.example.Outer$Inner", None, member_of_outer(0x0001)),
+        // ACC_PUBLIC | ACC_STATIC
+        stub("com.example.Outer$Nested", None, member_of_outer(0x0009)),
+    ] {
+        provider.stubs.insert(stub.binary_name.clone(), stub);
+    }
+
+    let mut store = TypeStore::with_minimal_jdk();
+    let mut loader = ExternalTypeLoader::new(&mut store, &provider);
+    let inner = loader
+        .ensure_class("com.example.Outer$Inner")
+        .expect("Inner should load");
+    let nested = loader
+        .ensure_class("com.example.Outer$Nested")
+        .expect("Nested should load");
+    let outer = store
+        .lookup_class("com.example.Outer")
+        .expect("Outer should be loaded as the enclosing class");
+
+    let inner_def = store.class(inner).unwrap();
+    assert_eq!(inner_def.enclosing, Some(outer));
+    assert!(!inner_def.is_static_nested);
+    assert_eq!(
+        inner_def.all_type_params(&store),
+        store.class(outer).unwrap().type_params
+    );
+
+    let nested_def = store.class(nested).unwrap();
+    assert_eq!(nested_def.enclosing, Some(outer));
+    assert!(nested_def.is_static_nested);
+    assert!(nested_def.all_type_params(&store).is_empty());
+}
@@ -23,6 +23,10 @@ fn self_referential_bound_allocates_type_var_ids_before_bounds() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let sig = parse_class_signature(
@@ -143,6 +147,10 @@ fn nested_class_segments_flatten_and_apply_mismatch_heuristics() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // com.example.Outer$Inner<T, U>
@@ -156,6 +164,10 @@ fn nested_class_segments_flatten_and_apply_mismatch_heuristics() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let mut scope = TypeVarScope::new();
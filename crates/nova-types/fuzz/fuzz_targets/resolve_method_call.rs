@@ -0,0 +1,68 @@
+#![no_main]
+
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use nova_types::fuzzing::{check_method_call, Choices, FuzzEnv};
+
+mod utils;
+
+struct Runner {
+    input_tx: mpsc::SyncSender<Vec<u8>>,
+    output_rx: Mutex<mpsc::Receiver<()>>,
+}
+
+fn runner() -> &'static Runner {
+    static RUNNER: OnceLock<Runner> = OnceLock::new();
+    RUNNER.get_or_init(|| {
+        let (input_tx, input_rx) = mpsc::sync_channel::<Vec<u8>>(0);
+        let (output_tx, output_rx) = mpsc::sync_channel::<()>(0);
+
+        std::thread::spawn(move || {
+            for input in input_rx {
+                // The bytes left after generating the environment are decoded into calls until
+                // they run out. Exceeding the lookup budget panics, which must propagate back to
+                // the main thread as a fuzz failure.
+                let mut choices = Choices::new(&input);
+                let env = FuzzEnv::generate(&mut choices);
+                while !choices.is_empty() {
+                    let call = env.method_call(&mut choices);
+                    let _ = check_method_call(&env, &call);
+                }
+                let _ = output_tx.send(());
+            }
+        });
+
+        Runner {
+            input_tx,
+            output_rx: Mutex::new(output_rx),
+        }
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    let data = &data[..data.len().min(utils::MAX_INPUT_SIZE)];
+
+    let runner = runner();
+    runner
+        .input_tx
+        .send(data.to_vec())
+        .expect("resolve_method_call worker thread exited");
+
+    match runner
+        .output_rx
+        .lock()
+        .expect("resolve_method_call worker receiver poisoned")
+        .recv_timeout(utils::TIMEOUT)
+    {
+        Ok(()) => {}
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            panic!("resolve_method_call fuzz target timed out")
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            panic!("resolve_method_call worker thread panicked")
+        }
+    }
+});
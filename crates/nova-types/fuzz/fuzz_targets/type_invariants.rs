@@ -0,0 +1,60 @@
+#![no_main]
+
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use nova_types::fuzzing::{Choices, FuzzCase};
+
+mod utils;
+
+struct Runner {
+    input_tx: mpsc::SyncSender<Vec<u8>>,
+    output_rx: Mutex<mpsc::Receiver<()>>,
+}
+
+fn runner() -> &'static Runner {
+    static RUNNER: OnceLock<Runner> = OnceLock::new();
+    RUNNER.get_or_init(|| {
+        let (input_tx, input_rx) = mpsc::sync_channel::<Vec<u8>>(0);
+        let (output_tx, output_rx) = mpsc::sync_channel::<()>(0);
+
+        std::thread::spawn(move || {
+            for input in input_rx {
+                // A failed invariant panics; the panic must propagate back to the main thread as
+                // a fuzz failure.
+                FuzzCase::generate(&mut Choices::new(&input)).check();
+                let _ = output_tx.send(());
+            }
+        });
+
+        Runner {
+            input_tx,
+            output_rx: Mutex::new(output_rx),
+        }
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    let data = &data[..data.len().min(utils::MAX_INPUT_SIZE)];
+
+    let runner = runner();
+    runner
+        .input_tx
+        .send(data.to_vec())
+        .expect("type_invariants worker thread exited");
+
+    match runner
+        .output_rx
+        .lock()
+        .expect("type_invariants worker receiver poisoned")
+        .recv_timeout(utils::TIMEOUT)
+    {
+        Ok(()) => {}
+        Err(mpsc::RecvTimeoutError::Timeout) => panic!("type_invariants fuzz target timed out"),
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            panic!("type_invariants worker thread panicked")
+        }
+    }
+});
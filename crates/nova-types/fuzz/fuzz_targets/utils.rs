@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+/// Maximum size of a fuzz input.
+///
+/// A generated environment decodes from the first few hundred bytes; the rest of a larger input
+/// only adds method calls, which would push a single input past [`TIMEOUT`].
+pub const MAX_INPUT_SIZE: usize = 4 * 1024; // 4 KiB
+
+/// Wall-clock timeout per fuzz input.
+pub const TIMEOUT: Duration = Duration::from_secs(1);
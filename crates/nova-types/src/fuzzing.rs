@@ -0,0 +1,712 @@
+//! Structured input generation for fuzzing the type system, shared by the `cargo-fuzz` targets
+//! under `crates/nova-types/fuzz/` and the proptest suites.
+//!
+//! The IDE hands this crate environments built from half-typed source: classes that reference
+//! missing or deleted types, generic classes used with the wrong number of arguments, cyclic
+//! bounds. The generators here build that kind of input from a byte string: a [`FuzzEnv`] with
+//! a random class DAG on top of the minimal JDK, [`Type`]s and [`MethodCall`]s over it. Every
+//! generator reads its choices from [`Choices`], so any input (including an empty one) decodes
+//! to a finite value, and proptest shrinks a failing case by shrinking its bytes.
+//!
+//! [`check_type_invariants`] and [`check_method_call`] are the properties both harnesses assert.
+
+use std::cell::Cell;
+
+use proptest::prelude::*;
+
+use crate::{
+    is_subtype, lub, normalize_intersection, resolve_method_call, well_formed, ArgShape, CallKind,
+    ClassDef, ClassDefState, ClassId, ClassKind, ConstructorDef, FieldDef, MethodCall,
+    MethodContract, MethodContractKey, MethodDef, MethodResolution, PrimitiveType, TyContext, Type,
+    TypeEnv, TypeParamDef, TypeStore, TypeSubstitution, TypeVarId, WellKnownTypes, WildcardBound,
+    MINIMAL_JDK_BINARY_NAMES,
+};
+
+/// Upper bound on the classes generated on top of the minimal JDK.
+pub const MAX_CLASSES: usize = 8;
+
+/// Nesting limit of generated types; `List<Map<String, List<? extends T>[]>>` has depth 4.
+pub const MAX_TYPE_DEPTH: usize = 5;
+
+/// Environment lookups allowed for a single [`resolve_method_call`] before
+/// [`check_method_call`] reports it as runaway.
+///
+/// Real resolutions on the generated environments stay in the low thousands; this is far above
+/// that, but low enough to turn an unbounded walk into a quick failure instead of a hang.
+pub const RESOLUTION_LOOKUP_BUDGET: usize = 1_000_000;
+
+/// Method names drawn for generated declarations and calls. A small pool makes overloads,
+/// overrides and hits in calls likely.
+const METHOD_NAMES: &[&str] = &["m", "get", "apply", "equals", "toString"];
+
+/// Names used for `Type::Named` references: some resolve, some never do.
+const NAMED_TYPES: &[&str] = &[
+    "java.lang.String",
+    "java.util.List",
+    "fuzz.C0",
+    "fuzz.C1",
+    "fuzz.Missing",
+    "Missing",
+];
+
+/// A stream of choices decoded from fuzzer input.
+///
+/// Once the bytes run out, every choice takes its first option, so generation always
+/// terminates.
+#[derive(Debug, Clone)]
+pub struct Choices<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Choices<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn byte(&mut self) -> u8 {
+        match self.bytes.split_first() {
+            Some((&byte, rest)) => {
+                self.bytes = rest;
+                byte
+            }
+            None => 0,
+        }
+    }
+
+    /// A number in `0..n`, or `0` when `n` is zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        if n <= 1 {
+            return 0;
+        }
+        usize::from(self.byte()) % n
+    }
+
+    /// `true` with roughly `percent`% probability on random input, and `false` once the input
+    /// is exhausted.
+    pub fn chance(&mut self, percent: u8) -> bool {
+        u32::from(u8::MAX - self.byte()) * 100 < u32::from(percent) * 256
+    }
+
+    pub fn pick<'t, T>(&mut self, items: &'t [T]) -> &'t T {
+        &items[self.below(items.len())]
+    }
+}
+
+/// A generated environment: the minimal JDK plus up to [`MAX_CLASSES`] classes named
+/// `fuzz.C<n>`.
+///
+/// The generated classes form a DAG whose supertypes may be raw, have the wrong number of type
+/// arguments or be cyclic (a back edge is occasionally added, as in code being edited). Some of
+/// the classes are removed again, leaving tombstones that other classes still refer to, and one
+/// may stay an undefined placeholder.
+#[derive(Debug)]
+pub struct FuzzEnv {
+    pub store: TypeStore,
+    /// Minimal JDK classes and every generated class, including removed ones.
+    pub classes: Vec<ClassId>,
+    /// Type parameters of generated classes and methods.
+    pub type_vars: Vec<TypeVarId>,
+}
+
+impl FuzzEnv {
+    pub fn generate(choices: &mut Choices<'_>) -> Self {
+        let mut store = TypeStore::with_minimal_jdk();
+        let mut classes: Vec<ClassId> = MINIMAL_JDK_BINARY_NAMES
+            .iter()
+            .filter_map(|name| store.lookup_class(name))
+            .collect();
+        let mut type_vars = Vec::new();
+
+        // Reserve every id and type parameter up front, so bounds and supertypes can refer to
+        // classes defined later.
+        let count = 1 + choices.below(MAX_CLASSES);
+        let mut generated = Vec::with_capacity(count);
+        for idx in 0..count {
+            let id = store.intern_class_id(&format!("fuzz.C{idx}"));
+            let kind = if choices.chance(30) {
+                ClassKind::Interface
+            } else {
+                ClassKind::Class
+            };
+            let params: Vec<TypeVarId> = (0..choices.below(3))
+                .map(|n| store.add_type_param(format!("T{n}"), vec![]))
+                .collect();
+            type_vars.extend(params.iter().copied());
+            classes.push(id);
+            generated.push((id, kind, params));
+        }
+        if choices.chance(20) {
+            classes.push(store.intern_class_id("fuzz.Pending"));
+        }
+
+        let object = store.well_known().object;
+        for (idx, (id, kind, params)) in generated.iter().enumerate() {
+            let scope = Scope {
+                classes: &classes,
+                type_vars: params,
+                store: &store,
+            };
+            let bounds: Vec<Vec<Type>> = params
+                .iter()
+                .map(|_| {
+                    (0..choices.below(3))
+                        .map(|_| scope.reference_type(choices, 2))
+                        .collect()
+                })
+                .collect();
+            for (&param, bounds) in params.iter().zip(bounds) {
+                store.define_type_param(
+                    param,
+                    TypeParamDef {
+                        name: store.type_param(param).unwrap().name.clone(),
+                        upper_bounds: bounds,
+                        lower_bound: None,
+                        declared_by: Some(*id),
+                    },
+                );
+            }
+
+            let scope = Scope {
+                classes: &classes,
+                type_vars: params,
+                store: &store,
+            };
+            // Supertypes come from earlier classes, except for the occasional back edge.
+            let earlier = if choices.chance(5) {
+                &generated[..]
+            } else {
+                &generated[..idx]
+            };
+            let super_class = match kind {
+                ClassKind::Interface => Some(Type::class(object, vec![])),
+                ClassKind::Class => {
+                    let classes: Vec<_> = earlier
+                        .iter()
+                        .filter(|(_, kind, _)| *kind == ClassKind::Class)
+                        .collect();
+                    if classes.is_empty() || choices.chance(30) {
+                        Some(scope.jdk_class_type(choices, 2))
+                    } else {
+                        let (super_id, _, super_params) = *choices.pick(&classes);
+                        Some(scope.instantiate(choices, *super_id, super_params.len(), 2))
+                    }
+                }
+            };
+            let interfaces = (0..choices.below(3))
+                .filter_map(|_| {
+                    let interfaces: Vec<_> = earlier
+                        .iter()
+                        .filter(|(_, kind, _)| *kind == ClassKind::Interface)
+                        .collect();
+                    if interfaces.is_empty() {
+                        return None;
+                    }
+                    let (iface, _, iface_params) = *choices.pick(&interfaces);
+                    Some(scope.instantiate(choices, *iface, iface_params.len(), 2))
+                })
+                .collect();
+            let fields = (0..choices.below(3))
+                .map(|n| FieldDef {
+                    name: format!("f{n}"),
+                    ty: scope.any_type(choices, 3),
+                    is_static: choices.chance(30),
+                    is_final: choices.chance(30),
+                    deprecation: None,
+                })
+                .collect();
+            let constructors = (0..choices.below(3))
+                .map(|_| {
+                    let (params, is_varargs) = scope.params(choices);
+                    ConstructorDef {
+                        params,
+                        is_varargs,
+                        is_accessible: !choices.chance(10),
+                        deprecation: None,
+                    }
+                })
+                .collect();
+            let methods = (0..choices.below(5))
+                .map(|_| {
+                    let method_params: Vec<TypeVarId> = (0..choices.below(2))
+                        .map(|n| store.add_type_param(format!("M{n}"), vec![]))
+                        .collect();
+                    type_vars.extend(method_params.iter().copied());
+                    let in_scope: Vec<TypeVarId> =
+                        params.iter().chain(&method_params).copied().collect();
+                    let scope = Scope {
+                        classes: &classes,
+                        type_vars: &in_scope,
+                        store: &store,
+                    };
+                    let (param_types, is_varargs) = scope.params(choices);
+                    let return_type = if choices.chance(20) {
+                        Type::Void
+                    } else {
+                        scope.any_type(choices, 3)
+                    };
+                    let is_abstract = *kind == ClassKind::Interface && choices.chance(70);
+                    MethodDef {
+                        name: choices.pick(METHOD_NAMES).to_string(),
+                        type_params: method_params,
+                        params: param_types,
+                        param_names: vec![],
+                        return_type,
+                        is_static: !is_abstract && choices.chance(20),
+                        is_varargs,
+                        is_abstract,
+                        is_private: choices.chance(10),
+                        deprecation: None,
+                    }
+                })
+                .collect();
+
+            store.define_class(
+                *id,
+                ClassDef {
+                    name: format!("fuzz.C{idx}"),
+                    kind: *kind,
+                    type_params: params.clone(),
+                    super_class,
+                    interfaces,
+                    fields,
+                    constructors,
+                    methods,
+                    enclosing: None,
+                    is_static_nested: false,
+                    is_abstract: choices.chance(20),
+                    deprecation: None,
+                },
+            );
+        }
+
+        for idx in 0..count {
+            if choices.chance(10) {
+                store.remove_class(&format!("fuzz.C{idx}"));
+            }
+        }
+
+        Self {
+            store,
+            classes,
+            type_vars,
+        }
+    }
+
+    /// Returns `true` if every supertype and type parameter bound is well-formed.
+    ///
+    /// Subtyping is only expected to agree with lub on such environments: a supertype of the
+    /// wrong arity (`extends Number<String>`) leaves the answers of both up to error recovery.
+    pub fn has_well_formed_declarations(&self) -> bool {
+        let supertypes_ok = self.classes.iter().all(|&id| {
+            let Some(def) = self.store.class(id) else {
+                return true;
+            };
+            def.super_class
+                .iter()
+                .chain(&def.interfaces)
+                .all(|ty| well_formed(&self.store, ty).is_ok())
+        });
+        supertypes_ok
+            && self.type_vars.iter().all(|&id| {
+                self.store.type_param(id).is_none_or(|tp| {
+                    tp.upper_bounds
+                        .iter()
+                        .all(|ty| well_formed(&self.store, ty).is_ok())
+                })
+            })
+    }
+
+    /// A type over the whole environment, with every generated type variable in scope.
+    pub fn any_type(&self, choices: &mut Choices<'_>) -> Type {
+        self.scope().any_type(choices, MAX_TYPE_DEPTH)
+    }
+
+    /// A call of one of the generated method names on a random receiver.
+    ///
+    /// Arguments are sometimes conditional shapes, with `args` holding their standalone types
+    /// as front-ends do.
+    pub fn method_call(&self, choices: &mut Choices<'_>) -> MethodCall<'static> {
+        let scope = self.scope();
+        let receiver = scope.any_type(choices, 3);
+        let call_kind = if choices.chance(30) {
+            CallKind::Static
+        } else {
+            CallKind::Instance
+        };
+        let arity = choices.below(4);
+        let arg_shapes: Vec<ArgShape> = if choices.chance(30) {
+            (0..arity).map(|_| scope.arg_shape(choices, 2)).collect()
+        } else {
+            vec![]
+        };
+        let args = if arg_shapes.is_empty() {
+            (0..arity).map(|_| scope.any_type(choices, 3)).collect()
+        } else {
+            arg_shapes
+                .iter()
+                .map(|shape| shape.standalone_type(&self.store))
+                .collect()
+        };
+        let mut explicit_type_args = Vec::new();
+        if choices.chance(20) {
+            for _ in 0..1 + choices.below(2) {
+                explicit_type_args.push(scope.reference_type(choices, 2));
+            }
+        }
+        let name = *choices.pick(METHOD_NAMES);
+        MethodCall {
+            receiver,
+            call_kind,
+            name,
+            args,
+            expected_return: choices.chance(30).then(|| scope.any_type(choices, 2)),
+            explicit_type_args,
+            allow_partial_type_args: choices.chance(50),
+            start_above: choices.chance(10).then(|| *choices.pick(&self.classes)),
+            caller_context: choices.chance(30).then(|| *choices.pick(&self.classes)),
+            arg_shapes,
+        }
+    }
+
+    /// A substitution of random generated type variables, which may map a variable to a type
+    /// mentioning itself.
+    pub fn substitution(&self, choices: &mut Choices<'_>) -> TypeSubstitution {
+        if self.type_vars.is_empty() {
+            return TypeSubstitution::empty();
+        }
+        let scope = self.scope();
+        (0..choices.below(4))
+            .map(|_| (*choices.pick(&self.type_vars), scope.any_type(choices, 3)))
+            .collect()
+    }
+
+    fn scope(&self) -> Scope<'_> {
+        Scope {
+            classes: &self.classes,
+            type_vars: &self.type_vars,
+            store: &self.store,
+        }
+    }
+}
+
+/// What generated types may refer to.
+struct Scope<'a> {
+    classes: &'a [ClassId],
+    type_vars: &'a [TypeVarId],
+    store: &'a TypeStore,
+}
+
+impl Scope<'_> {
+    /// Any type, including primitives, `void`, top-level wildcards and error types.
+    fn any_type(&self, choices: &mut Choices<'_>, depth: usize) -> Type {
+        match choices.below(16) {
+            0 | 1 => Type::Primitive(*choices.pick(&PRIMITIVES)),
+            2 => match choices.below(4) {
+                0 => Type::Void,
+                1 => Type::Null,
+                2 => Type::Unknown,
+                _ => Type::Error,
+            },
+            3 if depth > 0 => Type::Wildcard(self.wildcard_bound(choices, depth - 1)),
+            _ => self.reference_type(choices, depth),
+        }
+    }
+
+    /// A reference type (or a dangling `Named` one).
+    fn reference_type(&self, choices: &mut Choices<'_>, depth: usize) -> Type {
+        if depth == 0 {
+            return match choices.below(4) {
+                0 if !self.type_vars.is_empty() => Type::TypeVar(*choices.pick(self.type_vars)),
+                1 => Type::Named(choices.pick(NAMED_TYPES).to_string()),
+                _ => Type::class(*choices.pick(self.classes), vec![]),
+            };
+        }
+        match choices.below(10) {
+            0 | 1 if !self.type_vars.is_empty() => Type::TypeVar(*choices.pick(self.type_vars)),
+            2 => Type::Array(Box::new(self.any_type(choices, depth - 1))),
+            3 => {
+                let parts = (0..1 + choices.below(3))
+                    .map(|_| self.reference_type(choices, depth - 1))
+                    .collect();
+                Type::Intersection(parts)
+            }
+            4 => Type::Named(choices.pick(NAMED_TYPES).to_string()),
+            _ => {
+                let class = *choices.pick(self.classes);
+                let arity = self.store.class(class).map_or(0, |c| c.type_params.len());
+                self.instantiate(choices, class, arity, depth - 1)
+            }
+        }
+    }
+
+    /// `class` parameterized with `arity` arguments; sometimes raw, sometimes with too few or too
+    /// many arguments.
+    fn instantiate(
+        &self,
+        choices: &mut Choices<'_>,
+        class: ClassId,
+        arity: usize,
+        depth: usize,
+    ) -> Type {
+        let arity = match choices.below(10) {
+            0 => 0,
+            1 => arity + 1,
+            2 => arity.saturating_sub(1),
+            _ => arity,
+        };
+        let args = (0..arity).map(|_| self.type_arg(choices, depth)).collect();
+        Type::class(class, args)
+    }
+
+    fn jdk_class_type(&self, choices: &mut Choices<'_>, depth: usize) -> Type {
+        let jdk = &self.classes[..MINIMAL_JDK_BINARY_NAMES.len().min(self.classes.len())];
+        let class = *choices.pick(jdk);
+        let arity = self.store.class(class).map_or(0, |c| c.type_params.len());
+        self.instantiate(choices, class, arity, depth)
+    }
+
+    fn type_arg(&self, choices: &mut Choices<'_>, depth: usize) -> Type {
+        match choices.below(8) {
+            0 => Type::Wildcard(self.wildcard_bound(choices, depth)),
+            // Primitive type arguments are malformed, but front-ends still produce them.
+            1 if choices.chance(20) => Type::Primitive(*choices.pick(&PRIMITIVES)),
+            _ => self.reference_type(choices, depth),
+        }
+    }
+
+    fn wildcard_bound(&self, choices: &mut Choices<'_>, depth: usize) -> WildcardBound {
+        match choices.below(3) {
+            0 => WildcardBound::Unbounded,
+            1 => WildcardBound::Extends(Box::new(self.reference_type(choices, depth))),
+            _ => WildcardBound::Super(Box::new(self.reference_type(choices, depth))),
+        }
+    }
+
+    /// Parameter types of a method or constructor, and whether it is varargs.
+    fn params(&self, choices: &mut Choices<'_>) -> (Vec<Type>, bool) {
+        let mut params: Vec<Type> = (0..choices.below(4))
+            .map(|_| self.any_type(choices, 3))
+            .filter(|ty| !matches!(ty, Type::Void))
+            .collect();
+        let is_varargs = choices.chance(20);
+        if is_varargs {
+            // Loaders usually record the array type, but some record the element type.
+            let elem = self.any_type(choices, 2);
+            params.push(if choices.chance(80) {
+                Type::Array(Box::new(elem))
+            } else {
+                elem
+            });
+        }
+        (params, is_varargs)
+    }
+
+    fn arg_shape(&self, choices: &mut Choices<'_>, depth: usize) -> ArgShape {
+        if depth > 0 && choices.chance(40) {
+            ArgShape::conditional(
+                self.arg_shape(choices, depth - 1),
+                self.arg_shape(choices, depth - 1),
+            )
+        } else {
+            ArgShape::Standalone(self.any_type(choices, 2))
+        }
+    }
+}
+
+const PRIMITIVES: [PrimitiveType; 8] = [
+    PrimitiveType::Boolean,
+    PrimitiveType::Byte,
+    PrimitiveType::Short,
+    PrimitiveType::Char,
+    PrimitiveType::Int,
+    PrimitiveType::Long,
+    PrimitiveType::Float,
+    PrimitiveType::Double,
+];
+
+/// A [`TypeEnv`] wrapper that counts lookups and panics once `budget` is exceeded.
+///
+/// Algorithms that walk the environment without making progress (a cyclic hierarchy, a bound
+/// that mentions itself) show up as a budget panic rather than a hang.
+pub struct BudgetedTypeEnv<'a> {
+    inner: &'a dyn TypeEnv,
+    lookups: Cell<usize>,
+    budget: usize,
+}
+
+impl<'a> BudgetedTypeEnv<'a> {
+    pub fn new(inner: &'a dyn TypeEnv, budget: usize) -> Self {
+        Self {
+            inner,
+            lookups: Cell::new(0),
+            budget,
+        }
+    }
+
+    pub fn lookups(&self) -> usize {
+        self.lookups.get()
+    }
+
+    fn visit(&self) {
+        let lookups = self.lookups.get() + 1;
+        self.lookups.set(lookups);
+        assert!(
+            lookups <= self.budget,
+            "exceeded the budget of {} type environment lookups",
+            self.budget
+        );
+    }
+}
+
+impl TypeEnv for BudgetedTypeEnv<'_> {
+    fn class(&self, id: ClassId) -> Option<&ClassDef> {
+        self.visit();
+        self.inner.class(id)
+    }
+
+    fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
+        self.visit();
+        self.inner.type_param(id)
+    }
+
+    fn lookup_class(&self, name: &str) -> Option<ClassId> {
+        self.visit();
+        self.inner.lookup_class(name)
+    }
+
+    fn well_known(&self) -> &WellKnownTypes {
+        self.inner.well_known()
+    }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        self.visit();
+        self.inner.classes_declaring_method(name, limit)
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        self.visit();
+        self.inner.interfaces(limit)
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.inner.method_contract(key)
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        self.inner.class_def_state(id)
+    }
+}
+
+/// Asserts the cheap algebraic properties of subtyping, lub, intersections and substitution on
+/// `a`, `b` and `c`.
+pub fn check_type_invariants(
+    env: &FuzzEnv,
+    a: &Type,
+    b: &Type,
+    c: &Type,
+    subst: &TypeSubstitution,
+) {
+    let store = &env.store;
+    for ty in [a, b, c] {
+        assert!(is_subtype(store, ty, ty), "not reflexive: {ty:?}");
+    }
+
+    // The generator also builds ill-formed types (`String<int>`), which lub only has to survive.
+    let joined = lub(store, a, b);
+    let is_value =
+        |ty: &Type| ty.is_reference() && !ty.is_errorish() && well_formed(store, ty).is_ok();
+    if is_value(a) && is_value(b) && !joined.is_errorish() && env.has_well_formed_declarations() {
+        assert!(
+            is_subtype(store, a, &joined) && is_subtype(store, b, &joined),
+            "lub({a:?}, {b:?}) = {joined:?} is not a supertype of both"
+        );
+    }
+
+    let parts = [a.clone(), b.clone(), c.clone()];
+    let expected = normalize_intersection(store, parts.to_vec());
+    for order in [[1, 0, 2], [2, 1, 0], [0, 2, 1], [1, 2, 0], [2, 0, 1]] {
+        let permuted = order.iter().map(|&idx| parts[idx].clone()).collect();
+        assert_eq!(
+            normalize_intersection(store, permuted),
+            expected,
+            "intersection of {parts:?} depends on the order of {order:?}"
+        );
+    }
+
+    // Substitution is a single pass, even when a variable maps to a type mentioning itself.
+    let substituted = subst.apply(a);
+    let max_replacement = subst.iter().map(|(_, ty)| type_depth(ty)).max();
+    assert!(
+        type_depth(&substituted) <= type_depth(a) + max_replacement.unwrap_or(0),
+        "{subst:?} applied to {a:?} grew to {substituted:?}"
+    );
+}
+
+/// Resolves `call` against `env` within [`RESOLUTION_LOOKUP_BUDGET`] lookups.
+pub fn check_method_call(env: &FuzzEnv, call: &MethodCall<'_>) -> MethodResolution {
+    let budgeted = BudgetedTypeEnv::new(&env.store, RESOLUTION_LOOKUP_BUDGET);
+    let mut ctx = TyContext::new(&budgeted);
+    resolve_method_call(&mut ctx, call)
+}
+
+fn type_depth(ty: &Type) -> usize {
+    match ty {
+        Type::Class(class) => 1 + class.args.iter().map(type_depth).max().unwrap_or(0),
+        Type::Array(elem) => 1 + type_depth(elem),
+        Type::Wildcard(WildcardBound::Extends(bound) | WildcardBound::Super(bound)) => {
+            1 + type_depth(bound)
+        }
+        Type::Intersection(parts) => 1 + parts.iter().map(type_depth).max().unwrap_or(0),
+        _ => 1,
+    }
+}
+
+/// Generated inputs, decoded from up to `max_bytes` of proptest-chosen choices.
+#[derive(Debug)]
+pub struct FuzzCase {
+    pub env: FuzzEnv,
+    pub types: [Type; 3],
+    pub substitution: TypeSubstitution,
+    pub call: MethodCall<'static>,
+}
+
+impl FuzzCase {
+    pub fn generate(choices: &mut Choices<'_>) -> Self {
+        let env = FuzzEnv::generate(choices);
+        let types = [
+            env.any_type(choices),
+            env.any_type(choices),
+            env.any_type(choices),
+        ];
+        let substitution = env.substitution(choices);
+        let call = env.method_call(choices);
+        Self {
+            env,
+            types,
+            substitution,
+            call,
+        }
+    }
+
+    pub fn check(&self) {
+        let [a, b, c] = &self.types;
+        check_type_invariants(&self.env, a, b, c, &self.substitution);
+        check_method_call(&self.env, &self.call);
+    }
+}
+
+/// Proptest strategy over [`FuzzCase`]s.
+pub fn fuzz_case(max_bytes: usize) -> impl Strategy<Value = FuzzCase> {
+    proptest::collection::vec(any::<u8>(), 0..=max_bytes)
+        .prop_map(|bytes| FuzzCase::generate(&mut Choices::new(&bytes)))
+}
+
+/// Proptest strategy over [`FuzzEnv`]s.
+pub fn fuzz_env(max_bytes: usize) -> impl Strategy<Value = FuzzEnv> {
+    proptest::collection::vec(any::<u8>(), 0..=max_bytes)
+        .prop_map(|bytes| FuzzEnv::generate(&mut Choices::new(&bytes)))
+}
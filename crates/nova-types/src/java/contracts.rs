@@ -0,0 +1,242 @@
+//! Null-behavior contracts for well-known methods.
+//!
+//! Some JDK (and framework) methods have null semantics that their signatures cannot express:
+//! `Objects.requireNonNull(x)` throws on `null` and otherwise returns `x`, `Map.get` may return
+//! `null`, and so on. Null analysis consults [`well_known_contract`] (or
+//! [`resolved_method_contract`] for a resolved call) instead of hardcoding these methods.
+//!
+//! Contracts are keyed by the declaring class's binary name, the method name, and the erased
+//! parameter types (see [`MethodContractKey`]). A built-in table covers the JDK; environments
+//! can provide more via [`TypeEnv::method_contract`] (e.g. [`crate::TypeStore::register_method_contract`]).
+
+use std::collections::{HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ClassId, ClassType, ResolvedMethod, Type, TypeEnv};
+
+/// How a method treats `null`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NullBehavior {
+    /// Throws if the argument at `param` is `null` and otherwise returns it
+    /// (`Objects.requireNonNull`).
+    RequireNonNull { param: usize },
+    /// Returns `true` iff the argument at `param` is `null` (`Objects.isNull`).
+    IsNullCheck { param: usize },
+    /// Returns `true` iff the argument at `param` is not `null` (`Objects.nonNull`).
+    NonNullCheck { param: usize },
+    /// Throws if the argument at `param` is `null`; the result is never `null` (`Optional.of`).
+    RejectsNullArg { param: usize },
+    /// Accepts `null` for the argument at `param`; the result is never `null`
+    /// (`Optional.ofNullable`).
+    AcceptsNullArg { param: usize },
+    /// The result may be `null` (`Map.get`).
+    NullableReturn,
+    /// The result is never `null` (`Optional.empty`).
+    NonNullReturn,
+}
+
+/// Behavior of a method that its signature does not express.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MethodContract {
+    pub null_behavior: NullBehavior,
+    /// The method has no side effects other than possibly throwing.
+    pub pure: bool,
+}
+
+impl MethodContract {
+    pub const fn new(null_behavior: NullBehavior, pure: bool) -> Self {
+        Self {
+            null_behavior,
+            pure,
+        }
+    }
+}
+
+/// Identifies a method declaration for contract lookup.
+///
+/// `erased_params` are the erased parameter types of the declaration: binary class names
+/// (`java.lang.Object`), primitive keywords (`int`), and a `[]` suffix per array dimension.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MethodContractKey {
+    pub owner: String,
+    pub name: String,
+    pub erased_params: Vec<String>,
+}
+
+impl MethodContractKey {
+    pub fn new(owner: impl Into<String>, name: impl Into<String>, erased_params: &[&str]) -> Self {
+        Self {
+            owner: owner.into(),
+            name: name.into(),
+            erased_params: erased_params.iter().map(|p| (*p).to_string()).collect(),
+        }
+    }
+}
+
+const OBJECT: &str = "java.lang.Object";
+
+/// `(owner, name, erased params, contract)`.
+const BUILTIN_CONTRACTS: &[(&str, &str, &[&str], MethodContract)] = &[
+    (
+        "java.util.Objects",
+        "requireNonNull",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::RequireNonNull { param: 0 }, true),
+    ),
+    (
+        "java.util.Objects",
+        "requireNonNull",
+        &[OBJECT, "java.lang.String"],
+        MethodContract::new(NullBehavior::RequireNonNull { param: 0 }, true),
+    ),
+    (
+        "java.util.Objects",
+        "requireNonNull",
+        &[OBJECT, "java.util.function.Supplier"],
+        MethodContract::new(NullBehavior::RequireNonNull { param: 0 }, true),
+    ),
+    (
+        "java.util.Objects",
+        "isNull",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::IsNullCheck { param: 0 }, true),
+    ),
+    (
+        "java.util.Objects",
+        "nonNull",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::NonNullCheck { param: 0 }, true),
+    ),
+    (
+        "java.util.Optional",
+        "of",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::RejectsNullArg { param: 0 }, true),
+    ),
+    (
+        "java.util.Optional",
+        "ofNullable",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::AcceptsNullArg { param: 0 }, true),
+    ),
+    (
+        "java.util.Optional",
+        "empty",
+        &[],
+        MethodContract::new(NullBehavior::NonNullReturn, true),
+    ),
+    (
+        "java.util.Map",
+        "get",
+        &[OBJECT],
+        MethodContract::new(NullBehavior::NullableReturn, true),
+    ),
+    (
+        "java.util.Map",
+        "getOrDefault",
+        &[OBJECT, OBJECT],
+        MethodContract::new(NullBehavior::NullableReturn, true),
+    ),
+];
+
+/// Look up the contract of the method `method_name(erased_params)` declared in `owner` or one of
+/// its supertypes.
+///
+/// `erased_params` are the erased parameter types of the declaration (not of a particular call),
+/// e.g. `[Object]` for `Map.get`. Contracts provided by `env` (see [`TypeEnv::method_contract`])
+/// take precedence over the built-in JDK table, and a subtype's contract takes precedence over
+/// one inherited from a supertype.
+pub fn well_known_contract(
+    env: &dyn TypeEnv,
+    owner: ClassId,
+    method_name: &str,
+    erased_params: &[Type],
+) -> Option<MethodContract> {
+    let erased_params = erased_params
+        .iter()
+        .map(|ty| erased_type_name(env, ty))
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut queue = VecDeque::from([owner]);
+    let mut seen = HashSet::new();
+    while let Some(id) = queue.pop_front() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let Some(class_def) = env.class(id) else {
+            continue;
+        };
+
+        let key = MethodContractKey {
+            owner: class_def.name.clone(),
+            name: method_name.to_string(),
+            erased_params: erased_params.clone(),
+        };
+        if let Some(contract) = env.method_contract(&key).or_else(|| builtin_contract(&key)) {
+            return Some(contract);
+        }
+
+        let supertypes = class_def.super_class.iter().chain(&class_def.interfaces);
+        for supertype in supertypes {
+            if let Type::Class(ClassType { def, .. }) = supertype {
+                queue.push_back(*def);
+            }
+        }
+    }
+    None
+}
+
+/// Look up the contract of the declaration `method` was resolved to (see
+/// [`well_known_contract`]).
+///
+/// The declaration is found among `method.owner`'s methods by name and arity; when overloads
+/// share an arity, the one whose erased parameters accept the resolved parameter types is used.
+pub fn resolved_method_contract(
+    env: &dyn TypeEnv,
+    method: &ResolvedMethod,
+) -> Option<MethodContract> {
+    let class_def = env.class(method.owner)?;
+    let resolved_params = method.signature_params.as_deref().unwrap_or(&method.params);
+
+    class_def
+        .methods
+        .iter()
+        .filter(|def| {
+            def.name == method.name
+                && def.is_static == method.is_static
+                && def.params.len() == resolved_params.len()
+        })
+        .map(|def| {
+            def.params
+                .iter()
+                .map(|p| crate::erasure(env, p))
+                .collect::<Vec<_>>()
+        })
+        .filter(|erased| {
+            erased
+                .iter()
+                .zip(resolved_params)
+                .all(|(param, resolved)| crate::is_subtype(env, resolved, param))
+        })
+        .find_map(|erased| well_known_contract(env, method.owner, &method.name, &erased))
+}
+
+fn builtin_contract(key: &MethodContractKey) -> Option<MethodContract> {
+    BUILTIN_CONTRACTS
+        .iter()
+        .find(|(owner, name, params, _)| {
+            *owner == key.owner && *name == key.name && *params == key.erased_params.as_slice()
+        })
+        .map(|(_, _, _, contract)| *contract)
+}
+
+fn erased_type_name(env: &dyn TypeEnv, ty: &Type) -> Option<String> {
+    match crate::erasure(env, ty) {
+        Type::Primitive(p) => Some(p.keyword().to_string()),
+        Type::Class(ClassType { def, .. }) => Some(env.class(def)?.name.clone()),
+        Type::Named(name) => Some(name),
+        Type::Array(elem) => Some(format!("{}[]", erased_type_name(env, &elem)?)),
+        _ => None,
+    }
+}
@@ -1,8 +1,11 @@
+use std::cell::{OnceCell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use crate::{
-    CallKind, ClassId, ClassType, FieldDef, Type, TypeEnv, TypeParamDef, TypeVarId, WildcardBound,
+    CallKind, ClassDef, ClassDefState, ClassId, ClassType, FieldDef, MethodContract,
+    MethodContractKey, MethodDef, Type, TypeEnv, TypeParamDef, TypeStore, TypeSubstitution,
+    TypeVarId, WildcardBound,
 };
 
 /// Per-invocation typing context used by overload resolution and related algorithms.
@@ -12,7 +15,13 @@ use crate::{
 /// without mutating shared state.
 pub struct TyContext<'env> {
     base: &'env dyn TypeEnv,
+    /// Installed around `base` while dependency tracking is enabled.
+    tracker: Option<TrackingTypeEnv<'env>>,
     locals: Vec<TypeParamDef>,
+    recover_from_errorish_receiver: bool,
+    likely_receivers: Vec<Type>,
+    /// Placeholder classes touched so far; `None` unless placeholder checks are enabled.
+    placeholders: Option<RefCell<Vec<ClassId>>>,
 }
 
 impl fmt::Debug for TyContext<'_> {
@@ -27,10 +36,158 @@ impl<'env> TyContext<'env> {
     pub fn new(base: &'env dyn TypeEnv) -> Self {
         Self {
             base,
+            tracker: None,
             locals: Vec::new(),
+            recover_from_errorish_receiver: false,
+            likely_receivers: Vec::new(),
+            placeholders: None,
         }
     }
 
+    /// Opt in to best-effort method resolution on errorish (`Unknown`/`Error`) receivers.
+    ///
+    /// This is an IDE recovery mode for call chains whose previous link failed to resolve. When
+    /// the receiver is errorish, [`crate::resolve_method_call`] searches, in order: the call's
+    /// `expected_return` type, `likely_receivers` (e.g. the declared type of the chain root), and
+    /// finally a bounded set of classes declaring a method with the requested name. Results found
+    /// this way are marked [`crate::ResolvedMethod::speculative`].
+    ///
+    /// Disabled by default so batch analysis semantics are unaffected.
+    pub fn with_errorish_receiver_recovery(mut self, likely_receivers: Vec<Type>) -> Self {
+        self.recover_from_errorish_receiver = true;
+        self.likely_receivers = likely_receivers;
+        self
+    }
+
+    /// Opt in to reporting uncertainty caused by placeholder classes (see [`ClassDefState`]).
+    ///
+    /// While a loader is still populating the environment, a class may be interned but not yet
+    /// defined; its placeholder definition has no supertypes or members, so queries touching it
+    /// can give confident but wrong negative answers. With checks enabled, the context records
+    /// every placeholder it looks up: [`TyContext::is_subtype_checked`] reports
+    /// [`SubtypeCheck::Unknown`] instead of a negative answer, and [`crate::resolve_method_call`]
+    /// fills [`crate::ResolvedMethod::depends_on_placeholders`].
+    ///
+    /// Disabled by default so batch analysis semantics are unaffected.
+    pub fn with_placeholder_checks(mut self) -> Self {
+        self.placeholders = Some(RefCell::new(Vec::new()));
+        self
+    }
+
+    /// Returns the placeholder classes touched since checks were enabled (or since the last
+    /// call), in the order they were first touched.
+    ///
+    /// Returns an empty list when placeholder checks are disabled.
+    pub fn take_placeholders(&self) -> Vec<ClassId> {
+        self.placeholders
+            .as_ref()
+            .map(|touched| touched.take())
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn checks_placeholders(&self) -> bool {
+        self.placeholders.is_some()
+    }
+
+    /// Run `f` and return the placeholder classes it touched, in addition to recording them in
+    /// this context.
+    pub(crate) fn collect_placeholders<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> (R, Vec<ClassId>) {
+        let outer = self.take_placeholders();
+        let result = f(self);
+        let inner = self.take_placeholders();
+        if let Some(touched) = &self.placeholders {
+            let mut touched = touched.borrow_mut();
+            *touched = outer;
+            for id in &inner {
+                if !touched.contains(id) {
+                    touched.push(*id);
+                }
+            }
+        }
+        (result, inner)
+    }
+
+    /// Subtyping (`sub <: sup`) that distinguishes "no" from "not known yet".
+    ///
+    /// Without placeholder checks (see [`TyContext::with_placeholder_checks`]) this never returns
+    /// [`SubtypeCheck::Unknown`]. With them, a negative answer that depended on a placeholder
+    /// class is reported as unknown, since defining that class may add the missing supertype.
+    pub fn is_subtype_checked(&mut self, sub: &Type, sup: &Type) -> SubtypeCheck {
+        let (is_subtype, placeholders) =
+            self.collect_placeholders(|ctx| crate::is_subtype(ctx, sub, sup));
+        if is_subtype {
+            SubtypeCheck::Yes
+        } else if placeholders.is_empty() {
+            SubtypeCheck::No
+        } else {
+            SubtypeCheck::Unknown { placeholders }
+        }
+    }
+
+    /// Enable or disable recording of the classes and type parameters consulted through this
+    /// context (see [`TrackingTypeEnv`]).
+    ///
+    /// Incremental callers use the recorded [`DependencySet`] to invalidate cached resolution
+    /// results precisely. Disabling tracking discards anything recorded so far.
+    pub fn track_dependencies(&mut self, enabled: bool) {
+        match (enabled, self.tracker.is_some()) {
+            (true, false) => self.tracker = Some(TrackingTypeEnv::new(self.base)),
+            (false, true) => self.tracker = None,
+            _ => {}
+        }
+    }
+
+    /// Returns the dependencies recorded since tracking was enabled (or since the last call),
+    /// leaving tracking enabled with an empty set.
+    ///
+    /// Returns an empty set when tracking is disabled.
+    pub fn take_dependencies(&mut self) -> DependencySet {
+        self.tracker
+            .as_ref()
+            .map(TrackingTypeEnv::take_dependencies)
+            .unwrap_or_default()
+    }
+
+    /// Record the classes and type variables mentioned by `types` as dependencies.
+    ///
+    /// Algorithms may decide a relation without looking up a class (`String <: String` holds by
+    /// equality), yet a cached result keyed by those types still depends on their definitions.
+    pub(crate) fn record_type_dependencies<'t>(&self, types: impl IntoIterator<Item = &'t Type>) {
+        if let Some(tracker) = &self.tracker {
+            for ty in types {
+                tracker.record_type(ty);
+            }
+        }
+    }
+
+    /// This context as a plain [`TypeEnv`], for helpers such as [`crate::is_subtype`] that take
+    /// one directly.
+    ///
+    /// Lookups through it see the context-local type parameters (e.g. capture variables) and are
+    /// tracked like the context's own.
+    pub fn env(&self) -> &dyn TypeEnv {
+        self
+    }
+
+    /// The environment this context looks classes up in: the base one, or its tracking wrapper.
+    fn lookup_env(&self) -> &dyn TypeEnv {
+        match &self.tracker {
+            Some(tracker) => tracker,
+            None => self.base,
+        }
+    }
+
+    pub(crate) fn recovers_errorish_receivers(&self) -> bool {
+        self.recover_from_errorish_receiver
+    }
+
+    pub(crate) fn likely_receivers(&self) -> &[Type] {
+        &self.likely_receivers
+    }
+
     /// Normalize a receiver type for member lookup (field/method resolution).
     ///
     /// Java allows member access on type variables; those accesses are resolved against the
@@ -165,6 +322,7 @@ impl<'env> TyContext<'env> {
             name: format!("CAP#{}", idx),
             upper_bounds,
             lower_bound,
+            declared_by: None,
         });
         id
     }
@@ -196,7 +354,7 @@ impl<'env> TyContext<'env> {
 
         // First pass: allocate capture ids for wildcard arguments and build a substitution mapping
         // from the class's formal type parameters to either the concrete argument or the capture var.
-        let mut subst: HashMap<TypeVarId, Type> = HashMap::with_capacity(type_params.len());
+        let mut subst = TypeSubstitution::empty();
         let mut capture_ids: Vec<Option<TypeVarId>> = Vec::with_capacity(args.len());
 
         for (formal, arg) in type_params.iter().copied().zip(args.iter()) {
@@ -262,7 +420,7 @@ impl<'env> TyContext<'env> {
         // Build the captured type arguments in the class's formal parameter order.
         let mut new_args = Vec::with_capacity(args.len());
         for formal in &type_params {
-            let Some(arg) = subst.get(formal) else {
+            let Some(arg) = subst.get(*formal) else {
                 return ty.clone();
             };
             new_args.push(arg.clone());
@@ -292,22 +450,183 @@ fn simplify_upper_bounds(env: &dyn TypeEnv, bounds: Vec<Type>) -> Vec<Type> {
 
 impl TypeEnv for TyContext<'_> {
     fn class(&self, id: ClassId) -> Option<&crate::ClassDef> {
-        self.base.class(id)
+        if let Some(touched) = &self.placeholders {
+            if self.lookup_env().class_def_state(id) == ClassDefState::Placeholder {
+                let mut touched = touched.borrow_mut();
+                if !touched.contains(&id) {
+                    touched.push(id);
+                }
+            }
+        }
+        self.lookup_env().class(id)
     }
 
     fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
         if let Some(idx) = id.context_local_index() {
             return self.locals.get(idx);
         }
-        self.base.type_param(id)
+        self.lookup_env().type_param(id)
     }
 
     fn lookup_class(&self, name: &str) -> Option<ClassId> {
-        self.base.lookup_class(name)
+        self.lookup_env().lookup_class(name)
     }
 
     fn well_known(&self) -> &crate::WellKnownTypes {
-        self.base.well_known()
+        self.lookup_env().well_known()
+    }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        self.lookup_env().classes_declaring_method(name, limit)
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        self.lookup_env().interfaces(limit)
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.lookup_env().method_contract(key)
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        self.lookup_env().class_def_state(id)
+    }
+}
+
+/// Result of [`TyContext::is_subtype_checked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubtypeCheck {
+    Yes,
+    No,
+    /// The answer was negative, but it depended on classes that are still placeholders.
+    Unknown {
+        placeholders: Vec<ClassId>,
+    },
+}
+
+/// The classes and type parameters consulted while computing a result.
+///
+/// A cached result stays valid as long as none of these definitions change.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencySet {
+    pub classes: HashSet<ClassId>,
+    pub type_params: HashSet<TypeVarId>,
+}
+
+impl DependencySet {
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.type_params.is_empty()
+    }
+
+    pub fn extend(&mut self, other: DependencySet) {
+        self.classes.extend(other.classes);
+        self.type_params.extend(other.type_params);
+    }
+}
+
+/// A [`TypeEnv`] wrapper that records every class and type parameter lookup into a
+/// [`DependencySet`].
+///
+/// Wrapping the environment (rather than instrumenting individual algorithms) ensures subtyping,
+/// lub, conversions and member collection are all covered.
+pub struct TrackingTypeEnv<'a> {
+    inner: &'a dyn TypeEnv,
+    dependencies: RefCell<DependencySet>,
+}
+
+impl fmt::Debug for TrackingTypeEnv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrackingTypeEnv")
+            .field("dependencies", &self.dependencies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> TrackingTypeEnv<'a> {
+    pub fn new(inner: &'a dyn TypeEnv) -> Self {
+        Self {
+            inner,
+            dependencies: RefCell::new(DependencySet::default()),
+        }
+    }
+
+    /// Returns the dependencies recorded so far and resets the set.
+    pub fn take_dependencies(&self) -> DependencySet {
+        self.dependencies.take()
+    }
+
+    pub fn into_dependencies(self) -> DependencySet {
+        self.dependencies.into_inner()
+    }
+
+    fn record_type(&self, ty: &Type) {
+        match ty {
+            Type::Class(ClassType { def, args }) => {
+                self.dependencies.borrow_mut().classes.insert(*def);
+                for arg in args {
+                    self.record_type(arg);
+                }
+            }
+            Type::TypeVar(id) if id.context_local_index().is_none() => {
+                self.dependencies.borrow_mut().type_params.insert(*id);
+            }
+            Type::Array(elem) => self.record_type(elem),
+            Type::Wildcard(WildcardBound::Extends(bound) | WildcardBound::Super(bound)) => {
+                self.record_type(bound)
+            }
+            Type::Intersection(parts) => {
+                for part in parts {
+                    self.record_type(part);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl TypeEnv for TrackingTypeEnv<'_> {
+    fn class(&self, id: ClassId) -> Option<&ClassDef> {
+        self.dependencies.borrow_mut().classes.insert(id);
+        self.inner.class(id)
+    }
+
+    fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
+        self.dependencies.borrow_mut().type_params.insert(id);
+        self.inner.type_param(id)
+    }
+
+    fn lookup_class(&self, name: &str) -> Option<ClassId> {
+        self.inner.lookup_class(name)
+    }
+
+    fn well_known(&self) -> &crate::WellKnownTypes {
+        self.inner.well_known()
+    }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        let classes = self.inner.classes_declaring_method(name, limit);
+        self.dependencies
+            .borrow_mut()
+            .classes
+            .extend(classes.iter().copied());
+        classes
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        let interfaces = self.inner.interfaces(limit);
+        self.dependencies
+            .borrow_mut()
+            .classes
+            .extend(interfaces.iter().copied());
+        interfaces
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.inner.method_contract(key)
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        self.inner.class_def_state(id)
     }
 }
 
@@ -325,3 +644,229 @@ impl TypeVarId {
         Some((self.0 & !Self::CONTEXT_LOCAL_BIT) as usize)
     }
 }
+
+/// Extra members appended to an existing class by an [`AugmentedTypeEnv`].
+///
+/// Framework analyzers (Lombok, MapStruct, Spring Data, ...) use this to surface generated
+/// members on real classes without mutating the shared [`crate::TypeStore`].
+#[derive(Debug, Clone, Default)]
+pub struct ClassAugmentation {
+    pub fields: Vec<FieldDef>,
+    pub methods: Vec<MethodDef>,
+    pub interfaces: Vec<Type>,
+}
+
+impl ClassAugmentation {
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.methods.is_empty() && self.interfaces.is_empty()
+    }
+}
+
+/// A [`TypeEnv`] overlay that presents synthetic members on classes of a base environment.
+///
+/// [`TypeEnv::class`] returns borrowed definitions, so merged views are built lazily on first
+/// access and cached for the lifetime of the overlay. Classes without augmentations are served
+/// straight from the base environment.
+pub struct AugmentedTypeEnv<'a> {
+    base: &'a dyn TypeEnv,
+    augmentations: HashMap<ClassId, ClassAugmentation>,
+    merged: HashMap<ClassId, OnceCell<Option<ClassDef>>>,
+}
+
+impl fmt::Debug for AugmentedTypeEnv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AugmentedTypeEnv")
+            .field("augmentations", &self.augmentations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> AugmentedTypeEnv<'a> {
+    pub fn new(base: &'a dyn TypeEnv) -> Self {
+        Self {
+            base,
+            augmentations: HashMap::new(),
+            merged: HashMap::new(),
+        }
+    }
+
+    pub fn base(&self) -> &'a dyn TypeEnv {
+        self.base
+    }
+
+    /// Append `augmentation` to the members already registered for `class`.
+    pub fn augment(&mut self, class: ClassId, augmentation: ClassAugmentation) {
+        if augmentation.is_empty() {
+            return;
+        }
+        let entry = self.augmentations.entry(class).or_default();
+        entry.fields.extend(augmentation.fields);
+        entry.methods.extend(augmentation.methods);
+        entry.interfaces.extend(augmentation.interfaces);
+        // Drop any previously merged view so the next lookup observes the new members.
+        self.merged.insert(class, OnceCell::new());
+    }
+
+    pub fn augmentation(&self, class: ClassId) -> Option<&ClassAugmentation> {
+        self.augmentations.get(&class)
+    }
+
+    fn merge(&self, id: ClassId, augmentation: &ClassAugmentation) -> Option<ClassDef> {
+        let mut def = self.base.class(id)?.clone();
+        def.fields.extend(augmentation.fields.iter().cloned());
+        def.methods.extend(augmentation.methods.iter().cloned());
+        for iface in &augmentation.interfaces {
+            if !def.interfaces.contains(iface) {
+                def.interfaces.push(iface.clone());
+            }
+        }
+        Some(def)
+    }
+}
+
+impl TypeEnv for AugmentedTypeEnv<'_> {
+    fn class(&self, id: ClassId) -> Option<&ClassDef> {
+        let (Some(augmentation), Some(cell)) = (self.augmentations.get(&id), self.merged.get(&id))
+        else {
+            return self.base.class(id);
+        };
+        cell.get_or_init(|| self.merge(id, augmentation)).as_ref()
+    }
+
+    fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
+        self.base.type_param(id)
+    }
+
+    fn lookup_class(&self, name: &str) -> Option<ClassId> {
+        self.base.lookup_class(name)
+    }
+
+    fn well_known(&self) -> &crate::WellKnownTypes {
+        self.base.well_known()
+    }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        let mut out = self.base.classes_declaring_method(name, limit);
+        let mut augmented: Vec<ClassId> = self
+            .augmentations
+            .iter()
+            .filter(|(_, aug)| aug.methods.iter().any(|m| m.name == name))
+            .map(|(id, _)| *id)
+            .filter(|id| !out.contains(id))
+            .collect();
+        augmented.sort();
+        out.extend(augmented);
+        out.truncate(limit);
+        out
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        self.base.interfaces(limit)
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.base.method_contract(key)
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        self.base.class_def_state(id)
+    }
+}
+
+/// A [`TypeEnv`] that layers a small, frequently rebuilt [`TypeStore`] over a base environment.
+///
+/// This lets the classes of a file being edited shadow or extend the classpath without
+/// rebuilding the base store on every keystroke. Class and type parameter lookups consult the
+/// overlay first and fall back to the base; well-known types always come from the base.
+///
+/// Both stores share a single id space: the overlay must allocate ids that the base does not
+/// use, which [`TypeStore::overlay_for`] guarantees. A shadowing overlay class gets a new id, so
+/// base classes that reference the shadowed name keep seeing the base definition.
+pub struct OverlayTypeEnv<'a> {
+    base: &'a dyn TypeEnv,
+    overlay: &'a TypeStore,
+}
+
+impl fmt::Debug for OverlayTypeEnv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OverlayTypeEnv")
+            .field("overlay", &self.overlay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> OverlayTypeEnv<'a> {
+    pub fn new(base: &'a dyn TypeEnv, overlay: &'a TypeStore) -> Self {
+        Self { base, overlay }
+    }
+
+    pub fn base(&self) -> &'a dyn TypeEnv {
+        self.base
+    }
+
+    pub fn overlay(&self) -> &'a TypeStore {
+        self.overlay
+    }
+}
+
+impl TypeEnv for OverlayTypeEnv<'_> {
+    fn class(&self, id: ClassId) -> Option<&ClassDef> {
+        self.overlay.class(id).or_else(|| self.base.class(id))
+    }
+
+    fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
+        self.overlay
+            .type_param(id)
+            .or_else(|| self.base.type_param(id))
+    }
+
+    fn lookup_class(&self, name: &str) -> Option<ClassId> {
+        self.overlay
+            .lookup_class(name)
+            .or_else(|| self.base.lookup_class(name))
+    }
+
+    fn well_known(&self) -> &crate::WellKnownTypes {
+        self.base.well_known()
+    }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        let mut out = self.overlay.classes_declaring_method(name, limit);
+        for id in self.base.classes_declaring_method(name, limit) {
+            if out.len() >= limit {
+                break;
+            }
+            if !out.contains(&id) {
+                out.push(id);
+            }
+        }
+        out
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        let mut out = self.overlay.interfaces(limit);
+        for id in self.base.interfaces(limit) {
+            if out.len() >= limit {
+                break;
+            }
+            if !out.contains(&id) {
+                out.push(id);
+            }
+        }
+        out
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.overlay
+            .method_contract(key)
+            .or_else(|| self.base.method_contract(key))
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        if self.overlay.class(id).is_some() {
+            self.overlay.class_def_state(id)
+        } else {
+            self.base.class_def_state(id)
+        }
+    }
+}
@@ -6,32 +6,184 @@
 use std::fmt::{self, Write as _};
 
 use crate::{
-    ClassId, ClassType, MethodDef, ResolvedMethod, Type, TypeEnv, TypeVarId, WildcardBound,
+    AnnotatedType, ClassId, ClassKind, ClassType, FieldDef, MethodDef, ResolvedMethod, Span, Type,
+    TypeEnv, TypeSubstitution, TypeVarId, WildcardBound,
 };
 
 /// Convenience helper to format a [`Type`] into a newly allocated [`String`].
 pub fn format_type(env: &dyn TypeEnv, ty: &Type) -> String {
-    TypeDisplay { env, ty }.to_string()
+    TypeDisplay::new(env, ty).to_string()
+}
+
+/// Like [`format_type`], but type arguments nested more than `max_depth` levels deep are
+/// rendered as `...`: `Map<String, List<...>>` for `Map<String, List<Set<String>>>` at depth 2.
+pub fn format_type_truncated(env: &dyn TypeEnv, ty: &Type, max_depth: usize) -> String {
+    TypeDisplay::new(env, ty)
+        .with_max_depth(max_depth)
+        .to_string()
 }
 
 /// Display wrapper for formatting a [`Type`] with access to a [`TypeEnv`].
+#[derive(Clone, Copy)]
 pub struct TypeDisplay<'a> {
     pub env: &'a dyn TypeEnv,
     pub ty: &'a Type,
+    /// Number of type levels to render; deeper type argument lists are rendered as `<...>`.
+    /// The outermost type is always rendered.
+    pub max_depth: Option<usize>,
+    /// Maximum number of characters to render; longer renders are cut off and end in `...`.
+    pub max_width: Option<usize>,
 }
 
 impl<'a> TypeDisplay<'a> {
     pub fn new(env: &'a dyn TypeEnv, ty: &'a Type) -> Self {
-        Self { env, ty }
+        Self {
+            env,
+            ty,
+            max_depth: None,
+            max_width: None,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
     }
 }
 
 impl fmt::Display for TypeDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt_type(self.env, self.ty, f)
+        let Some(max_width) = self.max_width else {
+            return fmt_type_limited(
+                self.env,
+                self.ty,
+                self.max_depth.map(|d| d.max(1)),
+                ClassNames::Simple,
+                f,
+            );
+        };
+
+        let full = TypeDisplay {
+            max_width: None,
+            ..*self
+        }
+        .to_string();
+        if full.chars().count() <= max_width {
+            return f.write_str(&full);
+        }
+        const ELLIPSIS: &str = "...";
+        let kept: String = full
+            .chars()
+            .take(max_width.saturating_sub(ELLIPSIS.len()))
+            .collect();
+        f.write_str(&kept)?;
+        f.write_str(ELLIPSIS)
+    }
+}
+
+/// Presentation options for [`project_type_display`].
+///
+/// The default renders fully qualified names with every type argument.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeFormatOptions {
+    /// Render classes by their simple name (`Map.Entry`) rather than qualified
+    /// (`java.util.Map.Entry`).
+    pub use_simple_names: bool,
+    /// With qualified names, still render `java.lang` classes by their simple name (`String`).
+    pub omit_java_lang: bool,
+    /// Render every non-empty type argument list as `<...>`.
+    pub abbreviate_generics: bool,
+    /// Number of type levels to render, as in [`TypeDisplay::max_depth`].
+    pub max_depth: Option<usize>,
+}
+
+/// Display `ty` according to `options`, for contexts that want a presentation other than
+/// [`TypeDisplay`]'s simple names (e.g. fully qualified names in hover).
+///
+/// Types the environment does not track ([`Type::Named`]) are rendered by their binary name.
+pub fn project_type_display<'a>(
+    ty: &'a Type,
+    env: &'a dyn TypeEnv,
+    options: TypeFormatOptions,
+) -> impl fmt::Display + 'a {
+    ProjectTypeDisplay { env, ty, options }
+}
+
+struct ProjectTypeDisplay<'a> {
+    env: &'a dyn TypeEnv,
+    ty: &'a Type,
+    options: TypeFormatOptions,
+}
+
+impl fmt::Display for ProjectTypeDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = self.options;
+        let levels = match (options.abbreviate_generics, options.max_depth) {
+            (true, _) => Some(1),
+            (false, depth) => depth.map(|d| d.max(1)),
+        };
+        let names = if options.use_simple_names {
+            ClassNames::Simple
+        } else {
+            ClassNames::Qualified {
+                omit_java_lang: options.omit_java_lang,
+            }
+        };
+        fmt_type_limited(self.env, self.ty, levels, names, f)
     }
 }
 
+/// Like [`project_type_display`], with the nullability annotations of `ty` in Java syntax.
+///
+/// The annotation of a non-array type prefixes it (`@Nullable String`). An array's own annotation
+/// goes before its outermost brackets and its element type's annotation before the element type:
+/// `@NonNull String[]` is an array of non-null strings, `String @NonNull []` a non-null array.
+pub fn format_annotated_type(
+    env: &dyn TypeEnv,
+    ty: &AnnotatedType,
+    options: TypeFormatOptions,
+) -> String {
+    let mut out = String::new();
+    let mut element = &ty.ty;
+    let mut dims = 0;
+    while let Type::Array(inner) = element {
+        element = inner;
+        dims += 1;
+    }
+
+    let element_nullability = if dims == 0 {
+        ty.nullability
+    } else {
+        ty.element_nullability
+    };
+    if let Some(annotation) = element_nullability.annotation() {
+        out.push_str(annotation);
+        out.push(' ');
+    }
+    let _ = write!(out, "{}", project_type_display(element, env, options));
+    if dims > 0 {
+        if let Some(annotation) = ty.nullability.annotation() {
+            out.push(' ');
+            out.push_str(annotation);
+            out.push(' ');
+        }
+        out.push_str(&"[]".repeat(dims));
+    }
+    out
+}
+
+/// How class names are rendered.
+#[derive(Debug, Clone, Copy)]
+enum ClassNames {
+    Simple,
+    Qualified { omit_java_lang: bool },
+}
+
 /// Convenience helper to format a method or constructor signature (declaration).
 pub fn format_method_signature(env: &dyn TypeEnv, owner: ClassId, method: &MethodDef) -> String {
     MethodSignatureDisplay { env, owner, method }.to_string()
@@ -68,26 +220,330 @@ impl fmt::Display for ResolvedMethodDisplay<'_> {
     }
 }
 
+/// A rendered signature together with the position of each parameter in it (for signature
+/// help, where the client highlights the active parameter).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureLabel {
+    pub text: String,
+    /// One label per declared parameter, in order.
+    pub params: Vec<ParamLabel>,
+}
+
+/// A single parameter of a [`SignatureLabel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamLabel {
+    pub text: String,
+    /// Byte range of `text` within [`SignatureLabel::text`].
+    pub span_in_signature: Span,
+}
+
+impl ResolvedMethod {
+    /// Render the signature like [`format_resolved_method`], recording where each parameter
+    /// starts and ends.
+    ///
+    /// Parameters follow the declared signature (`signature_params` when present), so a
+    /// variable-arity call still shows a single `T...` parameter. Parameter names are appended
+    /// when they are known: `String substring(int beginIndex, int endIndex)`.
+    pub fn effective_signature_display(&self, env: &dyn TypeEnv) -> SignatureLabel {
+        let mut text = ResolvedMethodHead { env, method: self }.to_string();
+        let params = self.signature_params.as_deref().unwrap_or(&self.params);
+        let names = (self.param_names.len() == params.len()).then_some(&self.param_names);
+
+        let mut labels = Vec::with_capacity(params.len());
+        text.push('(');
+        for (idx, param) in params.iter().enumerate() {
+            if idx != 0 {
+                text.push_str(", ");
+            }
+            let start = text.len();
+            if self.is_varargs && idx == params.len() - 1 {
+                let elem = match param {
+                    Type::Array(elem) => elem,
+                    other => other,
+                };
+                let _ = write!(text, "{}...", TypeDisplay::new(env, elem));
+            } else {
+                let _ = write!(text, "{}", TypeDisplay::new(env, param));
+            }
+            if let Some(names) = names {
+                text.push(' ');
+                text.push_str(&names[idx]);
+            }
+            labels.push(ParamLabel {
+                text: text[start..].to_string(),
+                span_in_signature: Span::new(start, text.len()),
+            });
+        }
+        text.push(')');
+
+        SignatureLabel {
+            text,
+            params: labels,
+        }
+    }
+}
+
+/// The part of a resolved method signature before its parameter list.
+struct ResolvedMethodHead<'a> {
+    env: &'a dyn TypeEnv,
+    method: &'a ResolvedMethod,
+}
+
+impl fmt::Display for ResolvedMethodHead<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_resolved_method_head(self.env, self.method, f)
+    }
+}
+
+/// Options for [`render_member_declaration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDisplayOptions {
+    /// Render modifiers (`static`, `abstract`, `default`, `final`).
+    pub modifiers: bool,
+    /// Render parameter names when they are known.
+    pub param_names: bool,
+}
+
+impl Default for TypeDisplayOptions {
+    fn default() -> Self {
+        Self {
+            modifiers: true,
+            param_names: true,
+        }
+    }
+}
+
+/// A member resolved against a receiver type.
+#[derive(Debug, Clone, Copy)]
+pub enum ResolvedMember<'a> {
+    Method(&'a ResolvedMethod),
+    Field(&'a FieldDef),
+}
+
+/// Render the declaration of `member` as seen from `receiver` (for hover).
+///
+/// Class type parameters are substituted with the receiver's type arguments, while the
+/// method's own type parameters are kept and rendered in a `<...>` clause with their
+/// (substituted) bounds: `<U extends String> U pick(String a, U b)` for `Box<String>.pick`.
+/// If the declaration cannot be found, the resolved (fully inferred) signature is rendered
+/// instead. Fields are rendered with the type returned by [`crate::resolve_field`].
+pub fn render_member_declaration(
+    env: &dyn TypeEnv,
+    receiver: &Type,
+    member: ResolvedMember<'_>,
+    opts: &TypeDisplayOptions,
+) -> String {
+    let mut out = String::new();
+    match member {
+        ResolvedMember::Method(method) => {
+            let _ = write_method_declaration(env, receiver, method, opts, &mut out);
+        }
+        ResolvedMember::Field(field) => {
+            if opts.modifiers {
+                if field.is_static {
+                    out.push_str("static ");
+                }
+                if field.is_final {
+                    out.push_str("final ");
+                }
+            }
+            let _ = write!(out, "{} {}", TypeDisplay::new(env, &field.ty), field.name);
+        }
+    }
+    out
+}
+
+fn write_method_declaration(
+    env: &dyn TypeEnv,
+    receiver: &Type,
+    method: &ResolvedMethod,
+    opts: &TypeDisplayOptions,
+    out: &mut String,
+) -> fmt::Result {
+    let declared = find_method_declaration(env, receiver, method);
+
+    if opts.modifiers {
+        let is_interface = env
+            .class(method.owner)
+            .is_some_and(|class_def| class_def.kind == ClassKind::Interface);
+        let is_abstract = declared.as_ref().is_some_and(|(def, _)| def.is_abstract);
+        if method.is_static {
+            out.push_str("static ");
+        } else if is_interface && declared.is_some() && !is_abstract {
+            out.push_str("default ");
+        } else if is_abstract && !is_interface {
+            out.push_str("abstract ");
+        }
+    }
+
+    let Some((def, class_subst)) = declared else {
+        // Fall back to the resolved signature (method type parameters are already inferred).
+        let params = method.signature_params.as_deref().unwrap_or(&method.params);
+        write!(
+            out,
+            "{} {}",
+            TypeDisplay::new(env, &method.return_type),
+            method.name
+        )?;
+        return write_named_params(
+            env,
+            params,
+            &method.param_names,
+            method.is_varargs,
+            opts,
+            out,
+        );
+    };
+
+    if !def.type_params.is_empty() {
+        out.push('<');
+        for (idx, &id) in def.type_params.iter().enumerate() {
+            if idx != 0 {
+                out.push_str(", ");
+            }
+            write_type_param_decl(env, id, &class_subst, out)?;
+        }
+        out.push_str("> ");
+    }
+
+    let return_type = class_subst.apply(&def.return_type);
+    write!(out, "{} {}", TypeDisplay::new(env, &return_type), def.name)?;
+    let params: Vec<Type> = def.params.iter().map(|p| class_subst.apply(p)).collect();
+    write_named_params(env, &params, &def.param_names, def.is_varargs, opts, out)
+}
+
+/// Find the [`MethodDef`] that `method` was resolved from, along with the substitution of the
+/// owner's class type parameters as seen from `receiver`.
+fn find_method_declaration<'env>(
+    env: &'env dyn TypeEnv,
+    receiver: &Type,
+    method: &ResolvedMethod,
+) -> Option<(&'env MethodDef, TypeSubstitution)> {
+    let class_def = env.class(method.owner)?;
+    let class_subst = crate::class_substitution_for_owner(env, receiver, method.owner);
+    let resolved_params = method.signature_params.as_deref().unwrap_or(&method.params);
+
+    let candidates: Vec<&MethodDef> = class_def
+        .methods
+        .iter()
+        .filter(|def| {
+            def.name == method.name
+                && def.is_static == method.is_static
+                && def.is_varargs == method.is_varargs
+                && def.params.len() == resolved_params.len()
+        })
+        .collect();
+
+    // Prefer the overload whose instantiated signature matches the resolved one exactly.
+    let exact = candidates.iter().copied().find(|def| {
+        let method_subst = TypeSubstitution::from_iter(
+            def.type_params
+                .iter()
+                .copied()
+                .zip(method.inferred_type_args.iter().cloned()),
+        );
+        def.params
+            .iter()
+            .zip(resolved_params)
+            .all(|(param, resolved)| &method_subst.apply(&class_subst.apply(param)) == resolved)
+    });
+    let def = match (exact, candidates.as_slice()) {
+        (Some(def), _) => def,
+        (None, [only]) => *only,
+        _ => return None,
+    };
+    Some((def, class_subst))
+}
+
+fn write_type_param_decl(
+    env: &dyn TypeEnv,
+    id: TypeVarId,
+    class_subst: &TypeSubstitution,
+    out: &mut String,
+) -> fmt::Result {
+    let Some(tp) = env.type_param(id) else {
+        return write!(out, "<tv#{}>", id.0);
+    };
+    out.push_str(&tp.name);
+
+    let bounds: Vec<Type> = tp
+        .upper_bounds
+        .iter()
+        .map(|bound| class_subst.apply(bound))
+        .collect();
+    if bounds.is_empty() || (bounds.len() == 1 && is_object_bound(env, &bounds[0])) {
+        return Ok(());
+    }
+
+    out.push_str(" extends ");
+    for (idx, bound) in bounds.iter().enumerate() {
+        if idx != 0 {
+            out.push_str(" & ");
+        }
+        write!(out, "{}", TypeDisplay::new(env, bound))?;
+    }
+    Ok(())
+}
+
+fn write_named_params(
+    env: &dyn TypeEnv,
+    params: &[Type],
+    names: &[String],
+    is_varargs: bool,
+    opts: &TypeDisplayOptions,
+    out: &mut String,
+) -> fmt::Result {
+    let names = (opts.param_names && names.len() == params.len()).then_some(names);
+    out.push('(');
+    for (idx, param) in params.iter().enumerate() {
+        if idx != 0 {
+            out.push_str(", ");
+        }
+
+        match param {
+            Type::Array(elem) if is_varargs && idx == params.len() - 1 => {
+                write!(out, "{}...", TypeDisplay::new(env, elem))?;
+            }
+            _ => write!(out, "{}", TypeDisplay::new(env, param))?,
+        }
+        if let Some(names) = names {
+            out.push(' ');
+            out.push_str(&names[idx]);
+        }
+    }
+    out.push(')');
+    Ok(())
+}
+
 fn fmt_type(env: &dyn TypeEnv, ty: &Type, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt_type_limited(env, ty, None, ClassNames::Simple, f)
+}
+
+/// Format `ty`, rendering at most `levels` levels of nested type arguments (all if `None`).
+fn fmt_type_limited(
+    env: &dyn TypeEnv,
+    ty: &Type,
+    levels: Option<usize>,
+    names: ClassNames,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
     match ty {
         Type::Void => f.write_str("void"),
-        Type::Primitive(p) => f.write_str(match p {
-            crate::PrimitiveType::Boolean => "boolean",
-            crate::PrimitiveType::Byte => "byte",
-            crate::PrimitiveType::Short => "short",
-            crate::PrimitiveType::Char => "char",
-            crate::PrimitiveType::Int => "int",
-            crate::PrimitiveType::Long => "long",
-            crate::PrimitiveType::Float => "float",
-            crate::PrimitiveType::Double => "double",
-        }),
+        Type::Primitive(p) => f.write_str(p.keyword()),
         Type::Class(ClassType { def, args }) => {
-            fmt_class_id(env, *def, f)?;
-            fmt_type_args(env, args, f)
+            fmt_class_id(env, *def, names, f)?;
+            fmt_type_args(env, args, levels, names, f)
         }
         Type::Array(_) => {
             let (base, dims) = peel_array_dims(ty);
-            fmt_type(env, base, f)?;
+            if matches!(base, Type::Intersection(_)) {
+                // `(Cloneable & Serializable)[]` rather than the ambiguous `Cloneable & Serializable[]`.
+                f.write_char('(')?;
+                fmt_type_limited(env, base, levels, names, f)?;
+                f.write_char(')')?;
+            } else {
+                fmt_type_limited(env, base, levels, names, f)?;
+            }
             for _ in 0..dims {
                 f.write_str("[]")?;
             }
@@ -98,11 +554,11 @@ fn fmt_type(env: &dyn TypeEnv, ty: &Type, f: &mut fmt::Formatter<'_>) -> fmt::Re
             WildcardBound::Unbounded => f.write_str("?"),
             WildcardBound::Extends(upper) => {
                 f.write_str("? extends ")?;
-                fmt_type(env, upper, f)
+                fmt_type_limited(env, upper, levels, names, f)
             }
             WildcardBound::Super(lower) => {
                 f.write_str("? super ")?;
-                fmt_type(env, lower, f)
+                fmt_type_limited(env, lower, levels, names, f)
             }
         },
         Type::Intersection(types) => {
@@ -110,17 +566,22 @@ fn fmt_type(env: &dyn TypeEnv, ty: &Type, f: &mut fmt::Formatter<'_>) -> fmt::Re
             let Some(first) = it.next() else {
                 return f.write_str("<?>");
             };
-            fmt_type(env, first, f)?;
+            fmt_type_limited(env, first, levels, names, f)?;
             for ty in it {
                 f.write_str(" & ")?;
-                fmt_type(env, ty, f)?;
+                fmt_type_limited(env, ty, levels, names, f)?;
             }
             Ok(())
         }
         Type::Null => f.write_str("null"),
-        Type::Named(name) => f.write_str(name),
+        Type::Named(name) => match names {
+            ClassNames::Qualified {
+                omit_java_lang: true,
+            } if is_java_lang(name) => f.write_str(&name["java.lang.".len()..]),
+            _ => f.write_str(name),
+        },
         Type::VirtualInner { owner, name } => {
-            fmt_class_id(env, *owner, f)?;
+            fmt_class_id(env, *owner, names, f)?;
             f.write_char('.')?;
             f.write_str(name)
         }
@@ -138,34 +599,57 @@ fn peel_array_dims(mut ty: &Type) -> (&Type, usize) {
     (ty, dims)
 }
 
-fn fmt_type_args(env: &dyn TypeEnv, args: &[Type], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+fn fmt_type_args(
+    env: &dyn TypeEnv,
+    args: &[Type],
+    levels: Option<usize>,
+    names: ClassNames,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
     if args.is_empty() {
         return Ok(());
     }
+    let levels = match levels {
+        Some(levels) if levels <= 1 => return f.write_str("<...>"),
+        levels => levels.map(|levels| levels - 1),
+    };
     f.write_char('<')?;
     for (idx, arg) in args.iter().enumerate() {
         if idx != 0 {
             f.write_str(", ")?;
         }
-        fmt_type(env, arg, f)?;
+        fmt_type_limited(env, arg, levels, names, f)?;
     }
     f.write_char('>')
 }
 
-fn fmt_class_id(env: &dyn TypeEnv, id: ClassId, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+fn fmt_class_id(
+    env: &dyn TypeEnv,
+    id: ClassId,
+    names: ClassNames,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
     let Some(class_def) = env.class(id) else {
         return write!(f, "<class#{}>", id.to_raw());
     };
-    fmt_class_name(&class_def.name, f)
+    fmt_class_name(&class_def.name, names, f)
 }
 
-fn fmt_class_name(binary_name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+fn fmt_class_name(binary_name: &str, names: ClassNames, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     // The type model stores binary names (`java.util.Map$Entry`). For user display we render a
-    // Java source-style name (`Map.Entry`) and drop the package prefix for readability.
-    let class_part = binary_name
-        .rsplit_once('.')
-        .map(|(_, tail)| tail)
-        .unwrap_or(binary_name);
+    // Java source-style name (`Map.Entry`), by default dropping the package prefix for readability.
+    let class_part = match names {
+        ClassNames::Qualified {
+            omit_java_lang: false,
+        } => binary_name,
+        ClassNames::Qualified {
+            omit_java_lang: true,
+        } if !is_java_lang(binary_name) => binary_name,
+        _ => binary_name
+            .rsplit_once('.')
+            .map(|(_, tail)| tail)
+            .unwrap_or(binary_name),
+    };
     for ch in class_part.chars() {
         if ch == '$' {
             f.write_char('.')?;
@@ -176,6 +660,13 @@ fn fmt_class_name(binary_name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result
     Ok(())
 }
 
+/// Whether `binary_name` is a class in the `java.lang` package itself (not a subpackage).
+fn is_java_lang(binary_name: &str) -> bool {
+    binary_name
+        .strip_prefix("java.lang.")
+        .is_some_and(|rest| !rest.contains('.'))
+}
+
 fn fmt_type_var(env: &dyn TypeEnv, id: TypeVarId, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     if let Some(tp) = env.type_param(id) {
         f.write_str(&tp.name)
@@ -193,7 +684,7 @@ fn fmt_method_signature(
     fmt_type_param_list(env, &method.type_params, f)?;
 
     if is_constructor_name(&method.name) {
-        fmt_class_id(env, owner, f)?;
+        fmt_class_id(env, owner, ClassNames::Simple, f)?;
     } else {
         fmt_type(env, &method.return_type, f)?;
         f.write_char(' ')?;
@@ -207,16 +698,24 @@ fn fmt_resolved_method(
     env: &dyn TypeEnv,
     method: &ResolvedMethod,
     f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt_resolved_method_head(env, method, f)?;
+    let params = method.signature_params.as_deref().unwrap_or(&method.params);
+    fmt_param_list(env, params, method.is_varargs, f)
+}
+
+fn fmt_resolved_method_head(
+    env: &dyn TypeEnv,
+    method: &ResolvedMethod,
+    f: &mut fmt::Formatter<'_>,
 ) -> fmt::Result {
     if is_constructor_name(&method.name) {
-        fmt_class_id(env, method.owner, f)?;
+        fmt_class_id(env, method.owner, ClassNames::Simple, f)
     } else {
         fmt_type(env, &method.return_type, f)?;
         f.write_char(' ')?;
-        f.write_str(&method.name)?;
+        f.write_str(&method.name)
     }
-    let params = method.signature_params.as_deref().unwrap_or(&method.params);
-    fmt_param_list(env, params, method.is_varargs, f)
 }
 
 fn fmt_type_param_list(
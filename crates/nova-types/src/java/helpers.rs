@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::{ClassId, ClassKind, ClassType, PrimitiveType, Type, TypeEnv, TypeVarId};
+use crate::{
+    ClassId, ClassKind, ClassType, PrimitiveType, Type, TypeEnv, TypeSubstitution, TypeVarId,
+};
 
 /// Return `ty` viewed as `target` by walking the supertype graph and applying type argument
 /// substitution along the way.
@@ -151,8 +153,7 @@ pub fn instantiate_as_supertype(env: &dyn TypeEnv, ty: &Type, target: ClassId) -
             }
 
             // Apply the current instantiation's substitution to its supertypes.
-            let mut subst: HashMap<TypeVarId, Type> =
-                HashMap::with_capacity(class_def.type_params.len());
+            let mut subst = crate::TypeSubstitution::empty();
             for (idx, formal) in class_def.type_params.iter().copied().enumerate() {
                 subst.insert(formal, args.get(idx).cloned().unwrap_or(Type::Unknown));
             }
@@ -188,6 +189,27 @@ pub fn instantiate_as_supertype(env: &dyn TypeEnv, ty: &Type, target: ClassId) -
     inner(env, ty, target, &mut seen_type_vars)
 }
 
+/// Map `super_class`'s type parameters to their instantiation when `sub` is viewed as
+/// `super_class` (see [`instantiate_as_supertype`]).
+///
+/// Example: `ArrayList<String>` viewed as `List` returns `{E -> String}`, where `E` is `List`'s
+/// type parameter. A raw view produces an empty substitution; a `sub` that is not a subtype of
+/// `super_class` returns `None`.
+pub fn infer_type_args_for_supertype(
+    env: &dyn TypeEnv,
+    sub: &Type,
+    super_class: ClassId,
+) -> Option<TypeSubstitution> {
+    let Type::Class(ClassType { def, args }) = instantiate_as_supertype(env, sub, super_class)?
+    else {
+        return None;
+    };
+    let class_def = env.class(def)?;
+    Some(TypeSubstitution::from_class_def_with_enclosing(
+        env, class_def, &args,
+    ))
+}
+
 fn merge_instantiated_supertypes(env: &dyn TypeEnv, a: Type, b: Type) -> Option<Type> {
     if a == b {
         return Some(a);
@@ -341,8 +363,7 @@ pub fn sam_signature(env: &dyn TypeEnv, ty: &Type) -> Option<SamSignature> {
             //
             // If `args` is missing entries (raw or malformed), fall back to `Unknown` so downstream
             // callers still get a stable shape.
-            let mut subst: HashMap<TypeVarId, Type> =
-                HashMap::with_capacity(class_def.type_params.len());
+            let mut subst = crate::TypeSubstitution::empty();
             for (idx, formal) in class_def.type_params.iter().copied().enumerate() {
                 subst.insert(formal, args.get(idx).cloned().unwrap_or(Type::Unknown));
             }
@@ -509,6 +530,10 @@ mod tests {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         {
@@ -568,32 +593,45 @@ mod tests {
                     name: "defaultMethod".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 // Static method should be ignored.
                 MethodDef {
                     name: "staticMethod".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: true,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 // Only abstract instance method counts towards SAM.
                 MethodDef {
                     name: "apply".to_string(),
                     type_params: vec![],
                     params: vec![Type::TypeVar(iface_t)],
+                    param_names: vec![],
                     return_type: Type::TypeVar(iface_t),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 },
             ],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let string = Type::class(store.well_known().string, vec![]);
@@ -622,11 +660,18 @@ mod tests {
                 name: "apply".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Named("java.lang.String".to_string()),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let i2 = store.add_class(ClassDef {
@@ -641,11 +686,18 @@ mod tests {
                 name: "apply".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: string.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let root1 = store.add_class(ClassDef {
@@ -657,6 +709,10 @@ mod tests {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let root2 = store.add_class(ClassDef {
@@ -668,6 +724,10 @@ mod tests {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let sig1 = sam_signature(&store, &Type::class(root1, vec![]))
@@ -735,11 +795,18 @@ mod tests {
                 name: "apply".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Named("java.lang.String".to_string()),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let i_class = store.add_class(ClassDef {
@@ -754,11 +821,18 @@ mod tests {
                 name: "apply".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: string.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let tv = store.add_type_param(
@@ -4,6 +4,7 @@
 //! formatting preferences, etc). The formatters here are "Java-like" and stable,
 //! intended for diagnostics and language server features.
 
+pub mod contracts;
 pub mod env;
 pub mod format;
 pub mod helpers;
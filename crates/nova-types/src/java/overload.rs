@@ -1,4 +1,4 @@
-use crate::{MethodCall, MethodResolution, TypeEnv};
+use crate::{MethodCall, MethodResolution, ResolvedMethod, Type, TypeEnv};
 
 use super::env::TyContext;
 
@@ -8,8 +8,76 @@ use super::env::TyContext;
 /// This function is side-effect free with respect to the global environment: any capture
 /// conversion allocations are performed in the supplied [`TyContext`].
 pub fn resolve_method_call(ctx: &mut TyContext<'_>, call: &MethodCall<'_>) -> MethodResolution {
+    if !ctx.checks_placeholders() {
+        return resolve_method_call_inner(ctx, call);
+    }
+
+    let (mut resolution, placeholders) =
+        ctx.collect_placeholders(|ctx| resolve_method_call_inner(ctx, call));
+    match &mut resolution {
+        MethodResolution::Found(method) => method.depends_on_placeholders = placeholders,
+        MethodResolution::Ambiguous(ambiguity) => {
+            for candidate in &mut ambiguity.candidates {
+                candidate.depends_on_placeholders = placeholders.clone();
+            }
+        }
+        MethodResolution::NotFound(_) => {}
+    }
+    resolution
+}
+
+fn resolve_method_call_inner(ctx: &mut TyContext<'_>, call: &MethodCall<'_>) -> MethodResolution {
+    ctx.record_type_dependencies(
+        std::iter::once(&call.receiver)
+            .chain(&call.args)
+            .chain(&call.expected_return)
+            .chain(&call.explicit_type_args),
+    );
+
+    if ctx.recovers_errorish_receivers() && call.receiver.is_errorish() {
+        if let Some(found) = resolve_speculatively(ctx, call) {
+            return MethodResolution::Found(found);
+        }
+    }
+
     let receiver = ctx.normalize_receiver_for_member_access(&call.receiver);
 
     let env_ro: &dyn TypeEnv = &*ctx;
     crate::resolve_method_call_impl(env_ro, call, receiver)
 }
+
+/// Upper bound on the number of store classes searched by the last-resort recovery step.
+const MAX_SPECULATIVE_RECEIVER_CLASSES: usize = 16;
+
+/// Best-effort recovery for calls on errorish receivers (see
+/// [`TyContext::with_errorish_receiver_recovery`]).
+fn resolve_speculatively(ctx: &mut TyContext<'_>, call: &MethodCall<'_>) -> Option<ResolvedMethod> {
+    let mut receivers: Vec<Type> = Vec::new();
+    receivers.extend(call.expected_return.iter().cloned());
+    receivers.extend(ctx.likely_receivers().iter().cloned());
+    receivers.extend(
+        ctx.classes_declaring_method(call.name, MAX_SPECULATIVE_RECEIVER_CLASSES)
+            .into_iter()
+            .map(|id| Type::class(id, vec![])),
+    );
+
+    for receiver in receivers {
+        if receiver.is_errorish() {
+            continue;
+        }
+        let speculative_call = MethodCall {
+            receiver,
+            ..call.clone()
+        };
+        let receiver = ctx.normalize_receiver_for_member_access(&speculative_call.receiver);
+        let env_ro: &dyn TypeEnv = &*ctx;
+        if let MethodResolution::Found(mut found) =
+            crate::resolve_method_call_impl(env_ro, &speculative_call, receiver)
+        {
+            found.speculative = true;
+            return Some(found);
+        }
+    }
+
+    None
+}
@@ -11,27 +11,50 @@
 //! IDE) rather than a full JLS implementation.
 
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::path::PathBuf;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
 pub mod java;
+mod line_index;
+mod portable;
 
-pub use java::env::TyContext;
-pub use java::helpers::{instantiate_as_supertype, sam_signature, SamSignature};
+pub use java::contracts::{
+    resolved_method_contract, well_known_contract, MethodContract, MethodContractKey, NullBehavior,
+};
+pub use java::env::{
+    AugmentedTypeEnv, ClassAugmentation, DependencySet, OverlayTypeEnv, SubtypeCheck,
+    TrackingTypeEnv, TyContext,
+};
+pub use java::helpers::{
+    infer_type_args_for_supertype, instantiate_as_supertype, sam_signature, SamSignature,
+};
 pub use java::overload::resolve_method_call;
+pub use line_index::{LineIndex, Position};
+pub use portable::{
+    json_schemas, PortableClassRef, PortableClassType, PortableMethodAmbiguity,
+    PortableMethodCandidate, PortableMethodCandidateDiagnostics, PortableMethodCandidateFailure,
+    PortableMethodCandidateFailureReason, PortableMethodNotFound, PortableMethodResolution,
+    PortableResolvedMethod, PortableType, PortableTypeVarRef, PortableWildcardBound,
+    JSON_SCHEMA_VERSION,
+};
 
 pub use java::format::{
-    format_method_signature, format_resolved_method, format_type, MethodSignatureDisplay,
-    ResolvedMethodDisplay, TypeDisplay,
+    format_annotated_type, format_method_signature, format_resolved_method, format_type,
+    format_type_truncated, project_type_display, render_member_declaration, MethodSignatureDisplay,
+    ParamLabel, ResolvedMember, ResolvedMethodDisplay, SignatureLabel, TypeDisplay,
+    TypeDisplayOptions, TypeFormatOptions,
 };
 
 // === Generic shared types ====================================================
 
 /// A byte-span into a source string.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -49,6 +72,33 @@ impl Span {
     pub fn is_empty(&self) -> bool {
         self.start >= self.end
     }
+
+    /// Whether `other` lies entirely within `self`. An empty `other` is contained if its offset
+    /// is within `self.start..=self.end`.
+    pub fn contains(self, other: Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether `offset` is one of the bytes covered by `self`; an empty span covers no bytes.
+    pub fn contains_offset(self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// The smallest span covering both `self` and `other`.
+    pub fn merge(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// The overlap of `self` and `other`, or `None` if they share no bytes.
+    ///
+    /// Spans that merely touch (`0..2` and `2..4`) do not overlap, but an empty span inside the
+    /// other span intersects it as itself.
+    pub fn intersect(self, other: Span) -> Option<Span> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        let overlaps = start < end || (start == end && (self.is_empty() || other.is_empty()));
+        overlaps.then_some(Span::new(start, end))
+    }
 }
 
 impl fmt::Debug for Span {
@@ -57,46 +107,286 @@ impl fmt::Debug for Span {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Diagnostic severity, ordered from least to most severe (`Hint < Info < Warning < Error`).
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
 pub enum Severity {
-    Error,
-    Warning,
+    Hint,
     Info,
+    Warning,
+    Error,
+}
+
+/// Editor rendering hints for a diagnostic (mirrors LSP `DiagnosticTag`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum DiagnosticTag {
+    /// Unused or unreachable code; editors typically grey it out.
+    Unnecessary,
+    /// Use of a deprecated API; editors typically strike it through.
+    Deprecated,
+}
+
+/// A secondary location that helps explain a diagnostic, e.g. the other declaration of a
+/// duplicate name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RelatedInfo {
+    /// File containing `span`; `None` means the file the diagnostic itself was reported in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    pub span: Span,
+    pub message: String,
+}
+
+impl RelatedInfo {
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            span,
+            message: message.into(),
+        }
+    }
+
+    pub fn in_file(file: impl Into<PathBuf>, span: Span, message: impl Into<String>) -> Self {
+        Self {
+            file: Some(file.into()),
+            ..Self::new(span, message)
+        }
+    }
+}
+
+/// Names a quick fix that applies to a diagnostic without carrying any edits.
+///
+/// The quick-fix system resolves `id` (plus `args`) into concrete edits on demand, so diagnostics
+/// stay cheap to produce and to send across process boundaries.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct FixHint {
+    pub id: Cow<'static, str>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+}
+
+impl FixHint {
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            id: id.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+}
+
+/// Replace the text at `span` with `new_text` (an empty span inserts).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn new(span: Span, new_text: impl Into<String>) -> Self {
+        Self {
+            span,
+            new_text: new_text.into(),
+        }
+    }
+
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self::new(Span::new(offset, offset), text)
+    }
+}
+
+/// A ready-made fix for a diagnostic: edits to the diagnostic's file, shown to the user as
+/// `title` (LSP code action).
+///
+/// Unlike a [`FixHint`], the edits are computed when the diagnostic is produced, which suits
+/// fixes the producer already knows how to apply.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct Suggestion {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl Suggestion {
+    pub fn new(title: impl Into<String>, edits: Vec<TextEdit>) -> Self {
+        Self {
+            title: title.into(),
+            edits,
+        }
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Diagnostic {
     pub severity: Severity,
     pub code: Cow<'static, str>,
     pub message: String,
     pub span: Option<Span>,
+    /// Secondary locations (LSP `relatedInformation`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<RelatedInfo>,
+    /// Producer of the diagnostic, e.g. `"types"`, `"spring"` or `"lint"` (LSP `source`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Cow<'static, str>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<DiagnosticTag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_hint: Option<FixHint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<Suggestion>,
 }
 
 impl Diagnostic {
-    pub fn error(
+    pub fn new(
+        severity: Severity,
         code: impl Into<Cow<'static, str>>,
         message: impl Into<String>,
         span: Option<Span>,
     ) -> Self {
         Self {
-            severity: Severity::Error,
+            severity,
             code: code.into(),
             message: message.into(),
             span,
+            related: Vec::new(),
+            source: None,
+            tags: Vec::new(),
+            fix_hint: None,
+            suggestions: Vec::new(),
         }
     }
 
+    pub fn error(
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<String>,
+        span: Option<Span>,
+    ) -> Self {
+        Self::new(Severity::Error, code, message, span)
+    }
+
     pub fn warning(
         code: impl Into<Cow<'static, str>>,
         message: impl Into<String>,
         span: Option<Span>,
     ) -> Self {
-        Self {
-            severity: Severity::Warning,
-            code: code.into(),
-            message: message.into(),
-            span,
+        Self::new(Severity::Warning, code, message, span)
+    }
+
+    pub fn hint(
+        code: impl Into<Cow<'static, str>>,
+        message: impl Into<String>,
+        span: Option<Span>,
+    ) -> Self {
+        Self::new(Severity::Hint, code, message, span)
+    }
+
+    pub fn with_related(mut self, related: RelatedInfo) -> Self {
+        self.related.push(related);
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<Cow<'static, str>>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_tag(mut self, tag: DiagnosticTag) -> Self {
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
         }
+        self
+    }
+
+    pub fn with_fix_hint(mut self, fix_hint: FixHint) -> Self {
+        self.fix_hint = Some(fix_hint);
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+}
+
+/// An ordered collection of diagnostics with severity predicates.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiagnosticBag {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl DiagnosticBag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.diagnostics.iter()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.has_severity(Severity::Error)
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.has_severity(Severity::Warning)
+    }
+
+    pub fn has_hints(&self) -> bool {
+        self.has_severity(Severity::Hint)
+    }
+
+    /// Sort diagnostics by descending severity, then by span start.
+    pub fn sort(&mut self) {
+        self.diagnostics.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.span.map(|s| s.start).cmp(&b.span.map(|s| s.start)))
+        });
+    }
+
+    pub fn into_vec(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    fn has_severity(&self, severity: Severity) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == severity)
+    }
+}
+
+impl From<Vec<Diagnostic>> for DiagnosticBag {
+    fn from(diagnostics: Vec<Diagnostic>) -> Self {
+        Self { diagnostics }
+    }
+}
+
+impl Extend<Diagnostic> for DiagnosticBag {
+    fn extend<I: IntoIterator<Item = Diagnostic>>(&mut self, iter: I) {
+        self.diagnostics.extend(iter);
+    }
+}
+
+impl IntoIterator for DiagnosticBag {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.into_iter()
     }
 }
 
@@ -112,12 +402,11 @@ mod diagnostic_tests {
 
     #[test]
     fn dynamic_codes_can_be_owned() {
-        let diag = Diagnostic {
-            severity: Severity::Error,
-            code: Cow::Owned("my.plugin.code".to_string()),
-            message: "msg".to_string(),
-            span: None,
-        };
+        let diag = Diagnostic::error(
+            Cow::Owned("my.plugin.code".to_string()),
+            "msg".to_string(),
+            None,
+        );
 
         assert_eq!(diag.code.as_ref(), "my.plugin.code");
 
@@ -125,23 +414,248 @@ mod diagnostic_tests {
         let cloned = diag.clone();
         assert_eq!(cloned, diag);
     }
+
+    #[test]
+    fn severity_orders_error_above_hint() {
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Info);
+        assert!(Severity::Info > Severity::Hint);
+
+        let mut severities = vec![
+            Severity::Info,
+            Severity::Error,
+            Severity::Hint,
+            Severity::Warning,
+        ];
+        severities.sort();
+        assert_eq!(
+            severities,
+            vec![
+                Severity::Hint,
+                Severity::Info,
+                Severity::Warning,
+                Severity::Error
+            ]
+        );
+    }
+
+    #[test]
+    fn bag_predicates_track_severities() {
+        let mut bag = DiagnosticBag::new();
+        bag.push(Diagnostic::hint("HINT", "msg", None));
+        assert!(bag.has_hints());
+        assert!(!bag.has_errors());
+        assert!(!bag.has_warnings());
+
+        bag.push(Diagnostic::error("ERR", "msg", Some(Span::new(4, 5))));
+        bag.push(Diagnostic::warning("WARN", "msg", Some(Span::new(0, 1))));
+        assert!(bag.has_errors());
+        assert!(bag.has_warnings());
+
+        bag.sort();
+        let codes: Vec<_> = bag.iter().map(|d| d.code.as_ref()).collect();
+        assert_eq!(codes, vec!["ERR", "WARN", "HINT"]);
+    }
+
+    #[test]
+    fn hint_severity_round_trips_through_serde() {
+        let json = serde_json::to_string(&Severity::Hint).unwrap();
+        assert_eq!(json, "\"Hint\"");
+        let back: Severity = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, Severity::Hint);
+    }
+
+    #[test]
+    fn builders_attach_related_info_tags_and_fix_hints() {
+        let diag = Diagnostic::warning(
+            "DUPLICATE_BEAN",
+            "duplicate bean `foo`",
+            Some(Span::new(10, 13)),
+        )
+        .with_source("spring")
+        .with_related(RelatedInfo::new(Span::new(40, 43), "also declared here"))
+        .with_related(RelatedInfo::in_file(
+            "src/Other.java",
+            Span::new(0, 3),
+            "and here",
+        ))
+        .with_tag(DiagnosticTag::Unnecessary)
+        .with_tag(DiagnosticTag::Unnecessary)
+        .with_fix_hint(FixHint::new("rename-bean").with_arg("foo"));
+
+        assert_eq!(diag.source.as_deref(), Some("spring"));
+        assert_eq!(diag.related.len(), 2);
+        assert_eq!(diag.related[0].file, None);
+        assert_eq!(
+            diag.related[1].file.as_deref(),
+            Some(std::path::Path::new("src/Other.java"))
+        );
+        assert_eq!(diag.tags, vec![DiagnosticTag::Unnecessary]);
+        assert_eq!(
+            diag.fix_hint.as_ref().unwrap().args,
+            vec!["foo".to_string()]
+        );
+
+        let json = serde_json::to_string(&diag).unwrap();
+        let back: Diagnostic = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, diag);
+    }
+
+    #[test]
+    fn plain_diagnostics_deserialize_without_the_optional_fields() {
+        let diag: Diagnostic = serde_json::from_str(
+            r#"{"severity":"Error","code":"E","message":"msg","span":{"start":1,"end":2}}"#,
+        )
+        .unwrap();
+        assert_eq!(diag, Diagnostic::error("E", "msg", Some(Span::new(1, 2))));
+
+        let json = serde_json::to_value(&diag).unwrap();
+        assert!(json.get("related").is_none());
+        assert!(json.get("fix_hint").is_none());
+    }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// What a completion item inserts (mirrors the LSP `CompletionItemKind`s Nova produces).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, JsonSchema)]
+pub enum CompletionItemKind {
+    Method,
+    Field,
+    Class,
+    Interface,
+    Enum,
+    EnumMember,
+    Keyword,
+    Variable,
+    Constructor,
+    Package,
+    Snippet,
+}
+
+/// How a completion item's insert text is interpreted (mirrors LSP `InsertTextFormat`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, JsonSchema)]
+pub enum InsertTextFormat {
+    #[default]
+    PlainText,
+    /// LSP snippet syntax: `$1`/`${1:placeholder}` tab stops and a final `$0`.
+    Snippet,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
 pub struct CompletionItem {
     pub label: String,
+    pub kind: Option<CompletionItemKind>,
     pub detail: Option<String>,
     pub replace_span: Option<Span>,
+    /// Text to insert instead of `label`.
+    pub insert_text: Option<String>,
+    pub insert_text_format: InsertTextFormat,
+    /// Key used to order items instead of `label`.
+    pub sort_text: Option<String>,
+    /// Text matched against the typed prefix instead of `label`.
+    pub filter_text: Option<String>,
+    /// Edits applied alongside the completion, e.g. inserting an import.
+    pub additional_edits: Vec<(Span, String)>,
+    pub deprecated: bool,
 }
 
 impl CompletionItem {
     pub fn new(label: impl Into<String>) -> Self {
         Self {
             label: label.into(),
-            detail: None,
-            replace_span: None,
+            ..Self::default()
+        }
+    }
+
+    /// A completion for calling `method`, with a snippet tab stop per parameter.
+    ///
+    /// Placeholders use the parameter names when known (`arg0`, `arg1`, ... otherwise). Methods
+    /// without parameters insert plain `name()`, leaving the cursor after the parentheses.
+    /// Deprecated methods are marked [`CompletionItem::deprecated`].
+    pub fn method(method: &MethodDef) -> Self {
+        let item = Self::new(method.name.clone())
+            .with_kind(CompletionItemKind::Method)
+            .with_deprecated(method.deprecation.is_some());
+        if method.params.is_empty() {
+            return item.with_insert_text(format!("{}()", method.name));
+        }
+
+        let mut snippet = escape_snippet_text(&method.name);
+        snippet.push('(');
+        for idx in 0..method.params.len() {
+            if idx > 0 {
+                snippet.push_str(", ");
+            }
+            let placeholder = match method.param_name(idx) {
+                Some(name) => escape_snippet_text(name),
+                None => format!("arg{idx}"),
+            };
+            snippet.push_str(&format!("${{{}:{placeholder}}}", idx + 1));
+        }
+        snippet.push_str(")$0");
+        item.with_snippet(snippet)
+    }
+
+    pub fn with_kind(mut self, kind: CompletionItemKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_replace_span(mut self, span: Span) -> Self {
+        self.replace_span = Some(span);
+        self
+    }
+
+    /// Insert `text` verbatim instead of the label.
+    pub fn with_insert_text(mut self, text: impl Into<String>) -> Self {
+        self.insert_text = Some(text.into());
+        self.insert_text_format = InsertTextFormat::PlainText;
+        self
+    }
+
+    /// Insert `snippet` (in LSP snippet syntax) instead of the label.
+    pub fn with_snippet(mut self, snippet: impl Into<String>) -> Self {
+        self.insert_text = Some(snippet.into());
+        self.insert_text_format = InsertTextFormat::Snippet;
+        self
+    }
+
+    pub fn with_sort_text(mut self, sort_text: impl Into<String>) -> Self {
+        self.sort_text = Some(sort_text.into());
+        self
+    }
+
+    pub fn with_filter_text(mut self, filter_text: impl Into<String>) -> Self {
+        self.filter_text = Some(filter_text.into());
+        self
+    }
+
+    /// Apply `new_text` over `span` alongside the completion.
+    pub fn with_additional_edit(mut self, span: Span, new_text: impl Into<String>) -> Self {
+        self.additional_edits.push((span, new_text.into()));
+        self
+    }
+
+    pub fn with_deprecated(mut self, deprecated: bool) -> Self {
+        self.deprecated = deprecated;
+        self
+    }
+}
+
+/// Escape `text` so it is inserted literally inside an LSP snippet.
+fn escape_snippet_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '$' | '}') {
+            out.push('\\');
         }
+        out.push(ch);
     }
+    out
 }
 
 // -----------------------------------------------------------------------------
@@ -150,12 +664,14 @@ impl CompletionItem {
 
 pub use nova_ids::{ClassId, ProjectId};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
 pub struct TypeVarId(pub u32);
 
 // === Type representation (core) =============================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum PrimitiveType {
     Boolean,
     Byte,
@@ -168,6 +684,35 @@ pub enum PrimitiveType {
 }
 
 impl PrimitiveType {
+    /// Parse a primitive type keyword (`"int"`, `"boolean"`, ...).
+    pub fn from_keyword(s: &str) -> Option<PrimitiveType> {
+        Some(match s {
+            "boolean" => PrimitiveType::Boolean,
+            "byte" => PrimitiveType::Byte,
+            "short" => PrimitiveType::Short,
+            "char" => PrimitiveType::Char,
+            "int" => PrimitiveType::Int,
+            "long" => PrimitiveType::Long,
+            "float" => PrimitiveType::Float,
+            "double" => PrimitiveType::Double,
+            _ => return None,
+        })
+    }
+
+    /// The Java keyword naming this type.
+    pub fn keyword(self) -> &'static str {
+        match self {
+            PrimitiveType::Boolean => "boolean",
+            PrimitiveType::Byte => "byte",
+            PrimitiveType::Short => "short",
+            PrimitiveType::Char => "char",
+            PrimitiveType::Int => "int",
+            PrimitiveType::Long => "long",
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+        }
+    }
+
     pub fn is_numeric(self) -> bool {
         matches!(
             self,
@@ -182,25 +727,46 @@ impl PrimitiveType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct ClassType {
     pub def: ClassId,
     pub args: Vec<Type>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum WildcardBound {
     Unbounded,
     Extends(Box<Type>),
     Super(Box<Type>),
 }
 
+impl WildcardBound {
+    /// The explicit upper bound (`? extends T`), if any.
+    pub fn upper(&self) -> Option<&Type> {
+        match self {
+            WildcardBound::Extends(upper) => Some(upper),
+            WildcardBound::Unbounded | WildcardBound::Super(_) => None,
+        }
+    }
+
+    /// The explicit lower bound (`? super T`), if any.
+    pub fn lower(&self) -> Option<&Type> {
+        match self {
+            WildcardBound::Super(lower) => Some(lower),
+            WildcardBound::Unbounded | WildcardBound::Extends(_) => None,
+        }
+    }
+}
+
 /// Java type representation.
 ///
 /// The variants are modelled after `docs/06-semantic-analysis.md` with a few
 /// Nova-specific additions (`Named`, `VirtualInner`) that are used by framework
 /// analyzers before the full classpath/JDK model is wired in.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// It serializes in serde's default externally tagged form (`"Void"`, `{"TypeVar": 3}`), with
+/// class and type variable ids as bare integers; [`Type::to_portable`] adds their names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum Type {
     /// The special `void` type.
     Void,
@@ -254,6 +820,14 @@ impl Type {
         Type::Primitive(PrimitiveType::Int)
     }
 
+    /// Parse a primitive type keyword or `void`.
+    pub fn from_keyword(s: &str) -> Option<Type> {
+        match s {
+            "void" => Some(Type::Void),
+            _ => PrimitiveType::from_keyword(s).map(Type::Primitive),
+        }
+    }
+
     pub fn is_primitive_boolean(&self) -> bool {
         matches!(self, Type::Primitive(PrimitiveType::Boolean))
     }
@@ -274,6 +848,34 @@ impl Type {
         matches!(self, Type::Unknown | Type::Error)
     }
 
+    /// Returns `true` if this type can be written in Java source.
+    ///
+    /// Intersections (outside of type parameter bounds) are inferred-only types, e.g. the element
+    /// type of `lub(String[], Integer[])`; they must be projected before being shown as a `var`
+    /// type or inserted into source.
+    pub fn is_denotable(&self) -> bool {
+        match self {
+            Type::Intersection(_) => false,
+            Type::Array(elem) => elem.is_denotable(),
+            Type::Class(ClassType { args, .. }) => args.iter().all(Type::is_denotable),
+            Type::Wildcard(WildcardBound::Extends(bound) | WildcardBound::Super(bound)) => {
+                bound.is_denotable()
+            }
+            _ => true,
+        }
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self, Type::Wildcard(_))
+    }
+
+    pub fn wildcard_bound(&self) -> Option<&WildcardBound> {
+        match self {
+            Type::Wildcard(bound) => Some(bound),
+            _ => None,
+        }
+    }
+
     pub fn array_element(&self) -> Option<&Type> {
         match self {
             Type::Array(elem) => Some(elem.as_ref()),
@@ -282,23 +884,66 @@ impl Type {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Parameter {
-    pub name: String,
-    pub ty: Type,
+/// Nullability of a type use, as declared by a type annotation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Nullability {
+    /// No nullability annotation.
+    #[default]
+    Unknown,
+    Nullable,
+    NonNull,
 }
 
-impl Parameter {
-    pub fn new(name: impl Into<String>, ty: Type) -> Self {
-        Self {
-            name: name.into(),
-            ty,
+impl Nullability {
+    /// The annotation rendered for this nullability (`@Nullable`), if any.
+    pub fn annotation(self) -> Option<&'static str> {
+        match self {
+            Nullability::Unknown => None,
+            Nullability::Nullable => Some("@Nullable"),
+            Nullability::NonNull => Some("@NonNull"),
         }
     }
 }
-// --- External type stubs -----------------------------------------------------
-//
-// Nova's early semantic layers need a way to reason about types that come from
+
+/// A [`Type`] with the nullability annotations of its use.
+///
+/// For an array type, `nullability` annotates the (outermost) array itself and
+/// `element_nullability` its innermost element type, as in `@Nullable String @NonNull []`.
+/// `element_nullability` is ignored for other types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnnotatedType {
+    pub ty: Type,
+    pub nullability: Nullability,
+    pub element_nullability: Nullability,
+}
+
+impl AnnotatedType {
+    pub fn new(ty: Type, nullability: Nullability) -> Self {
+        Self {
+            ty,
+            nullability,
+            element_nullability: Nullability::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: String,
+    pub ty: Type,
+}
+
+impl Parameter {
+    pub fn new(name: impl Into<String>, ty: Type) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+        }
+    }
+}
+// --- External type stubs -----------------------------------------------------
+//
+// Nova's early semantic layers need a way to reason about types that come from
 // compiled dependencies (jars, output directories, etc). Full type-checking will
 // eventually use a richer model; these stubs are a lightweight bridge.
 
@@ -309,6 +954,7 @@ pub struct FieldStub {
     pub descriptor: String,
     pub signature: Option<String>,
     pub access_flags: u16,
+    pub deprecation: Option<Deprecation>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -318,6 +964,7 @@ pub struct MethodStub {
     pub descriptor: String,
     pub signature: Option<String>,
     pub access_flags: u16,
+    pub deprecation: Option<Deprecation>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -335,6 +982,19 @@ pub struct TypeDefStub {
     pub signature: Option<String>,
     pub fields: Vec<FieldStub>,
     pub methods: Vec<MethodStub>,
+    pub deprecation: Option<Deprecation>,
+    /// The type's own `InnerClasses` entry, when it is a member class. `None` when the type is
+    /// top level or the provider does not record nesting.
+    pub inner_class: Option<InnerClassStub>,
+}
+
+/// How a member class is nested, from its own entry in the `InnerClasses` attribute (JVMS 4.7.6).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InnerClassStub {
+    /// Binary name of the declaring class, e.g. `java.util.Map`.
+    pub outer_binary_name: String,
+    /// The `inner_class_access_flags`, which (unlike the class's own flags) include `static`.
+    pub access_flags: u16,
 }
 
 /// A source of types used by the semantic layers.
@@ -357,6 +1017,28 @@ pub trait TypeProvider {
             .collect()
     }
 
+    /// The methods among [`TypeProvider::members`].
+    fn methods(&self, binary_name: &str) -> Vec<MethodStub> {
+        self.members(binary_name)
+            .into_iter()
+            .filter_map(|member| match member {
+                MemberStub::Method(method) => Some(method),
+                MemberStub::Field(_) => None,
+            })
+            .collect()
+    }
+
+    /// The fields among [`TypeProvider::members`].
+    fn fields(&self, binary_name: &str) -> Vec<FieldStub> {
+        self.members(binary_name)
+            .into_iter()
+            .filter_map(|member| match member {
+                MemberStub::Field(field) => Some(field),
+                MemberStub::Method(_) => None,
+            })
+            .collect()
+    }
+
     fn supertypes(&self, binary_name: &str) -> Vec<String> {
         let Some(ty) = self.lookup_type(binary_name) else {
             return Vec::new();
@@ -403,6 +1085,22 @@ impl<'a> TypeProvider for ChainTypeProvider<'a> {
             .unwrap_or_default()
     }
 
+    fn methods(&self, binary_name: &str) -> Vec<MethodStub> {
+        self.providers
+            .iter()
+            .map(|p| p.methods(binary_name))
+            .find(|m| !m.is_empty())
+            .unwrap_or_default()
+    }
+
+    fn fields(&self, binary_name: &str) -> Vec<FieldStub> {
+        self.providers
+            .iter()
+            .map(|p| p.fields(binary_name))
+            .find(|f| !f.is_empty())
+            .unwrap_or_default()
+    }
+
     fn supertypes(&self, binary_name: &str) -> Vec<String> {
         self.providers
             .iter()
@@ -429,35 +1127,126 @@ impl TypeProvider for EmptyTypeProvider {
 
 // === Java type environment (nova-types) ======================================
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum ClassKind {
     Class,
     Interface,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum CallKind {
     Static,
     Instance,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeParamDef {
     pub name: String,
     pub upper_bounds: Vec<Type>,
     /// Capture conversion may introduce a lower bound (`? super T`).
     pub lower_bound: Option<Type>,
+    /// The class that declared this type parameter, if known.
+    ///
+    /// `None` for method type parameters, inference/capture variables, and type parameters
+    /// whose origin was not recorded.
+    pub declared_by: Option<ClassId>,
 }
 
-#[derive(Debug, Clone)]
+/// A type parameter bound rejected by [`TypeStore::validate_type_param_bounds`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeParamBoundError {
+    /// The type parameter is bounded by itself (`T extends T`).
+    SelfBound { param: TypeVarId },
+    /// The type parameters form a cycle through their bounds (`T extends U, U extends T`).
+    ///
+    /// `params` lists the cycle starting at the parameter being defined.
+    Cycle { params: Vec<TypeVarId> },
+}
+
+impl fmt::Display for TypeParamBoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeParamBoundError::SelfBound { param } => {
+                write!(f, "type parameter {param:?} cannot be bounded by itself")
+            }
+            TypeParamBoundError::Cycle { params } => {
+                write!(f, "cyclic type parameter bounds: {params:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TypeParamBoundError {}
+
+/// A malformed type reported by [`well_formed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeWellFormednessError {
+    /// `class` is neither raw nor given one type argument per declared type parameter.
+    WrongTypeArgCount {
+        class: ClassId,
+        expected: usize,
+        found: usize,
+    },
+    /// The type argument at `index` of `class` is a primitive type.
+    PrimitiveTypeArg { class: ClassId, index: usize },
+    /// The type argument at `index` of `class` is not within `bound`.
+    BoundNotSatisfied {
+        class: ClassId,
+        index: usize,
+        arg: Type,
+        bound: Type,
+    },
+}
+
+impl fmt::Display for TypeWellFormednessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeWellFormednessError::WrongTypeArgCount {
+                class,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{class:?} has {found} type arguments, expected {expected} (or none)"
+            ),
+            TypeWellFormednessError::PrimitiveTypeArg { class, index } => {
+                write!(f, "type argument {index} of {class:?} is a primitive type")
+            }
+            TypeWellFormednessError::BoundNotSatisfied {
+                class,
+                index,
+                arg,
+                bound,
+            } => write!(
+                f,
+                "type argument {index} of {class:?} ({arg:?}) is not within its bound {bound:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TypeWellFormednessError {}
+
+/// Why and how a declaration is deprecated: `@Deprecated(since = .., forRemoval = ..)`, or the
+/// classfile `Deprecated` attribute (which carries neither element).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct Deprecation {
+    /// The release the declaration was deprecated in, e.g. `"9"`.
+    pub since: Option<String>,
+    /// The declaration is slated for removal (`forRemoval = true`).
+    pub for_removal: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FieldDef {
     pub name: String,
     pub ty: Type,
     pub is_static: bool,
     pub is_final: bool,
+    pub deprecation: Option<Deprecation>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ConstructorDef {
     pub params: Vec<Type>,
     pub is_varargs: bool,
@@ -465,48 +1254,81 @@ pub struct ConstructorDef {
     /// inaccessible). Full accessibility rules depend on the call-site context
     /// and will be handled by higher semantic layers.
     pub is_accessible: bool,
+    pub deprecation: Option<Deprecation>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MethodDef {
     pub name: String,
     pub type_params: Vec<TypeVarId>,
     pub params: Vec<Type>,
+    /// Source parameter names, parallel to `params`.
+    ///
+    /// Empty when the names are unknown (e.g. classfiles compiled without `-parameters`).
+    pub param_names: Vec<String>,
     pub return_type: Type,
     pub is_static: bool,
     pub is_varargs: bool,
     pub is_abstract: bool,
+    /// A `private` method, accessible only within its top-level class (JLS 6.6.1).
+    ///
+    /// Checked against [`MethodCall::caller_context`] during method resolution.
+    #[serde(default)]
+    pub is_private: bool,
+    /// Set only when this declaration itself is deprecated: an override of a deprecated method
+    /// is not deprecated unless it is marked too.
+    pub deprecation: Option<Deprecation>,
 }
 
 impl MethodDef {
-    pub fn param_types_for_arity(&self, arity: usize) -> Vec<Type> {
-        if !self.is_varargs {
-            return self.params.clone();
-        }
-
-        if self.params.is_empty() {
-            return vec![];
+    /// Returns the declared name of the parameter at `idx`, if known.
+    pub fn param_name(&self, idx: usize) -> Option<&str> {
+        if self.param_names.len() != self.params.len() {
+            return None;
         }
+        self.param_names.get(idx).map(String::as_str)
+    }
 
-        let fixed = self.params.len() - 1;
-        let mut out = Vec::with_capacity(arity.max(self.params.len()));
-        out.extend(self.params[..fixed].iter().cloned());
+    /// Returns the parameter types to match against `arity` call arguments.
+    ///
+    /// For varargs methods this is the variable-arity expansion (JLS 15.12.2.4): the fixed
+    /// parameters followed by `arity - fixed` copies of the vararg element type. The result
+    /// always has exactly `arity` entries; `None` is returned when the method cannot be invoked
+    /// with `arity` arguments (fewer arguments than fixed parameters for varargs methods, or a
+    /// different count for fixed-arity methods).
+    pub fn param_types_for_arity(&self, arity: usize) -> Option<Vec<Type>> {
+        expand_params_for_arity(&self.params, self.is_varargs, arity)
+    }
+}
 
-        let vararg_ty = self.params[fixed].clone();
-        let elem_ty = match vararg_ty {
-            Type::Array(elem) => *elem,
-            other => other,
-        };
-        let extra = arity.saturating_sub(fixed);
-        for _ in 0..extra {
-            out.push(elem_ty.clone());
-        }
+/// Shared implementation of [`MethodDef::param_types_for_arity`].
+///
+/// The declared vararg type is expected to be an array (`T[]`), but some loaders record the
+/// element type directly; a non-array vararg type is treated as the element type.
+fn expand_params_for_arity(params: &[Type], is_varargs: bool, arity: usize) -> Option<Vec<Type>> {
+    if !is_varargs {
+        return (arity == params.len()).then(|| params.to_vec());
+    }
 
-        out
+    let Some((vararg_ty, fixed_params)) = params.split_last() else {
+        // Malformed varargs method without parameters: only a zero-argument call fits.
+        return (arity == 0).then(Vec::new);
+    };
+    if arity < fixed_params.len() {
+        return None;
     }
+
+    let elem_ty = match vararg_ty {
+        Type::Array(elem) => elem.as_ref(),
+        other => other,
+    };
+    let mut out = Vec::with_capacity(arity);
+    out.extend(fixed_params.iter().cloned());
+    out.extend(std::iter::repeat_n(elem_ty, arity - fixed_params.len()).cloned());
+    Some(out)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClassDef {
     pub name: String,
     pub kind: ClassKind,
@@ -516,9 +1338,220 @@ pub struct ClassDef {
     pub fields: Vec<FieldDef>,
     pub constructors: Vec<ConstructorDef>,
     pub methods: Vec<MethodDef>,
+    /// The lexically enclosing class of a nested class.
+    ///
+    /// When unset, it is derived from an `Outer$Inner` binary name (see [`outer_class`]), but the
+    /// nesting is then unknown and the class is treated as static: it has no enclosing instance.
+    pub enclosing: Option<ClassId>,
+    /// A `static` nested class has no enclosing instance, so the enclosing classes' type
+    /// parameters are not in scope.
+    pub is_static_nested: bool,
+    /// An `abstract` class. Interfaces are always abstract, whether or not this is set.
+    pub is_abstract: bool,
+    pub deprecation: Option<Deprecation>,
 }
 
-#[derive(Debug, Clone)]
+impl ClassDef {
+    /// Returns the type parameters in scope in this class: its own `type_params` followed by
+    /// those of the enclosing instances' classes (see [`ClassDef::enclosing`]), innermost first.
+    ///
+    /// Static nested classes, interfaces, and members of interfaces have no enclosing instance
+    /// (JLS 8.1.3, 9.1.1.3, 9.5), so the chain stops there.
+    pub fn all_type_params(&self, env: &dyn TypeEnv) -> Vec<TypeVarId> {
+        let mut out = self.type_params.clone();
+        for outer in enclosing_instance_classes(env, self) {
+            for &tv in &outer.type_params {
+                if !out.contains(&tv) {
+                    out.push(tv);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// The class lexically enclosing `id`: [`ClassDef::enclosing`] if set, otherwise the class
+/// named by the `Outer` part of an `Outer$Inner` binary name.
+pub fn outer_class(env: &dyn TypeEnv, id: ClassId) -> Option<ClassId> {
+    outer_class_of(env, env.class(id)?)
+}
+
+/// The classes enclosing `id`, innermost first (see [`outer_class`]).
+pub fn enclosing_chain(env: &dyn TypeEnv, id: ClassId) -> Vec<ClassId> {
+    let mut out = Vec::new();
+    let mut current = id;
+    while let Some(outer) = outer_class(env, current) {
+        if outer == id || out.contains(&outer) {
+            break;
+        }
+        out.push(outer);
+        current = outer;
+    }
+    out
+}
+
+fn outer_class_of(env: &dyn TypeEnv, class_def: &ClassDef) -> Option<ClassId> {
+    class_def.enclosing.or_else(|| {
+        let (outer_name, _) = class_def.name.rsplit_once('$')?;
+        env.lookup_class(outer_name)
+    })
+}
+
+/// The type parameters of `class_def`'s enclosing instances (outermost first) followed by its own,
+/// i.e. the parameters matching a flattened `Outer<..>.Inner<..>` argument list.
+fn flattened_type_params(env: &dyn TypeEnv, class_def: &ClassDef) -> Vec<TypeVarId> {
+    let mut out: Vec<TypeVarId> = enclosing_instance_classes(env, class_def)
+        .into_iter()
+        .rev()
+        .flat_map(|outer| outer.type_params.iter().copied())
+        .collect();
+    out.extend(class_def.type_params.iter().copied());
+    out
+}
+
+/// The classes of `class_def`'s enclosing instances, innermost first.
+fn enclosing_instance_classes<'env>(
+    env: &'env dyn TypeEnv,
+    class_def: &ClassDef,
+) -> Vec<&'env ClassDef> {
+    let mut out: Vec<&ClassDef> = Vec::new();
+    let mut current = class_def;
+    // Bounded so a malformed `enclosing` cycle cannot loop forever.
+    while has_enclosing_instance(current) && out.len() < 32 {
+        let Some(outer) = current.enclosing.and_then(|id| env.class(id)) else {
+            break;
+        };
+        if outer.kind != ClassKind::Class {
+            break;
+        }
+        out.push(outer);
+        current = outer;
+    }
+    out
+}
+
+/// Whether `class_def` is an inner class, i.e. has an enclosing instance: a non-static class
+/// with a known [`ClassDef::enclosing`] class.
+fn has_enclosing_instance(class_def: &ClassDef) -> bool {
+    class_def.kind == ClassKind::Class
+        && !class_def.is_static_nested
+        && class_def.enclosing.is_some()
+}
+
+/// Checks that every parameterized class type in `ty` is well formed (JLS 4.5).
+///
+/// Each class type must be raw (no arguments) or have one argument per declared type parameter
+/// (inner classes may also be given the flattened arguments of their enclosing instances, see
+/// [`TypeSubstitution::from_class_def_with_enclosing`]). Type arguments must be reference types
+/// within their parameter's bounds; wildcard arguments are not bound-checked. Classes missing
+/// from `env` and error types are accepted.
+pub fn well_formed(env: &dyn TypeEnv, ty: &Type) -> Result<(), TypeWellFormednessError> {
+    check_well_formed(env, ty, true)
+}
+
+fn check_well_formed(
+    env: &dyn TypeEnv,
+    ty: &Type,
+    check_bounds: bool,
+) -> Result<(), TypeWellFormednessError> {
+    match ty {
+        Type::Class(ClassType { def, args }) => {
+            for arg in args {
+                check_well_formed(env, arg, check_bounds)?;
+            }
+            let Some(class_def) = env.class(*def) else {
+                return Ok(());
+            };
+            if args.is_empty() {
+                return Ok(());
+            }
+
+            let params = if args.len() == class_def.type_params.len() {
+                class_def.type_params.clone()
+            } else {
+                flattened_type_params(env, class_def)
+            };
+            if args.len() != params.len() {
+                return Err(TypeWellFormednessError::WrongTypeArgCount {
+                    class: *def,
+                    expected: class_def.type_params.len(),
+                    found: args.len(),
+                });
+            }
+            if let Some(index) = args
+                .iter()
+                .position(|arg| matches!(arg, Type::Primitive(_)))
+            {
+                return Err(TypeWellFormednessError::PrimitiveTypeArg { class: *def, index });
+            }
+            if !check_bounds {
+                return Ok(());
+            }
+
+            // Bounds may mention other parameters of the class (`E extends Enum<E>`).
+            let subst: TypeSubstitution =
+                params.iter().copied().zip(args.iter().cloned()).collect();
+            for (index, (param, arg)) in params.iter().zip(args).enumerate() {
+                if arg.is_wildcard() || arg.is_errorish() {
+                    continue;
+                }
+                let Some(param_def) = env.type_param(*param) else {
+                    continue;
+                };
+                for bound in &param_def.upper_bounds {
+                    let bound = match subst.apply(bound) {
+                        Type::Wildcard(wildcard) => wildcard_upper_bound(env, &wildcard),
+                        bound => bound,
+                    };
+                    if !is_subtype(env, arg, &bound) {
+                        return Err(TypeWellFormednessError::BoundNotSatisfied {
+                            class: *def,
+                            index,
+                            arg: arg.clone(),
+                            bound,
+                        });
+                    }
+                }
+            }
+            Ok(())
+        }
+        Type::Array(elem) => check_well_formed(env, elem, check_bounds),
+        Type::Wildcard(WildcardBound::Extends(bound) | WildcardBound::Super(bound)) => {
+            check_well_formed(env, bound, check_bounds)
+        }
+        Type::Intersection(types) => types
+            .iter()
+            .try_for_each(|ty| check_well_formed(env, ty, check_bounds)),
+        _ => Ok(()),
+    }
+}
+
+/// Debug check for substitution: substituting well-formed reference types into a well-formed type
+/// must not produce a type with a wrong number of type arguments.
+///
+/// Only arity is checked; bounds may legitimately be violated mid-inference.
+fn debug_assert_substitution_well_formed(
+    env: &dyn TypeEnv,
+    ty: &Type,
+    subst: &TypeSubstitution,
+    out: &Type,
+) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let arity_ok = |ty: &Type| check_well_formed(env, ty, false).is_ok();
+    if arity_ok(ty)
+        && subst
+            .iter()
+            .all(|(_, arg)| arity_ok(arg) && !matches!(arg, Type::Primitive(_)))
+    {
+        if let Err(err) = check_well_formed(env, out, false) {
+            panic!("substitution produced a malformed type: {err}");
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WellKnownTypes {
     pub object: ClassId,
     pub string: ClassId,
@@ -533,6 +1566,42 @@ pub trait TypeEnv {
     fn lookup_class(&self, name: &str) -> Option<ClassId>;
     fn well_known(&self) -> &WellKnownTypes;
 
+    /// Classes that declare a method named `name`, in a deterministic order and capped at
+    /// `limit` entries.
+    ///
+    /// This is only used for best-effort IDE recovery; environments that cannot enumerate their
+    /// classes return an empty list.
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        let _ = (name, limit);
+        Vec::new()
+    }
+
+    /// Interfaces known to the environment, in a deterministic order and capped at `limit`
+    /// entries.
+    ///
+    /// Like [`TypeEnv::classes_declaring_method`], this is only used for best-effort IDE
+    /// features; environments that cannot enumerate their classes return an empty list.
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        let _ = limit;
+        Vec::new()
+    }
+
+    /// A contract registered for the method identified by `key`, in addition to the built-in
+    /// JDK table (see [`well_known_contract`]).
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        let _ = key;
+        None
+    }
+
+    /// Whether `id` has been defined or is still a placeholder reserved by
+    /// [`TypeStore::intern_class_id`].
+    ///
+    /// Environments that are not populated incrementally report every class as defined.
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        let _ = id;
+        ClassDefState::Defined
+    }
+
     /// Look up a class by a Java source name.
     ///
     /// This behaves like [`TypeEnv::lookup_class`], but also supports source-syntax nested
@@ -597,6 +1666,29 @@ pub trait ClasspathTypes {
 
 impl ClasspathTypes for () {}
 
+/// Whether a class slot in a [`TypeStore`] holds a real definition.
+///
+/// Loaders intern ids before parsing class bodies (see [`TypeStore::intern_class_id`]), so
+/// queries can observe a class whose definition is still pending. Its placeholder [`ClassDef`]
+/// has no supertypes or members, which makes negative answers (`A` is not a subtype of `B`, no
+/// method `m`) unreliable until the class is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClassDefState {
+    /// The id was reserved by [`TypeStore::intern_class_id`] and not defined yet.
+    Placeholder,
+    Defined,
+}
+
+/// What [`TypeStore::compact`] reclaimed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Type parameter slots turned into tombstones.
+    pub reclaimed_type_params: usize,
+}
+
+/// Name of the tombstone left behind by [`TypeStore::remove_type_param_range`].
+const REMOVED_TYPE_PARAM_NAME: &str = "<removed>";
+
 #[derive(Debug)]
 pub struct TypeStore {
     classes: Vec<ClassDef>,
@@ -604,6 +1696,20 @@ pub struct TypeStore {
     tombstones: HashMap<String, ClassId>,
     type_params: Vec<TypeParamDef>,
     well_known: Option<WellKnownTypes>,
+    /// Raw id of `classes[0]`; non-zero for overlay stores (see [`TypeStore::overlay_for`]).
+    class_id_offset: u32,
+    /// Raw id of `type_params[0]`; non-zero for overlay stores.
+    type_param_id_offset: u32,
+    method_contracts: HashMap<MethodContractKey, MethodContract>,
+    /// Classes interned by [`TypeStore::intern_class_id`] and not defined yet.
+    placeholders: HashSet<ClassId>,
+    /// Classes declaring a method with a given name, for
+    /// [`TypeEnv::classes_declaring_method`]. Maintained by `add_class`, `define_class` and
+    /// `remove_class`; entries are re-checked on lookup.
+    method_owners: HashMap<String, BTreeSet<ClassId>>,
+    /// Classes handed out through [`TypeStore::class_mut`] since they were last defined, whose
+    /// methods may be missing from `method_owners`.
+    unindexed_methods: BTreeSet<ClassId>,
 }
 
 impl Clone for TypeStore {
@@ -614,7 +1720,122 @@ impl Clone for TypeStore {
             tombstones: self.tombstones.clone(),
             type_params: self.type_params.clone(),
             well_known: self.well_known.clone(),
+            class_id_offset: self.class_id_offset,
+            type_param_id_offset: self.type_param_id_offset,
+            method_contracts: self.method_contracts.clone(),
+            placeholders: self.placeholders.clone(),
+            method_owners: self.method_owners.clone(),
+            unindexed_methods: self.unindexed_methods.clone(),
+        }
+    }
+}
+
+/// Serialized form of a [`TypeStore`].
+///
+/// `class_by_name` is not stored: it is rebuilt from `classes` and `tombstones` on load, so a
+/// deserialized store cannot disagree with its own class table.
+#[derive(Serialize, Deserialize)]
+struct TypeStoreRepr<'a> {
+    classes: Cow<'a, [ClassDef]>,
+    tombstones: Cow<'a, [(String, ClassId)]>,
+    type_params: Cow<'a, [TypeParamDef]>,
+    well_known: Option<Cow<'a, WellKnownTypes>>,
+    class_id_offset: u32,
+    type_param_id_offset: u32,
+    method_contracts: Cow<'a, [(MethodContractKey, MethodContract)]>,
+    placeholders: Cow<'a, [ClassId]>,
+}
+
+impl Serialize for TypeStore {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Sort the map-backed fields so equal stores serialize identically.
+        let mut tombstones: Vec<_> = self
+            .tombstones
+            .iter()
+            .map(|(name, id)| (name.clone(), *id))
+            .collect();
+        tombstones.sort_unstable_by_key(|(_, id)| *id);
+        let mut method_contracts: Vec<_> = self
+            .method_contracts
+            .iter()
+            .map(|(key, contract)| (key.clone(), *contract))
+            .collect();
+        method_contracts.sort_unstable_by(|(a, _), (b, _)| {
+            (&a.owner, &a.name, &a.erased_params).cmp(&(&b.owner, &b.name, &b.erased_params))
+        });
+        let mut placeholders: Vec<_> = self.placeholders.iter().copied().collect();
+        placeholders.sort_unstable();
+
+        TypeStoreRepr {
+            classes: Cow::Borrowed(&self.classes),
+            tombstones: Cow::Owned(tombstones),
+            type_params: Cow::Borrowed(&self.type_params),
+            well_known: self.well_known.as_ref().map(Cow::Borrowed),
+            class_id_offset: self.class_id_offset,
+            type_param_id_offset: self.type_param_id_offset,
+            method_contracts: Cow::Owned(method_contracts),
+            placeholders: Cow::Owned(placeholders),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeStore {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let repr = TypeStoreRepr::deserialize(deserializer)?;
+        let classes = repr.classes.into_owned();
+        let tombstones: HashMap<String, ClassId> =
+            repr.tombstones.into_owned().into_iter().collect();
+        let class_end = u64::from(repr.class_id_offset) + classes.len() as u64;
+        let in_range = |id: ClassId| {
+            (u64::from(repr.class_id_offset)..class_end).contains(&u64::from(id.to_raw()))
+        };
+
+        let mut class_by_name = HashMap::with_capacity(classes.len());
+        for (idx, def) in classes.iter().enumerate() {
+            let id = ClassId::from_raw(repr.class_id_offset + idx as u32);
+            if tombstones.get(&def.name) == Some(&id) {
+                continue;
+            }
+            if class_by_name.insert(def.name.clone(), id).is_some() {
+                return Err(D::Error::custom(format!(
+                    "duplicate class name {:?}",
+                    def.name
+                )));
+            }
+        }
+        for (name, id) in &tombstones {
+            if !in_range(*id) || class_by_name.contains_key(name) {
+                return Err(D::Error::custom(format!(
+                    "invalid tombstone {name:?} -> {id:?}"
+                )));
+            }
         }
+        let placeholders: HashSet<ClassId> = repr.placeholders.into_owned().into_iter().collect();
+        if let Some(id) = placeholders.iter().copied().find(|id| !in_range(*id)) {
+            return Err(D::Error::custom(format!("placeholder {id:?} out of range")));
+        }
+        let mut method_owners = HashMap::new();
+        for (idx, def) in classes.iter().enumerate() {
+            let id = ClassId::from_raw(repr.class_id_offset + idx as u32);
+            index_method_names(&mut method_owners, id, def);
+        }
+
+        Ok(Self {
+            classes,
+            class_by_name,
+            tombstones,
+            type_params: repr.type_params.into_owned(),
+            well_known: repr.well_known.map(Cow::into_owned),
+            class_id_offset: repr.class_id_offset,
+            type_param_id_offset: repr.type_param_id_offset,
+            method_contracts: repr.method_contracts.into_owned().into_iter().collect(),
+            placeholders,
+            method_owners,
+            unindexed_methods: BTreeSet::new(),
+        })
     }
 }
 
@@ -626,6 +1847,12 @@ impl Default for TypeStore {
             tombstones: HashMap::new(),
             type_params: Vec::new(),
             well_known: None,
+            class_id_offset: 0,
+            type_param_id_offset: 0,
+            method_contracts: HashMap::new(),
+            placeholders: HashSet::new(),
+            method_owners: HashMap::new(),
+            unindexed_methods: BTreeSet::new(),
         };
 
         // `nova-types` algorithms assume a baseline set of well-known JDK types
@@ -654,36 +1881,50 @@ impl Default for TypeStore {
                     params: vec![],
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 }],
                 methods: vec![
                     MethodDef {
                         name: "toString".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: string_ty.clone(),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "equals".to_string(),
                         type_params: vec![],
                         params: vec![object_ty.clone()],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Boolean),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "hashCode".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -701,14 +1942,20 @@ impl Default for TypeStore {
                         params: vec![],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                     ConstructorDef {
                         params: vec![string_ty.clone()],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                 ],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -722,6 +1969,10 @@ impl Default for TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -735,6 +1986,10 @@ impl Default for TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -748,6 +2003,10 @@ impl Default for TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -805,6 +2064,9 @@ pub const MINIMAL_JDK_BINARY_NAMES: &[&str] = &[
     "java.util.List",
     "java.util.Collections",
     "java.util.ArrayList",
+    "java.util.Map",
+    "java.util.Objects",
+    "java.util.Optional",
     // java.util.function
     "java.util.function.Function",
     "java.util.function.Supplier",
@@ -843,6 +2105,12 @@ impl TypeStore {
             .saturating_add((self.tombstones.capacity() * size_of::<(String, ClassId)>()) as u64);
         bytes = bytes.saturating_add(self.tombstones.capacity() as u64);
 
+        // Backing storage for `method_owners` + control bytes.
+        bytes = bytes.saturating_add(
+            (self.method_owners.capacity() * size_of::<(String, BTreeSet<ClassId>)>()) as u64,
+        );
+        bytes = bytes.saturating_add(self.method_owners.capacity() as u64);
+
         // Backing storage for `type_params`.
         bytes =
             bytes.saturating_add((self.type_params.capacity() * size_of::<TypeParamDef>()) as u64);
@@ -852,6 +2120,8 @@ impl TypeStore {
         bytes =
             bytes.saturating_add((self.class_by_name.len() as u64).saturating_mul(AVG_NAME_BYTES));
         bytes = bytes.saturating_add((self.tombstones.len() as u64).saturating_mul(AVG_NAME_BYTES));
+        bytes =
+            bytes.saturating_add((self.method_owners.len() as u64).saturating_mul(AVG_NAME_BYTES));
         bytes = bytes.saturating_add((self.type_params.len() as u64).saturating_mul(32));
 
         bytes
@@ -915,36 +2185,50 @@ impl TypeStore {
                     params: vec![],
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 }],
                 methods: vec![
                     MethodDef {
                         name: "toString".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: string_ty.clone(),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "equals".to_string(),
                         type_params: vec![],
                         params: vec![object_ty.clone()],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Boolean),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "hashCode".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -960,8 +2244,13 @@ impl TypeStore {
                     params: vec![],
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 }],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -977,8 +2266,13 @@ impl TypeStore {
                     params: vec![],
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 }],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -994,8 +2288,13 @@ impl TypeStore {
                     params: vec![],
                     is_varargs: false,
                     is_accessible: true,
+                    deprecation: None,
                 }],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -1013,14 +2312,20 @@ impl TypeStore {
                         params: vec![],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                     ConstructorDef {
                         params: vec![string_ty.clone()],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                 ],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         if let Some(string_def) = store.class_mut(string) {
@@ -1030,19 +2335,25 @@ impl TypeStore {
                     name: "length".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::Primitive(PrimitiveType::Int),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "substring".to_string(),
                     type_params: vec![],
                     params: vec![Type::Primitive(PrimitiveType::Int)],
+                    param_names: vec![],
                     return_type: string_ty.clone(),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "substring".to_string(),
@@ -1051,46 +2362,61 @@ impl TypeStore {
                         Type::Primitive(PrimitiveType::Int),
                         Type::Primitive(PrimitiveType::Int),
                     ],
+                    param_names: vec![],
                     return_type: string_ty.clone(),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "charAt".to_string(),
                     type_params: vec![],
                     params: vec![Type::Primitive(PrimitiveType::Int)],
+                    param_names: vec![],
                     return_type: Type::Primitive(PrimitiveType::Char),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "trim".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: string_ty.clone(),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "isEmpty".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::Primitive(PrimitiveType::Boolean),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "valueOf".to_string(),
                     type_params: vec![],
                     params: vec![Type::Primitive(PrimitiveType::Int)],
+                    param_names: vec![],
                     return_type: string_ty,
                     is_static: true,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
             ];
         }
@@ -1109,6 +2435,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: true,
+                deprecation: None,
             },
         );
 
@@ -1129,12 +2459,14 @@ impl TypeStore {
                         ty: Type::Primitive(PrimitiveType::Double),
                         is_static: true,
                         is_final: true,
+                        deprecation: None,
                     },
                     FieldDef {
                         name: "E".to_string(),
                         ty: Type::Primitive(PrimitiveType::Double),
                         is_static: true,
                         is_final: true,
+                        deprecation: None,
                     },
                 ],
                 constructors: vec![],
@@ -1146,10 +2478,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Int),
                             Type::Primitive(PrimitiveType::Int),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "max".to_string(),
@@ -1158,10 +2493,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Long),
                             Type::Primitive(PrimitiveType::Long),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Long),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "max".to_string(),
@@ -1170,10 +2508,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Float),
                             Type::Primitive(PrimitiveType::Float),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Float),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "max".to_string(),
@@ -1182,10 +2523,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Double),
                             Type::Primitive(PrimitiveType::Double),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Double),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "min".to_string(),
@@ -1194,10 +2538,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Int),
                             Type::Primitive(PrimitiveType::Int),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "min".to_string(),
@@ -1206,10 +2553,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Long),
                             Type::Primitive(PrimitiveType::Long),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Long),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "min".to_string(),
@@ -1218,10 +2568,13 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Float),
                             Type::Primitive(PrimitiveType::Float),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Float),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "min".to_string(),
@@ -1230,12 +2583,19 @@ impl TypeStore {
                             Type::Primitive(PrimitiveType::Double),
                             Type::Primitive(PrimitiveType::Double),
                         ],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Double),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1253,6 +2613,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1270,6 +2634,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1287,6 +2655,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1304,6 +2676,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -1317,6 +2693,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         let long = store
@@ -1333,6 +2713,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         let float = store
@@ -1349,6 +2733,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         let double = store
@@ -1365,6 +2753,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -1378,6 +2770,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         store.define_class(
@@ -1391,6 +2787,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1414,30 +2814,43 @@ impl TypeStore {
                         name: "name".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: string_ty.clone(),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "ordinal".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "toString".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: string_ty.clone(),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: true,
+                deprecation: None,
             },
         );
 
@@ -1460,30 +2873,43 @@ impl TypeStore {
                         name: "equals".to_string(),
                         type_params: vec![],
                         params: vec![object_ty.clone()],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Boolean),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "hashCode".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Int),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "toString".to_string(),
                         type_params: vec![],
                         params: vec![],
+                        param_names: vec![],
                         return_type: string_ty.clone(),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: true,
+                deprecation: None,
             },
         );
 
@@ -1505,11 +2931,18 @@ impl TypeStore {
                     name: "run".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1532,6 +2965,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1554,21 +2991,31 @@ impl TypeStore {
                         name: "println".to_string(),
                         type_params: vec![],
                         params: vec![Type::class(string, vec![])],
+                        param_names: vec![],
                         return_type: Type::Void,
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "println".to_string(),
                         type_params: vec![],
                         params: vec![Type::Primitive(PrimitiveType::Int)],
+                        param_names: vec![],
                         return_type: Type::Void,
                         is_static: false,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1589,9 +3036,14 @@ impl TypeStore {
                     ty: Type::class(print_stream, vec![]),
                     is_static: true,
                     is_final: true,
+                    deprecation: None,
                 }],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1617,30 +3069,43 @@ impl TypeStore {
                         name: "get".to_string(),
                         type_params: vec![],
                         params: vec![Type::Primitive(PrimitiveType::Int)],
+                        param_names: vec![],
                         return_type: Type::TypeVar(list_e),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "add".to_string(),
                         type_params: vec![],
                         params: vec![Type::TypeVar(list_e)],
+                        param_names: vec![],
                         return_type: Type::Primitive(PrimitiveType::Boolean),
                         is_static: false,
                         is_varargs: false,
                         is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "of".to_string(),
                         type_params: vec![list_of_e],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::class(list, vec![Type::TypeVar(list_of_e)]),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1669,21 +3134,31 @@ impl TypeStore {
                         name: "emptyList".to_string(),
                         type_params: vec![collections_t],
                         params: vec![],
+                        param_names: vec![],
                         return_type: Type::class(list, vec![Type::TypeVar(collections_t)]),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                     MethodDef {
                         name: "singletonList".to_string(),
                         type_params: vec![collections_u],
                         params: vec![Type::TypeVar(collections_u)],
+                        param_names: vec![],
                         return_type: Type::class(list, vec![Type::TypeVar(collections_u)]),
                         is_static: true,
                         is_varargs: false,
                         is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
                     },
                 ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1707,14 +3182,20 @@ impl TypeStore {
                         params: vec![],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                     ConstructorDef {
                         params: vec![Type::Primitive(PrimitiveType::Int)],
                         is_varargs: false,
                         is_accessible: true,
+                        deprecation: None,
                     },
                 ],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1738,11 +3219,18 @@ impl TypeStore {
                     name: "apply".to_string(),
                     type_params: vec![],
                     params: vec![Type::TypeVar(function_t)],
+                    param_names: vec![],
                     return_type: Type::TypeVar(function_r),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1765,11 +3253,18 @@ impl TypeStore {
                     name: "get".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::TypeVar(supplier_t),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1792,11 +3287,18 @@ impl TypeStore {
                     name: "accept".to_string(),
                     type_params: vec![],
                     params: vec![Type::TypeVar(consumer_t)],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1819,11 +3321,18 @@ impl TypeStore {
                     name: "test".to_string(),
                     type_params: vec![],
                     params: vec![Type::TypeVar(predicate_t)],
+                    param_names: vec![],
                     return_type: Type::Primitive(PrimitiveType::Boolean),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1843,6 +3352,10 @@ impl TypeStore {
                 fields: vec![],
                 constructors: vec![],
                 methods: vec![],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
         if let Some(object_def) = store.class_mut(object) {
@@ -1850,13 +3363,236 @@ impl TypeStore {
                 name: "getClass".to_string(),
                 type_params: vec![],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::class(class, vec![Type::Wildcard(WildcardBound::Unbounded)]),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             });
         }
 
+        // java.util.Map<K, V>
+        let map_k = store.add_type_param("K", vec![Type::class(object, vec![])]);
+        let map_v = store.add_type_param("V", vec![Type::class(object, vec![])]);
+        let map = store
+            .lookup_class("java.util.Map")
+            .expect("minimal JDK must contain java.util.Map");
+        store.define_class(
+            map,
+            ClassDef {
+                name: "java.util.Map".to_string(),
+                kind: ClassKind::Interface,
+                type_params: vec![map_k, map_v],
+                super_class: Some(Type::class(object, vec![])),
+                interfaces: vec![],
+                fields: vec![],
+                constructors: vec![],
+                methods: vec![
+                    MethodDef {
+                        name: "get".to_string(),
+                        type_params: vec![],
+                        params: vec![Type::class(object, vec![])],
+                        param_names: vec!["key".to_string()],
+                        return_type: Type::TypeVar(map_v),
+                        is_static: false,
+                        is_varargs: false,
+                        is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
+                    },
+                    MethodDef {
+                        name: "getOrDefault".to_string(),
+                        type_params: vec![],
+                        params: vec![Type::class(object, vec![]), Type::TypeVar(map_v)],
+                        param_names: vec!["key".to_string(), "defaultValue".to_string()],
+                        return_type: Type::TypeVar(map_v),
+                        is_static: false,
+                        is_varargs: false,
+                        is_abstract: false,
+                        is_private: false,
+                        deprecation: None,
+                    },
+                    MethodDef {
+                        name: "put".to_string(),
+                        type_params: vec![],
+                        params: vec![Type::TypeVar(map_k), Type::TypeVar(map_v)],
+                        param_names: vec!["key".to_string(), "value".to_string()],
+                        return_type: Type::TypeVar(map_v),
+                        is_static: false,
+                        is_varargs: false,
+                        is_abstract: true,
+                        is_private: false,
+                        deprecation: None,
+                    },
+                ],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
+            },
+        );
+
+        // java.util.Objects
+        //
+        // Each `requireNonNull` overload declares its own `<T>`.
+        let require_non_null_t: Vec<TypeVarId> = (0..3)
+            .map(|_| store.add_type_param("T", vec![Type::class(object, vec![])]))
+            .collect();
+        let objects = store
+            .lookup_class("java.util.Objects")
+            .expect("minimal JDK must contain java.util.Objects");
+        let require_non_null = |t: TypeVarId, extra: Option<(Type, &str)>| {
+            let mut params = vec![Type::TypeVar(t)];
+            let mut param_names = vec!["obj".to_string()];
+            if let Some((ty, name)) = extra {
+                params.push(ty);
+                param_names.push(name.to_string());
+            }
+            MethodDef {
+                name: "requireNonNull".to_string(),
+                type_params: vec![t],
+                params,
+                param_names,
+                return_type: Type::TypeVar(t),
+                is_static: true,
+                is_varargs: false,
+                is_abstract: false,
+                is_private: false,
+                deprecation: None,
+            }
+        };
+        let null_test = |name: &str| MethodDef {
+            name: name.to_string(),
+            type_params: vec![],
+            params: vec![Type::class(object, vec![])],
+            param_names: vec!["obj".to_string()],
+            return_type: Type::Primitive(PrimitiveType::Boolean),
+            is_static: true,
+            is_varargs: false,
+            is_abstract: false,
+            is_private: false,
+            deprecation: None,
+        };
+        let objects_methods = vec![
+            require_non_null(require_non_null_t[0], None),
+            require_non_null(require_non_null_t[1], Some((string_ty.clone(), "message"))),
+            require_non_null(
+                require_non_null_t[2],
+                Some((
+                    Type::class(supplier, vec![string_ty.clone()]),
+                    "messageSupplier",
+                )),
+            ),
+            null_test("isNull"),
+            null_test("nonNull"),
+        ];
+        store.define_class(
+            objects,
+            ClassDef {
+                name: "java.util.Objects".to_string(),
+                kind: ClassKind::Class,
+                type_params: vec![],
+                super_class: Some(Type::class(object, vec![])),
+                interfaces: vec![],
+                fields: vec![],
+                constructors: vec![],
+                methods: objects_methods,
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
+            },
+        );
+
+        // java.util.Optional<T>
+        let optional_t = store.add_type_param("T", vec![Type::class(object, vec![])]);
+        let optional_factory_t: Vec<TypeVarId> = (0..3)
+            .map(|_| store.add_type_param("T", vec![Type::class(object, vec![])]))
+            .collect();
+        let optional = store
+            .lookup_class("java.util.Optional")
+            .expect("minimal JDK must contain java.util.Optional");
+        let optional_factory = |name: &str, t: TypeVarId, takes_value: bool| MethodDef {
+            name: name.to_string(),
+            type_params: vec![t],
+            params: if takes_value {
+                vec![Type::TypeVar(t)]
+            } else {
+                vec![]
+            },
+            param_names: if takes_value {
+                vec!["value".to_string()]
+            } else {
+                vec![]
+            },
+            return_type: Type::class(optional, vec![Type::TypeVar(t)]),
+            is_static: true,
+            is_varargs: false,
+            is_abstract: false,
+            is_private: false,
+            deprecation: None,
+        };
+        let optional_methods = vec![
+            optional_factory("empty", optional_factory_t[0], false),
+            optional_factory("of", optional_factory_t[1], true),
+            optional_factory("ofNullable", optional_factory_t[2], true),
+            MethodDef {
+                name: "get".to_string(),
+                type_params: vec![],
+                params: vec![],
+                param_names: vec![],
+                return_type: Type::TypeVar(optional_t),
+                is_static: false,
+                is_varargs: false,
+                is_abstract: false,
+                is_private: false,
+                deprecation: None,
+            },
+            MethodDef {
+                name: "isPresent".to_string(),
+                type_params: vec![],
+                params: vec![],
+                param_names: vec![],
+                return_type: Type::Primitive(PrimitiveType::Boolean),
+                is_static: false,
+                is_varargs: false,
+                is_abstract: false,
+                is_private: false,
+                deprecation: None,
+            },
+            MethodDef {
+                name: "orElse".to_string(),
+                type_params: vec![],
+                params: vec![Type::TypeVar(optional_t)],
+                param_names: vec!["other".to_string()],
+                return_type: Type::TypeVar(optional_t),
+                is_static: false,
+                is_varargs: false,
+                is_abstract: false,
+                is_private: false,
+                deprecation: None,
+            },
+        ];
+        store.define_class(
+            optional,
+            ClassDef {
+                name: "java.util.Optional".to_string(),
+                kind: ClassKind::Class,
+                type_params: vec![optional_t],
+                super_class: Some(Type::class(object, vec![])),
+                interfaces: vec![],
+                fields: vec![],
+                constructors: vec![],
+                methods: optional_methods,
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
+            },
+        );
+
         // java.lang.annotation.Annotation
         store.define_class(
             annotation,
@@ -1872,11 +3608,18 @@ impl TypeStore {
                     name: "annotationType".to_string(),
                     type_params: vec![],
                     params: vec![],
+                    param_names: vec![],
                     return_type: Type::class(class, vec![Type::Wildcard(WildcardBound::Unbounded)]),
                     is_static: false,
                     is_varargs: false,
                     is_abstract: true,
+                    is_private: false,
+                    deprecation: None,
                 }],
+                enclosing: None,
+                is_static_nested: false,
+                is_abstract: false,
+                deprecation: None,
             },
         );
 
@@ -1904,7 +3647,60 @@ impl TypeStore {
     /// `TypeVarId`s are allocated densely starting at zero, so this can be used to
     /// predict the next `TypeVarId` before allocating a batch of parameters.
     pub fn type_param_count(&self) -> usize {
-        self.type_params.len()
+        self.type_param_id_offset as usize + self.type_params.len()
+    }
+
+    /// Create an empty store whose ids start after every id allocated in `base`.
+    ///
+    /// The result holds no classes of its own (not even the well-known JDK types, which are
+    /// shared with `base`), so class definitions added to it can reference `base` classes by
+    /// their existing ids. Combine both stores with [`OverlayTypeEnv`] to query them as one
+    /// environment.
+    ///
+    /// Ids allocated in `base` after this call will collide with overlay ids; create a fresh
+    /// overlay whenever the base store changes.
+    /// [`TypeStore::merge`] folds the overlay into `base` once its classes become permanent.
+    pub fn overlay_for(base: &TypeStore) -> Self {
+        Self {
+            classes: Vec::new(),
+            class_by_name: HashMap::new(),
+            tombstones: HashMap::new(),
+            type_params: Vec::new(),
+            well_known: base.well_known.clone(),
+            class_id_offset: base.class_id_offset + base.classes.len() as u32,
+            type_param_id_offset: base.type_param_count() as u32,
+            method_contracts: HashMap::new(),
+            placeholders: HashSet::new(),
+            method_owners: HashMap::new(),
+            unindexed_methods: BTreeSet::new(),
+        }
+    }
+
+    /// Whether `id` exists and has been defined (see [`ClassDefState`]).
+    ///
+    /// Classes reserved by [`TypeStore::intern_class_id`] stay undefined until
+    /// [`TypeStore::define_class`] or [`TypeStore::upsert_class`] fills them in.
+    pub fn is_defined(&self, id: ClassId) -> bool {
+        self.class(id).is_some() && !self.placeholders.contains(&id)
+    }
+
+    /// Register a contract for the method identified by `key` (e.g. a framework's
+    /// `Assert.notNull`), overriding any built-in contract for it.
+    ///
+    /// See [`well_known_contract`] for how contracts are looked up.
+    pub fn register_method_contract(&mut self, key: MethodContractKey, contract: MethodContract) {
+        self.method_contracts.insert(key, contract);
+    }
+
+    fn class_index(&self, id: ClassId) -> Option<usize> {
+        id.to_raw()
+            .checked_sub(self.class_id_offset)
+            .map(|idx| idx as usize)
+    }
+
+    fn type_param_index(&self, id: TypeVarId) -> Option<usize> {
+        id.0.checked_sub(self.type_param_id_offset)
+            .map(|idx| idx as usize)
     }
 
     pub fn add_type_param(
@@ -1912,11 +3708,12 @@ impl TypeStore {
         name: impl Into<String>,
         upper_bounds: Vec<Type>,
     ) -> TypeVarId {
-        let id = TypeVarId(self.type_params.len() as u32);
+        let id = TypeVarId(self.type_param_count() as u32);
         self.type_params.push(TypeParamDef {
             name: name.into(),
             upper_bounds,
             lower_bound: None,
+            declared_by: None,
         });
         id
     }
@@ -1933,8 +3730,8 @@ impl TypeStore {
     /// originally associated with `id`.
     pub fn define_type_param(&mut self, id: TypeVarId, def: TypeParamDef) {
         let slot = self
-            .type_params
-            .get_mut(id.0 as usize)
+            .type_param_index(id)
+            .and_then(|idx| self.type_params.get_mut(idx))
             .unwrap_or_else(|| panic!("define_type_param: invalid TypeVarId {:?}", id));
         let expected_name = slot.name.clone();
 
@@ -1949,6 +3746,238 @@ impl TypeStore {
         *slot = def;
     }
 
+    /// Append every type parameter defined in `from` to this store, and return the offset added
+    /// to their ids.
+    ///
+    /// `from`'s parameter `TypeVarId(n)` becomes `TypeVarId(n + offset)` here; its bounds are
+    /// rebased the same way, so apply [`rebase_type_vars`] with the returned offset to any type
+    /// taken from `from` before using it with this store. `declared_by` is carried over as-is:
+    /// class ids are not remapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from`'s ids start after this store's next id (e.g. `from` is an overlay of a
+    /// larger store), since no non-negative offset could map them.
+    pub fn import_type_params(&mut self, from: &TypeStore) -> u32 {
+        let offset = (self.type_param_count() as u32)
+            .checked_sub(from.type_param_id_offset)
+            .unwrap_or_else(|| {
+                panic!(
+                    "import_type_params: source ids start at {}, past this store's next id {}",
+                    from.type_param_id_offset,
+                    self.type_param_count()
+                )
+            });
+        self.type_params.extend(from.type_params.iter().map(|def| {
+            TypeParamDef {
+                name: def.name.clone(),
+                upper_bounds: def
+                    .upper_bounds
+                    .iter()
+                    .map(|bound| rebase_type_vars(bound, offset))
+                    .collect(),
+                lower_bound: def
+                    .lower_bound
+                    .as_ref()
+                    .map(|bound| rebase_type_vars(bound, offset)),
+                declared_by: def.declared_by,
+            }
+        }));
+        offset
+    }
+
+    /// Import every class, type parameter and method contract of `other` into this store, e.g.
+    /// to make an overlay created with [`TypeStore::overlay_for`] permanent.
+    ///
+    /// Type parameters are appended as by [`TypeStore::import_type_params`] (which panics under
+    /// the same conditions). Each of `other`'s classes takes the id of the class with the same
+    /// binary name in this store, or a new id, and every [`ClassId`] in `other`'s types,
+    /// definitions and type parameters is remapped accordingly. Ids below `other`'s own range
+    /// (the base classes of an overlay) are kept as-is.
+    ///
+    /// When both stores define a class, `other`'s definition wins, as with
+    /// [`TypeStore::upsert_class`]. Classes that `other` only interned
+    /// ([`ClassDefState::Placeholder`]) or has removed leave this store's definition untouched.
+    pub fn merge(&mut self, other: TypeStore) {
+        let defined: Vec<bool> = other
+            .iter_classes()
+            .map(|(id, def)| {
+                !other.placeholders.contains(&id) && other.class_by_name.get(&def.name) == Some(&id)
+            })
+            .collect();
+        let ids: Vec<ClassId> = other
+            .classes
+            .iter()
+            .map(|def| self.intern_class_id(&def.name))
+            .collect();
+        let remap = |id: ClassId| {
+            other
+                .class_index(id)
+                .and_then(|idx| ids.get(idx).copied())
+                .unwrap_or(id)
+        };
+
+        let first_imported = self.type_params.len();
+        let type_param_offset = self.import_type_params(&other);
+        for def in &mut self.type_params[first_imported..] {
+            for bound in &mut def.upper_bounds {
+                *bound = remap_class_ids(bound, &remap);
+            }
+            if let Some(bound) = &mut def.lower_bound {
+                *bound = remap_class_ids(bound, &remap);
+            }
+            def.declared_by = def.declared_by.map(remap);
+        }
+
+        let import = |ty: &Type| rebase_type_vars(&remap_class_ids(ty, &remap), type_param_offset);
+        let rebase = |id: TypeVarId| TypeVarId(id.0 + type_param_offset);
+        for ((def, id), defined) in other.classes.iter().zip(&ids).zip(defined) {
+            if !defined {
+                continue;
+            }
+            let def = ClassDef {
+                name: def.name.clone(),
+                kind: def.kind,
+                type_params: def.type_params.iter().copied().map(rebase).collect(),
+                super_class: def.super_class.as_ref().map(import),
+                interfaces: def.interfaces.iter().map(import).collect(),
+                fields: def
+                    .fields
+                    .iter()
+                    .map(|field| FieldDef {
+                        ty: import(&field.ty),
+                        ..field.clone()
+                    })
+                    .collect(),
+                constructors: def
+                    .constructors
+                    .iter()
+                    .map(|ctor| ConstructorDef {
+                        params: ctor.params.iter().map(import).collect(),
+                        ..ctor.clone()
+                    })
+                    .collect(),
+                methods: def
+                    .methods
+                    .iter()
+                    .map(|method| MethodDef {
+                        type_params: method.type_params.iter().copied().map(rebase).collect(),
+                        params: method.params.iter().map(import).collect(),
+                        return_type: import(&method.return_type),
+                        ..method.clone()
+                    })
+                    .collect(),
+                enclosing: def.enclosing.map(remap),
+                is_static_nested: def.is_static_nested,
+                is_abstract: def.is_abstract,
+                deprecation: def.deprecation.clone(),
+            };
+            self.define_class(*id, def);
+        }
+
+        self.method_contracts.extend(other.method_contracts);
+    }
+
+    /// Remove the type parameters with ids in `from..to` (half-open).
+    ///
+    /// This reclaims the bounds of inference temporaries (e.g. capture variables) once a caller
+    /// is done with them, in a stack-like fashion:
+    ///
+    /// ```ignore
+    /// let mark = TypeVarId(store.type_param_count() as u32);
+    /// // ... allocate temporaries ...
+    /// store.remove_type_param_range(mark, TypeVarId(store.type_param_count() as u32));
+    /// ```
+    ///
+    /// Removed slots become permanent tombstones: [`TypeEnv::type_param`] returns `None` for
+    /// them and their ids are never handed out again, so [`TypeStore::type_param_count`] does
+    /// not decrease. Ids outside the store are ignored.
+    pub fn remove_type_param_range(&mut self, from: TypeVarId, to: TypeVarId) {
+        let start = self.type_param_index(from).unwrap_or(0);
+        let end = self
+            .type_param_index(to)
+            .unwrap_or(0)
+            .min(self.type_params.len());
+        for slot in self.type_params.iter_mut().take(end).skip(start) {
+            *slot = TypeParamDef {
+                name: REMOVED_TYPE_PARAM_NAME.to_string(),
+                upper_bounds: Vec::new(),
+                lower_bound: None,
+                declared_by: None,
+            };
+        }
+    }
+
+    /// Like [`TypeStore::define_type_param`], but rejects bounds that would make the type
+    /// parameter cyclic (see [`TypeStore::validate_type_param_bounds`]).
+    ///
+    /// The store is left unchanged when validation fails.
+    pub fn try_define_type_param(
+        &mut self,
+        id: TypeVarId,
+        def: TypeParamDef,
+    ) -> Result<(), TypeParamBoundError> {
+        self.validate_type_param_bounds(id, &def)?;
+        self.define_type_param(id, def);
+        Ok(())
+    }
+
+    /// Check that defining `id` as `def` does not introduce a cyclic bound.
+    ///
+    /// Only bounds that are *bare* type variables participate in cycles: `T extends T` and
+    /// `T extends U, U extends T` are rejected because erasure (and upper-bound lookup) would
+    /// never terminate, while F-bounds that go through a generic class (`T extends
+    /// Comparable<T>`) are allowed.
+    pub fn validate_type_param_bounds(
+        &self,
+        id: TypeVarId,
+        def: &TypeParamDef,
+    ) -> Result<(), TypeParamBoundError> {
+        fn bare_type_var_bounds(bounds: &[Type]) -> Vec<TypeVarId> {
+            let mut out = Vec::new();
+            for bound in bounds {
+                match bound {
+                    Type::TypeVar(tv) => out.push(*tv),
+                    Type::Intersection(parts) => out.extend(bare_type_var_bounds(parts)),
+                    _ => {}
+                }
+            }
+            out
+        }
+
+        let direct = bare_type_var_bounds(&def.upper_bounds);
+        if direct.contains(&id) {
+            return Err(TypeParamBoundError::SelfBound { param: id });
+        }
+
+        // Depth-first walk over bare type-variable bounds, using `def` in place of the currently
+        // stored definition for `id`.
+        let mut path = vec![id];
+        let mut visited = HashSet::from([id]);
+        let mut stack: Vec<std::vec::IntoIter<TypeVarId>> = vec![direct.into_iter()];
+        while let Some(edges) = stack.last_mut() {
+            let Some(next) = edges.next() else {
+                stack.pop();
+                path.pop();
+                continue;
+            };
+            if next == id {
+                return Err(TypeParamBoundError::Cycle { params: path });
+            }
+            if !visited.insert(next) {
+                continue;
+            }
+            let bounds = self
+                .type_param(next)
+                .map(|tp| bare_type_var_bounds(&tp.upper_bounds))
+                .unwrap_or_default();
+            path.push(next);
+            stack.push(bounds.into_iter());
+        }
+
+        Ok(())
+    }
+
     /// Reserve (or reuse) a stable [`ClassId`] for `binary_name`.
     ///
     /// External type loaders (e.g. reading `.class` files or JDK stubs) often need a
@@ -1961,6 +3990,8 @@ impl TypeStore {
     /// [`ClassDef`] (kind = [`ClassKind::Class`], no supertypes, no fields, no constructors,
     /// no methods, no type params) and returns its id. If it already exists, returns the
     /// existing id.
+    ///
+    /// The class is reported as [`ClassDefState::Placeholder`] until it is defined.
     pub fn intern_class_id(&mut self, binary_name: &str) -> ClassId {
         if let Some(id) = self.class_by_name.get(binary_name).copied() {
             return id;
@@ -1971,7 +4002,7 @@ impl TypeStore {
             return id;
         }
 
-        self.add_class(ClassDef {
+        let id = self.add_class(ClassDef {
             name: binary_name.to_string(),
             kind: ClassKind::Class,
             type_params: vec![],
@@ -1980,7 +4011,13 @@ impl TypeStore {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
-        })
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
+        });
+        self.placeholders.insert(id);
+        id
     }
 
     /// Overwrite the existing class definition at `id`.
@@ -1995,8 +4032,8 @@ impl TypeStore {
     /// originally associated with `id`.
     pub fn define_class(&mut self, id: ClassId, def: ClassDef) {
         let slot = self
-            .classes
-            .get_mut(id.to_raw() as usize)
+            .class_index(id)
+            .and_then(|idx| self.classes.get_mut(idx))
             .unwrap_or_else(|| panic!("define_class: invalid ClassId {:?}", id));
         let expected_name = slot.name.clone();
 
@@ -2014,16 +4051,21 @@ impl TypeStore {
             id
         );
 
+        unindex_method_names(&mut self.method_owners, id, slot);
+        index_method_names(&mut self.method_owners, id, &def);
         *slot = def;
+        self.placeholders.remove(&id);
+        self.unindexed_methods.remove(&id);
     }
     pub fn add_class(&mut self, def: ClassDef) -> ClassId {
-        let id = ClassId::from_raw(self.classes.len() as u32);
+        let id = ClassId::from_raw(self.class_id_offset + self.classes.len() as u32);
         if self.class_by_name.contains_key(&def.name) || self.tombstones.contains_key(&def.name) {
             // Avoid silently creating two ids for the same class.
             // This is a programmer error in tests/builders.
             panic!("duplicate class definition for {}", def.name);
         }
         self.class_by_name.insert(def.name.clone(), id);
+        index_method_names(&mut self.method_owners, id, &def);
         self.classes.push(def);
         id
     }
@@ -2033,8 +4075,24 @@ impl TypeStore {
     /// This is primarily used for incremental updates where types may originate
     /// from multiple sources (classpath stubs, source code, generated overlays).
     /// The `ClassId` is stable for a given binary name as long as the store lives.
+    ///
+    /// When a class is redefined with as many type parameters as before, with the same names
+    /// (e.g. its file was saved again), the previous `TypeVarId`s are kept: the new parameters'
+    /// bounds are moved onto them and the new ids are replaced throughout `def`, so types
+    /// computed against the previous definition stay valid. Type parameters the class no longer
+    /// uses are left for [`TypeStore::compact`].
     pub fn upsert_class(&mut self, def: ClassDef) -> ClassId {
         if let Some(id) = self.class_by_name.get(&def.name).copied() {
+            let def = self.reuse_type_params(id, def);
+            let previous = self
+                .class(id)
+                .map(|class| class.type_params.clone())
+                .unwrap_or_default();
+            let dropped: Vec<TypeVarId> = previous
+                .into_iter()
+                .filter(|tv| !def.type_params.contains(tv))
+                .collect();
+            self.release_type_params(id, &dropped);
             self.define_class(id, def);
             return id;
         }
@@ -2048,6 +4106,155 @@ impl TypeStore {
         self.add_class(def)
     }
 
+    /// Rewrite `def`, the new definition of class `id`, to use the class's current type
+    /// parameter ids if it declares parameters with the same names (see
+    /// [`TypeStore::upsert_class`]).
+    fn reuse_type_params(&mut self, id: ClassId, def: ClassDef) -> ClassDef {
+        let Some(previous) = self.class(id).map(|class| class.type_params.clone()) else {
+            return def;
+        };
+        let same_names = |store: &TypeStore, old: TypeVarId, new: TypeVarId| match (
+            store.type_param(old),
+            store.type_param(new),
+        ) {
+            (Some(old), Some(new)) => old.name == new.name,
+            _ => false,
+        };
+        let reusable = previous.len() == def.type_params.len()
+            && previous != def.type_params
+            && previous.iter().zip(&def.type_params).all(|(&old, &new)| {
+                old == new || (!previous.contains(&new) && same_names(self, old, new))
+            });
+        if !reusable {
+            return def;
+        }
+
+        let subst = TypeSubstitution::renaming(&def.type_params, &previous);
+        for (&old, &new) in previous.iter().zip(&def.type_params) {
+            if old == new {
+                continue;
+            }
+            let Some(param) = self.type_param(new).cloned() else {
+                continue;
+            };
+            self.define_type_param(
+                old,
+                TypeParamDef {
+                    name: param.name,
+                    upper_bounds: param.upper_bounds.iter().map(|b| subst.apply(b)).collect(),
+                    lower_bound: param.lower_bound.as_ref().map(|b| subst.apply(b)),
+                    declared_by: Some(id),
+                },
+            );
+        }
+        // Bounds of the methods' own type parameters may mention the class's.
+        for method in &def.methods {
+            for &tv in &method.type_params {
+                if let Some(param) = self
+                    .type_param_index(tv)
+                    .and_then(|idx| self.type_params.get_mut(idx))
+                {
+                    for bound in &mut param.upper_bounds {
+                        *bound = subst.apply(bound);
+                    }
+                    if let Some(bound) = &mut param.lower_bound {
+                        *bound = subst.apply(bound);
+                    }
+                }
+            }
+        }
+        let replaced: Vec<TypeVarId> = def
+            .type_params
+            .iter()
+            .copied()
+            .filter(|tv| !previous.contains(tv))
+            .collect();
+        self.release_type_params(id, &replaced);
+
+        ClassDef {
+            name: def.name,
+            kind: def.kind,
+            type_params: previous,
+            super_class: def.super_class.as_ref().map(|ty| subst.apply(ty)),
+            interfaces: def.interfaces.iter().map(|ty| subst.apply(ty)).collect(),
+            fields: def
+                .fields
+                .into_iter()
+                .map(|field| FieldDef {
+                    ty: subst.apply(&field.ty),
+                    ..field
+                })
+                .collect(),
+            constructors: def
+                .constructors
+                .into_iter()
+                .map(|ctor| ConstructorDef {
+                    params: ctor.params.iter().map(|ty| subst.apply(ty)).collect(),
+                    ..ctor
+                })
+                .collect(),
+            methods: def
+                .methods
+                .into_iter()
+                .map(|method| MethodDef {
+                    params: method.params.iter().map(|ty| subst.apply(ty)).collect(),
+                    return_type: subst.apply(&method.return_type),
+                    ..method
+                })
+                .collect(),
+            enclosing: def.enclosing,
+            is_static_nested: def.is_static_nested,
+            is_abstract: def.is_abstract,
+            deprecation: def.deprecation,
+        }
+    }
+
+    /// Record that class `owner` no longer uses the type parameters `ids`, so that
+    /// [`TypeStore::compact`] can reclaim them. Parameters already attributed to another class
+    /// keep that owner.
+    fn release_type_params(&mut self, owner: ClassId, ids: &[TypeVarId]) {
+        for &tv in ids {
+            if let Some(param) = self
+                .type_param_index(tv)
+                .and_then(|idx| self.type_params.get_mut(idx))
+            {
+                if param.name != REMOVED_TYPE_PARAM_NAME {
+                    param.declared_by.get_or_insert(owner);
+                }
+            }
+        }
+    }
+
+    /// Reclaim the type parameters of removed classes, and those a class stopped using when it
+    /// was redefined.
+    ///
+    /// A type parameter is reclaimed when the class that declared it (see
+    /// [`TypeParamDef::declared_by`]) was removed or no longer lists it, and no other class
+    /// lists it either. Reclaimed slots become tombstones as with
+    /// [`TypeStore::remove_type_param_range`]: their ids are never reused, so types that still
+    /// mention them resolve to no type parameter instead of to an unrelated one.
+    pub fn compact(&mut self) -> CompactionReport {
+        let in_use: HashSet<TypeVarId> = self
+            .classes
+            .iter()
+            .flat_map(|class| class.type_params.iter().copied())
+            .collect();
+        let mut report = CompactionReport::default();
+        for idx in 0..self.type_params.len() {
+            let id = TypeVarId(self.type_param_id_offset + idx as u32);
+            let param = &self.type_params[idx];
+            if param.name == REMOVED_TYPE_PARAM_NAME
+                || param.declared_by.is_none()
+                || in_use.contains(&id)
+            {
+                continue;
+            }
+            self.remove_type_param_range(id, TypeVarId(id.0 + 1));
+            report.reclaimed_type_params += 1;
+        }
+        report
+    }
+
     /// Remove a class by binary name.
     ///
     /// The removed slot is kept as an inert placeholder so existing `ClassId`s
@@ -2056,8 +4263,19 @@ impl TypeStore {
     pub fn remove_class(&mut self, name: &str) -> Option<ClassId> {
         let id = self.class_by_name.remove(name)?;
         self.tombstones.insert(name.to_string(), id);
+        if let Some(type_params) = self.class(id).map(|class| class.type_params.clone()) {
+            self.release_type_params(id, &type_params);
+        }
 
-        if let Some(class_def) = self.classes.get_mut(id.to_raw() as usize) {
+        let well_known_object = self
+            .well_known
+            .as_ref()
+            .map(|wk| Type::class(wk.object, vec![]));
+        if let Some(class_def) = self
+            .class_index(id)
+            .and_then(|idx| self.classes.get_mut(idx))
+        {
+            unindex_method_names(&mut self.method_owners, id, class_def);
             class_def.type_params.clear();
             class_def.interfaces.clear();
             class_def.fields.clear();
@@ -2068,12 +4286,7 @@ impl TypeStore {
             // references to a deleted class.
             match class_def.kind {
                 ClassKind::Interface => class_def.super_class = None,
-                ClassKind::Class => {
-                    class_def.super_class = self
-                        .well_known
-                        .as_ref()
-                        .map(|wk| Type::class(wk.object, vec![]))
-                }
+                ClassKind::Class => class_def.super_class = well_known_object,
             }
         }
 
@@ -2094,21 +4307,53 @@ impl TypeStore {
         self.classes
             .iter()
             .enumerate()
-            .map(|(idx, def)| (ClassId::from_raw(idx as u32), def))
+            .map(|(idx, def)| (ClassId::from_raw(self.class_id_offset + idx as u32), def))
     }
 
+    /// Mutable access to a class definition.
+    ///
+    /// Prefer [`TypeStore::define_class`] or [`TypeStore::upsert_class`] for replacing a class;
+    /// methods added through this reference are found by
+    /// [`TypeEnv::classes_declaring_method`] only through a slower fallback.
     pub fn class_mut(&mut self, id: ClassId) -> Option<&mut ClassDef> {
-        self.classes.get_mut(id.to_raw() as usize)
+        let idx = self.class_index(id)?;
+        self.unindexed_methods.insert(id);
+        self.classes.get_mut(idx)
+    }
+}
+
+/// Record `id` as a declarer of every method name in `def`.
+fn index_method_names(index: &mut HashMap<String, BTreeSet<ClassId>>, id: ClassId, def: &ClassDef) {
+    for method in &def.methods {
+        index.entry(method.name.clone()).or_default().insert(id);
+    }
+}
+
+/// Undo [`index_method_names`] for `def`, the current definition of `id`.
+fn unindex_method_names(
+    index: &mut HashMap<String, BTreeSet<ClassId>>,
+    id: ClassId,
+    def: &ClassDef,
+) {
+    for method in &def.methods {
+        if let Some(owners) = index.get_mut(&method.name) {
+            owners.remove(&id);
+            if owners.is_empty() {
+                index.remove(&method.name);
+            }
+        }
     }
 }
 
 impl TypeEnv for TypeStore {
     fn class(&self, id: ClassId) -> Option<&ClassDef> {
-        self.classes.get(id.to_raw() as usize)
+        self.classes.get(self.class_index(id)?)
     }
 
     fn type_param(&self, id: TypeVarId) -> Option<&TypeParamDef> {
-        self.type_params.get(id.0 as usize)
+        self.type_params
+            .get(self.type_param_index(id)?)
+            .filter(|def| def.name != REMOVED_TYPE_PARAM_NAME)
     }
 
     fn lookup_class(&self, name: &str) -> Option<ClassId> {
@@ -2133,6 +4378,53 @@ impl TypeEnv for TypeStore {
             .as_ref()
             .expect("TypeStore must initialize well-known types")
     }
+
+    fn classes_declaring_method(&self, name: &str, limit: usize) -> Vec<ClassId> {
+        let declares = |id: &ClassId| {
+            self.class(*id)
+                .is_some_and(|def| def.methods.iter().any(|m| m.name == name))
+        };
+        // Both sources are ordered by id, so the first `limit` hits of each are enough.
+        let mut out: Vec<ClassId> = self
+            .method_owners
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter(|id| declares(id))
+            .take(limit)
+            .chain(
+                self.unindexed_methods
+                    .iter()
+                    .filter(|id| declares(id))
+                    .take(limit),
+            )
+            .copied()
+            .collect();
+        out.sort_unstable();
+        out.dedup();
+        out.truncate(limit);
+        out
+    }
+
+    fn interfaces(&self, limit: usize) -> Vec<ClassId> {
+        self.iter_classes()
+            .filter(|(_, def)| def.kind == ClassKind::Interface)
+            .map(|(id, _)| id)
+            .take(limit)
+            .collect()
+    }
+
+    fn method_contract(&self, key: &MethodContractKey) -> Option<MethodContract> {
+        self.method_contracts.get(key).copied()
+    }
+
+    fn class_def_state(&self, id: ClassId) -> ClassDefState {
+        if self.placeholders.contains(&id) {
+            ClassDefState::Placeholder
+        } else {
+            ClassDefState::Defined
+        }
+    }
 }
 
 // === Subtyping / assignability ==============================================
@@ -2163,8 +4455,12 @@ pub fn is_subtype(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
         // `void` is only compatible with itself (handled by equality above).
         (Type::Void, _) | (_, Type::Void) => false,
 
-        // null is subtype of any reference type
+        // null is subtype of any reference type, including type variables and intersections
+        // whatever their bounds. This must come before the `(other, TypeVar)` arm below, which
+        // only accepts subtypes of a lower bound.
         (Type::Null, t) if t.is_reference() || matches!(t, Type::Wildcard(_)) => true,
+        // Nothing but null itself (handled by equality above) is a subtype of the null type.
+        (_, Type::Null) => false,
 
         (Type::Primitive(a), Type::Primitive(b)) => primitive_widening(*a, *b),
 
@@ -2197,16 +4493,7 @@ pub fn is_subtype(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
         // `(A & B) <: X` iff `A <: X` or `B <: X`.
         (Type::Intersection(types), other) => types.iter().any(|t| is_subtype(env, t, other)),
 
-        (Type::TypeVar(id), other) => env
-            .type_param(*id)
-            .map(|tp| {
-                if tp.upper_bounds.is_empty() {
-                    false
-                } else {
-                    tp.upper_bounds.iter().any(|b| is_subtype(env, b, other))
-                }
-            })
-            .unwrap_or(false),
+        (Type::TypeVar(id), other) => type_var_is_subtype(env, *id, other),
 
         (other, Type::TypeVar(id)) => {
             env.type_param(*id)
@@ -2223,9 +4510,11 @@ pub fn is_subtype(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
                 .unwrap_or(false)
         }
 
-        (_, Type::Wildcard(WildcardBound::Unbounded)) => true,
-        (_, Type::Wildcard(WildcardBound::Extends(upper))) => is_subtype(env, sub, upper),
-        (_, Type::Wildcard(WildcardBound::Super(lower))) => is_subtype(env, lower, sub),
+        // A wildcard is not a type (JLS 4.5.1) and is only compared through containment at
+        // type argument positions (`type_args_compatible`). One that leaks out uncaptured is
+        // read the same way as a target, and as its upper bound as a source.
+        (_, Type::Wildcard(_)) => type_arg_contained_by(env, sub, super_),
+        (Type::Wildcard(bound), _) => is_subtype(env, &wildcard_upper_bound(env, bound), super_),
 
         // Best-effort: treat framework-only synthetic types as subtypes of Object.
         (Type::VirtualInner { .. } | Type::Named(_), Type::Class(ClassType { def, .. })) => {
@@ -2238,6 +4527,169 @@ pub fn is_subtype(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
     }
 }
 
+/// `T <: other` for a type variable `T`: one of its upper bounds is a subtype of `other`.
+///
+/// Bare type variable bounds (`T extends U`) are followed with a visited set rather than by
+/// recursion: source being edited can declare cyclic bounds (`<T extends T>`), which
+/// [`TypeStore::try_define_type_param`] rejects but front-ends lowering source do not. Such a
+/// variable, like one whose declaration is gone, is still a subtype of `Object`.
+fn type_var_is_subtype(env: &dyn TypeEnv, id: TypeVarId, other: &Type) -> bool {
+    if matches!(other, Type::Class(ClassType { def, args }) if *def == env.well_known().object && args.is_empty())
+    {
+        return true;
+    }
+    let mut visited = HashSet::new();
+    let mut pending = vec![id];
+    while let Some(id) = pending.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        let Some(tp) = env.type_param(id) else {
+            continue;
+        };
+        let mut bounds: Vec<&Type> = tp.upper_bounds.iter().collect();
+        while let Some(bound) = bounds.pop() {
+            match bound {
+                Type::TypeVar(next) if bound != other => pending.push(*next),
+                Type::Intersection(parts) => bounds.extend(parts),
+                _ => {
+                    if is_subtype(env, bound, other) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// The upper bounds of the type variable `id`, in declaration order, with bare type variable
+/// bounds (`T extends U`) replaced by the bounds they lead to and intersections flattened.
+///
+/// A cyclic chain (`<T extends T>`, see [`type_var_is_subtype`]) contributes no bounds, and
+/// neither does an array bound (`<T extends T[]>`), which JLS 4.4 does not allow.
+fn type_var_resolved_bounds(env: &dyn TypeEnv, id: TypeVarId) -> Vec<&Type> {
+    fn collect<'e>(
+        env: &'e dyn TypeEnv,
+        id: TypeVarId,
+        visited: &mut HashSet<TypeVarId>,
+        out: &mut Vec<&'e Type>,
+    ) {
+        if !visited.insert(id) {
+            return;
+        }
+        let Some(tp) = env.type_param(id) else {
+            return;
+        };
+        for bound in &tp.upper_bounds {
+            collect_bound(env, bound, visited, out);
+        }
+    }
+
+    fn collect_bound<'e>(
+        env: &'e dyn TypeEnv,
+        bound: &'e Type,
+        visited: &mut HashSet<TypeVarId>,
+        out: &mut Vec<&'e Type>,
+    ) {
+        match bound {
+            Type::TypeVar(next) => collect(env, *next, visited, out),
+            Type::Intersection(parts) => {
+                for part in parts {
+                    collect_bound(env, part, visited, out);
+                }
+            }
+            Type::Array(_) => {}
+            other => out.push(other),
+        }
+    }
+
+    let mut out = Vec::new();
+    collect(env, id, &mut HashSet::new(), &mut out);
+    out
+}
+
+/// Whether `ty` can be thrown: a subtype of `java.lang.Throwable`.
+///
+/// The null type and error-recovery types are not exceptions.
+pub fn is_exception_type(env: &dyn TypeEnv, ty: &Type) -> bool {
+    is_proper_subclass_of(env, ty, "java.lang.Throwable")
+}
+
+/// Whether `ty` is a checked exception (JLS 11.1.1): a `Throwable` that is neither a
+/// `RuntimeException` nor an `Error`.
+pub fn is_checked_exception(env: &dyn TypeEnv, ty: &Type) -> bool {
+    is_exception_type(env, ty) && !is_unchecked_exception(env, ty)
+}
+
+/// Whether `ty` is an unchecked exception: a subtype of `java.lang.RuntimeException` or
+/// `java.lang.Error`.
+///
+/// `Error` is only recognized when the environment defines it (the minimal JDK does not).
+pub fn is_unchecked_exception(env: &dyn TypeEnv, ty: &Type) -> bool {
+    is_proper_subclass_of(env, ty, "java.lang.RuntimeException")
+        || is_proper_subclass_of(env, ty, "java.lang.Error")
+}
+
+/// Whether `ty` is a subtype of the class `binary_name`, without the leniency [`is_subtype`]
+/// gives to the null type and error-recovery types.
+fn is_proper_subclass_of(env: &dyn TypeEnv, ty: &Type, binary_name: &str) -> bool {
+    if ty.is_errorish() || matches!(ty, Type::Null) {
+        return false;
+    }
+    env.lookup_class(binary_name)
+        .is_some_and(|id| is_subtype(env, ty, &Type::class(id, vec![])))
+}
+
+/// Whether `new T(..)` can create an instance of `ty`: a class type that is neither an interface
+/// nor `abstract`, with an accessible constructor.
+///
+/// A class without constructors is assumed to have its implicit default constructor.
+pub fn is_instantiable(env: &dyn TypeEnv, ty: &Type) -> bool {
+    let Type::Class(ClassType { def, .. }) = ty else {
+        return false;
+    };
+    env.class(*def).is_some_and(|class_def| {
+        class_def.kind == ClassKind::Class
+            && !class_def.is_abstract
+            && (class_def.constructors.is_empty()
+                || class_def.constructors.iter().any(|ctor| ctor.is_accessible))
+    })
+}
+
+/// Whether `ty`'s class is generic, so that naming it without type arguments is a raw type.
+pub fn requires_generic_args(env: &dyn TypeEnv, ty: &Type) -> bool {
+    let Type::Class(ClassType { def, .. }) = ty else {
+        return false;
+    };
+    env.class(*def)
+        .is_some_and(|class_def| !class_def.type_params.is_empty())
+}
+
+/// A bound on a type, as collected during inference: `ty <: U`, `ty :> L` or `ty = E`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TypeConstraint {
+    IsSubtypeOf(Type),
+    IsSupertypeOf(Type),
+    Equals(Type),
+}
+
+/// Whether `ty` satisfies `constraint`, e.g. whether a proposed type argument fits the bounds
+/// inference collected for it.
+///
+/// Equality holds between types that are subtypes of each other, so equivalent spellings such as
+/// `List<?>` and `List<? extends Object>` are equal. Bare wildcards on either side are compared
+/// as by [`is_subtype`].
+pub fn satisfies_constraint(env: &dyn TypeEnv, ty: &Type, constraint: &TypeConstraint) -> bool {
+    match constraint {
+        TypeConstraint::IsSubtypeOf(upper) => is_subtype(env, ty, upper),
+        TypeConstraint::IsSupertypeOf(lower) => is_subtype(env, lower, ty),
+        TypeConstraint::Equals(other) => {
+            ty == other || (is_subtype(env, ty, other) && is_subtype(env, other, ty))
+        }
+    }
+}
+
 fn primitive_widening(from: PrimitiveType, to: PrimitiveType) -> bool {
     use PrimitiveType::*;
     if from == to {
@@ -2265,7 +4717,7 @@ fn is_subtype_class(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
     };
 
     let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
+    let mut seen = VisitedSupertypes::default();
     queue.push_back(Type::class(sub_def, sub_args));
 
     while let Some(mut current) = queue.pop_front() {
@@ -2280,7 +4732,7 @@ fn is_subtype_class(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
         let Type::Class(ClassType { def, args }) = current.clone() else {
             continue;
         };
-        if !seen.insert((def, args.clone())) {
+        if !seen.insert(def, &args) {
             continue;
         }
 
@@ -2292,12 +4744,7 @@ fn is_subtype_class(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
             continue;
         };
 
-        let subst = class_def
-            .type_params
-            .iter()
-            .copied()
-            .zip(args.into_iter())
-            .collect::<HashMap<_, _>>();
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
 
         if let Some(sc) = &class_def.super_class {
             queue.push_back(substitute(sc, &subst));
@@ -2323,6 +4770,32 @@ fn is_subtype_class(env: &dyn TypeEnv, sub: &Type, super_: &Type) -> bool {
     false
 }
 
+/// How many instantiations of one class a supertype walk visits.
+const MAX_SUPERTYPE_INSTANTIATIONS: usize = 4;
+
+/// The instantiations a breadth-first walk over supertypes has visited.
+///
+/// A cyclic hierarchy in code being edited (`class C<T> implements C<List<T>>`) reaches the same
+/// class with ever larger arguments, so past [`MAX_SUPERTYPE_INSTANTIATIONS`] further
+/// instantiations of a class count as visited.
+#[derive(Default)]
+struct VisitedSupertypes {
+    seen: HashSet<(ClassId, Vec<Type>)>,
+    per_class: HashMap<ClassId, usize>,
+}
+
+impl VisitedSupertypes {
+    /// Returns `true` if `def<args>` is to be visited.
+    fn insert(&mut self, def: ClassId, args: &[Type]) -> bool {
+        let count = self.per_class.entry(def).or_default();
+        if *count >= MAX_SUPERTYPE_INSTANTIATIONS || !self.seen.insert((def, args.to_vec())) {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
 fn type_args_compatible(env: &dyn TypeEnv, def: ClassId, sub: &[Type], super_: &[Type]) -> bool {
     let type_param_len = env.class(def).map(|c| c.type_params.len()).unwrap_or(0);
     let sub_raw = sub.is_empty() && type_param_len != 0;
@@ -2345,73 +4818,918 @@ fn type_args_compatible(env: &dyn TypeEnv, def: ClassId, sub: &[Type], super_: &
             return false;
         }
     }
-    true
+    true
+}
+
+/// Type argument containment (JLS 4.5.1 / 4.10.2).
+///
+/// This is the relation used when comparing two parameterized types with the same
+/// generic class/interface, e.g. `List<? extends String> <: List<? extends Object>`.
+fn type_arg_contained_by(env: &dyn TypeEnv, actual: &Type, formal: &Type) -> bool {
+    match formal {
+        // `?` contains any type argument.
+        Type::Wildcard(WildcardBound::Unbounded) => true,
+
+        // `? extends U` contains:
+        // * `A` if `A <: U`
+        // * `? extends S` if `S <: U`
+        // * `?` as shorthand for `? extends Object` (so only if `Object <: U`)
+        // * `? super S`, whose upper bound is `Object` too
+        Type::Wildcard(WildcardBound::Extends(upper)) => match actual {
+            Type::Wildcard(WildcardBound::Unbounded | WildcardBound::Super(_)) => {
+                let object = Type::class(env.well_known().object, vec![]);
+                is_subtype(env, &object, upper)
+            }
+            Type::Wildcard(WildcardBound::Extends(actual_upper)) => {
+                is_subtype(env, actual_upper, upper)
+            }
+            other => is_subtype(env, other, upper),
+        },
+
+        // `? super L` contains:
+        // * `A` if `L <: A`
+        // * `? super S` if `L <: S` (contravariant containment)
+        Type::Wildcard(WildcardBound::Super(lower)) => match actual {
+            Type::Wildcard(WildcardBound::Super(actual_lower)) => {
+                is_subtype(env, lower, actual_lower)
+            }
+            Type::Wildcard(_) => false,
+            other => is_subtype(env, lower, other),
+        },
+
+        // Non-wildcard type arguments are invariant.
+        _ => actual == formal,
+    }
+}
+
+/// A mapping from type variables to the types that replace them.
+///
+/// This is the substitution produced by instantiating a generic class (`List<String>` maps `E`
+/// to `String`) or by method type argument inference.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TypeSubstitution(HashMap<TypeVarId, Type>);
+
+impl TypeSubstitution {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn single(id: TypeVarId, ty: Type) -> Self {
+        Self(HashMap::from([(id, ty)]))
+    }
+
+    /// Maps `class_def`'s type parameters to `args`, pairwise.
+    ///
+    /// Raw uses (`args` is empty) produce an empty substitution; extra or missing arguments of a
+    /// malformed instantiation are ignored.
+    pub fn from_class_def(class_def: &ClassDef, args: &[Type]) -> Self {
+        class_def
+            .type_params
+            .iter()
+            .copied()
+            .zip(args.iter().cloned())
+            .collect()
+    }
+
+    /// Like [`TypeSubstitution::from_class_def`], but also maps the type parameters of an inner
+    /// class's enclosing instances.
+    ///
+    /// `Outer<String>.Inner<Integer>` is represented with flattened arguments, outermost class
+    /// first (`Outer$Inner<String, Integer>`), the same layout external loaders produce from
+    /// generic signatures. Arguments for the class's own parameters alone are mapped as by
+    /// [`TypeSubstitution::from_class_def`].
+    pub fn from_class_def_with_enclosing(
+        env: &dyn TypeEnv,
+        class_def: &ClassDef,
+        args: &[Type],
+    ) -> Self {
+        if args.len() <= class_def.type_params.len() {
+            return Self::from_class_def(class_def, args);
+        }
+        let params = flattened_type_params(env, class_def);
+        if params.len() != args.len() {
+            return Self::from_class_def(class_def, args);
+        }
+        params.into_iter().zip(args.iter().cloned()).collect()
+    }
+
+    /// Maps each of `class_def`'s type parameters to its erasure, the member types seen through a
+    /// raw reference (JLS 4.8).
+    ///
+    /// Takes `env` to look up the parameters' bounds: `<T extends Number>` maps `T` to `Number`.
+    pub fn for_raw(env: &dyn TypeEnv, class_def: &ClassDef) -> Self {
+        class_def
+            .type_params
+            .iter()
+            .map(|&id| (id, erasure(env, &Type::TypeVar(id))))
+            .collect()
+    }
+
+    /// Returns the substitution equivalent to applying `self` and then `other`.
+    ///
+    /// Variables mapped by `self` are mapped to their `self` image with `other` applied; variables
+    /// only mapped by `other` keep their `other` image.
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut out: HashMap<TypeVarId, Type> = self
+            .0
+            .iter()
+            .map(|(id, ty)| (*id, other.apply(ty)))
+            .collect();
+        for (id, ty) in &other.0 {
+            out.entry(*id).or_insert_with(|| ty.clone());
+        }
+        Self(out)
+    }
+
+    /// Maps each of `from` to the type variable at the same position in `to`.
+    pub fn renaming(from: &[TypeVarId], to: &[TypeVarId]) -> Self {
+        from.iter()
+            .copied()
+            .zip(to.iter().copied().map(Type::TypeVar))
+            .collect()
+    }
+
+    pub fn apply(&self, ty: &Type) -> Type {
+        substitute(ty, self)
+    }
+
+    pub fn get(&self, id: TypeVarId) -> Option<&Type> {
+        self.0.get(&id)
+    }
+
+    pub fn insert(&mut self, id: TypeVarId, ty: Type) -> Option<Type> {
+        self.0.insert(id, ty)
+    }
+
+    pub fn contains(&self, id: TypeVarId) -> bool {
+        self.0.contains_key(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (TypeVarId, &Type)> {
+        self.0.iter().map(|(id, ty)| (*id, ty))
+    }
+}
+
+impl FromIterator<(TypeVarId, Type)> for TypeSubstitution {
+    fn from_iter<I: IntoIterator<Item = (TypeVarId, Type)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl From<HashMap<TypeVarId, Type>> for TypeSubstitution {
+    fn from(map: HashMap<TypeVarId, Type>) -> Self {
+        Self(map)
+    }
+}
+
+/// The substitution instantiating `class_def` with the explicit `type_args`, e.g.
+/// `{K -> String, V -> Integer}` for `Map<String, Integer>`.
+///
+/// `type_args` lists either the class's own arguments or, for an inner class, the flattened
+/// arguments of its enclosing instances followed by its own (see
+/// [`TypeSubstitution::from_class_def_with_enclosing`]). A raw use, or an argument count matching
+/// neither, produces the identity (empty) substitution; use [`TypeSubstitution::for_raw`] to
+/// erase the parameters instead.
+pub fn compute_type_argument_substitution(
+    env: &dyn TypeEnv,
+    class_def: &ClassDef,
+    type_args: &[Type],
+) -> TypeSubstitution {
+    if type_args.len() == class_def.type_params.len() {
+        return TypeSubstitution::from_class_def(class_def, type_args);
+    }
+    let params = flattened_type_params(env, class_def);
+    if type_args.is_empty() || params.len() != type_args.len() {
+        return TypeSubstitution::empty();
+    }
+    params.into_iter().zip(type_args.iter().cloned()).collect()
+}
+
+/// Copy `method` with fresh type variables in place of its own type parameters.
+///
+/// Generic methods reached through different paths (e.g. the bounds of an intersection
+/// receiver, or a SAM being instantiated for a lambda) may share `TypeVarId`s; renaming keeps
+/// their inference variables apart. Bounds are carried over, with references to the method's
+/// own type parameters (`<T extends Comparable<T>>`) renamed as well.
+///
+/// Returns the renamed method and the substitution from the original ids to the fresh ones.
+pub fn alpha_rename(env: &mut TypeStore, method: &MethodDef) -> (MethodDef, TypeSubstitution) {
+    // Allocate all ids first so bounds can refer to any of the renamed parameters.
+    let originals: Vec<TypeParamDef> = method
+        .type_params
+        .iter()
+        .map(|id| {
+            env.type_param(*id)
+                .cloned()
+                .unwrap_or_else(|| TypeParamDef {
+                    name: format!("T{}", id.0),
+                    upper_bounds: Vec::new(),
+                    lower_bound: None,
+                    declared_by: None,
+                })
+        })
+        .collect();
+    let fresh: Vec<TypeVarId> = originals
+        .iter()
+        .map(|def| env.add_type_param(def.name.clone(), Vec::new()))
+        .collect();
+    let subst = TypeSubstitution::renaming(&method.type_params, &fresh);
+
+    for (id, def) in fresh.iter().copied().zip(originals) {
+        env.define_type_param(
+            id,
+            TypeParamDef {
+                name: def.name,
+                upper_bounds: def.upper_bounds.iter().map(|b| subst.apply(b)).collect(),
+                lower_bound: def.lower_bound.as_ref().map(|b| subst.apply(b)),
+                declared_by: def.declared_by,
+            },
+        );
+    }
+
+    let renamed = MethodDef {
+        name: method.name.clone(),
+        type_params: fresh,
+        params: method.params.iter().map(|p| subst.apply(p)).collect(),
+        param_names: method.param_names.clone(),
+        return_type: subst.apply(&method.return_type),
+        is_static: method.is_static,
+        is_varargs: method.is_varargs,
+        is_abstract: method.is_abstract,
+        is_private: false,
+        deprecation: None,
+    };
+    (renamed, subst)
+}
+
+fn substitute(ty: &Type, subst: &TypeSubstitution) -> Type {
+    match ty {
+        Type::TypeVar(id) => subst.get(*id).cloned().unwrap_or(Type::TypeVar(*id)),
+        Type::Array(elem) => Type::Array(Box::new(substitute(elem, subst))),
+        Type::Class(ClassType { def, args }) => {
+            Type::class(*def, args.iter().map(|a| substitute(a, subst)).collect())
+        }
+        Type::Wildcard(WildcardBound::Unbounded) => Type::Wildcard(WildcardBound::Unbounded),
+        Type::Wildcard(WildcardBound::Extends(upper)) => {
+            Type::Wildcard(WildcardBound::Extends(Box::new(substitute(upper, subst))))
+        }
+        Type::Wildcard(WildcardBound::Super(lower)) => {
+            Type::Wildcard(WildcardBound::Super(Box::new(substitute(lower, subst))))
+        }
+        Type::Intersection(types) => {
+            Type::Intersection(types.iter().map(|t| substitute(t, subst)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Shift every `TypeVarId` in `ty` up by `offset`.
+///
+/// Stores allocate type variable ids densely from the same starting point, so a type taken from
+/// one store must be rebased before it can be used alongside another's; see
+/// [`TypeStore::import_type_params`].
+pub fn rebase_type_vars(ty: &Type, offset: u32) -> Type {
+    match ty {
+        Type::TypeVar(id) => Type::TypeVar(TypeVarId(id.0 + offset)),
+        Type::Array(elem) => Type::Array(Box::new(rebase_type_vars(elem, offset))),
+        Type::Class(ClassType { def, args }) => Type::class(
+            *def,
+            args.iter().map(|a| rebase_type_vars(a, offset)).collect(),
+        ),
+        Type::Wildcard(WildcardBound::Unbounded) => Type::Wildcard(WildcardBound::Unbounded),
+        Type::Wildcard(WildcardBound::Extends(upper)) => Type::Wildcard(WildcardBound::Extends(
+            Box::new(rebase_type_vars(upper, offset)),
+        )),
+        Type::Wildcard(WildcardBound::Super(lower)) => Type::Wildcard(WildcardBound::Super(
+            Box::new(rebase_type_vars(lower, offset)),
+        )),
+        Type::Intersection(types) => {
+            Type::Intersection(types.iter().map(|t| rebase_type_vars(t, offset)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Replace every [`ClassId`] in `ty` with `map(id)`; see [`TypeStore::merge`].
+fn remap_class_ids(ty: &Type, map: &impl Fn(ClassId) -> ClassId) -> Type {
+    match ty {
+        Type::Class(ClassType { def, args }) => Type::class(
+            map(*def),
+            args.iter().map(|a| remap_class_ids(a, map)).collect(),
+        ),
+        Type::Array(elem) => Type::Array(Box::new(remap_class_ids(elem, map))),
+        Type::Wildcard(WildcardBound::Unbounded) => Type::Wildcard(WildcardBound::Unbounded),
+        Type::Wildcard(WildcardBound::Extends(upper)) => Type::Wildcard(WildcardBound::Extends(
+            Box::new(remap_class_ids(upper, map)),
+        )),
+        Type::Wildcard(WildcardBound::Super(lower)) => {
+            Type::Wildcard(WildcardBound::Super(Box::new(remap_class_ids(lower, map))))
+        }
+        Type::Intersection(types) => {
+            Type::Intersection(types.iter().map(|t| remap_class_ids(t, map)).collect())
+        }
+        Type::VirtualInner { owner, name } => Type::VirtualInner {
+            owner: map(*owner),
+            name: name.clone(),
+        },
+        other => other.clone(),
+    }
+}
+
+/// How a class type parameter is used by the class's members (see [`variance_of_type_arg`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variance {
+    /// Only produced (method return types, final fields).
+    Covariant,
+    /// Only consumed (method parameter types).
+    Contravariant,
+    /// Both produced and consumed.
+    Invariant,
+    /// Not used by any member.
+    Bivariant,
+}
+
+impl Variance {
+    fn join(self, other: Variance) -> Variance {
+        match (self, other) {
+            (Variance::Bivariant, v) | (v, Variance::Bivariant) => v,
+            (a, b) if a == b => a,
+            _ => Variance::Invariant,
+        }
+    }
+
+    fn flip(self) -> Variance {
+        match self {
+            Variance::Covariant => Variance::Contravariant,
+            Variance::Contravariant => Variance::Covariant,
+            other => other,
+        }
+    }
+}
+
+/// Best-effort declaration-site variance of the type parameter at `param_idx` of `class_def`,
+/// computed by occurrence analysis over its instance methods and fields.
+///
+/// Occurrences in return types (and final fields) are covariant, occurrences in parameter types
+/// are contravariant, and non-final fields are both. Within a type, array components keep the
+/// position's polarity, `? extends` keeps it, `? super` flips it, and any other type argument
+/// position is treated as invariant.
+pub fn variance_of_type_arg(env: &dyn TypeEnv, class_def: &ClassDef, param_idx: usize) -> Variance {
+    fn occurrences(env: &dyn TypeEnv, ty: &Type, tv: TypeVarId, polarity: Variance) -> Variance {
+        match canonicalize_named(env, ty) {
+            Type::TypeVar(id) if id == tv => polarity,
+            Type::Array(elem) => occurrences(env, &elem, tv, polarity),
+            Type::Class(ClassType { args, .. }) => {
+                args.iter().fold(Variance::Bivariant, |acc, arg| {
+                    let v = match arg {
+                        Type::Wildcard(WildcardBound::Extends(upper)) => {
+                            occurrences(env, upper, tv, polarity)
+                        }
+                        Type::Wildcard(WildcardBound::Super(lower)) => {
+                            occurrences(env, lower, tv, polarity.flip())
+                        }
+                        other => match occurrences(env, other, tv, polarity) {
+                            Variance::Bivariant => Variance::Bivariant,
+                            _ => Variance::Invariant,
+                        },
+                    };
+                    acc.join(v)
+                })
+            }
+            Type::Intersection(parts) => parts.iter().fold(Variance::Bivariant, |acc, part| {
+                acc.join(occurrences(env, part, tv, polarity))
+            }),
+            _ => Variance::Bivariant,
+        }
+    }
+
+    let Some(&tv) = class_def.type_params.get(param_idx) else {
+        return Variance::Bivariant;
+    };
+
+    let mut variance = Variance::Bivariant;
+    for method in class_def.methods.iter().filter(|m| !m.is_static) {
+        variance = variance.join(occurrences(
+            env,
+            &method.return_type,
+            tv,
+            Variance::Covariant,
+        ));
+        for param in &method.params {
+            variance = variance.join(occurrences(env, param, tv, Variance::Contravariant));
+        }
+    }
+    for field in class_def.fields.iter().filter(|f| !f.is_static) {
+        let read = occurrences(env, &field.ty, tv, Variance::Covariant);
+        // Non-final fields are written as well as read.
+        let v = if field.is_final {
+            read
+        } else {
+            read.join(read.flip())
+        };
+        variance = variance.join(v);
+    }
+    variance
+}
+
+/// Substitute `subst` into a type that is *read* from a member (a method return type or a field
+/// type).
+///
+/// When a class type variable is instantiated with a wildcard (e.g. `E` in `List<? extends
+/// Number>` outside of a capturing [`TyContext`]), the wildcard itself is not a valid value type.
+/// Reads are projected to the wildcard's upper bound instead, so `get()` yields `Number`, while
+/// parameter types keep the wildcard and stay uncallable with anything but `null`.
+fn substitute_read_type(env: &dyn TypeEnv, ty: &Type, subst: &TypeSubstitution) -> Type {
+    let out = match ty {
+        Type::TypeVar(id) => match subst.get(*id) {
+            Some(Type::Wildcard(bound)) => wildcard_upper_bound(env, bound),
+            Some(arg) => arg.clone(),
+            None => Type::TypeVar(*id),
+        },
+        Type::Array(elem) => Type::Array(Box::new(substitute_read_type(env, elem, subst))),
+        other => substitute(other, subst),
+    };
+    debug_assert_substitution_well_formed(env, ty, subst, &out);
+    out
+}
+
+pub fn is_assignable(env: &dyn TypeEnv, from: &Type, to: &Type) -> bool {
+    is_assignable_with_reason(env, from, to).is_ok()
+}
+
+/// Why a value of one type cannot be assigned to another (see [`is_assignable_with_reason`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssignabilityError {
+    /// A primitive type does not widen to the target primitive (e.g. `long` -> `int`).
+    PrimitiveNotWidening {
+        from: PrimitiveType,
+        to: PrimitiveType,
+    },
+    /// Neither reference type is a subtype of the other.
+    ReferenceNotSubtype { from: Type, to: Type },
+    /// A primitive value would need boxing into an incompatible reference type (e.g. `int` ->
+    /// `String`).
+    BoxingRequired,
+    /// A reference value would need unboxing that assignment does not allow (e.g. `Long` ->
+    /// `int`, or `String` -> `int`).
+    UnboxingRequired,
+    /// One side is `void`.
+    VoidInvalid,
+    /// The target is a subtype of the source, so an explicit cast is required.
+    NarrowingRequired,
+}
+
+impl fmt::Display for AssignabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignabilityError::PrimitiveNotWidening { from, to } => {
+                write!(f, "{from:?} does not widen to {to:?}")
+            }
+            AssignabilityError::ReferenceNotSubtype { from, to } => {
+                write!(f, "{from:?} is not a subtype of {to:?}")
+            }
+            AssignabilityError::BoxingRequired => f.write_str("incompatible boxing conversion"),
+            AssignabilityError::UnboxingRequired => f.write_str("incompatible unboxing conversion"),
+            AssignabilityError::VoidInvalid => f.write_str("void is not a value type"),
+            AssignabilityError::NarrowingRequired => f.write_str("narrowing conversion required"),
+        }
+    }
+}
+
+impl std::error::Error for AssignabilityError {}
+
+/// Assignment conversion (JLS 5.2) that reports why the conversion failed.
+///
+/// Returns the [`Conversion`] produced by [`assignment_conversion`] on success.
+pub fn is_assignable_with_reason(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+) -> Result<Conversion, AssignabilityError> {
+    if let Some(conv) = assignment_conversion(env, from, to) {
+        return Ok(conv);
+    }
+
+    let from = canonicalize_named(env, from);
+    let to = canonicalize_named(env, to);
+    Err(match (&from, &to) {
+        (Type::Void, _) | (_, Type::Void) => AssignabilityError::VoidInvalid,
+        (Type::Primitive(from), Type::Primitive(to)) => AssignabilityError::PrimitiveNotWidening {
+            from: *from,
+            to: *to,
+        },
+        (Type::Primitive(_), _) => AssignabilityError::BoxingRequired,
+        (_, Type::Primitive(_)) => AssignabilityError::UnboxingRequired,
+        _ if from.is_reference() && to.is_reference() && is_subtype(env, &to, &from) => {
+            AssignabilityError::NarrowingRequired
+        }
+        _ => AssignabilityError::ReferenceNotSubtype { from, to },
+    })
+}
+
+/// A step in a [`TypeDiffEntry::path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypePathStep {
+    /// The type argument at this index of a parameterized type.
+    TypeArg(usize),
+    /// The element type of an array type.
+    ArrayElement,
+}
+
+/// A point where two types diverge (see [`type_diff`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDiffEntry {
+    /// Path from the compared types down to `expected`/`actual`.
+    pub path: Vec<TypePathStep>,
+    pub expected: Type,
+    pub actual: Type,
+}
+
+/// Explain why `actual` is not assignable to `expected` by pinpointing the mismatching parts.
+///
+/// Both types are walked in parallel through class type arguments and array elements; each
+/// branch stops at its first structural divergence. `actual` is viewed as `expected`'s class
+/// first, so `ArrayList<Integer>` vs `List<String>` reports `Integer` vs `String` at
+/// `[TypeArg(0)]`. When no nested divergence explains the mismatch, a single entry with an
+/// empty path is returned. Assignable types yield no entries.
+pub fn type_diff(env: &dyn TypeEnv, expected: &Type, actual: &Type) -> Vec<TypeDiffEntry> {
+    let mut out = Vec::new();
+    if !is_assignable(env, actual, expected) {
+        diff_types(env, expected, actual, &mut Vec::new(), &mut out);
+    }
+    out
+}
+
+fn diff_types(
+    env: &dyn TypeEnv,
+    expected: &Type,
+    actual: &Type,
+    path: &mut Vec<TypePathStep>,
+    out: &mut Vec<TypeDiffEntry>,
+) {
+    let before = out.len();
+    let expected = canonicalize_named(env, expected);
+    let actual = canonicalize_named(env, actual);
+    match (&expected, &actual) {
+        (Type::Class(e), Type::Class(a)) if !e.args.is_empty() => {
+            // View `actual` as `expected`'s class (`ArrayList<Integer>` as `List<Integer>`).
+            let viewed = if e.def == a.def {
+                Some(a.args.clone())
+            } else {
+                instantiate_as(env, a.def, a.args.clone(), e.def)
+            };
+            if let Some(args) = viewed.filter(|args| args.len() == e.args.len()) {
+                for (idx, (e_arg, a_arg)) in e.args.iter().zip(&args).enumerate() {
+                    if type_arg_contained_by(env, a_arg, e_arg) {
+                        continue;
+                    }
+                    path.push(TypePathStep::TypeArg(idx));
+                    diff_types(env, e_arg, a_arg, path, out);
+                    path.pop();
+                }
+            }
+        }
+        (Type::Array(e), Type::Array(a)) => {
+            path.push(TypePathStep::ArrayElement);
+            diff_types(env, e, a, path, out);
+            path.pop();
+        }
+        _ => {}
+    }
+
+    if out.len() == before {
+        out.push(TypeDiffEntry {
+            path: path.clone(),
+            expected,
+            actual,
+        });
+    }
+}
+
+/// The specific obstruction to an assignment, for diagnostics that explain more than
+/// "incompatible types" (see [`explain_assignment_failure`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssignmentObstruction {
+    /// The primitive would have to be narrowed, which takes a cast (`long` -> `int`).
+    PrimitiveNarrowingRequired {
+        from: PrimitiveType,
+        to: PrimitiveType,
+    },
+    /// No conversion exists between the primitives (`boolean` -> `int`), or they are the element
+    /// types of arrays and differ (`int[]` -> `long[]`).
+    IncompatiblePrimitives {
+        from: PrimitiveType,
+        to: PrimitiveType,
+    },
+    /// No conversion exists between the primitive and the reference type (`int` -> `String`).
+    PrimitiveReferenceMismatch { from: Type, to: Type },
+    /// `null` is assigned to a primitive, which would unbox it.
+    RequiresUnboxingOfNull { to: PrimitiveType },
+    /// Neither class extends the other. Each path runs from the class up its superclasses to
+    /// `java.lang.Object`.
+    UnrelatedClassHierarchies {
+        from_path_to_object: Vec<ClassId>,
+        to_path_to_object: Vec<ClassId>,
+    },
+    /// The source is a subtype of the target's generic class but a type argument differs.
+    ///
+    /// `path` holds the type argument indices from the outermost type down to the innermost
+    /// mismatch; its last element is the position of `found`. `variance_hint` is a wildcard
+    /// (`? extends expected` or `? super expected`) that makes the assignment succeed when
+    /// it replaces `expected`.
+    GenericArgumentMismatch {
+        path: Vec<usize>,
+        expected: Type,
+        found: Type,
+        variance_hint: Option<Type>,
+    },
+    /// A type argument at `path` (as in [`AssignmentObstruction::GenericArgumentMismatch`]) is
+    /// the raw form of the class the other side parameterizes (`List<List>` vs
+    /// `List<List<String>>`).
+    RawVsParameterized {
+        path: Vec<usize>,
+        raw: Type,
+        parameterized: Type,
+    },
+    /// The array element types are incompatible, for the nested reason.
+    ArrayElementMismatch(Box<AssignmentObstruction>),
+    /// Assignment fails but a cast to the target type succeeds (`Object` -> `String`).
+    WouldSucceedWithCast { conversion: Conversion },
+    /// The reference types are incompatible for another reason (e.g. a type variable target).
+    NotSubtype { from: Type, to: Type },
+}
+
+/// Explain why a value of type `from` can't be assigned to `to`, or `None` if it can.
+///
+/// Mismatching parameterized types are drilled into: `Map<String, List<Integer>>` vs
+/// `Map<String, List<String>>` reports the `Integer` vs `String` mismatch at path `[1, 0]`.
+pub fn explain_assignment_failure(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+) -> Option<AssignmentObstruction> {
+    if is_assignable(env, from, to) {
+        return None;
+    }
+    let from = canonicalize_named(env, from);
+    let to = canonicalize_named(env, to);
+    Some(assignment_obstruction(env, &from, &to))
+}
+
+fn assignment_obstruction(env: &dyn TypeEnv, from: &Type, to: &Type) -> AssignmentObstruction {
+    match (from, to) {
+        (Type::Primitive(from), Type::Primitive(to)) => {
+            if primitive_narrowing(*from, *to) {
+                AssignmentObstruction::PrimitiveNarrowingRequired {
+                    from: *from,
+                    to: *to,
+                }
+            } else {
+                AssignmentObstruction::IncompatiblePrimitives {
+                    from: *from,
+                    to: *to,
+                }
+            }
+        }
+        (Type::Null, Type::Primitive(to)) => {
+            AssignmentObstruction::RequiresUnboxingOfNull { to: *to }
+        }
+        (Type::Array(from_elem), Type::Array(to_elem)) => {
+            let elem = match (from_elem.as_ref(), to_elem.as_ref()) {
+                (Type::Primitive(from), Type::Primitive(to)) => {
+                    AssignmentObstruction::IncompatiblePrimitives {
+                        from: *from,
+                        to: *to,
+                    }
+                }
+                _ => explain_assignment_failure(env, from_elem, to_elem).unwrap_or_else(|| {
+                    AssignmentObstruction::NotSubtype {
+                        from: from.clone(),
+                        to: to.clone(),
+                    }
+                }),
+            };
+            AssignmentObstruction::ArrayElementMismatch(Box::new(elem))
+        }
+        (Type::Class(found), Type::Class(expected)) => {
+            if is_subtype(env, &erasure(env, from), &erasure(env, to)) {
+                let mut path = Vec::new();
+                if let Some(obstruction) =
+                    type_argument_obstruction(env, from, to, expected, found, &mut path)
+                {
+                    return obstruction;
+                }
+            }
+            if let Some(conversion) = cast_conversion(env, from, to) {
+                return AssignmentObstruction::WouldSucceedWithCast { conversion };
+            }
+            let is_class = |def| env.class(def).is_some_and(|c| c.kind == ClassKind::Class);
+            if is_class(found.def) && is_class(expected.def) {
+                return AssignmentObstruction::UnrelatedClassHierarchies {
+                    from_path_to_object: superclass_path(env, found.def),
+                    to_path_to_object: superclass_path(env, expected.def),
+                };
+            }
+            AssignmentObstruction::NotSubtype {
+                from: from.clone(),
+                to: to.clone(),
+            }
+        }
+        _ => {
+            if let Some(conversion) = cast_conversion(env, from, to) {
+                AssignmentObstruction::WouldSucceedWithCast { conversion }
+            } else if matches!(from, Type::Primitive(_)) || matches!(to, Type::Primitive(_)) {
+                AssignmentObstruction::PrimitiveReferenceMismatch {
+                    from: from.clone(),
+                    to: to.clone(),
+                }
+            } else {
+                AssignmentObstruction::NotSubtype {
+                    from: from.clone(),
+                    to: to.clone(),
+                }
+            }
+        }
+    }
 }
 
-/// Type argument containment (JLS 4.5.1 / 4.10.2).
-///
-/// This is the relation used when comparing two parameterized types with the same
-/// generic class/interface, e.g. `List<? extends String> <: List<? extends Object>`.
-fn type_arg_contained_by(env: &dyn TypeEnv, actual: &Type, formal: &Type) -> bool {
-    match formal {
-        // `?` contains any type argument.
-        Type::Wildcard(WildcardBound::Unbounded) => true,
-
-        // `? extends U` contains:
-        // * `A` if `A <: U`
-        // * `? extends S` if `S <: U`
-        // * `?` as shorthand for `? extends Object` (so only if `Object <: U`)
-        Type::Wildcard(WildcardBound::Extends(upper)) => match actual {
-            Type::Wildcard(WildcardBound::Unbounded) => {
-                let object = Type::class(env.well_known().object, vec![]);
-                is_subtype(env, &object, upper)
-            }
-            Type::Wildcard(WildcardBound::Extends(actual_upper)) => {
-                is_subtype(env, actual_upper, upper)
-            }
-            Type::Wildcard(WildcardBound::Super(_)) => false,
-            other => is_subtype(env, other, upper),
-        },
+/// The innermost type argument of `found` (viewed as `expected`'s class) that `expected`'s
+/// argument does not contain, descending into arguments of the same generic class. `root_from`
+/// and `root_to` are the types of the whole assignment, used to test variance hints.
+fn type_argument_obstruction(
+    env: &dyn TypeEnv,
+    root_from: &Type,
+    root_to: &Type,
+    expected: &ClassType,
+    found: &ClassType,
+    path: &mut Vec<usize>,
+) -> Option<AssignmentObstruction> {
+    let found_args = if found.def == expected.def {
+        found.args.clone()
+    } else {
+        instantiate_as(env, found.def, found.args.clone(), expected.def)?
+    };
+    if found_args.len() != expected.args.len() {
+        return None;
+    }
 
-        // `? super L` contains:
-        // * `A` if `L <: A`
-        // * `? super S` if `L <: S` (contravariant containment)
-        Type::Wildcard(WildcardBound::Super(lower)) => match actual {
-            Type::Wildcard(WildcardBound::Super(actual_lower)) => {
-                is_subtype(env, lower, actual_lower)
+    for (idx, (expected_arg, found_arg)) in expected.args.iter().zip(&found_args).enumerate() {
+        if type_arg_contained_by(env, found_arg, expected_arg) {
+            continue;
+        }
+        path.push(idx);
+        if let (Type::Class(e), Type::Class(f)) = (
+            canonicalize_named(env, expected_arg),
+            canonicalize_named(env, found_arg),
+        ) {
+            if e.def == f.def {
+                if e.args.is_empty() != f.args.is_empty() {
+                    let (raw, parameterized) = if f.args.is_empty() {
+                        (found_arg.clone(), expected_arg.clone())
+                    } else {
+                        (expected_arg.clone(), found_arg.clone())
+                    };
+                    return Some(AssignmentObstruction::RawVsParameterized {
+                        path: path.clone(),
+                        raw,
+                        parameterized,
+                    });
+                }
+                if let Some(inner) =
+                    type_argument_obstruction(env, root_from, root_to, &e, &f, path)
+                {
+                    return Some(inner);
+                }
             }
-            Type::Wildcard(_) => false,
-            other => is_subtype(env, lower, other),
-        },
+        }
 
-        // Non-wildcard type arguments are invariant.
-        _ => actual == formal,
+        let variance_hint = match expected_arg {
+            Type::Wildcard(_) => None,
+            bound => [
+                WildcardBound::Extends(Box::new(bound.clone())),
+                WildcardBound::Super(Box::new(bound.clone())),
+            ]
+            .into_iter()
+            .map(Type::Wildcard)
+            .find(|hint| {
+                replace_type_arg(root_to, path, hint)
+                    .is_some_and(|to| is_assignable(env, root_from, &to))
+            }),
+        };
+        return Some(AssignmentObstruction::GenericArgumentMismatch {
+            path: path.clone(),
+            expected: expected_arg.clone(),
+            found: found_arg.clone(),
+            variance_hint,
+        });
     }
+    None
 }
 
-fn substitute(ty: &Type, subst: &HashMap<TypeVarId, Type>) -> Type {
-    match ty {
-        Type::TypeVar(id) => subst.get(id).cloned().unwrap_or(Type::TypeVar(*id)),
-        Type::Array(elem) => Type::Array(Box::new(substitute(elem, subst))),
-        Type::Class(ClassType { def, args }) => {
-            Type::class(*def, args.iter().map(|a| substitute(a, subst)).collect())
-        }
-        Type::Wildcard(WildcardBound::Unbounded) => Type::Wildcard(WildcardBound::Unbounded),
-        Type::Wildcard(WildcardBound::Extends(upper)) => {
-            Type::Wildcard(WildcardBound::Extends(Box::new(substitute(upper, subst))))
-        }
-        Type::Wildcard(WildcardBound::Super(lower)) => {
-            Type::Wildcard(WildcardBound::Super(Box::new(substitute(lower, subst))))
-        }
-        Type::Intersection(types) => {
-            Type::Intersection(types.iter().map(|t| substitute(t, subst)).collect())
+/// `ty` with the type argument at `path` (indices from the outermost type down) replaced.
+fn replace_type_arg(ty: &Type, path: &[usize], replacement: &Type) -> Option<Type> {
+    let Some((&idx, rest)) = path.split_first() else {
+        return Some(replacement.clone());
+    };
+    let Type::Class(ClassType { def, args }) = ty else {
+        return None;
+    };
+    let mut args = args.clone();
+    let arg = args.get_mut(idx)?;
+    *arg = replace_type_arg(arg, rest, replacement)?;
+    Some(Type::class(*def, args))
+}
+
+/// `def` followed by its superclasses, up to `java.lang.Object`.
+fn superclass_path(env: &dyn TypeEnv, def: ClassId) -> Vec<ClassId> {
+    let mut path = vec![def];
+    let mut current = def;
+    while let Some(Type::Class(ClassType { def: super_def, .. })) = env
+        .class(current)
+        .and_then(|class| class.super_class.as_ref())
+        .map(|super_class| canonicalize_named(env, super_class))
+    {
+        if path.contains(&super_def) {
+            break;
         }
-        other => other.clone(),
+        path.push(super_def);
+        current = super_def;
     }
+    path
 }
 
-pub fn is_assignable(env: &dyn TypeEnv, from: &Type, to: &Type) -> bool {
-    assignment_conversion(env, from, to).is_some()
+/// The kind of a [`TypeMismatchDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeMismatchKind {
+    /// Both types are primitive and the assignment would narrow (`long` -> `int`), or no
+    /// conversion relates them at all (`boolean` -> `int`).
+    PrimitiveNarrowing,
+    /// The source is not a subtype of the reference target, and no more specific kind applies.
+    ReferenceNotSubtype,
+    /// One of the types is `void`, which has no values.
+    VoidNotAllowed,
+    /// A type argument mismatch involves a wildcard (`List<?>` -> `List<String>`).
+    WildcardIncompatible,
+    /// The target is an intersection and the source is not assignable to its component at
+    /// `index`.
+    IntersectionPartFailed { index: usize },
+}
+
+/// A one-line diagnosis of why `from` is not assignable to `to` (see [`describe_type_mismatch`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMismatchDescription {
+    pub from: Type,
+    pub to: Type,
+    pub kind: TypeMismatchKind,
+}
+
+/// Diagnose the failed assignment of `from` to `to`, with the most specific kind that applies.
+///
+/// This is the coarse counterpart of [`explain_assignment_failure`], for diagnostics that only
+/// need a category. The kind given for a pair that [`is_assignable`] accepts is unspecified.
+pub fn describe_type_mismatch(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+) -> TypeMismatchDescription {
+    let kind = if matches!(from, Type::Void) || matches!(to, Type::Void) {
+        TypeMismatchKind::VoidNotAllowed
+    } else if let Some(index) = match to {
+        Type::Intersection(parts) => parts
+            .iter()
+            .position(|part| !is_assignable(env, from, part)),
+        _ => None,
+    } {
+        TypeMismatchKind::IntersectionPartFailed { index }
+    } else {
+        let from = canonicalize_named(env, from);
+        let to = canonicalize_named(env, to);
+        match assignment_obstruction(env, &from, &to) {
+            AssignmentObstruction::PrimitiveNarrowingRequired { .. }
+            | AssignmentObstruction::IncompatiblePrimitives { .. } => {
+                TypeMismatchKind::PrimitiveNarrowing
+            }
+            AssignmentObstruction::GenericArgumentMismatch {
+                expected, found, ..
+            } if matches!(expected, Type::Wildcard(_)) || matches!(found, Type::Wildcard(_)) => {
+                TypeMismatchKind::WildcardIncompatible
+            }
+            _ => TypeMismatchKind::ReferenceNotSubtype,
+        }
+    };
+    TypeMismatchDescription {
+        from: from.clone(),
+        to: to.clone(),
+        kind,
+    }
 }
 
 // === Conversions (JLS 5) =====================================================
@@ -2428,23 +5746,89 @@ pub enum ConstValue {
     Boolean(bool),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Why a conversion or invocation is unchecked.
+///
+/// Each reason maps to exactly one javac `-Xlint` category via [`lint_category`], so callers can
+/// implement `@SuppressWarnings` as a filter over the produced warnings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum UncheckedReason {
+    /// Deprecated catch-all for raw conversions; no longer produced.
+    ///
+    /// Kept so downstream matches keep compiling. New code should handle the precise reasons
+    /// below; this reason maps to [`LintCategory::Unchecked`].
     RawConversion,
+    /// A parameterized type is used through its raw form (e.g. `List<String>` -> `List`, or
+    /// iterating a raw `Iterable`). Reported by javac under `-Xlint:rawtypes`.
+    RawTypeUsage,
+    /// A raw type is converted to a parameterized type in an assignment context
+    /// (`List<String> xs = raw;`).
+    UncheckedAssignment,
+    /// A raw type is converted to a parameterized type when passed as a method argument.
+    UncheckedInvocation,
+    /// A cast whose target type cannot be checked at runtime.
     UncheckedCast,
+    /// Generic array creation for a non-reifiable varargs parameter.
     UncheckedVarargs,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum TypeWarning {
     Unchecked(UncheckedReason),
     /// A static member was accessed via an instance expression (e.g. `obj.f()`).
     ///
     /// Java allows this but compilers typically warn because it is misleading.
     StaticAccessViaInstance,
+    /// A deprecated member is used; see [`deprecation_warning`].
+    DeprecatedMember {
+        for_removal: bool,
+    },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// javac `-Xlint` category that reports a [`TypeWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintCategory {
+    Unchecked,
+    Rawtypes,
+    Static,
+    Deprecation,
+    Removal,
+}
+
+impl LintCategory {
+    /// The name used by `-Xlint:<name>` and `@SuppressWarnings("<name>")`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LintCategory::Unchecked => "unchecked",
+            LintCategory::Rawtypes => "rawtypes",
+            LintCategory::Static => "static",
+            LintCategory::Deprecation => "deprecation",
+            LintCategory::Removal => "removal",
+        }
+    }
+}
+
+pub fn lint_category(warning: &TypeWarning) -> LintCategory {
+    match warning {
+        TypeWarning::Unchecked(UncheckedReason::RawTypeUsage) => LintCategory::Rawtypes,
+        TypeWarning::Unchecked(_) => LintCategory::Unchecked,
+        TypeWarning::StaticAccessViaInstance => LintCategory::Static,
+        TypeWarning::DeprecatedMember { for_removal: false } => LintCategory::Deprecation,
+        TypeWarning::DeprecatedMember { for_removal: true } => LintCategory::Removal,
+    }
+}
+
+/// The severity to report `warning` with.
+///
+/// Uses of members deprecated for removal are warnings; plain deprecations, which javac only
+/// notes unless `-Xlint:deprecation` is on, are reported as weak warnings ([`Severity::Info`]).
+pub fn warning_severity(warning: &TypeWarning) -> Severity {
+    match warning {
+        TypeWarning::DeprecatedMember { for_removal: false } => Severity::Info,
+        _ => Severity::Warning,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum ConversionStep {
     Identity,
     WideningPrimitive,
@@ -2456,10 +5840,14 @@ pub enum ConversionStep {
     Unchecked,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Conversion {
     pub steps: Vec<ConversionStep>,
     pub warnings: Vec<TypeWarning>,
+    /// For a reference conditional argument ([`ArgShape::Conditional`]), the conversions of its
+    /// `then` and `else` branches; `steps` and `warnings` then combine both.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<Conversion>,
 }
 
 impl Conversion {
@@ -2467,6 +5855,7 @@ impl Conversion {
         Self {
             steps: vec![step],
             warnings: Vec::new(),
+            branches: Vec::new(),
         }
     }
 
@@ -2508,6 +5897,212 @@ pub fn binary_numeric_promotion(a: PrimitiveType, b: PrimitiveType) -> Option<Pr
     })
 }
 
+/// A binary operator that [`fold_binary`] can evaluate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    /// `&`, bitwise on integral operands and logical on `boolean` ones.
+    And,
+    /// `|`, bitwise on integral operands and logical on `boolean` ones.
+    Or,
+    /// `^`, bitwise on integral operands and logical on `boolean` ones.
+    Xor,
+    /// `<<`
+    Shl,
+    /// `>>`
+    Shr,
+    /// `>>>`
+    UShr,
+}
+
+/// A unary operator that [`fold_unary`] can evaluate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnOp {
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+    /// `~`
+    BitNot,
+    /// `!`
+    Not,
+}
+
+/// Folds the constant expression `lhs op rhs` (JLS 15.29), given each operand's type and value.
+///
+/// Returns the type of the result and its value. Integral operands go through binary numeric
+/// promotion (only the left operand's unary promotion for shifts), and the arithmetic wraps at
+/// 32 or 64 bits like Java's, so `Integer.MAX_VALUE + 1` folds to `Integer.MIN_VALUE`.
+///
+/// Returns `None` when the expression is not a foldable constant: floating-point operands
+/// (which [`ConstValue`] doesn't model), mismatched operand kinds, or division by zero.
+pub fn fold_binary(
+    op: BinOp,
+    lhs: (PrimitiveType, ConstValue),
+    rhs: (PrimitiveType, ConstValue),
+) -> Option<(PrimitiveType, ConstValue)> {
+    use PrimitiveType::*;
+    match ((lhs.0, lhs.1), (rhs.0, rhs.1)) {
+        ((Boolean, ConstValue::Boolean(a)), (Boolean, ConstValue::Boolean(b))) => {
+            let value = match op {
+                BinOp::And => a & b,
+                BinOp::Or => a | b,
+                BinOp::Xor => a ^ b,
+                _ => return None,
+            };
+            Some((Boolean, ConstValue::Boolean(value)))
+        }
+        ((lhs_ty, ConstValue::Int(a)), (rhs_ty, ConstValue::Int(b))) => {
+            let ty = if matches!(op, BinOp::Shl | BinOp::Shr | BinOp::UShr) {
+                unary_numeric_promotion(rhs_ty)?;
+                unary_numeric_promotion(lhs_ty)?
+            } else {
+                binary_numeric_promotion(lhs_ty, rhs_ty)?
+            };
+            let value = match ty {
+                Int => i64::from(fold_int_binary(op, a as i32, b as i32)?),
+                Long => fold_long_binary(op, a, b)?,
+                _ => return None,
+            };
+            Some((ty, ConstValue::Int(value)))
+        }
+        _ => None,
+    }
+}
+
+fn fold_int_binary(op: BinOp, a: i32, b: i32) -> Option<i32> {
+    Some(match op {
+        BinOp::Add => a.wrapping_add(b),
+        BinOp::Sub => a.wrapping_sub(b),
+        BinOp::Mul => a.wrapping_mul(b),
+        // Division by zero throws, so the expression is not a constant. Java's
+        // `MIN_VALUE / -1 == MIN_VALUE` matches `wrapping_div`.
+        BinOp::Div | BinOp::Rem if b == 0 => return None,
+        BinOp::Div => a.wrapping_div(b),
+        BinOp::Rem => a.wrapping_rem(b),
+        BinOp::And => a & b,
+        BinOp::Or => a | b,
+        BinOp::Xor => a ^ b,
+        // Shift distances use only their low 5 bits (JLS 15.19).
+        BinOp::Shl => a.wrapping_shl(b as u32),
+        BinOp::Shr => a.wrapping_shr(b as u32),
+        BinOp::UShr => (a as u32).wrapping_shr(b as u32) as i32,
+    })
+}
+
+fn fold_long_binary(op: BinOp, a: i64, b: i64) -> Option<i64> {
+    Some(match op {
+        BinOp::Add => a.wrapping_add(b),
+        BinOp::Sub => a.wrapping_sub(b),
+        BinOp::Mul => a.wrapping_mul(b),
+        BinOp::Div | BinOp::Rem if b == 0 => return None,
+        BinOp::Div => a.wrapping_div(b),
+        BinOp::Rem => a.wrapping_rem(b),
+        BinOp::And => a & b,
+        BinOp::Or => a | b,
+        BinOp::Xor => a ^ b,
+        // Shift distances use only their low 6 bits (JLS 15.19).
+        BinOp::Shl => a.wrapping_shl(b as u32),
+        BinOp::Shr => a.wrapping_shr(b as u32),
+        BinOp::UShr => (a as u64).wrapping_shr(b as u32) as i64,
+    })
+}
+
+/// Folds the constant expression `op operand` (JLS 15.29), given the operand's type and value.
+///
+/// Integral operands go through unary numeric promotion and wrap like Java's arithmetic, so
+/// `-Integer.MIN_VALUE` folds to `Integer.MIN_VALUE`. Returns `None` for operators that don't
+/// apply to the operand and for floating-point operands.
+pub fn fold_unary(
+    op: UnOp,
+    operand: (PrimitiveType, ConstValue),
+) -> Option<(PrimitiveType, ConstValue)> {
+    use PrimitiveType::*;
+    match operand {
+        (Boolean, ConstValue::Boolean(value)) => {
+            (op == UnOp::Not).then_some((Boolean, ConstValue::Boolean(!value)))
+        }
+        (ty, ConstValue::Int(value)) => {
+            let ty = unary_numeric_promotion(ty)?;
+            let value = match (ty, op) {
+                (Int, UnOp::Plus) => i64::from(value as i32),
+                (Int, UnOp::Minus) => i64::from((value as i32).wrapping_neg()),
+                (Int, UnOp::BitNot) => i64::from(!(value as i32)),
+                (Long, UnOp::Plus) => value,
+                (Long, UnOp::Minus) => value.wrapping_neg(),
+                (Long, UnOp::BitNot) => !value,
+                _ => return None,
+            };
+            Some((ty, ConstValue::Int(value)))
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether `lhs` and `rhs` are valid operands for the numerical comparison operators
+/// `<`, `>`, `<=`, and `>=` (JLS 15.20.1).
+///
+/// Both operands must be convertible to a primitive numeric type, possibly via unboxing.
+pub fn is_numeric_comparison_valid(env: &dyn TypeEnv, lhs: &Type, rhs: &Type) -> bool {
+    if lhs.is_errorish() || rhs.is_errorish() {
+        return true;
+    }
+    numeric_operand_type(env, lhs).is_some() && numeric_operand_type(env, rhs).is_some()
+}
+
+/// Returns whether `lhs` and `rhs` are valid operands for the equality operators `==` and `!=`
+/// (JLS 15.21).
+///
+/// The operands must either be numeric (at least one of them primitive, the other possibly
+/// unboxed), boolean (at least one of them primitive, the other possibly unboxed), or both
+/// reference/null types where one can be cast to the other.
+pub fn is_equality_comparison_valid(env: &dyn TypeEnv, lhs: &Type, rhs: &Type) -> bool {
+    if lhs.is_errorish() || rhs.is_errorish() {
+        return true;
+    }
+
+    let lhs = canonicalize_named(env, lhs);
+    let rhs = canonicalize_named(env, rhs);
+
+    match (&lhs, &rhs) {
+        (Type::Void, _) | (_, Type::Void) => false,
+        // JLS 15.21.1 / 15.21.2: at least one operand is primitive; the other must be
+        // convertible (possibly via unboxing) to the same category.
+        (Type::Primitive(_), _) | (_, Type::Primitive(_)) => {
+            let numeric = numeric_operand_type(env, &lhs).is_some()
+                && numeric_operand_type(env, &rhs).is_some();
+            let boolean = boolean_operand(env, &lhs) && boolean_operand(env, &rhs);
+            numeric || boolean
+        }
+        // JLS 15.21.3: reference equality.
+        (Type::Null, other) | (other, Type::Null) => {
+            other.is_reference() || matches!(other, Type::Null)
+        }
+        (a, b) if a.is_reference() && b.is_reference() => {
+            cast_conversion(env, a, b).is_some() || cast_conversion(env, b, a).is_some()
+        }
+        _ => false,
+    }
+}
+
+fn numeric_operand_type(env: &dyn TypeEnv, ty: &Type) -> Option<PrimitiveType> {
+    match canonicalize_named(env, ty) {
+        Type::Primitive(p) => p.is_numeric().then_some(p),
+        other => unbox(env, &other).filter(|p| p.is_numeric()),
+    }
+}
+
+fn boolean_operand(env: &dyn TypeEnv, ty: &Type) -> bool {
+    match canonicalize_named(env, ty) {
+        Type::Primitive(p) => p == PrimitiveType::Boolean,
+        other => unbox(env, &other) == Some(PrimitiveType::Boolean),
+    }
+}
+
 fn primitive_narrowing(from: PrimitiveType, to: PrimitiveType) -> bool {
     if from == to {
         return true;
@@ -2521,6 +6116,17 @@ pub fn strict_method_invocation_conversion(
     env: &dyn TypeEnv,
     from: &Type,
     to: &Type,
+) -> Option<Conversion> {
+    strict_conversion_in(env, from, to, UncheckedReason::UncheckedInvocation)
+}
+
+/// Strict conversion shared by invocation and assignment contexts; `unchecked` is the reason
+/// reported for raw -> parameterized conversions in that context.
+fn strict_conversion_in(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+    unchecked: UncheckedReason,
 ) -> Option<Conversion> {
     let from = canonicalize_named(env, from);
     let to = canonicalize_named(env, to);
@@ -2538,9 +6144,8 @@ pub fn strict_method_invocation_conversion(
         }
         (a, b) if a.is_reference() && b.is_reference() && is_subtype(env, a, b) => {
             let mut conv = Conversion::new(ConversionStep::WideningReference);
-            if raw_warning(env, a, b) {
-                conv.warnings
-                    .push(TypeWarning::Unchecked(UncheckedReason::RawConversion));
+            if let Some(reason) = raw_warning(env, a, b, unchecked) {
+                conv.warnings.push(TypeWarning::Unchecked(reason));
             }
             Some(conv)
         }
@@ -2555,6 +6160,139 @@ pub fn method_invocation_conversion(
     from: &Type,
     to: &Type,
 ) -> Option<Conversion> {
+    loose_conversion_in(env, from, to, ConversionContext::Invocation)
+}
+
+/// The outcome of [`args_assignable`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgsMatch {
+    /// Every argument converts to its parameter; `conversions` is parallel to the arguments.
+    Applicable {
+        conversions: Vec<Conversion>,
+        /// Whether the trailing arguments were matched against the vararg element type rather
+        /// than passed as the array itself.
+        varargs_expanded: bool,
+    },
+    /// The argument count does not fit the parameter list. For varargs parameter lists
+    /// `expected` is the number of fixed parameters (the minimum arity).
+    WrongArity {
+        expected: usize,
+        found: usize,
+        is_varargs: bool,
+    },
+    /// The argument at `index` does not convert to its parameter type `to`.
+    ArgumentConversion { index: usize, from: Type, to: Type },
+}
+
+impl ArgsMatch {
+    pub fn is_applicable(&self) -> bool {
+        matches!(self, ArgsMatch::Applicable { .. })
+    }
+
+    /// The index of the first argument (or missing argument) that does not match.
+    pub fn first_failing_index(&self) -> Option<usize> {
+        match self {
+            ArgsMatch::Applicable { .. } => None,
+            ArgsMatch::WrongArity {
+                expected, found, ..
+            } => Some((*expected).min(*found)),
+            ArgsMatch::ArgumentConversion { index, .. } => Some(*index),
+        }
+    }
+}
+
+/// Checks `args` against a known parameter list using method invocation conversion (JLS 5.3).
+///
+/// For varargs parameter lists an argument list of the same length is first tried with the
+/// array passed as-is (JLS 15.12.2.2/3), then with variable-arity expansion (JLS 15.12.2.4, see
+/// [`MethodDef::param_types_for_arity`]). A mismatch is reported against the expanded form.
+pub fn args_assignable(
+    env: &dyn TypeEnv,
+    args: &[Type],
+    params: &[Type],
+    is_varargs: bool,
+) -> ArgsMatch {
+    if is_varargs && args.len() == params.len() {
+        if let Ok(conversions) = convert_args(env, args, params) {
+            return ArgsMatch::Applicable {
+                conversions,
+                varargs_expanded: false,
+            };
+        }
+    }
+
+    let Some(expanded) = expand_params_for_arity(params, is_varargs, args.len()) else {
+        return ArgsMatch::WrongArity {
+            expected: if is_varargs {
+                params.len().saturating_sub(1)
+            } else {
+                params.len()
+            },
+            found: args.len(),
+            is_varargs,
+        };
+    };
+    match convert_args(env, args, &expanded) {
+        Ok(conversions) => ArgsMatch::Applicable {
+            conversions,
+            varargs_expanded: is_varargs,
+        },
+        Err(index) => ArgsMatch::ArgumentConversion {
+            index,
+            from: args[index].clone(),
+            to: expanded[index].clone(),
+        },
+    }
+}
+
+/// Converts each argument to the parameter at the same index, or returns the first failing index.
+fn convert_args(
+    env: &dyn TypeEnv,
+    args: &[Type],
+    params: &[Type],
+) -> Result<Vec<Conversion>, usize> {
+    args.iter()
+        .zip(params)
+        .enumerate()
+        .map(|(idx, (arg, param))| method_invocation_conversion(env, arg, param).ok_or(idx))
+        .collect()
+}
+
+/// The conversion context a loose conversion is checked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConversionContext {
+    Invocation,
+    Assignment,
+    Cast,
+}
+
+impl ConversionContext {
+    fn unchecked_reason(self) -> UncheckedReason {
+        match self {
+            ConversionContext::Invocation => UncheckedReason::UncheckedInvocation,
+            ConversionContext::Assignment => UncheckedReason::UncheckedAssignment,
+            ConversionContext::Cast => UncheckedReason::UncheckedCast,
+        }
+    }
+
+    /// Whether a primitive may be widened before boxing (`int` -> `long` -> `Long`).
+    ///
+    /// JLS 5.2 never allows this in assignment contexts (`Long l = 1;` is rejected). Invocation
+    /// and cast contexts keep accepting it for best-effort overload resolution.
+    fn allows_widening_then_boxing(self) -> bool {
+        !matches!(self, ConversionContext::Assignment)
+    }
+}
+
+/// Loose conversion shared by invocation, assignment and cast contexts (see
+/// [`strict_conversion_in`]).
+fn loose_conversion_in(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+    context: ConversionContext,
+) -> Option<Conversion> {
+    let unchecked = context.unchecked_reason();
     let from = canonicalize_named(env, from);
     let to = canonicalize_named(env, to);
 
@@ -2565,7 +6303,7 @@ pub fn method_invocation_conversion(
         return Some(Conversion::new(ConversionStep::Identity));
     }
 
-    if let Some(conv) = strict_method_invocation_conversion(env, &from, &to) {
+    if let Some(conv) = strict_conversion_in(env, &from, &to, unchecked.clone()) {
         return Some(conv);
     }
 
@@ -2578,16 +6316,15 @@ pub fn method_invocation_conversion(
             if boxed.is_reference() && to.is_reference() && is_subtype(env, &boxed, &to) {
                 let mut conv = Conversion::new(ConversionStep::Boxing)
                     .push_step(ConversionStep::WideningReference);
-                if raw_warning(env, &boxed, &to) {
-                    conv.warnings
-                        .push(TypeWarning::Unchecked(UncheckedReason::RawConversion));
+                if let Some(reason) = raw_warning(env, &boxed, &to, unchecked.clone()) {
+                    conv.warnings.push(TypeWarning::Unchecked(reason));
                 }
                 return Some(conv);
             }
         }
 
         // Widening primitive conversion followed by boxing (e.g. `int` -> `long` -> `Long`).
-        if to.is_reference() {
+        if to.is_reference() && context.allows_widening_then_boxing() {
             let numeric_targets = [
                 PrimitiveType::Byte,
                 PrimitiveType::Short,
@@ -2617,9 +6354,8 @@ pub fn method_invocation_conversion(
                     let mut conv = Conversion::new(ConversionStep::WideningPrimitive)
                         .push_step(ConversionStep::Boxing)
                         .push_step(ConversionStep::WideningReference);
-                    if raw_warning(env, &boxed, &to) {
-                        conv.warnings
-                            .push(TypeWarning::Unchecked(UncheckedReason::RawConversion));
+                    if let Some(reason) = raw_warning(env, &boxed, &to, unchecked.clone()) {
+                        conv.warnings.push(TypeWarning::Unchecked(reason));
                     }
                     return Some(conv);
                 }
@@ -2643,7 +6379,7 @@ pub fn method_invocation_conversion(
     }
 
     // Unchecked conversion involving raw types.
-    if let Some(conv) = unchecked_raw_conversion(env, &from, &to) {
+    if let Some(conv) = unchecked_raw_conversion(env, &from, &to, unchecked) {
         return Some(conv);
     }
 
@@ -2657,15 +6393,19 @@ pub fn assignment_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Optio
 
 /// Assignment conversion (JLS 5.2) with an optional compile-time constant value.
 ///
-/// This extends [`method_invocation_conversion`] with *constant narrowing*
-/// conversions (e.g. `byte b = 1;`).
+/// Compared to [`method_invocation_conversion`], a primitive is only ever boxed to its own
+/// wrapper (`Long l = 1;` is rejected), and constant expressions of type `byte`, `short`, `char`
+/// or `int` additionally allow:
+/// - narrowing to `byte`/`short`/`char` when the value is representable (`byte b = 1;`)
+/// - conversion to `Byte`/`Short`/`Character` when the value is representable in the unboxed
+///   type (`Character c = 65;`, `Short s = (byte) 1;`)
 pub fn assignment_conversion_with_const(
     env: &dyn TypeEnv,
     from: &Type,
     to: &Type,
     const_value: Option<ConstValue>,
 ) -> Option<Conversion> {
-    if let Some(conv) = method_invocation_conversion(env, from, to) {
+    if let Some(conv) = loose_conversion_in(env, from, to, ConversionContext::Assignment) {
         return Some(conv);
     }
 
@@ -2685,24 +6425,34 @@ fn constant_narrowing_conversion(
     let from = canonicalize_named(env, from);
     let to = canonicalize_named(env, to);
 
-    let (Type::Primitive(from_p), Type::Primitive(to_p)) = (&from, &to) else {
-        return None;
-    };
-
-    // JLS 5.2: allow narrowing for constant expressions of type byte/short/char/int
-    // to byte/short/char when the value is representable.
+    // JLS 5.2: constant expressions of type byte/short/char/int may be narrowed to
+    // byte/short/char (and boxed to Byte/Short/Character) when the value is representable.
     use PrimitiveType::*;
-    if !matches!(*from_p, Byte | Short | Char | Int) {
+    let Type::Primitive(from_p) = from else {
         return None;
-    }
-    if !matches!(*to_p, Byte | Short | Char) {
+    };
+    if !matches!(from_p, Byte | Short | Char | Int) {
         return None;
     }
-    if !value_representable_in_primitive(value, *to_p) {
+
+    let (to_p, boxed) = match &to {
+        Type::Primitive(p) => (*p, false),
+        other => (unbox(env, other)?, true),
+    };
+    if !matches!(to_p, Byte | Short | Char) || !value_representable_in_primitive(value, to_p) {
         return None;
     }
 
-    Some(Conversion::new(ConversionStep::NarrowingPrimitive))
+    // Identity, widening and exact boxing were already accepted by the loose conversion.
+    if !boxed {
+        return Some(Conversion::new(ConversionStep::NarrowingPrimitive));
+    }
+    let first = if primitive_widening(from_p, to_p) {
+        ConversionStep::WideningPrimitive
+    } else {
+        ConversionStep::NarrowingPrimitive
+    };
+    Some(Conversion::new(first).push_step(ConversionStep::Boxing))
 }
 
 fn value_representable_in_primitive(value: i64, ty: PrimitiveType) -> bool {
@@ -2722,7 +6472,7 @@ pub fn cast_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Option<Conv
     let from = canonicalize_named(env, from);
     let to = canonicalize_named(env, to);
 
-    if let Some(conv) = assignment_conversion(env, &from, &to) {
+    if let Some(conv) = loose_conversion_in(env, &from, &to, ConversionContext::Cast) {
         return Some(conv);
     }
 
@@ -2752,9 +6502,8 @@ pub fn cast_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Option<Conv
             Castability::No => {}
             castability => {
                 let mut conv = Conversion::new(ConversionStep::NarrowingReference);
-                if raw_warning(env, &from, &to) {
-                    conv.warnings
-                        .push(TypeWarning::Unchecked(UncheckedReason::RawConversion));
+                if let Some(reason) = raw_warning(env, &from, &to, UncheckedReason::UncheckedCast) {
+                    conv.warnings.push(TypeWarning::Unchecked(reason));
                 } else if castability == Castability::Uncertain || !is_reifiable(env, &to) {
                     conv.warnings
                         .push(TypeWarning::Unchecked(UncheckedReason::UncheckedCast));
@@ -2810,9 +6559,8 @@ fn unbox(env: &dyn TypeEnv, from: &Type) -> Option<PrimitiveType> {
         Type::Class(ClassType { def, .. }) => {
             env.class(*def).and_then(|c| unbox_class_name(&c.name))
         }
-        Type::TypeVar(id) => env
-            .type_param(*id)
-            .and_then(|tp| tp.upper_bounds.first())
+        Type::TypeVar(id) => type_var_resolved_bounds(env, *id)
+            .first()
             .and_then(|b| unbox(env, b)),
         _ => None,
     }
@@ -2836,7 +6584,17 @@ fn is_raw_class(env: &dyn TypeEnv, def: ClassId, args: &[Type]) -> bool {
     args.is_empty() && env.class(def).is_some_and(|c| !c.type_params.is_empty())
 }
 
-fn raw_warning(env: &dyn TypeEnv, from: &Type, to: &Type) -> bool {
+/// Classify a conversion between a raw and a parameterized type.
+///
+/// Converting a raw type to a parameterized one is unchecked and reported as `unchecked` (the
+/// caller's context-specific reason), while widening a parameterized type to its raw form only
+/// exposes raw type usage ([`UncheckedReason::RawTypeUsage`]).
+fn raw_warning(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+    unchecked: UncheckedReason,
+) -> Option<UncheckedReason> {
     let (
         Type::Class(ClassType {
             def: f_def,
@@ -2848,16 +6606,27 @@ fn raw_warning(env: &dyn TypeEnv, from: &Type, to: &Type) -> bool {
         }),
     ) = (from, to)
     else {
-        return false;
+        return None;
     };
     let from_raw = is_raw_class(env, *f_def, f_args);
     let to_raw = is_raw_class(env, *t_def, t_args);
     let from_param = !from_raw && !f_args.is_empty();
     let to_param = !to_raw && !t_args.is_empty();
-    (from_raw && to_param) || (to_raw && from_param)
+    if from_raw && to_param {
+        Some(unchecked)
+    } else if to_raw && from_param {
+        Some(UncheckedReason::RawTypeUsage)
+    } else {
+        None
+    }
 }
 
-fn unchecked_raw_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Option<Conversion> {
+fn unchecked_raw_conversion(
+    env: &dyn TypeEnv,
+    from: &Type,
+    to: &Type,
+    unchecked: UncheckedReason,
+) -> Option<Conversion> {
     let (
         Type::Class(ClassType {
             def: f_def,
@@ -2881,7 +6650,7 @@ fn unchecked_raw_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Option
         if is_subtype(env, &from_er, &to_er) {
             return Some(
                 Conversion::new(ConversionStep::Unchecked)
-                    .push_warning(TypeWarning::Unchecked(UncheckedReason::RawConversion)),
+                    .push_warning(TypeWarning::Unchecked(unchecked)),
             );
         }
     }
@@ -2890,7 +6659,7 @@ fn unchecked_raw_conversion(env: &dyn TypeEnv, from: &Type, to: &Type) -> Option
     if !from_raw && !f_args.is_empty() && to_raw && is_subtype(env, from, to) {
         return Some(
             Conversion::new(ConversionStep::WideningReference)
-                .push_warning(TypeWarning::Unchecked(UncheckedReason::RawConversion)),
+                .push_warning(TypeWarning::Unchecked(UncheckedReason::RawTypeUsage)),
         );
     }
 
@@ -2901,11 +6670,10 @@ fn erasure(env: &dyn TypeEnv, ty: &Type) -> Type {
     match ty {
         Type::Class(ClassType { def, .. }) => Type::class(*def, vec![]),
         Type::Array(elem) => Type::Array(Box::new(erasure(env, elem))),
-        Type::TypeVar(id) => env
-            .type_param(*id)
-            .and_then(|tp| tp.upper_bounds.first().cloned())
-            .map(|b| erasure(env, &b))
-            .unwrap_or_else(|| Type::class(env.well_known().object, vec![])),
+        Type::TypeVar(id) => match type_var_resolved_bounds(env, *id).first() {
+            Some(bound) => erasure(env, bound),
+            None => Type::class(env.well_known().object, vec![]),
+        },
         Type::Intersection(types) => types
             .first()
             .map(|t| erasure(env, t))
@@ -2919,6 +6687,47 @@ fn erasure(env: &dyn TypeEnv, ty: &Type) -> Type {
     }
 }
 
+/// Whether `a` and `b` have the same name and pairwise-equal erased parameter types (JLS 8.4.2).
+///
+/// Used for override checking and bridge method detection: `List.add(E)` and its bridge
+/// `add(Object)` have the same erasure.
+pub fn is_same_erasure(env: &dyn TypeEnv, a: &MethodDef, b: &MethodDef) -> bool {
+    a.name == b.name
+        && a.params.len() == b.params.len()
+        && a.params
+            .iter()
+            .zip(&b.params)
+            .all(|(pa, pb)| erasure(env, pa) == erasure(env, pb))
+}
+
+/// The erased return type of `method`.
+pub fn erased_return_type(env: &dyn TypeEnv, method: &MethodDef) -> Type {
+    erasure(env, &method.return_type)
+}
+
+/// Whether a method returning `sub_return` may override one returning `super_return`
+/// (return-type-substitutability, JLS 8.4.8.3).
+///
+/// Stricter than [`is_subtype`]: `void` and primitive returns must match exactly (no widening or
+/// boxing), so array covariance only applies to reference element types. A raw `sub_return` may
+/// override its parameterization (`List` for `List<String>`, an unchecked override).
+pub fn is_subtype_for_overriding(
+    env: &dyn TypeEnv,
+    sub_return: &Type,
+    super_return: &Type,
+) -> bool {
+    if sub_return.is_errorish() || super_return.is_errorish() {
+        return true;
+    }
+
+    match (sub_return, super_return) {
+        (Type::Void, _) | (_, Type::Void) | (Type::Primitive(_), _) | (_, Type::Primitive(_)) => {
+            sub_return == super_return
+        }
+        _ => is_subtype(env, sub_return, super_return) || *sub_return == erasure(env, super_return),
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Castability {
     Yes,
@@ -3059,6 +6868,36 @@ fn type_arg_upper_bound_for_lub(env: &dyn TypeEnv, ty: &Type) -> Type {
     }
 }
 
+/// The effective upper bound of `ty`.
+///
+/// Wildcards yield their `extends` bound (`Object` when there is none), type variables yield the
+/// intersection of their declared upper bounds (`Object` when undeclared), and every other type is
+/// its own upper bound.
+pub fn upper_bound_of_type(env: &dyn TypeEnv, ty: &Type) -> Type {
+    match ty {
+        Type::Wildcard(bound) => wildcard_upper_bound(env, bound),
+        Type::TypeVar(id) => match env.type_param(*id) {
+            Some(tp) => match tp.upper_bounds.as_slice() {
+                [] => Type::class(env.well_known().object, vec![]),
+                [bound] => bound.clone(),
+                bounds => make_intersection(env, bounds.to_vec()),
+            },
+            None => ty.clone(),
+        },
+        other => other.clone(),
+    }
+}
+
+/// The lower bound of `ty`, if it has one: the bound of a `? super T` wildcard or of a captured
+/// type variable.
+pub fn lower_bound_of_type(env: &dyn TypeEnv, ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Wildcard(bound) => bound.lower().cloned(),
+        Type::TypeVar(id) => env.type_param(*id)?.lower_bound.clone(),
+        _ => None,
+    }
+}
+
 fn canonicalize_for_lub(env: &dyn TypeEnv, ty: &Type) -> Type {
     match ty {
         Type::Named(name) => env
@@ -3159,6 +6998,24 @@ fn type_sort_key(env: &dyn TypeEnv, ty: &Type) -> String {
     }
 }
 
+/// Normalize the intersection of `types`, as for the intersections this crate builds itself.
+///
+/// Nested intersections are flattened, duplicates and components implied by a more specific one
+/// are dropped (`ArrayList<String> & List<String>` is `ArrayList<String>`, `String & Object` is
+/// `String`), and the remaining components are sorted deterministically. A single remaining
+/// component is returned as is, and no components yield an empty [`Type::Intersection`].
+pub fn normalize_intersection(env: &dyn TypeEnv, types: Vec<Type>) -> Type {
+    make_intersection(env, types)
+}
+
+/// The components of `ty` if it is an intersection, otherwise `ty` alone.
+pub fn intersection_components(ty: &Type) -> &[Type] {
+    match ty {
+        Type::Intersection(parts) => parts,
+        other => std::slice::from_ref(other),
+    }
+}
+
 fn make_intersection(env: &dyn TypeEnv, types: Vec<Type>) -> Type {
     // Flatten all nested intersection components.
     let mut flat = Vec::new();
@@ -3178,11 +7035,25 @@ fn make_intersection(env: &dyn TypeEnv, types: Vec<Type>) -> Type {
         }
     }
 
+    // `Object` is redundant next to any other component, even one the subtyping below cannot
+    // relate to it (e.g. a class missing from the environment).
+    if uniq.iter().any(|t| !is_object_class(env, t)) {
+        uniq.retain(|t| !is_object_class(env, t));
+    }
+
     if uniq.is_empty() {
         return Type::Intersection(Vec::new());
     }
 
-    uniq.sort_by_cached_key(|ty| (intersection_component_rank(env, ty), type_sort_key(env, ty)));
+    // Distinct types can share a sort key (`T[]` and `(T)[]`, or two classes with the same
+    // name in an environment being edited); their structure breaks the tie.
+    uniq.sort_by_cached_key(|ty| {
+        (
+            intersection_component_rank(env, ty),
+            type_sort_key(env, ty),
+            format!("{ty:?}"),
+        )
+    });
 
     // Prune redundant supertypes (e.g. `ArrayList & List` => `ArrayList`), while
     // remaining deterministic in the face of our best-effort subtyping relation
@@ -3236,7 +7107,7 @@ fn lub_same_generic_class(
 
         let a_bound = type_arg_upper_bound_for_lub(env, a);
         let b_bound = type_arg_upper_bound_for_lub(env, b);
-        let bound_lub = lub(env, &a_bound, &b_bound);
+        let bound_lub = nested_lub(env, &a_bound, &b_bound);
         if is_object_class(env, &bound_lub) {
             out_args.push(Type::Wildcard(WildcardBound::Unbounded));
         } else {
@@ -3247,6 +7118,35 @@ fn lub_same_generic_class(
     Type::class(def, out_args)
 }
 
+/// How many type argument levels deep [`lub`] computes the arguments of a generic common
+/// supertype.
+///
+/// The lub of F-bounded types is infinite (JLS 4.10.4): `lub(Integer, String)` includes
+/// `Comparable<? extends lub(Integer, String)>`. Past this depth the argument is given up as `?`,
+/// which also bounds the work on the expansive hierarchies of code being edited.
+const MAX_LUB_NESTING: u32 = 3;
+
+thread_local! {
+    static LUB_NESTING: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+}
+
+/// [`lub`] of the type argument bounds `a` and `b`, or `Object` past [`MAX_LUB_NESTING`].
+fn nested_lub(env: &dyn TypeEnv, a: &Type, b: &Type) -> Type {
+    struct Nesting;
+    impl Drop for Nesting {
+        fn drop(&mut self) {
+            LUB_NESTING.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    if LUB_NESTING.with(|depth| depth.get()) >= MAX_LUB_NESTING {
+        return Type::class(env.well_known().object, vec![]);
+    }
+    LUB_NESTING.with(|depth| depth.set(depth.get() + 1));
+    let _nesting = Nesting;
+    lub(env, a, b)
+}
+
 fn collect_class_supertypes(
     env: &dyn TypeEnv,
     start_def: ClassId,
@@ -3254,14 +7154,14 @@ fn collect_class_supertypes(
 ) -> HashMap<ClassId, Type> {
     let mut bucket: HashMap<ClassId, Vec<Type>> = HashMap::new();
     let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
+    let mut seen = VisitedSupertypes::default();
     queue.push_back(Type::class(start_def, start_args));
 
     while let Some(current) = queue.pop_front() {
         let Type::Class(ClassType { def, args }) = current.clone() else {
             continue;
         };
-        if !seen.insert((def, args.clone())) {
+        if !seen.insert(def, &args) {
             continue;
         }
 
@@ -3275,12 +7175,7 @@ fn collect_class_supertypes(
         };
 
         let raw = is_raw_class(env, def, &args);
-        let subst = class_def
-            .type_params
-            .iter()
-            .copied()
-            .zip(args.into_iter())
-            .collect::<HashMap<_, _>>();
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
 
         if let Some(sc) = &class_def.super_class {
             let next = substitute(sc, &subst);
@@ -3392,15 +7287,13 @@ fn collect_supertypes_for_lub(env: &dyn TypeEnv, ty: &Type) -> HashMap<ClassId,
         }
         Type::TypeVar(id) => {
             let mut out = HashMap::new();
-            if let Some(tp) = env.type_param(*id) {
-                // Sort to avoid depending on how bounds are collected (and because bound order
-                // is not semantically meaningful for LUB computation).
-                let mut bounds: Vec<&Type> = tp.upper_bounds.iter().collect();
-                bounds.sort_by_cached_key(|t| type_sort_key(env, t));
-                for ub in bounds {
-                    let ub = canonicalize_for_lub(env, ub);
-                    merge_supertype_maps(env, &mut out, collect_supertypes_for_lub(env, &ub));
-                }
+            // Sort to avoid depending on how bounds are collected (and because bound order
+            // is not semantically meaningful for LUB computation).
+            let mut bounds = type_var_resolved_bounds(env, *id);
+            bounds.sort_by_cached_key(|t| type_sort_key(env, t));
+            for ub in bounds {
+                let ub = canonicalize_for_lub(env, ub);
+                merge_supertype_maps(env, &mut out, collect_supertypes_for_lub(env, &ub));
             }
             out.insert(env.well_known().object, object);
             out
@@ -3579,6 +7472,32 @@ pub fn lub(env: &dyn TypeEnv, a: &Type, b: &Type) -> Type {
     }
 }
 
+/// [`lub`] of all of `tys` (JLS 4.10.4 `lub(U1, ..., Uk)`), independent of their order.
+///
+/// No types give `Object`.
+pub fn multi_lub(env: &dyn TypeEnv, tys: &[Type]) -> Type {
+    lub_all(env, tys, &Type::class(env.well_known().object, vec![]))
+}
+
+/// The return type of a method or lambda body from the types of its `return` statements, with
+/// [`Type::Void`] standing for a bare `return;`.
+///
+/// A body without value returns returns `void`, and one mixing bare and value returns is an
+/// error ([`Type::Error`]). Otherwise the result is the [`multi_lub`] of the returned types.
+pub fn infer_return_type_from_body(env: &dyn TypeEnv, return_types: &[Type]) -> Type {
+    let voids = return_types
+        .iter()
+        .filter(|ty| matches!(ty, Type::Void))
+        .count();
+    if voids == return_types.len() {
+        Type::Void
+    } else if voids > 0 {
+        Type::Error
+    } else {
+        multi_lub(env, return_types)
+    }
+}
+
 fn glb(env: &dyn TypeEnv, a: &Type, b: &Type) -> Type {
     // Preserve exact equality (including unresolved `Named` types).
     if a == b {
@@ -3632,7 +7551,7 @@ pub fn resolve_field(
     }
 
     let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
+    let mut seen = VisitedSupertypes::default();
     match receiver {
         Type::Intersection(types) => {
             // Ensure intersection receivers are deterministic for member access. This keeps field
@@ -3675,6 +7594,9 @@ pub fn resolve_field(
         }
         Type::Class(_) => queue.push_back(receiver),
         Type::Array(_) => queue.push_back(Type::class(env.well_known().object, vec![])),
+        Type::Wildcard(bound) => {
+            return resolve_field(env, &wildcard_upper_bound(env, &bound), name, call_kind);
+        }
         _ => return None,
     }
 
@@ -3682,19 +7604,14 @@ pub fn resolve_field(
         let Type::Class(ClassType { def, args }) = current.clone() else {
             continue;
         };
-        if !seen.insert((def, args.clone())) {
+        if !seen.insert(def, &args) {
             continue;
         }
 
         let Some(class_def) = env.class(def) else {
             continue;
         };
-        let subst = class_def
-            .type_params
-            .iter()
-            .copied()
-            .zip(args.iter().cloned())
-            .collect::<HashMap<_, _>>();
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
 
         for field in &class_def.fields {
             if field.name != name {
@@ -3714,9 +7631,10 @@ pub fn resolve_field(
 
             return Some(FieldDef {
                 name: field.name.clone(),
-                ty: substitute(&field.ty, &subst),
+                ty: substitute_read_type(env, &field.ty, &subst),
                 is_static: field.is_static,
                 is_final: field.is_final,
+                deprecation: None,
             });
         }
 
@@ -3743,6 +7661,191 @@ pub fn resolve_field(
     None
 }
 
+/// A field found by [`resolve_field_with_owner`], with the class that declares it.
+#[derive(Debug, Clone)]
+pub struct ResolvedField {
+    /// The field, with its type as seen through the receiver.
+    pub field: FieldDef,
+    /// The class or interface declaring the field.
+    pub owner: ClassId,
+    /// The field is inherited rather than declared by the receiver's class, as for
+    /// `MyImpl.BUFFER_SIZE` with `BUFFER_SIZE` declared by an interface `MyImpl` implements. A
+    /// quick-fix can offer to qualify the access with `owner` instead.
+    pub inherited: bool,
+}
+
+/// The outcome of [`resolve_field_with_owner`].
+#[derive(Debug, Clone)]
+pub enum FieldResolution {
+    Found(ResolvedField),
+    /// Distinct fields of the name are inherited from several supertypes, and no declaration
+    /// closer to the receiver hides them (javac: "reference to X is ambiguous"). The candidates
+    /// are sorted by the name of their owner.
+    Ambiguous(Vec<ResolvedField>),
+    NotFound,
+}
+
+/// Resolves a field access like [`resolve_field`], following the JLS 8.3 inheritance rules and
+/// reporting where the field is declared.
+///
+/// A field declared by a class hides every field of the same name in its supertypes, so a class
+/// declaring its own `X` is unaffected by the `X` constants of the interfaces it implements. A
+/// field reached only through several supertypes is ambiguous unless it is the same
+/// declaration (an interface inherited along two paths). Hiding goes by name alone: an instance
+/// field hides inherited constants even for a static access, which then finds nothing.
+pub fn resolve_field_with_owner(
+    env: &dyn TypeEnv,
+    receiver: &Type,
+    name: &str,
+    call_kind: CallKind,
+) -> FieldResolution {
+    let mut memo = HashMap::new();
+    let (receiver_def, mut members) = match canonicalize_named(env, receiver) {
+        Type::Wildcard(bound) => {
+            return resolve_field_with_owner(
+                env,
+                &wildcard_upper_bound(env, &bound),
+                name,
+                call_kind,
+            );
+        }
+        ty @ Type::Class(ClassType { def, .. }) => (
+            Some(def),
+            field_members(env, &ty, name, &mut Vec::new(), &mut memo),
+        ),
+        Type::Intersection(parts) => {
+            // The members of a notional class extending every component (JLS 4.9).
+            let mut members: Vec<(ClassId, FieldDef)> = Vec::new();
+            for part in &parts {
+                for member in field_members(env, part, name, &mut Vec::new(), &mut memo) {
+                    if !members.iter().any(|(owner, _)| *owner == member.0) {
+                        members.push(member);
+                    }
+                }
+            }
+            (None, members)
+        }
+        _ => return FieldResolution::NotFound,
+    };
+
+    if call_kind == CallKind::Static {
+        members.retain(|(_, field)| field.is_static);
+    }
+    let mut resolved: Vec<ResolvedField> = members
+        .into_iter()
+        .map(|(owner, field)| ResolvedField {
+            field,
+            owner,
+            inherited: receiver_def != Some(owner),
+        })
+        .collect();
+    match resolved.len() {
+        0 => FieldResolution::NotFound,
+        1 => FieldResolution::Found(resolved.pop().unwrap()),
+        _ => {
+            resolved
+                .sort_by_cached_key(|field| type_sort_key(env, &Type::class(field.owner, vec![])));
+            FieldResolution::Ambiguous(resolved)
+        }
+    }
+}
+
+/// The fields named `name` that `ty` has as members (JLS 8.3): the declaration of its class,
+/// or else each distinct field it inherits from its direct supertypes, with their owners.
+///
+/// `path` holds the classes being looked into, which cuts cyclic hierarchies; `memo` keeps the
+/// members of classes already looked into, so diamond-shaped hierarchies are walked once.
+fn field_members(
+    env: &dyn TypeEnv,
+    ty: &Type,
+    name: &str,
+    path: &mut Vec<ClassId>,
+    memo: &mut HashMap<ClassId, Vec<(ClassId, FieldDef)>>,
+) -> Vec<(ClassId, FieldDef)> {
+    let Type::Class(ClassType { def, args }) = canonicalize_named(env, ty) else {
+        return Vec::new();
+    };
+    if let Some(members) = memo.get(&def) {
+        return members.clone();
+    }
+    if path.contains(&def) {
+        return Vec::new();
+    }
+    let Some(class_def) = env.class(def) else {
+        return Vec::new();
+    };
+    let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
+
+    let members = if let Some(field) = class_def.fields.iter().find(|field| field.name == name) {
+        vec![(
+            def,
+            FieldDef {
+                ty: substitute_read_type(env, &field.ty, &subst),
+                ..field.clone()
+            },
+        )]
+    } else {
+        path.push(def);
+        let mut members: Vec<(ClassId, FieldDef)> = Vec::new();
+        for supertype in class_def.super_class.iter().chain(&class_def.interfaces) {
+            let supertype = substitute(supertype, &subst);
+            for member in field_members(env, &supertype, name, path, memo) {
+                if !members.iter().any(|(owner, _)| *owner == member.0) {
+                    members.push(member);
+                }
+            }
+        }
+        path.pop();
+        members
+    };
+    memo.insert(def, members.clone());
+    members
+}
+
+/// Resolves an unqualified field name in code whose `this` has type `implicit_this`.
+///
+/// The class of `this` and its supertypes are searched first, as by [`resolve_field`] on an
+/// instance receiver; then each lexically enclosing class outward (see [`enclosing_chain`]), so
+/// the body of an inner or anonymous class sees the fields of its outer classes. Past a static
+/// nested class or an interface there is no enclosing instance, so only static fields of the
+/// classes further out are visible.
+pub fn resolve_field_access_with_implicit_this(
+    env: &dyn TypeEnv,
+    implicit_this: &Type,
+    name: &str,
+) -> Option<FieldDef> {
+    if let Some(field) = resolve_field(env, implicit_this, name, CallKind::Instance) {
+        return Some(field);
+    }
+
+    let Type::Class(ClassType { def, .. }) = canonicalize_named(env, implicit_this) else {
+        return None;
+    };
+    let mut call_kind = CallKind::Instance;
+    let mut inner = def;
+    for outer in enclosing_chain(env, def) {
+        let inner_def = env.class(inner)?;
+        if !has_enclosing_instance(inner_def) {
+            call_kind = CallKind::Static;
+        }
+        let outer_def = env.class(outer)?;
+        let receiver = Type::class(
+            outer,
+            outer_def
+                .type_params
+                .iter()
+                .copied()
+                .map(Type::TypeVar)
+                .collect(),
+        );
+        if let Some(field) = resolve_field(env, &receiver, name, call_kind) {
+            return Some(field);
+        }
+        inner = outer;
+    }
+    None
+}
+
 // === Method resolution =======================================================
 
 #[derive(Debug, Clone)]
@@ -3753,9 +7856,155 @@ pub struct MethodCall<'a> {
     pub args: Vec<Type>,
     pub expected_return: Option<Type>,
     pub explicit_type_args: Vec<Type>,
+    /// Tolerate fewer `explicit_type_args` than the method declares type parameters.
+    ///
+    /// Java requires all or none, but IDE features (e.g. completion inside `<...>`) see
+    /// partially typed type argument lists. When set, the provided arguments pin the leading
+    /// type parameters and the rest are inferred; otherwise resolution fails with
+    /// [`MethodCandidateFailureReason::ExplicitTypeArgCountMismatch`].
+    pub allow_partial_type_args: bool,
+    /// Skip the methods declared by this class and search its supertypes instead.
+    ///
+    /// Used for `super.m()` calls: set it to the class containing the call (and `receiver` to
+    /// that class's type) so the call resolves to the inherited declaration, not the override.
+    pub start_above: Option<ClassId>,
+    /// The class containing the call, used to exclude methods that are not accessible from it.
+    ///
+    /// `None` skips accessibility checks.
+    pub caller_context: Option<ClassId>,
+    /// Poly expression shapes of the arguments, parallel to `args`; empty when every argument
+    /// is typed standalone.
+    ///
+    /// `args` still holds each argument's standalone type (see [`ArgShape::standalone_type`]),
+    /// which diagnostics and type argument inference use.
+    pub arg_shapes: Vec<ArgShape>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// An argument expression as overload resolution sees it.
+///
+/// A conditional argument is typed against each candidate's formal parameter type rather than
+/// once up front: a reference conditional (JLS 15.25.3) is a poly expression whose branches must
+/// each be compatible with the formal, so `flag ? 1 : null` can be passed as `Integer` but not as
+/// `int`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgShape {
+    Standalone(Type),
+    Conditional {
+        then: Box<ArgShape>,
+        else_: Box<ArgShape>,
+    },
+}
+
+impl ArgShape {
+    pub fn conditional(then: ArgShape, else_: ArgShape) -> Self {
+        ArgShape::Conditional {
+            then: Box::new(then),
+            else_: Box::new(else_),
+        }
+    }
+
+    /// The type of the expression outside of an assignment or invocation context.
+    ///
+    /// Boolean and numeric conditionals have their JLS 15.25 type; a reference conditional has
+    /// the lub of its boxed branch types, or the type of its non-`null` branch.
+    pub fn standalone_type(&self, env: &dyn TypeEnv) -> Type {
+        match self {
+            ArgShape::Standalone(ty) => ty.clone(),
+            ArgShape::Conditional { then, else_ } => {
+                if let Some(ty) = conditional_standalone_type(env, then, else_) {
+                    return ty;
+                }
+                let boxed = |shape: &ArgShape| match shape.standalone_type(env) {
+                    Type::Primitive(p) => boxing_type(env, p).unwrap_or(Type::Primitive(p)),
+                    other => other,
+                };
+                match (boxed(then), boxed(else_)) {
+                    (Type::Null, ty) | (ty, Type::Null) => ty,
+                    (a, b) => lub(env, &a, &b),
+                }
+            }
+        }
+    }
+}
+
+/// The type of a boolean or numeric conditional (JLS 15.25.1-2), which is a standalone
+/// expression; `None` for a reference conditional.
+///
+/// Constant operands are not tracked, so `flag ? 1 : (byte) 2` is `int` rather than `byte`.
+fn conditional_standalone_type(
+    env: &dyn TypeEnv,
+    then: &ArgShape,
+    else_: &ArgShape,
+) -> Option<Type> {
+    let operand = |shape: &ArgShape| match shape {
+        ArgShape::Standalone(ty) => Some(ty.clone()),
+        ArgShape::Conditional { then, else_ } => conditional_standalone_type(env, then, else_),
+    };
+    let primitive = |ty: &Type| match ty {
+        Type::Primitive(p) => Some(*p),
+        other => unbox(env, other),
+    };
+    let (a, b) = (operand(then)?, operand(else_)?);
+    let (pa, pb) = (primitive(&a)?, primitive(&b)?);
+    if a == b {
+        return Some(a);
+    }
+    let ty = match (pa, pb) {
+        (PrimitiveType::Boolean, PrimitiveType::Boolean) => PrimitiveType::Boolean,
+        _ if pa == pb => pa,
+        (PrimitiveType::Byte, PrimitiveType::Short)
+        | (PrimitiveType::Short, PrimitiveType::Byte) => PrimitiveType::Short,
+        _ => binary_numeric_promotion(pa, pb)?,
+    };
+    Some(Type::Primitive(ty))
+}
+
+/// Converts an argument of shape `shape` to `param` in `phase`.
+///
+/// A reference conditional converts when both of its branches do; see [`Conversion::branches`].
+fn arg_shape_conversion(
+    env: &dyn TypeEnv,
+    shape: &ArgShape,
+    param: &Type,
+    phase: MethodSearchPhase,
+) -> Option<Conversion> {
+    let (then, else_) = match shape {
+        ArgShape::Standalone(ty) => return phase_conversion(env, ty, param, phase),
+        ArgShape::Conditional { then, else_ } => (then, else_),
+    };
+    if let Some(ty) = conditional_standalone_type(env, then, else_) {
+        return phase_conversion(env, &ty, param, phase);
+    }
+    let then = arg_shape_conversion(env, then, param, phase)?;
+    let else_ = arg_shape_conversion(env, else_, param, phase)?;
+    let mut warnings = then.warnings.clone();
+    for warning in &else_.warnings {
+        if !warnings.contains(warning) {
+            warnings.push(warning.clone());
+        }
+    }
+    Some(Conversion {
+        steps: then.steps.iter().chain(&else_.steps).cloned().collect(),
+        warnings,
+        branches: vec![then, else_],
+    })
+}
+
+fn phase_conversion(
+    env: &dyn TypeEnv,
+    arg: &Type,
+    param: &Type,
+    phase: MethodSearchPhase,
+) -> Option<Conversion> {
+    match phase {
+        MethodSearchPhase::Strict => strict_method_invocation_conversion(env, arg, param),
+        MethodSearchPhase::Loose | MethodSearchPhase::Varargs => {
+            method_invocation_conversion(env, arg, param)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ResolvedMethod {
     pub owner: ClassId,
     pub name: String,
@@ -3769,6 +8018,9 @@ pub struct ResolvedMethod {
     /// This is primarily used for variable-arity varargs invocations: `params` is expanded to match
     /// the call-site arity, but pretty-printers generally want to show the declared `T...` parameter.
     pub signature_params: Option<Vec<Type>>,
+    /// Declared parameter names, parallel to the declared signature (`signature_params` when
+    /// present, otherwise `params`). Empty when the names are unknown.
+    pub param_names: Vec<String>,
     pub return_type: Type,
     pub is_varargs: bool,
     pub is_static: bool,
@@ -3777,27 +8029,53 @@ pub struct ResolvedMethod {
     pub warnings: Vec<TypeWarning>,
     pub used_varargs: bool,
     pub phase: MethodSearchPhase,
+    /// The method was found by best-effort recovery on an errorish receiver (see
+    /// [`TyContext::with_errorish_receiver_recovery`]).
+    ///
+    /// Speculative results are only suitable for IDE features like completion and parameter
+    /// help; callers must never use them for error reporting.
+    pub speculative: bool,
+    /// Classes that were still placeholders (see [`ClassDefState`]) when this method was
+    /// resolved. Only recorded by a [`TyContext::with_placeholder_checks`] context.
+    ///
+    /// A non-empty list means the result may change once those classes are defined, so callers
+    /// should not cache it.
+    pub depends_on_placeholders: Vec<ClassId>,
+    /// The deprecation of the selected declaration itself (see [`MethodDef::deprecation`]).
+    pub deprecated: Option<Deprecation>,
+}
+
+/// The warning for calling `resolved`, if the selected declaration is deprecated.
+pub fn deprecation_warning(resolved: &ResolvedMethod) -> Option<TypeWarning> {
+    resolved
+        .deprecated
+        .as_ref()
+        .map(|deprecation| TypeWarning::DeprecatedMember {
+            for_removal: deprecation.for_removal,
+        })
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct MethodCandidate {
     pub owner: ClassId,
     pub name: String,
     pub params: Vec<Type>,
+    /// Declared parameter names, parallel to `params`. Empty when the names are unknown.
+    pub param_names: Vec<String>,
     pub return_type: Type,
     pub is_static: bool,
     pub is_varargs: bool,
     pub type_param_count: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum MethodSearchPhase {
     Strict,
     Loose,
     Varargs,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum MethodCandidateFailureReason {
     WrongCallKind {
         call_kind: CallKind,
@@ -3821,21 +8099,24 @@ pub enum MethodCandidateFailureReason {
         from: Type,
         to: Type,
     },
+    /// The method would be applicable in this phase but is not accessible from
+    /// [`MethodCall::caller_context`].
+    Inaccessible,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct MethodCandidateFailure {
     pub phase: MethodSearchPhase,
     pub reason: MethodCandidateFailureReason,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct MethodCandidateDiagnostics {
     pub candidate: MethodCandidate,
     pub failures: Vec<MethodCandidateFailure>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct MethodNotFound {
     pub receiver: Type,
     pub name: String,
@@ -3843,14 +8124,14 @@ pub struct MethodNotFound {
     pub candidates: Vec<MethodCandidateDiagnostics>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct MethodAmbiguity {
     pub phase: MethodSearchPhase,
     /// Applicable candidates for the selected phase, sorted from "best" to "worst".
     pub candidates: Vec<ResolvedMethod>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum MethodResolution {
     Found(ResolvedMethod),
     NotFound(MethodNotFound),
@@ -3862,7 +8143,7 @@ fn resolve_method_call_impl(
     call: &MethodCall<'_>,
     receiver: Type,
 ) -> MethodResolution {
-    let candidates = collect_method_candidates(env, &receiver, call.name);
+    let candidates = collect_method_candidates(env, &receiver, call.name, call.start_above);
 
     if candidates.is_empty() {
         return MethodResolution::NotFound(MethodNotFound {
@@ -3882,12 +8163,14 @@ fn resolve_method_call_impl(
                 .iter()
                 .map(|t| substitute(t, &cand.class_subst))
                 .collect::<Vec<_>>();
-            let base_return = substitute(&cand.method.return_type, &cand.class_subst);
+            let base_return =
+                substitute_read_type(env, &cand.method.return_type, &cand.class_subst);
             MethodCandidateDiagnostics {
                 candidate: MethodCandidate {
                     owner: cand.owner,
                     name: cand.method.name.clone(),
                     params: base_params,
+                    param_names: cand.method.param_names.clone(),
                     return_type: base_return,
                     is_static: cand.method.is_static,
                     is_varargs: cand.method.is_varargs,
@@ -3915,8 +8198,15 @@ fn resolve_method_call_impl(
                 continue;
             }
 
+            // Inaccessible methods are never chosen, but one that would otherwise apply is reported
+            // as such rather than as a plain mismatch.
+            let accessible = is_method_accessible(env, cand, call.caller_context);
             match check_applicability(env, cand, call, phase) {
-                Ok(resolved) => applicable.push(resolved),
+                Ok(resolved) if accessible => applicable.push(resolved),
+                Ok(_) => diagnostics[idx].failures.push(MethodCandidateFailure {
+                    phase,
+                    reason: MethodCandidateFailureReason::Inaccessible,
+                }),
                 Err(reason) => diagnostics[idx]
                     .failures
                     .push(MethodCandidateFailure { phase, reason }),
@@ -3946,6 +8236,25 @@ fn resolve_method_call_impl(
     })
 }
 
+/// Whether `cand` may be invoked from code in `caller` (JLS 6.6.1); always true without a caller.
+///
+/// Only `private` is modelled: such a method is accessible anywhere within the top-level class
+/// enclosing its declaration, including from nested classes.
+fn is_method_accessible(
+    env: &dyn TypeEnv,
+    cand: &CandidateMethod,
+    caller: Option<ClassId>,
+) -> bool {
+    let Some(caller) = caller else {
+        return true;
+    };
+    if !cand.method.is_private || caller == cand.owner {
+        return true;
+    }
+    let top_level = |id: ClassId| enclosing_chain(env, id).last().copied().unwrap_or(id);
+    top_level(caller) == top_level(cand.owner)
+}
+
 pub fn resolve_constructor_call(
     env: &dyn TypeEnv,
     class: ClassId,
@@ -3971,6 +8280,10 @@ pub fn resolve_constructor_call(
         args: args.to_vec(),
         expected_return: expected.cloned(),
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let Some(class_def) = env.class(class) else {
@@ -3982,12 +8295,8 @@ pub fn resolve_constructor_call(
         });
     };
 
-    let class_subst = class_def
-        .type_params
-        .iter()
-        .copied()
-        .zip(receiver_args)
-        .collect::<HashMap<_, _>>();
+    let class_subst =
+        TypeSubstitution::from_class_def_with_enclosing(env, class_def, &receiver_args);
 
     let candidates: Vec<CandidateMethod> = class_def
         .constructors
@@ -3999,10 +8308,13 @@ pub fn resolve_constructor_call(
                 name: "<init>".to_string(),
                 type_params: vec![],
                 params: ctor.params.clone(),
+                param_names: vec![],
                 return_type: return_type.clone(),
                 is_static: false,
                 is_varargs: ctor.is_varargs,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             class_subst: class_subst.clone(),
         })
@@ -4032,6 +8344,7 @@ pub fn resolve_constructor_call(
                     owner: cand.owner,
                     name: cand.method.name.clone(),
                     params: base_params,
+                    param_names: cand.method.param_names.clone(),
                     return_type: base_return,
                     is_static: cand.method.is_static,
                     is_varargs: cand.method.is_varargs,
@@ -4081,17 +8394,97 @@ pub fn resolve_constructor_call(
     })
 }
 
-#[derive(Debug, Clone)]
-struct CandidateMethod {
-    owner: ClassId,
-    method: MethodDef,
-    class_subst: HashMap<TypeVarId, Type>,
+/// The constructors of `class` that can accept `args`, in declaration order.
+///
+/// This is the applicability filter of [`resolve_constructor_call`] without overload ranking:
+/// a constructor is kept if `args` convert to its parameters by method invocation conversion
+/// (JLS 5.3), with boxing and variable-arity expansion allowed. Parameters are compared in their
+/// erased form, so constructors of generic classes match as in a diamond (`new Box<>(x)`)
+/// invocation. Accessibility is not checked.
+pub fn applicable_constructors<'a>(
+    env: &'a dyn TypeEnv,
+    class: ClassId,
+    args: &[Type],
+) -> Vec<&'a ConstructorDef> {
+    let Some(class_def) = env.class(class) else {
+        return Vec::new();
+    };
+
+    let accepts = |params: &[Type]| {
+        params.len() == args.len()
+            && args.iter().zip(params).all(|(arg, param)| {
+                method_invocation_conversion(env, arg, &erasure(env, param)).is_some()
+            })
+    };
+    class_def
+        .constructors
+        .iter()
+        .filter(|ctor| {
+            accepts(&ctor.params)
+                || (ctor.is_varargs
+                    && expand_params_for_arity(&ctor.params, true, args.len())
+                        .is_some_and(|params| accepts(&params)))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct CandidateMethod {
+    owner: ClassId,
+    method: MethodDef,
+    class_subst: TypeSubstitution,
+}
+
+/// Queue the class types whose members are accessible through `ty`, for a breadth-first walk
+/// of their supertypes.
+fn push_receiver_for_lookup(env: &dyn TypeEnv, queue: &mut VecDeque<Type>, ty: &Type) {
+    match ty {
+        Type::Intersection(types) => {
+            // Flatten + sort for deterministic traversal even if the intersection isn't
+            // normalized.
+            let mut flat = Vec::new();
+            let mut stack: Vec<&Type> = types.iter().collect();
+            while let Some(t) = stack.pop() {
+                match t {
+                    Type::Intersection(parts) => stack.extend(parts.iter()),
+                    other => flat.push(other.clone()),
+                }
+            }
+
+            let mut seen_parts = HashSet::new();
+            let mut uniq = Vec::new();
+            for t in flat {
+                if seen_parts.insert(t.clone()) {
+                    uniq.push(t);
+                }
+            }
+            uniq.sort_by_cached_key(|ty| {
+                (intersection_component_rank(env, ty), type_sort_key(env, ty))
+            });
+
+            for t in uniq {
+                push_receiver_for_lookup(env, queue, &t);
+            }
+        }
+        Type::Class(_) => queue.push_back(ty.clone()),
+        Type::Array(_) => queue.push_back(Type::class(env.well_known().object, vec![])),
+        Type::Named(n) => {
+            if let Some(id) = env.lookup_class_by_source_name(n) {
+                queue.push_back(Type::class(id, vec![]));
+            }
+        }
+        Type::Wildcard(bound) => {
+            push_receiver_for_lookup(env, queue, &wildcard_upper_bound(env, bound));
+        }
+        _ => {}
+    }
 }
 
 fn collect_method_candidates(
     env: &dyn TypeEnv,
     receiver: &Type,
     name: &str,
+    start_above: Option<ClassId>,
 ) -> Vec<CandidateMethod> {
     let mut out = Vec::new();
     // Track candidates we've already seen by erased signature so we don't return duplicates
@@ -4101,44 +8494,7 @@ fn collect_method_candidates(
     let mut seen_sigs: HashMap<(bool, Vec<Type>), usize> = HashMap::new();
 
     let mut queue = VecDeque::new();
-    let mut seen = HashSet::new();
-    fn push_receiver_for_lookup(env: &dyn TypeEnv, queue: &mut VecDeque<Type>, ty: &Type) {
-        match ty {
-            Type::Intersection(types) => {
-                // Flatten + sort for deterministic traversal even if the intersection isn't
-                // normalized.
-                let mut flat = Vec::new();
-                let mut stack: Vec<&Type> = types.iter().collect();
-                while let Some(t) = stack.pop() {
-                    match t {
-                        Type::Intersection(parts) => stack.extend(parts.iter()),
-                        other => flat.push(other.clone()),
-                    }
-                }
-
-                let mut seen_parts = HashSet::new();
-                let mut uniq = Vec::new();
-                for t in flat {
-                    if seen_parts.insert(t.clone()) {
-                        uniq.push(t);
-                    }
-                }
-                uniq.sort_by_cached_key(|ty| (intersection_component_rank(env, ty), type_sort_key(env, ty)));
-
-                for t in uniq {
-                    push_receiver_for_lookup(env, queue, &t);
-                }
-            }
-            Type::Class(_) => queue.push_back(ty.clone()),
-            Type::Array(_) => queue.push_back(Type::class(env.well_known().object, vec![])),
-            Type::Named(n) => {
-                if let Some(id) = env.lookup_class_by_source_name(n) {
-                    queue.push_back(Type::class(id, vec![]));
-                }
-            }
-            _ => {}
-        }
-    }
+    let mut seen = VisitedSupertypes::default();
     push_receiver_for_lookup(env, &mut queue, receiver);
     if queue.is_empty() {
         return out;
@@ -4148,22 +8504,33 @@ fn collect_method_candidates(
         let Type::Class(ClassType { def, args }) = current.clone() else {
             continue;
         };
-        if !seen.insert((def, args.clone())) {
+        if !seen.insert(def, &args) {
             continue;
         }
 
         let Some(class_def) = env.class(def) else {
             continue;
         };
-        let subst = class_def
-            .type_params
-            .iter()
-            .copied()
-            .zip(args.into_iter())
-            .collect::<HashMap<_, _>>();
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
+        // Instance members of a raw type have erased signatures (JLS 4.8), so a self-returning
+        // `Builder<T> add(T)` called on a raw `Builder` keeps the chain raw.
+        let raw = is_raw_class(env, def, &args);
 
-        for method in &class_def.methods {
+        // `super.m()`: only the supertypes of `start_above` are searched.
+        let methods: &[MethodDef] = if start_above == Some(def) {
+            &[]
+        } else {
+            &class_def.methods
+        };
+        for method in methods {
             if method.name == name {
+                let raw_method;
+                let method = if raw && !method.is_static {
+                    raw_method = raw_member_signature(env, method);
+                    &raw_method
+                } else {
+                    method
+                };
                 // Best-effort override/hiding handling:
                 // if we've already seen a method with the same erased signature, keep the
                 // most specific declaration (we traverse from receiver -> supertypes).
@@ -4274,15 +8641,10 @@ fn collect_method_candidates(
                     );
                     let mut current_return = substitute(&method.return_type, &subst);
                     if !method.type_params.is_empty() {
-                        let mut tv_subst = HashMap::with_capacity(method.type_params.len());
-                        for (from, to) in method
-                            .type_params
-                            .iter()
-                            .copied()
-                            .zip(out[existing].method.type_params.iter().copied())
-                        {
-                            tv_subst.insert(from, Type::TypeVar(to));
-                        }
+                        let tv_subst = TypeSubstitution::renaming(
+                            &method.type_params,
+                            &out[existing].method.type_params,
+                        );
                         current_return = substitute(&current_return, &tv_subst);
                     }
                     out[existing].method.return_type = glb(env, &existing_return, &current_return);
@@ -4297,16 +8659,20 @@ fn collect_method_candidates(
             }
         }
 
+        // The supertypes of a raw type are raw as well.
+        let supertype = |ty: &Type| {
+            if raw {
+                erasure(env, ty)
+            } else {
+                substitute(ty, &subst)
+            }
+        };
         if let Some(sc) = &class_def.super_class {
-            queue.push_back(substitute(sc, &subst));
+            queue.push_back(supertype(sc));
         }
         // Sort interface traversal so candidate collection is deterministic even if the
         // interface list ordering isn't stable.
-        let mut ifaces: Vec<Type> = class_def
-            .interfaces
-            .iter()
-            .map(|iface| substitute(iface, &subst))
-            .collect();
+        let mut ifaces: Vec<Type> = class_def.interfaces.iter().map(supertype).collect();
         ifaces.sort_by_cached_key(|ty| type_sort_key(env, ty));
         for iface in ifaces {
             queue.push_back(iface);
@@ -4320,6 +8686,395 @@ fn collect_method_candidates(
     out
 }
 
+/// Completion items for the members of `receiver` whose names start with `prefix`.
+///
+/// Fields and methods of the receiver's class and its supertypes are listed with their types
+/// instantiated for the receiver's type arguments; a member hidden or overridden by a subtype
+/// is listed once, as declared by the subtype. `CallKind::Static` lists the static members
+/// (`Type.member`), `CallKind::Instance` the instance members (`expr.member`). Every overload of
+/// a method is its own item, with its signature as the item's `detail`.
+///
+/// Deprecated members are marked as such and listed after the other members of the class that
+/// declares them.
+pub fn collect_candidate_completions(
+    env: &dyn TypeEnv,
+    receiver: &Type,
+    prefix: &str,
+    call_kind: CallKind,
+) -> Vec<CompletionItem> {
+    let want_static = call_kind == CallKind::Static;
+    let mut items = Vec::new();
+    let mut seen_fields = HashSet::new();
+    let mut seen_methods = HashSet::new();
+
+    let mut queue = VecDeque::new();
+    let mut seen = VisitedSupertypes::default();
+    push_receiver_for_lookup(env, &mut queue, receiver);
+    while let Some(current) = queue.pop_front() {
+        let Type::Class(ClassType { def, args }) = current else {
+            continue;
+        };
+        if !seen.insert(def, &args) {
+            continue;
+        }
+        let Some(class_def) = env.class(def) else {
+            continue;
+        };
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
+        let raw = is_raw_class(env, def, &args);
+        let class_items_start = items.len();
+
+        for field in &class_def.fields {
+            if field.is_static != want_static
+                || !field.name.starts_with(prefix)
+                || !seen_fields.insert(field.name.as_str())
+            {
+                continue;
+            }
+            let ty = if raw && !field.is_static {
+                erasure(env, &field.ty)
+            } else {
+                substitute(&field.ty, &subst)
+            };
+            items.push(
+                CompletionItem::new(field.name.clone())
+                    .with_kind(CompletionItemKind::Field)
+                    .with_detail(format_type(env, &ty))
+                    .with_deprecated(field.deprecation.is_some()),
+            );
+        }
+
+        for method in &class_def.methods {
+            if method.is_static != want_static || !method.name.starts_with(prefix) {
+                continue;
+            }
+            let method = if raw && !method.is_static {
+                raw_member_signature(env, method)
+            } else {
+                MethodDef {
+                    params: method
+                        .params
+                        .iter()
+                        .map(|ty| substitute(ty, &subst))
+                        .collect(),
+                    return_type: substitute(&method.return_type, &subst),
+                    ..method.clone()
+                }
+            };
+            let erased_params: Vec<Type> =
+                method.params.iter().map(|ty| erasure(env, ty)).collect();
+            if !seen_methods.insert((method.name.clone(), erased_params)) {
+                continue;
+            }
+            items.push(
+                CompletionItem::method(&method)
+                    .with_detail(format_method_signature(env, def, &method)),
+            );
+        }
+        items[class_items_start..].sort_by_key(|item| item.deprecated);
+
+        let supertype = |ty: &Type| {
+            if raw {
+                erasure(env, ty)
+            } else {
+                substitute(ty, &subst)
+            }
+        };
+        queue.extend(class_def.super_class.iter().map(supertype));
+        queue.extend(class_def.interfaces.iter().map(supertype));
+        if class_def.kind == ClassKind::Interface {
+            queue.push_back(Type::class(env.well_known().object, vec![]));
+        }
+    }
+
+    items
+}
+
+/// The methods of `receiver` whose names start with `name_prefix`, ignoring ASCII case, for
+/// completion UIs that resolve item details lazily.
+///
+/// Walks the receiver's class and supertypes like [`collect_candidate_completions`], static and
+/// instance methods alike, with signatures instantiated for the receiver's type arguments. A
+/// method overridden by a subtype is listed once, with the subtype as its `owner`. Only methods
+/// matching the prefix are instantiated.
+pub fn method_candidates_for_completion(
+    env: &dyn TypeEnv,
+    receiver: &Type,
+    name_prefix: &str,
+) -> Vec<MethodCandidate> {
+    let matches_prefix = |name: &str| {
+        name.get(..name_prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(name_prefix))
+    };
+    let mut out = Vec::new();
+    let mut seen_methods = HashSet::new();
+
+    let mut queue = VecDeque::new();
+    let mut seen = VisitedSupertypes::default();
+    push_receiver_for_lookup(env, &mut queue, receiver);
+    while let Some(current) = queue.pop_front() {
+        let Type::Class(ClassType { def, args }) = current else {
+            continue;
+        };
+        if !seen.insert(def, &args) {
+            continue;
+        }
+        let Some(class_def) = env.class(def) else {
+            continue;
+        };
+        let subst = TypeSubstitution::from_class_def_with_enclosing(env, class_def, &args);
+        let raw = is_raw_class(env, def, &args);
+
+        for method in class_def.methods.iter().filter(|m| matches_prefix(&m.name)) {
+            let (params, return_type) = if raw && !method.is_static {
+                let erased = raw_member_signature(env, method);
+                (erased.params, erased.return_type)
+            } else {
+                (
+                    method
+                        .params
+                        .iter()
+                        .map(|ty| substitute(ty, &subst))
+                        .collect::<Vec<_>>(),
+                    substitute(&method.return_type, &subst),
+                )
+            };
+            let erased_params: Vec<Type> = params.iter().map(|ty| erasure(env, ty)).collect();
+            if !seen_methods.insert((method.name.as_str(), method.is_static, erased_params)) {
+                continue;
+            }
+            out.push(MethodCandidate {
+                owner: def,
+                name: method.name.clone(),
+                params,
+                param_names: method.param_names.clone(),
+                return_type,
+                is_static: method.is_static,
+                is_varargs: method.is_varargs,
+                type_param_count: method.type_params.len(),
+            });
+        }
+
+        let supertype = |ty: &Type| {
+            if raw {
+                erasure(env, ty)
+            } else {
+                substitute(ty, &subst)
+            }
+        };
+        queue.extend(class_def.super_class.iter().map(supertype));
+        queue.extend(class_def.interfaces.iter().map(supertype));
+        if class_def.kind == ClassKind::Interface {
+            queue.push_back(Type::class(env.well_known().object, vec![]));
+        }
+    }
+
+    out
+}
+
+/// A static import in scope at a call site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaticImport {
+    /// `import static pkg.Owner.name;`
+    Single { owner: ClassId, name: String },
+    /// `import static pkg.Owner.*;`
+    OnDemand(ClassId),
+}
+
+/// The lexical position of an unqualified method call `m(..)` (see [`resolve_unqualified_call`]).
+#[derive(Debug, Clone)]
+pub struct CallContext {
+    /// The innermost class whose body contains the call.
+    pub enclosing_class: ClassId,
+    /// Type arguments of `this` inside `enclosing_class`, usually its own type variables.
+    pub enclosing_instantiation: Vec<Type>,
+    /// The call is in a static method, static initializer, or static field initializer.
+    pub in_static_context: bool,
+    /// The classes lexically enclosing `enclosing_class`, innermost first (see
+    /// [`enclosing_chain`]).
+    pub enclosing_outer: Vec<ClassId>,
+    pub static_imports: Vec<StaticImport>,
+}
+
+/// The scope that supplied the methods considered for an unqualified call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallScope {
+    /// Members (declared or inherited) of the enclosing class or a lexically enclosing class.
+    Class(ClassId),
+    SingleStaticImport,
+    StaticImportOnDemand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnqualifiedCallResolution {
+    pub resolution: MethodResolution,
+    /// The innermost scope with a method of the called name; `None` when no scope has one.
+    pub found_in: Option<CallScope>,
+    /// Scopes further out that also have a method of the called name, shadowed by `found_in`.
+    pub shadowed: Vec<CallScope>,
+}
+
+/// Resolves an unqualified method call `name(args)` (implicit `this` or static context).
+///
+/// Scopes are searched in the order of JLS 15.12.1: the enclosing class and its supertypes, each
+/// lexically enclosing class outward, single static imports, then on-demand static imports. The
+/// innermost scope with a method of that name shadows the others, and overload resolution only
+/// runs within it. Instance methods are not visible from a static context; moving out of a
+/// static nested class or an interface makes the context static.
+pub fn resolve_unqualified_call(
+    env: &dyn TypeEnv,
+    context: &CallContext,
+    name: &str,
+    args: &[Type],
+    expected: Option<Type>,
+) -> UnqualifiedCallResolution {
+    let mut scopes: Vec<(CallScope, Vec<(Type, CallKind)>)> = Vec::new();
+
+    let mut in_static_context = context.in_static_context;
+    let classes =
+        std::iter::once(context.enclosing_class).chain(context.enclosing_outer.iter().copied());
+    for (depth, class) in classes.enumerate() {
+        let Some(class_def) = env.class(class) else {
+            continue;
+        };
+        let receiver = if depth == 0 {
+            Type::class(class, context.enclosing_instantiation.clone())
+        } else {
+            Type::class(
+                class,
+                class_def
+                    .type_params
+                    .iter()
+                    .map(|&tv| Type::TypeVar(tv))
+                    .collect(),
+            )
+        };
+        let visible = collect_method_candidates(env, &receiver, name, None)
+            .iter()
+            .any(|cand| !in_static_context || cand.method.is_static);
+        if visible {
+            let call_kind = if in_static_context {
+                CallKind::Static
+            } else {
+                CallKind::Instance
+            };
+            scopes.push((CallScope::Class(class), vec![(receiver, call_kind)]));
+        }
+        if !has_enclosing_instance(class_def) {
+            in_static_context = true;
+        }
+    }
+
+    let single: Vec<ClassId> = context
+        .static_imports
+        .iter()
+        .filter_map(|import| match import {
+            StaticImport::Single {
+                owner,
+                name: imported,
+            } if imported == name => Some(*owner),
+            _ => None,
+        })
+        .collect();
+    let on_demand: Vec<ClassId> = context
+        .static_imports
+        .iter()
+        .filter_map(|import| match import {
+            StaticImport::OnDemand(owner) => Some(*owner),
+            StaticImport::Single { .. } => None,
+        })
+        .collect();
+    for (scope, owners) in [
+        (CallScope::SingleStaticImport, single),
+        (CallScope::StaticImportOnDemand, on_demand),
+    ] {
+        let mut receivers = Vec::new();
+        for owner in owners {
+            let receiver = Type::class(owner, vec![]);
+            let visible = collect_method_candidates(env, &receiver, name, None)
+                .iter()
+                .any(|cand| cand.method.is_static);
+            if visible && !receivers.iter().any(|(ty, _)| *ty == receiver) {
+                receivers.push((receiver, CallKind::Static));
+            }
+        }
+        if !receivers.is_empty() {
+            scopes.push((scope, receivers));
+        }
+    }
+
+    let mut scopes = scopes.into_iter();
+    let Some((found_in, receivers)) = scopes.next() else {
+        return UnqualifiedCallResolution {
+            resolution: MethodResolution::NotFound(MethodNotFound {
+                receiver: Type::class(
+                    context.enclosing_class,
+                    context.enclosing_instantiation.clone(),
+                ),
+                name: name.to_string(),
+                args: args.to_vec(),
+                candidates: Vec::new(),
+            }),
+            found_in: None,
+            shadowed: Vec::new(),
+        };
+    };
+
+    // Methods of the same name imported from several classes are overloads of each other only
+    // if exactly one of them is applicable; otherwise the call is ambiguous.
+    let mut found = Vec::new();
+    let mut failure = None;
+    for (receiver, call_kind) in receivers {
+        let call = MethodCall {
+            receiver: receiver.clone(),
+            call_kind,
+            name,
+            args: args.to_vec(),
+            expected_return: expected.clone(),
+            explicit_type_args: Vec::new(),
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
+        };
+        match resolve_method_call_impl(env, &call, receiver) {
+            MethodResolution::Found(method) => found.push(method),
+            other => {
+                failure.get_or_insert(other);
+            }
+        }
+    }
+    let resolution = match found.len() {
+        0 => failure.expect("every scope has at least one receiver"),
+        1 => MethodResolution::Found(found.remove(0)),
+        _ => MethodResolution::Ambiguous(MethodAmbiguity {
+            phase: found
+                .iter()
+                .map(|method| method.phase)
+                .max_by_key(|phase| *phase as u8)
+                .unwrap_or(MethodSearchPhase::Strict),
+            candidates: found,
+        }),
+    };
+
+    UnqualifiedCallResolution {
+        resolution,
+        found_in: Some(found_in),
+        shadowed: scopes.map(|(scope, _)| scope).collect(),
+    }
+}
+
+/// The signature of an instance method accessed through a raw receiver: parameter and return
+/// types are erased and the method is no longer generic.
+fn raw_member_signature(env: &dyn TypeEnv, method: &MethodDef) -> MethodDef {
+    MethodDef {
+        type_params: Vec::new(),
+        params: method.params.iter().map(|ty| erasure(env, ty)).collect(),
+        return_type: erasure(env, &method.return_type),
+        ..method.clone()
+    }
+}
+
 fn check_applicability(
     env: &dyn TypeEnv,
     cand: &CandidateMethod,
@@ -4364,7 +9119,7 @@ fn check_applicability(
         .iter()
         .map(|t| substitute(t, &cand.class_subst))
         .collect::<Vec<_>>();
-    let base_return_type = substitute(&method.return_type, &cand.class_subst);
+    let base_return_type = substitute_read_type(env, &method.return_type, &cand.class_subst);
 
     // Try a fixed-arity invocation first (including varargs methods invoked with an array).
     if !(method.is_varargs && phase == MethodSearchPhase::Varargs && arity != base_params.len()) {
@@ -4425,7 +9180,7 @@ fn try_method_invocation(
         if method.is_varargs && (phase == MethodSearchPhase::Varargs) {
             if force_varargs {
                 (
-                    expand_varargs_pattern(base_params, arity).ok_or(
+                    expand_params_for_arity(base_params, true, arity).ok_or(
                         MethodCandidateFailureReason::WrongArity {
                             expected: base_params.len().saturating_sub(1),
                             found: arity,
@@ -4463,18 +9218,22 @@ fn try_method_invocation(
     let inferred_type_args = if method.type_params.is_empty() {
         Vec::new()
     } else if !call.explicit_type_args.is_empty() {
-        if call.explicit_type_args.len() != method.type_params.len() {
+        if call.allow_partial_type_args && call.explicit_type_args.len() < method.type_params.len()
+        {
+            infer_type_arguments_with_prefix(env, method, &pattern_params, base_return_type, call)
+        } else if call.explicit_type_args.len() != method.type_params.len() {
             return Err(MethodCandidateFailureReason::ExplicitTypeArgCountMismatch {
                 expected: method.type_params.len(),
                 found: call.explicit_type_args.len(),
             });
+        } else {
+            call.explicit_type_args.clone()
         }
-        call.explicit_type_args.clone()
     } else {
         infer_type_arguments_from_call(env, method, &pattern_params, base_return_type, call)
     };
 
-    let method_subst: HashMap<TypeVarId, Type> = method
+    let method_subst: TypeSubstitution = method
         .type_params
         .iter()
         .copied()
@@ -4538,12 +9297,10 @@ fn try_method_invocation(
 
     let mut warnings = Vec::new();
     let mut conversions = Vec::with_capacity(arity);
-    for (arg, param) in call.args.iter().zip(&effective_params) {
-        let conv = match phase {
-            MethodSearchPhase::Strict => strict_method_invocation_conversion(env, arg, param),
-            MethodSearchPhase::Loose | MethodSearchPhase::Varargs => {
-                method_invocation_conversion(env, arg, param)
-            }
+    for (idx, (arg, param)) in call.args.iter().zip(&effective_params).enumerate() {
+        let conv = match call.arg_shapes.get(idx) {
+            Some(shape) => arg_shape_conversion(env, shape, param, phase),
+            None => phase_conversion(env, arg, param, phase),
         }
         .ok_or_else(|| MethodCandidateFailureReason::ArgumentConversion {
             arg_index: conversions.len(),
@@ -4574,6 +9331,7 @@ fn try_method_invocation(
         name: method.name.clone(),
         params: effective_params,
         signature_params,
+        param_names: method.param_names.clone(),
         return_type,
         is_varargs: method.is_varargs,
         is_static: method.is_static,
@@ -4582,38 +9340,79 @@ fn try_method_invocation(
         warnings,
         used_varargs,
         phase,
+        speculative: false,
+        depends_on_placeholders: Vec::new(),
+        deprecated: method.deprecation.clone(),
     })
 }
 
-fn expand_varargs_pattern(params: &[Type], arity: usize) -> Option<Vec<Type>> {
-    if params.is_empty() {
-        return Some(Vec::new());
-    }
-    let fixed = params.len().saturating_sub(1);
-    if arity < fixed {
-        return None;
-    }
-
-    let mut out = Vec::with_capacity(arity.max(params.len()));
-    out.extend(params[..fixed].iter().cloned());
-    let vararg_ty = params[fixed].clone();
-    let elem_ty = match vararg_ty {
-        Type::Array(elem) => *elem,
-        other => other,
-    };
-    let extra = arity.saturating_sub(fixed);
-    for _ in 0..extra {
-        out.push(elem_ty.clone());
-    }
-    Some(out)
-}
-
 #[derive(Default, Clone)]
 struct InferenceBounds {
     lower: Vec<Type>,
     upper: Vec<Type>,
 }
 
+/// The outcome of inferring a generic method's type arguments, with enough detail for IDE
+/// features to point out what went wrong.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InferenceResult {
+    /// One type per method type parameter, in declaration order. Unsolved and conflicting
+    /// variables fall back to the greatest lower bound of their upper bounds.
+    pub inferred: Vec<Type>,
+    /// Type variables that neither the arguments nor the expected return type constrained.
+    pub unsolved: Vec<TypeVarId>,
+    /// Type variables whose lower bounds don't fit their upper bounds, as
+    /// `(variable, lub of lower bounds, glb of upper bounds)`.
+    pub conflicts: Vec<(TypeVarId, Type, Type)>,
+}
+
+/// Like [`infer_type_arguments_from_call`], but pins the leading type parameters to
+/// `call.explicit_type_args` (see [`MethodCall::allow_partial_type_args`]) and only infers the
+/// rest.
+fn infer_type_arguments_with_prefix(
+    env: &dyn TypeEnv,
+    method: &MethodDef,
+    params: &[Type],
+    return_type: &Type,
+    call: &MethodCall<'_>,
+) -> Vec<Type> {
+    solve_type_arguments_with_prefix(env, method, params, return_type, call).inferred
+}
+
+fn solve_type_arguments_with_prefix(
+    env: &dyn TypeEnv,
+    method: &MethodDef,
+    params: &[Type],
+    return_type: &Type,
+    call: &MethodCall<'_>,
+) -> InferenceResult {
+    let explicit = &call.explicit_type_args;
+    if explicit.is_empty() {
+        return solve_type_arguments(env, method, params, return_type, call);
+    }
+
+    let pinned: TypeSubstitution = method
+        .type_params
+        .iter()
+        .copied()
+        .zip(explicit.iter().cloned())
+        .collect();
+    let params: Vec<Type> = params.iter().map(|p| pinned.apply(p)).collect();
+    let return_type = pinned.apply(return_type);
+    let mut result = solve_type_arguments(env, method, &params, &return_type, call);
+    let pinned_vars = &method.type_params[..explicit.len().min(method.type_params.len())];
+    result.inferred = explicit
+        .iter()
+        .cloned()
+        .chain(result.inferred.into_iter().skip(explicit.len()))
+        .collect();
+    result.unsolved.retain(|tv| !pinned_vars.contains(tv));
+    result
+        .conflicts
+        .retain(|(tv, _, _)| !pinned_vars.contains(tv));
+    result
+}
+
 fn infer_type_arguments_from_call(
     env: &dyn TypeEnv,
     method: &MethodDef,
@@ -4621,6 +9420,16 @@ fn infer_type_arguments_from_call(
     return_type: &Type,
     call: &MethodCall<'_>,
 ) -> Vec<Type> {
+    solve_type_arguments(env, method, params, return_type, call).inferred
+}
+
+fn solve_type_arguments(
+    env: &dyn TypeEnv,
+    method: &MethodDef,
+    params: &[Type],
+    return_type: &Type,
+    call: &MethodCall<'_>,
+) -> InferenceResult {
     let object = Type::class(env.well_known().object, vec![]);
     let mut bounds: HashMap<TypeVarId, InferenceBounds> = method
         .type_params
@@ -4642,6 +9451,8 @@ fn infer_type_arguments_from_call(
             (tv, b)
         })
         .collect();
+    let declared_bounds: HashMap<TypeVarId, usize> =
+        bounds.iter().map(|(tv, b)| (*tv, b.upper.len())).collect();
 
     // Constraints from arguments.
     for (arg, param) in call.args.iter().zip(params) {
@@ -4651,28 +9462,30 @@ fn infer_type_arguments_from_call(
     // Constraints from expected return type.
     if let Some(expected) = &call.expected_return {
         collect_return_constraints(env, return_type, expected, &mut bounds);
-    }
-
-    // Solve bounds: prefer LUB of lowers, else GLB of uppers.
-    method
-        .type_params
-        .iter()
-        .map(|tv| {
-            let b = bounds.get(tv).cloned().unwrap_or_default();
-            let upper_glb = glb_all(env, &b.upper, &object);
-            let candidate = if b.lower.is_empty() {
-                upper_glb.clone()
-            } else {
-                lub_all(env, &b.lower, &object)
-            };
+    }
 
-            if is_subtype(env, &candidate, &upper_glb) {
-                candidate
-            } else {
-                upper_glb
+    // Solve bounds: prefer LUB of lowers, else GLB of uppers.
+    let mut result = InferenceResult::default();
+    for tv in &method.type_params {
+        let b = bounds.get(tv).cloned().unwrap_or_default();
+        let upper_glb = glb_all(env, &b.upper, &object);
+        if b.lower.is_empty() {
+            if b.upper.len() <= declared_bounds.get(tv).copied().unwrap_or(0) {
+                result.unsolved.push(*tv);
             }
-        })
-        .collect()
+            result.inferred.push(upper_glb);
+            continue;
+        }
+
+        let candidate = lub_all(env, &b.lower, &object);
+        if is_subtype(env, &candidate, &upper_glb) {
+            result.inferred.push(candidate);
+        } else {
+            result.conflicts.push((*tv, candidate, upper_glb.clone()));
+            result.inferred.push(upper_glb);
+        }
+    }
+    result
 }
 
 fn glb_all(env: &dyn TypeEnv, tys: &[Type], object: &Type) -> Type {
@@ -4742,11 +9555,24 @@ fn collect_arg_constraints(
 ) {
     match param {
         Type::TypeVar(tv) => {
-            push_lower_bound(bounds, *tv, arg.clone());
+            // Type variables only range over reference types: a primitive argument
+            // constrains `T` through its boxed type (`id(1)` infers `T = Integer`).
+            match arg {
+                Type::Primitive(p) => {
+                    if let Some(boxed) = boxing_type(env, *p) {
+                        push_lower_bound(bounds, *tv, boxed);
+                    }
+                }
+                _ => push_lower_bound(bounds, *tv, arg.clone()),
+            }
         }
         Type::Array(p_elem) => {
             if let Type::Array(a_elem) = arg {
-                collect_arg_constraints(env, a_elem, p_elem, bounds);
+                // `int[]` is not an `Integer[]`: a primitive element type can't instantiate
+                // `T` in `T[]`, so leave `T` unconstrained and let applicability reject it.
+                if !matches!(a_elem.as_ref(), Type::Primitive(_)) {
+                    collect_arg_constraints(env, a_elem, p_elem, bounds);
+                }
             }
         }
         Type::Class(ClassType {
@@ -4915,7 +9741,7 @@ fn collect_return_constraints(
                 // Fast path: expected is the same generic class/interface.
                 if r_def == e_def && r_args.len() == e_args.len() {
                     for (r, e) in r_args.iter().zip(e_args) {
-                        collect_equality_constraints(env, e, r, bounds);
+                        collect_return_type_arg_constraints(env, r, e, bounds);
                     }
                     return;
                 }
@@ -4950,7 +9776,7 @@ fn collect_return_constraints(
                         return;
                     }
                     for (r, e) in mapped_args.iter().zip(e_args) {
-                        collect_equality_constraints(env, e, r, bounds);
+                        collect_return_type_arg_constraints(env, r, e, bounds);
                     }
                 }
             }
@@ -4969,6 +9795,29 @@ fn collect_return_constraints(
     }
 }
 
+/// Constraints from `ret_arg`, a type argument of the return type, being contained by
+/// `expected_arg`, the matching type argument of the expected type (JLS 4.5.1).
+///
+/// A wildcard only bounds the variable from one side: `List<T>` into `List<? extends Number>`
+/// gives `T <: Number`, leaving the argument constraints to pick the most specific `T`.
+fn collect_return_type_arg_constraints(
+    env: &dyn TypeEnv,
+    ret_arg: &Type,
+    expected_arg: &Type,
+    bounds: &mut HashMap<TypeVarId, InferenceBounds>,
+) {
+    match expected_arg {
+        Type::Wildcard(WildcardBound::Unbounded) => {}
+        Type::Wildcard(WildcardBound::Extends(upper)) => {
+            collect_return_constraints(env, ret_arg, upper, bounds);
+        }
+        Type::Wildcard(WildcardBound::Super(lower)) => {
+            collect_arg_constraints(env, lower, ret_arg, bounds);
+        }
+        _ => collect_equality_constraints(env, expected_arg, ret_arg, bounds),
+    }
+}
+
 fn collect_type_var_constraints(
     mapping: &mut HashMap<TypeVarId, Type>,
     pattern: &Type,
@@ -5035,6 +9884,9 @@ fn is_placeholder_type_for_inference(ty: &Type) -> bool {
     matches!(ty, Type::Unknown | Type::Error | Type::Null)
 }
 
+/// Score a conversion for overload tie-breaking: its [`ConversionCost`] tier, then its step count
+/// (so `Byte -> int`, unbox + widen, costs more than `Integer -> int`). A conversion has at most
+/// a handful of steps, so the step count never outweighs the tier.
 fn conversion_score(conv: &Conversion) -> u32 {
     let tier = match conversion_cost(conv) {
         ConversionCost::Identity => 0,
@@ -5080,6 +9932,19 @@ fn rank_resolved_methods(env: &dyn TypeEnv, call: &MethodCall<'_>, methods: &mut
         (primary, tie)
     });
 }
+/// Whether `a` is more specific than `b` (JLS 15.12.2.5, best-effort), for two methods
+/// applicable to the same call.
+///
+/// Both methods' `params` must be expanded to the call's arity, as [`resolve_method_call`]
+/// produces them. A method applicable by fixed arity invocation is more specific than one that
+/// needs variable arity invocation (and a non-varargs method than a varargs one); otherwise `a`
+/// is more specific iff each of its parameter types is a subtype of the corresponding parameter
+/// type of `b`. Primitive subtyping follows JLS 4.10.1, so `m(int)` is more specific than
+/// `m(long)`.
+pub fn is_more_specific_method(env: &dyn TypeEnv, a: &ResolvedMethod, b: &ResolvedMethod) -> bool {
+    is_more_specific(env, a, b, a.params.len())
+}
+
 fn is_more_specific(
     env: &dyn TypeEnv,
     a: &ResolvedMethod,
@@ -5278,6 +10143,91 @@ pub fn infer_var_type(initializer: Option<Type>) -> Type {
     initializer.unwrap_or(Type::Error)
 }
 
+/// Infer the type of the loop variable of an enhanced `for` statement (JLS 14.14.2).
+///
+/// With an explicit `decl_type`, the element type of `iterable_ty` must be assignable to it and
+/// `decl_type` is returned; with `var` (`decl_type == None`), the element type itself is
+/// returned. Returns `Type::Error` if `iterable_ty` is neither an array nor an `Iterable`, or if
+/// the element type is not assignable to `decl_type`.
+///
+/// See [`infer_enhanced_for_type_with_warnings`] to also receive unchecked warnings.
+pub fn infer_enhanced_for_type(
+    env: &dyn TypeEnv,
+    decl_type: Option<&Type>,
+    iterable_ty: &Type,
+) -> Type {
+    infer_enhanced_for_type_with_warnings(env, decl_type, iterable_ty).0
+}
+
+/// Like [`infer_enhanced_for_type`], but also returns the warnings produced while typing the loop
+/// variable.
+///
+/// Iterating a raw `Iterable` yields `Type::Unknown` elements and an unchecked warning.
+pub fn infer_enhanced_for_type_with_warnings(
+    env: &dyn TypeEnv,
+    decl_type: Option<&Type>,
+    iterable_ty: &Type,
+) -> (Type, Vec<TypeWarning>) {
+    let mut warnings = Vec::new();
+
+    let elem = if iterable_ty.is_errorish() {
+        Type::Unknown
+    } else {
+        match foreach_element_type(env, iterable_ty) {
+            Some(ForeachElement::Typed(elem)) => elem,
+            Some(ForeachElement::Raw) => {
+                warnings.push(TypeWarning::Unchecked(UncheckedReason::RawTypeUsage));
+                Type::Unknown
+            }
+            None => return (Type::Error, warnings),
+        }
+    };
+
+    let Some(decl_type) = decl_type else {
+        return (elem, warnings);
+    };
+
+    match assignment_conversion(env, &elem, decl_type) {
+        Some(conv) => {
+            for warning in conv.warnings {
+                if !warnings.contains(&warning) {
+                    warnings.push(warning);
+                }
+            }
+            (decl_type.clone(), warnings)
+        }
+        None => (Type::Error, warnings),
+    }
+}
+
+enum ForeachElement {
+    Typed(Type),
+    /// The iterable is a raw `Iterable` (sub)type, so its element type is unknown.
+    Raw,
+}
+
+fn foreach_element_type(env: &dyn TypeEnv, iterable_ty: &Type) -> Option<ForeachElement> {
+    let iterable_ty = canonicalize_named(env, iterable_ty);
+    if let Type::Array(elem) = &iterable_ty {
+        return Some(ForeachElement::Typed((**elem).clone()));
+    }
+
+    let iterable = env.lookup_class("java.lang.Iterable")?;
+    let Type::Class(ClassType { args, .. }) =
+        instantiate_as_supertype(env, &iterable_ty, iterable)?
+    else {
+        return None;
+    };
+
+    match args.first() {
+        None | Some(Type::Unknown) => Some(ForeachElement::Raw),
+        Some(Type::Wildcard(bound)) => {
+            Some(ForeachElement::Typed(wildcard_upper_bound(env, bound)))
+        }
+        Some(arg) => Some(ForeachElement::Typed(arg.clone())),
+    }
+}
+
 /// Infer type arguments for a generic method given a call site.
 ///
 /// This is a small, constraint-based solver (far from full JLS 18), but it's
@@ -5294,7 +10244,9 @@ pub fn infer_type_arguments(
         return Vec::new();
     }
 
-    if !call.explicit_type_args.is_empty() {
+    let partial =
+        call.allow_partial_type_args && call.explicit_type_args.len() < method.type_params.len();
+    if !call.explicit_type_args.is_empty() && !partial {
         return call.explicit_type_args.clone();
     }
 
@@ -5311,9 +10263,40 @@ pub fn infer_type_arguments(
         .iter()
         .map(|t| substitute(t, &class_subst))
         .collect::<Vec<_>>();
-    let return_type = substitute(&method.return_type, &class_subst);
+    let return_type = substitute_read_type(env, &method.return_type, &class_subst);
+
+    infer_type_arguments_with_prefix(env, method, &params, &return_type, call)
+}
+
+/// Infer type arguments for a generic method like [`infer_type_arguments`], reporting which
+/// type variables could not be solved and which have conflicting bounds.
+///
+/// Both the arguments and `call.expected_return` contribute constraints. `class_subst` maps the
+/// declaring class's type parameters to the receiver's type arguments (see
+/// [`TypeSubstitution::from_class_def`]); pass [`TypeSubstitution::empty`] for static methods
+/// and non-generic owners.
+pub fn infer_type_arguments_bidirectional(
+    env: &dyn TypeEnv,
+    call: &MethodCall<'_>,
+    method: &MethodDef,
+    class_subst: &TypeSubstitution,
+) -> InferenceResult {
+    if method.type_params.is_empty() {
+        return InferenceResult::default();
+    }
+
+    let partial =
+        call.allow_partial_type_args && call.explicit_type_args.len() < method.type_params.len();
+    if !call.explicit_type_args.is_empty() && !partial {
+        return InferenceResult {
+            inferred: call.explicit_type_args.clone(),
+            ..InferenceResult::default()
+        };
+    }
 
-    infer_type_arguments_from_call(env, method, &params, &return_type, call)
+    let params: Vec<Type> = method.params.iter().map(|t| class_subst.apply(t)).collect();
+    let return_type = substitute_read_type(env, &method.return_type, class_subst);
+    solve_type_arguments_with_prefix(env, method, &params, &return_type, call)
 }
 
 pub fn infer_diamond_type_args(
@@ -5329,46 +10312,118 @@ pub fn infer_diamond_type_args(
         return Vec::new();
     }
 
+    let object = Type::class(env.well_known().object, vec![]);
+    let mapping = diamond_target_substitution(env, class, target).unwrap_or_default();
+
+    // Fall back to Object for each type parameter the target leaves open.
+    class_def
+        .type_params
+        .iter()
+        .map(|id| mapping.get(*id).cloned().unwrap_or_else(|| object.clone()))
+        .collect()
+}
+
+/// Infer the type arguments of a diamond class instance creation `new C<>(args)` (JLS 15.9.3).
+///
+/// Unlike [`infer_diamond_type_args`], the constructor arguments constrain the class's type
+/// parameters too: the parameters of the first applicable constructor (see
+/// [`applicable_constructors`]) are treated as templates over the class's type parameters, as if
+/// the constructor were a generic method `<E> C<E> new(Collection<? extends E>)`. Where the
+/// arguments and `target` disagree, the target wins. Type parameters constrained by neither fall
+/// back to their bound.
+pub fn infer_diamond_constructor_call(
+    env: &dyn TypeEnv,
+    class: ClassId,
+    args: &[Type],
+    target: Option<&Type>,
+) -> Vec<Type> {
+    let Some(class_def) = env.class(class) else {
+        return Vec::new();
+    };
+
+    if class_def.type_params.is_empty() {
+        return Vec::new();
+    }
+
+    let Some(ctor) = applicable_constructors(env, class, args).into_iter().next() else {
+        return infer_diamond_type_args(env, class, target);
+    };
+
+    let type_params = class_def.type_params.clone();
+    let method = MethodDef {
+        name: "<init>".to_string(),
+        type_params: type_params.clone(),
+        params: ctor.params.clone(),
+        param_names: vec![],
+        return_type: Type::class(
+            class,
+            type_params.iter().copied().map(Type::TypeVar).collect(),
+        ),
+        is_static: false,
+        is_varargs: ctor.is_varargs,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    };
+    let params = if ctor.params.len() == args.len() {
+        ctor.params.clone()
+    } else {
+        expand_params_for_arity(&ctor.params, ctor.is_varargs, args.len())
+            .unwrap_or_else(|| ctor.params.clone())
+    };
+    let call = MethodCall {
+        receiver: Type::class(class, vec![]),
+        call_kind: CallKind::Instance,
+        name: "<init>",
+        args: args.to_vec(),
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let from_args =
+        infer_type_arguments_from_call(env, &method, &params, &method.return_type, &call);
+
+    let from_target = diamond_target_substitution(env, class, target).unwrap_or_default();
+    type_params
+        .iter()
+        .zip(from_args)
+        .map(|(id, inferred)| from_target.get(*id).cloned().unwrap_or(inferred))
+        .collect()
+}
+
+/// The type arguments of `class` determined by the target type of a diamond instance creation,
+/// either directly (`Box<String> b = new Box<>()`) or through a supertype
+/// (`List<String> xs = new ArrayList<>()`).
+fn diamond_target_substitution(
+    env: &dyn TypeEnv,
+    class: ClassId,
+    target: Option<&Type>,
+) -> Option<TypeSubstitution> {
+    let class_def = env.class(class)?;
+
     if let Some(Type::Class(ClassType { def, args })) = target {
         if *def == class && args.len() == class_def.type_params.len() {
-            return args.clone();
+            return Some(TypeSubstitution::from_class_def(class_def, args));
         }
     }
 
-    let object = Type::class(env.well_known().object, vec![]);
-
     // Best-effort: infer type parameters from a supertype target, e.g.
     // `List<String> xs = new ArrayList<>()` => `ArrayList<String>`.
-    if let Some(target_ty) = target {
-        let target_class = match target_ty {
-            Type::Class(ct) => Some(ct.clone()),
-            Type::Named(name) => env.lookup_class_by_source_name(name).map(|id| ClassType {
-                def: id,
-                args: vec![],
-            }),
-            _ => None,
-        };
-
-        if let Some(target_class) = target_class {
-            if !target_class.args.is_empty() {
-                if let Some(mapping) = infer_class_type_arguments_from_target(
-                    env,
-                    class,
-                    target_class.def,
-                    &target_class.args,
-                ) {
-                    return class_def
-                        .type_params
-                        .iter()
-                        .map(|id| mapping.get(id).cloned().unwrap_or_else(|| object.clone()))
-                        .collect();
-                }
-            }
-        }
+    let target_class = match target? {
+        Type::Class(ct) => ct.clone(),
+        Type::Named(name) => ClassType {
+            def: env.lookup_class_by_source_name(name)?,
+            args: vec![],
+        },
+        _ => return None,
+    };
+    if target_class.args.is_empty() {
+        return None;
     }
-
-    // Fall back to Object for each type parameter.
-    vec![object; class_def.type_params.len()]
+    infer_class_type_arguments_from_target(env, class, target_class.def, &target_class.args)
 }
 
 pub fn infer_lambda_param_types(env: &dyn TypeEnv, target: &Type) -> Option<Vec<Type>> {
@@ -5388,39 +10443,258 @@ pub fn infer_lambda_sam_signature(env: &dyn TypeEnv, target: &Type) -> Option<La
     })
 }
 
+/// The return type of a lambda targeting `sam_target` whose body has type `body_type`
+/// (JLS 15.27.3): the SAM's return type if the body is compatible with it in an assignment
+/// context, `None` if it isn't or `sam_target` is not a functional interface.
+///
+/// A `void` SAM accepts any body, since an expression body may be a statement expression whose
+/// value is discarded (`s -> list.add(s)`). A value-returning SAM rejects a `void` body.
+pub fn infer_lambda_return_type(
+    env: &dyn TypeEnv,
+    sam_target: &Type,
+    body_type: &Type,
+) -> Option<Type> {
+    let sam_return = infer_lambda_sam_signature(env, sam_target)?.return_type;
+    match (&sam_return, body_type) {
+        (Type::Void, _) => Some(Type::Void),
+        (_, Type::Void) => None,
+        _ => is_assignable(env, body_type, &sam_return).then_some(sam_return),
+    }
+}
+
+/// Upper bound on the number of interfaces searched by [`infer_lambda_target_from_context`].
+const MAX_LAMBDA_TARGET_CANDIDATES: usize = 256;
+
+/// Best-effort functional interface for a lambda whose target type is unknown, e.g. to suggest
+/// extracting the lambda into a variable.
+///
+/// `expected` is returned if it is a functional interface. Otherwise the environment's
+/// interfaces are searched for a SAM whose parameters are exactly `explicit_params` and whose
+/// return type accepts `body_type` (`Type::Void` for a void body, an errorish type when
+/// unknown). The interface's type parameters are instantiated from the parameters and the body
+/// type, so `() -> "x"` yields `Supplier<String>`.
+///
+/// A value-returning SAM is preferred over a `void` one for a value body. Returns `None` when no
+/// interface fits or several fit equally well.
+pub fn infer_lambda_target_from_context(
+    env: &dyn TypeEnv,
+    expected: Option<&Type>,
+    explicit_params: &[Type],
+    body_type: &Type,
+) -> Option<Type> {
+    if let Some(expected) = expected.filter(|ty| sam_signature(env, ty).is_some()) {
+        return Some(expected.clone());
+    }
+
+    let mut best: Option<(bool, Type)> = None;
+    let mut tied = false;
+    for id in env.interfaces(MAX_LAMBDA_TARGET_CANDIDATES) {
+        let Some((target, exact)) = lambda_target_candidate(env, id, explicit_params, body_type)
+        else {
+            continue;
+        };
+        match &best {
+            Some((best_exact, _)) if *best_exact == exact => tied = true,
+            Some((true, _)) => {}
+            _ => {
+                best = Some((exact, target));
+                tied = false;
+            }
+        }
+    }
+
+    if tied {
+        return None;
+    }
+    best.map(|(_, target)| target)
+}
+
+/// `iface` instantiated as the target of a lambda with `params` and `body_type`, and whether its
+/// SAM is an exact fit (a `void` SAM only loosely fits a value body).
+fn lambda_target_candidate(
+    env: &dyn TypeEnv,
+    iface: ClassId,
+    params: &[Type],
+    body_type: &Type,
+) -> Option<(Type, bool)> {
+    let class_def = env.class(iface)?;
+    if class_def.kind != ClassKind::Interface {
+        return None;
+    }
+    let declared = Type::class(
+        iface,
+        class_def
+            .type_params
+            .iter()
+            .copied()
+            .map(Type::TypeVar)
+            .collect(),
+    );
+    let sam = sam_signature(env, &declared)?;
+    if sam.params.len() != params.len() {
+        return None;
+    }
+
+    // Bind the interface's type parameters from the SAM signature.
+    let mut bindings: HashMap<TypeVarId, Type> = HashMap::new();
+    let mut bind = |declared: &Type, actual: Type| {
+        if let Type::TypeVar(tv) = declared {
+            if class_def.type_params.contains(tv) {
+                bindings.entry(*tv).or_insert(actual);
+            }
+        }
+    };
+    for (declared, actual) in sam.params.iter().zip(params) {
+        bind(declared, actual.clone());
+    }
+    let body_value = match body_type {
+        Type::Primitive(prim) => boxing_type(env, *prim),
+        Type::Void => None,
+        other if other.is_errorish() => None,
+        other => Some(other.clone()),
+    };
+    if let Some(value) = body_value {
+        bind(&sam.return_type, value);
+    }
+
+    let args: Vec<Type> = class_def
+        .type_params
+        .iter()
+        .map(|tv| {
+            bindings
+                .get(tv)
+                .cloned()
+                .unwrap_or(Type::Wildcard(WildcardBound::Unbounded))
+        })
+        .collect();
+    let subst = TypeSubstitution::from_class_def(class_def, &args);
+    if sam
+        .params
+        .iter()
+        .zip(params)
+        .any(|(declared, actual)| substitute(declared, &subst) != *actual)
+    {
+        return None;
+    }
+
+    let sam_return = substitute(&sam.return_type, &subst);
+    let exact = match (&sam_return, body_type) {
+        (Type::Void, body) => *body == Type::Void || body.is_errorish(),
+        (_, Type::Void) => return None,
+        (_, body) if body.is_errorish() => true,
+        (sam_return, body) => {
+            if !is_assignable(env, body, sam_return) {
+                return None;
+            }
+            true
+        }
+    };
+    Some((Type::class(iface, args), exact))
+}
+
+/// The shape of a method reference expression (JLS 15.13).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MethodRefKind {
+    /// `Type::staticMethod`
+    Static,
+    /// `expr::method`, where the receiver is already bound.
+    Bound,
+    /// `Type::instanceMethod`, where the SAM's first parameter becomes the receiver.
+    Unbound,
+    /// `Type::new`
+    Constructor,
+}
+
+/// Check that `method`, referenced as a method reference of the given `kind`, is compatible with
+/// the functional interface signature `sam` (JLS 15.13.2, best-effort).
+///
+/// The SAM parameters (minus the receiver for [`MethodRefKind::Unbound`]) must be convertible to
+/// the method's parameters by method invocation conversion, and unless the SAM returns `void`,
+/// the method's return type must be assignment-compatible with the SAM's return type.
+pub fn method_ref_matches_sam(
+    env: &dyn TypeEnv,
+    sam: &LambdaSamSignature,
+    method: &ResolvedMethod,
+    kind: MethodRefKind,
+) -> bool {
+    let args = match kind {
+        MethodRefKind::Static => {
+            if !method.is_static {
+                return false;
+            }
+            &sam.params[..]
+        }
+        MethodRefKind::Bound | MethodRefKind::Constructor => {
+            if method.is_static {
+                return false;
+            }
+            &sam.params[..]
+        }
+        MethodRefKind::Unbound => {
+            if method.is_static {
+                return false;
+            }
+            let Some((receiver, rest)) = sam.params.split_first() else {
+                return false;
+            };
+            let owner = Type::class(method.owner, vec![]);
+            if !receiver.is_errorish() && !is_subtype(env, &erasure(env, receiver), &owner) {
+                return false;
+            }
+            rest
+        }
+    };
+
+    // Try fixed arity first (including passing an array to a varargs parameter), then
+    // variable-arity expansion.
+    let declared = method.signature_params.as_ref().unwrap_or(&method.params);
+    let params_match = |params: &[Type]| {
+        params.len() == args.len()
+            && args
+                .iter()
+                .zip(params)
+                .all(|(arg, param)| method_invocation_conversion(env, arg, param).is_some())
+    };
+    let applicable = params_match(declared)
+        || (method.is_varargs
+            && expand_params_for_arity(declared, true, args.len())
+                .is_some_and(|params| params_match(&params)));
+    if !applicable {
+        return false;
+    }
+
+    match (&sam.return_type, &method.return_type) {
+        (Type::Void, _) => true,
+        (_, Type::Void) => false,
+        (expected, found) => assignment_conversion(env, found, expected).is_some(),
+    }
+}
+
 fn class_substitution_for_owner(
     env: &dyn TypeEnv,
     receiver: &Type,
     owner: ClassId,
-) -> HashMap<TypeVarId, Type> {
-    let mut subst = HashMap::new();
-
+) -> TypeSubstitution {
     let Type::Class(ClassType { def, args }) = receiver else {
-        return subst;
+        return TypeSubstitution::empty();
     };
 
     let owner_instantiation = instantiate_as(env, *def, args.clone(), owner);
     let Some(owner_instantiation) = owner_instantiation else {
-        return subst;
+        return TypeSubstitution::empty();
     };
 
     let Some(owner_def) = env.class(owner) else {
-        return subst;
+        return TypeSubstitution::empty();
     };
 
-    if owner_def.type_params.len() != owner_instantiation.len() {
-        return subst;
+    if owner_def.type_params.len() != owner_instantiation.len()
+        && flattened_type_params(env, owner_def).len() != owner_instantiation.len()
+    {
+        return TypeSubstitution::empty();
     }
 
-    subst.extend(
-        owner_def
-            .type_params
-            .iter()
-            .copied()
-            .zip(owner_instantiation),
-    );
-
-    subst
+    TypeSubstitution::from_class_def_with_enclosing(env, owner_def, &owner_instantiation)
 }
 
 /// Instantiate `ty` as `target_def`, returning the type arguments of `target_def`
@@ -5463,7 +10737,7 @@ fn infer_class_type_arguments_from_target(
     class: ClassId,
     target_def: ClassId,
     target_args: &[Type],
-) -> Option<HashMap<TypeVarId, Type>> {
+) -> Option<TypeSubstitution> {
     let class_def = env.class(class)?;
     if class_def.type_params.is_empty() {
         return None;
@@ -5488,7 +10762,7 @@ fn infer_class_type_arguments_from_target(
     for (pattern, actual) in args.iter().zip(target_args) {
         collect_type_var_constraints(&mut mapping, pattern, actual);
     }
-    Some(mapping)
+    Some(mapping.into())
 }
 
 // === Minimal expression typing ==============================================
@@ -5527,6 +10801,10 @@ pub fn type_of<'env>(ctx: &mut TyContext<'env>, expr: &Expr) -> Type {
                 args: arg_tys,
                 expected_return: expected_return.clone(),
                 explicit_type_args: vec![],
+                allow_partial_type_args: false,
+                start_above: None,
+                caller_context: None,
+                arg_shapes: vec![],
             };
             match resolve_method_call(ctx, &call) {
                 MethodResolution::Found(m) => m.return_type,
@@ -5568,6 +10846,58 @@ mod tests {
         assert!(is_assignable(&env, &Type::Null, &obj));
     }
 
+    #[test]
+    fn wildcard_bound_accessors() {
+        let env = store();
+        let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+        let integer = Type::class(env.well_known().integer, vec![]);
+
+        let extends = Type::Wildcard(WildcardBound::Extends(Box::new(number.clone())));
+        let bound = extends.wildcard_bound().unwrap();
+        assert_eq!(bound.upper(), Some(&number));
+        assert_eq!(bound.lower(), None);
+
+        let super_ = Type::Wildcard(WildcardBound::Super(Box::new(integer.clone())));
+        let bound = super_.wildcard_bound().unwrap();
+        assert_eq!(bound.upper(), None);
+        assert_eq!(bound.lower(), Some(&integer));
+
+        let unbounded = Type::Wildcard(WildcardBound::Unbounded);
+        let bound = unbounded.wildcard_bound().unwrap();
+        assert_eq!(bound.upper(), None);
+        assert_eq!(bound.lower(), None);
+
+        assert!(extends.is_wildcard() && super_.is_wildcard() && unbounded.is_wildcard());
+        assert!(!number.is_wildcard());
+        assert_eq!(number.wildcard_bound(), None);
+    }
+
+    #[test]
+    fn primitive_keywords_round_trip() {
+        let all = [
+            ("boolean", PrimitiveType::Boolean),
+            ("byte", PrimitiveType::Byte),
+            ("short", PrimitiveType::Short),
+            ("char", PrimitiveType::Char),
+            ("int", PrimitiveType::Int),
+            ("long", PrimitiveType::Long),
+            ("float", PrimitiveType::Float),
+            ("double", PrimitiveType::Double),
+        ];
+        for (keyword, prim) in all {
+            assert_eq!(PrimitiveType::from_keyword(keyword), Some(prim));
+            assert_eq!(prim.keyword(), keyword);
+            assert_eq!(Type::from_keyword(keyword), Some(Type::Primitive(prim)));
+        }
+
+        assert_eq!(Type::from_keyword("void"), Some(Type::Void));
+        assert_eq!(PrimitiveType::from_keyword("void"), None);
+        for not_a_keyword in ["Integer", "INT", " int", "int[]", "var", ""] {
+            assert_eq!(PrimitiveType::from_keyword(not_a_keyword), None);
+            assert_eq!(Type::from_keyword(not_a_keyword), None);
+        }
+    }
+
     #[test]
     fn type_store_resolves_java_lang_simple_names() {
         let env = store();
@@ -5589,6 +10919,10 @@ mod tests {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
         let dog = env.add_class(ClassDef {
             name: "Dog".to_string(),
@@ -5599,6 +10933,10 @@ mod tests {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         assert!(is_subtype(
@@ -5632,21 +10970,31 @@ mod tests {
                     name: "m".to_string(),
                     type_params: vec![],
                     params: vec![Type::class(object, vec![])],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
                 MethodDef {
                     name: "m".to_string(),
                     type_params: vec![],
                     params: vec![Type::class(string, vec![])],
+                    param_names: vec![],
                     return_type: Type::Void,
                     is_static: false,
                     is_varargs: false,
                     is_abstract: false,
+                    is_private: false,
+                    deprecation: None,
                 },
             ],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let call = MethodCall {
@@ -5656,6 +11004,10 @@ mod tests {
             args: vec![Type::class(string, vec![])],
             expected_return: None,
             explicit_type_args: vec![],
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
         };
 
         let mut ctx = TyContext::new(&env);
@@ -5732,11 +11084,18 @@ mod tests {
                 name: "id".to_string(),
                 type_params: vec![t],
                 params: vec![Type::TypeVar(t)],
+                param_names: vec![],
                 return_type: Type::TypeVar(t),
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let call = MethodCall {
@@ -5746,6 +11105,10 @@ mod tests {
             args: vec![string.clone()],
             expected_return: None,
             explicit_type_args: vec![],
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
         };
         let method = &env.class(util).unwrap().methods[0];
         let inferred = infer_type_arguments(&env, &call, util, method);
@@ -5771,11 +11134,18 @@ mod tests {
                 name: "id".to_string(),
                 type_params: vec![t],
                 params: vec![Type::TypeVar(t)],
+                param_names: vec![],
                 return_type: Type::TypeVar(t),
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let call = MethodCall {
@@ -5785,6 +11155,10 @@ mod tests {
             args: vec![Type::Unknown],
             expected_return: Some(string.clone()),
             explicit_type_args: vec![],
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
         };
         let method = &env.class(util).unwrap().methods[0];
         let inferred = infer_type_arguments(&env, &call, util, method);
@@ -5857,6 +11231,10 @@ mod tests {
             args: vec![],
             expected_return: Some(expected_return),
             explicit_type_args: vec![],
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
         };
         let method = &env.class(collections).unwrap().methods[0];
         let inferred = infer_type_arguments(&env, &call, collections, method);
@@ -5884,11 +11262,18 @@ mod tests {
                 name: "id".to_string(),
                 type_params: vec![t],
                 params: vec![Type::TypeVar(t)],
+                param_names: vec![],
                 return_type: Type::TypeVar(t),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
         let sub_i = env.add_class(ClassDef {
             name: "SubI".to_string(),
@@ -5902,18 +11287,25 @@ mod tests {
                 name: "id".to_string(),
                 type_params: vec![],
                 params: vec![object.clone()],
+                param_names: vec![],
                 return_type: object.clone(),
                 is_static: false,
                 is_varargs: false,
                 is_abstract: true,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         });
 
         let receiver = Type::Intersection(vec![
             Type::class(super_i, vec![]),
             Type::class(sub_i, vec![]),
         ]);
-        let cands = collect_method_candidates(&env, &receiver, "id");
+        let cands = collect_method_candidates(&env, &receiver, "id", None);
         assert_eq!(cands.len(), 1);
         assert_eq!(cands[0].owner, sub_i);
         assert!(cands[0].method.type_params.is_empty());
@@ -5988,10 +11380,11 @@ impl TypeRef {
 // Refactoring IDs
 // -----------------------------------------------------------------------------
 
-/// Identifies a method symbol within an `nova-index::Index` snapshot.
+/// Identifies a method symbol within an `nova-index::Index` snapshot, or a method of a
+/// [`TypeStore`] class (see [`MethodId::for_method`]).
 ///
 /// This is *not* stable across edits; it is intended to be resolved against a
-/// single index snapshot before applying a refactoring.
+/// single snapshot before applying a refactoring.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct MethodId(pub u32);
 
@@ -5999,4 +11392,66 @@ impl MethodId {
     pub fn new(raw: u32) -> Self {
         Self(raw)
     }
+
+    /// The id of `owner.methods[method_idx]` in `store`, encoded as
+    /// `owner << 16 | method_idx`.
+    ///
+    /// Returns `None` if the method doesn't exist, or if the class id or method index doesn't
+    /// fit in 16 bits.
+    pub fn for_method(store: &TypeStore, owner: ClassId, method_idx: usize) -> Option<MethodId> {
+        let class = u16::try_from(owner.to_raw()).ok()?;
+        let method = u16::try_from(method_idx).ok()?;
+        store.class(owner)?.methods.get(method_idx)?;
+        Some(MethodId((u32::from(class) << 16) | u32::from(method)))
+    }
+
+    /// Look up a method id created by [`MethodId::for_method`].
+    ///
+    /// The id is only meaningful for the store it was created from (or a later version of it).
+    /// `None` means the method no longer exists: the class was removed or redefined with fewer
+    /// methods, or `store` doesn't have the class at all.
+    pub fn resolve(self, store: &TypeStore) -> Option<(&ClassDef, &MethodDef)> {
+        let owner = ClassId::from_raw(self.0 >> 16);
+        let method_idx = (self.0 & 0xFFFF) as usize;
+        let class = store.class(owner)?;
+        Some((class, class.methods.get(method_idx)?))
+    }
+}
+
+/// Identifies a field of a [`TypeStore`] class, encoded like [`MethodId`] as
+/// `owner << 16 | field_idx`.
+///
+/// Like [`MethodId`], this is *not* stable across edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct FieldId(pub u32);
+
+impl FieldId {
+    pub fn new(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// The id of the field named `field_name` declared by `owner` in `store`.
+    ///
+    /// Returns `None` if `owner` doesn't declare such a field (inherited fields are not looked
+    /// up), or if the class id or field index doesn't fit in 16 bits.
+    pub fn for_field(store: &TypeStore, owner: ClassId, field_name: &str) -> Option<Self> {
+        let class = u16::try_from(owner.to_raw()).ok()?;
+        let field_idx = store
+            .class(owner)?
+            .fields
+            .iter()
+            .position(|field| field.name == field_name)?;
+        let field = u16::try_from(field_idx).ok()?;
+        Some(FieldId((u32::from(class) << 16) | u32::from(field)))
+    }
+
+    /// Look up a field id created by [`FieldId::for_field`].
+    ///
+    /// As with [`MethodId::resolve`], `None` means the field no longer exists in `store`.
+    pub fn resolve<'a>(&self, store: &'a TypeStore) -> Option<(&'a ClassDef, &'a FieldDef)> {
+        let owner = ClassId::from_raw(self.0 >> 16);
+        let field_idx = (self.0 & 0xFFFF) as usize;
+        let class = store.class(owner)?;
+        Some((class, class.fields.get(field_idx)?))
+    }
 }
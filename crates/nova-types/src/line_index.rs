@@ -0,0 +1,185 @@
+//! Byte offset <-> line/column conversion.
+
+/// A 0-based line/column position of a byte offset.
+///
+/// `col_utf16` counts UTF-16 code units (what LSP calls a character offset), so characters outside
+/// the BMP such as emoji count as two; `col_utf8` counts bytes from the start of the line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u32,
+    pub col_utf16: u32,
+    pub col_utf8: u32,
+}
+
+impl Position {
+    /// The same position with every field counted from 1, as compilers print them.
+    pub fn one_based(self) -> Position {
+        Position {
+            line: self.line + 1,
+            col_utf16: self.col_utf16 + 1,
+            col_utf8: self.col_utf8 + 1,
+        }
+    }
+}
+
+/// Line start offsets of a source string, for converting byte offsets into [`Position`]s and back.
+///
+/// Lines are terminated by `\n`, `\r\n`, or a lone `\r`. Building the index is a single pass over
+/// the text; the text itself is not retained.
+///
+/// ```
+/// use nova_types::{Diagnostic, LineIndex, Span};
+///
+/// let text = "class A {\n  String s = \"😀\"; int x = y;\n}\n";
+/// let start = text.find('y').unwrap();
+/// let span = Span::new(start, start + 1);
+/// let diagnostic = Diagnostic::error("unresolved", "cannot find `y`", Some(span));
+///
+/// let index = LineIndex::new(text);
+/// let pos = index.position(diagnostic.span.unwrap().start).one_based();
+/// // The emoji is 4 bytes but 2 UTF-16 code units.
+/// assert_eq!(format!("{}:{}", pos.line, pos.col_utf16), "2:28");
+/// assert_eq!(pos.col_utf8, 30);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineIndex {
+    lines: Vec<Line>,
+    len: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Line {
+    start: usize,
+    /// End of the line's content, before its terminator.
+    end: usize,
+    /// Characters that are longer in UTF-8 than in UTF-16, in order.
+    wide_chars: Vec<WideChar>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct WideChar {
+    /// Byte offset from the start of the line.
+    col: usize,
+    len_utf8: usize,
+    len_utf16: usize,
+}
+
+impl WideChar {
+    fn end(self) -> usize {
+        self.col + self.len_utf8
+    }
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut lines = Vec::new();
+        let mut line = Line::default();
+        let mut chars = text.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            let terminator_len = match ch {
+                '\n' => 1,
+                '\r' if chars.peek().is_some_and(|&(_, next)| next == '\n') => {
+                    chars.next();
+                    2
+                }
+                '\r' => 1,
+                _ => {
+                    if ch.len_utf8() > ch.len_utf16() {
+                        line.wide_chars.push(WideChar {
+                            col: offset - line.start,
+                            len_utf8: ch.len_utf8(),
+                            len_utf16: ch.len_utf16(),
+                        });
+                    }
+                    continue;
+                }
+            };
+            line.end = offset;
+            let next_start = offset + terminator_len;
+            lines.push(std::mem::replace(
+                &mut line,
+                Line {
+                    start: next_start,
+                    ..Line::default()
+                },
+            ));
+        }
+        line.end = text.len();
+        lines.push(line);
+
+        Self {
+            lines,
+            len: text.len(),
+        }
+    }
+
+    /// Number of lines; a trailing terminator starts a final, empty line.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The position of `offset`.
+    ///
+    /// Offsets past the end of the text are clamped to the end, and offsets inside a multi-byte
+    /// character are moved back to the start of that character.
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.len);
+        let line_idx = self
+            .lines
+            .partition_point(|line| line.start <= offset)
+            .saturating_sub(1);
+        let line = &self.lines[line_idx];
+
+        let mut col = offset - line.start;
+        let mut col_utf16 = col;
+        for wide in &line.wide_chars {
+            if wide.end() <= col {
+                col_utf16 -= wide.len_utf8 - wide.len_utf16;
+            } else {
+                if wide.col < col {
+                    col_utf16 -= col - wide.col;
+                    col = wide.col;
+                }
+                break;
+            }
+        }
+
+        Position {
+            line: line_idx as u32,
+            col_utf16: col_utf16 as u32,
+            col_utf8: col as u32,
+        }
+    }
+
+    /// The byte offset of a 0-based `line` and UTF-16 column.
+    ///
+    /// Out-of-range input is clamped rather than rejected: lines past the end map to the last line,
+    /// columns past the end of a line map to the end of its content, and a column that splits a
+    /// surrogate pair maps to the start of that character.
+    pub fn offset_of(&self, line: u32, col_utf16: u32) -> usize {
+        let Some(line) = self.lines.get(line as usize).or_else(|| self.lines.last()) else {
+            return 0;
+        };
+
+        let target = col_utf16 as usize;
+        // Byte column and UTF-16 column of the scan position.
+        let mut col = 0;
+        let mut utf16 = 0;
+        for wide in &line.wide_chars {
+            let before = wide.col - col;
+            if utf16 + before >= target {
+                break;
+            }
+            utf16 += before;
+            col = wide.col;
+            if utf16 + wide.len_utf16 > target {
+                return line.start + col;
+            }
+            utf16 += wide.len_utf16;
+            col = wide.end();
+        }
+
+        let col = col + (target - utf16.min(target));
+        line.start + col.min(line.end - line.start)
+    }
+}
@@ -0,0 +1,407 @@
+//! Self-describing forms of the semantic query results, for tools that consume them as JSON.
+//!
+//! [`Type`] and the method resolution results serialize [`ClassId`]s and [`TypeVarId`]s as bare
+//! integers, which mean nothing outside the [`TypeEnv`] that allocated them. The `Portable*`
+//! mirrors embed the binary name of every class and the name of every type variable next to the
+//! id, so a dump can be read without the environment. Convert with the `to_portable` methods,
+//! and use [`json_schemas`] for the schemas of the wire format.
+//!
+//! Enums use serde's default externally tagged representation, like the core types: unit
+//! variants are strings (`"Void"`) and the others are single-key objects
+//! (`{"Array": {"Primitive": "Int"}}`).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CallKind, ClassId, ClassType, Conversion, MethodAmbiguity, MethodCandidate,
+    MethodCandidateDiagnostics, MethodCandidateFailure, MethodCandidateFailureReason,
+    MethodNotFound, MethodResolution, MethodSearchPhase, PrimitiveType, ResolvedMethod, Type,
+    TypeEnv, TypeVarId, TypeWarning, WildcardBound,
+};
+
+/// Version of the JSON wire format described by [`json_schemas`].
+///
+/// Bump it whenever a change to the serialized types could break existing consumers.
+pub const JSON_SCHEMA_VERSION: &str = "1";
+
+/// The JSON schemas of the semantic query results, keyed by type name, with
+/// [`JSON_SCHEMA_VERSION`].
+pub fn json_schemas() -> serde_json::Value {
+    fn schema<T: JsonSchema>() -> serde_json::Value {
+        serde_json::to_value(schemars::schema_for!(T)).expect("schemas serialize to JSON")
+    }
+
+    serde_json::json!({
+        "version": JSON_SCHEMA_VERSION,
+        "schemas": {
+            "Type": schema::<Type>(),
+            "ResolvedMethod": schema::<ResolvedMethod>(),
+            "MethodResolution": schema::<MethodResolution>(),
+            "PortableType": schema::<PortableType>(),
+            "PortableResolvedMethod": schema::<PortableResolvedMethod>(),
+            "PortableMethodResolution": schema::<PortableMethodResolution>(),
+        },
+    })
+}
+
+/// A class id with the binary name it had in the environment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct PortableClassRef {
+    pub id: u32,
+    /// `None` if the environment does not define the class.
+    pub name: Option<String>,
+}
+
+impl PortableClassRef {
+    pub fn new(env: &dyn TypeEnv, id: ClassId) -> Self {
+        Self {
+            id: id.to_raw(),
+            name: env.class(id).map(|def| def.name.clone()),
+        }
+    }
+}
+
+/// A type variable id with the name of its type parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct PortableTypeVarRef {
+    pub id: u32,
+    /// `None` if the environment does not define the type parameter.
+    pub name: Option<String>,
+}
+
+impl PortableTypeVarRef {
+    pub fn new(env: &dyn TypeEnv, id: TypeVarId) -> Self {
+        Self {
+            id: id.0,
+            name: env.type_param(id).map(|def| def.name.clone()),
+        }
+    }
+}
+
+/// [`Type`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum PortableType {
+    Void,
+    Primitive(PrimitiveType),
+    Class(PortableClassType),
+    Array(Box<PortableType>),
+    TypeVar(PortableTypeVarRef),
+    Wildcard(PortableWildcardBound),
+    Intersection(Vec<PortableType>),
+    Null,
+    Named(String),
+    VirtualInner {
+        owner: PortableClassRef,
+        name: String,
+    },
+    Unknown,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct PortableClassType {
+    pub class: PortableClassRef,
+    pub args: Vec<PortableType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum PortableWildcardBound {
+    Unbounded,
+    Extends(Box<PortableType>),
+    Super(Box<PortableType>),
+}
+
+impl Type {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableType {
+        match self {
+            Type::Void => PortableType::Void,
+            Type::Primitive(prim) => PortableType::Primitive(*prim),
+            Type::Class(ClassType { def, args }) => PortableType::Class(PortableClassType {
+                class: PortableClassRef::new(env, *def),
+                args: portable_types(env, args),
+            }),
+            Type::Array(elem) => PortableType::Array(Box::new(elem.to_portable(env))),
+            Type::TypeVar(id) => PortableType::TypeVar(PortableTypeVarRef::new(env, *id)),
+            Type::Wildcard(bound) => PortableType::Wildcard(match bound {
+                WildcardBound::Unbounded => PortableWildcardBound::Unbounded,
+                WildcardBound::Extends(upper) => {
+                    PortableWildcardBound::Extends(Box::new(upper.to_portable(env)))
+                }
+                WildcardBound::Super(lower) => {
+                    PortableWildcardBound::Super(Box::new(lower.to_portable(env)))
+                }
+            }),
+            Type::Intersection(parts) => PortableType::Intersection(portable_types(env, parts)),
+            Type::Null => PortableType::Null,
+            Type::Named(name) => PortableType::Named(name.clone()),
+            Type::VirtualInner { owner, name } => PortableType::VirtualInner {
+                owner: PortableClassRef::new(env, *owner),
+                name: name.clone(),
+            },
+            Type::Unknown => PortableType::Unknown,
+            Type::Error => PortableType::Error,
+        }
+    }
+}
+
+fn portable_types(env: &dyn TypeEnv, types: &[Type]) -> Vec<PortableType> {
+    types.iter().map(|ty| ty.to_portable(env)).collect()
+}
+
+/// [`ResolvedMethod`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableResolvedMethod {
+    pub owner: PortableClassRef,
+    pub name: String,
+    pub params: Vec<PortableType>,
+    pub signature_params: Option<Vec<PortableType>>,
+    pub param_names: Vec<String>,
+    pub return_type: PortableType,
+    pub is_varargs: bool,
+    pub is_static: bool,
+    pub conversions: Vec<Conversion>,
+    pub inferred_type_args: Vec<PortableType>,
+    pub warnings: Vec<TypeWarning>,
+    pub used_varargs: bool,
+    pub phase: MethodSearchPhase,
+    pub speculative: bool,
+    pub depends_on_placeholders: Vec<PortableClassRef>,
+}
+
+impl ResolvedMethod {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableResolvedMethod {
+        PortableResolvedMethod {
+            owner: PortableClassRef::new(env, self.owner),
+            name: self.name.clone(),
+            params: portable_types(env, &self.params),
+            signature_params: self
+                .signature_params
+                .as_ref()
+                .map(|params| portable_types(env, params)),
+            param_names: self.param_names.clone(),
+            return_type: self.return_type.to_portable(env),
+            is_varargs: self.is_varargs,
+            is_static: self.is_static,
+            conversions: self.conversions.clone(),
+            inferred_type_args: portable_types(env, &self.inferred_type_args),
+            warnings: self.warnings.clone(),
+            used_varargs: self.used_varargs,
+            phase: self.phase,
+            speculative: self.speculative,
+            depends_on_placeholders: self
+                .depends_on_placeholders
+                .iter()
+                .map(|&id| PortableClassRef::new(env, id))
+                .collect(),
+        }
+    }
+}
+
+/// [`MethodCandidate`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableMethodCandidate {
+    pub owner: PortableClassRef,
+    pub name: String,
+    pub params: Vec<PortableType>,
+    pub param_names: Vec<String>,
+    pub return_type: PortableType,
+    pub is_static: bool,
+    pub is_varargs: bool,
+    pub type_param_count: usize,
+}
+
+impl MethodCandidate {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodCandidate {
+        PortableMethodCandidate {
+            owner: PortableClassRef::new(env, self.owner),
+            name: self.name.clone(),
+            params: portable_types(env, &self.params),
+            param_names: self.param_names.clone(),
+            return_type: self.return_type.to_portable(env),
+            is_static: self.is_static,
+            is_varargs: self.is_varargs,
+            type_param_count: self.type_param_count,
+        }
+    }
+}
+
+/// [`MethodCandidateFailureReason`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PortableMethodCandidateFailureReason {
+    WrongCallKind {
+        call_kind: CallKind,
+    },
+    WrongArity {
+        expected: usize,
+        found: usize,
+        is_varargs: bool,
+    },
+    ExplicitTypeArgCountMismatch {
+        expected: usize,
+        found: usize,
+    },
+    TypeArgOutOfBounds {
+        type_param: PortableTypeVarRef,
+        type_arg: PortableType,
+        upper_bound: PortableType,
+    },
+    ArgumentConversion {
+        arg_index: usize,
+        from: PortableType,
+        to: PortableType,
+    },
+    Inaccessible,
+}
+
+impl MethodCandidateFailureReason {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodCandidateFailureReason {
+        match self {
+            MethodCandidateFailureReason::WrongCallKind { call_kind } => {
+                PortableMethodCandidateFailureReason::WrongCallKind {
+                    call_kind: *call_kind,
+                }
+            }
+            MethodCandidateFailureReason::WrongArity {
+                expected,
+                found,
+                is_varargs,
+            } => PortableMethodCandidateFailureReason::WrongArity {
+                expected: *expected,
+                found: *found,
+                is_varargs: *is_varargs,
+            },
+            MethodCandidateFailureReason::ExplicitTypeArgCountMismatch { expected, found } => {
+                PortableMethodCandidateFailureReason::ExplicitTypeArgCountMismatch {
+                    expected: *expected,
+                    found: *found,
+                }
+            }
+            MethodCandidateFailureReason::TypeArgOutOfBounds {
+                type_param,
+                type_arg,
+                upper_bound,
+            } => PortableMethodCandidateFailureReason::TypeArgOutOfBounds {
+                type_param: PortableTypeVarRef::new(env, *type_param),
+                type_arg: type_arg.to_portable(env),
+                upper_bound: upper_bound.to_portable(env),
+            },
+            MethodCandidateFailureReason::ArgumentConversion {
+                arg_index,
+                from,
+                to,
+            } => PortableMethodCandidateFailureReason::ArgumentConversion {
+                arg_index: *arg_index,
+                from: from.to_portable(env),
+                to: to.to_portable(env),
+            },
+            MethodCandidateFailureReason::Inaccessible => {
+                PortableMethodCandidateFailureReason::Inaccessible
+            }
+        }
+    }
+}
+
+/// [`MethodCandidateFailure`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableMethodCandidateFailure {
+    pub phase: MethodSearchPhase,
+    pub reason: PortableMethodCandidateFailureReason,
+}
+
+impl MethodCandidateFailure {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodCandidateFailure {
+        PortableMethodCandidateFailure {
+            phase: self.phase,
+            reason: self.reason.to_portable(env),
+        }
+    }
+}
+
+/// [`MethodCandidateDiagnostics`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableMethodCandidateDiagnostics {
+    pub candidate: PortableMethodCandidate,
+    pub failures: Vec<PortableMethodCandidateFailure>,
+}
+
+impl MethodCandidateDiagnostics {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodCandidateDiagnostics {
+        PortableMethodCandidateDiagnostics {
+            candidate: self.candidate.to_portable(env),
+            failures: self
+                .failures
+                .iter()
+                .map(|failure| failure.to_portable(env))
+                .collect(),
+        }
+    }
+}
+
+/// [`MethodNotFound`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableMethodNotFound {
+    pub receiver: PortableType,
+    pub name: String,
+    pub args: Vec<PortableType>,
+    pub candidates: Vec<PortableMethodCandidateDiagnostics>,
+}
+
+impl MethodNotFound {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodNotFound {
+        PortableMethodNotFound {
+            receiver: self.receiver.to_portable(env),
+            name: self.name.clone(),
+            args: portable_types(env, &self.args),
+            candidates: self
+                .candidates
+                .iter()
+                .map(|candidate| candidate.to_portable(env))
+                .collect(),
+        }
+    }
+}
+
+/// [`MethodAmbiguity`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PortableMethodAmbiguity {
+    pub phase: MethodSearchPhase,
+    pub candidates: Vec<PortableResolvedMethod>,
+}
+
+impl MethodAmbiguity {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodAmbiguity {
+        PortableMethodAmbiguity {
+            phase: self.phase,
+            candidates: self
+                .candidates
+                .iter()
+                .map(|method| method.to_portable(env))
+                .collect(),
+        }
+    }
+}
+
+/// [`MethodResolution`] with named class and type variable references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PortableMethodResolution {
+    Found(PortableResolvedMethod),
+    NotFound(PortableMethodNotFound),
+    Ambiguous(PortableMethodAmbiguity),
+}
+
+impl MethodResolution {
+    pub fn to_portable(&self, env: &dyn TypeEnv) -> PortableMethodResolution {
+        match self {
+            MethodResolution::Found(method) => {
+                PortableMethodResolution::Found(method.to_portable(env))
+            }
+            MethodResolution::NotFound(not_found) => {
+                PortableMethodResolution::NotFound(not_found.to_portable(env))
+            }
+            MethodResolution::Ambiguous(ambiguity) => {
+                PortableMethodResolution::Ambiguous(ambiguity.to_portable(env))
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use nova_types::{
+    alpha_rename, MethodDef, Type, TypeEnv, TypeParamDef, TypeStore, TypeSubstitution,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+/// `<T extends List<T>, U> U m(T t, U[] us)`
+fn generic_method(env: &mut TypeStore) -> MethodDef {
+    let object = Type::class(env.well_known().object, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let t = env.add_type_param("T", vec![]);
+    let u = env.add_type_param("U", vec![object.clone()]);
+    env.define_type_param(
+        t,
+        TypeParamDef {
+            name: "T".to_string(),
+            upper_bounds: vec![Type::class(list, vec![Type::TypeVar(t)])],
+            lower_bound: None,
+            declared_by: None,
+        },
+    );
+    MethodDef {
+        type_params: vec![t, u],
+        is_static: true,
+        ..support::method(
+            "m",
+            vec![Type::TypeVar(t), Type::Array(Box::new(Type::TypeVar(u)))],
+            Type::TypeVar(u),
+        )
+    }
+}
+
+#[test]
+fn renamed_type_params_are_fresh() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let method = generic_method(&mut env);
+
+    let (renamed, subst) = alpha_rename(&mut env, &method);
+    assert_eq!(renamed.type_params.len(), method.type_params.len());
+    for (old, new) in method.type_params.iter().zip(&renamed.type_params) {
+        assert!(!method.type_params.contains(new));
+        assert_eq!(subst.get(*old), Some(&Type::TypeVar(*new)));
+        assert_eq!(
+            env.type_param(*old).unwrap().name,
+            env.type_param(*new).unwrap().name
+        );
+    }
+    assert_eq!(renamed.name, method.name);
+    assert_eq!(renamed.is_static, method.is_static);
+
+    // Renaming twice yields yet another set of ids.
+    let (again, _) = alpha_rename(&mut env, &method);
+    assert!(again
+        .type_params
+        .iter()
+        .all(|id| !renamed.type_params.contains(id)));
+}
+
+#[test]
+fn bounds_are_preserved_and_renamed() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let method = generic_method(&mut env);
+
+    let (renamed, _) = alpha_rename(&mut env, &method);
+    let (t, u) = (renamed.type_params[0], renamed.type_params[1]);
+
+    // The F-bound refers to the renamed `T`, not the original.
+    assert_eq!(
+        env.type_param(t).unwrap().upper_bounds,
+        vec![Type::class(list, vec![Type::TypeVar(t)])]
+    );
+    assert_eq!(env.type_param(u).unwrap().upper_bounds, vec![object]);
+    assert_eq!(env.type_param(u).unwrap().lower_bound, None);
+}
+
+#[test]
+fn substitution_relates_original_and_renamed_signatures() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let method = generic_method(&mut env);
+
+    let (renamed, subst) = alpha_rename(&mut env, &method);
+    let applied: Vec<Type> = method.params.iter().map(|p| subst.apply(p)).collect();
+    assert_eq!(applied, renamed.params);
+    assert_eq!(subst.apply(&method.return_type), renamed.return_type);
+
+    // The inverse renaming recovers the original signature.
+    let inverse = TypeSubstitution::renaming(&renamed.type_params, &method.type_params);
+    let recovered: Vec<Type> = renamed.params.iter().map(|p| inverse.apply(p)).collect();
+    assert_eq!(recovered, method.params);
+    assert_eq!(inverse.apply(&renamed.return_type), method.return_type);
+}
+
+#[test]
+fn non_generic_method_is_unchanged() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let method = support::method("length", vec![string], Type::int());
+    let before = env.type_param_count();
+
+    let (renamed, subst) = alpha_rename(&mut env, &method);
+    assert!(renamed.type_params.is_empty());
+    assert_eq!(renamed.params, method.params);
+    assert_eq!(renamed.return_type, method.return_type);
+    assert!(subst.is_empty());
+    assert_eq!(env.type_param_count(), before);
+}
@@ -0,0 +1,140 @@
+use nova_types::{args_assignable, ArgsMatch, ConversionStep, PrimitiveType, Type, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+fn class(env: &TypeStore, name: &str) -> Type {
+    Type::class(env.class_id(name).unwrap(), vec![])
+}
+
+#[test]
+fn exact_match_reports_each_conversion() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = class(&env, "java.lang.String");
+    let object = class(&env, "java.lang.Object");
+    let integer = class(&env, "java.lang.Integer");
+
+    let result = args_assignable(
+        &env,
+        &[string.clone(), Type::int()],
+        &[object.clone(), integer.clone()],
+        false,
+    );
+    let ArgsMatch::Applicable {
+        conversions,
+        varargs_expanded,
+    } = result
+    else {
+        panic!("expected the arguments to match, got {result:?}");
+    };
+    assert!(!varargs_expanded);
+    let steps: Vec<_> = conversions.iter().map(|conv| conv.steps.clone()).collect();
+    assert_eq!(
+        steps,
+        vec![
+            vec![ConversionStep::WideningReference],
+            vec![ConversionStep::Boxing]
+        ]
+    );
+
+    let result = args_assignable(
+        &env,
+        &[Type::int(), string.clone()],
+        &[object, integer],
+        false,
+    );
+    assert_eq!(
+        result,
+        ArgsMatch::ArgumentConversion {
+            index: 1,
+            from: string,
+            to: class(&env, "java.lang.Integer"),
+        }
+    );
+    assert_eq!(result.first_failing_index(), Some(1));
+}
+
+#[test]
+fn varargs_arguments_are_spread_over_the_element_type() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = class(&env, "java.lang.String");
+    let long = Type::Primitive(PrimitiveType::Long);
+    // `(String, long...)`
+    let params = [string.clone(), Type::Array(Box::new(long.clone()))];
+
+    for args in [
+        vec![string.clone()],
+        vec![string.clone(), Type::int()],
+        vec![string.clone(), Type::int(), long.clone(), Type::int()],
+    ] {
+        let result = args_assignable(&env, &args, &params, true);
+        assert!(
+            matches!(
+                result,
+                ArgsMatch::Applicable {
+                    varargs_expanded: true,
+                    ..
+                }
+            ),
+            "{args:?}: {result:?}"
+        );
+    }
+
+    // The array itself can be passed without expansion.
+    let result = args_assignable(&env, &params, &params, true);
+    assert!(
+        matches!(
+            result,
+            ArgsMatch::Applicable {
+                varargs_expanded: false,
+                ..
+            }
+        ),
+        "{result:?}"
+    );
+
+    let result = args_assignable(
+        &env,
+        &[string.clone(), Type::int(), string.clone()],
+        &params,
+        true,
+    );
+    assert_eq!(
+        result,
+        ArgsMatch::ArgumentConversion {
+            index: 2,
+            from: string,
+            to: long,
+        }
+    );
+}
+
+#[test]
+fn too_short_argument_lists_fail_at_the_first_missing_argument() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = class(&env, "java.lang.String");
+
+    let result = args_assignable(&env, &[Type::int()], &[Type::int(), string.clone()], false);
+    assert_eq!(
+        result,
+        ArgsMatch::WrongArity {
+            expected: 2,
+            found: 1,
+            is_varargs: false,
+        }
+    );
+    assert!(!result.is_applicable());
+    assert_eq!(result.first_failing_index(), Some(1));
+
+    // `(int, String, int...)` needs at least two arguments.
+    let params = [Type::int(), string, Type::Array(Box::new(Type::int()))];
+    let result = args_assignable(&env, &[Type::int()], &params, true);
+    assert_eq!(
+        result,
+        ArgsMatch::WrongArity {
+            expected: 2,
+            found: 1,
+            is_varargs: true,
+        }
+    );
+    assert_eq!(result.first_failing_index(), Some(1));
+}
@@ -0,0 +1,365 @@
+use nova_types::{
+    format_type, is_assignable, is_subtype, lub, resolve_method_call, CallKind, ClassDef, ClassId,
+    MethodCall, MethodDef, MethodResolution, PrimitiveType, TyContext, Type, TypeEnv, TypeStore,
+    WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn array(elem: Type) -> Type {
+    Type::Array(Box::new(elem))
+}
+
+fn extends(bound: Type) -> Type {
+    Type::Wildcard(WildcardBound::Extends(Box::new(bound)))
+}
+
+fn super_(bound: Type) -> Type {
+    Type::Wildcard(WildcardBound::Super(Box::new(bound)))
+}
+
+/// Declares `com.example.Arrays2` with:
+///
+/// ```java
+/// static <T> T id(T t);
+/// static <T> T first(T[] ts);
+/// static <T> List<T> asList(T... ts);
+/// ```
+fn add_generic_util(env: &mut TypeStore) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+
+    let t_id = env.add_type_param("T", vec![object.clone()]);
+    let t_first = env.add_type_param("T", vec![object.clone()]);
+    let t_as_list = env.add_type_param("T", vec![object.clone()]);
+
+    let method = |name: &str, tv, params, return_type, is_varargs| MethodDef {
+        type_params: vec![tv],
+        is_static: true,
+        is_varargs,
+        ..support::method(name, params, return_type)
+    };
+
+    env.add_class(ClassDef {
+        methods: vec![
+            method(
+                "id",
+                t_id,
+                vec![Type::TypeVar(t_id)],
+                Type::TypeVar(t_id),
+                false,
+            ),
+            method(
+                "first",
+                t_first,
+                vec![array(Type::TypeVar(t_first))],
+                Type::TypeVar(t_first),
+                false,
+            ),
+            method(
+                "asList",
+                t_as_list,
+                vec![array(Type::TypeVar(t_as_list))],
+                Type::class(list, vec![Type::TypeVar(t_as_list)]),
+                true,
+            ),
+        ],
+        ..support::class("com.example.Arrays2", Some(object))
+    })
+}
+
+fn call(env: &TypeStore, util: ClassId, name: &str, args: Vec<Type>) -> MethodResolution {
+    let call = MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: CallKind::Static,
+        name,
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    resolve_method_call(&mut ctx, &call)
+}
+
+#[test]
+fn list_of_array_is_invariant_in_its_element() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    let list_string_array = Type::class(list, vec![array(string)]);
+    let list_object_array = Type::class(list, vec![array(object)]);
+
+    assert!(is_subtype(&env, &list_string_array, &list_string_array));
+    // Arrays are covariant, but `List<String[]>` is still not a `List<Object[]>`.
+    assert!(!is_subtype(&env, &list_string_array, &list_object_array));
+    assert!(!is_subtype(&env, &list_object_array, &list_string_array));
+}
+
+#[test]
+fn extends_array_wildcard_contains_covariant_array() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `List<String[]>` <: `List<? extends Object[]>`
+    assert!(is_subtype(
+        &env,
+        &Type::class(list, vec![array(string.clone())]),
+        &Type::class(list, vec![extends(array(object.clone()))]),
+    ));
+    // `List<Object[]>` is not a `List<? extends String[]>`.
+    assert!(!is_subtype(
+        &env,
+        &Type::class(list, vec![array(object)]),
+        &Type::class(list, vec![extends(array(string))]),
+    ));
+}
+
+#[test]
+fn super_array_wildcard_contains_supertype_array() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `List<Object[]>` <: `List<? super String[]>`
+    assert!(is_subtype(
+        &env,
+        &Type::class(list, vec![array(object.clone())]),
+        &Type::class(list, vec![super_(array(string.clone()))]),
+    ));
+    // `List<String[]>` is not a `List<? super Object[]>`.
+    assert!(!is_subtype(
+        &env,
+        &Type::class(list, vec![array(string)]),
+        &Type::class(list, vec![super_(array(object))]),
+    ));
+}
+
+#[test]
+fn extends_object_array_does_not_contain_primitive_array() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    assert!(!is_subtype(
+        &env,
+        &Type::class(list, vec![array(Type::int())]),
+        &Type::class(list, vec![extends(array(object.clone()))]),
+    ));
+    // ... but `int[]` is itself an `Object`.
+    assert!(is_subtype(
+        &env,
+        &Type::class(list, vec![array(Type::int())]),
+        &Type::class(list, vec![extends(object)]),
+    ));
+}
+
+#[test]
+fn nested_array_wildcards_use_array_covariance() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `List<String[][]>` <: `List<? extends Object[]>` because `String[]` is an `Object`.
+    assert!(is_subtype(
+        &env,
+        &Type::class(list, vec![array(array(string))]),
+        &Type::class(list, vec![extends(array(object))]),
+    ));
+}
+
+#[test]
+fn generic_array_parameter_infers_element_type() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let MethodResolution::Found(found) = call(&env, util, "first", vec![array(string.clone())])
+    else {
+        panic!("expected first(String[]) to resolve");
+    };
+    assert_eq!(found.return_type, string);
+}
+
+#[test]
+fn generic_array_parameter_infers_array_element_for_nested_arrays() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `first(String[][])` infers `T = String[]`.
+    let MethodResolution::Found(found) =
+        call(&env, util, "first", vec![array(array(string.clone()))])
+    else {
+        panic!("expected first(String[][]) to resolve");
+    };
+    assert_eq!(found.return_type, array(string));
+}
+
+#[test]
+fn generic_array_parameter_rejects_primitive_array() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+
+    // javac: `first(new int[0])` has no applicable method (`int[]` is not a `T[]`).
+    assert!(matches!(
+        call(&env, util, "first", vec![array(Type::int())]),
+        MethodResolution::NotFound(_)
+    ));
+}
+
+#[test]
+fn primitive_argument_infers_boxed_type_variable() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let MethodResolution::Found(found) = call(&env, util, "id", vec![Type::int()]) else {
+        panic!("expected id(int) to resolve");
+    };
+    assert_eq!(found.return_type, integer);
+    assert_eq!(found.inferred_type_args, vec![integer]);
+}
+
+#[test]
+fn primitive_array_argument_infers_array_type_variable() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+
+    // `id(new int[0])` infers `T = int[]`, which is a reference type.
+    let MethodResolution::Found(found) = call(&env, util, "id", vec![array(Type::int())]) else {
+        panic!("expected id(int[]) to resolve");
+    };
+    assert_eq!(found.return_type, array(Type::int()));
+}
+
+#[test]
+fn varargs_of_primitives_box_the_element_type() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+    let list = env.class_id("java.util.List").unwrap();
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // `asList(1, 2)` is `List<Integer>`.
+    let MethodResolution::Found(found) = call(&env, util, "asList", vec![Type::int(), Type::int()])
+    else {
+        panic!("expected asList(int, int) to resolve");
+    };
+    assert!(found.used_varargs);
+    assert_eq!(found.return_type, Type::class(list, vec![integer]));
+}
+
+#[test]
+fn varargs_with_single_primitive_array_is_a_single_element() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let util = add_generic_util(&mut env);
+    let list = env.class_id("java.util.List").unwrap();
+
+    // `asList(new int[0])` is `List<int[]>`, not a spread of the `int` elements.
+    let MethodResolution::Found(found) = call(&env, util, "asList", vec![array(Type::int())])
+    else {
+        panic!("expected asList(int[]) to resolve");
+    };
+    assert!(found.used_varargs);
+    assert_eq!(
+        found.return_type,
+        Type::class(list, vec![array(Type::int())])
+    );
+}
+
+#[test]
+fn lub_of_reference_arrays_is_array_of_element_lub() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    assert_eq!(
+        lub(&env, &array(string.clone()), &array(object.clone())),
+        array(object.clone())
+    );
+    assert_eq!(
+        lub(&env, &array(array(string.clone())), &array(string.clone())),
+        array(object)
+    );
+    assert_eq!(
+        lub(&env, &array(string.clone()), &array(string.clone())),
+        array(string)
+    );
+}
+
+#[test]
+fn lub_of_primitive_and_reference_arrays_is_cloneable_and_serializable() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let long = Type::Primitive(PrimitiveType::Long);
+
+    for (a, b) in [
+        (array(Type::int()), array(long)),
+        (array(Type::int()), array(string)),
+    ] {
+        let ty = lub(&env, &a, &b);
+        assert!(matches!(ty, Type::Intersection(_)), "{ty:?}");
+        assert!(!ty.is_denotable());
+        assert!(is_assignable(&env, &a, &ty));
+        assert!(is_assignable(&env, &b, &ty));
+    }
+}
+
+#[test]
+fn lub_with_intersection_element_formats_with_parentheses() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let cloneable = Type::class(env.well_known().cloneable, vec![]);
+    let serializable = Type::class(env.well_known().serializable, vec![]);
+
+    let mut add = |name: &str| {
+        env.add_class(ClassDef {
+            interfaces: vec![cloneable.clone(), serializable.clone()],
+            ..support::class(name, Some(object.clone()))
+        })
+    };
+    let a = Type::class(add("com.example.A"), vec![]);
+    let b = Type::class(add("com.example.B"), vec![]);
+
+    let ty = lub(&env, &array(a.clone()), &array(b.clone()));
+    let Type::Array(elem) = &ty else {
+        panic!("expected an array lub, got {ty:?}");
+    };
+    assert!(matches!(elem.as_ref(), Type::Intersection(_)));
+    assert!(!ty.is_denotable());
+    assert!(is_subtype(&env, &array(a), &ty));
+    assert!(is_subtype(&env, &array(b), &ty));
+
+    let formatted = format_type(&env, &ty);
+    assert!(
+        formatted.starts_with('(') && formatted.ends_with(")[]"),
+        "{formatted}"
+    );
+    assert!(formatted.contains("Cloneable") && formatted.contains("Serializable"));
+}
+
+#[test]
+fn denotable_types() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let cloneable = Type::class(env.well_known().cloneable, vec![]);
+    let serializable = Type::class(env.well_known().serializable, vec![]);
+    let intersection = Type::Intersection(vec![cloneable.clone(), serializable]);
+
+    assert!(array(cloneable.clone()).is_denotable());
+    assert!(Type::class(list, vec![extends(array(cloneable))]).is_denotable());
+    assert!(!intersection.is_denotable());
+    assert!(!array(intersection.clone()).is_denotable());
+    assert!(!Type::class(list, vec![extends(intersection)]).is_denotable());
+}
@@ -0,0 +1,104 @@
+use nova_types::{
+    is_assignable, is_assignable_with_reason, AssignabilityError, ConversionStep, PrimitiveType,
+    Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn success_returns_the_conversion() {
+    let env = TypeStore::with_minimal_jdk();
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let conv = is_assignable_with_reason(&env, &Type::int(), &integer).unwrap();
+    assert_eq!(conv.steps, vec![ConversionStep::Boxing]);
+
+    let long = Type::Primitive(PrimitiveType::Long);
+    let conv = is_assignable_with_reason(&env, &Type::int(), &long).unwrap();
+    assert_eq!(conv.steps, vec![ConversionStep::WideningPrimitive]);
+    assert!(is_assignable(&env, &Type::int(), &long));
+}
+
+#[test]
+fn primitive_narrowing_is_not_widening() {
+    let env = TypeStore::with_minimal_jdk();
+    let long = Type::Primitive(PrimitiveType::Long);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &long, &Type::int()),
+        Err(AssignabilityError::PrimitiveNotWidening {
+            from: PrimitiveType::Long,
+            to: PrimitiveType::Int,
+        })
+    );
+    assert!(!is_assignable(&env, &long, &Type::int()));
+}
+
+#[test]
+fn unrelated_references_are_not_subtypes() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &string, &integer),
+        Err(AssignabilityError::ReferenceNotSubtype {
+            from: string,
+            to: integer,
+        })
+    );
+}
+
+#[test]
+fn boxing_into_unrelated_reference_is_rejected() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &Type::int(), &string),
+        Err(AssignabilityError::BoxingRequired)
+    );
+}
+
+#[test]
+fn unboxing_into_incompatible_primitive_is_rejected() {
+    let env = TypeStore::with_minimal_jdk();
+    let long_box = Type::class(env.class_id("java.lang.Long").unwrap(), vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &long_box, &Type::int()),
+        Err(AssignabilityError::UnboxingRequired)
+    );
+    assert_eq!(
+        is_assignable_with_reason(&env, &string, &Type::int()),
+        Err(AssignabilityError::UnboxingRequired)
+    );
+}
+
+#[test]
+fn void_is_never_assignable() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &Type::Void, &object),
+        Err(AssignabilityError::VoidInvalid)
+    );
+    assert_eq!(
+        is_assignable_with_reason(&env, &Type::int(), &Type::Void),
+        Err(AssignabilityError::VoidInvalid)
+    );
+}
+
+#[test]
+fn reference_downcast_requires_narrowing() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert_eq!(
+        is_assignable_with_reason(&env, &object, &string),
+        Err(AssignabilityError::NarrowingRequired)
+    );
+}
@@ -0,0 +1,292 @@
+use nova_types::{
+    assignment_conversion_with_const, ConstValue, ConversionStep, PrimitiveType, Type, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+/// Assignment targets, in matrix column order.
+const TARGETS: &[&str] = &[
+    "boolean",
+    "byte",
+    "short",
+    "char",
+    "int",
+    "long",
+    "float",
+    "double",
+    "Boolean",
+    "Byte",
+    "Short",
+    "Character",
+    "Integer",
+    "Long",
+    "Float",
+    "Double",
+    "Object",
+    "Number",
+];
+
+/// `(source type, constant value, targets javac accepts)`.
+///
+/// Each row was checked against javac with `<target> x = <expr>;`, where `<expr>` is a constant
+/// expression of the source type (e.g. `(byte) 1`, `'a'`, `1L`) or, for `None`, a local variable
+/// of that type.
+const MATRIX: &[(&str, Option<ConstValue>, &[&str])] = &[
+    (
+        "byte",
+        Some(ConstValue::Int(1)),
+        &[
+            "byte",
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Byte",
+            "Short",
+            "Character",
+            "Object",
+            "Number",
+        ],
+    ),
+    (
+        "byte",
+        Some(ConstValue::Int(-1)),
+        &[
+            "byte", "short", "int", "long", "float", "double", "Byte", "Short", "Object", "Number",
+        ],
+    ),
+    (
+        "short",
+        Some(ConstValue::Int(1)),
+        &[
+            "byte",
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Byte",
+            "Short",
+            "Character",
+            "Object",
+            "Number",
+        ],
+    ),
+    (
+        "short",
+        Some(ConstValue::Int(1000)),
+        &[
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Short",
+            "Character",
+            "Object",
+            "Number",
+        ],
+    ),
+    (
+        "char",
+        Some(ConstValue::Int(97)),
+        &[
+            "byte",
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Byte",
+            "Short",
+            "Character",
+            "Object",
+        ],
+    ),
+    (
+        "char",
+        Some(ConstValue::Int(65535)),
+        &[
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Character",
+            "Object",
+        ],
+    ),
+    (
+        "int",
+        Some(ConstValue::Int(1)),
+        &[
+            "byte",
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Byte",
+            "Short",
+            "Character",
+            "Integer",
+            "Object",
+            "Number",
+        ],
+    ),
+    (
+        "int",
+        Some(ConstValue::Int(200)),
+        &[
+            "short",
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Short",
+            "Character",
+            "Integer",
+            "Object",
+            "Number",
+        ],
+    ),
+    (
+        "int",
+        Some(ConstValue::Int(-1)),
+        &[
+            "byte", "short", "int", "long", "float", "double", "Byte", "Short", "Integer",
+            "Object", "Number",
+        ],
+    ),
+    (
+        "int",
+        Some(ConstValue::Int(70000)),
+        &[
+            "int", "long", "float", "double", "Integer", "Object", "Number",
+        ],
+    ),
+    (
+        "long",
+        Some(ConstValue::Int(1)),
+        &["long", "float", "double", "Long", "Object", "Number"],
+    ),
+    (
+        "float",
+        None,
+        &["float", "double", "Float", "Object", "Number"],
+    ),
+    ("double", None, &["double", "Double", "Object", "Number"]),
+    (
+        "boolean",
+        Some(ConstValue::Boolean(true)),
+        &["boolean", "Boolean", "Object"],
+    ),
+    // Non-constant expressions never narrow.
+    (
+        "byte",
+        None,
+        &[
+            "byte", "short", "int", "long", "float", "double", "Byte", "Object", "Number",
+        ],
+    ),
+    (
+        "char",
+        None,
+        &[
+            "char",
+            "int",
+            "long",
+            "float",
+            "double",
+            "Character",
+            "Object",
+        ],
+    ),
+    (
+        "int",
+        None,
+        &[
+            "int", "long", "float", "double", "Integer", "Object", "Number",
+        ],
+    ),
+];
+
+fn parse(env: &TypeStore, name: &str) -> Type {
+    Type::from_keyword(name).unwrap_or_else(|| {
+        let id = env
+            .class_id(name)
+            .unwrap_or_else(|| panic!("missing class {name}"));
+        Type::class(id, vec![])
+    })
+}
+
+#[test]
+fn assignment_context_matches_javac_matrix() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let mut mismatches = Vec::new();
+    for &(source, value, accepted) in MATRIX {
+        let from = parse(&env, source);
+        for &target in TARGETS {
+            let to = parse(&env, target);
+            let expected = accepted.contains(&target);
+            let actual = assignment_conversion_with_const(&env, &from, &to, value).is_some();
+            if actual != expected {
+                mismatches.push(format!(
+                    "{target} x = <{source} {value:?}>: expected {expected}, got {actual}"
+                ));
+            }
+        }
+    }
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn constant_boxing_steps() {
+    let env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let byte = Type::Primitive(PrimitiveType::Byte);
+
+    // `Character c = 65;`
+    let conv = assignment_conversion_with_const(
+        &env,
+        &int,
+        &parse(&env, "Character"),
+        Some(ConstValue::Int(65)),
+    )
+    .unwrap();
+    assert_eq!(
+        conv.steps,
+        vec![ConversionStep::NarrowingPrimitive, ConversionStep::Boxing]
+    );
+
+    // `Short s = (byte) 1;`
+    let conv = assignment_conversion_with_const(
+        &env,
+        &byte,
+        &parse(&env, "Short"),
+        Some(ConstValue::Int(1)),
+    )
+    .unwrap();
+    assert_eq!(
+        conv.steps,
+        vec![ConversionStep::WideningPrimitive, ConversionStep::Boxing]
+    );
+
+    // `Byte b = (byte) 1;` is plain boxing.
+    let conv = assignment_conversion_with_const(
+        &env,
+        &byte,
+        &parse(&env, "Byte"),
+        Some(ConstValue::Int(1)),
+    )
+    .unwrap();
+    assert_eq!(conv.steps, vec![ConversionStep::Boxing]);
+}
@@ -0,0 +1,246 @@
+use nova_types::{
+    explain_assignment_failure, AssignmentObstruction, ConversionStep, PrimitiveType, Type,
+    TypeStore, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+struct Fixture {
+    env: TypeStore,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            env: TypeStore::with_minimal_jdk(),
+        }
+    }
+
+    fn class(&self, name: &str, args: Vec<Type>) -> Type {
+        Type::class(self.env.class_id(name).unwrap(), args)
+    }
+
+    fn explain(&self, from: &Type, to: &Type) -> Option<AssignmentObstruction> {
+        explain_assignment_failure(&self.env, from, to)
+    }
+}
+
+#[test]
+fn successful_assignments_have_no_obstruction() {
+    let f = Fixture::new();
+    let string = f.class("java.lang.String", vec![]);
+    let object = f.class("java.lang.Object", vec![]);
+    let integer = f.class("java.lang.Integer", vec![]);
+
+    assert_eq!(f.explain(&string, &object), None);
+    assert_eq!(f.explain(&Type::int(), &integer), None);
+    assert_eq!(f.explain(&Type::Null, &string), None);
+}
+
+#[test]
+fn primitive_obstructions() {
+    let f = Fixture::new();
+    let long = Type::Primitive(PrimitiveType::Long);
+    let boolean = Type::Primitive(PrimitiveType::Boolean);
+    let string = f.class("java.lang.String", vec![]);
+
+    assert_eq!(
+        f.explain(&long, &Type::int()),
+        Some(AssignmentObstruction::PrimitiveNarrowingRequired {
+            from: PrimitiveType::Long,
+            to: PrimitiveType::Int,
+        })
+    );
+    assert_eq!(
+        f.explain(&boolean, &Type::int()),
+        Some(AssignmentObstruction::IncompatiblePrimitives {
+            from: PrimitiveType::Boolean,
+            to: PrimitiveType::Int,
+        })
+    );
+    assert_eq!(
+        f.explain(&Type::int(), &string),
+        Some(AssignmentObstruction::PrimitiveReferenceMismatch {
+            from: Type::int(),
+            to: string.clone(),
+        })
+    );
+    assert_eq!(
+        f.explain(&Type::Null, &Type::int()),
+        Some(AssignmentObstruction::RequiresUnboxingOfNull {
+            to: PrimitiveType::Int,
+        })
+    );
+}
+
+#[test]
+fn downcast_would_succeed_with_cast() {
+    let f = Fixture::new();
+    let object = f.class("java.lang.Object", vec![]);
+    let string = f.class("java.lang.String", vec![]);
+
+    let Some(AssignmentObstruction::WouldSucceedWithCast { conversion }) =
+        f.explain(&object, &string)
+    else {
+        panic!("expected a cast suggestion");
+    };
+    assert_eq!(conversion.steps, vec![ConversionStep::NarrowingReference]);
+}
+
+#[test]
+fn unrelated_classes_report_both_superclass_paths() {
+    let f = Fixture::new();
+    let string = f.class("java.lang.String", vec![]);
+    let integer = f.class("java.lang.Integer", vec![]);
+    let id = |name: &str| f.env.class_id(name).unwrap();
+
+    assert_eq!(
+        f.explain(&string, &integer),
+        Some(AssignmentObstruction::UnrelatedClassHierarchies {
+            from_path_to_object: vec![id("java.lang.String"), id("java.lang.Object")],
+            to_path_to_object: vec![
+                id("java.lang.Integer"),
+                id("java.lang.Number"),
+                id("java.lang.Object"),
+            ],
+        })
+    );
+}
+
+#[test]
+fn generic_argument_mismatch_suggests_a_wildcard_when_one_works() {
+    let f = Fixture::new();
+    let integer = f.class("java.lang.Integer", vec![]);
+    let number = f.class("java.lang.Number", vec![]);
+    let string = f.class("java.lang.String", vec![]);
+    let list = |arg: &Type| f.class("java.util.List", vec![arg.clone()]);
+
+    assert_eq!(
+        f.explain(&list(&integer), &list(&number)),
+        Some(AssignmentObstruction::GenericArgumentMismatch {
+            path: vec![0],
+            expected: number.clone(),
+            found: integer.clone(),
+            variance_hint: Some(Type::Wildcard(WildcardBound::Extends(Box::new(number)))),
+        })
+    );
+
+    // `List<Object>` fits `List<? super Integer>`.
+    let object = f.class("java.lang.Object", vec![]);
+    let Some(AssignmentObstruction::GenericArgumentMismatch { variance_hint, .. }) =
+        f.explain(&list(&object), &list(&integer))
+    else {
+        panic!("expected a generic argument mismatch");
+    };
+    assert_eq!(
+        variance_hint,
+        Some(Type::Wildcard(WildcardBound::Super(Box::new(
+            integer.clone()
+        ))))
+    );
+
+    assert_eq!(
+        f.explain(&list(&string), &list(&integer)),
+        Some(AssignmentObstruction::GenericArgumentMismatch {
+            path: vec![0],
+            expected: integer,
+            found: string,
+            variance_hint: None,
+        })
+    );
+}
+
+#[test]
+fn generic_argument_mismatch_is_reported_against_the_target_class() {
+    let f = Fixture::new();
+    let integer = f.class("java.lang.Integer", vec![]);
+    let string = f.class("java.lang.String", vec![]);
+    let array_list = f.class("java.util.ArrayList", vec![integer.clone()]);
+    let list = f.class("java.util.List", vec![string.clone()]);
+
+    assert_eq!(
+        f.explain(&array_list, &list),
+        Some(AssignmentObstruction::GenericArgumentMismatch {
+            path: vec![0],
+            expected: string,
+            found: integer,
+            variance_hint: None,
+        })
+    );
+}
+
+#[test]
+fn nested_generic_mismatch_points_at_the_innermost_argument() {
+    let f = Fixture::new();
+    let string = f.class("java.lang.String", vec![]);
+    let integer = f.class("java.lang.Integer", vec![]);
+    let map_of_lists = |elem: &Type| {
+        f.class(
+            "java.util.Map",
+            vec![
+                string.clone(),
+                f.class("java.util.List", vec![elem.clone()]),
+            ],
+        )
+    };
+
+    assert_eq!(
+        f.explain(&map_of_lists(&integer), &map_of_lists(&string)),
+        Some(AssignmentObstruction::GenericArgumentMismatch {
+            path: vec![1, 0],
+            expected: string.clone(),
+            found: integer,
+            variance_hint: None,
+        })
+    );
+}
+
+#[test]
+fn raw_type_argument_vs_parameterized() {
+    let f = Fixture::new();
+    let string = f.class("java.lang.String", vec![]);
+    let raw_list = f.class("java.util.List", vec![]);
+    let list_of_string = f.class("java.util.List", vec![string]);
+    let list_of = |arg: &Type| f.class("java.util.List", vec![arg.clone()]);
+
+    assert_eq!(
+        f.explain(&list_of(&raw_list), &list_of(&list_of_string)),
+        Some(AssignmentObstruction::RawVsParameterized {
+            path: vec![0],
+            raw: raw_list,
+            parameterized: list_of_string,
+        })
+    );
+}
+
+#[test]
+fn array_element_mismatch_recurses() {
+    let f = Fixture::new();
+    let string = f.class("java.lang.String", vec![]);
+    let integer = f.class("java.lang.Integer", vec![]);
+    let array = |elem: &Type| Type::Array(Box::new(elem.clone()));
+
+    let Some(AssignmentObstruction::ArrayElementMismatch(elem)) =
+        f.explain(&array(&string), &array(&integer))
+    else {
+        panic!("expected an array element mismatch");
+    };
+    assert!(
+        matches!(
+            *elem,
+            AssignmentObstruction::UnrelatedClassHierarchies { .. }
+        ),
+        "{elem:?}"
+    );
+
+    let long = Type::Primitive(PrimitiveType::Long);
+    assert_eq!(
+        f.explain(&array(&Type::int()), &array(&long)),
+        Some(AssignmentObstruction::ArrayElementMismatch(Box::new(
+            AssignmentObstruction::IncompatiblePrimitives {
+                from: PrimitiveType::Int,
+                to: PrimitiveType::Long,
+            }
+        )))
+    );
+}
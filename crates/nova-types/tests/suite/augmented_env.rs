@@ -0,0 +1,160 @@
+use nova_types::{
+    resolve_method_call, sam_signature, AugmentedTypeEnv, CallKind, ClassAugmentation, ClassDef,
+    ClassKind, FieldDef, MethodCall, MethodDef, MethodResolution, TyContext, Type, TypeEnv,
+    TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn add_person(env: &mut TypeStore) -> nova_types::ClassId {
+    let object = env.well_known().object;
+    let string = env.well_known().string;
+    env.add_class(ClassDef {
+        fields: vec![FieldDef {
+            name: "name".to_string(),
+            ty: Type::class(string, vec![]),
+            is_static: false,
+            is_final: false,
+            deprecation: None,
+        }],
+        ..support::class("com.example.Person", Some(Type::class(object, vec![])))
+    })
+}
+
+fn call_get_name(env: &dyn TypeEnv, receiver: Type) -> MethodResolution {
+    let call = MethodCall {
+        receiver,
+        call_kind: CallKind::Instance,
+        name: "getName",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    resolve_method_call(&mut ctx, &call)
+}
+
+#[test]
+fn synthetic_getter_resolves_through_augmented_env_only() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let person = add_person(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let mut augmented = AugmentedTypeEnv::new(&env);
+    augmented.augment(
+        person,
+        ClassAugmentation {
+            methods: vec![support::method("getName", vec![], string.clone())],
+            ..ClassAugmentation::default()
+        },
+    );
+
+    let MethodResolution::Found(found) = call_get_name(&augmented, Type::class(person, vec![]))
+    else {
+        panic!("expected getName() to resolve on the augmented env");
+    };
+    assert_eq!(found.owner, person);
+    assert_eq!(found.return_type, string);
+
+    // The base store is untouched.
+    assert!(env.class(person).unwrap().methods.is_empty());
+    assert!(matches!(
+        call_get_name(&env, Type::class(person, vec![])),
+        MethodResolution::NotFound(_)
+    ));
+}
+
+#[test]
+fn synthetic_members_are_inherited_by_subclasses() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let person = add_person(&mut env);
+    let employee = env.add_class(support::class(
+        "com.example.Employee",
+        Some(Type::class(person, vec![])),
+    ));
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let mut augmented = AugmentedTypeEnv::new(&env);
+    augmented.augment(
+        person,
+        ClassAugmentation {
+            methods: vec![support::method("getName", vec![], string.clone())],
+            ..ClassAugmentation::default()
+        },
+    );
+
+    let MethodResolution::Found(found) = call_get_name(&augmented, Type::class(employee, vec![]))
+    else {
+        panic!("expected inherited getName() to resolve");
+    };
+    assert_eq!(found.owner, person);
+    assert_eq!(found.return_type, string);
+}
+
+#[test]
+fn augmentations_accumulate_and_invalidate_cached_views() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let person = add_person(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let mut augmented = AugmentedTypeEnv::new(&env);
+    augmented.augment(
+        person,
+        ClassAugmentation {
+            methods: vec![support::method("getName", vec![], string.clone())],
+            ..ClassAugmentation::default()
+        },
+    );
+    assert_eq!(augmented.class(person).unwrap().methods.len(), 1);
+
+    augmented.augment(
+        person,
+        ClassAugmentation {
+            methods: vec![support::method("toString", vec![], string)],
+            ..ClassAugmentation::default()
+        },
+    );
+    let names: Vec<_> = augmented
+        .class(person)
+        .unwrap()
+        .methods
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["getName", "toString"]);
+    // Fields of the base class are preserved in the merged view.
+    assert_eq!(augmented.class(person).unwrap().fields.len(), 1);
+}
+
+#[test]
+fn synthetic_abstract_method_affects_sam_detection() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let iface = env.add_class(ClassDef {
+        kind: ClassKind::Interface,
+        ..support::class("com.example.Repository", None)
+    });
+    let ty = Type::class(iface, vec![]);
+    assert!(sam_signature(&env, &ty).is_none());
+
+    let mut augmented = AugmentedTypeEnv::new(&env);
+    augmented.augment(
+        iface,
+        ClassAugmentation {
+            methods: vec![MethodDef {
+                is_abstract: true,
+                ..support::method("findAll", vec![], Type::class(object, vec![]))
+            }],
+            ..ClassAugmentation::default()
+        },
+    );
+    let sam = sam_signature(&augmented, &ty).expect("augmented interface should be functional");
+    assert_eq!(sam.return_type, Type::class(object, vec![]));
+    assert!(sam_signature(&env, &ty).is_none());
+}
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
 use nova_types::{
-    resolve_constructor_call, resolve_field, CallKind, ClassDef, ClassKind, FieldDef, FieldStub,
-    MethodResolution, MethodStub, Type, TypeDefStub, TypeEnv, TypeProvider, TypeStore,
+    applicable_constructors, infer_diamond_constructor_call, resolve_constructor_call,
+    resolve_field, resolve_field_access_with_implicit_this, CallKind, ClassDef, ClassId, ClassKind,
+    ConstructorDef, FieldDef, FieldStub, MethodResolution, MethodStub, PrimitiveType, Type,
+    TypeDefStub, TypeEnv, TypeProvider, TypeStore, WildcardBound,
 };
 use nova_types_bridge::ExternalTypeLoader;
 
@@ -40,13 +42,17 @@ fn resolves_field_from_loaded_stub_class() {
             descriptor: "I".to_string(),
             signature: None,
             access_flags: 0,
+            deprecation: None,
         }],
         methods: vec![MethodStub {
             name: "<init>".to_string(),
             descriptor: "()V".to_string(),
             signature: None,
             access_flags: 0,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     });
     provider.insert(TypeDefStub {
         binary_name: "com.example.Foo".to_string(),
@@ -60,12 +66,14 @@ fn resolves_field_from_loaded_stub_class() {
                 descriptor: "Ljava/lang/String;".to_string(),
                 signature: None,
                 access_flags: 0,
+                deprecation: None,
             },
             FieldStub {
                 name: "CONST".to_string(),
                 descriptor: "I".to_string(),
                 signature: None,
                 access_flags: ACC_STATIC | ACC_FINAL,
+                deprecation: None,
             },
         ],
         methods: vec![
@@ -74,20 +82,25 @@ fn resolves_field_from_loaded_stub_class() {
                 descriptor: "()V".to_string(),
                 signature: None,
                 access_flags: 0,
+                deprecation: None,
             },
             MethodStub {
                 name: "greet".to_string(),
                 descriptor: "(I)Ljava/lang/String;".to_string(),
                 signature: None,
                 access_flags: 0,
+                deprecation: None,
             },
             MethodStub {
                 name: "util".to_string(),
                 descriptor: "()I".to_string(),
                 signature: None,
                 access_flags: ACC_STATIC,
+                deprecation: None,
             },
         ],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut env = TypeStore::with_minimal_jdk();
@@ -164,9 +177,14 @@ fn resolve_field_intersection_receiver_is_order_independent() {
             ty: Type::class(object, vec![]),
             is_static: true,
             is_final: true,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let class = env.add_class(ClassDef {
@@ -180,9 +198,14 @@ fn resolve_field_intersection_receiver_is_order_independent() {
             ty: Type::class(string, vec![]),
             is_static: false,
             is_final: false,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let receiver_iface_first =
@@ -220,20 +243,25 @@ fn resolves_constructor_overloads_from_loaded_stub_class() {
                 descriptor: "()V".to_string(),
                 signature: None,
                 access_flags: 0,
+                deprecation: None,
             },
             MethodStub {
                 name: "<init>".to_string(),
                 descriptor: "(I)V".to_string(),
                 signature: None,
                 access_flags: 0,
+                deprecation: None,
             },
             MethodStub {
                 name: "<init>".to_string(),
                 descriptor: "([I)V".to_string(),
                 signature: None,
                 access_flags: ACC_VARARGS,
+                deprecation: None,
             },
         ],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut env = TypeStore::with_minimal_jdk();
@@ -266,3 +294,241 @@ fn resolves_constructor_overloads_from_loaded_stub_class() {
     assert!(res.is_varargs);
     assert!(res.used_varargs);
 }
+
+#[test]
+fn applicable_constructors_filters_by_arity_and_conversion() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let int = Type::Primitive(PrimitiveType::Int);
+
+    let ctor = |params: Vec<Type>, is_varargs: bool| ConstructorDef {
+        params,
+        is_varargs,
+        is_accessible: true,
+        deprecation: None,
+    };
+    // class Foo { Foo(); Foo(String); Foo(int); Foo(int, String...); }
+    let foo = env.add_class(ClassDef {
+        name: "com.example.Foo".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(object),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![
+            ctor(vec![], false),
+            ctor(vec![string.clone()], false),
+            ctor(vec![int.clone()], false),
+            ctor(
+                vec![int.clone(), Type::Array(Box::new(string.clone()))],
+                true,
+            ),
+        ],
+        methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+    let params = |args: &[Type]| -> Vec<Vec<Type>> {
+        applicable_constructors(&env, foo, args)
+            .into_iter()
+            .map(|c| c.params.clone())
+            .collect()
+    };
+
+    assert_eq!(params(&[]), vec![Vec::<Type>::new()]);
+    assert_eq!(
+        params(std::slice::from_ref(&string)),
+        vec![vec![string.clone()]]
+    );
+    assert!(params(&[string.clone(), string.clone()]).is_empty());
+
+    // Loose invocation: `Integer` unboxes to `int`; `Foo(int, String...)` also takes one `int`.
+    let varargs = vec![int.clone(), Type::Array(Box::new(string.clone()))];
+    assert_eq!(params(&[integer]), vec![vec![int.clone()], varargs.clone()]);
+    assert_eq!(
+        params(&[int.clone(), string.clone(), string]),
+        vec![varargs]
+    );
+}
+
+/// `class Bag<E> { Bag() {} Bag(List<? extends E> items) {} }`, shaped like `ArrayList`'s copy
+/// constructor.
+fn add_class_with_field(
+    env: &mut TypeStore,
+    name: &str,
+    super_class: Type,
+    enclosing: Option<ClassId>,
+    field: &str,
+) -> ClassId {
+    env.add_class(ClassDef {
+        name: name.to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(super_class),
+        interfaces: vec![],
+        fields: vec![FieldDef {
+            name: field.to_string(),
+            ty: Type::Primitive(PrimitiveType::Int),
+            is_static: false,
+            is_final: false,
+            deprecation: None,
+        }],
+        constructors: vec![],
+        methods: vec![],
+        enclosing,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    })
+}
+
+#[test]
+fn implicit_this_field_access_searches_class_supertypes_and_outer_classes() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    let base = add_class_with_field(
+        &mut env,
+        "com.example.Base",
+        object.clone(),
+        None,
+        "inherited",
+    );
+    let derived = add_class_with_field(
+        &mut env,
+        "com.example.Derived",
+        Type::class(base, vec![]),
+        None,
+        "own",
+    );
+    add_class_with_field(
+        &mut env,
+        "com.example.Sibling",
+        object.clone(),
+        None,
+        "sibling",
+    );
+    let inner = add_class_with_field(
+        &mut env,
+        "com.example.Derived$Inner",
+        object,
+        Some(derived),
+        "innerField",
+    );
+
+    let this = Type::class(derived, vec![]);
+    let own = resolve_field_access_with_implicit_this(&env, &this, "own").expect("own field");
+    assert_eq!(own.name, "own");
+    let inherited = resolve_field_access_with_implicit_this(&env, &this, "inherited")
+        .expect("superclass field");
+    assert_eq!(inherited.name, "inherited");
+    assert!(resolve_field_access_with_implicit_this(&env, &this, "sibling").is_none());
+
+    // An inner class body sees the fields of its enclosing class (and that class's supertypes).
+    let inner_this = Type::class(inner, vec![]);
+    for name in ["innerField", "own", "inherited"] {
+        let field = resolve_field_access_with_implicit_this(&env, &inner_this, name)
+            .unwrap_or_else(|| panic!("{name} should be visible from Derived$Inner"));
+        assert_eq!(field.name, name);
+    }
+    assert!(resolve_field_access_with_implicit_this(&env, &inner_this, "sibling").is_none());
+}
+
+fn define_bag(env: &mut TypeStore) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let e = env.add_type_param("E", vec![object.clone()]);
+    env.add_class(ClassDef {
+        name: "com.example.Bag".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![e],
+        super_class: Some(object),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![
+            ConstructorDef {
+                params: vec![],
+                is_varargs: false,
+                is_accessible: true,
+                deprecation: None,
+            },
+            ConstructorDef {
+                params: vec![Type::class(
+                    list,
+                    vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+                        Type::TypeVar(e),
+                    )))],
+                )],
+                is_varargs: false,
+                is_accessible: true,
+                deprecation: None,
+            },
+        ],
+        methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    })
+}
+
+#[test]
+fn diamond_constructor_call_infers_from_arguments() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let bag = define_bag(&mut env);
+    let list = env.class_id("java.util.List").unwrap();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let string_list = Type::class(list, vec![string.clone()]);
+
+    // new Bag<>(stringList)
+    let inferred =
+        infer_diamond_constructor_call(&env, bag, std::slice::from_ref(&string_list), None);
+    assert_eq!(inferred, vec![string.clone()]);
+
+    let MethodResolution::Found(res) =
+        resolve_constructor_call(&env, bag, &[string_list], Some(&Type::class(bag, inferred)))
+    else {
+        panic!("expected constructor resolution");
+    };
+    assert_eq!(res.return_type, Type::class(bag, vec![string]));
+
+    // new Bag<>(): nothing constrains `E`.
+    assert_eq!(
+        infer_diamond_constructor_call(&env, bag, &[], None),
+        vec![object]
+    );
+}
+
+#[test]
+fn diamond_constructor_call_prefers_target_over_arguments() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let bag = define_bag(&mut env);
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // Bag<Integer> b = new Bag<>(stringList)
+    let target = Type::class(bag, vec![integer.clone()]);
+    let inferred = infer_diamond_constructor_call(
+        &env,
+        bag,
+        &[Type::class(list, vec![string.clone()])],
+        Some(&target),
+    );
+    assert_eq!(inferred, vec![integer]);
+
+    // A target that says nothing about `E` leaves the argument constraints in charge.
+    let object = Type::class(env.well_known().object, vec![]);
+    let inferred = infer_diamond_constructor_call(
+        &env,
+        bag,
+        &[Type::class(list, vec![string.clone()])],
+        Some(&object),
+    );
+    assert_eq!(inferred, vec![string]);
+}
@@ -0,0 +1,98 @@
+use nova_types::{
+    is_equality_comparison_valid, is_numeric_comparison_valid, PrimitiveType, Type, TypeEnv,
+    TypeStore,
+};
+
+#[test]
+fn numeric_comparison_requires_numeric_operands() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let int_ty = Type::int();
+    let double_ty = Type::Primitive(PrimitiveType::Double);
+    let integer_ty = Type::class(env.well_known().integer, vec![]);
+    let string_ty = Type::class(env.well_known().string, vec![]);
+
+    assert!(is_numeric_comparison_valid(&env, &int_ty, &double_ty));
+    assert!(is_numeric_comparison_valid(&env, &integer_ty, &int_ty));
+    assert!(is_numeric_comparison_valid(&env, &integer_ty, &integer_ty));
+
+    assert!(!is_numeric_comparison_valid(
+        &env,
+        &int_ty,
+        &Type::boolean()
+    ));
+    assert!(!is_numeric_comparison_valid(&env, &string_ty, &int_ty));
+    assert!(!is_numeric_comparison_valid(&env, &Type::Null, &int_ty));
+}
+
+#[test]
+fn equality_comparison_int_and_integer_is_valid_via_unboxing() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let integer_ty = Type::class(env.well_known().integer, vec![]);
+    assert!(is_equality_comparison_valid(
+        &env,
+        &Type::int(),
+        &integer_ty
+    ));
+    assert!(is_equality_comparison_valid(
+        &env,
+        &integer_ty,
+        &Type::int()
+    ));
+}
+
+#[test]
+fn equality_comparison_string_and_int_is_invalid() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let string_ty = Type::class(env.well_known().string, vec![]);
+    assert!(!is_equality_comparison_valid(
+        &env,
+        &string_ty,
+        &Type::int()
+    ));
+    assert!(!is_equality_comparison_valid(
+        &env,
+        &Type::int(),
+        &string_ty
+    ));
+}
+
+#[test]
+fn equality_comparison_null_and_object_is_valid() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let object_ty = Type::class(env.well_known().object, vec![]);
+    assert!(is_equality_comparison_valid(&env, &Type::Null, &object_ty));
+    assert!(is_equality_comparison_valid(&env, &object_ty, &Type::Null));
+    assert!(is_equality_comparison_valid(&env, &Type::Null, &Type::Null));
+    assert!(!is_equality_comparison_valid(
+        &env,
+        &Type::Null,
+        &Type::int()
+    ));
+}
+
+#[test]
+fn equality_comparison_booleans_and_unrelated_references() {
+    let env = TypeStore::with_minimal_jdk();
+
+    let boolean_box = Type::class(env.lookup_class("java.lang.Boolean").unwrap(), vec![]);
+    let string_ty = Type::class(env.well_known().string, vec![]);
+    let integer_ty = Type::class(env.well_known().integer, vec![]);
+
+    assert!(is_equality_comparison_valid(
+        &env,
+        &Type::boolean(),
+        &boolean_box
+    ));
+    assert!(!is_equality_comparison_valid(
+        &env,
+        &Type::boolean(),
+        &Type::int()
+    ));
+
+    // `String` and `Integer` are both final and unrelated, so neither can be cast to the other.
+    assert!(!is_equality_comparison_valid(&env, &string_ty, &integer_ty));
+}
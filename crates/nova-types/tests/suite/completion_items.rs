@@ -0,0 +1,170 @@
+use nova_types::{
+    collect_candidate_completions, method_candidates_for_completion, resolve_field, CallKind,
+    CompletionItem, CompletionItemKind, InsertTextFormat, MethodDef, PrimitiveType, Span, Type,
+    TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn method(name: &str, params: Vec<Type>, param_names: &[&str]) -> MethodDef {
+    MethodDef {
+        param_names: param_names.iter().map(|name| name.to_string()).collect(),
+        ..support::method(name, params, Type::Void)
+    }
+}
+
+#[test]
+fn method_items_place_a_tab_stop_per_parameter() {
+    let int = Type::Primitive(PrimitiveType::Int);
+    let item = CompletionItem::method(&method(
+        "substring",
+        vec![int.clone(), int],
+        &["beginIndex", "endIndex"],
+    ));
+
+    assert_eq!(item.label, "substring");
+    assert_eq!(item.kind, Some(CompletionItemKind::Method));
+    assert_eq!(item.insert_text_format, InsertTextFormat::Snippet);
+    assert_eq!(
+        item.insert_text.as_deref(),
+        Some("substring(${1:beginIndex}, ${2:endIndex})$0")
+    );
+}
+
+#[test]
+fn method_items_fall_back_to_positional_placeholders() {
+    let item = CompletionItem::method(&method(
+        "put",
+        vec![Type::Named("java.lang.Object".into()); 2],
+        &[],
+    ));
+    assert_eq!(
+        item.insert_text.as_deref(),
+        Some("put(${1:arg0}, ${2:arg1})$0")
+    );
+}
+
+#[test]
+fn zero_arg_method_items_have_no_tab_stop_after_the_parens() {
+    let item = CompletionItem::method(&method("length", vec![], &[]));
+
+    assert_eq!(item.insert_text.as_deref(), Some("length()"));
+    assert_eq!(item.insert_text_format, InsertTextFormat::PlainText);
+}
+
+#[test]
+fn builders_fill_in_the_optional_fields() {
+    let item = CompletionItem::new("ArrayList")
+        .with_kind(CompletionItemKind::Class)
+        .with_detail("java.util.ArrayList")
+        .with_sort_text("1_ArrayList")
+        .with_filter_text("ArrayList")
+        .with_additional_edit(Span::new(0, 0), "import java.util.ArrayList;\n")
+        .with_deprecated(true);
+
+    assert_eq!(item.kind, Some(CompletionItemKind::Class));
+    assert_eq!(item.detail.as_deref(), Some("java.util.ArrayList"));
+    assert_eq!(item.sort_text.as_deref(), Some("1_ArrayList"));
+    assert_eq!(item.filter_text.as_deref(), Some("ArrayList"));
+    assert_eq!(
+        item.additional_edits,
+        vec![(Span::new(0, 0), "import java.util.ArrayList;\n".to_string())]
+    );
+    assert!(item.deprecated);
+    assert_eq!(item.insert_text, None);
+    assert_eq!(item.replace_span, None);
+}
+
+#[test]
+fn member_completions_on_system_out_list_each_println_overload() {
+    let env = TypeStore::with_minimal_jdk();
+    let system = Type::class(env.class_id("java.lang.System").unwrap(), vec![]);
+    let out = resolve_field(&env, &system, "out", CallKind::Static).unwrap();
+
+    let items = collect_candidate_completions(&env, &out.ty, "print", CallKind::Instance);
+    let summary: Vec<_> = items
+        .iter()
+        .map(|item| (item.label.as_str(), item.kind, item.detail.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "println",
+                Some(CompletionItemKind::Method),
+                Some("void println(String)")
+            ),
+            (
+                "println",
+                Some(CompletionItemKind::Method),
+                Some("void println(int)")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn empty_prefix_lists_every_member_of_the_call_kind() {
+    let env = TypeStore::with_minimal_jdk();
+    let system = Type::class(env.class_id("java.lang.System").unwrap(), vec![]);
+
+    let statics = collect_candidate_completions(&env, &system, "", CallKind::Static);
+    let labels: Vec<_> = statics.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(labels, vec!["out"]);
+    assert_eq!(statics[0].kind, Some(CompletionItemKind::Field));
+    assert_eq!(statics[0].detail.as_deref(), Some("PrintStream"));
+
+    let print_stream = Type::class(env.class_id("java.io.PrintStream").unwrap(), vec![]);
+    let instance = collect_candidate_completions(&env, &print_stream, "", CallKind::Instance);
+    let labels: Vec<_> = instance.iter().map(|item| item.label.as_str()).collect();
+    // `PrintStream`'s own methods first, then those inherited from `Object`.
+    assert_eq!(
+        labels,
+        vec!["println", "println", "toString", "equals", "hashCode", "getClass"]
+    );
+}
+
+#[test]
+fn method_candidates_match_a_case_insensitive_prefix() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let object = env.class_id("java.lang.Object").unwrap();
+    let array_list = Type::class(
+        env.class_id("java.util.ArrayList").unwrap(),
+        vec![string.clone()],
+    );
+
+    let get = method_candidates_for_completion(&env, &array_list, "get");
+    let list_get = get
+        .iter()
+        .find(|cand| cand.name == "get")
+        .expect("`get(int)` from `List`");
+    assert_eq!(list_get.owner, list);
+    assert_eq!(list_get.params, vec![Type::int()]);
+    assert_eq!(list_get.return_type, string);
+    // `getClass()` is declared by `Object`.
+    let get_class = get.iter().find(|cand| cand.name == "getClass").unwrap();
+    assert_eq!(get_class.owner, object);
+
+    assert_eq!(
+        method_candidates_for_completion(&env, &array_list, "GET"),
+        get
+    );
+    assert!(method_candidates_for_completion(&env, &array_list, "getx").is_empty());
+}
+
+#[test]
+fn method_candidates_with_an_empty_prefix_list_every_method() {
+    let env = TypeStore::with_minimal_jdk();
+    let print_stream = Type::class(env.class_id("java.io.PrintStream").unwrap(), vec![]);
+
+    let candidates = method_candidates_for_completion(&env, &print_stream, "");
+    let names: Vec<_> = candidates.iter().map(|cand| cand.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["println", "println", "toString", "equals", "hashCode", "getClass"]
+    );
+}
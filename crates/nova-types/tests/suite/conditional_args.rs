@@ -0,0 +1,161 @@
+//! Conditional arguments are typed against each candidate's formal parameter (JLS 15.25): a
+//! reference conditional converts when both of its branches do, while boolean and numeric
+//! conditionals keep their standalone type.
+
+use nova_types::{
+    resolve_method_call, ArgShape, CallKind, ClassDef, ClassId, Conversion, ConversionStep,
+    MethodCall, MethodResolution, MethodSearchPhase, PrimitiveType, TyContext, Type, TypeEnv,
+    TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+/// Defines `Foo` with one `m` overload per entry of `params`.
+fn overloads(env: &mut TypeStore, params: Vec<Type>) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    env.add_class(ClassDef {
+        methods: params
+            .into_iter()
+            .map(|param| support::method("m", vec![param], Type::Void))
+            .collect(),
+        ..support::class("com.example.Foo", Some(object))
+    })
+}
+
+fn resolve(env: &TypeStore, owner: ClassId, arg: &ArgShape) -> MethodResolution {
+    let call = MethodCall {
+        receiver: Type::class(owner, vec![]),
+        call_kind: CallKind::Instance,
+        name: "m",
+        args: vec![arg.standalone_type(env)],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![arg.clone()],
+    };
+    let mut ctx = TyContext::new(env);
+    resolve_method_call(&mut ctx, &call)
+}
+
+fn resolved_param(env: &TypeStore, owner: ClassId, arg: &ArgShape) -> Type {
+    match resolve(env, owner, arg) {
+        MethodResolution::Found(method) => method.params[0].clone(),
+        other => panic!("expected a resolved method, got {other:?}"),
+    }
+}
+
+fn standalone(ty: Type) -> ArgShape {
+    ArgShape::Standalone(ty)
+}
+
+fn conversion(steps: Vec<ConversionStep>) -> Conversion {
+    Conversion {
+        steps,
+        warnings: vec![],
+        branches: vec![],
+    }
+}
+
+#[test]
+fn null_branch_conditional_is_typed_against_the_formal() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    // flag ? 1 : null
+    let arg = ArgShape::conditional(standalone(Type::int()), standalone(Type::Null));
+    assert_eq!(arg.standalone_type(&env), integer);
+
+    let owner = overloads(&mut env, vec![integer.clone(), object]);
+    let MethodResolution::Found(found) = resolve(&env, owner, &arg) else {
+        panic!("expected `m(Integer)` to resolve");
+    };
+    assert_eq!(found.params, vec![integer]);
+    // Boxing the `1` branch rules out the strict phase.
+    assert_eq!(found.phase, MethodSearchPhase::Loose);
+    assert_eq!(
+        found.conversions[0].branches,
+        vec![
+            conversion(vec![ConversionStep::Boxing]),
+            conversion(vec![ConversionStep::WideningReference]),
+        ]
+    );
+
+    // The `null` branch cannot be passed as `int`, although the standalone `Integer` could.
+    let mut env = TypeStore::with_minimal_jdk();
+    let owner = overloads(&mut env, vec![Type::int()]);
+    assert!(matches!(
+        resolve(&env, owner, &arg),
+        MethodResolution::NotFound(_)
+    ));
+}
+
+#[test]
+fn null_only_conditional_needs_a_reference_formal() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    // flag ? null : null
+    let arg = ArgShape::conditional(standalone(Type::Null), standalone(Type::Null));
+    assert_eq!(arg.standalone_type(&env), Type::Null);
+
+    let owner = overloads(&mut env, vec![Type::int(), string.clone()]);
+    assert_eq!(resolved_param(&env, owner, &arg), string);
+}
+
+#[test]
+fn mixed_numeric_conditional_keeps_its_promoted_type() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let long = Type::Primitive(PrimitiveType::Long);
+    let float = Type::Primitive(PrimitiveType::Float);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    // flag ? 1 : 2L
+    let arg = ArgShape::conditional(standalone(Type::int()), standalone(long.clone()));
+    assert_eq!(arg.standalone_type(&env), long);
+
+    let owner = overloads(&mut env, vec![Type::int(), float.clone()]);
+    let MethodResolution::Found(found) = resolve(&env, owner, &arg) else {
+        panic!("expected `m(float)` to resolve");
+    };
+    assert_eq!(found.params, vec![float]);
+    assert_eq!(found.phase, MethodSearchPhase::Strict);
+    assert!(found.conversions[0].branches.is_empty());
+
+    // An `int` and an `Integer` branch make an `int` conditional.
+    let arg = ArgShape::conditional(standalone(Type::int()), standalone(integer));
+    assert_eq!(arg.standalone_type(&env), Type::int());
+}
+
+#[test]
+fn nested_conditionals_are_typed_recursively() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let double = Type::Primitive(PrimitiveType::Double);
+
+    // a ? (b ? 1 : null) : 2
+    let reference = ArgShape::conditional(
+        ArgShape::conditional(standalone(Type::int()), standalone(Type::Null)),
+        standalone(Type::int()),
+    );
+    let owner = overloads(&mut env, vec![object, integer.clone()]);
+    assert_eq!(resolved_param(&env, owner, &reference), integer);
+
+    // a ? (b ? 1 : 2L) : 3.0f
+    let numeric = ArgShape::conditional(
+        ArgShape::conditional(
+            standalone(Type::int()),
+            standalone(Type::Primitive(PrimitiveType::Long)),
+        ),
+        standalone(Type::Primitive(PrimitiveType::Float)),
+    );
+    assert_eq!(
+        numeric.standalone_type(&env),
+        Type::Primitive(PrimitiveType::Float)
+    );
+    let mut env = TypeStore::with_minimal_jdk();
+    let owner = overloads(&mut env, vec![Type::int(), double.clone()]);
+    assert_eq!(resolved_param(&env, owner, &numeric), double);
+}
@@ -0,0 +1,98 @@
+use nova_types::{fold_binary, fold_unary, BinOp, ConstValue, PrimitiveType, UnOp};
+
+use pretty_assertions::assert_eq;
+
+use PrimitiveType::{Boolean, Byte, Char, Int, Long};
+
+fn int(value: i64) -> (PrimitiveType, ConstValue) {
+    (Int, ConstValue::Int(value))
+}
+
+fn long(value: i64) -> (PrimitiveType, ConstValue) {
+    (Long, ConstValue::Int(value))
+}
+
+#[test]
+fn int_arithmetic_folds_to_int() {
+    assert_eq!(fold_binary(BinOp::Add, int(1), int(2)), Some(int(3)));
+    assert_eq!(fold_binary(BinOp::Sub, int(1), int(2)), Some(int(-1)));
+    assert_eq!(fold_binary(BinOp::Mul, int(6), int(7)), Some(int(42)));
+    assert_eq!(fold_binary(BinOp::Div, int(-7), int(2)), Some(int(-3)));
+    assert_eq!(fold_binary(BinOp::Rem, int(-7), int(2)), Some(int(-1)));
+    assert_eq!(fold_binary(BinOp::Div, int(1), int(0)), None);
+    assert_eq!(
+        fold_binary(BinOp::Xor, int(0b1100), int(0b1010)),
+        Some(int(0b0110))
+    );
+}
+
+#[test]
+fn shifts_promote_only_the_left_operand() {
+    // `(byte) 1 << 3` is an `int`.
+    assert_eq!(
+        fold_binary(BinOp::Shl, (Byte, ConstValue::Int(1)), int(3)),
+        Some(int(8))
+    );
+    // An `int` shifted by a `long` distance stays `int`, and the distance is masked to 5 bits.
+    assert_eq!(fold_binary(BinOp::Shl, int(1), long(33)), Some(int(2)));
+    assert_eq!(
+        fold_binary(BinOp::Shl, long(1), int(33)),
+        Some(long(1 << 33))
+    );
+    assert_eq!(fold_binary(BinOp::Shr, int(-16), int(2)), Some(int(-4)));
+    assert_eq!(fold_binary(BinOp::UShr, int(-1), int(28)), Some(int(0xf)));
+}
+
+#[test]
+fn overflow_wraps_like_java() {
+    assert_eq!(
+        fold_binary(BinOp::Add, int(i64::from(i32::MAX)), int(1)),
+        Some(int(i64::from(i32::MIN)))
+    );
+    assert_eq!(
+        fold_binary(BinOp::Mul, int(65_536), int(65_536)),
+        Some(int(0))
+    );
+    assert_eq!(
+        fold_binary(BinOp::Div, int(i64::from(i32::MIN)), int(-1)),
+        Some(int(i64::from(i32::MIN)))
+    );
+    // A `long` operand promotes the whole expression, so it does not overflow at 32 bits.
+    assert_eq!(
+        fold_binary(BinOp::Add, int(i64::from(i32::MAX)), long(1)),
+        Some(long(i64::from(i32::MAX) + 1))
+    );
+    assert_eq!(
+        fold_binary(BinOp::Add, long(i64::MAX), long(1)),
+        Some(long(i64::MIN))
+    );
+}
+
+#[test]
+fn boolean_and_mismatched_operands() {
+    let t = (Boolean, ConstValue::Boolean(true));
+    let f = (Boolean, ConstValue::Boolean(false));
+    assert_eq!(fold_binary(BinOp::And, t, f), Some(f));
+    assert_eq!(fold_binary(BinOp::Xor, t, f), Some(t));
+    assert_eq!(fold_binary(BinOp::Add, t, f), None);
+    assert_eq!(fold_binary(BinOp::Or, t, int(1)), None);
+}
+
+#[test]
+fn unary_operators() {
+    assert_eq!(
+        fold_unary(UnOp::Minus, (Char, ConstValue::Int(65))),
+        Some(int(-65))
+    );
+    assert_eq!(
+        fold_unary(UnOp::Minus, int(i64::from(i32::MIN))),
+        Some(int(i64::from(i32::MIN)))
+    );
+    assert_eq!(fold_unary(UnOp::BitNot, int(0)), Some(int(-1)));
+    assert_eq!(fold_unary(UnOp::BitNot, long(0)), Some(long(-1)));
+    assert_eq!(
+        fold_unary(UnOp::Not, (Boolean, ConstValue::Boolean(true))),
+        Some((Boolean, ConstValue::Boolean(false)))
+    );
+    assert_eq!(fold_unary(UnOp::Not, int(1)), None);
+}
@@ -0,0 +1,92 @@
+use nova_types::{
+    resolve_method_call, CallKind, MethodCall, MethodResolution, TyContext, Type, TypeEnv,
+    TypeStore,
+};
+
+use super::support;
+
+fn add_call<'a>(env: &TypeStore, receiver: Type) -> MethodCall<'a> {
+    let string = Type::class(env.well_known().string, vec![]);
+    MethodCall {
+        receiver,
+        call_kind: CallKind::Instance,
+        name: "add",
+        args: vec![string],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    }
+}
+
+#[test]
+fn resolution_records_consulted_classes() {
+    let env = TypeStore::with_minimal_jdk();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let list = env.class_id("java.util.List").unwrap();
+    let iterable = env.class_id("java.lang.Iterable").unwrap();
+    let math = env.class_id("java.lang.Math").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let call = add_call(&env, Type::class(array_list, vec![string]));
+    let mut ctx = TyContext::new(&env);
+    ctx.track_dependencies(true);
+    let MethodResolution::Found(_) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected ArrayList<String>.add(String) to resolve");
+    };
+    let deps = ctx.take_dependencies();
+
+    for (name, id) in [
+        ("ArrayList", array_list),
+        ("List", list),
+        ("Iterable", iterable),
+        ("Object", env.well_known().object),
+        ("String", env.well_known().string),
+    ] {
+        assert!(deps.classes.contains(&id), "missing {name} in {deps:?}");
+    }
+    assert!(!deps.classes.contains(&math));
+
+    // Taking the set resets it while tracking stays enabled.
+    assert!(ctx.take_dependencies().is_empty());
+    let _ = resolve_method_call(&mut ctx, &call);
+    assert!(ctx.take_dependencies().classes.contains(&array_list));
+}
+
+#[test]
+fn unrelated_upserted_class_is_not_a_dependency() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let unrelated = env.upsert_class(support::class("com.example.Unrelated", Some(object)));
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let call = add_call(&env, Type::class(array_list, vec![string]));
+    let mut ctx = TyContext::new(&env);
+    ctx.track_dependencies(true);
+    let _ = resolve_method_call(&mut ctx, &call);
+    let deps = ctx.take_dependencies();
+
+    assert!(deps.classes.contains(&array_list));
+    assert!(!deps.classes.contains(&unrelated));
+}
+
+#[test]
+fn tracking_is_off_by_default() {
+    let env = TypeStore::with_minimal_jdk();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let call = add_call(&env, Type::class(array_list, vec![string]));
+    let mut ctx = TyContext::new(&env);
+    let _ = resolve_method_call(&mut ctx, &call);
+    assert!(ctx.take_dependencies().is_empty());
+
+    // Disabling drops anything recorded so far.
+    ctx.track_dependencies(true);
+    let _ = resolve_method_call(&mut ctx, &call);
+    ctx.track_dependencies(false);
+    assert!(ctx.take_dependencies().is_empty());
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use nova_types::{
+    collect_candidate_completions, deprecation_warning, lint_category, resolve_method_call,
+    warning_severity, CallKind, ClassId, Deprecation, FieldStub, LintCategory, MethodCall,
+    MethodResolution, MethodStub, ResolvedMethod, Severity, TyContext, Type, TypeDefStub, TypeEnv,
+    TypeProvider, TypeStore, TypeWarning,
+};
+use nova_types_bridge::ExternalTypeLoader;
+
+use pretty_assertions::assert_eq;
+
+const ACC_PUBLIC: u16 = 0x0001;
+
+#[derive(Default)]
+struct StubProvider {
+    stubs: HashMap<String, TypeDefStub>,
+}
+
+impl TypeProvider for StubProvider {
+    fn lookup_type(&self, binary_name: &str) -> Option<TypeDefStub> {
+        self.stubs.get(binary_name).cloned()
+    }
+}
+
+fn method(name: &str, deprecation: Option<Deprecation>) -> MethodStub {
+    MethodStub {
+        name: name.to_string(),
+        descriptor: "()V".to_string(),
+        signature: None,
+        access_flags: ACC_PUBLIC,
+        deprecation,
+    }
+}
+
+fn removal() -> Option<Deprecation> {
+    Some(Deprecation {
+        since: Some("9".to_string()),
+        for_removal: true,
+    })
+}
+
+/// `Base` deprecates `old()` (for removal), `legacy()` and the field `LEGACY`; `Sub` overrides
+/// `old()` without re-declaring the deprecation.
+fn setup() -> (TypeStore, ClassId, ClassId) {
+    let mut provider = StubProvider::default();
+    for stub in [
+        TypeDefStub {
+            binary_name: "com.example.Base".to_string(),
+            access_flags: ACC_PUBLIC,
+            super_binary_name: Some("java.lang.Object".to_string()),
+            interfaces: vec![],
+            signature: None,
+            fields: vec![FieldStub {
+                name: "LEGACY".to_string(),
+                descriptor: "I".to_string(),
+                signature: None,
+                access_flags: ACC_PUBLIC,
+                deprecation: Some(Deprecation::default()),
+            }],
+            methods: vec![
+                method("old", removal()),
+                method("legacy", Some(Deprecation::default())),
+                method("current", None),
+            ],
+            deprecation: None,
+            inner_class: None,
+        },
+        TypeDefStub {
+            binary_name: "com.example.Sub".to_string(),
+            access_flags: ACC_PUBLIC,
+            super_binary_name: Some("com.example.Base".to_string()),
+            interfaces: vec![],
+            signature: None,
+            fields: vec![],
+            methods: vec![method("old", None)],
+            deprecation: None,
+            inner_class: None,
+        },
+    ] {
+        provider.stubs.insert(stub.binary_name.clone(), stub);
+    }
+
+    let mut env = TypeStore::with_minimal_jdk();
+    let (base, sub) = {
+        let mut loader = ExternalTypeLoader::new(&mut env, &provider);
+        let base = loader.ensure_class("com.example.Base").expect("Base");
+        let sub = loader.ensure_class("com.example.Sub").expect("Sub");
+        (base, sub)
+    };
+    (env, base, sub)
+}
+
+fn resolve(env: &TypeStore, receiver: ClassId, name: &str) -> ResolvedMethod {
+    let call = MethodCall {
+        receiver: Type::class(receiver, vec![]),
+        call_kind: CallKind::Instance,
+        name,
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    match resolve_method_call(&mut ctx, &call) {
+        MethodResolution::Found(method) => method,
+        other => panic!("expected a resolved method, got {other:?}"),
+    }
+}
+
+#[test]
+fn loaded_stubs_keep_their_deprecation() {
+    let (env, base, _) = setup();
+    let class = env.class(base).expect("Base def");
+
+    let old = class.methods.iter().find(|m| m.name == "old").unwrap();
+    assert_eq!(old.deprecation, removal());
+    let current = class.methods.iter().find(|m| m.name == "current").unwrap();
+    assert_eq!(current.deprecation, None);
+    assert_eq!(class.fields[0].deprecation, Some(Deprecation::default()));
+}
+
+#[test]
+fn calls_to_deprecated_methods_warn() {
+    let (env, base, _) = setup();
+
+    let old = resolve(&env, base, "old");
+    assert_eq!(old.deprecated, removal());
+    let warning = deprecation_warning(&old).expect("deprecation warning");
+    assert_eq!(warning, TypeWarning::DeprecatedMember { for_removal: true });
+    assert_eq!(lint_category(&warning), LintCategory::Removal);
+    assert_eq!(warning_severity(&warning), Severity::Warning);
+
+    let legacy = resolve(&env, base, "legacy");
+    let warning = deprecation_warning(&legacy).expect("deprecation warning");
+    assert_eq!(
+        warning,
+        TypeWarning::DeprecatedMember { for_removal: false }
+    );
+    assert_eq!(lint_category(&warning), LintCategory::Deprecation);
+    assert_eq!(warning_severity(&warning), Severity::Info);
+
+    assert_eq!(deprecation_warning(&resolve(&env, base, "current")), None);
+}
+
+#[test]
+fn overrides_are_not_deprecated_unless_marked() {
+    let (env, _, sub) = setup();
+
+    let old = resolve(&env, sub, "old");
+    assert_eq!(old.deprecated, None);
+    assert_eq!(deprecation_warning(&old), None);
+}
+
+#[test]
+fn deprecated_completions_sort_after_their_siblings() {
+    let (env, base, sub) = setup();
+
+    let labels = |receiver: ClassId| {
+        collect_candidate_completions(&env, &Type::class(receiver, vec![]), "", CallKind::Instance)
+            .into_iter()
+            .map(|item| (item.label, item.deprecated))
+            .take_while(|(label, _)| label != "hashCode" && label != "toString")
+            .collect::<Vec<_>>()
+    };
+
+    let base_items = labels(base);
+    assert_eq!(
+        base_items,
+        vec![
+            ("current".to_string(), false),
+            ("LEGACY".to_string(), true),
+            ("old".to_string(), true),
+            ("legacy".to_string(), true),
+        ]
+    );
+
+    // `Sub.old()` is not deprecated, hides `Base.old()` and comes before anything inherited.
+    let sub_items = labels(sub);
+    assert_eq!(sub_items[0], ("old".to_string(), false));
+    assert_eq!(
+        sub_items[1..],
+        base_items
+            .iter()
+            .filter(|(label, _)| label != "old")
+            .cloned()
+            .collect::<Vec<_>>()[..]
+    );
+}
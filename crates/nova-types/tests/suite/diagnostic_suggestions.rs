@@ -0,0 +1,47 @@
+use nova_types::{Diagnostic, Span, Suggestion, TextEdit};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn diagnostics_carry_add_cast_suggestions() {
+    let text = "int x = count;";
+    let start = text.find("count").unwrap();
+    let span = Span::new(start, start + "count".len());
+
+    let plain = Diagnostic::error(
+        "incompatible-types",
+        "possible lossy conversion from long to int",
+        Some(span),
+    );
+    assert!(plain.suggestions.is_empty());
+
+    let add_cast = Suggestion::new(
+        "Add cast to int",
+        vec![TextEdit::insert(span.start, "(int) ")],
+    );
+    let diagnostic = plain.clone().with_suggestion(add_cast.clone());
+    assert_eq!(diagnostic.suggestions, vec![add_cast]);
+    assert_ne!(diagnostic, plain);
+
+    let edit = &diagnostic.suggestions[0].edits[0];
+    let mut fixed = text.to_string();
+    fixed.replace_range(edit.span.start..edit.span.end, &edit.new_text);
+    assert_eq!(fixed, "int x = (int) count;");
+}
+
+#[test]
+fn suggestions_are_omitted_from_json_when_empty() {
+    let diagnostic = Diagnostic::warning("unused", "unused import", Some(Span::new(0, 10)));
+    let json = serde_json::to_value(&diagnostic).unwrap();
+    assert!(json.get("suggestions").is_none());
+
+    let diagnostic = diagnostic.with_suggestion(Suggestion::new(
+        "Remove unused import",
+        vec![TextEdit::new(Span::new(0, 10), "")],
+    ));
+    let json = serde_json::to_string(&diagnostic).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Diagnostic>(&json).unwrap(),
+        diagnostic
+    );
+}
@@ -0,0 +1,98 @@
+use nova_types::{
+    infer_enhanced_for_type, infer_enhanced_for_type_with_warnings, PrimitiveType, Type, TypeEnv,
+    TypeStore, TypeWarning, UncheckedReason, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn explicit_type_compatible_with_element() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let list_string = Type::class(list, vec![string.clone()]);
+
+    // `for (String s : List<String>)`
+    assert_eq!(
+        infer_enhanced_for_type(&env, Some(&string), &list_string),
+        string
+    );
+    // `for (Object o : List<String>)` types the variable as declared.
+    assert_eq!(
+        infer_enhanced_for_type(&env, Some(&object), &list_string),
+        object
+    );
+    // `for (long x : int[])`
+    let int_array = Type::Array(Box::new(Type::int()));
+    let long = Type::Primitive(PrimitiveType::Long);
+    assert_eq!(infer_enhanced_for_type(&env, Some(&long), &int_array), long);
+}
+
+#[test]
+fn explicit_type_incompatible_with_element_is_error() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let list_string = Type::class(list, vec![string]);
+
+    assert_eq!(
+        infer_enhanced_for_type(&env, Some(&integer), &list_string),
+        Type::Error
+    );
+}
+
+#[test]
+fn var_uses_element_type() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // `for (var s : ArrayList<String>)`
+    assert_eq!(
+        infer_enhanced_for_type(&env, None, &Type::class(array_list, vec![string.clone()])),
+        string
+    );
+    // `for (var x : String[])`
+    assert_eq!(
+        infer_enhanced_for_type(&env, None, &Type::Array(Box::new(string.clone()))),
+        string
+    );
+    // `for (var n : List<? extends Integer>)`
+    let list_wildcard = Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+            integer.clone(),
+        )))],
+    );
+    assert_eq!(infer_enhanced_for_type(&env, None, &list_wildcard), integer);
+}
+
+#[test]
+fn raw_iterable_yields_unknown_with_unchecked_warning() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let raw_list = Type::class(list, vec![]);
+
+    let (ty, warnings) = infer_enhanced_for_type_with_warnings(&env, None, &raw_list);
+    assert_eq!(ty, Type::Unknown);
+    assert_eq!(
+        warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::RawTypeUsage)]
+    );
+}
+
+#[test]
+fn non_iterable_is_error() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert_eq!(infer_enhanced_for_type(&env, None, &string), Type::Error);
+    assert_eq!(
+        infer_enhanced_for_type(&env, None, &Type::int()),
+        Type::Error
+    );
+}
@@ -0,0 +1,62 @@
+use nova_types::{
+    is_checked_exception, is_exception_type, is_unchecked_exception, ClassDef, ConstructorDef,
+    Type, TypeEnv, TypeStore,
+};
+
+use super::support;
+
+fn exception_stub(env: &mut TypeStore, name: &str, super_class: &str) -> Type {
+    let super_class = env.class_id(super_class).unwrap();
+    let id = env.add_class(ClassDef {
+        constructors: vec![ConstructorDef {
+            params: vec![],
+            is_varargs: false,
+            is_accessible: true,
+            deprecation: None,
+        }],
+        ..support::class(name, Some(Type::class(super_class, vec![])))
+    });
+    Type::class(id, vec![])
+}
+
+#[test]
+fn classifies_checked_and_unchecked_exceptions() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let npe = exception_stub(
+        &mut env,
+        "java.lang.NullPointerException",
+        "java.lang.RuntimeException",
+    );
+    let io_exception = exception_stub(&mut env, "java.io.IOException", "java.lang.Exception");
+    let throwable = Type::class(env.class_id("java.lang.Throwable").unwrap(), vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert!(is_exception_type(&env, &throwable));
+    assert!(is_checked_exception(&env, &throwable));
+    assert!(!is_unchecked_exception(&env, &throwable));
+
+    assert!(is_exception_type(&env, &npe));
+    assert!(is_unchecked_exception(&env, &npe));
+    assert!(!is_checked_exception(&env, &npe));
+
+    assert!(is_exception_type(&env, &io_exception));
+    assert!(is_checked_exception(&env, &io_exception));
+    assert!(!is_unchecked_exception(&env, &io_exception));
+
+    for ty in [string, Type::Null, Type::Unknown] {
+        assert!(!is_exception_type(&env, &ty), "{ty:?}");
+        assert!(!is_checked_exception(&env, &ty), "{ty:?}");
+        assert!(!is_unchecked_exception(&env, &ty), "{ty:?}");
+    }
+}
+
+#[test]
+fn errors_are_unchecked_when_defined() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let error = exception_stub(&mut env, "java.lang.Error", "java.lang.Throwable");
+    let oom = exception_stub(&mut env, "java.lang.OutOfMemoryError", "java.lang.Error");
+
+    assert!(is_unchecked_exception(&env, &error));
+    assert!(is_unchecked_exception(&env, &oom));
+    assert!(!is_checked_exception(&env, &oom));
+}
@@ -75,6 +75,10 @@ fn external_type_loader_bridge_from_classpath_index() {
         args: vec![Type::int()],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     {
         let mut ctx = TyContext::new(&*loader.store);
@@ -166,6 +170,10 @@ fn external_type_loader_bridge_from_classpath_index() {
         args: vec![Type::class(string_id, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     {
         let mut ctx = TyContext::new(&*loader.store);
@@ -34,6 +34,8 @@ fn external_type_loader_flattens_inner_class_args_across_segments() {
         signature: Some("<T:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     // Inner class that (for signature purposes) expects both the outer and inner
@@ -46,6 +48,8 @@ fn external_type_loader_flattens_inner_class_args_across_segments() {
         signature: Some("<T:Ljava/lang/Object;U:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     provider.insert(TypeDefStub {
@@ -61,8 +65,11 @@ fn external_type_loader_flattens_inner_class_args_across_segments() {
             // Generic signature with per-segment args.
             signature: Some("Lcom/example/Outer<TT;>.Inner<TU;>;".to_string()),
             access_flags: 0,
+            deprecation: None,
         }],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut store = TypeStore::default();
@@ -103,6 +110,8 @@ fn external_type_loader_reconciles_inner_class_arg_mismatches_by_dropping_leadin
         signature: Some("<T:Ljava/lang/Object;U:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     // Target class expects 2 type arguments.
@@ -114,6 +123,8 @@ fn external_type_loader_reconciles_inner_class_arg_mismatches_by_dropping_leadin
         signature: Some("<A:Ljava/lang/Object;B:Ljava/lang/Object;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     // Signature provides 3 args across segments (`Outer<T, U>.Inner<V>`). The loader should
@@ -132,8 +143,11 @@ fn external_type_loader_reconciles_inner_class_arg_mismatches_by_dropping_leadin
             descriptor: "Lcom/example/Outer$Inner;".to_string(),
             signature: Some("Lcom/example/Outer<TT;TU;>.Inner<TV;>;".to_string()),
             access_flags: 0,
+            deprecation: None,
         }],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut store = TypeStore::default();
@@ -37,6 +37,8 @@ fn interface_only_class_type_parameter_bound_does_not_add_implicit_object() {
         signature: Some("<T::Ljava/io/Serializable;>Ljava/lang/Object;".to_string()),
         fields: vec![],
         methods: vec![],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut store = TypeStore::default();
@@ -80,7 +82,10 @@ fn interface_only_method_type_parameter_bound_does_not_add_implicit_object() {
             descriptor: "(Ljava/io/Serializable;)Ljava/io/Serializable;".to_string(),
             signature: Some("<T::Ljava/io/Serializable;>(TT;)TT;".to_string()),
             access_flags: 0,
+            deprecation: None,
         }],
+        deprecation: None,
+        inner_class: None,
     });
 
     let mut store = TypeStore::default();
@@ -0,0 +1,44 @@
+use nova_types::{ClassId, FieldId, PrimitiveType, Type, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn math_pi_resolves_through_its_field_id() {
+    let env = TypeStore::with_minimal_jdk();
+    let math = env.class_id("java.lang.Math").unwrap();
+
+    let pi = FieldId::for_field(&env, math, "PI").unwrap();
+    let (owner, field) = pi.resolve(&env).unwrap();
+    assert_eq!(owner.name, "java.lang.Math");
+    assert_eq!(field.name, "PI");
+    assert_eq!(field.ty, Type::Primitive(PrimitiveType::Double));
+    assert!(field.is_static && field.is_final);
+
+    // Round trip through the raw encoding.
+    let e = FieldId::for_field(&env, math, "E").unwrap();
+    assert_ne!(pi, e);
+    assert_eq!(FieldId::new(e.0), e);
+    assert_eq!(FieldId::new(e.0).resolve(&env).unwrap().1.name, "E");
+
+    assert_eq!(FieldId::for_field(&env, math, "TAU"), None);
+    assert_eq!(
+        FieldId::for_field(&env, ClassId::from_raw(u32::MAX), "PI"),
+        None
+    );
+}
+
+#[test]
+fn out_of_bounds_field_ids_resolve_to_none() {
+    let env = TypeStore::with_minimal_jdk();
+    let math = env.class_id("java.lang.Math").unwrap();
+    let pi = FieldId::for_field(&env, math, "PI").unwrap();
+
+    // An index past the class's fields.
+    assert!(FieldId::new(pi.0 | 0xFFFF).resolve(&env).is_none());
+    // A class id past the store.
+    assert!(FieldId::new(u32::MAX).resolve(&env).is_none());
+    // A store rebuilt without the class.
+    let mut rebuilt = TypeStore::with_minimal_jdk();
+    rebuilt.remove_class("java.lang.Math");
+    assert!(pi.resolve(&rebuilt).is_none());
+}
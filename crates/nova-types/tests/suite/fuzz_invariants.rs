@@ -0,0 +1,41 @@
+use nova_types::fuzzing::{check_method_call, fuzz_case, fuzz_env, Choices, FuzzCase, FuzzEnv};
+use nova_types::{TypeEnv, TypeStore};
+use proptest::prelude::*;
+
+#[test]
+fn empty_input_decodes_to_a_single_class() {
+    let env = FuzzEnv::generate(&mut Choices::new(&[]));
+    let c0 = env.store.lookup_class("fuzz.C0").unwrap();
+    assert!(env.classes.contains(&c0));
+    assert!(env.store.lookup_class("fuzz.C1").is_none());
+
+    FuzzCase::generate(&mut Choices::new(&[])).check();
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn generated_environments_keep_the_minimal_jdk(env in fuzz_env(256)) {
+        let jdk = TypeStore::with_minimal_jdk();
+        for name in nova_types::MINIMAL_JDK_BINARY_NAMES {
+            prop_assert_eq!(env.store.lookup_class(name), jdk.lookup_class(name));
+        }
+    }
+
+    #[test]
+    fn type_and_resolution_invariants_hold(case in fuzz_case(512)) {
+        case.check();
+    }
+
+    #[test]
+    fn every_generated_call_resolves_within_budget(
+        (env, bytes) in (fuzz_env(256), proptest::collection::vec(any::<u8>(), 0..128))
+    ) {
+        let mut choices = Choices::new(&bytes);
+        while !choices.is_empty() {
+            let call = env.method_call(&mut choices);
+            check_method_call(&env, &call);
+        }
+    }
+}
@@ -30,21 +30,31 @@ fn infer_upper_bound_intersection_is_order_independent() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -54,6 +64,10 @@ fn infer_upper_bound_intersection_is_order_independent() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -62,6 +76,10 @@ fn infer_upper_bound_intersection_is_order_independent() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -107,21 +125,31 @@ fn infer_upper_bound_intersection_is_order_independent_with_errorish_bounds() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -131,6 +159,10 @@ fn infer_upper_bound_intersection_is_order_independent_with_errorish_bounds() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -139,6 +171,10 @@ fn infer_upper_bound_intersection_is_order_independent_with_errorish_bounds() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -169,6 +205,10 @@ fn infer_upper_bound_intersection_prunes_redundant_supertypes() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let a = env.add_class(ClassDef {
         name: "com.example.A".to_string(),
@@ -179,6 +219,10 @@ fn infer_upper_bound_intersection_prunes_redundant_supertypes() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let i_ty = Type::class(i, vec![]);
@@ -208,21 +252,31 @@ fn infer_upper_bound_intersection_prunes_redundant_supertypes() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -232,6 +286,10 @@ fn infer_upper_bound_intersection_prunes_redundant_supertypes() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -240,6 +298,10 @@ fn infer_upper_bound_intersection_prunes_redundant_supertypes() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -276,6 +338,10 @@ fn infer_upper_bound_intersection_normalizes_equivalent_intersection_bounds() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let comparable = Type::class(comparable, vec![]);
 
@@ -308,21 +374,31 @@ fn infer_upper_bound_intersection_normalizes_equivalent_intersection_bounds() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -332,6 +408,10 @@ fn infer_upper_bound_intersection_normalizes_equivalent_intersection_bounds() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -340,6 +420,10 @@ fn infer_upper_bound_intersection_normalizes_equivalent_intersection_bounds() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -386,21 +470,31 @@ fn infer_upper_bound_intersection_keeps_class_bound_first() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -410,6 +504,10 @@ fn infer_upper_bound_intersection_keeps_class_bound_first() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -418,6 +516,10 @@ fn infer_upper_bound_intersection_keeps_class_bound_first() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -453,6 +555,10 @@ fn infer_upper_bound_intersection_keeps_class_bound_first_with_named_interface()
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let z = Type::class(z, vec![]);
 
@@ -474,21 +580,31 @@ fn infer_upper_bound_intersection_keeps_class_bound_first_with_named_interface()
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -498,6 +614,10 @@ fn infer_upper_bound_intersection_keeps_class_bound_first_with_named_interface()
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -506,6 +626,10 @@ fn infer_upper_bound_intersection_keeps_class_bound_first_with_named_interface()
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -552,11 +676,18 @@ fn infer_upper_bound_normalizes_existing_intersection_bound() {
             name: "m".to_string(),
             type_params: vec![t],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -566,6 +697,10 @@ fn infer_upper_bound_normalizes_existing_intersection_bound() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -608,21 +743,31 @@ fn infer_upper_bound_normalizes_single_intersection_bound() {
                 name: "m1".to_string(),
                 type_params: vec![t1],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m2".to_string(),
                 type_params: vec![t2],
                 params: vec![],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call1 = MethodCall {
@@ -632,6 +777,10 @@ fn infer_upper_bound_normalizes_single_intersection_bound() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let call2 = MethodCall {
         receiver: Type::class(test, vec![]),
@@ -640,6 +789,10 @@ fn infer_upper_bound_normalizes_single_intersection_bound() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -689,11 +842,18 @@ fn infer_upper_bound_flattens_nested_intersection_bounds() {
             name: "m".to_string(),
             type_params: vec![t],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -703,6 +863,10 @@ fn infer_upper_bound_flattens_nested_intersection_bounds() {
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -0,0 +1,185 @@
+use nova_types::{
+    enclosing_chain, outer_class, resolve_field, resolve_method_call, CallKind, ClassDef,
+    ClassKind, FieldDef, MethodCall, MethodResolution, TyContext, Type, TypeEnv, TypeParamDef,
+    TypeStore, TypeVarId,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn class(name: &str, kind: ClassKind, type_params: Vec<TypeVarId>) -> ClassDef {
+    ClassDef {
+        kind,
+        type_params,
+        ..support::class(name, None)
+    }
+}
+
+#[test]
+fn inner_class_sees_outer_type_params() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // class Outer<T> { class Inner<U> { T t; } }
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object.clone()]);
+    let outer = env.add_class(class("com.example.Outer", ClassKind::Class, vec![t]));
+    let inner = env.add_class(ClassDef {
+        enclosing: Some(outer),
+        ..class("com.example.Outer$Inner", ClassKind::Class, vec![u])
+    });
+    env.define_type_param(
+        t,
+        TypeParamDef {
+            name: "T".to_string(),
+            upper_bounds: vec![object],
+            lower_bound: None,
+            declared_by: Some(outer),
+        },
+    );
+
+    let inner_def = env.class(inner).unwrap();
+    assert_eq!(inner_def.all_type_params(&env), vec![u, t]);
+    assert_eq!(env.class(outer).unwrap().all_type_params(&env), vec![t]);
+
+    assert_eq!(env.type_param(t).unwrap().declared_by, Some(outer));
+    assert_eq!(env.type_param(u).unwrap().declared_by, None);
+}
+
+#[test]
+fn enclosing_chain_is_walked_and_deduplicated() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let a = env.add_type_param("A", vec![]);
+    let b = env.add_type_param("B", vec![]);
+    let top = env.add_class(class("com.example.Top", ClassKind::Class, vec![a]));
+    let mid = env.add_class(ClassDef {
+        enclosing: Some(top),
+        ..class("com.example.Top$Mid", ClassKind::Class, vec![b])
+    });
+    // The innermost class already lists `A` (e.g. a lowering that copies outer params).
+    let leaf = env.add_class(ClassDef {
+        enclosing: Some(mid),
+        ..class("com.example.Top$Mid$Leaf", ClassKind::Class, vec![a])
+    });
+
+    assert_eq!(env.class(leaf).unwrap().all_type_params(&env), vec![a, b]);
+}
+
+#[test]
+fn interfaces_do_not_inherit_type_params() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let t = env.add_type_param("T", vec![]);
+    env.add_class(class("com.example.Holder", ClassKind::Class, vec![t]));
+    let nested = env.add_class(class(
+        "com.example.Holder$Callback",
+        ClassKind::Interface,
+        vec![],
+    ));
+    let iface = env.add_class(class("com.example.Api", ClassKind::Interface, vec![t]));
+    let member = env.add_class(ClassDef {
+        enclosing: Some(iface),
+        ..class("com.example.Api$Impl", ClassKind::Class, vec![])
+    });
+
+    assert!(env.class(nested).unwrap().all_type_params(&env).is_empty());
+    assert!(env.class(member).unwrap().all_type_params(&env).is_empty());
+    assert_eq!(env.class(iface).unwrap().all_type_params(&env), vec![t]);
+}
+
+#[test]
+fn static_nested_classes_do_not_inherit_type_params() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let t = env.add_type_param("T", vec![]);
+    env.add_class(class("com.example.Holder", ClassKind::Class, vec![t]));
+    let nested = env.add_class(ClassDef {
+        is_static_nested: true,
+        is_abstract: false,
+        ..class("com.example.Holder$Entry", ClassKind::Class, vec![])
+    });
+    let inner = env.add_class(ClassDef {
+        enclosing: Some(nested),
+        ..class("com.example.Holder$Entry$Inner", ClassKind::Class, vec![])
+    });
+
+    assert!(env.class(nested).unwrap().all_type_params(&env).is_empty());
+    // `Inner`'s enclosing instance is an `Entry`, which has no enclosing instance itself.
+    assert!(env.class(inner).unwrap().all_type_params(&env).is_empty());
+}
+
+#[test]
+fn unknown_nesting_is_treated_as_static() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let t = env.add_type_param("T", vec![]);
+    let holder = env.add_class(class("com.example.Holder", ClassKind::Class, vec![t]));
+    // Only the binary name says `Entry` is nested; whether it is `static` is unknown.
+    let entry = env.add_class(class("com.example.Holder$Entry", ClassKind::Class, vec![]));
+
+    assert_eq!(outer_class(&env, entry), Some(holder));
+    assert!(env.class(entry).unwrap().all_type_params(&env).is_empty());
+}
+
+#[test]
+fn enclosing_chain_prefers_explicit_enclosing() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let outer = env.add_class(class("com.example.Outer", ClassKind::Class, vec![]));
+    let mid = env.add_class(class("com.example.Outer$Mid", ClassKind::Class, vec![]));
+    // Local classes get synthetic binary names that don't spell out the enclosing class.
+    let local = env.add_class(ClassDef {
+        enclosing: Some(mid),
+        ..class("com.example.Local$1", ClassKind::Class, vec![])
+    });
+
+    assert_eq!(outer_class(&env, mid), Some(outer));
+    assert_eq!(outer_class(&env, outer), None);
+    assert_eq!(outer_class(&env, local), Some(mid));
+    assert_eq!(enclosing_chain(&env, local), vec![mid, outer]);
+    assert!(enclosing_chain(&env, outer).is_empty());
+}
+
+#[test]
+fn inner_class_members_see_enclosing_instance_args() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // class Outer<T> { class Inner { T value; T get(); } }
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let outer = env.add_class(class("com.example.Outer", ClassKind::Class, vec![t]));
+    let inner = env.add_class(ClassDef {
+        super_class: Some(object),
+        fields: vec![FieldDef {
+            name: "value".to_string(),
+            ty: Type::TypeVar(t),
+            is_static: false,
+            is_final: false,
+            deprecation: None,
+        }],
+        methods: vec![support::method("get", vec![], Type::TypeVar(t))],
+        enclosing: Some(outer),
+        ..class("com.example.Outer$Inner", ClassKind::Class, vec![])
+    });
+
+    // `Outer<String>.Inner`, with the enclosing instance's arguments flattened in.
+    let receiver = Type::class(inner, vec![string.clone()]);
+    let field = resolve_field(&env, &receiver, "value", CallKind::Instance).unwrap();
+    assert_eq!(field.ty, string);
+
+    let call = MethodCall {
+        receiver,
+        call_kind: CallKind::Instance,
+        name: "get",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(get) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected get to resolve");
+    };
+    assert_eq!(get.return_type, string);
+}
@@ -0,0 +1,95 @@
+use nova_types::{
+    is_instantiable, requires_generic_args, ClassDef, ConstructorDef, PrimitiveType, Type, TypeEnv,
+    TypeStore,
+};
+
+use super::support;
+
+fn class(env: &TypeStore, name: &str, constructors: Vec<ConstructorDef>) -> ClassDef {
+    ClassDef {
+        constructors,
+        ..support::class(name, Some(Type::class(env.well_known().object, vec![])))
+    }
+}
+
+fn constructor(is_accessible: bool) -> ConstructorDef {
+    ConstructorDef {
+        params: vec![],
+        is_varargs: false,
+        is_accessible,
+        deprecation: None,
+    }
+}
+
+#[test]
+fn array_list_is_instantiable_but_list_is_not() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let array_list = env.lookup_class("java.util.ArrayList").unwrap();
+    let list = env.lookup_class("java.util.List").unwrap();
+
+    assert!(is_instantiable(
+        &env,
+        &Type::class(array_list, vec![string.clone()])
+    ));
+    assert!(is_instantiable(&env, &Type::class(array_list, vec![])));
+    assert!(!is_instantiable(&env, &Type::class(list, vec![string])));
+}
+
+#[test]
+fn abstract_classes_type_variables_and_primitives_are_not_instantiable() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let number = env.lookup_class("java.lang.Number").unwrap();
+    let t = env.add_type_param("T", vec![Type::class(env.well_known().object, vec![])]);
+    let shape = env.add_class(ClassDef {
+        is_abstract: true,
+        ..class(&env, "com.example.Shape", vec![])
+    });
+
+    assert!(!is_instantiable(&env, &Type::class(number, vec![])));
+    assert!(!is_instantiable(&env, &Type::class(shape, vec![])));
+    assert!(!is_instantiable(&env, &Type::TypeVar(t)));
+    assert!(!is_instantiable(&env, &Type::Primitive(PrimitiveType::Int)));
+}
+
+#[test]
+fn instantiation_needs_an_accessible_constructor() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let implicit = env.add_class(class(&env, "com.example.Implicit", vec![]));
+    let singleton = env.add_class(class(
+        &env,
+        "com.example.Singleton",
+        vec![constructor(false)],
+    ));
+    let factory = env.add_class(class(
+        &env,
+        "com.example.Factory",
+        vec![constructor(false), constructor(true)],
+    ));
+
+    assert!(is_instantiable(&env, &Type::class(implicit, vec![])));
+    assert!(!is_instantiable(&env, &Type::class(singleton, vec![])));
+    assert!(is_instantiable(&env, &Type::class(factory, vec![])));
+}
+
+#[test]
+fn generic_classes_require_type_arguments() {
+    let env = TypeStore::with_minimal_jdk();
+    let array_list = env.lookup_class("java.util.ArrayList").unwrap();
+    let list = env.lookup_class("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert!(requires_generic_args(
+        &env,
+        &Type::class(array_list, vec![])
+    ));
+    assert!(requires_generic_args(
+        &env,
+        &Type::class(list, vec![string.clone()])
+    ));
+    assert!(!requires_generic_args(&env, &string));
+    assert!(!requires_generic_args(
+        &env,
+        &Type::Array(Box::new(Type::class(list, vec![])))
+    ));
+}
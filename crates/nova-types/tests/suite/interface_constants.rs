@@ -0,0 +1,189 @@
+use nova_types::{
+    resolve_field_with_owner, CallKind, ClassDef, ClassId, ClassKind, FieldDef, FieldResolution,
+    Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+/// Interfaces `A` and `B` both declare `int X = 1`; the classes implement one, both, or both
+/// while declaring their own `X`.
+struct Fixture {
+    env: TypeStore,
+    a: ClassId,
+    b: ClassId,
+}
+
+fn field(name: &str, is_static: bool) -> FieldDef {
+    FieldDef {
+        name: name.to_string(),
+        ty: Type::int(),
+        is_static,
+        is_final: is_static,
+        deprecation: None,
+    }
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let mut env = TypeStore::with_minimal_jdk();
+        let a = add(
+            &mut env,
+            "p.A",
+            ClassKind::Interface,
+            None,
+            vec![],
+            vec![field("X", true)],
+        );
+        let b = add(
+            &mut env,
+            "p.B",
+            ClassKind::Interface,
+            None,
+            vec![],
+            vec![field("X", true)],
+        );
+        let (a_ty, b_ty) = (Type::class(a, vec![]), Type::class(b, vec![]));
+        let only_a = add(
+            &mut env,
+            "p.OnlyA",
+            ClassKind::Class,
+            None,
+            vec![a_ty.clone()],
+            vec![],
+        );
+        add(
+            &mut env,
+            "p.Both",
+            ClassKind::Class,
+            None,
+            vec![a_ty.clone(), b_ty.clone()],
+            vec![],
+        );
+        add(
+            &mut env,
+            "p.BothWithOwn",
+            ClassKind::Class,
+            None,
+            vec![a_ty.clone(), b_ty.clone()],
+            vec![field("X", true)],
+        );
+        // `class SubOfOnlyA extends OnlyA implements A`: `A.X` along two paths.
+        let only_a = Type::class(only_a, vec![]);
+        add(
+            &mut env,
+            "p.SubOfOnlyA",
+            ClassKind::Class,
+            Some(only_a.clone()),
+            vec![a_ty.clone()],
+            vec![],
+        );
+        // `class SubWithB extends OnlyA implements B`: superclass and interface both have an `X`.
+        add(
+            &mut env,
+            "p.SubWithB",
+            ClassKind::Class,
+            Some(only_a),
+            vec![b_ty],
+            vec![],
+        );
+        add(
+            &mut env,
+            "p.InstanceX",
+            ClassKind::Class,
+            None,
+            vec![a_ty],
+            vec![field("X", false)],
+        );
+        Self { env, a, b }
+    }
+
+    fn resolve(&self, class: &str, call_kind: CallKind) -> FieldResolution {
+        let receiver = Type::class(self.env.class_id(class).unwrap(), vec![]);
+        resolve_field_with_owner(&self.env, &receiver, "X", call_kind)
+    }
+
+    /// The owner and `inherited` flag of the field `X` found on `class`.
+    fn found(&self, class: &str, call_kind: CallKind) -> (ClassId, bool) {
+        match self.resolve(class, call_kind) {
+            FieldResolution::Found(field) => {
+                assert_eq!(field.field.name, "X");
+                (field.owner, field.inherited)
+            }
+            other => panic!("expected {class}.X to resolve, got {other:?}"),
+        }
+    }
+
+    fn ambiguous_owners(&self, class: &str, call_kind: CallKind) -> Vec<ClassId> {
+        match self.resolve(class, call_kind) {
+            FieldResolution::Ambiguous(candidates) => {
+                candidates.iter().map(|field| field.owner).collect()
+            }
+            other => panic!("expected {class}.X to be ambiguous, got {other:?}"),
+        }
+    }
+}
+
+fn add(
+    env: &mut TypeStore,
+    name: &str,
+    kind: ClassKind,
+    super_class: Option<Type>,
+    interfaces: Vec<Type>,
+    fields: Vec<FieldDef>,
+) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    env.add_class(ClassDef {
+        kind,
+        interfaces,
+        fields,
+        is_abstract: kind == ClassKind::Interface,
+        ..support::class(name, super_class.or(Some(object)))
+    })
+}
+
+#[test]
+fn constant_through_an_implementing_class_reports_the_interface() {
+    let f = Fixture::new();
+    assert_eq!(f.found("p.OnlyA", CallKind::Static), (f.a, true));
+    assert_eq!(f.found("p.OnlyA", CallKind::Instance), (f.a, true));
+    assert_eq!(f.found("p.A", CallKind::Static), (f.a, false));
+}
+
+#[test]
+fn constant_from_two_unrelated_interfaces_is_ambiguous() {
+    let f = Fixture::new();
+    assert_eq!(
+        f.ambiguous_owners("p.Both", CallKind::Static),
+        vec![f.a, f.b]
+    );
+    assert_eq!(
+        f.ambiguous_owners("p.SubWithB", CallKind::Static),
+        vec![f.a, f.b]
+    );
+}
+
+#[test]
+fn the_same_constant_along_two_paths_is_not_ambiguous() {
+    let f = Fixture::new();
+    assert_eq!(f.found("p.SubOfOnlyA", CallKind::Static), (f.a, true));
+}
+
+#[test]
+fn class_field_hides_interface_constants() {
+    let f = Fixture::new();
+    let own = f.env.class_id("p.BothWithOwn").unwrap();
+    assert_eq!(f.found("p.BothWithOwn", CallKind::Static), (own, false));
+
+    // An instance field hides the constant too, so a static access finds nothing.
+    let instance_x = f.env.class_id("p.InstanceX").unwrap();
+    assert_eq!(
+        f.found("p.InstanceX", CallKind::Instance),
+        (instance_x, false)
+    );
+    assert!(matches!(
+        f.resolve("p.InstanceX", CallKind::Static),
+        FieldResolution::NotFound
+    ));
+}
@@ -24,6 +24,10 @@ fn interface_receivers_can_resolve_object_methods_without_explicit_super_class()
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -33,6 +37,10 @@ fn interface_receivers_can_resolve_object_methods_without_explicit_super_class()
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -53,6 +61,10 @@ fn interface_receivers_can_resolve_object_methods_without_explicit_super_class()
         args: vec![Type::class(iface, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -73,6 +85,10 @@ fn interface_receivers_can_resolve_object_methods_without_explicit_super_class()
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -0,0 +1,108 @@
+use nova_types::{
+    intersection_components, normalize_intersection, ClassDef, ClassKind, Type, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+#[test]
+fn normalize_intersection_prunes_sorts_and_flattens() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let integer = Type::class(env.class_id("java.lang.Integer").unwrap(), vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let array_list_of_string = Type::class(array_list, vec![string.clone()]);
+
+    assert_eq!(
+        normalize_intersection(
+            &env,
+            vec![
+                array_list_of_string.clone(),
+                Type::class(list, vec![string.clone()]),
+            ],
+        ),
+        array_list_of_string
+    );
+
+    let sorted = Type::Intersection(vec![integer.clone(), string.clone()]);
+    assert_eq!(
+        normalize_intersection(&env, vec![string.clone(), integer.clone()]),
+        sorted
+    );
+    assert_eq!(
+        normalize_intersection(&env, vec![sorted.clone(), string.clone()]),
+        sorted
+    );
+
+    assert_eq!(
+        normalize_intersection(&env, Vec::new()),
+        Type::Intersection(Vec::new())
+    );
+}
+
+#[test]
+fn intersection_components_of_intersections_and_other_types() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let integer = Type::class(env.class_id("java.lang.Integer").unwrap(), vec![]);
+
+    let both = Type::Intersection(vec![integer.clone(), string.clone()]);
+    assert_eq!(intersection_components(&both), &[integer, string.clone()]);
+    assert_eq!(
+        intersection_components(&string),
+        std::slice::from_ref(&string)
+    );
+    assert!(intersection_components(&Type::Intersection(Vec::new())).is_empty());
+}
+
+#[test]
+fn normalize_intersection_breaks_sort_key_ties_by_structure() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.class_id("java.lang.Object").unwrap(), vec![]);
+    let t = Type::TypeVar(env.add_type_param("T", vec![object]));
+
+    // `T[]` and `(T)[]` are mutual subtypes with the same sort key.
+    let plain = Type::Array(Box::new(t.clone()));
+    let wrapped = Type::Array(Box::new(Type::Intersection(vec![t])));
+    assert_eq!(
+        normalize_intersection(&env, vec![plain.clone(), wrapped.clone()]),
+        normalize_intersection(&env, vec![wrapped, plain]),
+    );
+}
+
+#[test]
+fn normalize_intersection_drops_object_next_to_other_components() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object_id = env.class_id("java.lang.Object").unwrap();
+    let object = Type::class(object_id, vec![]);
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let comparable = Type::class(
+        env.add_class(ClassDef {
+            kind: ClassKind::Interface,
+            ..support::class("java.lang.Comparable", None)
+        }),
+        vec![],
+    );
+
+    assert_eq!(
+        normalize_intersection(&env, vec![string.clone(), object.clone()]),
+        string
+    );
+    assert_eq!(
+        normalize_intersection(&env, vec![object.clone(), comparable.clone()]),
+        comparable
+    );
+    assert_eq!(
+        normalize_intersection(&env, vec![object.clone(), object.clone()]),
+        object
+    );
+
+    // Even next to a class the environment knows nothing about.
+    let missing = Type::class(env.intern_class_id("com.example.Missing"), vec![]);
+    assert_eq!(
+        normalize_intersection(&env, vec![object, missing.clone()]),
+        missing
+    );
+}
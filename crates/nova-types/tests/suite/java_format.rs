@@ -1,7 +1,8 @@
 use nova_types::{
-    format_method_signature, format_resolved_method, format_type, resolve_method_call, CallKind,
-    ClassDef, ClassKind, MethodCall, MethodDef, MethodResolution, MethodSearchPhase,
-    ResolvedMethod, Type, TypeEnv, TypeStore, WildcardBound,
+    format_annotated_type, format_method_signature, format_resolved_method, format_type,
+    format_type_truncated, project_type_display, resolve_method_call, AnnotatedType, CallKind,
+    ClassDef, ClassKind, MethodCall, MethodDef, MethodResolution, MethodSearchPhase, Nullability,
+    ResolvedMethod, Span, Type, TypeDisplay, TypeEnv, TypeFormatOptions, TypeStore, WildcardBound,
 };
 
 use pretty_assertions::assert_eq;
@@ -35,6 +36,10 @@ fn formats_intersection_types() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let ty = Type::Intersection(vec![
@@ -57,6 +62,10 @@ fn formats_nested_class_names() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     assert_eq!(format_type(&env, &Type::class(entry, vec![])), "Map.Entry");
@@ -77,6 +86,10 @@ fn formats_varargs_and_generic_methods() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let test_owner = env.add_class(ClassDef {
@@ -88,6 +101,10 @@ fn formats_varargs_and_generic_methods() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let t = env.add_type_param(
@@ -102,10 +119,13 @@ fn formats_varargs_and_generic_methods() {
         name: "max".to_string(),
         type_params: vec![t],
         params: vec![Type::TypeVar(t), Type::TypeVar(t)],
+        param_names: vec![],
         return_type: Type::TypeVar(t),
         is_static: true,
         is_varargs: false,
         is_abstract: false,
+        is_private: false,
+        deprecation: None,
     };
 
     assert_eq!(
@@ -117,10 +137,13 @@ fn formats_varargs_and_generic_methods() {
         name: "join".to_string(),
         type_params: vec![],
         params: vec![Type::Array(Box::new(Type::class(string, vec![])))],
+        param_names: vec![],
         return_type: Type::class(string, vec![]),
         is_static: true,
         is_varargs: true,
         is_abstract: false,
+        is_private: false,
+        deprecation: None,
     };
 
     assert_eq!(
@@ -134,6 +157,7 @@ fn formats_varargs_and_generic_methods() {
         name: "max".to_string(),
         params: vec![Type::class(string, vec![]), Type::class(string, vec![])],
         signature_params: None,
+        param_names: vec![],
         return_type: Type::class(string, vec![]),
         is_varargs: false,
         is_static: true,
@@ -142,6 +166,9 @@ fn formats_varargs_and_generic_methods() {
         warnings: vec![],
         used_varargs: false,
         phase: MethodSearchPhase::Strict,
+        speculative: false,
+        depends_on_placeholders: Vec::new(),
+        deprecated: None,
     };
 
     assert_eq!(
@@ -168,11 +195,18 @@ fn resolved_method_collapses_varargs_patterns_for_display() {
             name: "join".to_string(),
             type_params: vec![],
             params: vec![Type::Array(Box::new(Type::class(string, vec![])))],
+            param_names: vec![],
             return_type: Type::class(string, vec![]),
             is_static: true,
             is_varargs: true,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -182,6 +216,10 @@ fn resolved_method_collapses_varargs_patterns_for_display() {
         args: vec![Type::class(string, vec![]), Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = nova_types::java::env::TyContext::new(&env);
@@ -203,3 +241,261 @@ fn resolved_method_collapses_varargs_patterns_for_display() {
         "String join(String...)"
     );
 }
+
+fn nested_map(env: &TypeStore) -> Type {
+    let map = env.class_id("java.util.Map").unwrap();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // `Map<String, List<Map<Integer, List<String>>>>`
+    let inner = Type::class(map, vec![integer, Type::class(list, vec![string.clone()])]);
+    Type::class(map, vec![string, Type::class(list, vec![inner])])
+}
+
+#[test]
+fn truncates_type_arguments_beyond_max_depth() {
+    let env = TypeStore::with_minimal_jdk();
+    let ty = nested_map(&env);
+
+    assert_eq!(
+        format_type(&env, &ty),
+        "Map<String, List<Map<Integer, List<String>>>>"
+    );
+    assert_eq!(format_type_truncated(&env, &ty, 1), "Map<...>");
+    assert_eq!(
+        format_type_truncated(&env, &ty, 2),
+        "Map<String, List<...>>"
+    );
+    assert_eq!(
+        format_type_truncated(&env, &ty, 3),
+        "Map<String, List<Map<...>>>"
+    );
+
+    // Wildcards and arrays don't add a level.
+    let list = env.class_id("java.util.List").unwrap();
+    let wildcard = Type::Array(Box::new(Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(ty)))],
+    )));
+    assert_eq!(
+        format_type_truncated(&env, &wildcard, 2),
+        "List<? extends Map<...>>[]"
+    );
+}
+
+#[test]
+fn truncation_leaves_shallow_types_unchanged() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let ty = Type::class(list, vec![string.clone()]);
+
+    assert_eq!(format_type_truncated(&env, &ty, 2), "List<String>");
+    assert_eq!(format_type_truncated(&env, &ty, 10), "List<String>");
+    assert_eq!(format_type_truncated(&env, &string, 1), "String");
+}
+
+#[test]
+fn truncates_renders_wider_than_max_width() {
+    let env = TypeStore::with_minimal_jdk();
+    let ty = nested_map(&env);
+
+    assert_eq!(
+        TypeDisplay::new(&env, &ty).with_max_width(20).to_string(),
+        "Map<String, List<..."
+    );
+    assert_eq!(
+        TypeDisplay::new(&env, &ty)
+            .with_max_depth(2)
+            .with_max_width(22)
+            .to_string(),
+        "Map<String, List<...>>"
+    );
+}
+
+#[test]
+fn project_type_display_respects_options() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let list_of_string = Type::class(list, vec![string.clone()]);
+    let nested = Type::class(list, vec![list_of_string.clone()]);
+
+    let qualified = TypeFormatOptions::default();
+    assert_eq!(
+        project_type_display(&list_of_string, &env, qualified).to_string(),
+        "java.util.List<java.lang.String>"
+    );
+
+    let omit_java_lang = TypeFormatOptions {
+        omit_java_lang: true,
+        ..TypeFormatOptions::default()
+    };
+    assert_eq!(
+        project_type_display(&string, &env, omit_java_lang).to_string(),
+        "String"
+    );
+    assert_eq!(
+        project_type_display(&list_of_string, &env, omit_java_lang).to_string(),
+        "java.util.List<String>"
+    );
+
+    let abbreviated = TypeFormatOptions {
+        use_simple_names: true,
+        abbreviate_generics: true,
+        ..TypeFormatOptions::default()
+    };
+    assert_eq!(
+        project_type_display(&list_of_string, &env, abbreviated).to_string(),
+        "List<...>"
+    );
+    assert_eq!(
+        project_type_display(&string, &env, abbreviated).to_string(),
+        "String"
+    );
+
+    let depth_one = TypeFormatOptions {
+        use_simple_names: true,
+        max_depth: Some(1),
+        ..TypeFormatOptions::default()
+    };
+    assert_eq!(
+        project_type_display(&nested, &env, depth_one).to_string(),
+        "List<...>"
+    );
+    let depth_two = TypeFormatOptions {
+        max_depth: Some(2),
+        ..depth_one
+    };
+    assert_eq!(
+        project_type_display(&nested, &env, depth_two).to_string(),
+        "List<List<...>>"
+    );
+}
+
+#[test]
+fn signature_param_labels_index_into_rendered_signature() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let string = env.well_known().string;
+
+    let owner = env.add_class(ClassDef {
+        name: "com.example.Text".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(Type::class(object, vec![])),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![MethodDef {
+            name: "pad".to_string(),
+            type_params: vec![],
+            params: vec![
+                Type::class(string, vec![]),
+                Type::int(),
+                Type::Array(Box::new(Type::class(object, vec![]))),
+            ],
+            param_names: vec!["pattern".into(), "width".into(), "args".into()],
+            return_type: Type::class(string, vec![]),
+            is_static: true,
+            is_varargs: true,
+            is_abstract: false,
+            is_private: false,
+            deprecation: None,
+        }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+
+    // Variable-arity call: four arguments against three declared parameters.
+    let call = MethodCall {
+        receiver: Type::class(owner, vec![]),
+        call_kind: CallKind::Static,
+        name: "pad",
+        args: vec![
+            Type::class(string, vec![]),
+            Type::int(),
+            Type::class(string, vec![]),
+            Type::class(string, vec![]),
+        ],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = nova_types::java::env::TyContext::new(&env);
+    let MethodResolution::Found(resolved) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(resolved.params.len(), 4);
+
+    let label = resolved.effective_signature_display(&env);
+    assert_eq!(
+        label.text,
+        "String pad(String pattern, int width, Object... args)"
+    );
+    let params: Vec<&str> = label.params.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(params, ["String pattern", "int width", "Object... args"]);
+    for param in &label.params {
+        let span = param.span_in_signature;
+        assert_eq!(&label.text[span.start..span.end], param.text);
+    }
+    assert_eq!(label.params[1].span_in_signature, Span::new(27, 36));
+
+    // Without names, labels cover just the types.
+    let unnamed = ResolvedMethod {
+        param_names: vec![],
+        ..resolved
+    };
+    let label = unnamed.effective_signature_display(&env);
+    assert_eq!(label.text, format_resolved_method(&env, &unnamed));
+    let spans: Vec<Span> = label.params.iter().map(|p| p.span_in_signature).collect();
+    assert_eq!(
+        spans,
+        [Span::new(11, 17), Span::new(19, 22), Span::new(24, 33)]
+    );
+}
+
+#[test]
+fn formats_nullability_annotations() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let options = TypeFormatOptions {
+        use_simple_names: true,
+        ..TypeFormatOptions::default()
+    };
+
+    let nullable = AnnotatedType::new(string.clone(), Nullability::Nullable);
+    assert_eq!(
+        format_annotated_type(&env, &nullable, options),
+        "@Nullable String"
+    );
+
+    let strings = Type::Array(Box::new(Type::Array(Box::new(string.clone()))));
+    let non_null_elements = AnnotatedType {
+        ty: strings.clone(),
+        nullability: Nullability::Unknown,
+        element_nullability: Nullability::NonNull,
+    };
+    assert_eq!(
+        format_annotated_type(&env, &non_null_elements, options),
+        "@NonNull String[][]"
+    );
+
+    let non_null_array = AnnotatedType::new(strings, Nullability::NonNull);
+    assert_eq!(
+        format_annotated_type(&env, &non_null_array, options),
+        "String @NonNull [][]"
+    );
+
+    let unannotated = AnnotatedType::new(string, Nullability::Unknown);
+    assert_eq!(
+        format_annotated_type(&env, &unannotated, TypeFormatOptions::default()),
+        "java.lang.String"
+    );
+}
@@ -1,8 +1,8 @@
 use nova_types::{
     assignment_conversion, assignment_conversion_with_const, binary_numeric_promotion,
-    cast_conversion, conversion_cost, method_invocation_conversion, unary_numeric_promotion,
-    ConstValue, ConversionCost, ConversionStep, PrimitiveType, Type, TypeEnv, TypeStore,
-    TypeWarning, UncheckedReason,
+    cast_conversion, conversion_cost, is_assignable, method_invocation_conversion,
+    unary_numeric_promotion, ConstValue, ConversionCost, ConversionStep, PrimitiveType, Type,
+    TypeEnv, TypeStore, TypeWarning, UncheckedReason,
 };
 
 use pretty_assertions::assert_eq;
@@ -75,6 +75,32 @@ fn unboxing_and_widening_primitive() {
     );
 }
 
+#[test]
+fn type_variables_unbox_through_their_bound() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let integer_ty = Type::class(env.well_known().integer, vec![]);
+    let t = Type::TypeVar(env.add_type_param("T", vec![integer_ty]));
+    let int_ty = Type::Primitive(PrimitiveType::Int);
+    let long_ty = Type::Primitive(PrimitiveType::Long);
+
+    assert!(is_assignable(&env, &t, &int_ty));
+    assert!(is_assignable(&env, &t, &long_ty));
+    assert!(!is_assignable(
+        &env,
+        &t,
+        &Type::Primitive(PrimitiveType::Short)
+    ));
+
+    assert_eq!(
+        assignment_conversion(&env, &t, &int_ty).unwrap().steps,
+        vec![ConversionStep::Unboxing]
+    );
+    assert_eq!(
+        assignment_conversion(&env, &t, &long_ty).unwrap().steps,
+        vec![ConversionStep::Unboxing, ConversionStep::WideningPrimitive]
+    );
+}
+
 #[test]
 fn raw_type_conversions_produce_unchecked_warning() {
     let env = TypeStore::with_minimal_jdk();
@@ -84,14 +110,14 @@ fn raw_type_conversions_produce_unchecked_warning() {
     let raw_list = Type::class(list_id, vec![]);
 
     let conv = assignment_conversion(&env, &raw_list, &list_string).unwrap();
-    assert!(conv
-        .warnings
-        .contains(&TypeWarning::Unchecked(UncheckedReason::RawConversion)));
+    assert!(conv.warnings.contains(&TypeWarning::Unchecked(
+        UncheckedReason::UncheckedAssignment
+    )));
 
     let conv2 = assignment_conversion(&env, &list_string, &raw_list).unwrap();
     assert!(conv2
         .warnings
-        .contains(&TypeWarning::Unchecked(UncheckedReason::RawConversion)));
+        .contains(&TypeWarning::Unchecked(UncheckedReason::RawTypeUsage)));
 }
 
 #[test]
@@ -144,7 +170,7 @@ fn parameterized_casts_are_unchecked() {
     let conv_raw = cast_conversion(&env, &raw_list, &list_string).unwrap();
     assert!(conv_raw
         .warnings
-        .contains(&TypeWarning::Unchecked(UncheckedReason::RawConversion)));
+        .contains(&TypeWarning::Unchecked(UncheckedReason::UncheckedCast)));
 }
 
 #[test]
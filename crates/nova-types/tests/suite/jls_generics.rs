@@ -40,6 +40,10 @@ fn instantiate_supertype_is_order_independent_for_type_var_and_intersection_boun
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // class A implements I<String>
@@ -52,6 +56,10 @@ fn instantiate_supertype_is_order_independent_for_type_var_and_intersection_boun
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // class B implements I<String>
@@ -64,6 +72,10 @@ fn instantiate_supertype_is_order_independent_for_type_var_and_intersection_boun
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Two type vars with identical bounds in opposite order.
@@ -131,6 +143,7 @@ fn capture_conversion_substitutes_self_referential_bounds() {
             name: "E".to_string(),
             upper_bounds: vec![Type::class(enum_like, vec![Type::TypeVar(e)])],
             lower_bound: None,
+            declared_by: None,
         },
     );
     env.define_class(
@@ -144,6 +157,10 @@ fn capture_conversion_substitutes_self_referential_bounds() {
             fields: vec![],
             constructors: vec![],
             methods: vec![],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         },
     );
 
@@ -185,6 +202,10 @@ fn capture_conversion_sorts_capture_upper_bounds() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     let foo2 = env.add_class(ClassDef {
         name: "com.example.Foo2".to_string(),
@@ -195,6 +216,10 @@ fn capture_conversion_sorts_capture_upper_bounds() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let mut ctx = TyContext::new(&env);
@@ -247,6 +272,10 @@ fn method_resolution_applies_capture_conversion_for_extends_wildcard() {
         args: vec![Type::int()],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -294,6 +323,10 @@ fn method_resolution_applies_capture_conversion_for_super_wildcard() {
         args: vec![Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx_ok = TyContext::new(&env);
     let MethodResolution::Found(resolved) = resolve_method_call(&mut ctx_ok, &call_ok) else {
@@ -317,6 +350,10 @@ fn method_resolution_applies_capture_conversion_for_super_wildcard() {
         args: vec![Type::class(object, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx_bad = TyContext::new(&env);
     assert!(matches!(
@@ -374,6 +411,129 @@ fn wildcard_type_argument_containment_super() {
     assert!(!is_subtype(&env, &list_super_string, &list_super_object));
 }
 
+#[test]
+fn nested_contravariant_wildcard_containment() {
+    let env = TypeStore::with_minimal_jdk();
+    let consumer = env.class_id("java.util.function.Consumer").unwrap();
+    let list = env.class_id("java.util.List").unwrap();
+    let class = |name: &str| Type::class(env.class_id(name).unwrap(), vec![]);
+    let super_of = |ty: Type| Type::Wildcard(WildcardBound::Super(Box::new(ty)));
+    let consumer_of = |arg: Type| Type::class(consumer, vec![arg]);
+
+    // Consumer<Object> -> Consumer<? super String>
+    let consumer_super_string = consumer_of(super_of(class("java.lang.String")));
+    assert!(is_assignable(
+        &env,
+        &consumer_of(class("java.lang.Object")),
+        &consumer_super_string
+    ));
+
+    // Consumer<? super Number> -> Consumer<? super Integer>, but not the other way around.
+    let consumer_super_number = consumer_of(super_of(class("java.lang.Number")));
+    let consumer_super_integer = consumer_of(super_of(class("java.lang.Integer")));
+    assert!(is_assignable(
+        &env,
+        &consumer_super_number,
+        &consumer_super_integer
+    ));
+    assert!(!is_assignable(
+        &env,
+        &consumer_super_integer,
+        &consumer_super_number
+    ));
+
+    // Consumer<? super List<?>> -> Consumer<? super List<String>>, since List<String> <: List<?>.
+    let list_of_string = Type::class(list, vec![class("java.lang.String")]);
+    let list_of_any = Type::class(list, vec![Type::Wildcard(WildcardBound::Unbounded)]);
+    let consumer_super_list_of_any = consumer_of(super_of(list_of_any));
+    let consumer_super_list_of_string = consumer_of(super_of(list_of_string));
+    assert!(is_assignable(
+        &env,
+        &consumer_super_list_of_any,
+        &consumer_super_list_of_string
+    ));
+    assert!(!is_assignable(
+        &env,
+        &consumer_super_list_of_string,
+        &consumer_super_list_of_any
+    ));
+}
+
+#[test]
+fn super_wildcard_is_contained_by_extends_object() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    let list_super_string = Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Super(Box::new(
+            string.clone(),
+        )))],
+    );
+    let list_extends = |upper: Type| {
+        Type::class(
+            list,
+            vec![Type::Wildcard(WildcardBound::Extends(Box::new(upper)))],
+        )
+    };
+
+    assert!(is_subtype(&env, &list_super_string, &list_extends(object)));
+    assert!(!is_subtype(&env, &list_super_string, &list_extends(string)));
+}
+
+#[test]
+fn bare_wildcards_compare_like_type_arguments() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let extends_string = Type::Wildcard(WildcardBound::Extends(Box::new(string.clone())));
+    let super_string = Type::Wildcard(WildcardBound::Super(Box::new(string.clone())));
+
+    // As a source, a wildcard stands for its upper bound.
+    assert!(is_subtype(&env, &extends_string, &string));
+    assert!(is_subtype(&env, &super_string, &object));
+    assert!(!is_subtype(&env, &super_string, &string));
+
+    // As a target, it contains what it would contain as a type argument.
+    assert!(is_subtype(&env, &string, &extends_string));
+    assert!(is_subtype(&env, &object, &super_string));
+    assert!(!is_subtype(&env, &object, &extends_string));
+    assert!(is_subtype(
+        &env,
+        &extends_string,
+        &Type::Wildcard(WildcardBound::Unbounded)
+    ));
+    assert!(!is_subtype(&env, &extends_string, &super_string));
+}
+
+#[test]
+fn context_env_sees_capture_variables() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let list_extends_string = Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+            string.clone(),
+        )))],
+    );
+
+    let mut ctx = TyContext::new(&env);
+    let Type::Class(ClassType { args, .. }) = ctx.capture_conversion(&list_extends_string) else {
+        panic!("expected a captured class type");
+    };
+    let captured = &args[0];
+    assert!(matches!(captured, Type::TypeVar(_)), "{captured:?}");
+
+    // The capture variable only exists in the context, so helpers need `ctx.env()`.
+    assert!(is_subtype(ctx.env(), captured, &string));
+    assert!(is_subtype(ctx.env(), captured, &object));
+    assert!(!is_subtype(&env, captured, &string));
+}
+
 #[test]
 fn generic_subtyping_remains_invariant_without_wildcards() {
     let env = TypeStore::with_minimal_jdk();
@@ -407,6 +567,10 @@ fn method_resolution_is_deterministic_across_invocations() {
         args: vec![Type::int()],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx1 = TyContext::new(&env);
@@ -441,6 +605,10 @@ fn method_resolution_is_order_independent() {
         args: vec![Type::int()],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let call_integer = MethodCall {
@@ -455,6 +623,10 @@ fn method_resolution_is_order_independent() {
         args: vec![Type::int()],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     // Resolve string-then-integer.
@@ -499,11 +671,18 @@ fn method_resolution_prefers_class_bound_over_interface_bound_for_type_var_recei
             name: "foo".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::class(object, vec![]),
             is_static: false,
             is_varargs: false,
             is_abstract: true,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let class = env.add_class(ClassDef {
@@ -518,11 +697,18 @@ fn method_resolution_prefers_class_bound_over_interface_bound_for_type_var_recei
             name: "foo".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::class(string, vec![]),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Intentionally put the interface bound first (even though Java source syntax requires the
@@ -539,6 +725,10 @@ fn method_resolution_prefers_class_bound_over_interface_bound_for_type_var_recei
         args: vec![],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -566,11 +756,18 @@ fn method_resolution_type_var_receiver_keeps_non_errorish_bounds_when_unknown_pr
             name: "foo".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::class(string, vec![]),
             is_static: false,
             is_varargs: false,
             is_abstract: true,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // If receiver normalization prunes via `is_subtype` (where `Unknown` is treated as compatible
@@ -589,6 +786,10 @@ fn method_resolution_type_var_receiver_keeps_non_errorish_bounds_when_unknown_pr
             args: vec![],
             expected_return: None,
             explicit_type_args: vec![],
+            allow_partial_type_args: false,
+            start_above: None,
+            caller_context: None,
+            arg_shapes: vec![],
         };
 
         let mut ctx = TyContext::new(&env);
@@ -617,9 +818,14 @@ fn field_resolution_prefers_class_bound_over_interface_bound_for_type_var_receiv
             ty: Type::class(object, vec![]),
             is_static: true,
             is_final: true,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let class = env.add_class(ClassDef {
@@ -633,9 +839,14 @@ fn field_resolution_prefers_class_bound_over_interface_bound_for_type_var_receiv
             ty: Type::class(string, vec![]),
             is_static: false,
             is_final: false,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Intentionally put the interface bound first.
@@ -673,9 +884,14 @@ fn field_resolution_applies_capture_conversion_for_extends_wildcard() {
             ty: Type::TypeVar(t),
             is_static: false,
             is_final: false,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let receiver = Type::class(
@@ -724,9 +940,14 @@ fn field_resolution_applies_capture_conversion_for_super_wildcard() {
             ty: Type::TypeVar(t),
             is_static: false,
             is_final: false,
+            deprecation: None,
         }],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let receiver = Type::class(
@@ -1,6 +1,7 @@
 use nova_types::{
-    resolve_method_call, ClassDef, ClassKind, MethodCall, MethodDef, MethodResolution, TyContext,
-    Type, TypeEnv, TypeStore,
+    infer_type_arguments_bidirectional, resolve_method_call, ClassDef, ClassKind, MethodCall,
+    MethodDef, MethodResolution, TyContext, Type, TypeEnv, TypeStore, TypeSubstitution,
+    WildcardBound,
 };
 
 use pretty_assertions::assert_eq;
@@ -24,11 +25,18 @@ fn infer_simple_identity() {
             name: "id".to_string(),
             type_params: vec![t],
             params: vec![Type::TypeVar(t)],
+            param_names: vec![],
             return_type: Type::TypeVar(t),
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -38,6 +46,10 @@ fn infer_simple_identity() {
         args: vec![Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -68,11 +80,18 @@ fn infer_from_return_context() {
             name: "empty".to_string(),
             type_params: vec![t],
             params: vec![],
+            param_names: vec![],
             return_type: Type::class(list, vec![Type::TypeVar(t)]),
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let expected = Type::class(list, vec![Type::class(string, vec![])]);
@@ -83,6 +102,10 @@ fn infer_from_return_context() {
         args: vec![],
         expected_return: Some(expected.clone()),
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -112,11 +135,18 @@ fn inferred_type_respects_bounds() {
             name: "m".to_string(),
             type_params: vec![n],
             params: vec![Type::TypeVar(n)],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -126,6 +156,10 @@ fn inferred_type_respects_bounds() {
         args: vec![Type::class(integer, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -156,11 +190,18 @@ fn infer_from_argument_via_generic_supertype() {
             name: "m".to_string(),
             type_params: vec![t],
             params: vec![Type::class(list, vec![Type::TypeVar(t)])],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -170,6 +211,10 @@ fn infer_from_argument_via_generic_supertype() {
         args: vec![Type::class(array_list, vec![Type::class(string, vec![])])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -200,11 +245,18 @@ fn infer_from_return_context_via_generic_supertype() {
             name: "empty".to_string(),
             type_params: vec![t],
             params: vec![],
+            param_names: vec![],
             return_type: Type::class(array_list, vec![Type::TypeVar(t)]),
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let expected = Type::class(list, vec![Type::class(string, vec![])]);
@@ -215,6 +267,10 @@ fn infer_from_return_context_via_generic_supertype() {
         args: vec![],
         expected_return: Some(expected.clone()),
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -228,3 +284,205 @@ fn infer_from_return_context_via_generic_supertype() {
     );
     assert!(nova_types::is_assignable(&env, &res.return_type, &expected));
 }
+
+#[test]
+fn partial_explicit_type_args_pin_leading_params() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let string = env.well_known().string;
+    let integer = env.well_known().integer;
+
+    // static <A, B> B pair(A first, B second)
+    let a = env.add_type_param("A", vec![Type::class(object, vec![])]);
+    let b = env.add_type_param("B", vec![Type::class(object, vec![])]);
+    let test = env.add_class(ClassDef {
+        name: "com.example.Pairs".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(Type::class(object, vec![])),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![MethodDef {
+            name: "pair".to_string(),
+            type_params: vec![a, b],
+            params: vec![Type::TypeVar(a), Type::TypeVar(b)],
+            param_names: vec![],
+            return_type: Type::TypeVar(b),
+            is_static: true,
+            is_varargs: false,
+            is_abstract: false,
+            is_private: false,
+            deprecation: None,
+        }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+
+    // `Pairs.<Object>pair("x", 1)`, as seen while typing inside the `<...>`.
+    let call = MethodCall {
+        receiver: Type::class(test, vec![]),
+        call_kind: nova_types::CallKind::Static,
+        name: "pair",
+        args: vec![Type::class(string, vec![]), Type::class(integer, vec![])],
+        expected_return: None,
+        explicit_type_args: vec![Type::class(object, vec![])],
+        allow_partial_type_args: true,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(res) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected partial type args to resolve");
+    };
+    assert_eq!(
+        res.inferred_type_args,
+        vec![Type::class(object, vec![]), Type::class(integer, vec![])]
+    );
+    assert_eq!(res.return_type, Type::class(integer, vec![]));
+
+    // Strict resolution still rejects the partial list.
+    let strict = MethodCall {
+        allow_partial_type_args: false,
+        start_above: None,
+        ..call
+    };
+    let MethodResolution::NotFound(not_found) = resolve_method_call(&mut ctx, &strict) else {
+        panic!("expected strict resolution to fail");
+    };
+    assert!(not_found.candidates[0]
+        .failures
+        .iter()
+        .all(|failure| matches!(
+            failure.reason,
+            nova_types::MethodCandidateFailureReason::ExplicitTypeArgCountMismatch {
+                expected: 2,
+                found: 1
+            }
+        )));
+}
+
+fn static_call<'a>(receiver: Type, name: &'a str, args: Vec<Type>) -> MethodCall<'a> {
+    MethodCall {
+        receiver,
+        call_kind: nova_types::CallKind::Static,
+        name,
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    }
+}
+
+#[test]
+fn bidirectional_inference_reports_bound_conflicts() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // <T extends Integer> void m(T t)
+    let t = env.add_type_param("T", vec![integer.clone()]);
+    let method = MethodDef {
+        name: "m".to_string(),
+        type_params: vec![t],
+        params: vec![Type::TypeVar(t)],
+        param_names: vec![],
+        return_type: Type::Void,
+        is_static: true,
+        is_varargs: false,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    };
+
+    let call = static_call(Type::class(object, vec![]), "m", vec![string.clone()]);
+    let result =
+        infer_type_arguments_bidirectional(&env, &call, &method, &TypeSubstitution::empty());
+    assert_eq!(result.inferred, vec![integer.clone()]);
+    assert_eq!(result.unsolved, vec![]);
+    assert_eq!(result.conflicts, vec![(t, string, integer)]);
+}
+
+#[test]
+fn bidirectional_inference_reports_unsolved_variables() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+
+    // <T, R> R convert(T value)
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let r = env.add_type_param("R", vec![object.clone()]);
+    let convert = MethodDef {
+        name: "convert".to_string(),
+        type_params: vec![t, r],
+        params: vec![Type::TypeVar(t)],
+        param_names: vec![],
+        return_type: Type::TypeVar(r),
+        is_static: true,
+        is_varargs: false,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    };
+
+    let mut call = static_call(object.clone(), "convert", vec![string.clone()]);
+    let result =
+        infer_type_arguments_bidirectional(&env, &call, &convert, &TypeSubstitution::empty());
+    assert_eq!(result.inferred, vec![string.clone(), object.clone()]);
+    assert_eq!(result.unsolved, vec![r]);
+    assert_eq!(result.conflicts, vec![]);
+
+    // The expected return type solves `R`.
+    let list_of_string = Type::class(list, vec![string.clone()]);
+    call.expected_return = Some(list_of_string.clone());
+    let result =
+        infer_type_arguments_bidirectional(&env, &call, &convert, &TypeSubstitution::empty());
+    assert_eq!(result.inferred, vec![string, list_of_string]);
+    assert_eq!(result.unsolved, vec![]);
+    assert_eq!(result.conflicts, vec![]);
+}
+
+#[test]
+fn singleton_list_into_wildcard_target_infers_the_argument_type() {
+    // List<? extends Number> xs = Collections.singletonList(someInteger);
+    let env = TypeStore::with_minimal_jdk();
+    let collections = env.class_id("java.util.Collections").unwrap();
+    let list = env.class_id("java.util.List").unwrap();
+    let number = env.class_id("java.lang.Number").unwrap();
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let expected = Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+            Type::class(number, vec![]),
+        )))],
+    );
+    let call = MethodCall {
+        receiver: Type::class(collections, vec![]),
+        call_kind: nova_types::CallKind::Static,
+        name: "singletonList",
+        args: vec![integer.clone()],
+        expected_return: Some(expected),
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(res) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(res.inferred_type_args, vec![integer.clone()]);
+    assert_eq!(res.return_type, Type::class(list, vec![integer]));
+}
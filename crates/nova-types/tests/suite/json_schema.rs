@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use nova_types::{
+    json_schemas, CallKind, ClassDef, CompletionItem, Diagnostic, MethodCall, MethodDef,
+    MethodNotFound, MethodResolution, TyContext, Type, TypeEnv, TypeStore, JSON_SCHEMA_VERSION,
+};
+use schemars::schema_for;
+use serde_json::{json, Value};
+
+fn schema_json<T: schemars::JsonSchema>() -> Value {
+    let schema = schema_for!(T);
+    let text = serde_json::to_string(&schema).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+#[test]
+fn type_schema_lists_every_variant() {
+    let schema = schema_json::<Type>();
+    let text = schema.to_string();
+    for variant in [
+        "Void",
+        "Primitive",
+        "Class",
+        "Array",
+        "TypeVar",
+        "Wildcard",
+        "Intersection",
+        "Null",
+        "Named",
+        "VirtualInner",
+        "Unknown",
+        "Error",
+    ] {
+        assert!(
+            text.contains(&format!("\"{variant}\"")),
+            "missing {variant}"
+        );
+    }
+
+    // The boxed and nested types are described once, as definitions.
+    let definitions = schema["definitions"].as_object().unwrap();
+    for name in ["ClassType", "WildcardBound", "PrimitiveType", "TypeVarId"] {
+        assert!(definitions.contains_key(name), "missing definition {name}");
+    }
+    assert!(definitions["WildcardBound"]
+        .to_string()
+        .contains("\"Extends\""));
+}
+
+#[test]
+fn class_ids_are_described_as_integers() {
+    let schema = schema_json::<ClassDef>();
+    assert_eq!(schema["properties"]["enclosing"]["format"], "uint32");
+
+    let definitions = schema["definitions"].as_object().unwrap();
+    for name in [
+        "Type",
+        "MethodDef",
+        "FieldDef",
+        "ConstructorDef",
+        "ClassKind",
+    ] {
+        assert!(definitions.contains_key(name), "missing definition {name}");
+    }
+    assert!(!definitions.contains_key("ClassId"));
+}
+
+#[test]
+fn method_and_ide_types_have_schemas() {
+    let method = schema_json::<MethodDef>();
+    assert_eq!(method["title"], "MethodDef");
+    assert!(method["properties"]["return_type"].is_object());
+
+    let diagnostic = schema_json::<Diagnostic>();
+    assert!(diagnostic["definitions"]["Severity"]
+        .to_string()
+        .contains("\"Hint\""));
+    assert_eq!(diagnostic["properties"]["code"]["type"], "string");
+
+    let completion = schema_json::<CompletionItem>();
+    assert!(completion["definitions"]
+        .as_object()
+        .unwrap()
+        .contains_key("Span"));
+}
+
+/// The checked-in schema bundle. Regenerate it with `BLESS=1` after an intentional change to the
+/// wire format, and bump `JSON_SCHEMA_VERSION` if the change can break existing consumers.
+const SCHEMA_SNAPSHOT: &str = "tests/snapshots/json_schemas.json";
+
+#[test]
+fn json_schema_bundle_matches_snapshot() {
+    let bundle = json_schemas();
+    assert_eq!(bundle["version"], JSON_SCHEMA_VERSION);
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(SCHEMA_SNAPSHOT);
+    if std::env::var_os("BLESS").is_some() {
+        let text = serde_json::to_string_pretty(&bundle).unwrap() + "\n";
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, text).unwrap();
+        return;
+    }
+    let snapshot = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "failed to read {} (run with `BLESS=1` to write it): {err}",
+            path.display()
+        )
+    });
+    let snapshot: Value = serde_json::from_str(&snapshot).unwrap();
+    assert!(
+        bundle == snapshot,
+        "the JSON schemas changed; if intended, run with `BLESS=1` to update {SCHEMA_SNAPSHOT}"
+    );
+}
+
+#[test]
+fn portable_resolution_names_every_class() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.lookup_class("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    let mut ctx = TyContext::new(&env);
+    let call = MethodCall {
+        receiver: Type::class(list, vec![string.clone()]),
+        call_kind: CallKind::Instance,
+        name: "get",
+        args: vec![Type::int()],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let MethodResolution::Found(method) = nova_types::resolve_method_call(&mut ctx, &call) else {
+        panic!("expected List.get to resolve");
+    };
+
+    let portable = serde_json::to_value(method.to_portable(&env)).unwrap();
+    assert_eq!(
+        portable["owner"],
+        json!({ "id": list.to_raw(), "name": "java.util.List" })
+    );
+    assert_eq!(
+        portable["return_type"],
+        json!({ "Class": {
+            "class": { "id": env.well_known().string.to_raw(), "name": "java.lang.String" },
+            "args": [],
+        } })
+    );
+    assert_eq!(portable["params"], json!([{ "Primitive": "Int" }]));
+
+    let not_found = MethodResolution::NotFound(MethodNotFound {
+        receiver: string,
+        name: "nope".to_string(),
+        args: vec![],
+        candidates: vec![],
+    });
+    let portable = serde_json::to_value(not_found.to_portable(&env)).unwrap();
+    assert_eq!(
+        portable["NotFound"]["receiver"]["Class"]["class"]["name"],
+        "java.lang.String"
+    );
+}
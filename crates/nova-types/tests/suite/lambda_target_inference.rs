@@ -0,0 +1,84 @@
+use nova_types::{infer_lambda_target_from_context, PrimitiveType, Type, TypeEnv, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn zero_parameter_void_lambda_targets_runnable() {
+    let env = TypeStore::with_minimal_jdk();
+    let runnable = env.lookup_class("java.lang.Runnable").unwrap();
+
+    assert_eq!(
+        infer_lambda_target_from_context(&env, None, &[], &Type::Void),
+        Some(Type::class(runnable, vec![]))
+    );
+}
+
+#[test]
+fn zero_parameter_value_lambda_targets_supplier() {
+    let env = TypeStore::with_minimal_jdk();
+    let supplier = env.lookup_class("java.util.function.Supplier").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    assert_eq!(
+        infer_lambda_target_from_context(&env, None, &[], &string),
+        Some(Type::class(supplier, vec![string]))
+    );
+    // Primitive bodies instantiate the interface with the boxed type.
+    assert_eq!(
+        infer_lambda_target_from_context(&env, None, &[], &Type::Primitive(PrimitiveType::Int)),
+        Some(Type::class(supplier, vec![integer]))
+    );
+}
+
+#[test]
+fn functional_expected_type_wins() {
+    let env = TypeStore::with_minimal_jdk();
+    let function = env.lookup_class("java.util.function.Function").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let expected = Type::class(function, vec![string.clone(), string.clone()]);
+
+    assert_eq!(
+        infer_lambda_target_from_context(
+            &env,
+            Some(&expected),
+            std::slice::from_ref(&string),
+            &string
+        ),
+        Some(expected)
+    );
+    // A non-functional expected type falls back to the search.
+    let consumer = env.lookup_class("java.util.function.Consumer").unwrap();
+    assert_eq!(
+        infer_lambda_target_from_context(
+            &env,
+            Some(&string),
+            std::slice::from_ref(&string),
+            &Type::Void
+        ),
+        Some(Type::class(consumer, vec![string]))
+    );
+}
+
+#[test]
+fn several_matching_interfaces_are_ambiguous() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let boolean = Type::Primitive(PrimitiveType::Boolean);
+
+    // `(String s) -> s.isEmpty()` fits both `Function<String, Boolean>` and `Predicate<String>`.
+    assert_eq!(
+        infer_lambda_target_from_context(&env, None, std::slice::from_ref(&string), &boolean),
+        None
+    );
+    // Nothing takes three parameters.
+    assert_eq!(
+        infer_lambda_target_from_context(
+            &env,
+            None,
+            &[string.clone(), string.clone(), string],
+            &Type::Void
+        ),
+        None
+    );
+}
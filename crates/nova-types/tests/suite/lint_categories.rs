@@ -0,0 +1,227 @@
+//! Conformance of [`TypeWarning`]s with the javac `-Xlint` category that reports them.
+
+use nova_types::{
+    assignment_conversion, cast_conversion, infer_enhanced_for_type_with_warnings, lint_category,
+    method_invocation_conversion, resolve_method_call, CallKind, ClassDef, LintCategory,
+    MethodCall, MethodDef, MethodResolution, TyContext, Type, TypeEnv, TypeStore, TypeWarning,
+    UncheckedReason,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+struct Fixture {
+    env: TypeStore,
+    raw_list: Type,
+    list_string: Type,
+    object: Type,
+}
+
+fn fixture() -> Fixture {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    Fixture {
+        raw_list: Type::class(list, vec![]),
+        list_string: Type::class(list, vec![string]),
+        object,
+        env,
+    }
+}
+
+fn categories(warnings: &[TypeWarning]) -> Vec<&'static str> {
+    warnings.iter().map(|w| lint_category(w).as_str()).collect()
+}
+
+fn static_method(name: &str, params: Vec<Type>, is_varargs: bool) -> MethodDef {
+    MethodDef {
+        is_static: true,
+        is_varargs,
+        ..support::method(name, params, Type::Void)
+    }
+}
+
+#[test]
+fn raw_to_parameterized_assignment_is_unchecked() {
+    // `List<String> xs = raw;`
+    let f = fixture();
+    let conv = assignment_conversion(&f.env, &f.raw_list, &f.list_string).unwrap();
+    assert_eq!(
+        conv.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::UncheckedAssignment)]
+    );
+    assert_eq!(categories(&conv.warnings), vec!["unchecked"]);
+}
+
+#[test]
+fn raw_subclass_to_parameterized_assignment_is_unchecked() {
+    // `List<String> xs = new ArrayList();`
+    let f = fixture();
+    let array_list = f.env.class_id("java.util.ArrayList").unwrap();
+    let conv =
+        assignment_conversion(&f.env, &Type::class(array_list, vec![]), &f.list_string).unwrap();
+    assert_eq!(categories(&conv.warnings), vec!["unchecked"]);
+}
+
+#[test]
+fn parameterized_to_raw_assignment_is_rawtypes() {
+    // `List raw = xs;`
+    let f = fixture();
+    let conv = assignment_conversion(&f.env, &f.list_string, &f.raw_list).unwrap();
+    assert_eq!(
+        conv.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::RawTypeUsage)]
+    );
+    assert_eq!(categories(&conv.warnings), vec!["rawtypes"]);
+}
+
+#[test]
+fn raw_argument_to_parameterized_parameter_is_unchecked_invocation() {
+    // `take(raw)` where `static void take(List<String> xs)`.
+    let mut f = fixture();
+    let conv = method_invocation_conversion(&f.env, &f.raw_list, &f.list_string).unwrap();
+    assert_eq!(
+        conv.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::UncheckedInvocation)]
+    );
+
+    let object = f.env.well_known().object;
+    let util = f.env.add_class(ClassDef {
+        methods: vec![static_method("take", vec![f.list_string.clone()], false)],
+        ..support::class("com.example.Lint", Some(Type::class(object, vec![])))
+    });
+    let call = MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: CallKind::Static,
+        name: "take",
+        args: vec![f.raw_list.clone()],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&f.env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected take(raw) to resolve");
+    };
+    assert!(found.warnings.contains(&TypeWarning::Unchecked(
+        UncheckedReason::UncheckedInvocation
+    )));
+    assert_eq!(categories(&found.warnings), vec!["unchecked"]);
+}
+
+#[test]
+fn cast_from_object_to_parameterized_is_unchecked() {
+    // `(List<String>) obj`
+    let f = fixture();
+    let conv = cast_conversion(&f.env, &f.object, &f.list_string).unwrap();
+    assert_eq!(
+        conv.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::UncheckedCast)]
+    );
+    assert_eq!(categories(&conv.warnings), vec!["unchecked"]);
+}
+
+#[test]
+fn cast_from_raw_to_parameterized_is_unchecked() {
+    // `(List<String>) raw`
+    let f = fixture();
+    let conv = cast_conversion(&f.env, &f.raw_list, &f.list_string).unwrap();
+    assert_eq!(
+        conv.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::UncheckedCast)]
+    );
+}
+
+#[test]
+fn cast_from_parameterized_to_raw_is_rawtypes() {
+    // `(List) xs`
+    let f = fixture();
+    let conv = cast_conversion(&f.env, &f.list_string, &f.raw_list).unwrap();
+    assert_eq!(categories(&conv.warnings), vec!["rawtypes"]);
+}
+
+#[test]
+fn iterating_a_raw_iterable_is_rawtypes() {
+    // `for (Object o : raw)`
+    let f = fixture();
+    let (_, warnings) = infer_enhanced_for_type_with_warnings(&f.env, Some(&f.object), &f.raw_list);
+    assert_eq!(categories(&warnings), vec!["rawtypes"]);
+}
+
+#[test]
+fn generic_varargs_array_creation_is_unchecked() {
+    // `<T> void m(T... xs)` called as `m("a", "b")`.
+    let mut f = fixture();
+    let object = f.env.well_known().object;
+    let string = Type::class(f.env.well_known().string, vec![]);
+    let t = f.env.add_type_param("T", vec![]);
+    let util = f.env.add_class(ClassDef {
+        methods: vec![MethodDef {
+            type_params: vec![t],
+            ..static_method("m", vec![Type::Array(Box::new(Type::TypeVar(t)))], true)
+        }],
+        ..support::class("com.example.LintVarargs", Some(Type::class(object, vec![])))
+    });
+    let call = MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: CallKind::Static,
+        name: "m",
+        args: vec![string.clone(), string],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&f.env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected m(\"a\", \"b\") to resolve");
+    };
+    assert_eq!(
+        found.warnings,
+        vec![TypeWarning::Unchecked(UncheckedReason::UncheckedVarargs)]
+    );
+    assert_eq!(categories(&found.warnings), vec!["unchecked"]);
+}
+
+#[test]
+fn static_access_via_instance_is_static() {
+    // `obj.m()` where `static void m()`.
+    let mut f = fixture();
+    let object = f.env.well_known().object;
+    let util = f.env.add_class(ClassDef {
+        methods: vec![static_method("m", vec![], false)],
+        ..support::class("com.example.LintStatic", Some(Type::class(object, vec![])))
+    });
+    let call = MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: CallKind::Instance,
+        name: "m",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&f.env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected obj.m() to resolve");
+    };
+    assert_eq!(categories(&found.warnings), vec!["static"]);
+}
+
+#[test]
+fn legacy_raw_conversion_reason_maps_to_unchecked() {
+    assert_eq!(
+        lint_category(&TypeWarning::Unchecked(UncheckedReason::RawConversion)),
+        LintCategory::Unchecked
+    );
+}
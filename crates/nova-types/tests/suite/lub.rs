@@ -1,10 +1,12 @@
 use nova_types::{
-    lub, resolve_method_call, CallKind, ClassDef, ClassKind, MethodCall, MethodDef,
-    MethodResolution, TyContext, Type, TypeEnv, TypeStore, WildcardBound,
+    is_subtype, lub, resolve_method_call, CallKind, ClassDef, ClassId, ClassKind, MethodCall,
+    MethodDef, MethodResolution, TyContext, Type, TypeEnv, TypeStore, TypeVarId, WildcardBound,
 };
 
 use pretty_assertions::assert_eq;
 
+use super::support;
+
 #[test]
 fn lub_string_integer_is_object() {
     let env = TypeStore::with_minimal_jdk();
@@ -130,11 +132,18 @@ fn inference_uses_lub_for_generic_instances() {
             name: "pick".to_string(),
             type_params: vec![t],
             params: vec![Type::TypeVar(t), Type::TypeVar(t)],
+            param_names: vec![],
             return_type: Type::TypeVar(t),
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let list_string = Type::class(list, vec![Type::class(env.well_known().string, vec![])]);
@@ -148,6 +157,10 @@ fn inference_uses_lub_for_generic_instances() {
         args: vec![list_string, list_integer],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -158,3 +171,74 @@ fn inference_uses_lub_for_generic_instances() {
     assert_eq!(res.inferred_type_args, vec![expected_t.clone()]);
     assert_eq!(res.return_type, expected_t);
 }
+
+fn class_def(
+    name: &str,
+    kind: ClassKind,
+    type_params: Vec<TypeVarId>,
+    object: ClassId,
+) -> ClassDef {
+    ClassDef {
+        kind,
+        type_params,
+        ..support::class(name, Some(Type::class(object, vec![])))
+    }
+}
+
+#[test]
+fn lub_of_f_bounded_classes_gives_up_on_the_innermost_argument() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+
+    // `interface Cmp<T>`, `class A implements Cmp<A>`, `class B implements Cmp<B>`: the lub is
+    // the infinite `Cmp<? extends Cmp<? extends ...>>`.
+    let t = env.add_type_param("T", vec![Type::class(object, vec![])]);
+    let cmp = env.add_class(class_def(
+        "com.example.Cmp",
+        ClassKind::Interface,
+        vec![t],
+        object,
+    ));
+    let mut classes = Vec::new();
+    for name in ["com.example.A", "com.example.B"] {
+        let id = env.intern_class_id(name);
+        let mut def = class_def(name, ClassKind::Class, vec![], object);
+        def.interfaces = vec![Type::class(cmp, vec![Type::class(id, vec![])])];
+        env.define_class(id, def);
+        classes.push(Type::class(id, vec![]));
+    }
+
+    let mut expected = Type::Wildcard(WildcardBound::Unbounded);
+    for _ in 0..3 {
+        expected = Type::Wildcard(WildcardBound::Extends(Box::new(Type::class(
+            cmp,
+            vec![expected],
+        ))));
+    }
+    let expected = Type::class(cmp, vec![expected]);
+    assert_eq!(lub(&env, &classes[0], &classes[1]), expected);
+    assert!(is_subtype(&env, &classes[0], &expected));
+    assert!(is_subtype(&env, &classes[1], &expected));
+}
+
+#[test]
+fn lub_over_an_expansive_cyclic_hierarchy_terminates() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `class C<T> extends C<List<T>>`, as typed mid-edit.
+    let t = env.add_type_param("T", vec![Type::class(object, vec![])]);
+    let c = env.intern_class_id("com.example.C");
+    let mut def = class_def("com.example.C", ClassKind::Class, vec![t], object);
+    def.super_class = Some(Type::class(
+        c,
+        vec![Type::class(list, vec![Type::TypeVar(t)])],
+    ));
+    env.define_class(c, def);
+
+    let c_string = Type::class(c, vec![string.clone()]);
+    assert_eq!(lub(&env, &c_string, &string), Type::class(object, vec![]));
+    assert!(!is_subtype(&env, &c_string, &string));
+}
@@ -0,0 +1,169 @@
+use nova_types::{
+    render_member_declaration, resolve_field, resolve_method_call, CallKind, ClassDef, ClassId,
+    FieldDef, MethodCall, MethodDef, MethodResolution, ResolvedMember, ResolvedMethod, TyContext,
+    Type, TypeDisplayOptions, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+/// ```java
+/// class Box<T> {
+///     T value;
+///     T get(int index);
+///     <U extends T> U narrow(T value);
+///     static <E> E firstOf(E... items);
+///     abstract void clear();
+/// }
+/// ```
+fn box_class(env: &mut TypeStore) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![Type::TypeVar(t)]);
+    let e = env.add_type_param("E", vec![object.clone()]);
+    env.add_class(ClassDef {
+        type_params: vec![t],
+        fields: vec![FieldDef {
+            name: "value".to_string(),
+            ty: Type::TypeVar(t),
+            is_static: false,
+            is_final: false,
+            deprecation: None,
+        }],
+        methods: vec![
+            MethodDef {
+                param_names: vec!["index".to_string()],
+                ..support::method("get", vec![Type::int()], Type::TypeVar(t))
+            },
+            MethodDef {
+                type_params: vec![u],
+                param_names: vec!["value".to_string()],
+                ..support::method("narrow", vec![Type::TypeVar(t)], Type::TypeVar(u))
+            },
+            MethodDef {
+                type_params: vec![e],
+                param_names: vec!["items".to_string()],
+                is_static: true,
+                is_varargs: true,
+                ..support::method(
+                    "firstOf",
+                    vec![Type::Array(Box::new(Type::TypeVar(e)))],
+                    Type::TypeVar(e),
+                )
+            },
+            MethodDef {
+                is_abstract: true,
+                ..support::method("clear", vec![], Type::Void)
+            },
+        ],
+        ..support::class("com.example.Box", Some(object))
+    })
+}
+
+fn resolve(env: &TypeStore, receiver: &Type, name: &str, args: Vec<Type>) -> ResolvedMethod {
+    let call_kind = if name == "firstOf" {
+        CallKind::Static
+    } else {
+        CallKind::Instance
+    };
+    let call = MethodCall {
+        receiver: receiver.clone(),
+        call_kind,
+        name,
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    match resolve_method_call(&mut ctx, &call) {
+        MethodResolution::Found(method) => method,
+        other => panic!("expected {name} to resolve, got {other:?}"),
+    }
+}
+
+fn render(env: &TypeStore, receiver: &Type, method: &ResolvedMethod) -> String {
+    render_member_declaration(
+        env,
+        receiver,
+        ResolvedMember::Method(method),
+        &TypeDisplayOptions::default(),
+    )
+}
+
+#[test]
+fn generic_method_through_two_receivers() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let box_id = box_class(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let box_string = Type::class(box_id, vec![string.clone()]);
+    let get = resolve(&env, &box_string, "get", vec![Type::int()]);
+    assert_eq!(get.param_names, vec!["index".to_string()]);
+    assert_eq!(render(&env, &box_string, &get), "String get(int index)");
+    let narrow = resolve(&env, &box_string, "narrow", vec![string.clone()]);
+    assert_eq!(
+        render(&env, &box_string, &narrow),
+        "<U extends String> U narrow(String value)"
+    );
+
+    let box_integer = Type::class(box_id, vec![integer.clone()]);
+    let get = resolve(&env, &box_integer, "get", vec![Type::int()]);
+    assert_eq!(render(&env, &box_integer, &get), "Integer get(int index)");
+    let narrow = resolve(&env, &box_integer, "narrow", vec![integer]);
+    assert_eq!(
+        render(&env, &box_integer, &narrow),
+        "<U extends Integer> U narrow(Integer value)"
+    );
+}
+
+#[test]
+fn modifiers_varargs_and_unknown_names() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let box_id = box_class(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+    let receiver = Type::class(box_id, vec![string.clone()]);
+
+    let first_of = resolve(&env, &receiver, "firstOf", vec![string.clone(), string]);
+    assert_eq!(
+        render(&env, &receiver, &first_of),
+        "static <E> E firstOf(E... items)"
+    );
+
+    // Parameter names are omitted when unknown or disabled.
+    let clear = resolve(&env, &receiver, "clear", vec![]);
+    assert_eq!(render(&env, &receiver, &clear), "abstract void clear()");
+    let get = resolve(&env, &receiver, "get", vec![Type::int()]);
+    let opts = TypeDisplayOptions {
+        modifiers: false,
+        param_names: false,
+    };
+    assert_eq!(
+        render_member_declaration(&env, &receiver, ResolvedMember::Method(&get), &opts),
+        "String get(int)"
+    );
+}
+
+#[test]
+fn field_declaration() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let box_id = box_class(&mut env);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let receiver = Type::class(box_id, vec![integer]);
+
+    let field = resolve_field(&env, &receiver, "value", CallKind::Instance).unwrap();
+    assert_eq!(
+        render_member_declaration(
+            &env,
+            &receiver,
+            ResolvedMember::Field(&field),
+            &TypeDisplayOptions::default()
+        ),
+        "Integer value"
+    );
+}
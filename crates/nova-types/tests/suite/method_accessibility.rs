@@ -0,0 +1,122 @@
+//! `private` methods are only applicable from within their top-level class (JLS 6.6.1); a call
+//! that only matches an inaccessible method reports it as such.
+
+use nova_types::{
+    resolve_method_call, CallKind, ClassDef, ClassId, MethodCall, MethodCandidateFailureReason,
+    MethodDef, MethodResolution, TyContext, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn method(param: Type, is_private: bool) -> MethodDef {
+    MethodDef {
+        is_private,
+        ..support::method("m", vec![param], Type::Void)
+    }
+}
+
+fn class(env: &mut TypeStore, name: &str, methods: Vec<MethodDef>) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    env.add_class(ClassDef {
+        methods,
+        is_static_nested: true,
+        ..support::class(name, Some(object))
+    })
+}
+
+fn resolve(
+    env: &TypeStore,
+    receiver: ClassId,
+    arg: Type,
+    caller: Option<ClassId>,
+) -> MethodResolution {
+    let call = MethodCall {
+        receiver: Type::class(receiver, vec![]),
+        call_kind: CallKind::Instance,
+        name: "m",
+        args: vec![arg],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: caller,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    resolve_method_call(&mut ctx, &call)
+}
+
+#[test]
+fn private_method_is_accessible_within_its_top_level_class() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let foo = class(
+        &mut env,
+        "com.example.Foo",
+        vec![method(string.clone(), true)],
+    );
+    let nested = class(&mut env, "com.example.Foo$Nested", vec![]);
+
+    for caller in [Some(foo), Some(nested), None] {
+        let MethodResolution::Found(found) = resolve(&env, foo, string.clone(), caller) else {
+            panic!("expected `m` to resolve from {caller:?}");
+        };
+        assert_eq!(found.owner, foo);
+    }
+}
+
+#[test]
+fn private_method_called_from_another_class_is_inaccessible() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let foo = class(
+        &mut env,
+        "com.example.Foo",
+        vec![method(string.clone(), true)],
+    );
+    let bar = class(&mut env, "com.example.Bar", vec![]);
+
+    let MethodResolution::NotFound(not_found) = resolve(&env, foo, string, Some(bar)) else {
+        panic!("expected the private method to be rejected");
+    };
+    assert_eq!(not_found.candidates.len(), 1);
+    assert!(not_found.candidates[0]
+        .failures
+        .iter()
+        .all(|failure| failure.reason == MethodCandidateFailureReason::Inaccessible));
+
+    // A call that would not match anyway reports the mismatch instead.
+    let MethodResolution::NotFound(not_found) = resolve(&env, foo, integer, Some(bar)) else {
+        panic!("expected no applicable method");
+    };
+    assert!(matches!(
+        not_found.candidates[0].failures[0].reason,
+        MethodCandidateFailureReason::ArgumentConversion { .. }
+    ));
+}
+
+#[test]
+fn inaccessible_overloads_are_skipped() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let foo = class(
+        &mut env,
+        "com.example.Foo",
+        vec![method(string.clone(), true), method(object.clone(), false)],
+    );
+    let bar = class(&mut env, "com.example.Bar", vec![]);
+
+    let MethodResolution::Found(found) = resolve(&env, foo, string.clone(), Some(bar)) else {
+        panic!("expected `m(Object)` to resolve");
+    };
+    assert_eq!(found.params, vec![object]);
+
+    let MethodResolution::Found(found) = resolve(&env, foo, string.clone(), Some(foo)) else {
+        panic!("expected `m(String)` to resolve");
+    };
+    assert_eq!(found.params, vec![string]);
+}
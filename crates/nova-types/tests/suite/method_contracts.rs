@@ -0,0 +1,253 @@
+use nova_types::{
+    resolve_method_call, resolved_method_contract, well_known_contract, CallKind, ClassDef,
+    ClassId, MethodCall, MethodContract, MethodContractKey, MethodDef, MethodResolution,
+    NullBehavior, ResolvedMethod, TyContext, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn resolve(
+    env: &TypeStore,
+    receiver: Type,
+    call_kind: CallKind,
+    name: &str,
+    args: Vec<Type>,
+) -> ResolvedMethod {
+    let call = MethodCall {
+        receiver,
+        call_kind,
+        name,
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    match resolve_method_call(&mut ctx, &call) {
+        MethodResolution::Found(method) => method,
+        other => panic!("expected {name} to resolve, got {other:?}"),
+    }
+}
+
+fn class(env: &TypeStore, name: &str) -> ClassId {
+    env.class_id(name)
+        .unwrap_or_else(|| panic!("missing class {name}"))
+}
+
+#[test]
+fn require_non_null_overloads() {
+    let env = TypeStore::with_minimal_jdk();
+    let objects = Type::class(class(&env, "java.util.Objects"), vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let supplier = Type::class(
+        class(&env, "java.util.function.Supplier"),
+        vec![string.clone()],
+    );
+    let require_non_null = Some(MethodContract::new(
+        NullBehavior::RequireNonNull { param: 0 },
+        true,
+    ));
+
+    // `Objects.requireNonNull(s)`
+    let method = resolve(
+        &env,
+        objects.clone(),
+        CallKind::Static,
+        "requireNonNull",
+        vec![string.clone()],
+    );
+    assert_eq!(method.return_type, string);
+    assert_eq!(resolved_method_contract(&env, &method), require_non_null);
+
+    // `Objects.requireNonNull(s, "message")`
+    let method = resolve(
+        &env,
+        objects.clone(),
+        CallKind::Static,
+        "requireNonNull",
+        vec![string.clone(), string.clone()],
+    );
+    assert_eq!(method.params[1], string);
+    assert_eq!(resolved_method_contract(&env, &method), require_non_null);
+
+    // `Objects.requireNonNull(s, () -> "message")`
+    let method = resolve(
+        &env,
+        objects.clone(),
+        CallKind::Static,
+        "requireNonNull",
+        vec![string.clone(), supplier.clone()],
+    );
+    assert_eq!(method.params[1], supplier);
+    assert_eq!(resolved_method_contract(&env, &method), require_non_null);
+
+    let method = resolve(&env, objects, CallKind::Static, "isNull", vec![string]);
+    assert_eq!(
+        resolved_method_contract(&env, &method),
+        Some(MethodContract::new(
+            NullBehavior::IsNullCheck { param: 0 },
+            true
+        ))
+    );
+}
+
+#[test]
+fn optional_factories() {
+    let env = TypeStore::with_minimal_jdk();
+    let optional = class(&env, "java.util.Optional");
+    let string = Type::class(env.well_known().string, vec![]);
+    let receiver = Type::class(optional, vec![]);
+
+    let of = resolve(
+        &env,
+        receiver.clone(),
+        CallKind::Static,
+        "of",
+        vec![string.clone()],
+    );
+    assert_eq!(of.return_type, Type::class(optional, vec![string.clone()]));
+    assert_eq!(
+        resolved_method_contract(&env, &of).map(|c| c.null_behavior),
+        Some(NullBehavior::RejectsNullArg { param: 0 })
+    );
+
+    let of_nullable = resolve(
+        &env,
+        receiver.clone(),
+        CallKind::Static,
+        "ofNullable",
+        vec![Type::Null],
+    );
+    assert_eq!(
+        resolved_method_contract(&env, &of_nullable).map(|c| c.null_behavior),
+        Some(NullBehavior::AcceptsNullArg { param: 0 })
+    );
+
+    let empty = resolve(&env, receiver, CallKind::Static, "empty", vec![]);
+    assert_eq!(
+        resolved_method_contract(&env, &empty).map(|c| c.null_behavior),
+        Some(NullBehavior::NonNullReturn)
+    );
+
+    // Methods without a contract have none.
+    let get = resolve(
+        &env,
+        Type::class(optional, vec![string]),
+        CallKind::Instance,
+        "get",
+        vec![],
+    );
+    assert_eq!(resolved_method_contract(&env, &get), None);
+}
+
+#[test]
+fn map_get_through_inherited_and_overriding_declarations() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let map = class(&env, "java.util.Map");
+    let map_of = Type::class(map, vec![string.clone(), integer.clone()]);
+
+    // `abstract class Cache implements Map<String, Integer> {}`
+    let cache = env.add_class(ClassDef {
+        interfaces: vec![map_of.clone()],
+        ..support::class("com.example.Cache", Some(object.clone()))
+    });
+    // `class Registry implements Map<String, Integer> { Integer get(Object key); }`
+    let registry = env.add_class(ClassDef {
+        interfaces: vec![map_of],
+        methods: vec![MethodDef {
+            param_names: vec!["key".to_string()],
+            ..support::method("get", vec![object.clone()], integer.clone())
+        }],
+        ..support::class("com.example.Registry", Some(object.clone()))
+    });
+    let nullable = Some(MethodContract::new(NullBehavior::NullableReturn, true));
+
+    let get = resolve(
+        &env,
+        Type::class(cache, vec![]),
+        CallKind::Instance,
+        "get",
+        vec![string.clone()],
+    );
+    assert_eq!(get.owner, map);
+    assert_eq!(get.return_type, integer);
+    assert_eq!(resolved_method_contract(&env, &get), nullable);
+
+    let get = resolve(
+        &env,
+        Type::class(registry, vec![]),
+        CallKind::Instance,
+        "get",
+        vec![string.clone()],
+    );
+    assert_eq!(get.owner, registry);
+    assert_eq!(resolved_method_contract(&env, &get), nullable);
+
+    let get_or_default = resolve(
+        &env,
+        Type::class(cache, vec![]),
+        CallKind::Instance,
+        "getOrDefault",
+        vec![string.clone(), integer],
+    );
+    assert_eq!(resolved_method_contract(&env, &get_or_default), nullable);
+
+    // Lookup by declaration goes through supertypes; the erased descriptor must match.
+    assert_eq!(well_known_contract(&env, cache, "get", &[object]), nullable);
+    assert_eq!(well_known_contract(&env, cache, "get", &[string]), None);
+}
+
+#[test]
+fn registered_contracts_extend_and_override_builtins() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `abstract class Assert { static void notNull(Object object, String message); }`
+    let assert = env.add_class(ClassDef {
+        methods: vec![MethodDef {
+            param_names: vec!["object".to_string(), "message".to_string()],
+            is_static: true,
+            ..support::method("notNull", vec![object.clone(), string.clone()], Type::Void)
+        }],
+        ..support::class("org.springframework.util.Assert", Some(object.clone()))
+    });
+    let not_null = MethodContract::new(NullBehavior::RequireNonNull { param: 0 }, false);
+    env.register_method_contract(
+        MethodContractKey::new(
+            "org.springframework.util.Assert",
+            "notNull",
+            &["java.lang.Object", "java.lang.String"],
+        ),
+        not_null,
+    );
+
+    let method = resolve(
+        &env,
+        Type::class(assert, vec![]),
+        CallKind::Static,
+        "notNull",
+        vec![string.clone(), string.clone()],
+    );
+    assert_eq!(resolved_method_contract(&env, &method), Some(not_null));
+
+    // Registered contracts take precedence over the built-in table.
+    let map = class(&env, "java.util.Map");
+    let non_null_get = MethodContract::new(NullBehavior::NonNullReturn, true);
+    env.register_method_contract(
+        MethodContractKey::new("java.util.Map", "get", &["java.lang.Object"]),
+        non_null_get,
+    );
+    assert_eq!(
+        well_known_contract(&env, map, "get", &[object]),
+        Some(non_null_get)
+    );
+}
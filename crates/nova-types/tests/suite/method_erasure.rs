@@ -0,0 +1,61 @@
+use nova_types::{
+    erased_return_type, is_same_erasure, MethodDef, PrimitiveType, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support::method;
+
+fn list_method(env: &TypeStore, name: &str) -> MethodDef {
+    let list = env.class_id("java.util.List").unwrap();
+    env.class(list)
+        .unwrap()
+        .methods
+        .iter()
+        .find(|m| m.name == name)
+        .unwrap_or_else(|| panic!("missing List.{name}"))
+        .clone()
+}
+
+#[test]
+fn bridge_has_same_erasure_as_generic_method() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let boolean = Type::Primitive(PrimitiveType::Boolean);
+
+    // `boolean add(E)` and the bridge `boolean add(Object)`.
+    let add = list_method(&env, "add");
+    let bridge = method("add", vec![object.clone()], boolean.clone());
+    assert!(is_same_erasure(&env, &add, &bridge));
+    assert!(is_same_erasure(&env, &bridge, &add));
+    assert_eq!(
+        erased_return_type(&env, &add),
+        erased_return_type(&env, &bridge)
+    );
+
+    // `E get(int)` erases its return type to `Object`.
+    let get = list_method(&env, "get");
+    assert_eq!(erased_return_type(&env, &get), object);
+}
+
+#[test]
+fn name_and_parameter_count_must_match() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let boolean = Type::Primitive(PrimitiveType::Boolean);
+    let add = list_method(&env, "add");
+
+    let renamed = method("put", vec![object.clone()], boolean.clone());
+    assert!(!is_same_erasure(&env, &add, &renamed));
+
+    let extra_param = method("add", vec![object.clone(), object], boolean.clone());
+    assert!(!is_same_erasure(&env, &add, &extra_param));
+
+    let no_params = method("add", vec![], boolean.clone());
+    assert!(!is_same_erasure(&env, &add, &no_params));
+
+    // `E` erases to `Object`, not `String`.
+    let narrower = method("add", vec![string], boolean);
+    assert!(!is_same_erasure(&env, &add, &narrower));
+}
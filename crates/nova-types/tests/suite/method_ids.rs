@@ -0,0 +1,59 @@
+use nova_types::{ClassDef, ClassId, MethodId, Type, TypeEnv, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn class(env: &TypeStore, name: &str, methods: &[&str]) -> ClassDef {
+    ClassDef {
+        methods: methods
+            .iter()
+            .map(|name| support::method(name, vec![], Type::Void))
+            .collect(),
+        ..support::class(name, Some(Type::class(env.well_known().object, vec![])))
+    }
+}
+
+#[test]
+fn method_ids_round_trip_through_the_store() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let widget = env.add_class(class(&env, "com.example.Widget", &["draw", "resize"]));
+
+    for (idx, name) in ["draw", "resize"].into_iter().enumerate() {
+        let id = MethodId::for_method(&env, widget, idx).unwrap();
+        let (owner, method) = id.resolve(&env).unwrap();
+        assert_eq!(owner.name, "com.example.Widget");
+        assert_eq!(method.name, name);
+    }
+
+    assert_eq!(MethodId::for_method(&env, widget, 2), None);
+    assert_eq!(
+        MethodId::for_method(&env, ClassId::from_raw(u32::MAX), 0),
+        None
+    );
+    assert_ne!(
+        MethodId::for_method(&env, widget, 0),
+        MethodId::for_method(&env, widget, 1)
+    );
+}
+
+#[test]
+fn stale_method_ids_resolve_to_none() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let widget = env.add_class(class(&env, "com.example.Widget", &["draw", "resize"]));
+    let resize = MethodId::for_method(&env, widget, 1).unwrap();
+
+    // A store rebuilt without the class.
+    let rebuilt = TypeStore::with_minimal_jdk();
+    assert!(resize.resolve(&rebuilt).is_none());
+
+    // The class was redefined with fewer methods.
+    env.upsert_class(class(&env, "com.example.Widget", &["draw"]));
+    assert!(resize.resolve(&env).is_none());
+    let draw = MethodId::for_method(&env, widget, 0).unwrap();
+    assert_eq!(draw.resolve(&env).unwrap().1.name, "draw");
+
+    // The class was removed.
+    env.remove_class("com.example.Widget");
+    assert!(draw.resolve(&env).is_none());
+}
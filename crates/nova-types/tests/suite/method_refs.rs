@@ -0,0 +1,176 @@
+use nova_types::{
+    infer_lambda_return_type, infer_lambda_sam_signature, method_ref_matches_sam,
+    resolve_method_call, CallKind, ClassId, MethodCall, MethodRefKind, MethodResolution,
+    MethodSearchPhase, ResolvedMethod, TyContext, Type, TypeEnv, TypeStore,
+};
+
+fn function_of(env: &TypeStore, arg: Type, ret: Type) -> Type {
+    let function = env.class_id("java.util.function.Function").unwrap();
+    Type::class(function, vec![arg, ret])
+}
+
+fn string_length(env: &TypeStore) -> ResolvedMethod {
+    let string = Type::class(env.well_known().string, vec![]);
+    let call = MethodCall {
+        receiver: string,
+        call_kind: CallKind::Instance,
+        name: "length",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected String.length() to resolve");
+    };
+    found
+}
+
+/// `static int parseInt(String s)` on `java.lang.Integer`.
+fn integer_parse_int(owner: ClassId, string: Type) -> ResolvedMethod {
+    ResolvedMethod {
+        owner,
+        name: "parseInt".to_string(),
+        params: vec![string],
+        signature_params: None,
+        param_names: vec![],
+        return_type: Type::int(),
+        is_varargs: false,
+        is_static: true,
+        conversions: vec![],
+        inferred_type_args: vec![],
+        warnings: vec![],
+        used_varargs: false,
+        phase: MethodSearchPhase::Strict,
+        speculative: false,
+        depends_on_placeholders: Vec::new(),
+        deprecated: None,
+    }
+}
+
+#[test]
+fn unbound_string_length_matches_function_string_integer() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let sam = infer_lambda_sam_signature(&env, &function_of(&env, string, integer.clone()))
+        .expect("Function is functional");
+    let length = string_length(&env);
+
+    assert!(method_ref_matches_sam(
+        &env,
+        &sam,
+        &length,
+        MethodRefKind::Unbound
+    ));
+    // `length` takes no arguments, so a bound/static reference cannot consume the SAM parameter.
+    assert!(!method_ref_matches_sam(
+        &env,
+        &sam,
+        &length,
+        MethodRefKind::Bound
+    ));
+    assert!(!method_ref_matches_sam(
+        &env,
+        &sam,
+        &length,
+        MethodRefKind::Static
+    ));
+
+    // `Function<Integer, Integer>`: `Integer` is not a `String` receiver.
+    let sam = infer_lambda_sam_signature(&env, &function_of(&env, integer.clone(), integer))
+        .expect("Function is functional");
+    assert!(!method_ref_matches_sam(
+        &env,
+        &sam,
+        &length,
+        MethodRefKind::Unbound
+    ));
+}
+
+#[test]
+fn static_parse_int_matches_function_string_integer() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let sam = infer_lambda_sam_signature(&env, &function_of(&env, string.clone(), integer))
+        .expect("Function is functional");
+    let parse_int = integer_parse_int(env.well_known().integer, string.clone());
+
+    assert!(method_ref_matches_sam(
+        &env,
+        &sam,
+        &parse_int,
+        MethodRefKind::Static
+    ));
+    assert!(!method_ref_matches_sam(
+        &env,
+        &sam,
+        &parse_int,
+        MethodRefKind::Unbound
+    ));
+
+    // `Function<String, String>`: `int` is not assignable to `String`.
+    let sam = infer_lambda_sam_signature(&env, &function_of(&env, string.clone(), string))
+        .expect("Function is functional");
+    assert!(!method_ref_matches_sam(
+        &env,
+        &sam,
+        &parse_int,
+        MethodRefKind::Static
+    ));
+}
+
+#[test]
+fn void_sam_discards_the_method_result() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let consumer = env.class_id("java.util.function.Consumer").unwrap();
+    let sam = infer_lambda_sam_signature(&env, &Type::class(consumer, vec![string.clone()]))
+        .expect("Consumer is functional");
+
+    // `Consumer<String> c = Integer::parseInt;`
+    let parse_int = integer_parse_int(env.well_known().integer, string);
+    assert!(method_ref_matches_sam(
+        &env,
+        &sam,
+        &parse_int,
+        MethodRefKind::Static
+    ));
+}
+
+#[test]
+fn lambda_return_type_is_checked_against_the_sam() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let function = function_of(&env, string.clone(), integer.clone());
+
+    // `Function<String, Integer> f = s -> s.length();` boxes the `int` body.
+    assert_eq!(
+        infer_lambda_return_type(&env, &function, &Type::int()),
+        Some(integer.clone())
+    );
+    assert_eq!(
+        infer_lambda_return_type(&env, &function, &integer),
+        Some(integer)
+    );
+    // `Function<String, Integer> f = s -> s;`
+    assert_eq!(infer_lambda_return_type(&env, &function, &string), None);
+    assert_eq!(infer_lambda_return_type(&env, &function, &Type::Void), None);
+
+    // `Consumer<String> c = s -> System.out.println(s);`
+    let consumer = env.class_id("java.util.function.Consumer").unwrap();
+    let consumer = Type::class(consumer, vec![string.clone()]);
+    assert_eq!(
+        infer_lambda_return_type(&env, &consumer, &Type::Void),
+        Some(Type::Void)
+    );
+
+    // `String` is not a functional interface.
+    assert_eq!(infer_lambda_return_type(&env, &string, &string), None);
+}
@@ -0,0 +1,98 @@
+use nova_types::{
+    is_more_specific_method, ClassId, MethodSearchPhase, PrimitiveType, ResolvedMethod, Type,
+    TypeEnv, TypeStore,
+};
+
+fn resolved(
+    owner: ClassId,
+    params: Vec<Type>,
+    is_varargs: bool,
+    used_varargs: bool,
+) -> ResolvedMethod {
+    ResolvedMethod {
+        owner,
+        name: "m".to_string(),
+        params,
+        signature_params: None,
+        param_names: vec![],
+        return_type: Type::Void,
+        is_varargs,
+        is_static: false,
+        conversions: vec![],
+        inferred_type_args: vec![],
+        warnings: vec![],
+        used_varargs,
+        phase: if used_varargs {
+            MethodSearchPhase::Varargs
+        } else {
+            MethodSearchPhase::Strict
+        },
+        speculative: false,
+        depends_on_placeholders: vec![],
+        deprecated: None,
+    }
+}
+
+#[test]
+fn subclass_parameters_are_more_specific() {
+    let env = TypeStore::with_minimal_jdk();
+    let owner = env.well_known().object;
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    let m_string = resolved(owner, vec![string], false, false);
+    let m_object = resolved(owner, vec![object], false, false);
+    assert!(is_more_specific_method(&env, &m_string, &m_object));
+    assert!(!is_more_specific_method(&env, &m_object, &m_string));
+}
+
+#[test]
+fn primitive_specificity_follows_primitive_subtyping() {
+    let env = TypeStore::with_minimal_jdk();
+    let owner = env.well_known().object;
+    let m_int = resolved(
+        owner,
+        vec![Type::Primitive(PrimitiveType::Int)],
+        false,
+        false,
+    );
+    let m_long = resolved(
+        owner,
+        vec![Type::Primitive(PrimitiveType::Long)],
+        false,
+        false,
+    );
+
+    // `int <: long` (JLS 4.10.1), so `m(5)` picks `m(int)`; widening only goes one way.
+    assert!(is_more_specific_method(&env, &m_int, &m_long));
+    assert!(!is_more_specific_method(&env, &m_long, &m_int));
+
+    let m_boolean = resolved(
+        owner,
+        vec![Type::Primitive(PrimitiveType::Boolean)],
+        false,
+        false,
+    );
+    assert!(!is_more_specific_method(&env, &m_int, &m_boolean));
+    assert!(!is_more_specific_method(&env, &m_boolean, &m_int));
+}
+
+#[test]
+fn fixed_arity_is_more_specific_than_varargs() {
+    let env = TypeStore::with_minimal_jdk();
+    let owner = env.well_known().object;
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `m(String, String)` vs `m(String...)` called as `m("a", "b")`.
+    let fixed = resolved(owner, vec![string.clone(), string.clone()], false, false);
+    let varargs = resolved(owner, vec![string.clone(), string.clone()], true, true);
+    assert!(is_more_specific_method(&env, &fixed, &varargs));
+    assert!(!is_more_specific_method(&env, &varargs, &fixed));
+
+    // Between two variable arity invocations the expanded parameter types decide:
+    // `m(String...)` is more specific than `m(Object...)`.
+    let object_varargs = resolved(owner, vec![object.clone(), object], true, true);
+    assert!(is_more_specific_method(&env, &varargs, &object_varargs));
+    assert!(!is_more_specific_method(&env, &object_varargs, &varargs));
+}
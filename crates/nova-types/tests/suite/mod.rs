@@ -1,18 +1,74 @@
+mod alpha_rename;
+mod args_assignable;
+mod array_generics;
+mod assignability_errors;
+mod assignment_constants;
+mod assignment_obstructions;
+mod augmented_env;
 mod class_members;
+mod comparison_operators;
+mod completion_items;
+mod conditional_args;
+mod constant_folding;
 mod default_well_known;
+mod dependency_tracking;
+mod deprecation;
+mod diagnostic_suggestions;
+mod enhanced_for;
+mod exception_types;
 mod external_type_loader;
 mod external_type_loader_nested_class_generics;
 mod external_type_loader_type_parameter_bounds;
+mod field_ids;
+mod fuzz_invariants;
 mod glb;
+mod inherited_type_params;
+mod instantiable_types;
+mod interface_constants;
 mod interface_object_supertype;
+mod intersection_normalization;
 mod java_format;
 mod jls_conversions;
 mod jls_generics;
 mod jls_inference;
+mod json_schema;
+mod lambda_target_inference;
+mod lint_categories;
 mod lub;
+mod member_declaration;
+mod method_accessibility;
+mod method_contracts;
+mod method_erasure;
+mod method_ids;
+mod method_refs;
+mod method_specificity;
 mod minimal_jdk_binary_names;
 mod minimal_jdk_subtyping;
+mod null_subtyping;
+mod overlay_env;
+mod overload_phases;
 mod overload_resolution;
+mod override_return_types;
+mod placeholder_classes;
+mod return_type_inference;
+mod spans;
+mod speculative_resolution;
+mod support;
+mod type_bounds;
+mod type_constraints;
+mod type_diff;
+mod type_mismatch;
+mod type_param_bounds;
+mod type_param_removal;
+mod type_provider_members;
 mod type_store_clone;
+mod type_store_merge;
+mod type_store_serde;
 mod type_store_upsert;
+mod type_substitution;
 mod unchecked_varargs;
+mod unqualified_calls;
+mod varargs_arity;
+mod variance;
+mod well_formed_types;
+mod wildcard_member_access;
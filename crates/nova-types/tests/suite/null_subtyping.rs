@@ -0,0 +1,86 @@
+use nova_types::{
+    assignment_conversion, is_subtype, ClassDef, MethodDef, Type, TypeEnv, TypeStore, WildcardBound,
+};
+
+use super::support;
+
+#[test]
+fn null_is_a_subtype_of_type_variables() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let number = Type::class(env.well_known().integer, vec![]);
+    let cloneable = Type::class(env.well_known().cloneable, vec![]);
+
+    // `T`, `N extends Integer`, `C extends Integer & Cloneable`, and a type variable with no
+    // recorded bounds at all.
+    let t = env.add_type_param("T", vec![object]);
+    let n = env.add_type_param("N", vec![number.clone()]);
+    let c = env.add_type_param("C", vec![number, cloneable]);
+    let bare = env.add_type_param("B", vec![]);
+    for var in [t, n, c, bare] {
+        let ty = Type::TypeVar(var);
+        assert!(is_subtype(&env, &Type::Null, &ty), "null <: {ty:?}");
+        assert!(
+            assignment_conversion(&env, &Type::Null, &ty).is_some(),
+            "null is assignable to {ty:?}"
+        );
+    }
+}
+
+#[test]
+fn only_null_is_a_subtype_of_null() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let t = env.add_type_param("T", vec![object.clone()]);
+
+    assert!(is_subtype(&env, &Type::Null, &Type::Null));
+    for ty in [
+        object.clone(),
+        string.clone(),
+        Type::TypeVar(t),
+        Type::Array(Box::new(string.clone())),
+        Type::Wildcard(WildcardBound::Extends(Box::new(string))),
+        Type::int(),
+    ] {
+        assert!(!is_subtype(&env, &ty, &Type::Null), "{ty:?} </: null");
+    }
+}
+
+#[test]
+fn null_argument_infers_the_type_variable_bound() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let n = env.add_type_param("N", vec![integer.clone()]);
+    let util = env.add_class(ClassDef {
+        methods: vec![MethodDef {
+            type_params: vec![n],
+            is_static: true,
+            ..support::method("id", vec![Type::TypeVar(n)], Type::TypeVar(n))
+        }],
+        ..support::class("com.example.Util", Some(object))
+    });
+
+    // `Util.id(null)`
+    let call = nova_types::MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: nova_types::CallKind::Static,
+        name: "id",
+        args: vec![Type::Null],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = nova_types::TyContext::new(&env);
+    let nova_types::MethodResolution::Found(found) =
+        nova_types::resolve_method_call(&mut ctx, &call)
+    else {
+        panic!("expected id(null) to resolve");
+    };
+    assert_eq!(found.params, vec![integer.clone()]);
+    assert_eq!(found.return_type, integer);
+}
@@ -0,0 +1,116 @@
+use nova_types::{
+    is_subtype, resolve_method_call, CallKind, ClassDef, MethodCall, MethodResolution,
+    OverlayTypeEnv, TyContext, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn class(name: &str, super_class: Type, interfaces: Vec<Type>) -> ClassDef {
+    ClassDef {
+        interfaces,
+        ..support::class(name, Some(super_class))
+    }
+}
+
+#[test]
+fn overlay_class_extending_base_class_is_a_subtype() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let list = base.class_id("java.util.List").unwrap();
+    let string = Type::class(base.well_known().string, vec![]);
+    let animal = base.add_class(class("com.example.Animal", object.clone(), vec![]));
+
+    let mut overlay = TypeStore::overlay_for(&base);
+    let dog = overlay.add_class(class(
+        "com.example.Dog",
+        Type::class(animal, vec![]),
+        vec![Type::class(list, vec![string.clone()])],
+    ));
+    assert!(base.class(dog).is_none());
+
+    let env = OverlayTypeEnv::new(&base, &overlay);
+    assert_eq!(env.lookup_class("com.example.Dog"), Some(dog));
+    assert_eq!(env.lookup_class("com.example.Animal"), Some(animal));
+    assert_eq!(env.well_known().object, base.well_known().object);
+
+    let dog = Type::class(dog, vec![]);
+    assert!(is_subtype(&env, &dog, &Type::class(animal, vec![])));
+    assert!(is_subtype(&env, &dog, &object));
+    assert!(is_subtype(&env, &dog, &Type::class(list, vec![string])));
+    assert!(!is_subtype(&env, &Type::class(animal, vec![]), &dog));
+}
+
+#[test]
+fn overlay_shadows_base_class_by_name() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let cloneable = Type::class(base.well_known().cloneable, vec![]);
+    let stale = base.add_class(class("com.example.Edited", object.clone(), vec![]));
+
+    let mut overlay = TypeStore::overlay_for(&base);
+    let fresh = overlay.add_class(class("com.example.Edited", object, vec![cloneable.clone()]));
+    assert_ne!(stale, fresh);
+
+    let env = OverlayTypeEnv::new(&base, &overlay);
+    assert_eq!(env.lookup_class("com.example.Edited"), Some(fresh));
+    assert!(is_subtype(&env, &Type::class(fresh, vec![]), &cloneable));
+    // The base definition stays reachable through its own id.
+    assert!(!is_subtype(&env, &Type::class(stale, vec![]), &cloneable));
+}
+
+#[test]
+fn overlay_type_params_do_not_collide_with_base() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let base_t = base.add_type_param("T", vec![object.clone()]);
+
+    let mut overlay = TypeStore::overlay_for(&base);
+    let overlay_u = overlay.add_type_param("U", vec![object]);
+    assert_ne!(base_t, overlay_u);
+    assert_eq!(overlay.type_param_count(), base.type_param_count() + 1);
+
+    let env = OverlayTypeEnv::new(&base, &overlay);
+    assert_eq!(env.type_param(base_t).unwrap().name, "T");
+    assert_eq!(env.type_param(overlay_u).unwrap().name, "U");
+}
+
+#[test]
+fn method_resolution_sees_members_across_the_boundary() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let string = Type::class(base.well_known().string, vec![]);
+    let mut animal = class("com.example.Animal", object, vec![]);
+    animal
+        .methods
+        .push(support::method("name", vec![], string.clone()));
+    let animal = base.add_class(animal);
+
+    let mut overlay = TypeStore::overlay_for(&base);
+    let dog = overlay.add_class(class(
+        "com.example.Dog",
+        Type::class(animal, vec![]),
+        vec![],
+    ));
+
+    let env = OverlayTypeEnv::new(&base, &overlay);
+    let call = MethodCall {
+        receiver: Type::class(dog, vec![]),
+        call_kind: CallKind::Instance,
+        name: "name",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected inherited name() to resolve on the overlay class");
+    };
+    assert_eq!(found.owner, animal);
+    assert_eq!(found.return_type, string);
+}
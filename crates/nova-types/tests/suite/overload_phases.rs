@@ -0,0 +1,219 @@
+//! Overload resolution picks the first phase (strict, then loose, then varargs) with an
+//! applicable method (JLS 15.12.2), regardless of which class in the hierarchy declares it.
+
+use nova_types::{
+    resolve_method_call, CallKind, ClassDef, ClassId, MethodCall, MethodDef, MethodResolution,
+    MethodSearchPhase, PrimitiveType, ResolvedMethod, TyContext, Type, TypeEnv, TypeStore,
+    TypeVarId,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn method(params: Vec<Type>, is_varargs: bool) -> MethodDef {
+    MethodDef {
+        is_varargs,
+        ..support::method("process", params, Type::Void)
+    }
+}
+
+fn class(
+    name: &str,
+    type_params: Vec<TypeVarId>,
+    super_class: Type,
+    methods: Vec<MethodDef>,
+) -> ClassDef {
+    ClassDef {
+        type_params,
+        methods,
+        ..support::class(name, Some(super_class))
+    }
+}
+
+/// Defines `Base` with `base_methods` and `Sub extends <sub_super>` with `sub_methods`.
+fn hierarchy(
+    env: &mut TypeStore,
+    base_type_params: Vec<TypeVarId>,
+    base_methods: Vec<MethodDef>,
+    sub_super_args: Option<Vec<Type>>,
+    sub_methods: Vec<MethodDef>,
+) -> (ClassId, ClassId) {
+    let object = Type::class(env.well_known().object, vec![]);
+    let base = env.add_class(class(
+        "com.example.Base",
+        base_type_params,
+        object,
+        base_methods,
+    ));
+    let sub = env.add_class(class(
+        "com.example.Sub",
+        vec![],
+        Type::class(base, sub_super_args.unwrap_or_default()),
+        sub_methods,
+    ));
+    (base, sub)
+}
+
+fn resolve(env: &TypeStore, receiver: ClassId, arg: Type) -> ResolvedMethod {
+    let call = MethodCall {
+        receiver: Type::class(receiver, vec![]),
+        call_kind: CallKind::Instance,
+        name: "process",
+        args: vec![arg],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    match resolve_method_call(&mut ctx, &call) {
+        MethodResolution::Found(method) => method,
+        other => panic!("expected a resolved method, got {other:?}"),
+    }
+}
+
+#[test]
+fn strict_superclass_method_beats_boxing_subclass_method() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    // class Base { void process(int) }  class Sub extends Base { void process(Integer) }
+    let (base, sub) = hierarchy(
+        &mut env,
+        vec![],
+        vec![method(vec![int.clone()], false)],
+        None,
+        vec![method(vec![integer.clone()], false)],
+    );
+
+    let found = resolve(&env, sub, int.clone());
+    assert_eq!(
+        (found.owner, found.phase),
+        (base, MethodSearchPhase::Strict)
+    );
+    assert_eq!(found.params, vec![int]);
+
+    // And the other way around: an `Integer` argument picks the subclass overload in phase 1.
+    let found = resolve(&env, sub, integer);
+    assert_eq!((found.owner, found.phase), (sub, MethodSearchPhase::Strict));
+}
+
+#[test]
+fn fixed_arity_superclass_method_beats_varargs_subclass_method() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    // class Base { void process(Object) }  class Sub extends Base { void process(String...) }
+    let (base, sub) = hierarchy(
+        &mut env,
+        vec![],
+        vec![method(vec![object], false)],
+        None,
+        vec![method(vec![Type::Array(Box::new(string.clone()))], true)],
+    );
+
+    let found = resolve(&env, sub, string);
+    assert_eq!(
+        (found.owner, found.phase),
+        (base, MethodSearchPhase::Strict)
+    );
+    assert!(!found.used_varargs);
+}
+
+#[test]
+fn substituted_generic_parameter_is_strict_applicable() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let t = env.add_type_param("T", vec![Type::class(env.well_known().object, vec![])]);
+    // class Base<T> { void process(T) }  class Sub extends Base<Integer> { void process(int) }
+    let (base, sub) = hierarchy(
+        &mut env,
+        vec![t],
+        vec![method(vec![Type::TypeVar(t)], false)],
+        Some(vec![integer.clone()]),
+        vec![method(vec![int], false)],
+    );
+
+    // `process(Integer)` matches `Base<Integer>.process(T)` without unboxing.
+    let found = resolve(&env, sub, integer.clone());
+    assert_eq!(
+        (found.owner, found.phase),
+        (base, MethodSearchPhase::Strict)
+    );
+    assert_eq!(found.params, vec![integer]);
+}
+
+#[test]
+fn raw_supertype_keeps_primitive_parameters_strict_applicable() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let t = env.add_type_param("T", vec![Type::class(env.well_known().object, vec![])]);
+    // class Base<T> { void process(int); void process(T, T) }
+    // class Sub extends Base /* raw */ { void process(Integer) }
+    let (base, sub) = hierarchy(
+        &mut env,
+        vec![t],
+        vec![
+            method(vec![int.clone()], false),
+            method(vec![Type::TypeVar(t), Type::TypeVar(t)], false),
+        ],
+        None,
+        vec![method(vec![integer], false)],
+    );
+
+    let found = resolve(&env, sub, int.clone());
+    assert_eq!(
+        (found.owner, found.phase),
+        (base, MethodSearchPhase::Strict)
+    );
+    assert_eq!(found.params, vec![int]);
+}
+
+/// Defines `com.example.Overloads` declaring `process` once per entry of `params`.
+fn overloads(env: &mut TypeStore, params: Vec<Type>) -> ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    let methods = params
+        .into_iter()
+        .map(|param| method(vec![param], false))
+        .collect();
+    env.add_class(class("com.example.Overloads", vec![], object, methods))
+}
+
+#[test]
+fn strict_phase_prefers_primitive_overloads_over_boxing() {
+    let int = Type::Primitive(PrimitiveType::Int);
+    let long = Type::Primitive(PrimitiveType::Long);
+
+    // `process(int)` vs `process(Integer)`: only the exact match is strict-applicable.
+    let mut env = TypeStore::with_minimal_jdk();
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let class = overloads(&mut env, vec![integer.clone(), int.clone()]);
+    let found = resolve(&env, class, int.clone());
+    assert_eq!(
+        (found.params, found.phase),
+        (vec![int.clone()], MethodSearchPhase::Strict)
+    );
+
+    // `process(long)` vs `process(Integer)`: widening is strict, boxing is not.
+    let mut env = TypeStore::with_minimal_jdk();
+    let class = overloads(&mut env, vec![integer.clone(), long.clone()]);
+    let found = resolve(&env, class, int.clone());
+    assert_eq!(
+        (found.params, found.phase),
+        (vec![long.clone()], MethodSearchPhase::Strict)
+    );
+
+    // `process(int)`, `process(long)` and `process(Integer)`: the exact match is most specific.
+    let mut env = TypeStore::with_minimal_jdk();
+    let class = overloads(&mut env, vec![integer, long, int.clone()]);
+    let found = resolve(&env, class, int.clone());
+    assert_eq!(
+        (found.params, found.phase),
+        (vec![int], MethodSearchPhase::Strict)
+    );
+}
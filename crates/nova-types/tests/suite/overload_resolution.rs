@@ -24,22 +24,32 @@ fn static_vs_instance_call_kind_filtering_and_warning() {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::Primitive(PrimitiveType::Int)],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             // Static overload: m(long)
             MethodDef {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::Primitive(PrimitiveType::Long)],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // `CallKinds.m(1)` should ignore the instance overload and pick `m(long)`.
@@ -50,6 +60,10 @@ fn static_vs_instance_call_kind_filtering_and_warning() {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx = TyContext::new(&env);
     let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call_static) else {
@@ -66,6 +80,10 @@ fn static_vs_instance_call_kind_filtering_and_warning() {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx = TyContext::new(&env);
     let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call_instance) else {
@@ -82,6 +100,10 @@ fn static_vs_instance_call_kind_filtering_and_warning() {
         args: vec![Type::Primitive(PrimitiveType::Long)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx = TyContext::new(&env);
     let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call_static_via_instance)
@@ -111,11 +133,18 @@ fn overriding_removes_obvious_duplicates() {
             name: "m".to_string(),
             type_params: vec![],
             params: vec![Type::Primitive(PrimitiveType::Int)],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let sub = env.add_class(ClassDef {
@@ -130,11 +159,18 @@ fn overriding_removes_obvious_duplicates() {
             name: "m".to_string(),
             type_params: vec![],
             params: vec![Type::Primitive(PrimitiveType::Int)],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -144,6 +180,10 @@ fn overriding_removes_obvious_duplicates() {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -153,6 +193,91 @@ fn overriding_removes_obvious_duplicates() {
     assert_eq!(found.owner, sub);
 }
 
+#[test]
+fn super_call_skips_the_calling_class_declarations() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+
+    let m = |return_type: Type| MethodDef {
+        name: "m".to_string(),
+        type_params: vec![],
+        params: vec![],
+        param_names: vec![],
+        return_type,
+        is_static: false,
+        is_varargs: false,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    };
+    let a = env.add_class(ClassDef {
+        name: "com.example.A".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(Type::class(object, vec![])),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![m(Type::Primitive(PrimitiveType::Long))],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+    let b = env.add_class(ClassDef {
+        name: "com.example.B".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(Type::class(a, vec![])),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![m(Type::Primitive(PrimitiveType::Int))],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+
+    let call = |name| MethodCall {
+        receiver: Type::class(b, vec![]),
+        call_kind: CallKind::Instance,
+        name,
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: Some(b),
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+
+    // `super.m()` inside `B` calls `A.m`.
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call("m")) else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(found.owner, a);
+    assert_eq!(found.return_type, Type::Primitive(PrimitiveType::Long));
+
+    // `super.toString()` falls through to `Object`.
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call("toString")) else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(found.owner, object);
+
+    // A method only `B` declares is not visible through `super`.
+    env.class_mut(b).unwrap().methods.push(MethodDef {
+        name: "onlyInB".to_string(),
+        ..m(Type::Void)
+    });
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call("onlyInB")),
+        MethodResolution::NotFound(_)
+    ));
+}
+
 #[test]
 fn tie_breaks_on_conversion_cost() {
     let mut env = TypeStore::with_minimal_jdk();
@@ -173,30 +298,43 @@ fn tie_breaks_on_conversion_cost() {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::class(integer, vec![])],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::class(long_wrapper, vec![])],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             MethodDef {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::class(object, vec![])],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -206,6 +344,10 @@ fn tie_breaks_on_conversion_cost() {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -215,6 +357,87 @@ fn tie_breaks_on_conversion_cost() {
     assert_eq!(found.params, vec![Type::class(integer, vec![])]);
 }
 
+#[test]
+fn boxing_tie_break_prefers_fewer_conversion_steps() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let byte_wrapper = Type::class(
+        env.class_id("java.lang.Byte").expect("Byte should exist"),
+        vec![],
+    );
+    let int = Type::Primitive(PrimitiveType::Int);
+
+    let method = |params: Vec<Type>| MethodDef {
+        name: "m".to_string(),
+        type_params: vec![],
+        params,
+        param_names: vec![],
+        return_type: Type::Void,
+        is_static: true,
+        is_varargs: false,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    };
+    let test = env.add_class(ClassDef {
+        name: "com.example.Unboxing".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(object.clone()),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![
+            method(vec![int.clone(), object.clone()]),
+            method(vec![object.clone(), int.clone()]),
+        ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+
+    let call = |args: Vec<Type>| MethodCall {
+        receiver: Type::class(test, vec![]),
+        call_kind: CallKind::Static,
+        name: "m",
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+
+    // Neither overload is more specific, and both need one unboxing conversion. `Byte -> int`
+    // (unbox + widen) costs more than `Integer -> int` (unbox only), so `m(Object, int)` wins.
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) =
+        resolve_method_call(&mut ctx, &call(vec![byte_wrapper.clone(), integer.clone()]))
+    else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(found.params, vec![object.clone(), int.clone()]);
+
+    // Mirrored arguments pick the mirrored overload.
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) =
+        resolve_method_call(&mut ctx, &call(vec![integer.clone(), byte_wrapper]))
+    else {
+        panic!("expected method resolution success");
+    };
+    assert_eq!(found.params, vec![int.clone(), object.clone()]);
+
+    // With equal step counts the tie-break has nothing to go on.
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call(vec![integer.clone(), integer])),
+        MethodResolution::Ambiguous(_)
+    ));
+}
+
 #[test]
 fn not_found_includes_useful_diagnostics() {
     let mut env = TypeStore::with_minimal_jdk();
@@ -236,11 +459,18 @@ fn not_found_includes_useful_diagnostics() {
                 Type::Primitive(PrimitiveType::Int),
                 Type::Primitive(PrimitiveType::Int),
             ],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Wrong arity should be reported.
@@ -251,6 +481,10 @@ fn not_found_includes_useful_diagnostics() {
         args: vec![Type::Primitive(PrimitiveType::Int)],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx = TyContext::new(&env);
     let MethodResolution::NotFound(nf) = resolve_method_call(&mut ctx, &wrong_arity) else {
@@ -274,6 +508,10 @@ fn not_found_includes_useful_diagnostics() {
         args: vec![Type::class(string, vec![]), Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
     let mut ctx = TyContext::new(&env);
     let MethodResolution::NotFound(nf) = resolve_method_call(&mut ctx, &conv_fail) else {
@@ -308,11 +546,18 @@ fn not_found_reports_inference_bound_failures() {
             name: "id".to_string(),
             type_params: vec![n],
             params: vec![Type::TypeVar(n)],
+            param_names: vec![],
             return_type: Type::TypeVar(n),
             is_static: true,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Explicit type arguments must satisfy bounds: `<N extends Number> id(N)`.
@@ -323,6 +568,10 @@ fn not_found_reports_inference_bound_failures() {
         args: vec![Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![Type::class(string, vec![])],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -334,3 +583,114 @@ fn not_found_reports_inference_bound_failures() {
         nova_types::MethodCandidateFailureReason::TypeArgOutOfBounds { type_param, .. } if *type_param == n
     )));
 }
+
+/// `class Builder<T> { Builder<T> add(T value) }`
+fn define_builder(env: &mut TypeStore) -> nova_types::ClassId {
+    let object = Type::class(env.well_known().object, vec![]);
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let builder = env.intern_class_id("com.example.Builder");
+    env.define_class(
+        builder,
+        ClassDef {
+            name: "com.example.Builder".to_string(),
+            kind: ClassKind::Class,
+            type_params: vec![t],
+            super_class: Some(object),
+            interfaces: vec![],
+            fields: vec![],
+            constructors: vec![],
+            methods: vec![MethodDef {
+                name: "add".to_string(),
+                type_params: vec![],
+                params: vec![Type::TypeVar(t)],
+                param_names: vec![],
+                return_type: Type::class(builder, vec![Type::TypeVar(t)]),
+                is_static: false,
+                is_varargs: false,
+                is_abstract: false,
+                is_private: false,
+                deprecation: None,
+            }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
+        },
+    );
+    builder
+}
+
+fn resolve_add(env: &TypeStore, receiver: Type, arg: Type) -> nova_types::ResolvedMethod {
+    let call = MethodCall {
+        receiver,
+        call_kind: CallKind::Instance,
+        name: "add",
+        args: vec![arg],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(env);
+    match resolve_method_call(&mut ctx, &call) {
+        MethodResolution::Found(found) => found,
+        other => panic!("expected add to resolve, got {other:?}"),
+    }
+}
+
+#[test]
+fn self_returning_method_chain_keeps_receiver_type_args() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let builder = define_builder(&mut env);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // new Builder<String>().add("a").add("b")
+    let mut receiver = Type::class(builder, vec![string.clone()]);
+    for _ in 0..2 {
+        let found = resolve_add(&env, receiver, string.clone());
+        assert_eq!(found.params, vec![string.clone()]);
+        assert_eq!(
+            found.return_type,
+            Type::class(builder, vec![string.clone()])
+        );
+        receiver = found.return_type;
+    }
+
+    // class Names extends Builder<String> {}
+    let names = env.add_class(ClassDef {
+        name: "com.example.Names".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class: Some(Type::class(builder, vec![string.clone()])),
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    });
+    let found = resolve_add(&env, Type::class(names, vec![]), string.clone());
+    assert_eq!(found.owner, builder);
+    assert_eq!(found.return_type, Type::class(builder, vec![string]));
+}
+
+#[test]
+fn self_returning_method_on_raw_receiver_stays_raw() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let builder = define_builder(&mut env);
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // new Builder().add("a").add("b")
+    let mut receiver = Type::class(builder, vec![]);
+    for _ in 0..2 {
+        let found = resolve_add(&env, receiver, string.clone());
+        assert_eq!(found.params, vec![object.clone()]);
+        assert_eq!(found.return_type, Type::class(builder, vec![]));
+        receiver = found.return_type;
+    }
+}
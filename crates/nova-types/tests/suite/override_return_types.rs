@@ -0,0 +1,66 @@
+use nova_types::{is_subtype_for_overriding, PrimitiveType, Type, TypeEnv, TypeStore};
+
+#[test]
+fn reference_returns_are_covariant() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert!(is_subtype_for_overriding(&env, &string, &object));
+    assert!(!is_subtype_for_overriding(&env, &object, &string));
+}
+
+#[test]
+fn primitive_returns_must_match_exactly() {
+    let env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let long = Type::Primitive(PrimitiveType::Long);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    assert!(is_subtype_for_overriding(&env, &int, &int));
+    // No widening...
+    assert!(!is_subtype_for_overriding(&env, &int, &long));
+    // ...and no boxing.
+    assert!(!is_subtype_for_overriding(&env, &int, &integer));
+    assert!(!is_subtype_for_overriding(&env, &integer, &int));
+}
+
+#[test]
+fn void_only_overrides_void() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    assert!(is_subtype_for_overriding(&env, &Type::Void, &Type::Void));
+    assert!(!is_subtype_for_overriding(&env, &Type::Void, &object));
+    assert!(!is_subtype_for_overriding(&env, &object, &Type::Void));
+}
+
+#[test]
+fn array_returns_are_covariant_only_for_reference_elements() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let object_array = Type::Array(Box::new(object));
+    let string_array = Type::Array(Box::new(string));
+    let int_array = Type::Array(Box::new(Type::Primitive(PrimitiveType::Int)));
+
+    assert!(is_subtype_for_overriding(
+        &env,
+        &string_array,
+        &object_array
+    ));
+    assert!(!is_subtype_for_overriding(&env, &int_array, &object_array));
+}
+
+#[test]
+fn raw_return_overrides_parameterized_return() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert!(is_subtype_for_overriding(
+        &env,
+        &Type::class(list, vec![]),
+        &Type::class(list, vec![string]),
+    ));
+}
@@ -0,0 +1,163 @@
+use nova_types::{
+    is_subtype, resolve_method_call, CallKind, ClassDef, ClassDefState, ClassId, MethodCall,
+    MethodDef, MethodResolution, SubtypeCheck, TyContext, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn class_def(
+    name: &str,
+    super_class: Type,
+    interfaces: Vec<Type>,
+    methods: Vec<MethodDef>,
+) -> ClassDef {
+    ClassDef {
+        interfaces,
+        methods,
+        ..support::class(name, Some(super_class))
+    }
+}
+
+/// Mid-load snapshot: `B` is interned but not defined yet, and `class A extends B` is.
+fn mid_load(env: &mut TypeStore) -> (ClassId, ClassId) {
+    let object = Type::class(env.well_known().object, vec![]);
+    let b = env.intern_class_id("com.example.B");
+    let a = env.add_class(class_def(
+        "com.example.A",
+        Type::class(b, vec![]),
+        vec![],
+        vec![support::method("m", vec![object], Type::Void)],
+    ));
+    (a, b)
+}
+
+/// Completes the load: `class B implements Serializable { void m(String) }`.
+fn define_b(env: &mut TypeStore, b: ClassId) {
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let serializable = Type::class(env.well_known().serializable, vec![]);
+    env.define_class(
+        b,
+        class_def(
+            "com.example.B",
+            object,
+            vec![serializable],
+            vec![support::method("m", vec![string], Type::Void)],
+        ),
+    );
+}
+
+#[test]
+fn definition_state_follows_intern_and_define() {
+    let mut env = TypeStore::with_minimal_jdk();
+    for (id, def) in env.iter_classes() {
+        assert!(env.is_defined(id), "{} is a placeholder", def.name);
+    }
+
+    let (a, b) = mid_load(&mut env);
+    assert!(env.is_defined(a));
+    assert!(!env.is_defined(b));
+    assert_eq!(env.class_def_state(b), ClassDefState::Placeholder);
+    // Interning again does not define the class.
+    assert_eq!(env.intern_class_id("com.example.B"), b);
+    assert!(!env.is_defined(b));
+
+    define_b(&mut env, b);
+    assert!(env.is_defined(b));
+    assert_eq!(env.class_def_state(b), ClassDefState::Defined);
+
+    let c = env.intern_class_id("com.example.C");
+    let object = Type::class(env.well_known().object, vec![]);
+    assert_eq!(
+        env.upsert_class(class_def("com.example.C", object, vec![], vec![])),
+        c
+    );
+    assert!(env.is_defined(c));
+}
+
+#[test]
+fn subtyping_through_placeholder_is_uncertain() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let (a, b) = mid_load(&mut env);
+    let a_ty = Type::class(a, vec![]);
+    let serializable = Type::class(env.well_known().serializable, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // Default behavior is unchanged: a confident `false`.
+    assert!(!is_subtype(&env, &a_ty, &serializable));
+    let mut ctx = TyContext::new(&env);
+    assert_eq!(
+        ctx.is_subtype_checked(&a_ty, &serializable),
+        SubtypeCheck::No
+    );
+
+    let mut ctx = TyContext::new(&env).with_placeholder_checks();
+    assert_eq!(
+        ctx.is_subtype_checked(&a_ty, &serializable),
+        SubtypeCheck::Unknown {
+            placeholders: vec![b]
+        }
+    );
+    // Queries that never reach a placeholder stay certain.
+    assert_eq!(ctx.is_subtype_checked(&string, &integer), SubtypeCheck::No);
+    assert_eq!(ctx.is_subtype_checked(&a_ty, &a_ty), SubtypeCheck::Yes);
+    assert_eq!(ctx.take_placeholders(), vec![b]);
+    assert_eq!(ctx.take_placeholders(), vec![]);
+
+    define_b(&mut env, b);
+    let mut ctx = TyContext::new(&env).with_placeholder_checks();
+    assert_eq!(
+        ctx.is_subtype_checked(&a_ty, &serializable),
+        SubtypeCheck::Yes
+    );
+}
+
+#[test]
+fn resolution_records_placeholder_dependencies() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let (a, b) = mid_load(&mut env);
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let call = MethodCall {
+        receiver: Type::class(a, vec![]),
+        call_kind: CallKind::Instance,
+        name: "m",
+        args: vec![string.clone()],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let resolve = |env: &TypeStore, checks: bool| {
+        let ctx = TyContext::new(env);
+        let mut ctx = if checks {
+            ctx.with_placeholder_checks()
+        } else {
+            ctx
+        };
+        match resolve_method_call(&mut ctx, &call) {
+            MethodResolution::Found(method) => method,
+            other => panic!("expected m to resolve, got {other:?}"),
+        }
+    };
+
+    // `A.m(Object)` is found, but `B` may still declare a better overload.
+    let found = resolve(&env, false);
+    assert_eq!(found.params, vec![object.clone()]);
+    assert_eq!(found.depends_on_placeholders, vec![]);
+    let found = resolve(&env, true);
+    assert_eq!(found.params, vec![object]);
+    assert_eq!(found.depends_on_placeholders, vec![b]);
+
+    // Once `B` is defined its `m(String)` wins and the result no longer depends on placeholders.
+    define_b(&mut env, b);
+    let found = resolve(&env, true);
+    assert_eq!(found.owner, b);
+    assert_eq!(found.params, vec![string]);
+    assert_eq!(found.depends_on_placeholders, vec![]);
+}
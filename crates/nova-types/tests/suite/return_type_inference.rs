@@ -0,0 +1,62 @@
+use nova_types::{infer_return_type_from_body, multi_lub, Type, TypeEnv, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn bodies_without_value_returns_return_void() {
+    let env = TypeStore::with_minimal_jdk();
+    assert_eq!(infer_return_type_from_body(&env, &[]), Type::Void);
+    assert_eq!(
+        infer_return_type_from_body(&env, &[Type::Void, Type::Void]),
+        Type::Void
+    );
+}
+
+#[test]
+fn value_returns_are_joined() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let object = Type::class(env.well_known().object, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    assert_eq!(
+        infer_return_type_from_body(&env, &[string.clone(), string.clone()]),
+        string
+    );
+    assert_eq!(
+        infer_return_type_from_body(&env, &[string.clone(), object.clone()]),
+        object
+    );
+    assert_eq!(
+        infer_return_type_from_body(&env, &[string.clone(), Type::Null]),
+        string
+    );
+    assert_eq!(
+        infer_return_type_from_body(&env, &[string.clone(), integer.clone()]),
+        multi_lub(&env, &[integer, string])
+    );
+}
+
+#[test]
+fn mixing_bare_and_value_returns_is_an_error() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    assert_eq!(
+        infer_return_type_from_body(&env, &[string.clone(), Type::Void]),
+        Type::Error
+    );
+    assert_eq!(
+        infer_return_type_from_body(&env, &[Type::Void, string]),
+        Type::Error
+    );
+}
+
+#[test]
+fn multi_lub_of_nothing_is_object() {
+    let env = TypeStore::with_minimal_jdk();
+    assert_eq!(
+        multi_lub(&env, &[]),
+        Type::class(env.well_known().object, vec![])
+    );
+}
@@ -0,0 +1,156 @@
+use nova_types::{LineIndex, Position, Span};
+use proptest::prelude::*;
+
+use pretty_assertions::assert_eq;
+
+fn pos(line: u32, col_utf16: u32, col_utf8: u32) -> Position {
+    Position {
+        line,
+        col_utf16,
+        col_utf8,
+    }
+}
+
+#[test]
+fn span_containment_merge_and_intersection() {
+    let outer = Span::new(2, 10);
+    assert!(outer.contains(Span::new(2, 10)));
+    assert!(outer.contains(Span::new(4, 6)));
+    assert!(outer.contains(Span::new(10, 10)));
+    assert!(!outer.contains(Span::new(1, 4)));
+    assert!(!outer.contains(Span::new(8, 11)));
+
+    assert!(outer.contains_offset(2));
+    assert!(outer.contains_offset(9));
+    assert!(!outer.contains_offset(10));
+    assert!(!Span::new(3, 3).contains_offset(3));
+
+    assert_eq!(Span::new(4, 6).merge(Span::new(1, 2)), Span::new(1, 6));
+    assert_eq!(Span::new(1, 8).merge(Span::new(2, 3)), Span::new(1, 8));
+
+    assert_eq!(outer.intersect(Span::new(8, 12)), Some(Span::new(8, 10)));
+    assert_eq!(outer.intersect(Span::new(0, 20)), Some(outer));
+    assert_eq!(Span::new(0, 2).intersect(Span::new(2, 4)), None);
+    assert_eq!(outer.intersect(Span::new(11, 12)), None);
+    assert_eq!(outer.intersect(Span::new(5, 5)), Some(Span::new(5, 5)));
+    assert_eq!(outer.intersect(Span::new(12, 12)), None);
+}
+
+#[test]
+fn line_index_handles_every_line_terminator() {
+    let text = "a\nb\r\nc\rd";
+    let index = LineIndex::new(text);
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.position(0), pos(0, 0, 0));
+    assert_eq!(index.position(2), pos(1, 0, 0));
+    assert_eq!(index.position(5), pos(2, 0, 0));
+    assert_eq!(index.position(7), pos(3, 0, 0));
+    assert_eq!(index.position(8), pos(3, 1, 1));
+    assert_eq!(index.offset_of(1, 0), 2);
+    assert_eq!(index.offset_of(2, 0), 5);
+    assert_eq!(index.offset_of(3, 1), 8);
+
+    // A trailing terminator starts an empty last line.
+    let index = LineIndex::new("a\n");
+    assert_eq!(index.line_count(), 2);
+    assert_eq!(index.position(2), pos(1, 0, 0));
+
+    assert_eq!(LineIndex::new("").position(0), pos(0, 0, 0));
+}
+
+#[test]
+fn line_index_counts_utf16_code_units() {
+    // `é` is 2 bytes / 1 code unit, `😀` is 4 bytes / 2 code units (a surrogate pair).
+    let text = "é😀x";
+    let index = LineIndex::new(text);
+    assert_eq!(index.position(2), pos(0, 1, 2));
+    assert_eq!(index.position(6), pos(0, 3, 6));
+    assert_eq!(index.offset_of(0, 3), 6);
+
+    // Offsets inside a character snap back to its start.
+    assert_eq!(index.position(4), pos(0, 1, 2));
+    // A column that splits the surrogate pair maps to the start of the emoji.
+    assert_eq!(index.offset_of(0, 2), 2);
+}
+
+#[test]
+fn line_index_clamps_out_of_range_input() {
+    let text = "ab\ncd";
+    let index = LineIndex::new(text);
+    assert_eq!(index.position(100), pos(1, 2, 2));
+    assert_eq!(index.offset_of(0, 100), 2);
+    assert_eq!(index.offset_of(100, 1), 4);
+    assert_eq!(index.offset_of(100, 100), 5);
+}
+
+fn arb_text() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop::sample::select(vec!['a', 'b', ' ', '\n', '\r', 'é', '中', '😀', '🦀']),
+        0..48,
+    )
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+fn arb_text_and_offset() -> impl Strategy<Value = (String, usize)> {
+    arb_text().prop_flat_map(|text| {
+        let len = text.len();
+        (Just(text), 0..=len)
+    })
+}
+
+/// Reference conversion: walk the text char by char.
+fn naive_position(text: &str, offset: usize) -> Position {
+    let mut line = 0;
+    let mut col_utf16 = 0;
+    let mut col_utf8 = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        if idx + ch.len_utf8() > offset {
+            break;
+        }
+        let crlf = ch == '\r' && chars.peek().is_some_and(|&(_, next)| next == '\n');
+        if ch == '\n' || (ch == '\r' && !crlf) {
+            line += 1;
+            col_utf16 = 0;
+            col_utf8 = 0;
+        } else if crlf && idx + 2 <= offset {
+            chars.next();
+            line += 1;
+            col_utf16 = 0;
+            col_utf8 = 0;
+        } else {
+            col_utf16 += ch.len_utf16() as u32;
+            col_utf8 += ch.len_utf8() as u32;
+        }
+    }
+    Position {
+        line,
+        col_utf16,
+        col_utf8,
+    }
+}
+
+proptest! {
+    #[test]
+    fn position_matches_a_naive_walk((text, offset) in arb_text_and_offset()) {
+        let index = LineIndex::new(&text);
+        prop_assert_eq!(index.position(offset), naive_position(&text, offset));
+    }
+
+    #[test]
+    fn offsets_round_trip_through_positions((text, offset) in arb_text_and_offset()) {
+        let index = LineIndex::new(&text);
+        let pos = index.position(offset);
+        let back = index.offset_of(pos.line, pos.col_utf16);
+
+        let bytes = text.as_bytes();
+        let between_cr_lf =
+            offset > 0 && bytes[offset - 1] == b'\r' && bytes.get(offset) == Some(&b'\n');
+        if text.is_char_boundary(offset) && !between_cr_lf {
+            prop_assert_eq!(back, offset);
+        } else {
+            // Inside a character (or a `\r\n` pair): lands on an earlier char boundary.
+            prop_assert!(back < offset && text.is_char_boundary(back));
+        }
+    }
+}
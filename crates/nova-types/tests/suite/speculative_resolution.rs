@@ -0,0 +1,154 @@
+use nova_types::{
+    resolve_method_call, CallKind, ClassDef, ClassId, MethodCall, MethodResolution, TyContext,
+    Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+/// `class Builder { Builder bar(); Builder baz(); }`
+fn add_builder(env: &mut TypeStore) -> ClassId {
+    let object = env.well_known().object;
+    let builder = env.intern_class_id("com.example.Builder");
+    let self_ty = Type::class(builder, vec![]);
+    env.define_class(
+        builder,
+        ClassDef {
+            methods: vec![
+                support::method("bar", vec![], self_ty.clone()),
+                support::method("baz", vec![], self_ty),
+            ],
+            ..support::class("com.example.Builder", Some(Type::class(object, vec![])))
+        },
+    );
+    builder
+}
+
+fn call_on<'a>(receiver: Type, name: &'a str) -> MethodCall<'a> {
+    MethodCall {
+        receiver,
+        call_kind: CallKind::Instance,
+        name,
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    }
+}
+
+#[test]
+fn builder_chain_after_unresolvable_call_resolves_speculatively() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let builder = add_builder(&mut env);
+
+    // `foo().bar().baz()` where `foo()` failed to resolve.
+    let mut ctx = TyContext::new(&env).with_errorish_receiver_recovery(vec![]);
+    let MethodResolution::Found(bar) =
+        resolve_method_call(&mut ctx, &call_on(Type::Unknown, "bar"))
+    else {
+        panic!("expected speculative resolution of bar()");
+    };
+    assert!(bar.speculative);
+    assert_eq!(bar.owner, builder);
+
+    // The rest of the chain resolves normally against bar()'s return type...
+    let MethodResolution::Found(baz) =
+        resolve_method_call(&mut ctx, &call_on(bar.return_type.clone(), "baz"))
+    else {
+        panic!("expected baz() to resolve on Builder");
+    };
+    assert!(!baz.speculative);
+
+    // ...and completion-style resolution of `baz` on an errorish receiver is speculative too.
+    let MethodResolution::Found(baz) = resolve_method_call(&mut ctx, &call_on(Type::Error, "baz"))
+    else {
+        panic!("expected speculative resolution of baz()");
+    };
+    assert!(baz.speculative);
+    assert_eq!(baz.owner, builder);
+}
+
+#[test]
+fn recovery_is_disabled_by_default() {
+    let mut env = TypeStore::with_minimal_jdk();
+    add_builder(&mut env);
+
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call_on(Type::Unknown, "bar")),
+        MethodResolution::NotFound(_)
+    ));
+}
+
+#[test]
+fn expected_return_and_likely_receivers_are_searched_first() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let builder = add_builder(&mut env);
+    let object = env.well_known().object;
+    // A second class also declaring `bar()`, registered after `Builder`.
+    let other = env.add_class(ClassDef {
+        methods: vec![support::method("bar", vec![], Type::Void)],
+        ..support::class("com.example.Other", Some(Type::class(object, vec![])))
+    });
+
+    let mut ctx =
+        TyContext::new(&env).with_errorish_receiver_recovery(vec![Type::class(other, vec![])]);
+    let MethodResolution::Found(found) =
+        resolve_method_call(&mut ctx, &call_on(Type::Unknown, "bar"))
+    else {
+        panic!("expected speculative resolution via likely receivers");
+    };
+    assert!(found.speculative);
+    assert_eq!(found.owner, other);
+
+    let call = MethodCall {
+        expected_return: Some(Type::class(builder, vec![])),
+        ..call_on(Type::Unknown, "bar")
+    };
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected speculative resolution via the expected return type");
+    };
+    assert!(found.speculative);
+    assert_eq!(found.owner, builder);
+}
+
+#[test]
+fn classes_declaring_method_follows_store_updates() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let builder = add_builder(&mut env);
+    assert_eq!(env.classes_declaring_method("bar", 10), vec![builder]);
+    assert_eq!(env.classes_declaring_method("missing", 10), vec![]);
+
+    // Saving the class again without `bar` drops it from the index.
+    let mut def = env.class(builder).unwrap().clone();
+    def.methods.retain(|method| method.name != "bar");
+    env.upsert_class(def);
+    assert_eq!(env.classes_declaring_method("bar", 10), vec![]);
+    assert_eq!(env.classes_declaring_method("baz", 10), vec![builder]);
+
+    // Methods added in place are found too, in id order and up to the limit.
+    let object = env.well_known().object;
+    env.class_mut(object)
+        .unwrap()
+        .methods
+        .push(support::method("baz", vec![], Type::Void));
+    assert_eq!(
+        env.classes_declaring_method("baz", 10),
+        vec![object, builder]
+    );
+    assert_eq!(env.classes_declaring_method("baz", 1), vec![object]);
+
+    let json = serde_json::to_string(&env).unwrap();
+    let restored: TypeStore = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        restored.classes_declaring_method("baz", 10),
+        vec![object, builder]
+    );
+
+    env.remove_class("com.example.Builder");
+    assert_eq!(env.classes_declaring_method("baz", 10), vec![object]);
+}
@@ -0,0 +1,40 @@
+//! Shared [`ClassDef`] and [`MethodDef`] fixtures.
+//!
+//! The builders cover the common case; tests override the remaining fields with struct update
+//! syntax, e.g. `MethodDef { is_static: true, ..method("f", vec![], Type::Void) }`.
+
+use nova_types::{ClassDef, ClassKind, MethodDef, Type};
+
+/// A public, concrete instance method without type parameters or parameter names.
+pub(crate) fn method(name: &str, params: Vec<Type>, return_type: Type) -> MethodDef {
+    MethodDef {
+        name: name.to_string(),
+        type_params: vec![],
+        params,
+        param_names: vec![],
+        return_type,
+        is_static: false,
+        is_varargs: false,
+        is_abstract: false,
+        is_private: false,
+        deprecation: None,
+    }
+}
+
+/// A concrete, non-generic top-level class without members.
+pub(crate) fn class(name: &str, super_class: Option<Type>) -> ClassDef {
+    ClassDef {
+        name: name.to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![],
+        super_class,
+        interfaces: vec![],
+        fields: vec![],
+        constructors: vec![],
+        methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    }
+}
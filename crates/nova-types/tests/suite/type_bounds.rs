@@ -0,0 +1,87 @@
+use nova_types::{
+    lower_bound_of_type, upper_bound_of_type, PrimitiveType, Type, TypeEnv, TypeParamDef,
+    TypeStore, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn wildcard_bounds() {
+    let env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `?`
+    let unbounded = Type::Wildcard(WildcardBound::Unbounded);
+    assert_eq!(upper_bound_of_type(&env, &unbounded), object);
+    assert_eq!(lower_bound_of_type(&env, &unbounded), None);
+
+    // `? extends String`
+    let extends = Type::Wildcard(WildcardBound::Extends(Box::new(string.clone())));
+    assert_eq!(upper_bound_of_type(&env, &extends), string);
+    assert_eq!(lower_bound_of_type(&env, &extends), None);
+
+    // `? super String`
+    let sup = Type::Wildcard(WildcardBound::Super(Box::new(string.clone())));
+    assert_eq!(upper_bound_of_type(&env, &sup), object);
+    assert_eq!(lower_bound_of_type(&env, &sup), Some(string));
+}
+
+#[test]
+fn type_variable_bounds() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let serializable = Type::class(env.class_id("java.io.Serializable").unwrap(), vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let t = env.add_type_param("T", vec![]);
+    assert_eq!(upper_bound_of_type(&env, &Type::TypeVar(t)), object);
+
+    let u = env.add_type_param("U", vec![number.clone()]);
+    assert_eq!(upper_bound_of_type(&env, &Type::TypeVar(u)), number);
+    assert_eq!(lower_bound_of_type(&env, &Type::TypeVar(u)), None);
+
+    // `V extends Number & Serializable`
+    let v = env.add_type_param("V", vec![number.clone(), serializable.clone()]);
+    let Type::Intersection(parts) = upper_bound_of_type(&env, &Type::TypeVar(v)) else {
+        panic!("expected an intersection bound");
+    };
+    assert_eq!(parts.len(), 2);
+    assert!(parts.contains(&number) && parts.contains(&serializable));
+
+    // A captured `? super Integer`.
+    let cap = env.add_type_param("CAP#1", vec![object.clone()]);
+    env.define_type_param(
+        cap,
+        TypeParamDef {
+            name: "CAP#1".to_string(),
+            upper_bounds: vec![object.clone()],
+            lower_bound: Some(integer.clone()),
+            declared_by: None,
+        },
+    );
+    assert_eq!(upper_bound_of_type(&env, &Type::TypeVar(cap)), object);
+    assert_eq!(
+        lower_bound_of_type(&env, &Type::TypeVar(cap)),
+        Some(integer)
+    );
+}
+
+#[test]
+fn other_types_are_their_own_upper_bound() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+
+    for ty in [
+        string.clone(),
+        Type::class(list, vec![string.clone()]),
+        Type::Array(Box::new(string)),
+        Type::Primitive(PrimitiveType::Int),
+        Type::Null,
+    ] {
+        assert_eq!(upper_bound_of_type(&env, &ty), ty);
+        assert_eq!(lower_bound_of_type(&env, &ty), None);
+    }
+}
@@ -0,0 +1,105 @@
+use nova_types::{satisfies_constraint, Type, TypeConstraint, TypeStore, WildcardBound};
+
+struct Fixture {
+    env: TypeStore,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            env: TypeStore::with_minimal_jdk(),
+        }
+    }
+
+    fn class(&self, name: &str, args: Vec<Type>) -> Type {
+        Type::class(self.env.class_id(name).unwrap(), args)
+    }
+
+    fn satisfies(&self, ty: &Type, constraint: TypeConstraint) -> bool {
+        satisfies_constraint(&self.env, ty, &constraint)
+    }
+}
+
+fn extends(ty: &Type) -> Type {
+    Type::Wildcard(WildcardBound::Extends(Box::new(ty.clone())))
+}
+
+fn super_(ty: &Type) -> Type {
+    Type::Wildcard(WildcardBound::Super(Box::new(ty.clone())))
+}
+
+#[test]
+fn upper_bound_constraints() {
+    let f = Fixture::new();
+    let integer = f.class("java.lang.Integer", vec![]);
+    let number = f.class("java.lang.Number", vec![]);
+    let string = f.class("java.lang.String", vec![]);
+
+    assert!(f.satisfies(&integer, TypeConstraint::IsSubtypeOf(number.clone())));
+    assert!(!f.satisfies(&number, TypeConstraint::IsSubtypeOf(integer.clone())));
+    assert!(!f.satisfies(&string, TypeConstraint::IsSubtypeOf(number.clone())));
+
+    // Wildcards on either side compare by containment.
+    assert!(f.satisfies(&integer, TypeConstraint::IsSubtypeOf(extends(&number))));
+    assert!(f.satisfies(
+        &extends(&integer),
+        TypeConstraint::IsSubtypeOf(extends(&number))
+    ));
+    assert!(!f.satisfies(
+        &super_(&integer),
+        TypeConstraint::IsSubtypeOf(extends(&number))
+    ));
+    assert!(f.satisfies(&extends(&integer), TypeConstraint::IsSubtypeOf(number)));
+}
+
+#[test]
+fn lower_bound_constraints() {
+    let f = Fixture::new();
+    let integer = f.class("java.lang.Integer", vec![]);
+    let number = f.class("java.lang.Number", vec![]);
+    let object = f.class("java.lang.Object", vec![]);
+
+    assert!(f.satisfies(&number, TypeConstraint::IsSupertypeOf(integer.clone())));
+    assert!(!f.satisfies(&integer, TypeConstraint::IsSupertypeOf(number.clone())));
+    assert!(f.satisfies(&object, TypeConstraint::IsSupertypeOf(Type::Null)));
+
+    assert!(f.satisfies(
+        &super_(&integer),
+        TypeConstraint::IsSupertypeOf(number.clone())
+    ));
+    assert!(f.satisfies(
+        &super_(&integer),
+        TypeConstraint::IsSupertypeOf(super_(&number))
+    ));
+    assert!(!f.satisfies(
+        &super_(&number),
+        TypeConstraint::IsSupertypeOf(super_(&integer))
+    ));
+}
+
+#[test]
+fn equality_constraints() {
+    let f = Fixture::new();
+    let integer = f.class("java.lang.Integer", vec![]);
+    let number = f.class("java.lang.Number", vec![]);
+    let object = f.class("java.lang.Object", vec![]);
+    let list_of = |arg: Type| f.class("java.util.List", vec![arg]);
+
+    assert!(f.satisfies(&integer, TypeConstraint::Equals(integer.clone())));
+    assert!(!f.satisfies(&integer, TypeConstraint::Equals(number.clone())));
+    assert!(!f.satisfies(&Type::int(), TypeConstraint::Equals(integer.clone())));
+    assert!(!f.satisfies(
+        &list_of(integer.clone()),
+        TypeConstraint::Equals(list_of(number.clone()))
+    ));
+
+    // `?` and `? extends Object` are two spellings of the same wildcard.
+    let unbounded = Type::Wildcard(WildcardBound::Unbounded);
+    assert!(f.satisfies(&unbounded, TypeConstraint::Equals(extends(&object))));
+    assert!(f.satisfies(
+        &list_of(unbounded),
+        TypeConstraint::Equals(list_of(extends(&object)))
+    ));
+    assert!(!f.satisfies(&extends(&integer), TypeConstraint::Equals(extends(&number))));
+    assert!(!f.satisfies(&super_(&integer), TypeConstraint::Equals(extends(&integer))));
+}
@@ -0,0 +1,119 @@
+use nova_types::{type_diff, Type, TypeDiffEntry, TypeEnv, TypePathStep, TypeStore, WildcardBound};
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn nested_generic_points_at_inner_type_arg() {
+    let env = TypeStore::with_minimal_jdk();
+    let map = env.class_id("java.util.Map").unwrap();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let expected = Type::class(
+        map,
+        vec![string.clone(), Type::class(list, vec![integer.clone()])],
+    );
+    let actual = Type::class(
+        map,
+        vec![string.clone(), Type::class(list, vec![string.clone()])],
+    );
+
+    assert_eq!(
+        type_diff(&env, &expected, &actual),
+        vec![TypeDiffEntry {
+            path: vec![TypePathStep::TypeArg(1), TypePathStep::TypeArg(0)],
+            expected: integer,
+            actual: string,
+        }]
+    );
+}
+
+#[test]
+fn every_diverging_branch_is_reported() {
+    let env = TypeStore::with_minimal_jdk();
+    let map = env.class_id("java.util.Map").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let long = Type::class(env.class_id("java.lang.Long").unwrap(), vec![]);
+
+    let expected = Type::class(map, vec![string.clone(), integer.clone()]);
+    let actual = Type::class(map, vec![integer.clone(), long.clone()]);
+    let diff = type_diff(&env, &expected, &actual);
+    let paths: Vec<_> = diff.iter().map(|entry| entry.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            vec![TypePathStep::TypeArg(0)],
+            vec![TypePathStep::TypeArg(1)]
+        ]
+    );
+    assert_eq!(diff[1].expected, integer);
+    assert_eq!(diff[1].actual, long);
+}
+
+#[test]
+fn subtype_is_viewed_as_expected_class() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    let expected = Type::class(list, vec![string.clone()]);
+    let actual = Type::class(array_list, vec![integer.clone()]);
+    assert_eq!(
+        type_diff(&env, &expected, &actual),
+        vec![TypeDiffEntry {
+            path: vec![TypePathStep::TypeArg(0)],
+            expected: string.clone(),
+            actual: integer,
+        }]
+    );
+
+    // Assignable types have no diff.
+    let ok = Type::class(array_list, vec![string]);
+    assert!(type_diff(&env, &expected, &ok).is_empty());
+}
+
+#[test]
+fn arrays_wildcards_and_unrelated_types() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+
+    // `List<String>[]` vs `List<Integer>[]`
+    let expected = Type::Array(Box::new(Type::class(list, vec![string.clone()])));
+    let actual = Type::Array(Box::new(Type::class(list, vec![integer.clone()])));
+    let diff = type_diff(&env, &expected, &actual);
+    assert_eq!(
+        diff[0].path,
+        vec![TypePathStep::ArrayElement, TypePathStep::TypeArg(0)]
+    );
+
+    // A wildcard that does not contain the argument is reported as a whole.
+    let extends_number = Type::Wildcard(WildcardBound::Extends(Box::new(number)));
+    let expected = Type::class(list, vec![extends_number.clone()]);
+    let actual = Type::class(list, vec![string.clone()]);
+    assert_eq!(
+        type_diff(&env, &expected, &actual),
+        vec![TypeDiffEntry {
+            path: vec![TypePathStep::TypeArg(0)],
+            expected: extends_number,
+            actual: string.clone(),
+        }]
+    );
+
+    // Unrelated classes diverge at the root.
+    let expected = Type::class(list, vec![string.clone()]);
+    assert_eq!(
+        type_diff(&env, &expected, &integer),
+        vec![TypeDiffEntry {
+            path: vec![],
+            expected,
+            actual: integer,
+        }]
+    );
+}
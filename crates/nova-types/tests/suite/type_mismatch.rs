@@ -0,0 +1,98 @@
+use nova_types::{
+    describe_type_mismatch, PrimitiveType, Type, TypeMismatchDescription, TypeMismatchKind,
+    TypeStore, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+fn kind(env: &TypeStore, from: &Type, to: &Type) -> TypeMismatchKind {
+    describe_type_mismatch(env, from, to).kind
+}
+
+#[test]
+fn primitive_narrowing() {
+    let env = TypeStore::with_minimal_jdk();
+    let long = Type::Primitive(PrimitiveType::Long);
+
+    assert_eq!(
+        describe_type_mismatch(&env, &long, &Type::int()),
+        TypeMismatchDescription {
+            from: long,
+            to: Type::int(),
+            kind: TypeMismatchKind::PrimitiveNarrowing,
+        }
+    );
+    assert_eq!(
+        kind(&env, &Type::boolean(), &Type::int()),
+        TypeMismatchKind::PrimitiveNarrowing
+    );
+}
+
+#[test]
+fn reference_not_subtype() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let integer = Type::class(env.class_id("java.lang.Integer").unwrap(), vec![]);
+
+    assert_eq!(
+        kind(&env, &string, &integer),
+        TypeMismatchKind::ReferenceNotSubtype
+    );
+    assert_eq!(
+        kind(&env, &Type::int(), &string),
+        TypeMismatchKind::ReferenceNotSubtype
+    );
+}
+
+#[test]
+fn void_not_allowed() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+
+    assert_eq!(
+        kind(&env, &Type::Void, &string),
+        TypeMismatchKind::VoidNotAllowed
+    );
+    assert_eq!(
+        kind(&env, &string, &Type::Void),
+        TypeMismatchKind::VoidNotAllowed
+    );
+}
+
+#[test]
+fn wildcard_incompatible() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let string = Type::class(env.class_id("java.lang.String").unwrap(), vec![]);
+    let list_of_any = Type::class(list, vec![Type::Wildcard(WildcardBound::Unbounded)]);
+    let list_of_string = Type::class(list, vec![string.clone()]);
+
+    assert_eq!(
+        kind(&env, &list_of_any, &list_of_string),
+        TypeMismatchKind::WildcardIncompatible
+    );
+
+    // `List<String>` to `List<? super Integer>`.
+    let integer = Type::class(env.class_id("java.lang.Integer").unwrap(), vec![]);
+    let list_super_integer = Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Super(Box::new(integer)))],
+    );
+    assert_eq!(
+        kind(&env, &list_of_string, &list_super_integer),
+        TypeMismatchKind::WildcardIncompatible
+    );
+}
+
+#[test]
+fn intersection_part_failed() {
+    let env = TypeStore::with_minimal_jdk();
+    let integer = Type::class(env.class_id("java.lang.Integer").unwrap(), vec![]);
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let cloneable = Type::class(env.class_id("java.lang.Cloneable").unwrap(), vec![]);
+
+    assert_eq!(
+        kind(&env, &integer, &Type::Intersection(vec![number, cloneable])),
+        TypeMismatchKind::IntersectionPartFailed { index: 1 }
+    );
+}
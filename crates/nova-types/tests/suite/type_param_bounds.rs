@@ -0,0 +1,102 @@
+use nova_types::{is_subtype, lub, Type, TypeEnv, TypeParamBoundError, TypeParamDef, TypeStore};
+
+use pretty_assertions::assert_eq;
+
+fn def(name: &str, upper_bounds: Vec<Type>) -> TypeParamDef {
+    TypeParamDef {
+        name: name.to_string(),
+        upper_bounds,
+        lower_bound: None,
+        declared_by: None,
+    }
+}
+
+#[test]
+fn f_bound_through_generic_class_is_allowed() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+
+    // `T extends List<T>` (the same shape as `T extends Comparable<T>`).
+    let t = env.add_type_param("T", vec![object]);
+    let bound = Type::class(list, vec![Type::TypeVar(t)]);
+    env.try_define_type_param(t, def("T", vec![bound.clone()]))
+        .expect("F-bounds are valid");
+    assert_eq!(env.type_param(t).unwrap().upper_bounds, vec![bound]);
+}
+
+#[test]
+fn direct_self_bound_is_rejected() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `T extends T`
+    let t = env.add_type_param("T", vec![object.clone()]);
+    assert_eq!(
+        env.try_define_type_param(t, def("T", vec![Type::TypeVar(t)])),
+        Err(TypeParamBoundError::SelfBound { param: t })
+    );
+    // The previous definition is kept.
+    assert_eq!(env.type_param(t).unwrap().upper_bounds, vec![object]);
+}
+
+#[test]
+fn mutual_cycle_is_rejected() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+
+    // `<T extends U, U extends V, V extends T>`
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object.clone()]);
+    let v = env.add_type_param("V", vec![object]);
+    env.try_define_type_param(t, def("T", vec![Type::TypeVar(u)]))
+        .unwrap();
+    env.try_define_type_param(u, def("U", vec![Type::TypeVar(v)]))
+        .unwrap();
+    assert_eq!(
+        env.try_define_type_param(v, def("V", vec![Type::TypeVar(t)])),
+        Err(TypeParamBoundError::Cycle {
+            params: vec![v, t, u]
+        })
+    );
+}
+
+#[test]
+fn chained_bounds_without_cycle_are_allowed() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let cloneable = Type::class(env.well_known().cloneable, vec![]);
+
+    // `<T extends U, U extends Cloneable>` with `T` also in an intersection bound.
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object]);
+    env.try_define_type_param(u, def("U", vec![cloneable.clone()]))
+        .unwrap();
+    env.try_define_type_param(
+        t,
+        def(
+            "T",
+            vec![Type::Intersection(vec![Type::TypeVar(u), cloneable])],
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn cyclic_bounds_lowered_from_source_are_tolerated() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `<T extends T, U extends U[]>`, which front-ends define without validation.
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object.clone()]);
+    env.define_type_param(t, def("T", vec![Type::TypeVar(t)]));
+    env.define_type_param(u, def("U", vec![Type::Array(Box::new(Type::TypeVar(u)))]));
+
+    for var in [Type::TypeVar(t), Type::TypeVar(u)] {
+        assert!(is_subtype(&env, &var, &object));
+        assert!(!is_subtype(&env, &var, &string));
+        assert_eq!(lub(&env, &var, &string), object);
+    }
+}
@@ -0,0 +1,103 @@
+use nova_types::{ClassDef, CompactionReport, Type, TypeEnv, TypeStore, TypeVarId};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+#[test]
+fn removed_type_params_are_not_found() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let kept = env.add_type_param("T", vec![object.clone()]);
+
+    let mark = TypeVarId(env.type_param_count() as u32);
+    let temps: Vec<TypeVarId> = (0..3)
+        .map(|idx| env.add_type_param(format!("CAP#{idx}"), vec![object.clone()]))
+        .collect();
+    let end = TypeVarId(env.type_param_count() as u32);
+
+    env.remove_type_param_range(mark, end);
+    for id in temps {
+        assert!(env.type_param(id).is_none(), "{id:?} should be removed");
+    }
+    assert_eq!(env.type_param(kept).unwrap().name, "T");
+    assert_eq!(env.type_param(kept).unwrap().upper_bounds, vec![object]);
+}
+
+#[test]
+fn removed_ids_are_never_reallocated() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let first = env.add_type_param("A", vec![]);
+    let second = env.add_type_param("B", vec![]);
+    let count = env.type_param_count();
+
+    env.remove_type_param_range(first, TypeVarId(second.0 + 1));
+    assert_eq!(env.type_param_count(), count);
+
+    let fresh = env.add_type_param("C", vec![]);
+    assert_ne!(fresh, first);
+    assert_ne!(fresh, second);
+    assert_eq!(fresh.0 as usize, count);
+    assert_eq!(env.type_param(fresh).unwrap().name, "C");
+    assert!(env.type_param(first).is_none());
+}
+
+#[test]
+fn range_is_half_open_and_clamped() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let a = env.add_type_param("A", vec![]);
+    let b = env.add_type_param("B", vec![]);
+
+    // An empty range removes nothing.
+    env.remove_type_param_range(a, a);
+    assert!(env.type_param(a).is_some());
+
+    // `to` is exclusive; out-of-range ends are ignored.
+    env.remove_type_param_range(b, TypeVarId(u32::MAX));
+    assert!(env.type_param(a).is_some());
+    assert!(env.type_param(b).is_none());
+    assert_eq!(env.type_param_count(), b.0 as usize + 1);
+}
+
+fn generic_class(name: &str, type_params: Vec<TypeVarId>) -> ClassDef {
+    ClassDef {
+        type_params,
+        ..support::class(name, None)
+    }
+}
+
+#[test]
+fn compact_reclaims_type_params_of_removed_and_redefined_classes() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let a = env.add_type_param("A", vec![]);
+    let b = env.add_type_param("B", vec![]);
+    env.add_class(generic_class("com.example.Pair", vec![a, b]));
+    let t = env.add_type_param("T", vec![]);
+    env.add_class(generic_class("com.example.Box", vec![t]));
+    let free = env.add_type_param("F", vec![]);
+
+    // Nothing is released while the classes are live.
+    assert_eq!(env.compact(), CompactionReport::default());
+
+    env.remove_class("com.example.Pair");
+    let u = env.add_type_param("U", vec![]);
+    env.upsert_class(generic_class("com.example.Box", vec![u]));
+    let count = env.type_param_count();
+
+    assert_eq!(
+        env.compact(),
+        CompactionReport {
+            reclaimed_type_params: 3
+        }
+    );
+    for id in [a, b, t] {
+        assert!(env.type_param(id).is_none(), "{id:?} should be reclaimed");
+    }
+    assert_eq!(env.type_param(u).unwrap().name, "U");
+    // Not attributed to any class.
+    assert_eq!(env.type_param(free).unwrap().name, "F");
+    // Ids are not reused.
+    assert_eq!(env.type_param_count(), count);
+
+    assert_eq!(env.compact().reclaimed_type_params, 0);
+}
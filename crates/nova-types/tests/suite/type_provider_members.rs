@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use nova_types::{ChainTypeProvider, FieldStub, MethodStub, TypeDefStub, TypeProvider};
+
+/// Implements only `lookup_type`, so `members`, `methods` and `fields` are the trait defaults.
+#[derive(Default)]
+struct StubProvider {
+    stubs: HashMap<String, TypeDefStub>,
+}
+
+impl StubProvider {
+    fn with(stub: TypeDefStub) -> Self {
+        let mut stubs = HashMap::new();
+        stubs.insert(stub.binary_name.clone(), stub);
+        Self { stubs }
+    }
+}
+
+impl TypeProvider for StubProvider {
+    fn lookup_type(&self, binary_name: &str) -> Option<TypeDefStub> {
+        self.stubs.get(binary_name).cloned()
+    }
+}
+
+fn stub(fields: Vec<FieldStub>, methods: Vec<MethodStub>) -> TypeDefStub {
+    TypeDefStub {
+        binary_name: "com.example.Foo".to_string(),
+        access_flags: 0,
+        super_binary_name: Some("java.lang.Object".to_string()),
+        interfaces: vec![],
+        signature: None,
+        fields,
+        methods,
+        deprecation: None,
+        inner_class: None,
+    }
+}
+
+fn field(name: &str) -> FieldStub {
+    FieldStub {
+        name: name.to_string(),
+        descriptor: "I".to_string(),
+        signature: None,
+        access_flags: 0,
+        deprecation: None,
+    }
+}
+
+fn method(name: &str) -> MethodStub {
+    MethodStub {
+        name: name.to_string(),
+        descriptor: "()V".to_string(),
+        signature: None,
+        access_flags: 0,
+        deprecation: None,
+    }
+}
+
+#[test]
+fn default_methods_and_fields_split_members() {
+    let provider = StubProvider::with(stub(vec![field("count")], vec![method("run")]));
+
+    assert_eq!(provider.methods("com.example.Foo"), vec![method("run")]);
+    assert_eq!(provider.fields("com.example.Foo"), vec![field("count")]);
+    assert!(provider.methods("com.example.Missing").is_empty());
+    assert!(provider.fields("com.example.Missing").is_empty());
+}
+
+#[test]
+fn chain_delegates_methods_and_fields_per_kind() {
+    // The first provider only knows fields of `Foo` and the second only its methods. Filtering
+    // the chain's `members` would stop at the first provider and find no methods.
+    let fields_only = StubProvider::with(stub(vec![field("count")], vec![]));
+    let methods_only = StubProvider::with(stub(vec![], vec![method("run")]));
+    let chain = ChainTypeProvider::new(vec![&fields_only, &methods_only]);
+
+    assert_eq!(chain.members("com.example.Foo").len(), 1);
+    assert_eq!(chain.methods("com.example.Foo"), vec![method("run")]);
+    assert_eq!(chain.fields("com.example.Foo"), vec![field("count")]);
+}
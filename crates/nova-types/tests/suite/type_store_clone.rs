@@ -25,11 +25,18 @@ fn type_store_clone_preserves_ids_and_is_independent() {
             name: "foo".to_string(),
             type_params: vec![],
             params: vec![Type::Primitive(PrimitiveType::Int)],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     };
     let foo_id = store.upsert_class(foo_def.clone());
 
@@ -45,11 +52,18 @@ fn type_store_clone_preserves_ids_and_is_independent() {
             name: "bar".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Primitive(PrimitiveType::Boolean),
             is_static: false,
             is_varargs: false,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     };
     let bar_id = store.upsert_class(bar_def.clone());
 
@@ -202,6 +216,10 @@ fn default_type_store_can_be_cloned_and_mutated_independently() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
     assert_eq!(cloned.lookup_class("com.example.Foo"), Some(foo_id));
     assert_eq!(store.lookup_class("com.example.Foo"), None);
@@ -0,0 +1,211 @@
+use nova_types::{
+    is_subtype, rebase_type_vars, ClassDef, ClassKind, Deprecation, FieldDef, Type, TypeEnv,
+    TypeParamDef, TypeStore, TypeVarId, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+#[test]
+fn imported_type_params_get_distinct_ids() {
+    let mut first = TypeStore::with_minimal_jdk();
+    let first_t = first.add_type_param("T", vec![]);
+
+    let mut second = TypeStore::with_minimal_jdk();
+    let list = second.class_id("java.util.List").unwrap();
+    let second_t = second.add_type_param("T", vec![]);
+    let second_u =
+        second.add_type_param("U", vec![Type::class(list, vec![Type::TypeVar(second_t)])]);
+    assert_eq!(first_t, second_t);
+
+    let before = first.type_param_count();
+    let offset = first.import_type_params(&second);
+    assert_eq!(offset as usize, before);
+    let imported_t = match rebase_type_vars(&Type::TypeVar(second_t), offset) {
+        Type::TypeVar(id) => id,
+        other => panic!("expected a type variable, got {other:?}"),
+    };
+    assert_ne!(imported_t, first_t);
+    assert_eq!(first.type_param(imported_t).unwrap().name, "T");
+    assert_eq!(first.type_param(first_t).unwrap().name, "T");
+    assert_eq!(first.type_param_count(), before + second.type_param_count());
+
+    // Bounds referring to the imported store's own variables are rebased too.
+    let imported_u = TypeVarId(second_u.0 + offset);
+    assert_eq!(
+        first.type_param(imported_u).unwrap().upper_bounds,
+        vec![Type::class(list, vec![Type::TypeVar(imported_t)])]
+    );
+}
+
+#[test]
+fn rebase_reaches_nested_type_vars() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let t = Type::TypeVar(TypeVarId(0));
+    let ty = Type::Intersection(vec![
+        Type::Array(Box::new(t.clone())),
+        Type::class(
+            list,
+            vec![Type::Wildcard(WildcardBound::Super(Box::new(t.clone())))],
+        ),
+    ]);
+
+    let shifted = Type::TypeVar(TypeVarId(5));
+    assert_eq!(
+        rebase_type_vars(&ty, 5),
+        Type::Intersection(vec![
+            Type::Array(Box::new(shifted.clone())),
+            Type::class(
+                list,
+                vec![Type::Wildcard(WildcardBound::Super(Box::new(shifted)))],
+            ),
+        ])
+    );
+}
+
+fn class(name: &str, type_params: Vec<TypeVarId>, super_class: Type) -> ClassDef {
+    ClassDef {
+        type_params,
+        ..support::class(name, Some(super_class))
+    }
+}
+
+#[test]
+fn merge_remaps_class_and_type_var_references() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let box_t = base.add_type_param("T", vec![]);
+    let boxed = base.add_class(class("com.example.Box", vec![box_t], object.clone()));
+    base.add_class(class("com.example.Shared", vec![], object));
+
+    let mut other = TypeStore::with_minimal_jdk();
+    let other_object = Type::class(other.well_known().object, vec![]);
+    let other_string = Type::class(other.well_known().string, vec![]);
+    let other_box = other.intern_class_id("com.example.Box");
+    let node = other.intern_class_id("com.example.Node");
+    let e = other.add_type_param("E", vec![]);
+    let mut node_def = class(
+        "com.example.Node",
+        vec![e],
+        Type::class(other_box, vec![other_string]),
+    );
+    node_def.fields.push(FieldDef {
+        name: "next".to_string(),
+        ty: Type::class(node, vec![Type::TypeVar(e)]),
+        is_static: false,
+        is_final: false,
+        deprecation: None,
+    });
+    other.define_class(node, node_def);
+    let other_shared = other.add_class(class("com.example.Shared", vec![], other_object));
+    other.class_mut(other_shared).unwrap().kind = ClassKind::Interface;
+
+    base.merge(other);
+
+    // `Box` was only interned by `other`, so the base definition survives.
+    assert_eq!(base.class_id("com.example.Box"), Some(boxed));
+    assert_eq!(base.class(boxed).unwrap().type_params, vec![box_t]);
+
+    // Conflicting definitions: `other` wins, keeping the base id.
+    let shared = base.class_id("com.example.Shared").unwrap();
+    assert_eq!(base.class(shared).unwrap().kind, ClassKind::Interface);
+
+    let node = base.class_id("com.example.Node").unwrap();
+    let node_def = base.class(node).unwrap();
+    let [e] = node_def.type_params[..] else {
+        panic!(
+            "expected one type parameter, got {:?}",
+            node_def.type_params
+        );
+    };
+    assert_ne!(e, box_t);
+    assert_eq!(base.type_param(e).unwrap().name, "E");
+    let string = Type::class(base.well_known().string, vec![]);
+    assert_eq!(
+        node_def.super_class,
+        Some(Type::class(boxed, vec![string.clone()]))
+    );
+    assert_eq!(
+        node_def.fields[0].ty,
+        Type::class(node, vec![Type::TypeVar(e)])
+    );
+    assert!(is_subtype(
+        &base,
+        &Type::class(node, vec![string.clone()]),
+        &Type::class(boxed, vec![string])
+    ));
+}
+
+#[test]
+fn merging_an_overlay_keeps_its_ids() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    let animal = base.add_class(class("com.example.Animal", vec![], object));
+
+    let mut overlay = TypeStore::overlay_for(&base);
+    let t = overlay.add_type_param("T", vec![Type::class(animal, vec![])]);
+    let dog = overlay.add_class(class(
+        "com.example.Dog",
+        vec![t],
+        Type::class(animal, vec![]),
+    ));
+    overlay.define_type_param(
+        t,
+        TypeParamDef {
+            name: "T".to_string(),
+            upper_bounds: vec![Type::class(animal, vec![])],
+            lower_bound: None,
+            declared_by: Some(dog),
+        },
+    );
+
+    base.merge(overlay);
+
+    assert_eq!(base.class_id("com.example.Dog"), Some(dog));
+    assert_eq!(base.class(dog).unwrap().type_params, vec![t]);
+    let param = base.type_param(t).unwrap();
+    assert_eq!(param.upper_bounds, vec![Type::class(animal, vec![])]);
+    assert_eq!(param.declared_by, Some(dog));
+    assert!(is_subtype(
+        &base,
+        &Type::class(dog, vec![Type::class(animal, vec![])]),
+        &Type::class(animal, vec![])
+    ));
+}
+
+#[test]
+fn merge_keeps_class_deprecation() {
+    let mut base = TypeStore::with_minimal_jdk();
+    let object = Type::class(base.well_known().object, vec![]);
+    base.add_class(class("com.example.Legacy", vec![], object.clone()));
+
+    let mut other = TypeStore::with_minimal_jdk();
+    let other_object = Type::class(other.well_known().object, vec![]);
+    let mut legacy = class("com.example.Legacy", vec![], other_object.clone());
+    legacy.deprecation = Some(Deprecation {
+        since: Some("9".to_string()),
+        for_removal: true,
+    });
+    other.add_class(legacy);
+    let mut old = class("com.example.Old", vec![], other_object);
+    old.deprecation = Some(Deprecation::default());
+    other.add_class(old);
+
+    base.merge(other);
+
+    let legacy = base.class_id("com.example.Legacy").unwrap();
+    assert_eq!(
+        base.class(legacy).unwrap().deprecation,
+        Some(Deprecation {
+            since: Some("9".to_string()),
+            for_removal: true,
+        })
+    );
+    let old = base.class_id("com.example.Old").unwrap();
+    assert_eq!(
+        base.class(old).unwrap().deprecation,
+        Some(Deprecation::default())
+    );
+}
@@ -0,0 +1,89 @@
+use nova_types::{
+    ClassDef, FieldDef, MethodContract, MethodContractKey, NullBehavior, Type, TypeEnv, TypeStore,
+    WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn round_trip(store: &TypeStore) -> TypeStore {
+    let json = serde_json::to_string(store).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn minimal_jdk_round_trips_with_stable_ids() {
+    let store = TypeStore::with_minimal_jdk();
+    let back = round_trip(&store);
+
+    let (wk, back_wk) = (store.well_known(), back.well_known());
+    assert_eq!(back_wk.object, wk.object);
+    assert_eq!(back_wk.string, wk.string);
+    assert_eq!(back_wk.integer, wk.integer);
+    assert_eq!(back_wk.cloneable, wk.cloneable);
+    assert_eq!(back_wk.serializable, wk.serializable);
+
+    assert_eq!(back.iter_classes().count(), store.iter_classes().count());
+    for (id, def) in store.iter_classes() {
+        assert_eq!(back.lookup_class(&def.name), Some(id), "{}", def.name);
+        assert_eq!(back.class(id).unwrap().type_params, def.type_params);
+        assert_eq!(back.class(id).unwrap().methods.len(), def.methods.len());
+    }
+    assert_eq!(back.type_param_count(), store.type_param_count());
+
+    // Serialization is deterministic.
+    assert_eq!(
+        serde_json::to_string(&back).unwrap(),
+        serde_json::to_string(&store).unwrap()
+    );
+}
+
+#[test]
+fn removed_classes_and_contracts_survive_round_trip() {
+    let mut store = TypeStore::with_minimal_jdk();
+    let object = Type::class(store.well_known().object, vec![]);
+    let list = store.class_id("java.util.List").unwrap();
+    let foo_def = ClassDef {
+        fields: vec![FieldDef {
+            name: "items".to_string(),
+            ty: Type::class(
+                list,
+                vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+                    object.clone(),
+                )))],
+            ),
+            is_static: false,
+            is_final: true,
+            deprecation: None,
+        }],
+        ..support::class("com.example.Foo", Some(object))
+    };
+    let foo = store.upsert_class(foo_def.clone());
+    let key = MethodContractKey::new("com.example.Assert", "notNull", &["java.lang.Object"]);
+    let contract = MethodContract::new(NullBehavior::RequireNonNull { param: 0 }, true);
+    store.register_method_contract(key.clone(), contract);
+    store.remove_class("com.example.Foo");
+
+    let mut back = round_trip(&store);
+    assert_eq!(back.lookup_class("com.example.Foo"), None);
+    assert_eq!(back.method_contract(&key), Some(contract));
+
+    // Re-inserting a removed class reuses its id.
+    assert_eq!(back.upsert_class(foo_def), foo);
+    let items = &back.class(foo).unwrap().fields[0].ty;
+    let json = serde_json::to_string(items).unwrap();
+    assert_eq!(&serde_json::from_str::<Type>(&json).unwrap(), items);
+}
+
+#[test]
+fn duplicate_class_names_are_rejected() {
+    let store = TypeStore::with_minimal_jdk();
+    let mut json: serde_json::Value = serde_json::to_value(&store).unwrap();
+    let classes = json["classes"].as_array_mut().unwrap();
+    let first = classes[0].clone();
+    classes.push(first);
+
+    let err = serde_json::from_value::<TypeStore>(json).unwrap_err();
+    assert!(err.to_string().contains("duplicate class name"), "{err}");
+}
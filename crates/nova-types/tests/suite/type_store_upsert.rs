@@ -1,4 +1,7 @@
-use nova_types::{ClassDef, ClassKind, MethodDef, PrimitiveType, Type, TypeEnv, TypeStore};
+use nova_types::{
+    ClassDef, ClassKind, Deprecation, FieldDef, MethodDef, PrimitiveType, Type, TypeEnv, TypeStore,
+    TypeVarId,
+};
 
 use pretty_assertions::assert_eq;
 
@@ -30,11 +33,18 @@ fn define_class_overwrites_placeholder() {
                 name: "m".to_string(),
                 type_params: vec![],
                 params: vec![Type::Primitive(PrimitiveType::Int)],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: false,
                 is_varargs: false,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             }],
+            enclosing: None,
+            is_static_nested: false,
+            is_abstract: false,
+            deprecation: None,
         },
     );
 
@@ -58,6 +68,10 @@ fn upsert_class_overwrites_without_changing_id() {
         fields: vec![],
         constructors: vec![],
         methods: vec![],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let second = store.upsert_class(ClassDef {
@@ -72,11 +86,18 @@ fn upsert_class_overwrites_without_changing_id() {
             name: "f".to_string(),
             type_params: vec![],
             params: vec![],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: false,
             is_varargs: false,
             is_abstract: true,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     assert_eq!(first, second);
@@ -85,3 +106,85 @@ fn upsert_class_overwrites_without_changing_id() {
     assert_eq!(def.methods.len(), 1);
     assert_eq!(def.methods[0].name, "f");
 }
+
+/// `class Box<T> { T value; T get(); }` with the given type parameter.
+fn generic_box(t: TypeVarId) -> ClassDef {
+    ClassDef {
+        name: "com.example.Box".to_string(),
+        kind: ClassKind::Class,
+        type_params: vec![t],
+        super_class: None,
+        interfaces: vec![],
+        fields: vec![FieldDef {
+            name: "value".to_string(),
+            ty: Type::TypeVar(t),
+            is_static: false,
+            is_final: false,
+            deprecation: None,
+        }],
+        constructors: vec![],
+        methods: vec![MethodDef {
+            name: "get".to_string(),
+            type_params: vec![],
+            params: vec![],
+            param_names: vec![],
+            return_type: Type::TypeVar(t),
+            is_static: false,
+            is_varargs: false,
+            is_abstract: false,
+            is_private: false,
+            deprecation: None,
+        }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
+    }
+}
+
+#[test]
+fn resaving_a_generic_class_keeps_its_type_param_ids() {
+    let mut store = TypeStore::with_minimal_jdk();
+    let object = Type::class(store.well_known().object, vec![]);
+    let string = Type::class(store.well_known().string, vec![]);
+    let t = store.add_type_param("T", vec![object]);
+    let id = store.upsert_class(generic_box(t));
+    let cached = Type::class(id, vec![Type::TypeVar(t)]);
+
+    // The file is saved again, with a new bound; the loader allocates fresh ids.
+    let resaved_t = store.add_type_param("T", vec![string.clone()]);
+    assert_eq!(store.upsert_class(generic_box(resaved_t)), id);
+
+    let def = store.class(id).unwrap();
+    assert_eq!(def.type_params, vec![t]);
+    assert_eq!(def.fields[0].ty, Type::TypeVar(t));
+    assert_eq!(def.methods[0].return_type, Type::TypeVar(t));
+    assert_eq!(store.type_param(t).unwrap().upper_bounds, vec![string]);
+    assert_eq!(
+        cached,
+        Type::class(id, vec![Type::TypeVar(def.type_params[0])])
+    );
+
+    // Renaming the parameter is a different declaration and gets the new id.
+    let u = store.add_type_param("U", vec![]);
+    store.upsert_class(generic_box(u));
+    assert_eq!(store.class(id).unwrap().type_params, vec![u]);
+}
+
+#[test]
+fn resaving_a_deprecated_generic_class_keeps_its_deprecation() {
+    let mut store = TypeStore::with_minimal_jdk();
+    let t = store.add_type_param("T", vec![]);
+    let mut def = generic_box(t);
+    def.deprecation = Some(Deprecation::default());
+    let id = store.upsert_class(def);
+
+    let resaved_t = store.add_type_param("T", vec![]);
+    let mut resaved = generic_box(resaved_t);
+    resaved.deprecation = Some(Deprecation::default());
+    assert_eq!(store.upsert_class(resaved), id);
+
+    let def = store.class(id).unwrap();
+    assert_eq!(def.type_params, vec![t]);
+    assert_eq!(def.deprecation, Some(Deprecation::default()));
+}
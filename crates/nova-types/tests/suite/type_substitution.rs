@@ -0,0 +1,206 @@
+use nova_types::{
+    compute_type_argument_substitution, infer_type_args_for_supertype, instantiate_supertype,
+    ClassDef, Type, TypeEnv, TypeStore, TypeSubstitution, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+#[test]
+fn apply_replaces_type_vars_structurally() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object]);
+
+    let subst = TypeSubstitution::single(t, string.clone());
+    assert_eq!(subst.get(t), Some(&string));
+    assert_eq!(subst.get(u), None);
+
+    // `List<? extends T>[]` -> `List<? extends String>[]`
+    let ty = Type::Array(Box::new(Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+            Type::TypeVar(t),
+        )))],
+    )));
+    let expected = Type::Array(Box::new(Type::class(
+        list,
+        vec![Type::Wildcard(WildcardBound::Extends(Box::new(
+            string.clone(),
+        )))],
+    )));
+    assert_eq!(subst.apply(&ty), expected);
+
+    // Unmapped variables are left alone.
+    assert_eq!(subst.apply(&Type::TypeVar(u)), Type::TypeVar(u));
+    assert_eq!(TypeSubstitution::empty().apply(&ty), ty);
+}
+
+#[test]
+fn from_class_def_matches_supertype_instantiation() {
+    let env = TypeStore::with_minimal_jdk();
+    let string = Type::class(env.well_known().string, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let array_list_def = env.class(array_list).unwrap();
+
+    let subst = TypeSubstitution::from_class_def(array_list_def, std::slice::from_ref(&string));
+    assert_eq!(subst.len(), 1);
+
+    // Substituting into `ArrayList`'s declared `List<E>` supertype agrees with
+    // `instantiate_supertype(ArrayList<String>, List)`.
+    let declared = array_list_def
+        .interfaces
+        .iter()
+        .find(|ty| matches!(ty, Type::Class(c) if c.def == list))
+        .expect("ArrayList implements List");
+    let Type::Class(applied) = subst.apply(declared) else {
+        panic!("expected a class type");
+    };
+    assert_eq!(
+        Some(applied.args),
+        instantiate_supertype(&env, &Type::class(array_list, vec![string]), list)
+    );
+
+    // Raw uses produce no mapping.
+    assert!(TypeSubstitution::from_class_def(array_list_def, &[]).is_empty());
+}
+
+#[test]
+fn compose_applies_left_then_right() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let t = env.add_type_param("T", vec![object.clone()]);
+    let u = env.add_type_param("U", vec![object.clone()]);
+    let v = env.add_type_param("V", vec![object]);
+
+    // `T -> List<U>` then `U -> String, V -> Integer`
+    let first = TypeSubstitution::single(t, Type::class(list, vec![Type::TypeVar(u)]));
+    let second: TypeSubstitution = [(u, string.clone()), (v, integer.clone())]
+        .into_iter()
+        .collect();
+    let composed = first.compose(&second);
+
+    assert_eq!(
+        composed.get(t),
+        Some(&Type::class(list, vec![string.clone()]))
+    );
+    assert_eq!(composed.get(u), Some(&string));
+    assert_eq!(composed.get(v), Some(&integer));
+
+    for ty in [Type::TypeVar(t), Type::TypeVar(u), Type::TypeVar(v)] {
+        assert_eq!(composed.apply(&ty), second.apply(&first.apply(&ty)));
+    }
+}
+
+#[test]
+fn compose_chains_are_associative() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let a = env.add_type_param("A", vec![object.clone()]);
+    let b = env.add_type_param("B", vec![object.clone()]);
+    let c = env.add_type_param("C", vec![object]);
+
+    let s1 = TypeSubstitution::single(a, Type::class(list, vec![Type::TypeVar(b)]));
+    let s2 = TypeSubstitution::single(b, Type::class(list, vec![Type::TypeVar(c)]));
+    let s3 = TypeSubstitution::single(c, string.clone());
+
+    let left = s1.compose(&s2).compose(&s3);
+    let right = s1.compose(&s2.compose(&s3));
+    assert_eq!(left, right);
+
+    let expected = Type::class(list, vec![Type::class(list, vec![string])]);
+    assert_eq!(left.apply(&Type::TypeVar(a)), expected);
+}
+
+#[test]
+fn supertype_type_args_are_keyed_by_the_supertype_params() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+    let array_list = env.class_id("java.util.ArrayList").unwrap();
+    let map = env.class_id("java.util.Map").unwrap();
+
+    // class HashMap<K, V> implements Map<K, V> {}
+    let k = env.add_type_param("K", vec![object.clone()]);
+    let v = env.add_type_param("V", vec![object.clone()]);
+    let hash_map = env.add_class(ClassDef {
+        type_params: vec![k, v],
+        interfaces: vec![Type::class(map, vec![Type::TypeVar(k), Type::TypeVar(v)])],
+        ..support::class("java.util.HashMap", Some(object))
+    });
+
+    // `ArrayList<String>` as `List` gives `{E -> String}`.
+    let list_e = env.class(list).unwrap().type_params[0];
+    let subst =
+        infer_type_args_for_supertype(&env, &Type::class(array_list, vec![string.clone()]), list)
+            .unwrap();
+    assert_eq!(subst, TypeSubstitution::single(list_e, string.clone()));
+
+    // `HashMap<String, Integer>` as `Map` gives `{K -> String, V -> Integer}`, keyed by `Map`'s
+    // own parameters rather than `HashMap`'s.
+    let map_params = env.class(map).unwrap().type_params.clone();
+    let subst = infer_type_args_for_supertype(
+        &env,
+        &Type::class(hash_map, vec![string.clone(), integer.clone()]),
+        map,
+    )
+    .unwrap();
+    let expected: TypeSubstitution = [(map_params[0], string.clone()), (map_params[1], integer)]
+        .into_iter()
+        .collect();
+    assert_eq!(subst, expected);
+
+    // Unrelated types have no view as `super_class`.
+    assert_eq!(infer_type_args_for_supertype(&env, &string, list), None);
+}
+
+#[test]
+fn explicit_type_arguments_instantiate_member_types() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+    let list = env.class_id("java.util.List").unwrap();
+
+    // class Box<T extends Number> { List<T> items(); }
+    let t = env.add_type_param("T", vec![number.clone()]);
+    let box_class = env.add_class(ClassDef {
+        type_params: vec![t],
+        methods: vec![support::method(
+            "items",
+            vec![],
+            Type::class(list, vec![Type::TypeVar(t)]),
+        )],
+        ..support::class("com.example.Box", Some(object))
+    });
+    let box_def = env.class(box_class).unwrap();
+    let items = &box_def.methods[0].return_type;
+
+    let subst = compute_type_argument_substitution(&env, box_def, std::slice::from_ref(&integer));
+    assert_eq!(subst, TypeSubstitution::single(t, integer.clone()));
+    assert_eq!(subst.apply(items), Type::class(list, vec![integer.clone()]));
+
+    // Raw and malformed instantiations leave the type variable in place.
+    for args in [vec![], vec![integer, string]] {
+        let subst = compute_type_argument_substitution(&env, box_def, &args);
+        assert!(subst.is_empty());
+        assert_eq!(subst.apply(items), *items);
+    }
+
+    // Seen through a raw `Box`, `T` is erased to its bound.
+    let raw = TypeSubstitution::for_raw(&env, box_def);
+    assert_eq!(raw.apply(items), Type::class(list, vec![number]));
+}
@@ -24,22 +24,32 @@ fn warns_for_non_reifiable_varargs_parameter_in_variable_arity_form() {
                 name: "m".to_string(),
                 type_params: vec![t],
                 params: vec![Type::Array(Box::new(Type::TypeVar(t)))],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: true,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
             // `void n(String... xs)`
             MethodDef {
                 name: "n".to_string(),
                 type_params: vec![],
                 params: vec![Type::Array(Box::new(Type::class(string, vec![])))],
+                param_names: vec![],
                 return_type: Type::Void,
                 is_static: true,
                 is_varargs: true,
                 is_abstract: false,
+                is_private: false,
+                deprecation: None,
             },
         ],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     // Variable-arity call (`m("a", "b")`).
@@ -50,6 +60,10 @@ fn warns_for_non_reifiable_varargs_parameter_in_variable_arity_form() {
         args: vec![Type::class(string, vec![]), Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -85,11 +99,18 @@ fn no_warning_for_reifiable_varargs_parameter_in_variable_arity_form() {
             name: "n".to_string(),
             type_params: vec![],
             params: vec![Type::Array(Box::new(Type::class(string, vec![])))],
+            param_names: vec![],
             return_type: Type::Void,
             is_static: true,
             is_varargs: true,
             is_abstract: false,
+            is_private: false,
+            deprecation: None,
         }],
+        enclosing: None,
+        is_static_nested: false,
+        is_abstract: false,
+        deprecation: None,
     });
 
     let call = MethodCall {
@@ -99,6 +120,10 @@ fn no_warning_for_reifiable_varargs_parameter_in_variable_arity_form() {
         args: vec![Type::class(string, vec![]), Type::class(string, vec![])],
         expected_return: None,
         explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
     };
 
     let mut ctx = TyContext::new(&env);
@@ -0,0 +1,132 @@
+use nova_types::{
+    enclosing_chain, resolve_unqualified_call, CallContext, CallScope, ClassDef, ClassId,
+    MethodDef, MethodResolution, PrimitiveType, StaticImport, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn method(name: &str, params: Vec<Type>, return_type: Type, is_static: bool) -> MethodDef {
+    MethodDef {
+        is_static,
+        ..support::method(name, params, return_type)
+    }
+}
+
+fn class(env: &TypeStore, name: &str, methods: Vec<MethodDef>) -> ClassDef {
+    ClassDef {
+        methods,
+        ..support::class(name, Some(Type::class(env.well_known().object, vec![])))
+    }
+}
+
+fn context(env: &TypeStore, class: ClassId) -> CallContext {
+    CallContext {
+        enclosing_class: class,
+        enclosing_instantiation: vec![],
+        in_static_context: false,
+        enclosing_outer: enclosing_chain(env, class),
+        static_imports: vec![],
+    }
+}
+
+#[test]
+fn inner_class_method_shadows_outer_overloads() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let string = Type::class(env.well_known().string, vec![]);
+    // class Outer { void log(String s) {} class Inner { void log(int i) {} } }
+    let outer = env.add_class(class(
+        &env,
+        "com.example.Outer",
+        vec![method("log", vec![string.clone()], Type::Void, false)],
+    ));
+    let inner = env.add_class(ClassDef {
+        enclosing: Some(outer),
+        ..class(
+            &env,
+            "com.example.Outer$Inner",
+            vec![method("log", vec![int.clone()], Type::Void, false)],
+        )
+    });
+    let ctx = context(&env, inner);
+
+    let res = resolve_unqualified_call(&env, &ctx, "log", &[int], None);
+    assert_eq!(res.found_in, Some(CallScope::Class(inner)));
+    assert_eq!(res.shadowed, vec![CallScope::Class(outer)]);
+    let MethodResolution::Found(found) = res.resolution else {
+        panic!("expected Inner.log(int), got {:?}", res.resolution);
+    };
+    assert_eq!(found.owner, inner);
+
+    // `log("x")` inside `Inner` does not fall back to `Outer.log(String)`.
+    let res = resolve_unqualified_call(&env, &ctx, "log", &[string], None);
+    assert_eq!(res.found_in, Some(CallScope::Class(inner)));
+    assert!(matches!(res.resolution, MethodResolution::NotFound(_)));
+}
+
+#[test]
+fn outer_instance_method_is_not_visible_from_a_static_nested_class() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let outer = env.add_class(class(
+        &env,
+        "com.example.Outer",
+        vec![method("reset", vec![], Type::Void, false)],
+    ));
+    let nested = env.add_class(ClassDef {
+        is_static_nested: true,
+        is_abstract: false,
+        ..class(&env, "com.example.Outer$Nested", vec![])
+    });
+
+    let res = resolve_unqualified_call(&env, &context(&env, nested), "reset", &[], None);
+    assert_eq!(res.found_in, None);
+    assert!(matches!(res.resolution, MethodResolution::NotFound(_)));
+
+    let res = resolve_unqualified_call(&env, &context(&env, outer), "reset", &[], None);
+    assert_eq!(res.found_in, Some(CallScope::Class(outer)));
+    assert!(matches!(res.resolution, MethodResolution::Found(_)));
+}
+
+#[test]
+fn static_context_skips_instance_method_and_finds_static_import() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let int = Type::Primitive(PrimitiveType::Int);
+    let math = env.lookup_class("java.lang.Math").unwrap();
+    // class Util { int max(int a, int b) { .. } static void main() { max(1, 2); } }
+    let util = env.add_class(class(
+        &env,
+        "com.example.Util",
+        vec![method(
+            "max",
+            vec![int.clone(), int.clone()],
+            int.clone(),
+            false,
+        )],
+    ));
+    let mut ctx = context(&env, util);
+    ctx.static_imports = vec![StaticImport::Single {
+        owner: math,
+        name: "max".to_string(),
+    }];
+
+    ctx.in_static_context = true;
+    let res = resolve_unqualified_call(&env, &ctx, "max", &[int.clone(), int.clone()], None);
+    assert_eq!(res.found_in, Some(CallScope::SingleStaticImport));
+    let MethodResolution::Found(found) = res.resolution else {
+        panic!("expected Math.max(int, int), got {:?}", res.resolution);
+    };
+    assert_eq!(found.owner, math);
+    assert_eq!(found.return_type, int);
+
+    // In an instance method the class's own `max` shadows the import.
+    ctx.in_static_context = false;
+    let res = resolve_unqualified_call(&env, &ctx, "max", &[int.clone(), int], None);
+    assert_eq!(res.found_in, Some(CallScope::Class(util)));
+    assert_eq!(res.shadowed, vec![CallScope::SingleStaticImport]);
+    let MethodResolution::Found(found) = res.resolution else {
+        panic!("expected Util.max(int, int), got {:?}", res.resolution);
+    };
+    assert_eq!(found.owner, util);
+}
@@ -0,0 +1,169 @@
+use nova_types::{
+    resolve_method_call, CallKind, ClassDef, MethodCall, MethodCandidateFailureReason, MethodDef,
+    MethodResolution, PrimitiveType, TyContext, Type, TypeEnv, TypeStore,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn method(params: Vec<Type>, is_varargs: bool) -> MethodDef {
+    MethodDef {
+        is_static: true,
+        is_varargs,
+        ..support::method("m", params, Type::Void)
+    }
+}
+
+fn int() -> Type {
+    Type::Primitive(PrimitiveType::Int)
+}
+
+fn long() -> Type {
+    Type::Primitive(PrimitiveType::Long)
+}
+
+fn int_array() -> Type {
+    Type::Array(Box::new(int()))
+}
+
+#[test]
+fn fixed_arity_methods_only_accept_their_exact_arity() {
+    for len in 0..3 {
+        let params: Vec<Type> = (0..len).map(|_| int()).collect();
+        let m = method(params.clone(), false);
+        for arity in 0..5 {
+            let expected = (arity == len).then(|| params.clone());
+            assert_eq!(
+                m.param_types_for_arity(arity),
+                expected,
+                "params.len()={len}, arity={arity}"
+            );
+        }
+    }
+}
+
+#[test]
+fn varargs_expansion_matches_arity_exactly() {
+    // `m(long, int...)`
+    let m = method(vec![long(), int_array()], true);
+    assert_eq!(m.param_types_for_arity(0), None);
+    assert_eq!(m.param_types_for_arity(1), Some(vec![long()]));
+    assert_eq!(m.param_types_for_arity(2), Some(vec![long(), int()]));
+    assert_eq!(
+        m.param_types_for_arity(4),
+        Some(vec![long(), int(), int(), int()])
+    );
+
+    // `m(long, long, int...)`
+    let m = method(vec![long(), long(), int_array()], true);
+    assert_eq!(m.param_types_for_arity(0), None);
+    assert_eq!(m.param_types_for_arity(1), None);
+    assert_eq!(m.param_types_for_arity(2), Some(vec![long(), long()]));
+    assert_eq!(
+        m.param_types_for_arity(3),
+        Some(vec![long(), long(), int()])
+    );
+}
+
+#[test]
+fn varargs_expansion_is_exhaustively_consistent() {
+    for fixed in 0..3 {
+        let mut params: Vec<Type> = (0..fixed).map(|_| long()).collect();
+        params.push(int_array());
+        let m = method(params, true);
+
+        for arity in 0..6 {
+            let expanded = m.param_types_for_arity(arity);
+            if arity < fixed {
+                assert_eq!(expanded, None, "fixed={fixed}, arity={arity}");
+                continue;
+            }
+            let expanded = expanded.expect("arity >= fixed should expand");
+            assert_eq!(expanded.len(), arity, "fixed={fixed}, arity={arity}");
+            assert!(expanded[..fixed].iter().all(|ty| *ty == long()));
+            assert!(expanded[fixed..].iter().all(|ty| *ty == int()));
+        }
+    }
+}
+
+#[test]
+fn zero_arg_varargs_call_yields_empty_expansion() {
+    // `m(int...)` called as `m()`.
+    let m = method(vec![int_array()], true);
+    assert_eq!(m.param_types_for_arity(0), Some(vec![]));
+    assert_eq!(m.param_types_for_arity(1), Some(vec![int()]));
+}
+
+#[test]
+fn vararg_recorded_without_array_wrapper_is_treated_as_element_type() {
+    let m = method(vec![long(), int()], true);
+    assert_eq!(m.param_types_for_arity(1), Some(vec![long()]));
+    assert_eq!(m.param_types_for_arity(3), Some(vec![long(), int(), int()]));
+}
+
+#[test]
+fn malformed_varargs_without_params_only_accepts_zero_args() {
+    let m = method(vec![], true);
+    assert_eq!(m.param_types_for_arity(0), Some(vec![]));
+    assert_eq!(m.param_types_for_arity(1), None);
+}
+
+#[test]
+fn variable_arity_resolution_reports_wrong_arity_below_fixed_count() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let string = env.well_known().string;
+
+    // `static void m(String fmt, Object... args)`
+    let util = env.add_class(ClassDef {
+        methods: vec![method(
+            vec![
+                Type::class(string, vec![]),
+                Type::Array(Box::new(Type::class(object, vec![]))),
+            ],
+            true,
+        )],
+        ..support::class("com.example.Printf", Some(Type::class(object, vec![])))
+    });
+
+    let call = MethodCall {
+        receiver: Type::class(util, vec![]),
+        call_kind: CallKind::Static,
+        name: "m",
+        args: vec![],
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    };
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::NotFound(not_found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected resolution failure for m()");
+    };
+    assert!(not_found
+        .candidates
+        .iter()
+        .any(|cand| cand.failures.iter().any(|failure| matches!(
+            failure.reason,
+            MethodCandidateFailureReason::WrongArity {
+                expected: 1,
+                found: 0,
+                is_varargs: true,
+            }
+        ))));
+
+    // `m("x")` uses variable-arity invocation with an empty vararg list.
+    let call = MethodCall {
+        args: vec![Type::class(string, vec![])],
+        ..call
+    };
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) = resolve_method_call(&mut ctx, &call) else {
+        panic!("expected m(\"x\") to resolve");
+    };
+    assert!(found.used_varargs);
+    assert_eq!(found.params, vec![Type::class(string, vec![])]);
+}
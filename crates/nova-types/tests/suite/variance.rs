@@ -0,0 +1,119 @@
+use nova_types::{
+    variance_of_type_arg, ClassDef, FieldDef, MethodDef, Type, TypeEnv, TypeStore, Variance,
+    WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn variance_of(env: &TypeStore, class: &str, idx: usize) -> Variance {
+    let id = env.class_id(class).unwrap();
+    variance_of_type_arg(env, env.class(id).unwrap(), idx)
+}
+
+#[test]
+fn functional_interfaces_have_expected_variance() {
+    let env = TypeStore::with_minimal_jdk();
+
+    assert_eq!(
+        variance_of(&env, "java.util.function.Supplier", 0),
+        Variance::Covariant
+    );
+    assert_eq!(
+        variance_of(&env, "java.util.function.Consumer", 0),
+        Variance::Contravariant
+    );
+    assert_eq!(
+        variance_of(&env, "java.util.function.Function", 0),
+        Variance::Contravariant
+    );
+    assert_eq!(
+        variance_of(&env, "java.util.function.Function", 1),
+        Variance::Covariant
+    );
+}
+
+#[test]
+fn nested_occurrences_follow_wildcards_and_fields() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let list = env.class_id("java.util.List").unwrap();
+
+    // class Box<A, B, C, D, E> {
+    //   void sink(List<? super A> xs);   // A: covariant (flipped twice)
+    //   List<B> items();                 // B: invariant (bare type argument)
+    //   final C[] cs;                    // C: covariant
+    //   D d;                             // D: invariant (mutable field)
+    //   static E make(E e);              // E: unused by instance members
+    // }
+    let a = env.add_type_param("A", vec![Type::class(object, vec![])]);
+    let b = env.add_type_param("B", vec![Type::class(object, vec![])]);
+    let c = env.add_type_param("C", vec![Type::class(object, vec![])]);
+    let d = env.add_type_param("D", vec![Type::class(object, vec![])]);
+    let e = env.add_type_param("E", vec![Type::class(object, vec![])]);
+    let method = |name: &str, params: Vec<Type>, return_type: Type, is_static: bool| MethodDef {
+        is_static,
+        ..support::method(name, params, return_type)
+    };
+    let class_def = ClassDef {
+        type_params: vec![a, b, c, d, e],
+        fields: vec![
+            FieldDef {
+                name: "cs".to_string(),
+                ty: Type::Array(Box::new(Type::TypeVar(c))),
+                is_static: false,
+                is_final: true,
+                deprecation: None,
+            },
+            FieldDef {
+                name: "d".to_string(),
+                ty: Type::TypeVar(d),
+                is_static: false,
+                is_final: false,
+                deprecation: None,
+            },
+        ],
+        methods: vec![
+            method(
+                "sink",
+                vec![Type::class(
+                    list,
+                    vec![Type::Wildcard(WildcardBound::Super(Box::new(
+                        Type::TypeVar(a),
+                    )))],
+                )],
+                Type::Void,
+                false,
+            ),
+            method(
+                "items",
+                vec![],
+                Type::class(list, vec![Type::TypeVar(b)]),
+                false,
+            ),
+            method("make", vec![Type::TypeVar(e)], Type::TypeVar(e), true),
+        ],
+        ..support::class("com.example.Box", Some(Type::class(object, vec![])))
+    };
+
+    let expected = [
+        Variance::Covariant,
+        Variance::Invariant,
+        Variance::Covariant,
+        Variance::Invariant,
+        Variance::Bivariant,
+    ];
+    for (idx, expected) in expected.into_iter().enumerate() {
+        assert_eq!(
+            variance_of_type_arg(&env, &class_def, idx),
+            expected,
+            "type param #{idx}"
+        );
+    }
+    // Out-of-range indices have no occurrences.
+    assert_eq!(
+        variance_of_type_arg(&env, &class_def, 5),
+        Variance::Bivariant
+    );
+}
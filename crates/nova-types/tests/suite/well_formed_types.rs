@@ -0,0 +1,98 @@
+use nova_types::{
+    well_formed, ClassDef, Type, TypeEnv, TypeStore, TypeWellFormednessError, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+#[test]
+fn map_arity() {
+    let env = TypeStore::with_minimal_jdk();
+    let map = env.class_id("java.util.Map").unwrap();
+    let string = Type::class(env.well_known().string, vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+
+    // `Map<String, Integer>` and raw `Map`.
+    assert_eq!(
+        well_formed(&env, &Type::class(map, vec![string.clone(), integer])),
+        Ok(())
+    );
+    assert_eq!(well_formed(&env, &Type::class(map, vec![])), Ok(()));
+
+    // `Map<String>`
+    let malformed = Type::class(map, vec![string]);
+    assert_eq!(
+        well_formed(&env, &malformed),
+        Err(TypeWellFormednessError::WrongTypeArgCount {
+            class: map,
+            expected: 2,
+            found: 1,
+        })
+    );
+
+    // Nested: `List<Map<String>>[]`.
+    let list = env.class_id("java.util.List").unwrap();
+    let nested = Type::Array(Box::new(Type::class(list, vec![malformed.clone()])));
+    assert!(matches!(
+        well_formed(&env, &nested),
+        Err(TypeWellFormednessError::WrongTypeArgCount { found: 1, .. })
+    ));
+}
+
+#[test]
+fn primitive_type_argument() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+
+    // `List<int>`
+    let ty = Type::class(list, vec![Type::int()]);
+    assert_eq!(
+        well_formed(&env, &ty),
+        Err(TypeWellFormednessError::PrimitiveTypeArg {
+            class: list,
+            index: 0
+        })
+    );
+}
+
+#[test]
+fn type_argument_bounds() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = Type::class(env.well_known().object, vec![]);
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let string = Type::class(env.well_known().string, vec![]);
+
+    // `class Box<T extends Number>`
+    let t = env.add_type_param("T", vec![number.clone()]);
+    let box_id = env.add_class(ClassDef {
+        type_params: vec![t],
+        ..support::class("com.example.Box", Some(object))
+    });
+
+    assert_eq!(
+        well_formed(&env, &Type::class(box_id, vec![integer])),
+        Ok(())
+    );
+    // Wildcards are not bound-checked: `Box<?>`.
+    assert_eq!(
+        well_formed(
+            &env,
+            &Type::class(box_id, vec![Type::Wildcard(WildcardBound::Unbounded)])
+        ),
+        Ok(())
+    );
+
+    // `Box<String>`
+    let ty = Type::class(box_id, vec![string.clone()]);
+    assert_eq!(
+        well_formed(&env, &ty),
+        Err(TypeWellFormednessError::BoundNotSatisfied {
+            class: box_id,
+            index: 0,
+            arg: string,
+            bound: number,
+        })
+    );
+}
@@ -0,0 +1,129 @@
+use nova_types::{
+    is_assignable, resolve_field, resolve_method_call, CallKind, ClassDef, FieldDef, MethodCall,
+    MethodResolution, TyContext, Type, TypeEnv, TypeStore, WildcardBound,
+};
+
+use pretty_assertions::assert_eq;
+
+use super::support;
+
+fn call<'a>(receiver: &Type, name: &'a str, args: Vec<Type>) -> MethodCall<'a> {
+    MethodCall {
+        receiver: receiver.clone(),
+        call_kind: CallKind::Instance,
+        name,
+        args,
+        expected_return: None,
+        explicit_type_args: vec![],
+        allow_partial_type_args: false,
+        start_above: None,
+        caller_context: None,
+        arg_shapes: vec![],
+    }
+}
+
+fn extends(ty: Type) -> Type {
+    Type::Wildcard(WildcardBound::Extends(Box::new(ty)))
+}
+
+#[test]
+fn get_on_extends_receiver_returns_a_value_of_the_upper_bound() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let receiver = Type::class(list, vec![extends(number.clone())]);
+
+    let mut ctx = TyContext::new(&env);
+    let MethodResolution::Found(found) =
+        resolve_method_call(&mut ctx, &call(&receiver, "get", vec![Type::int()]))
+    else {
+        panic!("expected List<? extends Number>.get(int) to resolve");
+    };
+    assert!(!matches!(found.return_type, Type::Wildcard(_)));
+    assert!(is_assignable(&ctx, &found.return_type, &number));
+}
+
+#[test]
+fn add_on_extends_receiver_only_accepts_null() {
+    let env = TypeStore::with_minimal_jdk();
+    let list = env.class_id("java.util.List").unwrap();
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+    let integer = Type::class(env.well_known().integer, vec![]);
+    let receiver = Type::class(list, vec![extends(number.clone())]);
+
+    for arg in [number, integer] {
+        let mut ctx = TyContext::new(&env);
+        assert!(
+            matches!(
+                resolve_method_call(&mut ctx, &call(&receiver, "add", vec![arg.clone()])),
+                MethodResolution::NotFound(_)
+            ),
+            "add({arg:?}) should not be applicable"
+        );
+    }
+
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call(&receiver, "add", vec![Type::Null])),
+        MethodResolution::Found(_)
+    ));
+}
+
+#[test]
+fn uncaptured_member_reads_project_wildcards_to_their_upper_bound() {
+    let mut env = TypeStore::with_minimal_jdk();
+    let object = env.well_known().object;
+    let number = Type::class(env.class_id("java.lang.Number").unwrap(), vec![]);
+
+    // `class Holder<E> { E value; E[] values; void set(E e); }`
+    let e = env.add_type_param("E", vec![Type::class(object, vec![])]);
+    let holder = env.add_class(ClassDef {
+        type_params: vec![e],
+        fields: vec![
+            FieldDef {
+                name: "value".to_string(),
+                ty: Type::TypeVar(e),
+                is_static: false,
+                is_final: false,
+                deprecation: None,
+            },
+            FieldDef {
+                name: "values".to_string(),
+                ty: Type::Array(Box::new(Type::TypeVar(e))),
+                is_static: false,
+                is_final: false,
+                deprecation: None,
+            },
+        ],
+        methods: vec![support::method("set", vec![Type::TypeVar(e)], Type::Void)],
+        ..support::class("com.example.Holder", Some(Type::class(object, vec![])))
+    });
+    let receiver = Type::class(holder, vec![extends(number.clone())]);
+
+    let value = resolve_field(&env, &receiver, "value", CallKind::Instance).unwrap();
+    assert_eq!(value.ty, number);
+    let values = resolve_field(&env, &receiver, "values", CallKind::Instance).unwrap();
+    assert_eq!(values.ty, Type::Array(Box::new(number.clone())));
+
+    // A wildcard-typed receiver is looked up through its upper bound.
+    let value = resolve_field(
+        &env,
+        &extends(receiver.clone()),
+        "value",
+        CallKind::Instance,
+    )
+    .expect("field lookup on a wildcard receiver");
+    assert_eq!(value.ty, number);
+
+    // Parameters keep the wildcard, so only `null` is accepted.
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call(&receiver, "set", vec![number])),
+        MethodResolution::NotFound(_)
+    ));
+    let mut ctx = TyContext::new(&env);
+    assert!(matches!(
+        resolve_method_call(&mut ctx, &call(&receiver, "set", vec![Type::Null])),
+        MethodResolution::Found(_)
+    ));
+}
@@ -13,15 +13,16 @@ use nova_config::{init_tracing_with_config, NovaConfig};
 use nova_db::salsa::Database as SalsaDatabase;
 use nova_db::{FileId, NovaHir, NovaInputs, NovaSyntax, SourceRootId};
 use nova_remote_proto::v3::{
-    CachedIndexInfo, Capabilities, CompressionAlgo, DiagnosticSeverity, Notification,
-    ProtocolVersion, RemoteDiagnostic, Request, Response, RpcError as ProtoRpcError, RpcErrorCode,
-    SupportedVersions,
+    CachedIndexInfo, Capabilities, CompressionAlgo, DiagnosticSeverity, FileChange, Notification,
+    ProtocolVersion, RemoteDiagnostic, RemoteDiagnosticTag, RemoteFixHint, RemoteRelatedInfo,
+    Request, Response, RpcError as ProtoRpcError, RpcErrorCode, SupportedVersions, SymbolReference,
 };
 use nova_remote_proto::{FileText, ShardId, ShardIndex, WorkerStats};
 use nova_remote_rpc::{CancellationToken, RequestContext, RpcConnection, WorkerConfig};
+use nova_types::{Diagnostic, DiagnosticTag, Severity, Span};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::net::TcpStream;
-use tokio::sync::{watch, Mutex};
+use tokio::sync::{mpsc, watch, Mutex};
 use tracing::{info, warn};
 
 #[cfg(unix)]
@@ -35,6 +36,9 @@ mod tls;
 
 const DEFAULT_MAX_RPC_BYTES: usize = nova_remote_proto::MAX_MESSAGE_BYTES;
 
+/// `Notification::IndexProgress` is sent every this many files (and once all files are done).
+const INDEX_PROGRESS_INTERVAL_FILES: usize = 256;
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> ExitCode {
     match run().await {
@@ -207,10 +211,12 @@ Use `tcp+tls:` or pass `--allow-insecure` for local testing."
     conn.set_request_handler({
         let state = state.clone();
         let shutdown_tx = shutdown_tx.clone();
+        let conn = conn.clone();
         move |ctx, request| {
             let state = state.clone();
             let shutdown_tx = shutdown_tx.clone();
-            async move { handle_request(state, shutdown_tx, ctx, request).await }
+            let conn = conn.clone();
+            async move { handle_request(state, shutdown_tx, conn, ctx, request).await }
         }
     });
 
@@ -269,6 +275,7 @@ fn contains_serde_json_error(err: &(dyn std::error::Error + 'static)) -> bool {
 async fn handle_request(
     state: Arc<Mutex<WorkerState>>,
     shutdown_tx: watch::Sender<bool>,
+    conn: RpcConnection,
     ctx: RequestContext,
     request: Request,
 ) -> std::result::Result<Response, ProtoRpcError> {
@@ -291,7 +298,15 @@ async fn handle_request(
             if ctx.cancellation().is_cancelled() {
                 return Err(cancelled_error());
             }
-            let index = state.build_index(Some(ctx.cancellation())).await?;
+            let (progress_tx, progress_rx) = mpsc::unbounded_channel();
+            let forward_progress =
+                tokio::spawn(forward_index_progress(conn, revision, progress_rx));
+            let index = state
+                .build_index(Some(ctx.cancellation()), Some(progress_tx))
+                .await;
+            // `build_index` dropped the sender; flush progress before the response goes out.
+            let _ = forward_progress.await;
+            let index = index?;
             if ctx.cancellation().is_cancelled() {
                 return Err(cancelled_error());
             }
@@ -305,7 +320,39 @@ async fn handle_request(
             if ctx.cancellation().is_cancelled() {
                 return Err(cancelled_error());
             }
-            let index = state.build_index(Some(ctx.cancellation())).await?;
+            let index = state.build_index(Some(ctx.cancellation()), None).await?;
+            if ctx.cancellation().is_cancelled() {
+                return Err(cancelled_error());
+            }
+            Ok(Response::ShardIndex(index))
+        }
+        Request::UpdateFiles { revision, changes } => {
+            if changes
+                .iter()
+                .any(|change| matches!(change, FileChange::Unknown))
+            {
+                return Err(ProtoRpcError {
+                    code: RpcErrorCode::InvalidRequest,
+                    message: "unknown file change".into(),
+                    retryable: false,
+                    details: None,
+                });
+            }
+
+            let mut state = state.lock().await;
+            state.revision = revision;
+            for change in changes {
+                match change {
+                    FileChange::Upsert { file } => state.apply_file_update(file),
+                    FileChange::Delete { path } => state.apply_file_delete(&path),
+                    FileChange::Unknown => {}
+                }
+            }
+
+            if ctx.cancellation().is_cancelled() {
+                return Err(cancelled_error());
+            }
+            let index = state.build_index(Some(ctx.cancellation()), None).await?;
             if ctx.cancellation().is_cancelled() {
                 return Err(cancelled_error());
             }
@@ -330,16 +377,33 @@ async fn handle_request(
                 .iter()
                 .take(nova_remote_proto::MAX_DIAGNOSTICS_PER_MESSAGE)
             {
-                let (line, column_utf16) = byte_offset_to_line_col(&text, err.range.start);
-                diagnostics.push(RemoteDiagnostic {
-                    severity: DiagnosticSeverity::Error,
-                    line,
-                    column: column_utf16,
-                    message: err.message.clone(),
-                });
+                let diagnostic = Diagnostic::error(
+                    "syntax-error",
+                    err.message.clone(),
+                    Some(Span::new(err.range.start as usize, err.range.end as usize)),
+                )
+                .with_source("syntax");
+                diagnostics.push(remote_diagnostic(&text, &diagnostic, |path| {
+                    let file_id = *state.path_to_file_id.get(path)?;
+                    Some(snap.file_content(file_id))
+                }));
             }
             Ok(Response::Diagnostics { diagnostics })
         }
+        Request::FindReferences { symbol } => {
+            let state = state.lock().await;
+            let snap = state.db.snapshot();
+            let mut references = Vec::new();
+            for (path, &file_id) in &state.files {
+                if ctx.cancellation().is_cancelled() {
+                    return Err(cancelled_error());
+                }
+                let text = snap.file_content(file_id);
+                references.extend(find_name_references(path, &text, &symbol.name));
+            }
+            references.truncate(nova_remote_proto::MAX_REFERENCES_PER_MESSAGE);
+            Ok(Response::References { references })
+        }
         Request::GetWorkerStats => {
             let state = state.lock().await;
             Ok(Response::WorkerStats(state.worker_stats()))
@@ -357,11 +421,108 @@ async fn handle_request(
     }
 }
 
+/// Every occurrence of `name` in `text` that is a whole Java identifier (not part of a longer
+/// one).
+fn find_name_references(path: &str, text: &str, name: &str) -> Vec<SymbolReference> {
+    if name.is_empty() {
+        return Vec::new();
+    }
+    let is_ident = |ch: char| ch.is_alphanumeric() || ch == '_' || ch == '$';
+    let line_index = nova_core::LineIndex::new(text);
+    text.match_indices(name)
+        .filter(|(start, _)| {
+            let before = text[..*start].chars().next_back();
+            let after = text[start + name.len()..].chars().next();
+            !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+        })
+        .map(|(start, _)| {
+            let offset = u32::try_from(start).unwrap_or(u32::MAX);
+            let pos = line_index.position(text, nova_core::TextSize::from(offset));
+            SymbolReference {
+                path: path.to_string(),
+                offset,
+                line: pos.line,
+                column: pos.character,
+            }
+        })
+        .collect()
+}
+
 /// Convert a byte offset within `text` into a 0-based (line, column) pair.
 ///
 /// `line` is counted by the number of `\n` characters before `byte_offset`.
 /// `column` is measured in UTF-16 code units since the last `\n`, matching the
 /// LSP `Position.character` encoding.
+/// Converts a diagnostic reported against `text` into its wire form.
+///
+/// Related locations in other files are positioned using `file_text`; a location whose file the
+/// shard does not know keeps its path but is reported at line 0, column 0.
+fn remote_diagnostic(
+    text: &str,
+    diagnostic: &Diagnostic,
+    file_text: impl Fn(&str) -> Option<Arc<String>>,
+) -> RemoteDiagnostic {
+    let (line, column) = diagnostic
+        .span
+        .map(|span| byte_offset_to_line_col(text, span.start as u32))
+        .unwrap_or((0, 0));
+
+    let related = diagnostic
+        .related
+        .iter()
+        .take(nova_remote_proto::MAX_RELATED_PER_DIAGNOSTIC)
+        .map(|related| {
+            let path = related
+                .file
+                .as_ref()
+                .map(|file| file.to_string_lossy().into_owned());
+            let (line, column) = match &path {
+                None => byte_offset_to_line_col(text, related.span.start as u32),
+                Some(path) => file_text(path)
+                    .map(|other| byte_offset_to_line_col(&other, related.span.start as u32))
+                    .unwrap_or((0, 0)),
+            };
+            RemoteRelatedInfo {
+                path,
+                line,
+                column,
+                message: related.message.clone(),
+            }
+        })
+        .collect();
+
+    RemoteDiagnostic {
+        severity: match diagnostic.severity {
+            Severity::Error => DiagnosticSeverity::Error,
+            Severity::Warning => DiagnosticSeverity::Warning,
+            Severity::Info => DiagnosticSeverity::Info,
+            Severity::Hint => DiagnosticSeverity::Hint,
+        },
+        line,
+        column,
+        message: diagnostic.message.clone(),
+        source: diagnostic.source.as_deref().map(str::to_owned),
+        related,
+        tags: diagnostic
+            .tags
+            .iter()
+            .map(|tag| match tag {
+                DiagnosticTag::Unnecessary => RemoteDiagnosticTag::Unnecessary,
+                DiagnosticTag::Deprecated => RemoteDiagnosticTag::Deprecated,
+            })
+            .collect(),
+        fix_hint: diagnostic.fix_hint.as_ref().map(|hint| RemoteFixHint {
+            id: hint.id.to_string(),
+            args: hint
+                .args
+                .iter()
+                .take(nova_remote_proto::MAX_DIAGNOSTIC_ATTRS)
+                .cloned()
+                .collect(),
+        }),
+    }
+}
+
 fn byte_offset_to_line_col(text: &str, byte_offset: u32) -> (u32, u32) {
     let mut end = (byte_offset as usize).min(text.len());
 
@@ -772,6 +933,13 @@ impl WorkerState {
         self.files.insert(file.path, file_id);
     }
 
+    fn apply_file_delete(&mut self, path: &str) {
+        if let Some(file_id) = self.files.remove(path) {
+            self.db.set_file_exists(file_id, false);
+            self.db.set_file_content(file_id, Arc::new(String::new()));
+        }
+    }
+
     fn file_id_for_path(&mut self, path: &str) -> FileId {
         if let Some(file_id) = self.path_to_file_id.get(path) {
             return *file_id;
@@ -783,9 +951,12 @@ impl WorkerState {
         file_id
     }
 
+    /// Rebuilds the shard index, reporting `(files_done, file_count)` to `progress` every
+    /// [`INDEX_PROGRESS_INTERVAL_FILES`] files.
     async fn build_index(
         &mut self,
         cancel: Option<CancellationToken>,
+        progress: Option<mpsc::UnboundedSender<(u32, u32)>>,
     ) -> std::result::Result<ShardIndex, ProtoRpcError> {
         let next_index_generation = self.index_generation.saturating_add(1);
         let shard_id = self.shard_id;
@@ -804,7 +975,7 @@ impl WorkerState {
                 if cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
                     return Err(cancelled_error());
                 }
-                let symbols = build_symbols(&db, &files, cancel.as_ref())?;
+                let symbols = build_symbols(&db, &files, cancel.as_ref(), progress.as_ref())?;
                 if cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
                     return Err(cancelled_error());
                 }
@@ -832,14 +1003,36 @@ impl WorkerState {
     }
 }
 
+/// Sends each `(files_done, file_count)` report as a `Notification::IndexProgress` until the
+/// indexing task drops its sender.
+async fn forward_index_progress(
+    conn: RpcConnection,
+    revision: u64,
+    mut progress: mpsc::UnboundedReceiver<(u32, u32)>,
+) {
+    while let Some((files_done, file_count)) = progress.recv().await {
+        let notification = Notification::IndexProgress {
+            revision,
+            files_done,
+            file_count,
+        };
+        if let Err(err) = conn.notify(notification).await {
+            warn!(error = ?err, "failed to send index progress notification");
+            return;
+        }
+    }
+}
+
 fn build_symbols(
     db: &SalsaDatabase,
     files: &[(String, FileId)],
     cancel: Option<&CancellationToken>,
+    progress: Option<&mpsc::UnboundedSender<(u32, u32)>>,
 ) -> std::result::Result<Vec<nova_remote_proto::Symbol>, ProtoRpcError> {
     let snap = db.snapshot();
     let mut symbols = Vec::new();
-    for (path, file_id) in files {
+    let file_count = u32::try_from(files.len()).unwrap_or(u32::MAX);
+    for (idx, (path, file_id)) in files.iter().enumerate() {
         if cancel.is_some_and(|c| c.is_cancelled()) {
             return Err(cancelled_error());
         }
@@ -910,9 +1103,9 @@ fn build_symbols(
                         );
                     }
                     nova_hir::item_tree::Member::Initializer(_) => {}
-                    nova_hir::item_tree::Member::Type(item) => collect_item_symbols(
-                        tree, *item, line_index, text, path, out,
-                    ),
+                    nova_hir::item_tree::Member::Type(item) => {
+                        collect_item_symbols(tree, *item, line_index, text, path, out)
+                    }
                 }
             }
         }
@@ -936,14 +1129,7 @@ fn build_symbols(
                         path,
                         data.name_range.start,
                     );
-                    collect_member_symbols(
-                        tree,
-                        &data.members,
-                        line_index,
-                        text,
-                        path,
-                        out,
-                    );
+                    collect_member_symbols(tree, &data.members, line_index, text, path, out);
                 }
                 nova_hir::item_tree::Item::Interface(id) => {
                     let data = tree.interface(id);
@@ -955,14 +1141,7 @@ fn build_symbols(
                         path,
                         data.name_range.start,
                     );
-                    collect_member_symbols(
-                        tree,
-                        &data.members,
-                        line_index,
-                        text,
-                        path,
-                        out,
-                    );
+                    collect_member_symbols(tree, &data.members, line_index, text, path, out);
                 }
                 nova_hir::item_tree::Item::Enum(id) => {
                     let data = tree.enum_(id);
@@ -974,14 +1153,7 @@ fn build_symbols(
                         path,
                         data.name_range.start,
                     );
-                    collect_member_symbols(
-                        tree,
-                        &data.members,
-                        line_index,
-                        text,
-                        path,
-                        out,
-                    );
+                    collect_member_symbols(tree, &data.members, line_index, text, path, out);
                 }
                 nova_hir::item_tree::Item::Record(id) => {
                     let data = tree.record(id);
@@ -993,14 +1165,7 @@ fn build_symbols(
                         path,
                         data.name_range.start,
                     );
-                    collect_member_symbols(
-                        tree,
-                        &data.members,
-                        line_index,
-                        text,
-                        path,
-                        out,
-                    );
+                    collect_member_symbols(tree, &data.members, line_index, text, path, out);
                 }
                 nova_hir::item_tree::Item::Annotation(id) => {
                     let data = tree.annotation(id);
@@ -1012,14 +1177,7 @@ fn build_symbols(
                         path,
                         data.name_range.start,
                     );
-                    collect_member_symbols(
-                        tree,
-                        &data.members,
-                        line_index,
-                        text,
-                        path,
-                        out,
-                    );
+                    collect_member_symbols(tree, &data.members, line_index, text, path, out);
                 }
             }
         }
@@ -1027,6 +1185,14 @@ fn build_symbols(
         for item in tree.items.iter() {
             collect_item_symbols(tree.as_ref(), *item, &line_index, &text, path, &mut symbols);
         }
+
+        let files_done = idx + 1;
+        if let Some(progress) = progress {
+            if files_done % INDEX_PROGRESS_INTERVAL_FILES == 0 || files_done == files.len() {
+                let files_done = u32::try_from(files_done).unwrap_or(u32::MAX);
+                let _ = progress.send((files_done, file_count));
+            }
+        }
     }
 
     symbols.sort_by(|a, b| {
@@ -1048,6 +1214,7 @@ impl<T> AsyncReadWrite for T where T: AsyncRead + AsyncWrite + Unpin + Send + 's
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nova_types::{FixHint, RelatedInfo};
     use tempfile::TempDir;
 
     #[test]
@@ -1427,6 +1594,55 @@ mod tests {
         assert_eq!(col, 3);
     }
 
+    #[test]
+    fn remote_diagnostic_positions_related_locations_in_their_own_files() {
+        let text = "class A {}\nclass B {}\n";
+        let other = Arc::new("\n\n  class B {}".to_string());
+        let diagnostic = Diagnostic::warning("DUP", "duplicate class `B`", Some(Span::new(11, 21)))
+            .with_source("types")
+            .with_related(RelatedInfo::new(Span::new(0, 10), "same file"))
+            .with_related(RelatedInfo::in_file(
+                "Other.java",
+                Span::new(4, 11),
+                "other file",
+            ))
+            .with_related(RelatedInfo::in_file(
+                "Missing.java",
+                Span::new(5, 6),
+                "unknown file",
+            ))
+            .with_tag(DiagnosticTag::Deprecated)
+            .with_fix_hint(FixHint::new("remove-class").with_arg("B"));
+
+        let remote = remote_diagnostic(text, &diagnostic, |path| {
+            (path == "Other.java").then(|| other.clone())
+        });
+
+        assert_eq!(remote.severity, DiagnosticSeverity::Warning);
+        assert_eq!((remote.line, remote.column), (1, 0));
+        assert_eq!(remote.source.as_deref(), Some("types"));
+        assert_eq!(
+            remote
+                .related
+                .iter()
+                .map(|r| (r.path.as_deref(), r.line, r.column))
+                .collect::<Vec<_>>(),
+            vec![
+                (None, 0, 0),
+                (Some("Other.java"), 2, 2),
+                (Some("Missing.java"), 0, 0)
+            ]
+        );
+        assert_eq!(remote.tags, vec![RemoteDiagnosticTag::Deprecated]);
+        assert_eq!(
+            remote.fix_hint,
+            Some(RemoteFixHint {
+                id: "remove-class".into(),
+                args: vec!["B".into()],
+            })
+        );
+    }
+
     fn parse_executions(db: &SalsaDatabase) -> u64 {
         db.query_stats()
             .by_query
@@ -1453,7 +1669,7 @@ mod tests {
         ]);
 
         let _ = state
-            .build_index(None)
+            .build_index(None, None)
             .await
             .map_err(|err| anyhow!(err.message))?;
         let first_parse = parse_executions(&state.db);
@@ -1468,7 +1684,7 @@ mod tests {
             text: "class Alpha { int x; }".into(),
         });
         let _ = state
-            .build_index(None)
+            .build_index(None, None)
             .await
             .map_err(|err| anyhow!(err.message))?;
 
@@ -7,7 +7,9 @@ use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
 use nova_fuzzy::FuzzyMatcher;
-use nova_remote_proto::v3::{Notification, Request, Response, WireFrame};
+use nova_remote_proto::v3::{
+    FileChange, Notification, Request, Response, SymbolKey, SymbolReference, WireFrame,
+};
 use nova_remote_proto::{FileText, ShardId, ShardIndex, Symbol, WorkerStats};
 use nova_remote_rpc::{RouterConfig, RpcConnection};
 use tempfile::TempDir;
@@ -95,6 +97,152 @@ async fn distributed_indexing_updates_only_one_shard() -> Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn distributed_batch_update_reindexes_each_shard_once() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let workspace_root = tmp.path();
+
+    let module_a = workspace_root.join("module_a").join("src");
+    let module_b = workspace_root.join("module_b").join("src");
+    tokio::fs::create_dir_all(&module_a).await?;
+    tokio::fs::create_dir_all(&module_b).await?;
+
+    let file_a = module_a.join("A.java");
+    let file_old = module_a.join("Old.java");
+    let file_b = module_b.join("B.java");
+    let file_c = module_b.join("C.java");
+    tokio::fs::write(&file_a, "package a; public class Alpha {}").await?;
+    tokio::fs::write(&file_old, "package a; public class Obsolete {}").await?;
+    tokio::fs::write(&file_b, "package b; public class Beta {}").await?;
+
+    let listen_path = workspace_root.join("router.sock");
+    let cache_dir = workspace_root.join("cache");
+    let worker_bin = PathBuf::from(env!("CARGO_BIN_EXE_nova-worker"));
+
+    let router = TestRouter::new(listen_path.clone()).await?;
+    let mut workers = vec![
+        spawn_worker(&worker_bin, &listen_path, &cache_dir, 0).await?,
+        spawn_worker(&worker_bin, &listen_path, &cache_dir, 1).await?,
+    ];
+    router.wait_for_workers(&[0, 1]).await?;
+    router.index_shard(0, &module_a).await?;
+    router.index_shard(1, &module_b).await?;
+
+    let before = router.worker_stats(&[0, 1]).await?;
+
+    // `Old.java` is deleted while still on disk; the worker must drop it from its file map.
+    let upsert = |path: &Path, text: &str| FileChange::Upsert {
+        file: FileText {
+            path: path.to_string_lossy().to_string(),
+            text: text.to_string(),
+        },
+    };
+    router
+        .update_files(
+            0,
+            vec![
+                upsert(&file_a, "package a; public class Alpha {} class Gamma {}"),
+                FileChange::Delete {
+                    path: file_old.to_string_lossy().to_string(),
+                },
+            ],
+        )
+        .await?;
+    router
+        .update_files(
+            1,
+            vec![
+                upsert(&file_b, "package b; public class Beta {} class Delta {}"),
+                upsert(&file_c, "package b; public class Charlie {}"),
+            ],
+        )
+        .await?;
+
+    let after = router.worker_stats(&[0, 1]).await?;
+    for shard in [0, 1] {
+        assert_eq!(
+            after[&shard].index_generation,
+            before[&shard].index_generation + 1,
+            "expected shard {shard} to reindex exactly once"
+        );
+    }
+    assert_eq!(after[&0].file_count, 1);
+    assert_eq!(after[&1].file_count, 2);
+
+    let symbols = router.workspace_symbols("").await;
+    let names: Vec<_> = symbols.into_iter().map(|s| s.name).collect();
+    for name in ["Alpha", "Gamma", "Beta", "Delta", "Charlie"] {
+        assert!(
+            names.contains(&name.to_string()),
+            "missing {name}: {names:?}"
+        );
+    }
+    assert!(!names.contains(&"Obsolete".to_string()));
+
+    router.shutdown_workers(&[0, 1]).await?;
+    router.shutdown().await?;
+
+    for worker in &mut workers {
+        let status = tokio::time::timeout(Duration::from_secs(10), worker.wait()).await??;
+        assert!(status.success());
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn index_shard_reports_progress_notifications() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let workspace_root = tmp.path();
+
+    let module = workspace_root.join("module").join("src");
+    tokio::fs::create_dir_all(&module).await?;
+    for idx in 0..300 {
+        tokio::fs::write(
+            module.join(format!("C{idx}.java")),
+            format!("class C{idx} {{}}"),
+        )
+        .await?;
+    }
+
+    let listen_path = workspace_root.join("router.sock");
+    let cache_dir = workspace_root.join("cache");
+    let worker_bin = PathBuf::from(env!("CARGO_BIN_EXE_nova-worker"));
+
+    let router = TestRouter::new(listen_path.clone()).await?;
+    let mut worker = spawn_worker(&worker_bin, &listen_path, &cache_dir, 0).await?;
+    router.wait_for_workers(&[0]).await?;
+    router.index_shard(0, &module).await?;
+
+    let revision = router.current_revision();
+    assert_eq!(
+        router.wait_for_index_progress(0, revision).await?,
+        vec![(256, 300), (300, 300)]
+    );
+
+    // Incremental updates do not report progress.
+    router
+        .update_file(
+            0,
+            FileText {
+                path: module.join("C0.java").to_string_lossy().to_string(),
+                text: "class Changed {}".to_string(),
+            },
+        )
+        .await?;
+    let reports = router.state.progress.lock().await.len();
+    assert_eq!(reports, 2);
+
+    router.shutdown_workers(&[0]).await?;
+    router.shutdown().await?;
+    let status = tokio::time::timeout(Duration::from_secs(10), worker.wait()).await??;
+    assert!(status.success());
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn distributed_diagnostics_returns_parse_errors() -> Result<()> {
@@ -140,6 +288,74 @@ async fn distributed_diagnostics_returns_parse_errors() -> Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn distributed_find_references_matches_whole_identifiers() -> Result<()> {
+    let tmp = TempDir::new()?;
+    let workspace_root = tmp.path();
+
+    let module = workspace_root.join("module").join("src");
+    tokio::fs::create_dir_all(&module).await?;
+
+    let file = module.join("A.java");
+    tokio::fs::write(
+        &file,
+        "class Alpha {\n  Alpha next;\n  AlphaBeta other;\n}\n",
+    )
+    .await?;
+
+    let listen_path = workspace_root.join("router.sock");
+    let cache_dir = workspace_root.join("cache");
+    let worker_bin = PathBuf::from(env!("CARGO_BIN_EXE_nova-worker"));
+
+    let router = TestRouter::new(listen_path.clone()).await?;
+    let mut worker = spawn_worker(&worker_bin, &listen_path, &cache_dir, 0).await?;
+
+    router.wait_for_workers(&[0]).await?;
+    router.index_shard(0, &module).await?;
+
+    let resp = router
+        .call(
+            0,
+            Request::FindReferences {
+                symbol: SymbolKey {
+                    name: "Alpha".into(),
+                },
+            },
+        )
+        .await?;
+    let references = match resp {
+        Response::References { references } => references,
+        other => return Err(anyhow!("unexpected FindReferences response: {other:?}")),
+    };
+    let path = file.to_string_lossy().to_string();
+    assert_eq!(
+        references,
+        vec![
+            SymbolReference {
+                path: path.clone(),
+                offset: 6,
+                line: 0,
+                column: 6,
+            },
+            SymbolReference {
+                path,
+                offset: 16,
+                line: 1,
+                column: 2,
+            },
+        ]
+    );
+
+    router.shutdown_workers(&[0]).await?;
+    router.shutdown().await?;
+
+    let status = tokio::time::timeout(Duration::from_secs(10), worker.wait()).await??;
+    assert!(status.success());
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn invalid_hello_doesnt_kill_accept_loop() -> Result<()> {
@@ -479,6 +695,8 @@ struct TestRouter {
 struct TestRouterState {
     workers: Mutex<HashMap<ShardId, RpcConnection>>,
     indexes: Mutex<HashMap<ShardId, ShardIndex>>,
+    /// `(shard_id, revision, files_done, file_count)` of each `IndexProgress` notification.
+    progress: Mutex<Vec<(ShardId, u64, u32, u32)>>,
     next_worker_id: AtomicU32,
     revision: AtomicU64,
 }
@@ -493,6 +711,7 @@ impl TestRouter {
         let state = Arc::new(TestRouterState {
             workers: Mutex::new(HashMap::new()),
             indexes: Mutex::new(HashMap::new()),
+            progress: Mutex::new(Vec::new()),
             next_worker_id: AtomicU32::new(1),
             revision: AtomicU64::new(0),
         });
@@ -580,6 +799,39 @@ impl TestRouter {
         }
     }
 
+    /// Waits until shard `shard_id` reported progress `files_done == file_count` for `revision`,
+    /// then returns its reports for that revision as `(files_done, file_count)`, sorted.
+    async fn wait_for_index_progress(
+        &self,
+        shard_id: ShardId,
+        revision: u64,
+    ) -> Result<Vec<(u32, u32)>> {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        loop {
+            let mut reports: Vec<(u32, u32)> = self
+                .state
+                .progress
+                .lock()
+                .await
+                .iter()
+                .filter(|(shard, rev, _, _)| *shard == shard_id && *rev == revision)
+                .map(|(_, _, done, count)| (*done, *count))
+                .collect();
+            if reports.iter().any(|(done, count)| done == count) {
+                reports.sort_unstable();
+                return Ok(reports);
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(anyhow!(
+                    "timed out waiting for index progress; got {reports:?}"
+                ));
+            }
+
+            tokio::task::yield_now().await;
+        }
+    }
+
     async fn load_shard_files(&self, shard_id: ShardId, root: &Path) -> Result<()> {
         let files = collect_java_files(root).await?;
         let revision = self.current_revision();
@@ -606,6 +858,20 @@ impl TestRouter {
         }
     }
 
+    async fn update_files(&self, shard_id: ShardId, changes: Vec<FileChange>) -> Result<()> {
+        let revision = self.next_revision();
+        let resp = self
+            .call(shard_id, Request::UpdateFiles { revision, changes })
+            .await?;
+        match resp {
+            Response::ShardIndex(index) => {
+                self.state.indexes.lock().await.insert(shard_id, index);
+                Ok(())
+            }
+            other => Err(anyhow!("unexpected UpdateFiles response: {other:?}")),
+        }
+    }
+
     async fn worker_stats(&self, shards: &[ShardId]) -> Result<HashMap<ShardId, WorkerStats>> {
         let mut out = HashMap::new();
         for &shard_id in shards {
@@ -767,6 +1033,7 @@ async fn handle_connection(stream: UnixStream, state: Arc<TestRouterState>) -> R
         .await
         .map_err(|err| anyhow!("handshake failed: {err}"))?;
 
+    let shard_id = welcome.shard_id;
     conn.set_notification_handler({
         let state = state.clone();
         move |notification| {
@@ -776,6 +1043,17 @@ async fn handle_connection(stream: UnixStream, state: Arc<TestRouterState>) -> R
                     Notification::CachedIndex(index) => {
                         state.indexes.lock().await.insert(index.shard_id, index);
                     }
+                    Notification::IndexProgress {
+                        revision,
+                        files_done,
+                        file_count,
+                    } => {
+                        state
+                            .progress
+                            .lock()
+                            .await
+                            .push((shard_id, revision, files_done, file_count));
+                    }
                     Notification::Unknown => {}
                 }
             }
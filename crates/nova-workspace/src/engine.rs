@@ -2162,14 +2162,7 @@ impl WorkspaceEngine {
         // Truncate before mapping into `nova_types::CompletionItem` so we avoid allocating
         // intermediate completion structs (and their strings) that would be dropped immediately.
         lsp_items.truncate(cap);
-        lsp_items
-            .into_iter()
-            .map(|item| CompletionItem {
-                label: item.label,
-                detail: item.detail,
-                replace_span: None,
-            })
-            .collect()
+        nova_ide::analysis::completion_items_from_lsp(text, lsp_items)
     }
 
     fn background_indexing_plan(
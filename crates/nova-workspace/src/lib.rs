@@ -857,7 +857,9 @@ impl Workspace {
             let severity = match source_diag.diagnostic.severity {
                 nova_framework_spring::Severity::Error => Severity::Error,
                 nova_framework_spring::Severity::Warning => Severity::Warning,
-                nova_framework_spring::Severity::Info => Severity::Warning,
+                nova_framework_spring::Severity::Info | nova_framework_spring::Severity::Hint => {
+                    Severity::Warning
+                }
             };
 
             let display_path = file_path
@@ -934,7 +936,9 @@ impl Workspace {
             let severity = match source_diag.diagnostic.severity {
                 nova_framework_jpa::Severity::Error => Severity::Error,
                 nova_framework_jpa::Severity::Warning => Severity::Warning,
-                nova_framework_jpa::Severity::Info => Severity::Warning,
+                nova_framework_jpa::Severity::Info | nova_framework_jpa::Severity::Hint => {
+                    Severity::Warning
+                }
             };
 
             let display_path = file_path